@@ -0,0 +1,194 @@
+/// 缓存注册表
+///
+/// 统一登记各模块的内存缓存（文件索引、剪贴板历史等），总占用超过配置的内存预算时
+/// 定期裁剪，并为 `/memory` 诊断视图提供各缓存的占用
+use std::{
+    cmp::Reverse,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+
+use crate::core::config_manager::global_config;
+
+/// 可裁剪的缓存
+pub trait Cache: Send + Sync {
+    /// 缓存名称，显示在诊断视图中，同时作为登记的唯一标识
+    fn name(&self) -> &str;
+
+    /// 当前占用的内存（字节，估算值）
+    fn memory_usage(&self) -> usize;
+
+    /// 裁剪到不超过 `target` 字节，返回释放的字节数
+    fn trim(&self, target: usize) -> usize;
+}
+
+/// 缓存占用
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheUsage {
+    /// 缓存名称
+    pub name: String,
+    /// 占用的内存（字节）
+    pub bytes: usize,
+}
+
+/// 缓存注册表
+#[derive(Default)]
+pub struct CacheRegistry {
+    /// 已登记的缓存
+    caches: RwLock<Vec<Arc<dyn Cache>>>,
+    /// 定期裁剪线程是否已启动
+    trimming: AtomicBool,
+}
+
+impl CacheRegistry {
+    /// 创建新的缓存注册表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记缓存，同名缓存（如插件重新初始化）替换之前的登记
+    pub fn register(&self, cache: Arc<dyn Cache>) {
+        log::debug!("登记缓存: {}", cache.name());
+        let mut caches = self.caches.write().unwrap();
+        caches.retain(|c| c.name() != cache.name());
+        caches.push(cache);
+    }
+
+    /// 各缓存的占用，按占用从大到小
+    pub fn usage(&self) -> Vec<CacheUsage> {
+        let mut usage: Vec<_> = self
+            .caches
+            .read()
+            .unwrap()
+            .iter()
+            .map(|cache| CacheUsage { name: cache.name().to_string(), bytes: cache.memory_usage() })
+            .collect();
+        usage.sort_by_key(|u| Reverse(u.bytes));
+        usage
+    }
+
+    /// 所有缓存的总占用
+    pub fn total_usage(&self) -> usize {
+        self.caches.read().unwrap().iter().map(|cache| cache.memory_usage()).sum()
+    }
+
+    /// 总占用超过 `budget` 时裁剪缓存，返回释放的字节数
+    ///
+    /// 预算按各缓存当前占用的比例分配，占用大的缓存释放得多
+    pub fn trim_to(&self, budget: usize) -> usize {
+        let caches = self.caches.read().unwrap().clone();
+        let usage: Vec<_> = caches.iter().map(|cache| cache.memory_usage()).collect();
+        let total: usize = usage.iter().sum();
+        if total <= budget {
+            return 0;
+        }
+
+        let mut freed = 0;
+        for (cache, bytes) in caches.iter().zip(usage) {
+            let target = (bytes as u128 * budget as u128 / total as u128) as usize;
+            let released = cache.trim(target);
+            if released > 0 {
+                log::info!("裁剪缓存 {}: 释放 {}", cache.name(), format_bytes(released));
+            }
+            freed += released;
+        }
+        freed
+    }
+
+    /// 释放指定名称的缓存，返回释放的字节数
+    pub fn release(&self, name: &str) -> usize {
+        let cache = self.caches.read().unwrap().iter().find(|c| c.name() == name).cloned();
+        cache.map_or(0, |cache| cache.trim(0))
+    }
+
+    /// 按配置的内存预算裁剪
+    pub fn trim(&self) -> usize {
+        self.trim_to(global_config().memory().budget_bytes())
+    }
+
+    /// 启动定期裁剪线程，重复调用只启动一次
+    pub fn start_periodic_trim(&'static self) {
+        if self.trimming.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        std::thread::spawn(move || loop {
+            let interval = global_config().memory().trim_interval_secs.max(1);
+            std::thread::sleep(Duration::from_secs(interval));
+            self.trim();
+        });
+    }
+}
+
+/// 格式化字节数
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit_index])
+}
+
+/// 全局缓存注册表
+static GLOBAL_CACHES: Lazy<CacheRegistry> = Lazy::new(CacheRegistry::new);
+
+/// 获取全局缓存注册表
+pub fn global_caches() -> &'static CacheRegistry {
+    &GLOBAL_CACHES
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// 按条目计数的测试缓存
+    struct VecCache(&'static str, Mutex<Vec<u8>>);
+
+    impl Cache for VecCache {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn memory_usage(&self) -> usize {
+            self.1.lock().unwrap().len()
+        }
+
+        fn trim(&self, target: usize) -> usize {
+            let mut items = self.1.lock().unwrap();
+            let before = items.len();
+            items.truncate(target);
+            before - items.len()
+        }
+    }
+
+    #[test]
+    fn test_trim_to_budget() {
+        let registry = CacheRegistry::new();
+        registry.register(Arc::new(VecCache("a", Mutex::new(vec![0; 300]))));
+        registry.register(Arc::new(VecCache("b", Mutex::new(vec![0; 100]))));
+        assert_eq!(registry.trim_to(400), 0);
+
+        // 预算按占用比例分配：a 保留 150，b 保留 50
+        assert_eq!(registry.trim_to(200), 200);
+        let usage = registry.usage();
+        assert_eq!(usage[0], CacheUsage { name: "a".to_string(), bytes: 150 });
+        assert_eq!(registry.total_usage(), 200);
+
+        // 同名缓存替换旧的登记
+        registry.register(Arc::new(VecCache("b", Mutex::new(Vec::new()))));
+        assert_eq!(registry.usage().len(), 2);
+        assert_eq!(registry.total_usage(), 150);
+    }
+}
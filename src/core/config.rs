@@ -1,9 +1,14 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use config::{Config, File, FileFormat};
 /// 管理启动器的所有配置项
 use serde::{Deserialize, Serialize};
 
+use crate::core::{
+    error::{Result, WerunError},
+    search::{ActionData, SearchResult},
+};
+
 /// 应用配置
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct AppConfig {
@@ -17,11 +22,35 @@ pub struct AppConfig {
     pub keybindings: KeybindingsConfig,
     /// 插件配置
     pub plugins: PluginsConfig,
+    /// 隐私配置
+    #[serde(default)]
+    pub privacy: PrivacyConfig,
+    /// 执行确认配置
+    #[serde(default)]
+    pub confirmation: ConfirmationConfig,
+    /// 免打扰时段配置
+    #[serde(default)]
+    pub quiet_hours: QuietHoursConfig,
+    /// 内存预算配置
+    #[serde(default)]
+    pub memory: MemoryConfig,
+    /// 缩写展开配置
+    #[serde(default)]
+    pub hotstrings: HotstringConfig,
+    /// 启动配置
+    #[serde(default)]
+    pub startup: StartupConfig,
+    /// 通知配置
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    /// 预填查询配置
+    #[serde(default)]
+    pub prefill: PrefillConfig,
 }
 
 impl AppConfig {
     /// 加载配置文件
-    pub fn load() -> anyhow::Result<Self> {
+    pub fn load() -> Result<Self> {
         let config_path = Self::config_path();
 
         let mut settings = Config::builder();
@@ -33,7 +62,10 @@ impl AppConfig {
             ));
         }
 
-        let config: AppConfig = settings.build()?.try_deserialize()?;
+        let config: AppConfig = settings
+            .build()
+            .and_then(|settings| settings.try_deserialize())
+            .map_err(|e| WerunError::ConfigLoad(e.into()))?;
 
         // 如果配置文件不存在，保存默认配置
         if !config_path.exists() {
@@ -44,17 +76,17 @@ impl AppConfig {
     }
 
     /// 保存配置文件
-    pub fn save(&self) -> anyhow::Result<()> {
+    pub fn save(&self) -> Result<()> {
         let config_path = Self::config_path();
 
-        let content = toml::to_string_pretty(self)?;
-        std::fs::write(&config_path, content)?;
+        let content = toml::to_string_pretty(self).map_err(|e| WerunError::ConfigSave(e.into()))?;
+        std::fs::write(&config_path, content).map_err(|e| WerunError::ConfigSave(e.into()))?;
 
         Ok(())
     }
 
     /// 获取配置文件路径
-    fn config_path() -> PathBuf {
+    pub fn config_path() -> PathBuf {
         PathBuf::from(".").join("config.toml")
     }
 }
@@ -76,6 +108,24 @@ pub struct WindowConfig {
     pub hide_on_blur: bool,
     /// 显示动画时长 (毫秒)
     pub animation_duration_ms: u64,
+    /// 预览面板显示方式
+    #[serde(default)]
+    pub preview: PreviewMode,
+    /// 窗口背景材质
+    #[serde(default)]
+    pub backdrop: Backdrop,
+    /// 界面密度
+    #[serde(default)]
+    pub density: Density,
+    /// 唤出窗口时所在的显示器
+    #[serde(default)]
+    pub monitor: MonitorTarget,
+    /// 在结果行上显示 Alt+1..9 快捷键提示
+    #[serde(default)]
+    pub show_index_hints: bool,
+    /// 结果布局
+    #[serde(default)]
+    pub layout: ResultLayoutConfig,
 }
 
 impl Default for WindowConfig {
@@ -88,25 +138,310 @@ impl Default for WindowConfig {
             always_on_top: true,
             hide_on_blur: true,
             animation_duration_ms: 150,
+            preview: PreviewMode::default(),
+            backdrop: Backdrop::default(),
+            density: Density::default(),
+            monitor: MonitorTarget::default(),
+            show_index_hints: false,
+            layout: ResultLayoutConfig::default(),
+        }
+    }
+}
+
+/// 预览面板显示方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreviewMode {
+    /// 选中结果带有预览内容时显示
+    #[default]
+    Auto,
+    /// 始终显示，没有预览内容的结果显示基本信息
+    Always,
+    /// 不显示
+    Never,
+}
+
+impl PreviewMode {
+    /// 循环切换到下一种显示方式
+    pub fn next(self) -> Self {
+        match self {
+            PreviewMode::Auto => PreviewMode::Always,
+            PreviewMode::Always => PreviewMode::Never,
+            PreviewMode::Never => PreviewMode::Auto,
+        }
+    }
+
+    /// 显示名称
+    pub fn display(self) -> &'static str {
+        match self {
+            PreviewMode::Auto => "自动",
+            PreviewMode::Always => "始终显示",
+            PreviewMode::Never => "不显示",
+        }
+    }
+}
+
+/// 唤出窗口时所在的显示器
+///
+/// 窗口在该显示器的工作区内居中，大小按该显示器的 DPI 缩放
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorTarget {
+    /// 鼠标所在的显示器
+    #[default]
+    Cursor,
+    /// 前台窗口所在的显示器
+    FocusedWindow,
+    /// 主显示器
+    Primary,
+}
+
+/// 界面密度
+///
+/// 决定结果行的高度、内边距、图标尺寸和列表同时显示的结果数
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    /// 紧凑
+    Compact,
+    /// 默认
+    #[default]
+    Default,
+    /// 宽松
+    Comfortable,
+}
+
+impl Density {
+    /// 所有密度
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Default, Density::Comfortable];
+
+    /// 配置中的名称
+    pub fn key(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Default => "default",
+            Density::Comfortable => "comfortable",
+        }
+    }
+
+    /// 显示名称
+    pub fn display(self) -> &'static str {
+        match self {
+            Density::Compact => "紧凑",
+            Density::Default => "默认",
+            Density::Comfortable => "宽松",
+        }
+    }
+
+    /// 结果行高度（像素）
+    pub fn row_height(self) -> f32 {
+        match self {
+            Density::Compact => 44.0,
+            Density::Default => 52.0,
+            Density::Comfortable => 64.0,
+        }
+    }
+
+    /// 结果行的水平内边距（像素）
+    pub fn row_padding(self) -> f32 {
+        match self {
+            Density::Compact => 8.0,
+            Density::Default => 12.0,
+            Density::Comfortable => 16.0,
+        }
+    }
+
+    /// 结果图标底板的边长（像素）
+    pub fn icon_size(self) -> f32 {
+        match self {
+            Density::Compact => 24.0,
+            Density::Default => 32.0,
+            Density::Comfortable => 40.0,
+        }
+    }
+
+    /// 列表同时显示的结果数，超出时滚动
+    pub fn visible_results(self) -> usize {
+        match self {
+            Density::Compact => 9,
+            Density::Default => 7,
+            Density::Comfortable => 6,
+        }
+    }
+}
+
+/// 窗口背景材质
+///
+/// 系统不支持所选材质时保持透明背景
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Backdrop {
+    /// 不使用背景材质
+    #[default]
+    None,
+    /// 云母（Windows 11）
+    Mica,
+    /// 亚克力模糊（Windows 11，旧版本 Windows 10 使用模糊背景代替）
+    Acrylic,
+}
+
+/// 结果布局
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultLayout {
+    /// 列表，每行显示图标、标题和描述
+    #[default]
+    List,
+    /// 网格，以大图标为主，适合颜色、应用等图标比描述更重要的结果
+    Grid,
+}
+
+/// 结果布局配置
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResultLayoutConfig {
+    /// 默认布局
+    #[serde(default)]
+    pub default: ResultLayout,
+    /// 按插件 ID 覆盖
+    #[serde(default)]
+    pub plugins: HashMap<String, ResultLayout>,
+}
+
+impl Default for ResultLayoutConfig {
+    fn default() -> Self {
+        Self {
+            default: ResultLayout::List,
+            plugins: HashMap::from([("color_picker".to_string(), ResultLayout::Grid)]),
         }
     }
 }
 
+impl ResultLayoutConfig {
+    /// 结果使用的布局
+    ///
+    /// 已激活插件时按该插件的覆盖；否则结果全部来自同一个插件时（如通过关键字查询）按该插件的覆盖，
+    /// 都没有覆盖时使用默认布局
+    pub fn layout<'a>(
+        &self,
+        active_plugin: Option<&'a str>,
+        mut plugin_ids: impl Iterator<Item = &'a str>,
+    ) -> ResultLayout {
+        let plugin = active_plugin.or_else(|| {
+            let first = plugin_ids.next()?;
+            plugin_ids.all(|id| id == first).then_some(first)
+        });
+        plugin.and_then(|id| self.plugins.get(id)).copied().unwrap_or(self.default)
+    }
+}
+
 /// 主题配置
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ThemeConfig {
     /// 当前主题名称
     pub current_theme: String,
-    /// 是否跟随系统主题
+    /// 是否跟随系统主题（包括系统的高对比度模式）
     pub follow_system: bool,
+    /// 切换到浅色时使用的主题名称
+    #[serde(default = "default_light_theme")]
+    pub light_theme: String,
+    /// 切换到深色时使用的主题名称
+    #[serde(default = "default_dark_theme")]
+    pub dark_theme: String,
+    /// 始终使用高对比度主题
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// 按结果类型覆盖强调色（键为结果类型，如 `file`、`destructive`，值为 `#RRGGBB`）
+    ///
+    /// 未覆盖的类型使用当前主题的基础色
+    #[serde(default)]
+    pub result_colors: HashMap<String, String>,
+    /// 结果类型徽标样式
+    #[serde(default)]
+    pub badge_style: BadgeStyle,
+    /// 按时间切换浅色/深色，启用时优先于跟随系统
+    #[serde(default)]
+    pub schedule: ThemeSchedule,
 }
 
 impl Default for ThemeConfig {
     fn default() -> Self {
-        Self { current_theme: "dark".to_string(), follow_system: true }
+        Self {
+            current_theme: "dark".to_string(),
+            follow_system: true,
+            light_theme: default_light_theme(),
+            dark_theme: default_dark_theme(),
+            high_contrast: false,
+            result_colors: HashMap::new(),
+            badge_style: BadgeStyle::default(),
+            schedule: ThemeSchedule::default(),
+        }
+    }
+}
+
+/// 默认的浅色主题
+fn default_light_theme() -> String {
+    "Default Light".to_string()
+}
+
+/// 默认的深色主题
+fn default_dark_theme() -> String {
+    "Default Dark".to_string()
+}
+
+/// 主题定时切换方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleMode {
+    /// 不定时切换
+    #[default]
+    Off,
+    /// 按指定时间切换
+    Times,
+    /// 按所在经纬度的日出日落切换
+    Sun,
+}
+
+/// 主题定时切换配置
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSchedule {
+    /// 切换方式
+    pub mode: ScheduleMode,
+    /// 切换到浅色的时间 (HH:MM)
+    pub light_start: String,
+    /// 切换到深色的时间 (HH:MM)
+    pub dark_start: String,
+    /// 纬度（北纬为正），用于计算日出日落
+    pub latitude: f64,
+    /// 经度（东经为正），用于计算日出日落
+    pub longitude: f64,
+}
+
+impl Default for ThemeSchedule {
+    fn default() -> Self {
+        Self {
+            mode: ScheduleMode::Off,
+            light_start: "07:00".to_string(),
+            dark_start: "19:00".to_string(),
+            latitude: 39.9,
+            longitude: 116.4,
+        }
     }
 }
 
+/// 结果类型徽标样式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BadgeStyle {
+    /// 浅色底 + 强调色文字
+    #[default]
+    Subtle,
+    /// 强调色底 + 背景色文字
+    Solid,
+    /// 强调色边框
+    Outline,
+}
+
 /// 搜索配置
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchConfig {
@@ -118,8 +453,29 @@ pub struct SearchConfig {
     pub enable_file_search: bool,
     /// 文件搜索路径
     pub file_search_paths: Vec<String>,
-    /// 忽略的文件模式
+    /// 忽略的文件模式 (glob，匹配文件名、目录名或相对搜索路径的路径)
     pub file_ignore_patterns: Vec<String>,
+    /// 文件搜索最大递归深度
+    #[serde(default = "default_file_search_depth")]
+    pub file_search_max_depth: usize,
+    /// 按搜索路径覆盖的递归深度
+    #[serde(default)]
+    pub file_search_depth_overrides: HashMap<String, usize>,
+    /// 是否索引隐藏文件
+    #[serde(default)]
+    pub include_hidden_files: bool,
+    /// 模糊匹配的单词边界加分
+    #[serde(default)]
+    pub boundary_bonus: BoundaryBonus,
+    /// 查询为空时固定显示的应用（应用路径，按显示顺序）
+    #[serde(default)]
+    pub pinned_apps: Vec<String>,
+    /// 查询为空时在固定应用之后显示的最常用应用数量
+    #[serde(default = "default_home_recent_apps")]
+    pub home_recent_apps: usize,
+    /// 没有结果时提供兜底结果的插件（按显示顺序，未列出的插件不提供）
+    #[serde(default = "default_fallbacks")]
+    pub fallbacks: Vec<String>,
 }
 
 impl Default for SearchConfig {
@@ -137,11 +493,59 @@ impl Default for SearchConfig {
                 "*.log".to_string(),
                 "node_modules".to_string(),
                 ".git".to_string(),
+                "target".to_string(),
+                "dist".to_string(),
+                "build".to_string(),
+                ".idea".to_string(),
+                ".vscode".to_string(),
             ],
+            file_search_max_depth: default_file_search_depth(),
+            file_search_depth_overrides: HashMap::new(),
+            include_hidden_files: false,
+            boundary_bonus: BoundaryBonus::default(),
+            pinned_apps: Vec::new(),
+            home_recent_apps: default_home_recent_apps(),
+            fallbacks: default_fallbacks(),
         }
     }
 }
 
+/// 文件搜索默认递归深度
+fn default_file_search_depth() -> usize {
+    3
+}
+
+/// 查询为空时默认显示的最常用应用数量
+fn default_home_recent_apps() -> usize {
+    8
+}
+
+/// 默认提供兜底结果的插件
+fn default_fallbacks() -> Vec<String> {
+    ["web_search", "file_search", "task_manager"].map(String::from).to_vec()
+}
+
+/// 单词边界加分权重
+///
+/// 查询字符命中单词开头时获得额外分数，用于调优模糊匹配排序
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct BoundaryBonus {
+    /// 命中字符串开头
+    pub start: u32,
+    /// 命中分隔符 (空格、连字符、下划线等) 之后的字符
+    pub separator: u32,
+    /// 命中大小写切换处 (camelCase)
+    pub case_change: u32,
+    /// 与上一个命中字符连续
+    pub consecutive: u32,
+}
+
+impl Default for BoundaryBonus {
+    fn default() -> Self {
+        Self { start: 50, separator: 30, case_change: 30, consecutive: 10 }
+    }
+}
+
 /// 快捷键配置
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct KeybindingsConfig {
@@ -155,6 +559,15 @@ pub struct KeybindingsConfig {
     pub confirm: String,
     /// 关闭窗口
     pub close: String,
+    /// 全局快捷键透传，把原按键交还给前台窗口（如 Alt+Space 打开窗口的系统菜单）
+    #[serde(default)]
+    pub hotkey_passthrough: HotkeyPassthrough,
+    /// 双击快捷键的判定间隔 (毫秒)
+    #[serde(default = "default_double_press_ms")]
+    pub double_press_ms: u64,
+    /// 以选中文本或剪贴板内容预填搜索框并显示启动器的全局快捷键
+    #[serde(default = "default_prefill_launcher")]
+    pub prefill_launcher: String,
 }
 
 impl Default for KeybindingsConfig {
@@ -165,28 +578,416 @@ impl Default for KeybindingsConfig {
             navigate_down: "ArrowDown".to_string(),
             confirm: "Enter".to_string(),
             close: "Escape".to_string(),
+            hotkey_passthrough: HotkeyPassthrough::default(),
+            double_press_ms: default_double_press_ms(),
+            prefill_launcher: default_prefill_launcher(),
         }
     }
 }
 
+/// 双击快捷键默认判定间隔
+fn default_double_press_ms() -> u64 {
+    400
+}
+
+/// 默认的预填快捷键
+fn default_prefill_launcher() -> String {
+    "Alt+Shift+Space".to_string()
+}
+
+/// 全局快捷键透传模式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyPassthrough {
+    /// 始终由启动器处理
+    #[default]
+    Off,
+    /// 启动器已显示且前台是其他窗口时透传
+    WhenVisible,
+    /// 间隔内连按两次时，第二次关闭启动器并透传
+    DoublePress,
+}
+
 /// 插件配置
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PluginsConfig {
-    /// 启用的插件列表
-    pub enabled: Vec<String>,
+    /// 在插件页关闭的插件，不参与搜索
+    #[serde(default)]
+    pub disabled: Vec<String>,
+    /// 插件模式的别名，输入 `/<别名>` 与输入 `/<插件 ID>` 相同
+    #[serde(default)]
+    pub keywords: HashMap<String, Vec<String>>,
+    /// 插件优先级，每级为插件的搜索结果加减 [`PRIORITY_STEP`] 分
+    #[serde(default)]
+    pub priorities: HashMap<String, i32>,
     /// 插件特定配置
     pub settings: serde_json::Value,
 }
 
+/// 插件优先级每级调整的分数
+pub const PRIORITY_STEP: i32 = 100;
+
+/// 插件优先级的取值范围
+pub const PRIORITY_RANGE: std::ops::RangeInclusive<i32> = -5..=5;
+
 impl Default for PluginsConfig {
     fn default() -> Self {
         Self {
-            enabled: vec![
-                "app_launcher".to_string(),
-                "calculator".to_string(),
-                "clipboard".to_string(),
-            ],
+            disabled: Vec::new(),
+            keywords: HashMap::new(),
+            priorities: HashMap::new(),
             settings: serde_json::json!({}),
         }
     }
 }
+
+/// 隐私配置
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrivacyConfig {
+    /// 不记录使用历史和查询日志的插件
+    pub history_excluded_plugins: Vec<String>,
+}
+
+impl Default for PrivacyConfig {
+    fn default() -> Self {
+        // 剪贴板内容、生成的密码和密码库条目可能包含敏感信息，默认不记录
+        Self {
+            history_excluded_plugins: ["clipboard", "pwgen", "vault"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// 执行确认策略
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmPolicy {
+    /// 直接执行
+    Never,
+    /// 仅破坏性操作需要确认
+    #[default]
+    Destructive,
+    /// 总是需要确认
+    Always,
+}
+
+impl ConfirmPolicy {
+    /// 该策略下执行结果前是否需要确认
+    pub fn requires(self, destructive: bool) -> bool {
+        match self {
+            ConfirmPolicy::Never => false,
+            ConfirmPolicy::Destructive => destructive,
+            ConfirmPolicy::Always => true,
+        }
+    }
+}
+
+/// 执行确认配置
+///
+/// 由启动器在执行结果前统一检查，插件无需各自实现确认；
+/// 插件和动作类型的覆盖优先于默认策略，两者同时命中时取更严格的一个
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConfirmationConfig {
+    /// 默认策略
+    #[serde(default)]
+    pub default: ConfirmPolicy,
+    /// 按插件 ID 覆盖
+    #[serde(default)]
+    pub plugins: HashMap<String, ConfirmPolicy>,
+    /// 按动作类型覆盖（如 `execute_command`、`launch_app`）
+    #[serde(default)]
+    pub actions: HashMap<String, ConfirmPolicy>,
+}
+
+impl ConfirmationConfig {
+    /// 插件和动作类型对应的策略
+    pub fn policy(&self, plugin_id: &str, action_kind: &str) -> ConfirmPolicy {
+        let plugin = self.plugins.get(plugin_id);
+        let action = self.actions.get(action_kind);
+        plugin.into_iter().chain(action).max().copied().unwrap_or(self.default)
+    }
+
+    /// 执行结果前是否需要确认，批量结果中任一项需要确认时整体确认
+    pub fn requires_confirmation(&self, result: &SearchResult) -> bool {
+        match &result.action {
+            ActionData::Batch { results } => {
+                results.iter().any(|item| self.requires_confirmation(item))
+            },
+            action => self.policy(result.plugin_id(), action.kind()).requires(result.destructive),
+        }
+    }
+}
+
+/// 免打扰时段配置
+///
+/// 时段内推迟后台刷新、不显示非关键通知；结束时间早于开始时间表示跨越午夜
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuietHoursConfig {
+    /// 是否启用
+    pub enabled: bool,
+    /// 开始时间 (HH:MM)
+    pub start: String,
+    /// 结束时间 (HH:MM)
+    pub end: String,
+}
+
+impl Default for QuietHoursConfig {
+    fn default() -> Self {
+        Self { enabled: false, start: "22:00".to_string(), end: "07:00".to_string() }
+    }
+}
+
+impl QuietHoursConfig {
+    /// 指定时间是否处于免打扰时段，时间格式无效时视为未启用
+    pub fn contains(&self, time: chrono::NaiveTime) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        let parse = |value: &str| chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M");
+        let (Ok(start), Ok(end)) = (parse(&self.start), parse(&self.end)) else {
+            log::warn!("免打扰时段格式无效: {} - {}", self.start, self.end);
+            return false;
+        };
+
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+}
+
+/// 内存预算配置
+///
+/// 已登记缓存的总占用超过预算时，定期按占用比例裁剪各缓存
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryConfig {
+    /// 缓存内存预算 (MB)
+    pub budget_mb: usize,
+    /// 裁剪检查间隔（秒）
+    pub trim_interval_secs: u64,
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        Self { budget_mb: 128, trim_interval_secs: 300 }
+    }
+}
+
+impl MemoryConfig {
+    /// 内存预算（字节）
+    pub fn budget_bytes(&self) -> usize {
+        self.budget_mb * 1024 * 1024
+    }
+}
+
+/// 缩写展开配置
+///
+/// 启用后在任意程序中输入缩写时替换为展开内容，展开内容支持 `{date}`、`{time}`、
+/// `{datetime}` 占位符
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HotstringConfig {
+    /// 是否启用
+    pub enabled: bool,
+    /// 不展开缩写的进程（可执行文件名，如 `keepassxc.exe`）
+    pub excluded_processes: Vec<String>,
+    /// 缩写列表
+    pub abbreviations: Vec<Abbreviation>,
+}
+
+impl Default for HotstringConfig {
+    fn default() -> Self {
+        let abbreviation = |trigger: &str, text: &str| Abbreviation {
+            trigger: trigger.to_string(),
+            text: text.to_string(),
+        };
+        Self {
+            enabled: false,
+            excluded_processes: vec!["keepass.exe".to_string(), "keepassxc.exe".to_string()],
+            abbreviations: vec![
+                abbreviation(";date", "{date}"),
+                abbreviation(";time", "{time}"),
+                abbreviation(";now", "{datetime}"),
+            ],
+        }
+    }
+}
+
+impl HotstringConfig {
+    /// 是否排除该进程（不区分大小写）
+    pub fn is_excluded(&self, process: &str) -> bool {
+        self.excluded_processes.iter().any(|name| name.eq_ignore_ascii_case(process))
+    }
+}
+
+/// 缩写
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Abbreviation {
+    /// 输入的缩写
+    pub trigger: String,
+    /// 展开内容
+    pub text: String,
+}
+
+/// 启动配置
+///
+/// 登录时启动的实例不显示窗口，以后台优先级建立索引、预读图标，完成后才注册全局快捷键
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StartupConfig {
+    /// 是否在登录时于后台启动，未设置时不改动已有的启动项
+    pub launch_at_login: Option<bool>,
+    /// 预热的最长等待时间（秒），超时后直接注册快捷键
+    pub warm_up_timeout_secs: u64,
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        Self { launch_at_login: None, warm_up_timeout_secs: 300 }
+    }
+}
+
+/// 通知配置
+///
+/// 重建索引完成、捕获剪贴板内容等后台事件的通知；窗口隐藏时显示为系统通知
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// 是否显示通知
+    pub enabled: bool,
+    /// 不显示通知的事件（`index_rebuilt`、`clipboard_captured`）
+    pub muted: Vec<String>,
+}
+
+impl NotificationConfig {
+    /// 是否显示指定事件的通知
+    pub fn allows(&self, kind: &str) -> bool {
+        self.enabled && !self.muted.iter().any(|muted| muted == kind)
+    }
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        // 复制很频繁，默认不为捕获剪贴板内容发出通知
+        Self { enabled: true, muted: vec!["clipboard_captured".to_string()] }
+    }
+}
+
+/// 预填内容的来源
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrefillSource {
+    /// 前台程序中选中的文本
+    Selection,
+    /// 剪贴板中的文本
+    Clipboard,
+    /// 优先使用选中的文本，没有时使用剪贴板
+    #[default]
+    SelectionOrClipboard,
+}
+
+/// 预填查询配置
+///
+/// 按预填快捷键唤出启动器时，用前台程序中选中的文本或剪贴板内容预填搜索框
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrefillConfig {
+    /// 预填内容的来源
+    pub source: PrefillSource,
+    /// 读取不到选中文本时模拟 Ctrl+C 复制，读取后恢复原剪贴板内容
+    pub simulate_copy: bool,
+    /// 填入内容前加的前缀，如 `g ` 直接进入网页搜索
+    pub prefix: String,
+    /// 普通唤出快捷键显示启动器时也预填
+    pub on_toggle: bool,
+}
+
+impl Default for PrefillConfig {
+    fn default() -> Self {
+        Self {
+            source: PrefillSource::default(),
+            simulate_copy: true,
+            prefix: String::new(),
+            on_toggle: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::search::ResultType;
+
+    #[test]
+    fn test_confirmation_policy() {
+        let command = |id: &str, destructive: bool| {
+            SearchResult::new(
+                id.to_string(),
+                id.to_string(),
+                String::new(),
+                ResultType::Command,
+                90,
+                ActionData::ExecuteCommand { command: id.to_string() },
+            )
+            .with_destructive(destructive)
+        };
+
+        let mut config = ConfirmationConfig::default();
+        assert!(config.requires_confirmation(&command("system_commands:shutdown", true)));
+        assert!(!config.requires_confirmation(&command("system_commands:lock", false)));
+
+        // 插件覆盖放宽默认策略，动作类型覆盖同时命中时取更严格的
+        config.plugins.insert("system_commands".to_string(), ConfirmPolicy::Never);
+        assert!(!config.requires_confirmation(&command("system_commands:shutdown", true)));
+        config.actions.insert("execute_command".to_string(), ConfirmPolicy::Always);
+        assert!(config.requires_confirmation(&command("system_commands:lock", false)));
+
+        let batch = SearchResult::batch(vec![command("custom_commands:git", false)]);
+        assert!(config.requires_confirmation(&batch));
+    }
+
+    #[test]
+    fn test_result_layout() {
+        let config = ResultLayoutConfig::default();
+        assert_eq!(config.layout(Some("color_picker"), std::iter::empty()), ResultLayout::Grid);
+        assert_eq!(
+            config.layout(None, ["color_picker", "color_picker"].into_iter()),
+            ResultLayout::Grid
+        );
+        assert_eq!(
+            config.layout(None, ["color_picker", "calculator"].into_iter()),
+            ResultLayout::List
+        );
+        assert_eq!(
+            config.layout(Some("calculator"), ["color_picker"].into_iter()),
+            ResultLayout::List
+        );
+        assert_eq!(config.layout(None, std::iter::empty()), ResultLayout::List);
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let at = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let mut quiet = QuietHoursConfig { enabled: true, ..Default::default() };
+
+        // 跨越午夜
+        assert!(quiet.contains(at(23, 30)));
+        assert!(quiet.contains(at(6, 59)));
+        assert!(!quiet.contains(at(7, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+
+        quiet.start = "9:00".to_string();
+        quiet.end = "17:30".to_string();
+        assert!(quiet.contains(at(9, 0)));
+        assert!(!quiet.contains(at(17, 30)));
+
+        quiet.end = "later".to_string();
+        assert!(!quiet.contains(at(10, 0)));
+    }
+}
@@ -1,9 +1,18 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 /// 配置管理器
 ///
 /// 管理应用配置的加载、保存和实时更新
-use crate::core::config::AppConfig;
+use crate::core::{
+    config::{
+        AppConfig, Density, HotstringConfig, MemoryConfig, NotificationConfig, PrefillConfig,
+        QuietHoursConfig, StartupConfig, ThemeConfig, PRIORITY_RANGE,
+    },
+    error::Result,
+};
 
 /// 全局配置管理器
 pub struct ConfigManager {
@@ -19,7 +28,7 @@ impl ConfigManager {
                 cfg
             },
             Err(e) => {
-                log::warn!("加载配置失败: {:?}，使用默认配置", e);
+                log::warn!("[{}] {:?}，使用默认配置", e.code(), e);
                 AppConfig::default()
             },
         };
@@ -27,13 +36,21 @@ impl ConfigManager {
         Self { config: Arc::new(Mutex::new(config)) }
     }
 
+    /// 重新读取配置文件，读取失败时保留当前配置
+    pub fn reload(&self) -> Result<()> {
+        let config = AppConfig::load()?;
+        *self.config.lock().unwrap() = config;
+        log::info!("配置已重新加载");
+        Ok(())
+    }
+
     /// 获取配置
     pub fn get_config(&self) -> AppConfig {
         self.config.lock().unwrap().clone()
     }
 
     /// 更新配置
-    pub fn update_config<F>(&self, f: F) -> anyhow::Result<()>
+    pub fn update_config<F>(&self, f: F) -> Result<()>
     where
         F: FnOnce(&mut AppConfig),
     {
@@ -60,35 +77,139 @@ impl ConfigManager {
     }
 
     /// 设置当前主题
-    pub fn set_theme(&self, theme: &str) -> anyhow::Result<()> {
+    pub fn set_theme(&self, theme: &str) -> Result<()> {
         self.update_config(|config| {
             config.theme.current_theme = theme.to_string();
         })
     }
 
+    /// 获取界面密度
+    pub fn density(&self) -> Density {
+        self.config.lock().unwrap().window.density
+    }
+
+    /// 设置界面密度，下次绘制时生效
+    pub fn set_density(&self, density: Density) -> Result<()> {
+        self.update_config(|config| {
+            config.window.density = density;
+        })
+    }
+
+    /// 是否在结果行上显示数字快捷键提示
+    pub fn show_index_hints(&self) -> bool {
+        self.config.lock().unwrap().window.show_index_hints
+    }
+
+    /// 获取主题配置
+    pub fn theme_config(&self) -> ThemeConfig {
+        self.config.lock().unwrap().theme.clone()
+    }
+
     /// 获取最大结果数
     pub fn max_results(&self) -> usize {
         self.config.lock().unwrap().search.max_results
     }
 
-    /// 检查插件是否启用
+    /// 检查插件是否启用（未在插件页关闭）
     pub fn is_plugin_enabled(&self, plugin_id: &str) -> bool {
-        self.config.lock().unwrap().plugins.enabled.contains(&plugin_id.to_string())
+        !self.config.lock().unwrap().plugins.disabled.iter().any(|id| id == plugin_id)
+    }
+
+    /// 设置插件是否启用
+    pub fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> Result<()> {
+        self.update_config(|config| {
+            let disabled = &mut config.plugins.disabled;
+            disabled.retain(|id| id != plugin_id);
+            if !enabled {
+                disabled.push(plugin_id.to_string());
+            }
+        })
+    }
+
+    /// 插件模式的别名
+    pub fn plugin_keywords(&self) -> HashMap<String, Vec<String>> {
+        self.config.lock().unwrap().plugins.keywords.clone()
     }
 
-    /// 启用插件
-    pub fn enable_plugin(&self, plugin_id: &str) -> anyhow::Result<()> {
+    /// 设置插件模式的别名，别名为空时删除配置项
+    pub fn set_plugin_keywords(&self, plugin_id: &str, keywords: Vec<String>) -> Result<()> {
         self.update_config(|config| {
-            if !config.plugins.enabled.contains(&plugin_id.to_string()) {
-                config.plugins.enabled.push(plugin_id.to_string());
+            if keywords.is_empty() {
+                config.plugins.keywords.remove(plugin_id);
+            } else {
+                config.plugins.keywords.insert(plugin_id.to_string(), keywords);
             }
         })
     }
 
-    /// 禁用插件
-    pub fn disable_plugin(&self, plugin_id: &str) -> anyhow::Result<()> {
+    /// 插件优先级
+    pub fn plugin_priorities(&self) -> HashMap<String, i32> {
+        self.config.lock().unwrap().plugins.priorities.clone()
+    }
+
+    /// 设置插件优先级，超出范围时取边界值，为 0 时删除配置项
+    pub fn set_plugin_priority(&self, plugin_id: &str, priority: i32) -> Result<()> {
+        let priority = priority.clamp(*PRIORITY_RANGE.start(), *PRIORITY_RANGE.end());
         self.update_config(|config| {
-            config.plugins.enabled.retain(|id| id != plugin_id);
+            if priority == 0 {
+                config.plugins.priorities.remove(plugin_id);
+            } else {
+                config.plugins.priorities.insert(plugin_id.to_string(), priority);
+            }
+        })
+    }
+
+    /// 设置插件特定配置的一项（`[plugins.settings.<plugin_id>]` 中的 `key`）
+    pub fn set_plugin_setting(
+        &self,
+        plugin_id: &str,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<()> {
+        self.update_config(|config| {
+            // 配置文件中写错类型的表替换为空表，避免按键索引时 panic
+            if !config.plugins.settings.is_object() {
+                config.plugins.settings = serde_json::json!({});
+            }
+            let settings = &mut config.plugins.settings[plugin_id];
+            if !settings.is_object() {
+                *settings = serde_json::json!({});
+            }
+            settings[key] = value;
+        })
+    }
+
+    /// 获取插件特定配置（`[plugins.settings.<plugin_id>]`），未配置时为 Null
+    pub fn plugin_settings(&self, plugin_id: &str) -> serde_json::Value {
+        self.config
+            .lock()
+            .unwrap()
+            .plugins
+            .settings
+            .get(plugin_id)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null)
+    }
+
+    /// 检查插件是否被排除在历史记录之外
+    pub fn is_history_excluded(&self, plugin_id: &str) -> bool {
+        self.config
+            .lock()
+            .unwrap()
+            .privacy
+            .history_excluded_plugins
+            .iter()
+            .any(|id| id == plugin_id)
+    }
+
+    /// 设置插件是否被排除在历史记录之外
+    pub fn set_history_excluded(&self, plugin_id: &str, excluded: bool) -> Result<()> {
+        self.update_config(|config| {
+            let plugins = &mut config.privacy.history_excluded_plugins;
+            plugins.retain(|id| id != plugin_id);
+            if excluded {
+                plugins.push(plugin_id.to_string());
+            }
         })
     }
 
@@ -98,7 +219,7 @@ impl ConfigManager {
     }
 
     /// 添加文件搜索路径
-    pub fn add_file_search_path(&self, path: &str) -> anyhow::Result<()> {
+    pub fn add_file_search_path(&self, path: &str) -> Result<()> {
         self.update_config(|config| {
             if !config.search.file_search_paths.contains(&path.to_string()) {
                 config.search.file_search_paths.push(path.to_string());
@@ -107,14 +228,67 @@ impl ConfigManager {
     }
 
     /// 移除文件搜索路径
-    pub fn remove_file_search_path(&self, path: &str) -> anyhow::Result<()> {
+    pub fn remove_file_search_path(&self, path: &str) -> Result<()> {
         self.update_config(|config| {
             config.search.file_search_paths.retain(|p| p != path);
         })
     }
 
+    /// 查询为空时固定显示的应用路径
+    pub fn pinned_apps(&self) -> Vec<String> {
+        self.config.lock().unwrap().search.pinned_apps.clone()
+    }
+
+    /// 查询为空时显示的最常用应用数量
+    pub fn home_recent_apps(&self) -> usize {
+        self.config.lock().unwrap().search.home_recent_apps
+    }
+
+    /// 固定或取消固定应用，新固定的应用排在最后
+    pub fn set_app_pinned(&self, path: &str, pinned: bool) -> Result<()> {
+        self.update_config(|config| {
+            let apps = &mut config.search.pinned_apps;
+            let exists = apps.iter().any(|p| p == path);
+            if pinned && !exists {
+                apps.push(path.to_string());
+            } else if !pinned {
+                apps.retain(|p| p != path);
+            }
+        })
+    }
+
+    /// 获取免打扰时段配置
+    pub fn quiet_hours(&self) -> QuietHoursConfig {
+        self.config.lock().unwrap().quiet_hours.clone()
+    }
+
+    /// 获取内存预算配置
+    pub fn memory(&self) -> MemoryConfig {
+        self.config.lock().unwrap().memory.clone()
+    }
+
+    /// 获取缩写展开配置
+    pub fn hotstrings(&self) -> HotstringConfig {
+        self.config.lock().unwrap().hotstrings.clone()
+    }
+
+    /// 获取启动配置
+    pub fn startup(&self) -> StartupConfig {
+        self.config.lock().unwrap().startup.clone()
+    }
+
+    /// 获取通知配置
+    pub fn notifications(&self) -> NotificationConfig {
+        self.config.lock().unwrap().notifications.clone()
+    }
+
+    /// 获取预填查询配置
+    pub fn prefill(&self) -> PrefillConfig {
+        self.config.lock().unwrap().prefill.clone()
+    }
+
     /// 保存当前配置
-    pub fn save(&self) -> anyhow::Result<()> {
+    pub fn save(&self) -> Result<()> {
         let config = self.config.lock().unwrap();
         config.save()
     }
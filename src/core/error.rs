@@ -0,0 +1,262 @@
+/// 错误类型模块
+///
+/// 定义面向用户的错误类型和错误码，
+/// UI 根据错误码展示本地化、可操作的提示，而不是原始的调试信息
+use thiserror::Error;
+
+/// 错误类别
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// 配置
+    Config,
+    /// 插件
+    Plugin,
+    /// 平台 (Windows API)
+    Platform,
+    /// 网络
+    Network,
+}
+
+impl ErrorCategory {
+    /// 获取显示名称
+    pub fn display(&self) -> &'static str {
+        match self {
+            ErrorCategory::Config => "配置",
+            ErrorCategory::Plugin => "插件",
+            ErrorCategory::Platform => "系统",
+            ErrorCategory::Network => "网络",
+        }
+    }
+}
+
+/// WeRun 错误
+///
+/// `Display` 输出技术细节，供日志使用；`user_message` 输出面向用户的提示
+#[derive(Debug, Error)]
+pub enum WerunError {
+    /// 配置文件读取或解析失败
+    #[error("加载配置失败: {0}")]
+    ConfigLoad(#[source] anyhow::Error),
+
+    /// 配置文件写入失败
+    #[error("保存配置失败: {0}")]
+    ConfigSave(#[source] anyhow::Error),
+
+    /// 找不到处理结果的插件
+    #[error("未找到对应的插件: {0}")]
+    PluginNotFound(String),
+
+    /// 插件初始化失败
+    #[error("初始化插件 {plugin} 失败: {source}")]
+    PluginInit {
+        /// 插件 ID
+        plugin: String,
+        /// 原始错误
+        #[source]
+        source: anyhow::Error,
+    },
+
+    /// 插件执行结果失败
+    #[error("插件 {plugin} 执行失败: {source}")]
+    PluginExecute {
+        /// 插件 ID
+        plugin: String,
+        /// 原始错误
+        #[source]
+        source: anyhow::Error,
+    },
+
+    /// 快捷键管理器创建失败
+    #[error("创建快捷键管理器失败: {0}")]
+    HotkeySetup(#[source] anyhow::Error),
+
+    /// 全局快捷键已被其他程序注册
+    #[error("全局快捷键 {0} 已被占用")]
+    HotkeyInUse(String),
+
+    /// 剪贴板读写失败
+    #[error("剪贴板操作失败: {0}")]
+    Clipboard(String),
+
+    /// 以管理员身份运行失败
+    #[error("以管理员身份运行失败: {0}")]
+    Elevation(String),
+
+    /// 粘贴到之前的前台窗口失败
+    #[error("粘贴到目标窗口失败: {0}")]
+    Paste(String),
+
+    /// 任务栏跳转列表或其启动命令转发不可用
+    #[error("跳转列表不可用: {0}")]
+    JumpList(#[source] anyhow::Error),
+
+    /// 缩写展开的键盘钩子安装失败
+    #[error("启用缩写展开失败: {0}")]
+    Hotstring(String),
+
+    /// 登录时启动项写入注册表失败
+    #[error("设置登录时启动失败: {0}")]
+    Autostart(String),
+
+    /// 音频设备控制失败
+    #[error("控制音频设备失败: {0}")]
+    Audio(String),
+
+    /// 媒体播放控制失败
+    #[error("控制媒体播放失败: {0}")]
+    Media(String),
+
+    /// 显示或电源设置失败
+    #[error("更改显示或电源设置失败: {0}")]
+    DisplayPower(String),
+
+    /// 屏幕取色失败
+    #[error("屏幕取色失败: {0}")]
+    ScreenColor(String),
+
+    /// 清空回收站失败
+    #[error("清空回收站失败: {0}")]
+    RecycleBin(String),
+
+    /// 启动或停止服务失败
+    #[error("控制服务失败: {0}")]
+    Service(String),
+
+    /// 托盘图标添加失败
+    #[error("添加托盘图标失败: {0}")]
+    TrayIcon(String),
+
+    /// 系统通知显示失败
+    #[error("显示系统通知失败: {0}")]
+    Toast(String),
+
+    /// 通过 Shell 打开目标失败
+    #[error("打开失败: {0}")]
+    ShellOpen(String),
+
+    /// 网络请求失败
+    #[error("网络请求失败: {0}")]
+    Network(#[source] anyhow::Error),
+}
+
+impl WerunError {
+    /// 获取错误类别
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            WerunError::ConfigLoad(_) | WerunError::ConfigSave(_) => ErrorCategory::Config,
+            WerunError::PluginNotFound(_)
+            | WerunError::PluginInit { .. }
+            | WerunError::PluginExecute { .. } => ErrorCategory::Plugin,
+            WerunError::HotkeySetup(_)
+            | WerunError::HotkeyInUse(_)
+            | WerunError::Clipboard(_)
+            | WerunError::Elevation(_)
+            | WerunError::Paste(_)
+            | WerunError::JumpList(_)
+            | WerunError::Hotstring(_)
+            | WerunError::Autostart(_)
+            | WerunError::Audio(_)
+            | WerunError::Media(_)
+            | WerunError::DisplayPower(_)
+            | WerunError::ScreenColor(_)
+            | WerunError::RecycleBin(_)
+            | WerunError::Service(_)
+            | WerunError::TrayIcon(_)
+            | WerunError::Toast(_)
+            | WerunError::ShellOpen(_) => ErrorCategory::Platform,
+            WerunError::Network(_) => ErrorCategory::Network,
+        }
+    }
+
+    /// 获取错误码
+    ///
+    /// 按类别分段：E1xxx 平台，E2xxx 配置，E3xxx 插件，E4xxx 网络
+    pub fn code(&self) -> &'static str {
+        match self {
+            WerunError::HotkeySetup(_) => "E1001",
+            WerunError::HotkeyInUse(_) => "E1002",
+            WerunError::Clipboard(_) => "E1003",
+            WerunError::Elevation(_) => "E1004",
+            WerunError::Paste(_) => "E1005",
+            WerunError::JumpList(_) => "E1006",
+            WerunError::Hotstring(_) => "E1007",
+            WerunError::Autostart(_) => "E1008",
+            WerunError::Audio(_) => "E1009",
+            WerunError::Media(_) => "E1010",
+            WerunError::DisplayPower(_) => "E1011",
+            WerunError::ScreenColor(_) => "E1012",
+            WerunError::RecycleBin(_) => "E1013",
+            WerunError::Service(_) => "E1014",
+            WerunError::TrayIcon(_) => "E1015",
+            WerunError::Toast(_) => "E1016",
+            WerunError::ShellOpen(_) => "E1017",
+            WerunError::ConfigLoad(_) => "E2001",
+            WerunError::ConfigSave(_) => "E2002",
+            WerunError::PluginNotFound(_) => "E3001",
+            WerunError::PluginInit { .. } => "E3002",
+            WerunError::PluginExecute { .. } => "E3003",
+            WerunError::Network(_) => "E4001",
+        }
+    }
+
+    /// 获取面向用户的本地化提示（附带错误码）
+    pub fn user_message(&self) -> String {
+        let message = match self {
+            WerunError::ConfigLoad(_) => "配置文件格式有误，已使用默认配置".to_string(),
+            WerunError::ConfigSave(_) => "无法保存配置，请检查配置文件是否只读".to_string(),
+            WerunError::PluginNotFound(_) => "没有插件能处理该结果".to_string(),
+            WerunError::PluginInit { plugin, .. } => format!("插件 {} 加载失败", plugin),
+            WerunError::PluginExecute { plugin, .. } => format!("插件 {} 执行失败", plugin),
+            WerunError::HotkeySetup(_) => "无法初始化全局快捷键".to_string(),
+            WerunError::HotkeyInUse(_) => "全局快捷键被其他程序占用".to_string(),
+            WerunError::Clipboard(_) => "剪贴板被其他程序占用，请稍后重试".to_string(),
+            WerunError::Elevation(_) => "未能以管理员身份运行，UAC 提示可能已被取消".to_string(),
+            WerunError::Paste(_) => "无法切换回之前的窗口，内容已复制到剪贴板".to_string(),
+            WerunError::JumpList(_) => "任务栏跳转列表不可用".to_string(),
+            WerunError::Hotstring(_) => "无法启用缩写展开，可能被安全软件拦截".to_string(),
+            WerunError::Autostart(_) => "无法设置登录时启动，可能被安全软件拦截".to_string(),
+            WerunError::Audio(_) => "无法控制音频设备，设备可能已断开".to_string(),
+            WerunError::Media(_) => "播放器没有响应，可能已关闭".to_string(),
+            WerunError::DisplayPower(_) => "无法更改显示或电源设置，可能不受设备支持".to_string(),
+            WerunError::ScreenColor(_) => "无法读取屏幕颜色".to_string(),
+            WerunError::RecycleBin(_) => "无法清空回收站，部分文件可能正在使用".to_string(),
+            WerunError::Service(_) => "无法控制服务，服务可能已被禁用或有依赖未启动".to_string(),
+            WerunError::TrayIcon(_) => "无法添加托盘图标，资源管理器可能尚未启动".to_string(),
+            WerunError::Toast(_) => "无法显示系统通知，请检查系统的通知设置".to_string(),
+            WerunError::ShellOpen(_) => "无法打开，目标可能已被移动或删除".to_string(),
+            WerunError::Network(_) => "网络连接失败，请检查网络设置".to_string(),
+        };
+
+        format!("{} ({})", message, self.code())
+    }
+
+    /// 以结构化格式记录错误日志
+    pub fn log(&self) {
+        log::error!("[{}] [{}] {:?}", self.code(), self.category().display(), self);
+    }
+}
+
+/// WeRun 结果类型
+pub type Result<T> = std::result::Result<T, WerunError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_message_includes_code() {
+        let error = WerunError::HotkeyInUse("Alt+Space".to_string());
+        assert_eq!(error.category(), ErrorCategory::Platform);
+        assert_eq!(error.user_message(), "全局快捷键被其他程序占用 (E1002)");
+    }
+
+    #[test]
+    fn test_plugin_error_keeps_source() {
+        let error = WerunError::PluginExecute {
+            plugin: "clipboard".to_string(),
+            source: anyhow::anyhow!("拒绝访问"),
+        };
+        assert_eq!(error.code(), "E3003");
+        assert!(error.to_string().contains("拒绝访问"));
+    }
+}
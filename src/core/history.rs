@@ -0,0 +1,289 @@
+/// 使用历史模块
+///
+/// 记录执行过的结果和查询，为频率/最近使用 (frecency) 排序提供数据。
+/// 插件的隐私开关在这里统一生效，插件自身无需也无法绕过
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    config_manager::global_config,
+    error,
+    search::{SearchResult, ELEVATED_ID_SUFFIX},
+};
+
+/// 保留的结果记录上限
+const MAX_ENTRIES: usize = 1000;
+
+/// 保留的查询记录上限
+const MAX_QUERIES: usize = 200;
+
+/// 结果使用记录
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// 结果 ID
+    pub result_id: String,
+    /// 插件 ID
+    pub plugin_id: String,
+    /// 结果标题
+    pub title: String,
+    /// 执行次数
+    pub count: u32,
+    /// 最近执行时间 (Unix 时间戳，毫秒)
+    pub last_used: i64,
+}
+
+impl HistoryEntry {
+    /// 计算 frecency 分数
+    ///
+    /// 执行次数按最近使用时间衰减：越近越高
+    pub fn frecency(&self, now: i64) -> f64 {
+        let age_hours = (now - self.last_used).max(0) as f64 / 3_600_000.0;
+        let weight = if age_hours <= 1.0 {
+            4.0
+        } else if age_hours <= 24.0 {
+            2.0
+        } else if age_hours <= 24.0 * 7.0 {
+            1.0
+        } else if age_hours <= 24.0 * 30.0 {
+            0.5
+        } else {
+            0.25
+        };
+
+        self.count as f64 * weight
+    }
+}
+
+/// 查询记录
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryRecord {
+    /// 查询内容
+    pub query: String,
+    /// 执行结果所属的插件 ID
+    pub plugin_id: String,
+    /// 记录时间 (Unix 时间戳，毫秒)
+    pub timestamp: i64,
+}
+
+/// 历史数据（持久化格式）
+#[derive(Default, Serialize, Deserialize)]
+struct HistoryData {
+    /// 结果使用记录
+    entries: Vec<HistoryEntry>,
+    /// 查询记录（最新的在最后）
+    queries: Vec<QueryRecord>,
+}
+
+/// 使用历史存储
+pub struct HistoryStore {
+    /// 历史数据
+    data: Mutex<HistoryData>,
+    /// 数据文件路径
+    data_file: PathBuf,
+}
+
+impl HistoryStore {
+    /// 创建新的历史存储并加载数据
+    pub fn new() -> Self {
+        let data_file = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("werun")
+            .join("history.json");
+
+        Self::with_path(data_file)
+    }
+
+    /// 使用指定的数据文件创建历史存储
+    pub fn with_path(data_file: PathBuf) -> Self {
+        let store = Self { data: Mutex::new(HistoryData::default()), data_file };
+
+        if let Err(e) = store.load() {
+            log::warn!("加载使用历史失败: {:?}", e);
+        }
+
+        store
+    }
+
+    /// 插件是否允许记录历史
+    fn is_recordable(&self, plugin_id: &str) -> bool {
+        !global_config().is_history_excluded(plugin_id)
+    }
+
+    /// 记录一次执行
+    ///
+    /// 同时记录触发执行的查询；插件被排除或结果包含敏感内容时两者都不记录
+    pub fn record_execution(&self, result: &SearchResult, query: &str) {
+        if result.sensitive {
+            log::debug!("结果包含敏感内容，不记录历史: {}", result.id);
+            return;
+        }
+        let plugin_id = result.plugin_id();
+        if !self.is_recordable(plugin_id) {
+            log::debug!("插件 {} 已排除在历史记录之外", plugin_id);
+            return;
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        // 以管理员身份运行与普通运行计入同一条历史
+        let result_id = result.id.strip_suffix(ELEVATED_ID_SUFFIX).unwrap_or(&result.id);
+
+        {
+            let mut data = self.data.lock().unwrap();
+
+            match data.entries.iter_mut().find(|e| e.result_id == result_id) {
+                Some(entry) => {
+                    entry.count += 1;
+                    entry.last_used = now;
+                    entry.title = result.title.clone();
+                },
+                None => data.entries.push(HistoryEntry {
+                    result_id: result_id.to_string(),
+                    plugin_id: plugin_id.to_string(),
+                    title: result.title.clone(),
+                    count: 1,
+                    last_used: now,
+                }),
+            }
+
+            if data.entries.len() > MAX_ENTRIES {
+                // 淘汰 frecency 最低的记录
+                data.entries.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+                data.entries.truncate(MAX_ENTRIES);
+            }
+
+            let query = query.trim();
+            if !query.is_empty() {
+                data.queries.push(QueryRecord {
+                    query: query.to_string(),
+                    plugin_id: plugin_id.to_string(),
+                    timestamp: now,
+                });
+
+                let overflow = data.queries.len().saturating_sub(MAX_QUERIES);
+                data.queries.drain(..overflow);
+            }
+        }
+
+        if let Err(e) = self.save() {
+            log::warn!("保存使用历史失败: {:?}", e);
+        }
+    }
+
+    /// 获取结果的 frecency 分数（无记录时为 0）
+    pub fn frecency(&self, result_id: &str) -> f64 {
+        let now = chrono::Utc::now().timestamp_millis();
+        let data = self.data.lock().unwrap();
+        data.entries.iter().find(|e| e.result_id == result_id).map_or(0.0, |e| e.frecency(now))
+    }
+
+    /// 获取按 frecency 排序的使用记录
+    pub fn top_entries(&self, limit: usize) -> Vec<HistoryEntry> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut entries = self.data.lock().unwrap().entries.clone();
+        entries.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+        entries.truncate(limit);
+        entries
+    }
+
+    /// 获取最近的查询（最新的在前）
+    pub fn recent_queries(&self, limit: usize) -> Vec<QueryRecord> {
+        let data = self.data.lock().unwrap();
+        data.queries.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// 设置插件是否被排除在历史记录之外
+    ///
+    /// 排除时同时清除该插件已有的记录和查询
+    pub fn set_plugin_excluded(&self, plugin_id: &str, excluded: bool) -> error::Result<()> {
+        global_config().set_history_excluded(plugin_id, excluded)?;
+
+        if excluded {
+            self.purge_plugin(plugin_id);
+        }
+
+        log::info!("插件 {} 历史记录: {}", plugin_id, if excluded { "已排除" } else { "已允许" });
+        Ok(())
+    }
+
+    /// 清除插件的所有历史记录
+    pub fn purge_plugin(&self, plugin_id: &str) {
+        {
+            let mut data = self.data.lock().unwrap();
+            data.entries.retain(|e| e.plugin_id != plugin_id);
+            data.queries.retain(|q| q.plugin_id != plugin_id);
+        }
+
+        if let Err(e) = self.save() {
+            log::warn!("保存使用历史失败: {:?}", e);
+        }
+    }
+
+    /// 加载历史数据
+    ///
+    /// 同时清除已被排除的插件遗留的记录
+    fn load(&self) -> Result<()> {
+        if self.data_file.exists() {
+            let content = fs::read_to_string(&self.data_file)?;
+            let mut loaded: HistoryData = serde_json::from_str(&content)?;
+            loaded.entries.retain(|e| self.is_recordable(&e.plugin_id));
+            loaded.queries.retain(|q| self.is_recordable(&q.plugin_id));
+            *self.data.lock().unwrap() = loaded;
+        }
+        Ok(())
+    }
+
+    /// 保存历史数据
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.data_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let data = self.data.lock().unwrap();
+        let content = serde_json::to_string_pretty(&*data)?;
+        fs::write(&self.data_file, content)?;
+        Ok(())
+    }
+}
+
+impl Default for HistoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 全局历史实例
+static GLOBAL_HISTORY: Lazy<HistoryStore> = Lazy::new(HistoryStore::new);
+
+/// 获取全局历史存储
+pub fn global_history() -> &'static HistoryStore {
+    &GLOBAL_HISTORY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(count: u32, last_used: i64) -> HistoryEntry {
+        HistoryEntry {
+            result_id: "app_launcher:test".to_string(),
+            plugin_id: "app_launcher".to_string(),
+            title: "test".to_string(),
+            count,
+            last_used,
+        }
+    }
+
+    #[test]
+    fn test_frecency_decays_with_age() {
+        let now = 100 * 24 * 3_600_000;
+        let recent = entry(2, now - 60_000);
+        let old = entry(10, now - 60 * 24 * 3_600_000);
+
+        assert_eq!(recent.frecency(now), 8.0);
+        assert_eq!(old.frecency(now), 2.5);
+        assert!(recent.frecency(now) > old.frecency(now));
+    }
+}
@@ -0,0 +1,94 @@
+//! 全局快捷键
+//!
+//! 解析配置中 `Alt+Shift+Space` 形式的快捷键，得到 RegisterHotKey 使用的修饰键和虚拟键码
+
+/// 修饰键 Alt
+pub const MOD_ALT: u32 = 0x0001;
+/// 修饰键 Ctrl
+pub const MOD_CONTROL: u32 = 0x0002;
+/// 修饰键 Shift
+pub const MOD_SHIFT: u32 = 0x0004;
+/// 修饰键 Win
+pub const MOD_WIN: u32 = 0x0008;
+
+/// 快捷键
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HotkeyChord {
+    /// 修饰键组合
+    pub modifiers: u32,
+    /// 虚拟键码
+    pub key: u32,
+}
+
+impl HotkeyChord {
+    /// 解析快捷键，不区分大小写；必须包含修饰键和一个按键，无法识别时返回 `None`
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = 0;
+        let mut key = None;
+        for part in text.split('+').map(|part| part.trim().to_lowercase()) {
+            match part.as_str() {
+                "alt" => modifiers |= MOD_ALT,
+                "ctrl" | "control" => modifiers |= MOD_CONTROL,
+                "shift" => modifiers |= MOD_SHIFT,
+                "win" | "super" => modifiers |= MOD_WIN,
+                name if key.is_none() => key = Some(virtual_key(name)?),
+                _ => return None,
+            }
+        }
+        (modifiers != 0).then_some(Self { modifiers, key: key? })
+    }
+}
+
+/// 按键名称对应的虚拟键码
+fn virtual_key(name: &str) -> Option<u32> {
+    let code = match name {
+        "space" => 0x20,
+        "enter" => 0x0D,
+        "tab" => 0x09,
+        "backspace" => 0x08,
+        "insert" => 0x2D,
+        "delete" => 0x2E,
+        "home" => 0x24,
+        "end" => 0x23,
+        "pageup" => 0x21,
+        "pagedown" => 0x22,
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next()?, chars.next()) {
+                // 字母和数字的虚拟键码即大写字符的编码
+                (c @ ('a'..='z' | '0'..='9'), None) => c.to_ascii_uppercase() as u32,
+                // F1 为 0x70，依次到 F24
+                ('f', Some(_)) => {
+                    let number: u32 = name[1..].parse().ok()?;
+                    if !(1..=24).contains(&number) {
+                        return None;
+                    }
+                    0x6F + number
+                },
+                _ => return None,
+            }
+        },
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hotkey() {
+        assert_eq!(
+            HotkeyChord::parse("Alt+Shift+Space"),
+            Some(HotkeyChord { modifiers: MOD_ALT | MOD_SHIFT, key: 0x20 })
+        );
+        assert_eq!(
+            HotkeyChord::parse("ctrl + win + t"),
+            Some(HotkeyChord { modifiers: MOD_CONTROL | MOD_WIN, key: 'T' as u32 })
+        );
+        assert_eq!(HotkeyChord::parse("Alt+F12").map(|chord| chord.key), Some(0x7B));
+        assert_eq!(HotkeyChord::parse("Space"), None);
+        assert_eq!(HotkeyChord::parse("Alt+F25"), None);
+        assert_eq!(HotkeyChord::parse("Alt+A+B"), None);
+    }
+}
@@ -0,0 +1,127 @@
+/// 缩写展开
+///
+/// 记录在任意程序中最近输入的字符，以配置的缩写结尾时给出展开内容，
+/// 由平台层删除已输入的缩写并输入展开后的文本
+use chrono::NaiveDateTime;
+
+use crate::core::config::Abbreviation;
+
+/// 缩写匹配结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expansion {
+    /// 需要删除的已输入字符数（包括触发匹配的最后一个字符）
+    pub erase: usize,
+    /// 展开模板，占位符在输入时用 [`expand_placeholders`] 替换
+    pub template: String,
+}
+
+/// 缩写匹配器
+pub struct HotstringMatcher {
+    /// 缩写和展开模板，较长的缩写在前，避免被其后缀抢先匹配
+    abbreviations: Vec<(String, String)>,
+    /// 最近输入的字符
+    typed: String,
+    /// 最长缩写的字符数，输入记录只保留这么长
+    max_len: usize,
+}
+
+impl HotstringMatcher {
+    /// 创建匹配器，忽略空的缩写
+    pub fn new(abbreviations: &[Abbreviation]) -> Self {
+        let mut abbreviations: Vec<_> = abbreviations
+            .iter()
+            .filter(|a| !a.trigger.is_empty())
+            .map(|a| (a.trigger.clone(), a.text.clone()))
+            .collect();
+        abbreviations.sort_by_key(|(trigger, _)| std::cmp::Reverse(trigger.chars().count()));
+        let max_len = abbreviations.first().map_or(0, |(trigger, _)| trigger.chars().count());
+
+        Self { abbreviations, typed: String::new(), max_len }
+    }
+
+    /// 是否没有可用的缩写
+    pub fn is_empty(&self) -> bool {
+        self.abbreviations.is_empty()
+    }
+
+    /// 记录输入的字符，输入以缩写结尾时返回展开内容并清空记录
+    pub fn push(&mut self, c: char) -> Option<Expansion> {
+        self.typed.push(c);
+        let len = self.typed.chars().count();
+        if len > self.max_len {
+            let excess = self.typed.char_indices().nth(len - self.max_len).map_or(0, |(i, _)| i);
+            self.typed.drain(..excess);
+        }
+
+        let (trigger, template) = self
+            .abbreviations
+            .iter()
+            .find(|(trigger, _)| self.typed.ends_with(trigger.as_str()))?;
+        let expansion = Expansion { erase: trigger.chars().count(), template: template.clone() };
+        self.typed.clear();
+        Some(expansion)
+    }
+
+    /// 退格删除最后一个输入的字符
+    pub fn backspace(&mut self) {
+        self.typed.pop();
+    }
+
+    /// 清空输入记录（光标移动、切换窗口等无法跟踪输入位置时）
+    pub fn reset(&mut self) {
+        self.typed.clear();
+    }
+}
+
+/// 替换展开模板中的占位符：`{date}`、`{time}`、`{datetime}`
+pub fn expand_placeholders(template: &str, now: NaiveDateTime) -> String {
+    template
+        .replace("{datetime}", &now.format("%Y-%m-%d %H:%M").to_string())
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H:%M").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abbreviation(trigger: &str, text: &str) -> Abbreviation {
+        Abbreviation { trigger: trigger.to_string(), text: text.to_string() }
+    }
+
+    fn type_text(matcher: &mut HotstringMatcher, text: &str) -> Option<Expansion> {
+        text.chars().fold(None, |_, c| matcher.push(c))
+    }
+
+    #[test]
+    fn test_matches_typed_abbreviations() {
+        let mut matcher = HotstringMatcher::new(&[
+            abbreviation("@@", "me@example.com"),
+            abbreviation("date", "suffix"),
+            abbreviation(";date", "{date}"),
+            abbreviation("", "ignored"),
+        ]);
+
+        assert_eq!(
+            type_text(&mut matcher, "mail @@"),
+            Some(Expansion { erase: 2, template: "me@example.com".to_string() })
+        );
+        // 较长的缩写优先于其后缀，退格修正后的输入同样匹配
+        assert_eq!(type_text(&mut matcher, ";dt"), None);
+        matcher.backspace();
+        assert_eq!(
+            type_text(&mut matcher, "ate"),
+            Some(Expansion { erase: 5, template: "{date}".to_string() })
+        );
+
+        // 重置后之前的输入不参与匹配
+        type_text(&mut matcher, "@");
+        matcher.reset();
+        assert_eq!(type_text(&mut matcher, "@"), None);
+
+        let now =
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(9, 7, 0).unwrap();
+        assert_eq!(expand_placeholders("{date} {time}", now), "2024-03-05 09:07");
+        assert_eq!(expand_placeholders("{datetime}", now), "2024-03-05 09:07");
+    }
+}
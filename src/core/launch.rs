@@ -0,0 +1,95 @@
+/// 启动命令
+///
+/// 任务栏跳转列表中的任务以命令行参数启动 werun，已有实例运行时由新进程转发给它处理
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LaunchCommand {
+    /// 显示或隐藏启动器（`--toggle`）
+    Toggle,
+    /// 打开配置文件（`--settings`）
+    Settings,
+    /// 显示启动器并填入查询（`--query <文本>`）
+    Query(String),
+    /// 登录时在后台启动，预热完成后才注册快捷键（`--background [工作目录]`）
+    ///
+    /// 登录时启动的程序工作目录为系统目录，配置文件按工作目录查找，需在参数中带上
+    Background(Option<String>),
+}
+
+impl LaunchCommand {
+    /// 从命令行参数（不含程序路径）解析，没有可识别的命令时返回 `None`
+    pub fn parse<I>(args: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        match args.next()?.as_str() {
+            "--toggle" => Some(LaunchCommand::Toggle),
+            "--settings" => Some(LaunchCommand::Settings),
+            "--query" => Some(LaunchCommand::Query(args.next().unwrap_or_default())),
+            "--background" => Some(LaunchCommand::Background(args.next())),
+            _ => None,
+        }
+    }
+
+    /// 命令行参数
+    pub fn args(&self) -> Vec<String> {
+        match self {
+            LaunchCommand::Toggle => vec!["--toggle".to_string()],
+            LaunchCommand::Settings => vec!["--settings".to_string()],
+            LaunchCommand::Query(query) => vec!["--query".to_string(), query.clone()],
+            LaunchCommand::Background(dir) => {
+                std::iter::once("--background".to_string()).chain(dir.clone()).collect()
+            },
+        }
+    }
+
+    /// 快捷方式使用的参数字符串，含空格的参数加引号
+    pub fn command_line(&self) -> String {
+        self.args()
+            .iter()
+            .map(|arg| {
+                if arg.is_empty() || arg.contains(' ') {
+                    format!("\"{}\"", arg)
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// 转发给已运行实例的消息，参数以换行分隔
+    pub fn encode(&self) -> String {
+        self.args().join("\n")
+    }
+
+    /// 解析转发的消息
+    pub fn decode(message: &str) -> Option<Self> {
+        Self::parse(message.split('\n').map(String::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launch_command_round_trip() {
+        let commands = [
+            LaunchCommand::Toggle,
+            LaunchCommand::Settings,
+            LaunchCommand::Query("/clipboard ".to_string()),
+            LaunchCommand::Background(None),
+            LaunchCommand::Background(Some("C:\\Tools\\WeRun".to_string())),
+        ];
+        for command in commands {
+            assert_eq!(LaunchCommand::decode(&command.encode()), Some(command.clone()));
+            assert_eq!(LaunchCommand::parse(command.args()), Some(command));
+        }
+
+        let query = LaunchCommand::Query("/clipboard ".to_string());
+        assert_eq!(query.command_line(), "--query \"/clipboard \"");
+        assert_eq!(LaunchCommand::parse(vec!["--unknown".to_string()]), None);
+        assert_eq!(LaunchCommand::parse(Vec::new()), None);
+    }
+}
@@ -1,7 +1,24 @@
 /// 核心模块
 ///
 /// 提供启动器的核心功能：搜索、配置、插件接口
+pub mod cache;
 pub mod config;
 pub mod config_manager;
+pub mod error;
+pub mod history;
+pub mod hotkey;
+pub mod hotstring;
+pub mod launch;
+pub mod notes;
+pub mod notifications;
 pub mod plugin;
+pub mod prefill;
+pub mod preview;
+pub mod progress;
+pub mod query;
+pub mod scheduler;
 pub mod search;
+pub mod settings_catalog;
+pub mod spelling;
+pub mod themes;
+pub mod warm_up;
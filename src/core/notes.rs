@@ -0,0 +1,195 @@
+/// 结果备注模块
+///
+/// 为任意结果（按结果 ID）附加一条简短备注，如"生产服务器，小心操作"，
+/// 备注显示在结果描述旁边，并可以被搜索，作为跨插件的标签使用
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::core::search::SearchResult;
+
+/// 备注的最大字符数
+pub const MAX_NOTE_CHARS: usize = 80;
+
+/// 结果备注
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    /// 结果 ID
+    pub result_id: String,
+    /// 插件 ID
+    pub plugin_id: String,
+    /// 添加备注时的结果标题，按备注搜索时用它向插件重新查找结果
+    pub title: String,
+    /// 备注内容
+    pub text: String,
+    /// 最近修改时间 (Unix 时间戳，毫秒)
+    pub updated_at: i64,
+}
+
+impl Note {
+    /// 备注是否匹配查询（忽略大小写的子串匹配）
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        !query.is_empty() && self.text.to_lowercase().contains(&query)
+    }
+}
+
+/// 备注存储
+pub struct NoteStore {
+    /// 备注（最近修改的在最后）
+    notes: Mutex<Vec<Note>>,
+    /// 数据文件路径
+    data_file: PathBuf,
+}
+
+impl NoteStore {
+    /// 创建新的备注存储并加载数据
+    pub fn new() -> Self {
+        let data_file = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("werun")
+            .join("notes.json");
+
+        Self::with_path(data_file)
+    }
+
+    /// 使用指定的数据文件创建备注存储
+    pub fn with_path(data_file: PathBuf) -> Self {
+        let store = Self { notes: Mutex::new(Vec::new()), data_file };
+
+        if let Err(e) = store.load() {
+            log::warn!("加载结果备注失败: {:?}", e);
+        }
+
+        store
+    }
+
+    /// 获取结果的备注
+    pub fn get(&self, result_id: &str) -> Option<String> {
+        let notes = self.notes.lock().unwrap();
+        notes.iter().find(|n| n.result_id == result_id).map(|n| n.text.clone())
+    }
+
+    /// 设置结果的备注，内容为空时删除备注；超出长度的部分被截断
+    pub fn set(&self, result: &SearchResult, text: &str) {
+        let text: String = text.trim().chars().take(MAX_NOTE_CHARS).collect();
+
+        {
+            let mut notes = self.notes.lock().unwrap();
+            notes.retain(|n| n.result_id != result.id);
+            if !text.is_empty() {
+                notes.push(Note {
+                    result_id: result.id.clone(),
+                    plugin_id: result.plugin_id().to_string(),
+                    title: result.title.clone(),
+                    text,
+                    updated_at: chrono::Utc::now().timestamp_millis(),
+                });
+            }
+        }
+
+        if let Err(e) = self.save() {
+            log::warn!("保存结果备注失败: {:?}", e);
+        }
+    }
+
+    /// 备注匹配查询的记录（最近修改的在前）
+    pub fn matching(&self, query: &str) -> Vec<Note> {
+        let notes = self.notes.lock().unwrap();
+        notes.iter().rev().filter(|n| n.matches(query)).cloned().collect()
+    }
+
+    /// 为结果附上备注
+    pub fn annotate(&self, results: &mut [SearchResult]) {
+        let notes = self.notes.lock().unwrap();
+        if notes.is_empty() {
+            return;
+        }
+
+        for result in results {
+            result.note = notes.iter().find(|n| n.result_id == result.id).map(|n| n.text.clone());
+        }
+    }
+
+    /// 加载备注
+    fn load(&self) -> Result<()> {
+        if self.data_file.exists() {
+            let content = fs::read_to_string(&self.data_file)?;
+            *self.notes.lock().unwrap() = serde_json::from_str(&content)?;
+        }
+        Ok(())
+    }
+
+    /// 保存备注
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.data_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let notes = self.notes.lock().unwrap();
+        let content = serde_json::to_string_pretty(&*notes)?;
+        fs::write(&self.data_file, content)?;
+        Ok(())
+    }
+}
+
+impl Default for NoteStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 全局备注实例
+static GLOBAL_NOTES: Lazy<NoteStore> = Lazy::new(NoteStore::new);
+
+/// 获取全局备注存储
+pub fn global_notes() -> &'static NoteStore {
+    &GLOBAL_NOTES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::search::{ActionData, ResultType};
+
+    fn result(id: &str, title: &str) -> SearchResult {
+        SearchResult::new(
+            id.to_string(),
+            title.to_string(),
+            String::new(),
+            ResultType::Command,
+            100,
+            ActionData::ExecuteCommand { command: "ssh prod".to_string() },
+        )
+    }
+
+    #[test]
+    fn test_set_annotate_and_search() {
+        let dir = std::env::temp_dir().join(format!("werun-notes-{}", std::process::id()));
+        let store = NoteStore::with_path(dir.join("notes.json"));
+
+        let server = result("ssh:prod", "prod-01");
+        store.set(&server, "  生产服务器 — Careful ");
+        store.set(&result("file_search:contract.pdf", "contract.pdf"), "客户 X 合同");
+
+        let mut results = vec![server.clone(), result("ssh:staging", "staging")];
+        store.annotate(&mut results);
+        assert_eq!(results[0].note.as_deref(), Some("生产服务器 — Careful"));
+        assert_eq!(results[1].note, None);
+
+        let matched = store.matching("careful");
+        assert_eq!(matched.len(), 1);
+        assert_eq!((matched[0].plugin_id.as_str(), matched[0].title.as_str()), ("ssh", "prod-01"));
+        assert!(store.matching("  ").is_empty());
+
+        // 重新加载后仍然存在，清空内容即删除
+        let reloaded = NoteStore::with_path(dir.join("notes.json"));
+        assert_eq!(reloaded.get("file_search:contract.pdf").as_deref(), Some("客户 X 合同"));
+        reloaded.set(&server, "");
+        assert_eq!(reloaded.get("ssh:prod"), None);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,117 @@
+/// 后台通知模块
+///
+/// 重建索引完成、捕获剪贴板内容等没有窗口上下文的后台事件在这里排队，
+/// 由启动器窗口定时取出：窗口显示时在窗口内的通知层显示，隐藏时改用系统通知
+use std::{collections::VecDeque, sync::Mutex};
+
+use once_cell::sync::Lazy;
+
+use crate::core::{config_manager::global_config, scheduler::global_scheduler};
+
+/// 等待显示的通知数上限，超出时丢弃最早的
+const MAX_PENDING: usize = 20;
+
+/// 通知事件
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoticeKind {
+    /// 重建索引完成
+    IndexRebuilt,
+    /// 捕获到剪贴板内容
+    ClipboardCaptured,
+}
+
+impl NoticeKind {
+    /// 配置中的名称
+    pub fn key(self) -> &'static str {
+        match self {
+            NoticeKind::IndexRebuilt => "index_rebuilt",
+            NoticeKind::ClipboardCaptured => "clipboard_captured",
+        }
+    }
+}
+
+/// 通知
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notice {
+    /// 事件
+    pub kind: NoticeKind,
+    /// 标题
+    pub title: String,
+    /// 内容
+    pub message: String,
+}
+
+/// 通知中心
+#[derive(Default)]
+pub struct NotificationCenter {
+    /// 等待显示的通知
+    pending: Mutex<VecDeque<Notice>>,
+}
+
+impl NotificationCenter {
+    /// 创建新的通知中心
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 发出通知；通知已关闭、该事件已静音或处于免打扰时段时忽略
+    pub fn notify(&self, kind: NoticeKind, title: impl Into<String>, message: impl Into<String>) {
+        if !global_config().notifications().allows(kind.key()) {
+            return;
+        }
+        if !global_scheduler().allows_notification(false) {
+            log::debug!("免打扰时段内不显示通知: {}", kind.key());
+            return;
+        }
+
+        self.push(Notice { kind, title: title.into(), message: message.into() });
+    }
+
+    /// 加入等待队列
+    fn push(&self, notice: Notice) {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= MAX_PENDING {
+            pending.pop_front();
+        }
+        pending.push_back(notice);
+    }
+
+    /// 取出所有等待显示的通知
+    pub fn take_pending(&self) -> Vec<Notice> {
+        self.pending.lock().unwrap().drain(..).collect()
+    }
+}
+
+/// 全局通知中心
+static GLOBAL_NOTIFICATIONS: Lazy<NotificationCenter> = Lazy::new(NotificationCenter::new);
+
+/// 获取全局通知中心
+pub fn global_notifications() -> &'static NotificationCenter {
+    &GLOBAL_NOTIFICATIONS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_limit() {
+        let center = NotificationCenter::new();
+        for index in 0..=MAX_PENDING {
+            center.push(Notice {
+                kind: NoticeKind::IndexRebuilt,
+                title: "索引已重建".to_string(),
+                message: index.to_string(),
+            });
+        }
+
+        let pending = center.take_pending();
+        assert_eq!(pending.len(), MAX_PENDING);
+        assert_eq!(pending[0].message, "1");
+        assert!(center.take_pending().is_empty());
+
+        let config = crate::core::config::NotificationConfig::default();
+        assert!(config.allows(NoticeKind::IndexRebuilt.key()));
+        assert!(!config.allows(NoticeKind::ClipboardCaptured.key()));
+    }
+}
@@ -1,11 +1,214 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 
 /// 插件系统接口
 ///
 /// 定义所有插件必须实现的 trait
-use super::search::SearchResult;
+use super::{
+    config::PRIORITY_STEP,
+    config_manager::global_config,
+    error::{self, WerunError},
+    notes::global_notes,
+    search::{
+        split_queries, ActionData, Intent, SearchResult, BATCH_GROUP_LIMIT, INTENT_BOOST,
+        NOTE_BOOST,
+    },
+    spelling::{suggestion_result, Vocabulary},
+};
+use crate::utils::elevation;
+
+/// 插件图标
+///
+/// 内置图标以名称表示（图标库中的 kebab-case 名称，如 `"calculator"`），由界面映射为图标；
+/// 也可以是图片路径
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PluginIcon {
+    /// 内置图标名称
+    Named(String),
+    /// 图片路径
+    Image(String),
+}
+
+impl PluginIcon {
+    /// 内置图标
+    pub fn named(name: &str) -> Self {
+        PluginIcon::Named(name.to_string())
+    }
+
+    /// 从字符串解析：含路径分隔符或扩展名的视为图片路径
+    pub fn parse(value: &str) -> Self {
+        if value.contains(['/', '\\', '.']) {
+            PluginIcon::Image(value.to_string())
+        } else {
+            PluginIcon::Named(value.to_string())
+        }
+    }
+
+    /// 字符串形式，可由 [`PluginIcon::parse`] 还原
+    pub fn as_str(&self) -> &str {
+        match self {
+            PluginIcon::Named(name) => name,
+            PluginIcon::Image(path) => path,
+        }
+    }
+}
+
+/// 按备注搜索时最多重新查找的结果数
+const MAX_NOTE_RESULTS: usize = 5;
+
+/// 拼写纠正词汇表的有效期，索引在后台更新，过期后重新收集
+const VOCABULARY_TTL: Duration = Duration::from_secs(60);
+
+/// 浏览器进程
+const BROWSER_PROCESSES: &[&str] =
+    &["chrome.exe", "msedge.exe", "firefox.exe", "brave.exe", "opera.exe", "vivaldi.exe"];
+
+/// 启动器显示前的前台应用
+///
+/// 显示启动器时记录，插件据此在查询为空时提供与当前应用相关的结果
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ForegroundInfo {
+    /// 窗口句柄
+    pub hwnd: isize,
+    /// 进程的可执行文件名（小写，如 `explorer.exe`）
+    pub process: String,
+    /// 窗口类名
+    pub class_name: String,
+    /// 窗口标题
+    pub title: String,
+    /// 资源管理器窗口当前打开的文件夹（显示启动器后在后台读取）
+    pub folder: Option<String>,
+    /// 窗口中选中的文本（仅浏览器，显示启动器后在后台读取）
+    pub selection: Option<String>,
+}
+
+impl ForegroundInfo {
+    /// 是否为指定进程之一（不区分大小写）
+    pub fn is_process(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| name.eq_ignore_ascii_case(&self.process))
+    }
+
+    /// 是否为浏览器
+    pub fn is_browser(&self) -> bool {
+        self.is_process(BROWSER_PROCESSES)
+    }
+}
+
+/// 插件设置项的取值，插件页按类型显示开关、数字输入或文本输入
+#[derive(Clone, Debug, PartialEq)]
+pub enum SettingValue {
+    /// 开关
+    Bool(bool),
+    /// 整数，取值范围为 `min..=max`
+    Integer { value: i64, min: i64, max: i64 },
+    /// 文本
+    Text(String),
+    /// 文本列表，插件页中以逗号分隔编辑
+    List(Vec<String>),
+}
+
+impl SettingValue {
+    /// 写入配置文件的值
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            SettingValue::Bool(value) => serde_json::json!(value),
+            SettingValue::Integer { value, .. } => serde_json::json!(value),
+            SettingValue::Text(value) => serde_json::json!(value),
+            SettingValue::List(values) => serde_json::json!(values),
+        }
+    }
+
+    /// 插件页输入框中的文本
+    pub fn to_text(&self) -> String {
+        match self {
+            SettingValue::Bool(value) => value.to_string(),
+            SettingValue::Integer { value, .. } => value.to_string(),
+            SettingValue::Text(value) => value.clone(),
+            SettingValue::List(values) => values.join(", "),
+        }
+    }
+
+    /// 按同一类型解析插件页输入的文本，整数超出范围时取边界值，无法解析时返回 `None`
+    pub fn parse_text(&self, text: &str) -> Option<SettingValue> {
+        let text = text.trim();
+        match self {
+            SettingValue::Bool(_) => text.parse().ok().map(SettingValue::Bool),
+            SettingValue::Integer { min, max, .. } => {
+                let value = text.parse::<i64>().ok()?.clamp(*min, *max);
+                Some(SettingValue::Integer { value, min: *min, max: *max })
+            },
+            SettingValue::Text(_) => Some(SettingValue::Text(text.to_string())),
+            SettingValue::List(_) => Some(SettingValue::List(
+                text.split([',', '，'])
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )),
+        }
+    }
+
+    /// 按同一类型读取配置文件中的值，整数超出范围时取边界值，类型不符时返回 `None`
+    pub fn parse_json(&self, json: &serde_json::Value) -> Option<SettingValue> {
+        match self {
+            SettingValue::Bool(_) => json.as_bool().map(SettingValue::Bool),
+            SettingValue::Integer { min, max, .. } => {
+                let value = json.as_i64()?.clamp(*min, *max);
+                Some(SettingValue::Integer { value, min: *min, max: *max })
+            },
+            SettingValue::Text(_) => json.as_str().map(|text| SettingValue::Text(text.to_string())),
+            SettingValue::List(_) => Some(SettingValue::List(
+                json.as_array()?
+                    .iter()
+                    .filter_map(|item| item.as_str())
+                    .map(str::to_string)
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// 插件设置项，对应 `[plugins.settings.<plugin_id>]` 中的一个键
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettingField {
+    /// 配置键
+    pub key: &'static str,
+    /// 插件页显示的名称
+    pub label: &'static str,
+    /// 当前值（未配置时为默认值）
+    pub value: SettingValue,
+}
+
+impl SettingField {
+    /// 创建设置项
+    pub fn new(key: &'static str, label: &'static str, value: SettingValue) -> Self {
+        Self { key, label, value }
+    }
+}
+
+/// 插件页显示的插件信息
+#[derive(Clone, Debug)]
+pub struct PluginInfo {
+    /// 插件 ID
+    pub id: String,
+    /// 名称
+    pub name: String,
+    /// 描述
+    pub description: String,
+    /// 版本
+    pub version: String,
+    /// 图标
+    pub icon: PluginIcon,
+    /// 是否启用
+    pub enabled: bool,
+    /// 可在插件页修改的设置项
+    pub settings: Vec<SettingField>,
+}
 
 /// 插件 trait
 ///
@@ -23,6 +226,16 @@ pub trait Plugin: Send + Sync {
     /// 插件版本
     fn version(&self) -> &str;
 
+    /// 插件图标，显示在插件目录、当前插件标签和分组标题中
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("box")
+    }
+
+    /// 插件模式下输入框的占位提示，默认为“在<名称>中搜索…”
+    fn placeholder(&self) -> Option<&str> {
+        None
+    }
+
     /// 是否启用
     fn is_enabled(&self) -> bool;
 
@@ -35,55 +248,208 @@ pub trait Plugin: Send + Sync {
     /// 执行搜索
     fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>>;
 
+    /// 插件模式下查询为空时显示的默认结果（如最近文件），默认不显示
+    fn default_results(&self, _limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
+    /// 查询为空时根据启动器显示前的前台应用提供的上下文结果，默认不提供
+    fn contextual_results(
+        &self,
+        _foreground: &ForegroundInfo,
+        _limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
+    /// 用于拼写纠正的索引文本（应用名称、文件名等），默认不提供
+    fn vocabulary(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 没有任何插件返回结果时提供的兜底结果（如用搜索引擎搜索查询），默认不提供
+    ///
+    /// 兜底结果按配置的插件顺序显示在拼写建议之后，插件返回的分数会被忽略
+    fn fallback(&self, _query: &str) -> Vec<SearchResult> {
+        Vec::new()
+    }
+
     /// 执行动作
     fn execute(&self, result: &SearchResult) -> Result<()>;
 
     /// 刷新插件数据（如重新索引）
     fn refresh(&mut self) -> Result<()>;
+
+    /// 启动器窗口每次显示时调用，插件可在此清除只在一次显示期间有效的缓存，默认不处理
+    fn on_activate(&mut self) {}
+
+    /// 可在插件页修改的设置项及其当前值，默认没有
+    ///
+    /// 插件页把修改写入 `[plugins.settings.<plugin_id>]` 后调用 [`Plugin::refresh`]，
+    /// 提供设置项的插件需要在刷新时重新读取设置
+    fn settings(&self) -> Vec<SettingField> {
+        Vec::new()
+    }
+}
+
+/// 按关键词延迟加载的插件
+struct LazyPlugin {
+    /// 插件 ID
+    id: String,
+    /// 触发加载的关键词（查询的第一个词，小写）
+    keywords: Vec<String>,
+    /// 前台应用为这些进程时加载以提供上下文结果（小写）
+    processes: Vec<String>,
+    /// 构造插件；插件页也用它构造不初始化的实例来读取名称、描述等信息
+    factory: Box<dyn Fn() -> Arc<Mutex<dyn Plugin>> + Send>,
 }
 
 /// 插件管理器
 pub struct PluginManager {
-    /// 已注册的插件列表
-    plugins: Vec<Arc<Mutex<dyn Plugin>>>,
+    /// 已加载的插件列表
+    plugins: RwLock<Vec<Arc<Mutex<dyn Plugin>>>>,
+    /// 尚未加载的插件，首次输入关键词或进入插件模式时才构造和初始化
+    lazy: Mutex<Vec<LazyPlugin>>,
+    /// 拼写纠正词汇表及其收集时间
+    vocabulary: Mutex<Option<(Instant, Arc<Vocabulary>)>>,
 }
 
 impl PluginManager {
     /// 创建新的插件管理器
     pub fn new() -> Self {
-        Self { plugins: Vec::new() }
+        Self {
+            plugins: RwLock::new(Vec::new()),
+            lazy: Mutex::new(Vec::new()),
+            vocabulary: Mutex::new(None),
+        }
     }
 
     /// 注册插件
     pub fn register(&mut self, plugin: impl Plugin + 'static) {
         let plugin = Arc::new(Mutex::new(plugin));
         log::info!("注册插件");
-        self.plugins.push(plugin);
+        self.plugins.get_mut().unwrap().push(plugin);
     }
 
-    /// 获取所有插件数量
+    /// 注册按需加载的插件
+    ///
+    /// 不常用的插件在查询的第一个词为 `keywords` 之一（或进入该插件模式）时才构造和初始化，
+    /// 插件数量增加时启动耗时和常驻内存保持不变
+    pub fn register_lazy<P: Plugin + 'static>(
+        &mut self,
+        id: &str,
+        keywords: &[&str],
+        factory: impl Fn() -> P + Send + 'static,
+    ) {
+        log::info!("注册按需加载插件: {}", id);
+        self.lazy.get_mut().unwrap().push(LazyPlugin {
+            id: id.to_string(),
+            keywords: keywords.iter().map(|keyword| keyword.to_lowercase()).collect(),
+            processes: Vec::new(),
+            factory: Box::new(move || Arc::new(Mutex::new(factory())) as Arc<Mutex<dyn Plugin>>),
+        });
+    }
+
+    /// 前台应用为 `processes` 之一时也加载按需加载的插件，以便提供上下文结果
+    pub fn load_on_foreground(&mut self, id: &str, processes: &[&str]) {
+        if let Some(plugin) = self.lazy.get_mut().unwrap().iter_mut().find(|p| p.id == id) {
+            plugin.processes = processes.iter().map(|process| process.to_lowercase()).collect();
+        }
+    }
+
+    /// 获取所有插件数量（包括尚未加载的插件）
     pub fn plugin_count(&self) -> usize {
-        self.plugins.len()
+        self.plugins().len() + self.lazy_plugin_count()
+    }
+
+    /// 尚未加载的插件数量
+    pub fn lazy_plugin_count(&self) -> usize {
+        self.lazy.lock().unwrap().len()
+    }
+
+    /// 已加载插件的快照，遍历期间按需加载的插件不受读锁阻塞
+    fn plugins(&self) -> Vec<Arc<Mutex<dyn Plugin>>> {
+        self.plugins.read().unwrap().clone()
+    }
+
+    /// 构造并初始化满足条件的延迟加载插件
+    fn load_lazy(&self, matches: impl Fn(&LazyPlugin) -> bool) {
+        let pending: Vec<LazyPlugin> = {
+            let mut lazy = self.lazy.lock().unwrap();
+            if !lazy.iter().any(&matches) {
+                return;
+            }
+            let (pending, rest) = std::mem::take(&mut *lazy).into_iter().partition(&matches);
+            *lazy = rest;
+            pending
+        };
+
+        for LazyPlugin { id, factory, .. } in pending {
+            let started = Instant::now();
+            let plugin = factory();
+            if let Ok(mut guard) = plugin.lock() {
+                if let Err(source) = guard.initialize() {
+                    WerunError::PluginInit { plugin: id.clone(), source }.log();
+                }
+                apply_disabled(&mut *guard);
+            }
+            log::info!("按需加载插件: {}，耗时 {:?}", id, started.elapsed());
+            self.plugins.write().unwrap().push(plugin);
+        }
+    }
+
+    /// 查询的第一个词是关键词时加载对应的插件
+    fn load_for_query(&self, query: &str) {
+        if let Some(word) = query.split_whitespace().next() {
+            let word = word.to_lowercase();
+            self.load_lazy(|plugin| plugin.keywords.contains(&word));
+        }
     }
 
     /// 初始化所有插件
-    pub fn initialize_all(&mut self) -> Result<()> {
-        for plugin in &self.plugins {
+    ///
+    /// 单个插件初始化失败只记录日志，不影响其他插件
+    pub fn initialize_all(&mut self) -> error::Result<()> {
+        for plugin in &self.plugins() {
             if let Ok(mut guard) = plugin.lock() {
                 log::info!("初始化插件: {}", guard.name());
-                if let Err(e) = guard.initialize() {
-                    log::error!("初始化插件 {} 失败: {:?}", guard.name(), e);
+                if let Err(source) = guard.initialize() {
+                    WerunError::PluginInit { plugin: guard.id().to_string(), source }.log();
                 }
+                apply_disabled(&mut *guard);
             }
         }
         Ok(())
     }
 
     /// 搜索所有插件
+    ///
+    /// 分号分隔的多个查询分别搜索，结果按查询分组
     pub fn search_all(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        if let Some(queries) = split_queries(query) {
+            return self.search_batch(&queries, limit);
+        }
+
+        let mut results = self.search_plugins(query, limit);
+
+        // 没有任何结果时给出拼写纠正建议；兜底结果排在最后，保证启动器总有可执行的结果
+        if results.is_empty() {
+            results =
+                self.vocabulary().suggest(query).iter().map(|q| suggestion_result(q)).collect();
+            results.sort_by_key(|b| std::cmp::Reverse(b.score));
+            results.extend(self.fallback_results(query));
+            results.truncate(limit);
+        }
+
+        results
+    }
+
+    /// 只搜索插件的结果，不含拼写纠正建议和兜底结果
+    fn search_plugins(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        self.load_for_query(query);
         let mut results = Vec::new();
 
-        for plugin in &self.plugins {
+        for plugin in &self.plugins() {
             if let Ok(guard) = plugin.lock() {
                 if guard.is_enabled() {
                     match guard.search(query, limit) {
@@ -98,6 +464,16 @@ impl PluginManager {
             }
         }
 
+        // 识别出意图时，提升对应插件的结果
+        if let Some(intent) = Intent::detect(query) {
+            for result in results.iter_mut().filter(|r| r.plugin_id() == intent.plugin_id()) {
+                result.score = result.score.saturating_add(INTENT_BOOST);
+            }
+        }
+
+        self.apply_priorities(&mut results);
+        self.apply_notes(query, &mut results);
+
         // 按分数排序
         results.sort_by_key(|b| std::cmp::Reverse(b.score));
         results.truncate(limit);
@@ -105,9 +481,121 @@ impl PluginManager {
         results
     }
 
+    /// 按插件页设置的优先级调整各插件结果的分数
+    fn apply_priorities(&self, results: &mut [SearchResult]) {
+        let priorities = global_config().plugin_priorities();
+        if priorities.is_empty() {
+            return;
+        }
+        for result in results {
+            if let Some(priority) = priorities.get(result.plugin_id()) {
+                result.score = result.score.saturating_add_signed(priority * PRIORITY_STEP);
+            }
+        }
+    }
+
+    /// 按配置的插件顺序收集兜底结果，未列出的插件不提供兜底结果
+    fn fallback_results(&self, query: &str) -> Vec<SearchResult> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let order = global_config().get_config().search.fallbacks;
+        let mut fallbacks: HashMap<String, Vec<SearchResult>> = HashMap::new();
+        for plugin in &self.plugins() {
+            if let Ok(guard) = plugin.lock() {
+                if guard.is_enabled() && order.iter().any(|id| id == guard.id()) {
+                    fallbacks.insert(guard.id().to_string(), guard.fallback(query));
+                }
+            }
+        }
+
+        order
+            .iter()
+            .filter_map(|id| fallbacks.remove(id))
+            .flatten()
+            .map(|result| SearchResult { score: 0, ..result })
+            .collect()
+    }
+
+    /// 备注匹配查询的结果加分；插件没有返回的，按添加备注时的标题向插件重新查找
+    fn apply_notes(&self, query: &str, results: &mut Vec<SearchResult>) {
+        let matched = global_notes().matching(query);
+        for note in matched.iter().take(MAX_NOTE_RESULTS) {
+            if results.iter().any(|r| r.id == note.result_id) {
+                continue;
+            }
+            let found = self
+                .search_plugin(&note.plugin_id, &note.title, BATCH_GROUP_LIMIT)
+                .into_iter()
+                .find(|r| r.id == note.result_id);
+            results.extend(found);
+        }
+
+        for result in results.iter_mut().filter(|r| matched.iter().any(|n| n.result_id == r.id)) {
+            result.score = result.score.saturating_add(NOTE_BOOST);
+        }
+    }
+
+    /// 拼写纠正词汇表，过期时重新从各插件收集
+    fn vocabulary(&self) -> Arc<Vocabulary> {
+        let mut cached = self.vocabulary.lock().unwrap();
+        if let Some((collected_at, vocabulary)) = cached.as_ref() {
+            if collected_at.elapsed() < VOCABULARY_TTL {
+                return vocabulary.clone();
+            }
+        }
+
+        let mut texts = Vec::new();
+        for plugin in &self.plugins() {
+            if let Ok(guard) = plugin.lock() {
+                if guard.is_enabled() {
+                    texts.extend(guard.vocabulary());
+                }
+            }
+        }
+        let vocabulary = Arc::new(Vocabulary::from_texts(texts));
+        log::debug!("已收集拼写纠正词汇: {} 个词", vocabulary.len());
+
+        *cached = Some((Instant::now(), vocabulary.clone()));
+        vocabulary
+    }
+
+    /// 多查询搜索
+    ///
+    /// 每个查询保留前几个结果作为一组；有两组以上命中时，
+    /// 在最前面加入"全部打开"，依次执行每组的首个结果。
+    /// 只取插件的结果，拼写纠正建议和兜底结果不能批量执行
+    fn search_batch(&self, queries: &[&str], limit: usize) -> Vec<SearchResult> {
+        let mut results = Vec::new();
+        let mut firsts = Vec::new();
+
+        for (index, query) in queries.iter().enumerate() {
+            for (rank, mut result) in
+                self.search_plugins(query, BATCH_GROUP_LIMIT).into_iter().enumerate()
+            {
+                result.highlight(query);
+                let result = result.with_group(index, query);
+                if rank == 0 {
+                    firsts.push(result.clone());
+                }
+                results.push(result);
+            }
+        }
+
+        if firsts.len() >= 2 {
+            results.insert(0, SearchResult::batch(firsts));
+        }
+        results.truncate(limit);
+
+        results
+    }
+
     /// 搜索指定插件
     pub fn search_plugin(&self, plugin_id: &str, query: &str, limit: usize) -> Vec<SearchResult> {
-        for plugin in &self.plugins {
+        self.load_lazy(|plugin| plugin.id == plugin_id);
+        for plugin in &self.plugins() {
             if let Ok(guard) = plugin.lock() {
                 if guard.id() == plugin_id && guard.is_enabled() {
                     match guard.search(query, limit) {
@@ -122,44 +610,266 @@ impl PluginManager {
         Vec::new()
     }
 
-    /// 获取所有插件ID列表
+    /// 获取指定插件的默认结果
+    pub fn default_results(&self, plugin_id: &str, limit: usize) -> Vec<SearchResult> {
+        self.load_lazy(|plugin| plugin.id == plugin_id);
+        for plugin in &self.plugins() {
+            if let Ok(guard) = plugin.lock() {
+                if guard.id() == plugin_id && guard.is_enabled() {
+                    match guard.default_results(limit) {
+                        Ok(results) => return results,
+                        Err(e) => {
+                            log::error!("插件 {} 获取默认结果失败: {:?}", guard.name(), e);
+                        },
+                    }
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    /// 收集各插件针对前台应用的上下文结果，按分数排序
+    pub fn contextual_results(
+        &self,
+        foreground: &ForegroundInfo,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let process = foreground.process.to_lowercase();
+        self.load_lazy(|plugin| plugin.processes.contains(&process));
+
+        let mut results = Vec::new();
+        for plugin in &self.plugins() {
+            if let Ok(guard) = plugin.lock() {
+                if guard.is_enabled() {
+                    match guard.contextual_results(foreground, limit) {
+                        Ok(mut plugin_results) => results.append(&mut plugin_results),
+                        Err(e) => {
+                            log::error!("插件 {} 获取上下文结果失败: {:?}", guard.name(), e);
+                        },
+                    }
+                }
+            }
+        }
+
+        results.sort_by_key(|b| std::cmp::Reverse(b.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 获取所有插件ID列表（尚未加载的插件排在最后）
     pub fn get_plugin_ids(&self) -> Vec<String> {
         let mut ids = Vec::new();
-        for plugin in &self.plugins {
+        for plugin in &self.plugins() {
             if let Ok(guard) = plugin.lock() {
                 if guard.is_enabled() {
                     ids.push(guard.id().to_string());
                 }
             }
         }
+        let config = global_config();
+        let lazy = self.lazy.lock().unwrap();
+        let lazy_ids = lazy.iter().map(|plugin| plugin.id.clone());
+        ids.extend(lazy_ids.filter(|id| config.is_plugin_enabled(id)));
         ids
     }
 
-    /// 根据前缀模糊匹配插件ID
+    /// 获取插件名称
+    pub fn plugin_name(&self, plugin_id: &str) -> Option<String> {
+        self.find_plugin(plugin_id, |plugin| plugin.name().to_string())
+    }
+
+    /// 获取插件图标
+    pub fn plugin_icon(&self, plugin_id: &str) -> Option<PluginIcon> {
+        self.find_plugin(plugin_id, |plugin| plugin.icon())
+    }
+
+    /// 获取插件模式下输入框的占位提示
+    pub fn plugin_placeholder(&self, plugin_id: &str) -> Option<String> {
+        self.find_plugin(plugin_id, |plugin| match plugin.placeholder() {
+            Some(placeholder) => placeholder.to_string(),
+            None => format!("在{}中搜索…", plugin.name()),
+        })
+    }
+
+    /// 查找指定 ID 的插件并读取信息
+    fn find_plugin<T>(&self, plugin_id: &str, read: impl Fn(&dyn Plugin) -> T) -> Option<T> {
+        self.plugins().iter().find_map(|plugin| {
+            let guard = plugin.lock().ok()?;
+            (guard.id() == plugin_id).then(|| read(&*guard))
+        })
+    }
+
+    /// 刷新所有已加载的插件（重新扫描应用、文件索引等），单个插件失败不影响其余插件
+    pub fn refresh_all(&self) {
+        for plugin in &self.plugins() {
+            if let Ok(mut guard) = plugin.lock() {
+                if let Err(source) = guard.refresh() {
+                    WerunError::PluginInit { plugin: guard.id().to_string(), source }.log();
+                }
+            }
+        }
+    }
+
+    /// 通知已加载的插件启动器窗口已显示
+    pub fn notify_activated(&self) {
+        for plugin in &self.plugins() {
+            if let Ok(mut guard) = plugin.lock() {
+                guard.on_activate();
+            }
+        }
+    }
+
+    /// 根据前缀模糊匹配插件ID，也匹配插件页设置的别名
     pub fn match_plugin_ids(&self, prefix: &str) -> Vec<String> {
         let prefix_lower = prefix.to_lowercase();
+        let keywords = global_config().plugin_keywords();
+        let matches = |name: &str| name.to_lowercase().starts_with(&prefix_lower);
         self.get_plugin_ids()
             .into_iter()
-            .filter(|id| id.to_lowercase().starts_with(&prefix_lower))
+            .filter(|id| {
+                matches(id)
+                    || keywords.get(id).is_some_and(|words| words.iter().any(|w| matches(w)))
+            })
             .collect()
     }
 
+    /// 插件页列出的所有插件（包括已关闭的插件）
+    ///
+    /// 尚未加载的插件不初始化，按配置文件给出启用状态和设置项的值
+    pub fn plugin_infos(&self) -> Vec<PluginInfo> {
+        let mut infos: Vec<PluginInfo> = self
+            .plugins()
+            .iter()
+            .filter_map(|plugin| Some(plugin_info(&*plugin.lock().ok()?)))
+            .collect();
+        infos.extend(self.lazy.lock().unwrap().iter().filter_map(lazy_plugin_info));
+        infos
+    }
+
+    /// 指定插件的信息
+    pub fn plugin_info(&self, plugin_id: &str) -> Option<PluginInfo> {
+        let loaded = self.plugins().into_iter().find_map(|plugin| {
+            let guard = plugin.lock().ok()?;
+            (guard.id() == plugin_id).then(|| plugin_info(&*guard))
+        });
+        loaded.or_else(|| {
+            let lazy = self.lazy.lock().unwrap();
+            lazy.iter().filter(|plugin| plugin.id == plugin_id).find_map(lazy_plugin_info)
+        })
+    }
+
+    /// 启用或关闭插件并写入配置
+    pub fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> error::Result<()> {
+        global_config().set_plugin_enabled(plugin_id, enabled)?;
+        self.with_plugin(plugin_id, |plugin| {
+            plugin.set_enabled(enabled);
+            Ok(())
+        })
+    }
+
+    /// 重新加载插件：刷新插件数据并重新读取插件设置
+    pub fn reload_plugin(&self, plugin_id: &str) -> error::Result<()> {
+        self.with_plugin(plugin_id, |plugin| {
+            plugin
+                .refresh()
+                .map_err(|source| WerunError::PluginInit { plugin: plugin_id.to_string(), source })
+        })
+    }
+
+    /// 对指定 ID 的已加载插件执行操作；尚未加载的插件在加载时才读取配置，无需操作
+    fn with_plugin(
+        &self,
+        plugin_id: &str,
+        f: impl FnOnce(&mut dyn Plugin) -> error::Result<()>,
+    ) -> error::Result<()> {
+        let Some(plugin) = self
+            .plugins()
+            .into_iter()
+            .find(|plugin| plugin.lock().is_ok_and(|guard| guard.id() == plugin_id))
+        else {
+            if self.lazy.lock().unwrap().iter().any(|plugin| plugin.id == plugin_id) {
+                return Ok(());
+            }
+            return Err(WerunError::PluginNotFound(plugin_id.to_string()));
+        };
+        let mut guard =
+            plugin.lock().map_err(|_| WerunError::PluginNotFound(plugin_id.to_string()))?;
+        f(&mut *guard)
+    }
+
     /// 执行结果
-    pub fn execute(&self, result: &SearchResult) -> Result<()> {
+    pub fn execute(&self, result: &SearchResult) -> error::Result<()> {
+        // 批量结果逐个执行，单个失败不影响其余结果，返回第一个错误
+        if let ActionData::Batch { results } = &result.action {
+            let mut first_error = None;
+            for item in results {
+                if let Err(e) = self.execute(item) {
+                    match first_error {
+                        None => first_error = Some(e),
+                        Some(_) => e.log(),
+                    }
+                }
+            }
+            return first_error.map_or(Ok(()), Err);
+        }
+
+        // 提权启动与产生结果的插件无关，统一通过 runas 动词启动
+        if let ActionData::LaunchApp { path, args, elevated: true } = &result.action {
+            return elevation::run_elevated(path, &elevation::join_args(args), None);
+        }
+
         // 根据 ID 前缀找到对应的插件
-        for plugin in &self.plugins {
+        for plugin in &self.plugins() {
             if let Ok(guard) = plugin.lock() {
                 let plugin_id = guard.id();
                 // 支持两种匹配方式：
                 // 1. result.id 以 "plugin_id:" 开头
                 // 2. result.id 等于 plugin_id
                 if result.id.starts_with(&format!("{}:", plugin_id)) || result.id == plugin_id {
-                    return guard.execute(result);
+                    return guard.execute(result).map_err(|source| WerunError::PluginExecute {
+                        plugin: plugin_id.to_string(),
+                        source,
+                    });
                 }
             }
         }
 
-        Err(anyhow::anyhow!("未找到对应的插件"))
+        Err(WerunError::PluginNotFound(result.id.clone()))
+    }
+}
+
+/// 插件页显示的插件信息
+fn plugin_info(plugin: &dyn Plugin) -> PluginInfo {
+    PluginInfo {
+        id: plugin.id().to_string(),
+        name: plugin.name().to_string(),
+        description: plugin.description().to_string(),
+        version: plugin.version().to_string(),
+        icon: plugin.icon(),
+        enabled: plugin.is_enabled(),
+        settings: plugin.settings(),
+    }
+}
+
+/// 尚未加载的插件的信息：构造不初始化的实例，启用状态和设置项的值从配置文件读取
+fn lazy_plugin_info(lazy: &LazyPlugin) -> Option<PluginInfo> {
+    let plugin = (lazy.factory)();
+    let mut info = plugin_info(&*plugin.lock().ok()?);
+    info.enabled = global_config().is_plugin_enabled(&info.id);
+    let configured = global_config().plugin_settings(&info.id);
+    for field in &mut info.settings {
+        if let Some(value) = configured.get(field.key).and_then(|json| field.value.parse_json(json))
+        {
+            field.value = value;
+        }
+    }
+    Some(info)
+}
+
+/// 在插件页关闭的插件加载后立即停用
+fn apply_disabled(plugin: &mut dyn Plugin) {
+    if !global_config().is_plugin_enabled(plugin.id()) {
+        plugin.set_enabled(false);
     }
 }
 
@@ -168,3 +878,84 @@ impl Default for PluginManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::spelling::SUGGESTION_ID_PREFIX,
+        plugins::{stress_test::StressTestPlugin, web_search::WebSearchPlugin},
+    };
+
+    #[test]
+    fn test_lazy_plugin_loads_on_keyword() {
+        let mut manager = PluginManager::new();
+        manager.register_lazy("stress_test", &["stress"], StressTestPlugin::new);
+        assert_eq!(manager.plugin_count(), 1);
+        assert_eq!(manager.get_plugin_ids(), ["stress_test"]);
+
+        // 插件页读取信息不加载插件
+        assert_eq!(manager.plugin_infos()[0].name, "压力测试");
+        assert!(manager.plugin_info("stress_test").is_some_and(|info| info.enabled));
+        assert_eq!(manager.lazy_plugin_count(), 1);
+
+        // 其他查询不加载插件
+        assert!(manager.search_all("hello", 10).is_empty());
+        assert_eq!(manager.lazy_plugin_count(), 1);
+
+        assert!(!manager.search_all("stress 3", 10).is_empty());
+        assert_eq!(manager.lazy_plugin_count(), 0);
+        assert_eq!(manager.plugin_count(), 1);
+    }
+
+    #[test]
+    fn test_fallback_when_no_results() {
+        let mut manager = PluginManager::new();
+        manager.register(WebSearchPlugin::new());
+
+        // 带前缀的查询由插件直接响应
+        let results = manager.search_all("g rust", 10);
+        assert_eq!(results[0].id, "web_search:google:rust");
+
+        let results = manager.search_all("qwzx", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!((results[0].id.as_str(), results[0].score), ("web_search:fallback:qwzx", 0));
+        assert!(manager.search_all("  ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_batch_skips_fallback_results() {
+        let mut manager = PluginManager::new();
+        manager.register(WebSearchPlugin::new());
+
+        // 未命中的子查询不产生兜底结果，也不进入"全部打开"
+        let results = manager.search_all("g rust; qwzx; g go", 10);
+        let ActionData::Batch { results: firsts } = &results[0].action else {
+            panic!("缺少全部打开: {:?}", results[0].id);
+        };
+        let ids: Vec<&str> = firsts.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["web_search:google:rust", "web_search:google:go"]);
+        assert!(results
+            .iter()
+            .all(|r| !r.id.contains("fallback") && !r.id.starts_with(SUGGESTION_ID_PREFIX)));
+    }
+
+    #[test]
+    fn test_setting_value_parse_text() {
+        let integer = SettingValue::Integer { value: 24, min: 1, max: 720 };
+        assert_eq!(integer.to_text(), "24");
+        assert_eq!(
+            integer.parse_text(" 9999 "),
+            Some(SettingValue::Integer { value: 720, min: 1, max: 720 })
+        );
+        assert_eq!(integer.parse_text("abc"), None);
+        assert_eq!(integer.parse_json(&serde_json::json!(0)), integer.parse_text("0"));
+        assert_eq!(integer.parse_json(&serde_json::json!("9")), None);
+
+        let list = SettingValue::List(Vec::new());
+        let parsed = list.parse_text("CNY, USD，,EUR").unwrap();
+        assert_eq!(parsed.to_json(), serde_json::json!(["CNY", "USD", "EUR"]));
+        assert_eq!(parsed.to_text(), "CNY, USD, EUR");
+        assert_eq!(SettingValue::Bool(false).parse_text("true"), Some(SettingValue::Bool(true)));
+    }
+}
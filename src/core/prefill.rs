@@ -0,0 +1,57 @@
+/// 预填查询
+///
+/// 唤出启动器时用前台程序中选中的文本或剪贴板内容预填搜索框，
+/// 配合前缀直接进入翻译、查词、网页搜索等流程
+use crate::core::config::{PrefillConfig, PrefillSource};
+
+/// 预填内容的最大字符数，超出部分截断
+const MAX_PREFILL_CHARS: usize = 200;
+
+/// 按配置的来源选择预填内容，整理为单行并加上前缀；没有可用内容时返回 `None`
+pub fn prefill_query(
+    config: &PrefillConfig,
+    selection: Option<&str>,
+    clipboard: Option<&str>,
+) -> Option<String> {
+    let selection = selection.filter(|text| !text.trim().is_empty());
+    let text = match config.source {
+        PrefillSource::Selection => selection,
+        PrefillSource::Clipboard => clipboard,
+        PrefillSource::SelectionOrClipboard => selection.or(clipboard),
+    }?;
+
+    // 搜索框只有一行，换行和连续空白合并为一个空格
+    let text: String = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_PREFILL_CHARS)
+        .collect();
+    (!text.is_empty()).then(|| format!("{}{}", config.prefix, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefill_query() {
+        let mut config = PrefillConfig::default();
+        assert_eq!(
+            prefill_query(&config, Some("  hello\r\n world "), Some("clip")),
+            Some("hello world".to_string())
+        );
+        assert_eq!(prefill_query(&config, Some(" "), Some("clip")), Some("clip".to_string()));
+        assert_eq!(prefill_query(&config, None, None), None);
+
+        config.source = PrefillSource::Selection;
+        config.prefix = "g ".to_string();
+        assert_eq!(prefill_query(&config, None, Some("clip")), None);
+        assert_eq!(prefill_query(&config, Some("rust"), None), Some("g rust".to_string()));
+
+        let long = "字".repeat(MAX_PREFILL_CHARS + 10);
+        let query = prefill_query(&config, Some(&long), None).unwrap();
+        assert_eq!(query.chars().count(), MAX_PREFILL_CHARS + 2);
+    }
+}
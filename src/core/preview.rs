@@ -0,0 +1,125 @@
+/// 富预览
+///
+/// 插件可以为结果附带结构化的详情（分节的键值行），选中该结果时由预览面板渲染，
+/// 如计算器的表达式树和计算步骤；也可以附带由界面绘制的可视内容，如颜色色块，
+/// 以及正文，如文件的前若干行或图片缩略图
+use std::path::PathBuf;
+
+/// 结果的预览内容
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Preview {
+    /// 可视内容，在预览面板顶部绘制，结果行中代替图标显示
+    pub visual: Option<PreviewVisual>,
+    /// 正文，显示在各分节之前
+    pub body: Option<PreviewBody>,
+    /// 各分节
+    pub sections: Vec<PreviewSection>,
+}
+
+impl Preview {
+    /// 创建空预览
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加分节，空分节会被忽略
+    pub fn section(mut self, title: impl Into<String>, rows: Vec<PreviewRow>) -> Self {
+        if !rows.is_empty() {
+            self.sections.push(PreviewSection { title: title.into(), rows });
+        }
+        self
+    }
+
+    /// 设置可视内容
+    pub fn visual(mut self, visual: PreviewVisual) -> Self {
+        self.visual = Some(visual);
+        self
+    }
+
+    /// 设置正文
+    pub fn body(mut self, body: PreviewBody) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// 是否没有任何内容
+    pub fn is_empty(&self) -> bool {
+        self.visual.is_none() && self.body.is_none() && self.sections.is_empty()
+    }
+}
+
+/// 预览中的可视内容
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreviewVisual {
+    /// 纯色色块
+    Color(u8, u8, u8),
+}
+
+/// 预览正文
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewBody {
+    /// 按语法着色的文本行
+    Code(Vec<CodeLine>),
+    /// 图片缩略图
+    Image(PathBuf),
+}
+
+/// 着色的一行文本
+pub type CodeLine = Vec<CodeSpan>;
+
+/// 同一种着色的一段文本
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeSpan {
+    /// 文本
+    pub text: String,
+    /// 着色类别
+    pub kind: TokenKind,
+}
+
+/// 语法着色类别
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// 普通文本
+    Plain,
+    /// 关键字
+    Keyword,
+    /// 字符串
+    String,
+    /// 数字
+    Number,
+    /// 注释
+    Comment,
+}
+
+/// 预览分节
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreviewSection {
+    /// 分节标题
+    pub title: String,
+    /// 分节内容
+    pub rows: Vec<PreviewRow>,
+}
+
+/// 预览行
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreviewRow {
+    /// 名称
+    pub label: String,
+    /// 值
+    pub value: String,
+    /// 缩进层级（用于树形结构）
+    pub depth: usize,
+}
+
+impl PreviewRow {
+    /// 创建预览行
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self { label: label.into(), value: value.into(), depth: 0 }
+    }
+
+    /// 设置缩进层级
+    pub fn indented(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+}
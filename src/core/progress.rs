@@ -0,0 +1,197 @@
+/// 后台任务进度模块
+///
+/// 插件在后台建立索引（文件、应用等）时通过共享的进度报告器登记任务，
+/// 界面据此显示状态行并支持取消，避免预热期间静默返回不完整的结果
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use once_cell::sync::Lazy;
+
+/// 后台任务
+pub struct ProgressTask {
+    /// 任务 ID（同一 ID 同时只有一个任务）
+    id: String,
+    /// 显示名称，如 "正在索引文件"
+    label: String,
+    /// 预计总数（0 表示未知）
+    total: AtomicUsize,
+    /// 已完成数量
+    done: AtomicUsize,
+    /// 是否已请求取消
+    cancelled: AtomicBool,
+    /// 是否已结束
+    finished: AtomicBool,
+}
+
+impl ProgressTask {
+    /// 创建任务（不登记到报告器）
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            total: AtomicUsize::new(0),
+            done: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+            finished: AtomicBool::new(false),
+        }
+    }
+
+    /// 设置预计总数
+    pub fn set_total(&self, total: usize) {
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    /// 增加已完成数量
+    pub fn advance(&self, count: usize) {
+        self.done.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 已完成数量
+    pub fn done(&self) -> usize {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    /// 请求取消，由任务自行在合适的位置检查并退出
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// 是否已请求取消
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// 标记任务结束
+    pub fn finish(&self) {
+        self.finished.store(true, Ordering::Relaxed);
+    }
+
+    /// 是否已结束
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    /// 完成百分比，总数未知时为 `None`
+    ///
+    /// 总数通常是估计值（如上次索引的数量），结束前最多显示 99%
+    pub fn percent(&self) -> Option<u8> {
+        let total = self.total.load(Ordering::Relaxed);
+        if total == 0 {
+            return None;
+        }
+
+        let percent = (self.done() * 100 / total).min(99);
+        Some(percent as u8)
+    }
+
+    /// 状态文本，如 "正在索引文件… 42%"
+    pub fn status(&self) -> String {
+        match self.percent() {
+            Some(percent) => format!("{}… {}%", self.label, percent),
+            None => format!("{}… 已处理 {} 项", self.label, self.done()),
+        }
+    }
+}
+
+/// 进度报告器
+#[derive(Default)]
+pub struct ProgressReporter {
+    /// 进行中的任务
+    tasks: Mutex<Vec<Arc<ProgressTask>>>,
+    /// 后台任务是否更新了已显示的结果
+    results_changed: AtomicBool,
+}
+
+impl ProgressReporter {
+    /// 创建新的进度报告器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记新任务，取消同一 ID 下仍在进行的旧任务
+    pub fn start(&self, id: &str, label: &str) -> Arc<ProgressTask> {
+        let task = Arc::new(ProgressTask::new(id, label));
+        let mut tasks = self.tasks.lock().unwrap();
+
+        tasks.retain(|t| {
+            if t.id == id {
+                t.cancel();
+            }
+            t.id != id && !t.is_finished()
+        });
+        tasks.push(task.clone());
+
+        task
+    }
+
+    /// 进行中的任务
+    pub fn active(&self) -> Vec<Arc<ProgressTask>> {
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.retain(|t| !t.is_finished());
+        tasks.clone()
+    }
+
+    /// 是否有进行中的任务
+    pub fn is_busy(&self) -> bool {
+        !self.active().is_empty()
+    }
+
+    /// 取消所有进行中的任务
+    pub fn cancel_all(&self) {
+        for task in self.active() {
+            log::info!("取消后台任务: {}", task.label);
+            task.cancel();
+        }
+    }
+
+    /// 通知界面后台任务更新了结果（如 ping 的实时延迟），界面随后重新搜索当前查询
+    pub fn mark_results_changed(&self) {
+        self.results_changed.store(true, Ordering::Relaxed);
+    }
+
+    /// 取出并清除结果更新标记
+    pub fn take_results_changed(&self) -> bool {
+        self.results_changed.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// 全局进度报告器
+static GLOBAL_PROGRESS: Lazy<ProgressReporter> = Lazy::new(ProgressReporter::new);
+
+/// 获取全局进度报告器
+pub fn global_progress() -> &'static ProgressReporter {
+    &GLOBAL_PROGRESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_status_and_replace() {
+        let reporter = ProgressReporter::new();
+        let first = reporter.start("files", "正在索引文件");
+        first.set_total(200);
+        first.advance(84);
+        assert_eq!(first.status(), "正在索引文件… 42%");
+
+        // 同一 ID 重新开始时取消旧任务
+        let second = reporter.start("files", "正在索引文件");
+        assert!(first.is_cancelled());
+        assert_eq!(second.status(), "正在索引文件… 已处理 0 项");
+        assert_eq!(reporter.active().len(), 1);
+
+        second.advance(500);
+        second.set_total(100);
+        assert_eq!(second.percent(), Some(99));
+
+        second.finish();
+        assert!(!reporter.is_busy());
+
+        reporter.mark_results_changed();
+        assert!(reporter.take_results_changed());
+        assert!(!reporter.take_results_changed());
+    }
+}
@@ -0,0 +1,241 @@
+/// 查询解析
+///
+/// 识别查询中的过滤标记：`ext:pdf` 按扩展名过滤、`in:Downloads` 按所在目录过滤，
+/// 开头的 `/插件` 限定搜索的插件；其余部分为搜索文本。
+/// 过滤标记仍保留在输入框文本中，搜索栏据此将其显示为可移除的标签
+use std::ops::Range;
+
+/// 过滤标记类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterKind {
+    /// 扩展名（`ext:pdf`，多个用逗号分隔）
+    Extension,
+    /// 所在目录（`in:Downloads`，含空格时加引号）
+    Folder,
+    /// 插件关键词（查询开头的 `/files`）
+    Plugin,
+}
+
+/// 查询中的过滤标记
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryFilter {
+    /// 类型
+    pub kind: FilterKind,
+    /// 值（不含前缀和引号）
+    pub value: String,
+    /// 在原始查询中的字节范围
+    pub range: Range<usize>,
+    /// 后面是否已输入空白；未完成的标记仍在输入中，不显示为标签
+    pub committed: bool,
+}
+
+impl QueryFilter {
+    /// 标签文本
+    pub fn label(&self) -> String {
+        match self.kind {
+            FilterKind::Extension => {
+                self.value.split(',').map(|ext| format!(".{}", ext)).collect::<Vec<_>>().join(" ")
+            },
+            FilterKind::Folder => format!("在 {}", self.value),
+            FilterKind::Plugin => format!("/{}", self.value),
+        }
+    }
+}
+
+/// 解析后的查询
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    /// 去掉过滤标记后的搜索文本
+    pub text: String,
+    /// 过滤标记，按出现顺序
+    pub filters: Vec<QueryFilter>,
+}
+
+impl ParsedQuery {
+    /// 解析查询
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        let mut words = Vec::new();
+
+        for (range, word) in split_words(query) {
+            let committed = query[range.end..].starts_with(char::is_whitespace);
+            let filter = |kind, value: &str| QueryFilter {
+                kind,
+                value: value.trim_matches('"').to_string(),
+                range: range.clone(),
+                committed,
+            };
+
+            let lower = word.to_lowercase();
+            if range.start == 0 && word.len() > 1 && word.starts_with('/') {
+                parsed.filters.push(filter(FilterKind::Plugin, &word[1..]));
+            } else if lower.starts_with("ext:") && word.len() > 4 {
+                let extensions: Vec<_> = lower[4..]
+                    .split(',')
+                    .map(|ext| ext.trim_start_matches('.'))
+                    .filter(|ext| !ext.is_empty())
+                    .collect();
+                if extensions.is_empty() {
+                    words.push(word);
+                    continue;
+                }
+                parsed.filters.push(filter(FilterKind::Extension, &extensions.join(",")));
+            } else if lower.starts_with("in:") && !word[3..].trim_matches('"').is_empty() {
+                parsed.filters.push(filter(FilterKind::Folder, &word[3..]));
+            } else {
+                words.push(word);
+            }
+        }
+
+        parsed.text = words.join(" ");
+        parsed
+    }
+
+    /// 插件关键词
+    pub fn plugin(&self) -> Option<&str> {
+        self.filters
+            .iter()
+            .find(|filter| filter.kind == FilterKind::Plugin)
+            .map(|filter| filter.value.as_str())
+    }
+
+    /// 是否含有文件过滤标记（扩展名或目录）
+    pub fn has_file_filters(&self) -> bool {
+        self.filters.iter().any(|filter| filter.kind != FilterKind::Plugin)
+    }
+
+    /// 路径是否满足所有文件过滤标记
+    ///
+    /// 多个扩展名之间为“或”，不同的过滤标记之间为“且”；目录名按路径中的某一级目录匹配，
+    /// 含路径分隔符时按目录路径的一部分匹配，均不区分大小写
+    pub fn matches_path(&self, path: &str) -> bool {
+        let path = path.replace('/', "\\").to_lowercase();
+        let (dir, name) = path.rsplit_once('\\').unwrap_or(("", &path));
+
+        self.filters.iter().all(|filter| match filter.kind {
+            FilterKind::Extension => {
+                let ext = name.rsplit_once('.').map_or("", |(_, ext)| ext);
+                filter.value.split(',').any(|wanted| wanted == ext)
+            },
+            FilterKind::Folder => {
+                let wanted = filter.value.replace('/', "\\").to_lowercase();
+                let wanted = wanted.trim_matches('\\');
+                if wanted.contains('\\') {
+                    dir.contains(wanted)
+                } else {
+                    dir.split('\\').any(|component| component == wanted)
+                }
+            },
+            FilterKind::Plugin => true,
+        })
+    }
+}
+
+/// 从查询中移除过滤标记及其后的空白
+pub fn remove_filter(query: &str, filter: &QueryFilter) -> String {
+    let rest = &query[filter.range.end..];
+    let end = query.len() - rest.trim_start().len();
+    format!("{}{}", &query[..filter.range.start], &query[end..])
+}
+
+/// 将删除过滤标记中任一字符的编辑扩展为删除整个标记
+///
+/// 搜索栏中已完成的过滤标记作为整体编辑：在标签后按退格（删除其后的空白）
+/// 或删除标记中的字符时，返回移除整个标记后的查询；其他编辑返回 `None`
+pub fn collapse_deleted_filter(previous: &str, current: &str) -> Option<String> {
+    // 只处理删除单个字符的编辑
+    let prefix = previous
+        .char_indices()
+        .zip(current.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(current.len(), |((index, _), _)| index);
+    let removed = previous[prefix..].chars().next()?;
+    if previous.len() != current.len() + removed.len_utf8()
+        || previous[prefix + removed.len_utf8()..] != current[prefix..]
+    {
+        return None;
+    }
+
+    // 删除的是标记中的字符，或紧跟在标记后的空白
+    ParsedQuery::parse(previous)
+        .filters
+        .iter()
+        .find(|filter| {
+            filter.committed && (filter.range.contains(&prefix) || filter.range.end == prefix)
+        })
+        .map(|filter| remove_filter(previous, filter))
+}
+
+/// 按空白切分查询，引号内的空白不切分
+fn split_words(query: &str) -> Vec<(Range<usize>, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+
+    for (index, c) in query.char_indices() {
+        match (start, c) {
+            (_, '"') => {
+                quoted = !quoted;
+                start.get_or_insert(index);
+            },
+            (Some(begin), c) if c.is_whitespace() && !quoted => {
+                words.push((begin..index, &query[begin..index]));
+                start = None;
+            },
+            (None, c) if !c.is_whitespace() => start = Some(index),
+            _ => {},
+        }
+    }
+    if let Some(begin) = start {
+        words.push((begin..query.len(), &query[begin..]));
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filters() {
+        let parsed = ParsedQuery::parse("/files report ext:PDF,.docx in:\"My Docs\" draft");
+        assert_eq!(parsed.text, "report draft");
+        assert_eq!(parsed.plugin(), Some("files"));
+
+        let kinds: Vec<_> = parsed.filters.iter().map(|f| (f.kind, f.value.as_str())).collect();
+        assert_eq!(kinds, [
+            (FilterKind::Plugin, "files"),
+            (FilterKind::Extension, "pdf,docx"),
+            (FilterKind::Folder, "My Docs"),
+        ]);
+        assert_eq!(parsed.filters[1].label(), ".pdf .docx");
+        assert!(parsed.filters.iter().all(|f| f.committed));
+
+        // 空值和结尾未完成的标记
+        let parsed = ParsedQuery::parse("ext: in:Down");
+        assert_eq!(parsed.text, "ext:");
+        assert!(!parsed.filters[0].committed);
+
+        assert!(ParsedQuery::parse("ext:pdf in:docs").matches_path("C:/Users/a/Docs/x.PDF"));
+        assert!(!ParsedQuery::parse("ext:pdf in:docs").matches_path("C:/docs2/x.pdf"));
+        assert!(ParsedQuery::parse("in:a/b").matches_path("D:\\x\\A\\B\\c\\f.txt"));
+    }
+
+    #[test]
+    fn test_remove_and_collapse() {
+        let query = "report ext:pdf in:Downloads";
+        let parsed = ParsedQuery::parse(query);
+        assert_eq!(remove_filter(query, &parsed.filters[0]), "report in:Downloads");
+        assert_eq!(remove_filter(query, &parsed.filters[1]), "report ext:pdf ");
+
+        // 标签后按退格删除整个标记，未完成的标记正常编辑
+        assert_eq!(
+            collapse_deleted_filter("report ext:pdf ", "report ext:pdf").as_deref(),
+            Some("report ")
+        );
+        assert_eq!(collapse_deleted_filter("ext:pdf x", "ext:df x").as_deref(), Some("x"));
+        assert_eq!(collapse_deleted_filter("report ext:pdf", "report ext:pd"), None);
+        assert_eq!(collapse_deleted_filter("ext:pdf ab", "ext:pdf a"), None);
+    }
+}
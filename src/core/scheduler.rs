@@ -0,0 +1,96 @@
+/// 后台任务调度模块
+///
+/// 统一判断当前是否处于免打扰时段：时段内后台刷新推迟到时段结束后执行，
+/// 非关键通知不显示，避免演示时唤醒磁盘或弹出通知
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+
+use crate::core::config_manager::global_config;
+
+/// 检查免打扰时段是否结束的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 推迟执行的后台任务
+struct DeferredJob {
+    /// 任务 ID（同一 ID 只保留最新的任务）
+    id: String,
+    /// 任务内容
+    job: Box<dyn FnOnce() + Send>,
+}
+
+/// 后台任务调度器
+#[derive(Default)]
+pub struct BackgroundScheduler {
+    /// 推迟到免打扰时段结束后执行的任务
+    deferred: Mutex<Vec<DeferredJob>>,
+    /// 检查线程是否在运行
+    checking: AtomicBool,
+}
+
+impl BackgroundScheduler {
+    /// 创建新的调度器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 当前是否处于免打扰时段
+    pub fn is_quiet(&self) -> bool {
+        global_config().quiet_hours().contains(chrono::Local::now().time())
+    }
+
+    /// 是否允许显示通知，关键通知不受免打扰时段限制
+    pub fn allows_notification(&self, critical: bool) -> bool {
+        critical || !self.is_quiet()
+    }
+
+    /// 执行后台任务，免打扰时段内推迟到时段结束后执行
+    ///
+    /// 同一 ID 的任务推迟期间只保留最新的一个，如多次触发的重新扫描只执行一次
+    pub fn run_or_defer(&self, id: &str, job: impl FnOnce() + Send + 'static) {
+        if !self.is_quiet() {
+            job();
+            return;
+        }
+
+        log::info!("免打扰时段，推迟后台任务: {}", id);
+        {
+            let mut deferred = self.deferred.lock().unwrap();
+            deferred.retain(|d| d.id != id);
+            deferred.push(DeferredJob { id: id.to_string(), job: Box::new(job) });
+        }
+
+        if !self.checking.swap(true, Ordering::SeqCst) {
+            std::thread::spawn(|| global_scheduler().wait_for_quiet_end());
+        }
+    }
+
+    /// 等待免打扰时段结束后执行推迟的任务
+    fn wait_for_quiet_end(&self) {
+        while self.is_quiet() {
+            std::thread::sleep(CHECK_INTERVAL);
+        }
+
+        self.checking.store(false, Ordering::SeqCst);
+        let jobs = std::mem::take(&mut *self.deferred.lock().unwrap());
+        log::info!("免打扰时段结束，执行 {} 个推迟的后台任务", jobs.len());
+        for DeferredJob { id, job } in jobs {
+            log::debug!("执行推迟的后台任务: {}", id);
+            job();
+        }
+    }
+}
+
+/// 全局后台任务调度器
+static GLOBAL_SCHEDULER: Lazy<BackgroundScheduler> = Lazy::new(BackgroundScheduler::new);
+
+/// 获取全局后台任务调度器
+pub fn global_scheduler() -> &'static BackgroundScheduler {
+    &GLOBAL_SCHEDULER
+}
@@ -1,7 +1,10 @@
 /// 搜索引擎模块
 ///
 /// 提供高性能的模糊搜索功能
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
+
+use super::preview::Preview;
+use crate::utils::{fuzzy, timezone, units};
 
 /// 搜索结果项
 #[derive(Clone, Debug)]
@@ -20,10 +23,26 @@ pub struct SearchResult {
     pub score: u32,
     /// 动作数据
     pub action: ActionData,
-    /// 高亮显示的标题（包含匹配标记）
-    pub highlighted_title: Option<String>,
-    /// 高亮显示的描述（包含匹配标记）
-    pub highlighted_description: Option<String>,
+    /// 标题中匹配字符的字节范围
+    pub title_highlights: Option<Vec<Range<usize>>>,
+    /// 描述中匹配字符的字节范围
+    pub description_highlights: Option<Vec<Range<usize>>>,
+    /// 排序键（由插件提供，用于非相关度排序）
+    pub sort_keys: SortKeys,
+    /// 是否为破坏性操作（关机、重启等），界面以危险色标出
+    pub destructive: bool,
+    /// 次要动作（在动作面板中列出）
+    pub secondary_actions: Vec<SecondaryAction>,
+    /// 多查询搜索时所属的分组
+    pub group: Option<ResultGroup>,
+    /// 富预览（选中时在预览面板中显示）
+    pub preview: Option<Preview>,
+    /// 执行后粘贴到启动器显示前的前台窗口
+    pub paste: bool,
+    /// 包含敏感内容（密码等），执行后不记录到历史
+    pub sensitive: bool,
+    /// 用户为该结果添加的备注（由备注模块附加）
+    pub note: Option<String>,
 }
 
 impl SearchResult {
@@ -44,37 +63,349 @@ impl SearchResult {
             result_type,
             score,
             action,
-            highlighted_title: None,
-            highlighted_description: None,
+            title_highlights: None,
+            description_highlights: None,
+            sort_keys: SortKeys::default(),
+            destructive: false,
+            secondary_actions: Vec::new(),
+            group: None,
+            preview: None,
+            paste: false,
+            sensitive: false,
+            note: None,
         }
     }
 
-    /// 设置高亮显示的标题
-    pub fn with_highlighted_title(mut self, highlighted: String) -> Self {
-        self.highlighted_title = Some(highlighted);
+    /// 设置标题的高亮范围
+    pub fn with_title_highlights(mut self, ranges: Vec<Range<usize>>) -> Self {
+        self.title_highlights = Some(ranges);
+        self
+    }
+
+    /// 设置描述的高亮范围
+    pub fn with_description_highlights(mut self, ranges: Vec<Range<usize>>) -> Self {
+        self.description_highlights = Some(ranges);
+        self
+    }
+
+    /// 设置排序键
+    pub fn with_sort_keys(mut self, sort_keys: SortKeys) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// 标记为破坏性操作
+    pub fn with_destructive(mut self, destructive: bool) -> Self {
+        self.destructive = destructive;
+        self
+    }
+
+    /// 设置次要动作
+    pub fn with_secondary_actions(mut self, actions: Vec<SecondaryAction>) -> Self {
+        self.secondary_actions = actions;
+        self
+    }
+
+    /// 设置富预览
+    pub fn with_preview(mut self, preview: Preview) -> Self {
+        self.preview = (!preview.is_empty()).then_some(preview);
+        self
+    }
+
+    /// 设置执行后是否粘贴到之前的窗口
+    pub fn with_paste(mut self, paste: bool) -> Self {
+        self.paste = paste;
+        self
+    }
+
+    /// 标记为包含敏感内容
+    pub fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
         self
     }
 
-    /// 设置高亮显示的描述
-    pub fn with_highlighted_description(mut self, highlighted: String) -> Self {
-        self.highlighted_description = Some(highlighted);
+    /// 设置所属分组
+    pub fn with_group(mut self, index: usize, query: &str) -> Self {
+        self.group = Some(ResultGroup { index, query: query.to_string() });
         self
     }
 
+    /// 批量执行多个结果的结果项
+    pub fn batch(results: Vec<SearchResult>) -> Self {
+        let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+        let description = titles.join(" · ");
+
+        SearchResult::new(
+            "batch:open_all".to_string(),
+            format!("全部打开 ({})", results.len()),
+            description,
+            ResultType::Custom("batch".to_string()),
+            u32::MAX,
+            ActionData::Batch { results },
+        )
+    }
+
+    /// 标记的多个结果可用的批量动作：依次执行各自的主动作；都带有路径时还可一次复制全部路径
+    pub fn batch_items(results: &[SearchResult]) -> Vec<SearchResult> {
+        let Some(first) = results.first() else {
+            return Vec::new();
+        };
+
+        // 主动作相同时沿用其名称，如“全部打开”“全部运行”
+        let label = first.action.label();
+        let label = if results.iter().all(|r| r.action.label() == label) {
+            format!("全部{}", label)
+        } else {
+            "全部执行".to_string()
+        };
+        let mut execute_all = SearchResult::batch(results.to_vec());
+        execute_all.title = format!("{} ({})", label, results.len());
+        let mut items = vec![execute_all];
+
+        let paths: Vec<&str> = results.iter().filter_map(|r| r.action.path()).collect();
+        if paths.len() == results.len() {
+            items.push(SearchResult::new(
+                "batch:copy_paths".to_string(),
+                format!("复制 {} 个路径", paths.len()),
+                paths.join(" · "),
+                ResultType::Custom("batch".to_string()),
+                u32::MAX,
+                ActionData::CopyToClipboard { text: paths.join("\n") },
+            ));
+        }
+        items
+    }
+
+    /// 将次要动作展开为动作面板中的结果项
+    ///
+    /// 结果项沿用原结果的 ID 前缀，执行时仍由原插件处理
+    pub fn action_items(&self) -> Vec<SearchResult> {
+        // 应用和命令都可以以管理员身份运行
+        let elevated =
+            self.elevated().map(|result| SecondaryAction::new("以管理员身份运行", result.action));
+
+        self.secondary_actions
+            .iter()
+            .cloned()
+            .chain(elevated)
+            .enumerate()
+            .map(|(index, secondary)| {
+                let mut item = SearchResult::new(
+                    format!("{}#{}", self.id, index),
+                    secondary.title,
+                    self.title.clone(),
+                    self.result_type.clone(),
+                    self.score,
+                    secondary.action,
+                )
+                .with_destructive(secondary.destructive);
+                item.icon = self.icon.clone();
+                item
+            })
+            .collect()
+    }
+
+    /// 文件和文件夹结果对应的本地路径，用于加载文件预览
+    pub fn file_path(&self) -> Option<&str> {
+        if !matches!(self.result_type, ResultType::File | ResultType::Folder) {
+            return None;
+        }
+        match &self.action {
+            ActionData::OpenFile { path } | ActionData::RevealInExplorer { path } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// 以管理员身份运行的版本，只有应用和命令支持提权
+    pub fn elevated(&self) -> Option<SearchResult> {
+        let action = match &self.action {
+            ActionData::LaunchApp { path, args, .. } => {
+                ActionData::LaunchApp { path: path.clone(), args: args.clone(), elevated: true }
+            },
+            ActionData::ExecuteCommand { command } => ActionData::LaunchApp {
+                path: "cmd".to_string(),
+                args: vec!["/c".to_string(), command.clone()],
+                elevated: true,
+            },
+            _ => return None,
+        };
+
+        let mut result = self.clone();
+        result.id = format!("{}{}", self.id, ELEVATED_ID_SUFFIX);
+        result.action = action;
+        result.secondary_actions.clear();
+        Some(result)
+    }
+
+    /// 主要动作（Enter 执行）的名称
+    pub fn primary_label(&self) -> &'static str {
+        if self.paste {
+            "粘贴"
+        } else {
+            self.action.label()
+        }
+    }
+
+    /// 底部的快捷键提示：主要动作、支持提权时的管理员运行和动作面板中的动作数
+    ///
+    /// `extra_actions` 为启动器另外加入动作面板的动作数（如添加备注）
+    pub fn shortcut_hints(&self, extra_actions: usize) -> Vec<String> {
+        let mut hints = vec![format!("↵ {}", self.primary_label())];
+        if self.elevated().is_some() {
+            hints.push("⌃⇧↵ 管理员".to_string());
+        }
+        let actions = self.action_items().len() + extra_actions;
+        if actions > 0 {
+            hints.push(format!("⌃K {} 个动作", actions));
+        }
+        hints
+    }
+
     /// 设置图标
     pub fn with_icon(mut self, icon: Option<String>) -> Self {
         self.icon = icon;
         self
     }
 
-    /// 获取显示的标题（优先使用高亮版本）
-    pub fn display_title(&self) -> &str {
-        self.highlighted_title.as_deref().unwrap_or(&self.title)
+    /// 产生该结果的插件 ID（结果 ID 的 "plugin_id:" 前缀）
+    pub fn plugin_id(&self) -> &str {
+        self.id.split_once(':').map_or(self.id.as_str(), |(plugin_id, _)| plugin_id)
+    }
+
+    /// 根据查询计算标题和描述的高亮范围
+    pub fn highlight(&mut self, query: &str) {
+        self.title_highlights = Some(fuzzy::match_ranges(query, &self.title));
+        self.description_highlights = Some(fuzzy::match_ranges(query, &self.description));
     }
 
-    /// 获取显示的描述（优先使用高亮版本）
-    pub fn display_description(&self) -> &str {
-        self.highlighted_description.as_deref().unwrap_or(&self.description)
+    /// 配色键，破坏性操作优先于结果类型
+    pub fn color_key(&self) -> &'static str {
+        if self.destructive {
+            "destructive"
+        } else {
+            self.result_type.color_key()
+        }
+    }
+
+    /// 标题的高亮范围（未计算时为空）
+    pub fn title_highlights(&self) -> &[Range<usize>] {
+        self.title_highlights.as_deref().unwrap_or_default()
+    }
+
+    /// 描述的高亮范围（未计算时为空）
+    pub fn description_highlights(&self) -> &[Range<usize>] {
+        self.description_highlights.as_deref().unwrap_or_default()
+    }
+}
+
+/// 结果分组
+///
+/// `foo; bar` 形式的多查询搜索中，每个子查询的结果为一组
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResultGroup {
+    /// 子查询序号
+    pub index: usize,
+    /// 子查询
+    pub query: String,
+}
+
+/// 次要动作
+#[derive(Clone, Debug)]
+pub struct SecondaryAction {
+    /// 动作名称
+    pub title: String,
+    /// 动作数据
+    pub action: ActionData,
+    /// 是否为破坏性操作（如卸载）
+    pub destructive: bool,
+}
+
+impl SecondaryAction {
+    /// 创建次要动作
+    pub fn new(title: impl Into<String>, action: ActionData) -> Self {
+        Self { title: title.into(), action, destructive: false }
+    }
+
+    /// 设置是否为破坏性操作
+    pub fn with_destructive(mut self, destructive: bool) -> Self {
+        self.destructive = destructive;
+        self
+    }
+}
+
+/// 排序键
+///
+/// 插件可选提供，供排序层在相关度之外对结果重新排序
+#[derive(Clone, Debug, Default)]
+pub struct SortKeys {
+    /// 最近使用/修改时间 (Unix 时间戳，毫秒)
+    pub recency: Option<i64>,
+    /// 大小 (字节)
+    pub size: Option<u64>,
+}
+
+/// 结果排序方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// 按匹配分数
+    #[default]
+    Relevance,
+    /// 按标题字母顺序
+    Alphabetical,
+    /// 最近使用/修改优先
+    Recent,
+    /// 按大小（仅对文件有意义）
+    Size,
+}
+
+impl SortOrder {
+    /// 循环切换到下一种排序方式
+    pub fn next(self) -> Self {
+        match self {
+            SortOrder::Relevance => SortOrder::Alphabetical,
+            SortOrder::Alphabetical => SortOrder::Recent,
+            SortOrder::Recent => SortOrder::Size,
+            SortOrder::Size => SortOrder::Relevance,
+        }
+    }
+
+    /// 获取排序方式的中文显示
+    pub fn display(&self) -> &'static str {
+        match self {
+            SortOrder::Relevance => "相关度",
+            SortOrder::Alphabetical => "名称",
+            SortOrder::Recent => "最近",
+            SortOrder::Size => "大小",
+        }
+    }
+
+    /// 对合并后的结果排序
+    ///
+    /// 缺少对应排序键的结果排在最后，并保持原有的相对顺序
+    pub fn apply(&self, results: &mut [SearchResult]) {
+        match self {
+            SortOrder::Relevance => {
+                results.sort_by_key(|r| std::cmp::Reverse(r.score));
+            },
+            SortOrder::Alphabetical => {
+                results.sort_by_cached_key(|r| r.title.to_lowercase());
+            },
+            SortOrder::Recent => {
+                results.sort_by_key(|r| {
+                    (r.sort_keys.recency.is_none(), std::cmp::Reverse(r.sort_keys.recency))
+                });
+            },
+            SortOrder::Size => {
+                results.sort_by_key(|r| {
+                    (r.sort_keys.size.is_none(), std::cmp::Reverse(r.sort_keys.size))
+                });
+            },
+        }
+
+        // 多查询搜索时只在组内排序，未分组的结果（如"全部打开"）保持在最前
+        if results.iter().any(|r| r.group.is_some()) {
+            results.sort_by_key(|r| r.group.as_ref().map(|g| g.index));
+        }
     }
 }
 
@@ -103,11 +434,45 @@ pub enum ResultType {
     Custom(String),
 }
 
+impl ResultType {
+    /// 获取结果类型的中文显示
+    pub fn display(&self) -> &'static str {
+        match self {
+            ResultType::Application => "应用",
+            ResultType::File => "文件",
+            ResultType::Folder => "文件夹",
+            ResultType::Command => "命令",
+            ResultType::Calculator => "计算",
+            ResultType::Clipboard => "剪贴板",
+            ResultType::Settings => "设置",
+            ResultType::SystemCommand => "系统命令",
+            ResultType::Task => "任务",
+            ResultType::Custom(_) => "其他",
+        }
+    }
+
+    /// 配色键（主题配置中按类型覆盖强调色时使用）
+    pub fn color_key(&self) -> &'static str {
+        match self {
+            ResultType::Application => "application",
+            ResultType::File => "file",
+            ResultType::Folder => "folder",
+            ResultType::Command => "command",
+            ResultType::Calculator => "calculator",
+            ResultType::Clipboard => "clipboard",
+            ResultType::Settings => "settings",
+            ResultType::SystemCommand => "system_command",
+            ResultType::Task => "task",
+            ResultType::Custom(_) => "custom",
+        }
+    }
+}
+
 /// 动作数据
 #[derive(Clone, Debug)]
 pub enum ActionData {
-    /// 启动应用
-    LaunchApp { path: String, args: Vec<String> },
+    /// 启动应用，`elevated` 时以管理员身份运行
+    LaunchApp { path: String, args: Vec<String>, elevated: bool },
     /// 打开文件
     OpenFile { path: String },
     /// 执行命令
@@ -116,6 +481,12 @@ pub enum ActionData {
     CopyToClipboard { text: String },
     /// 打开 URL
     OpenUrl { url: String },
+    /// 在资源管理器中显示
+    RevealInExplorer { path: String },
+    /// 选择打开方式
+    OpenWith { path: String },
+    /// 复制文件到剪贴板 (CF_HDROP)
+    CopyFile { path: String },
     /// 创建任务
     CreateTask {
         title: String,
@@ -124,10 +495,77 @@ pub enum ActionData {
     },
     /// 查看任务
     ViewTask { task: crate::plugins::task_manager::Task },
+    /// 依次执行多个结果
+    Batch { results: Vec<SearchResult> },
     /// 自定义动作
     Custom { plugin: String, data: String },
 }
 
+impl ActionData {
+    /// 动作的显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActionData::LaunchApp { elevated: true, .. } => "以管理员身份运行",
+            ActionData::LaunchApp { .. } => "启动",
+            ActionData::OpenFile { .. } => "打开",
+            ActionData::ExecuteCommand { .. } => "运行",
+            ActionData::CopyToClipboard { .. } => "复制",
+            ActionData::OpenUrl { .. } => "打开链接",
+            ActionData::RevealInExplorer { .. } => "在资源管理器中显示",
+            ActionData::OpenWith { .. } => "选择打开方式",
+            ActionData::CopyFile { .. } => "复制文件",
+            ActionData::CreateTask { .. } => "创建任务",
+            ActionData::ViewTask { .. } => "查看任务",
+            ActionData::Batch { .. } => "全部打开",
+            ActionData::Custom { .. } => "执行",
+        }
+    }
+
+    /// 动作类型名称，用于按动作类型配置（如执行确认策略）
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ActionData::LaunchApp { .. } => "launch_app",
+            ActionData::OpenFile { .. } => "open_file",
+            ActionData::ExecuteCommand { .. } => "execute_command",
+            ActionData::CopyToClipboard { .. } => "copy_to_clipboard",
+            ActionData::OpenUrl { .. } => "open_url",
+            ActionData::RevealInExplorer { .. } => "reveal_in_explorer",
+            ActionData::OpenWith { .. } => "open_with",
+            ActionData::CopyFile { .. } => "copy_file",
+            ActionData::CreateTask { .. } => "create_task",
+            ActionData::ViewTask { .. } => "view_task",
+            ActionData::Batch { .. } => "batch",
+            ActionData::Custom { .. } => "custom",
+        }
+    }
+
+    /// 动作针对的本地路径（应用、文件、文件夹）
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            ActionData::LaunchApp { path, .. }
+            | ActionData::OpenFile { path }
+            | ActionData::RevealInExplorer { path }
+            | ActionData::OpenWith { path }
+            | ActionData::CopyFile { path } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// 动作携带的完整内容及其名称（路径、命令、文本、链接），快速查看时显示
+    pub fn payload(&self) -> Option<(&'static str, &str)> {
+        if let Some(path) = self.path() {
+            return Some(("路径", path));
+        }
+        match self {
+            ActionData::ExecuteCommand { command } => Some(("命令", command)),
+            ActionData::CopyToClipboard { text } => Some(("文本", text)),
+            ActionData::OpenUrl { url } => Some(("链接", url)),
+            ActionData::CreateTask { description, .. } => Some(("描述", description)),
+            _ => None,
+        }
+    }
+}
+
 /// 搜索引擎
 pub struct SearchEngine {
     /// 查询字符串
@@ -185,3 +623,494 @@ impl Default for SearchEngine {
         Self::new()
     }
 }
+
+/// 识别出意图的结果额外加分，使其排在普通匹配结果之前
+pub const INTENT_BOOST: u32 = 500;
+
+/// 多查询分隔符
+pub const QUERY_SEPARATOR: char = ';';
+
+/// 备注匹配查询的结果额外加分
+pub const NOTE_BOOST: u32 = 300;
+
+/// 多查询搜索时每组保留的结果数量
+pub const BATCH_GROUP_LIMIT: usize = 3;
+
+/// 以管理员身份运行的版本在原结果 ID 后加上的后缀，执行确认与普通运行分开计算
+pub const ELEVATED_ID_SUFFIX: &str = "#elevated";
+
+/// 将 `foo; bar; baz` 拆分为多个独立查询
+///
+/// 少于两个非空查询时返回 `None`；命令执行查询（`>`、`!` 前缀）中的分号属于命令本身，不拆分
+pub fn split_queries(query: &str) -> Option<Vec<&str>> {
+    let trimmed = query.trim_start();
+    if trimmed.starts_with(['>', '!', '/']) {
+        return None;
+    }
+
+    let queries: Vec<&str> =
+        query.split(QUERY_SEPARATOR).map(str::trim).filter(|q| !q.is_empty()).collect();
+    (queries.len() >= 2).then_some(queries)
+}
+
+/// 温度单位
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    /// 摄氏度
+    Celsius,
+    /// 华氏度
+    Fahrenheit,
+    /// 开尔文
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    /// 单位符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+
+    /// 转换为摄氏度
+    pub fn to_celsius(&self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            TemperatureUnit::Kelvin => value - 273.15,
+        }
+    }
+
+    /// 从摄氏度转换
+    pub fn from_celsius(&self, celsius: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    /// 换算为摄氏度的公式
+    pub fn to_celsius_formula(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "(°F − 32) × 5/9",
+            TemperatureUnit::Kelvin => "K − 273.15",
+        }
+    }
+
+    /// 由摄氏度换算的公式
+    pub fn from_celsius_formula(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°C × 9/5 + 32",
+            TemperatureUnit::Kelvin => "°C + 273.15",
+        }
+    }
+}
+
+/// 查询意图
+///
+/// 无需插件关键词，直接从普通查询中识别货币、温度、单位换算和城市时间，
+/// 由 [`PluginManager::search_all`](super::plugin::PluginManager::search_all) 为对应插件的结果加分
+#[derive(Clone, Debug, PartialEq)]
+pub enum Intent {
+    /// 货币金额，如 `100usd`、`¥50`
+    Currency { amount: f64, code: String },
+    /// 城市时间，如 `5pm tokyo`、`london`
+    CityTime { city: String, time: Option<(u32, u32)> },
+    /// 温度，如 `72f`、`-5°C`
+    Temperature { value: f64, unit: TemperatureUnit },
+    /// 单位或货币换算，如 `10km to mi`、`100 usd to eur`
+    ///
+    /// `from`、`to` 为单位符号（见 [`units::lookup`]）或货币的 ISO 代码
+    Conversion { value: f64, from: String, to: String },
+}
+
+/// 货币别名 -> ISO 代码
+const CURRENCY_ALIASES: &[(&str, &str)] = &[
+    ("usd", "USD"),
+    ("$", "USD"),
+    ("美元", "USD"),
+    ("eur", "EUR"),
+    ("€", "EUR"),
+    ("欧元", "EUR"),
+    ("cny", "CNY"),
+    ("rmb", "CNY"),
+    ("元", "CNY"),
+    ("人民币", "CNY"),
+    ("jpy", "JPY"),
+    ("¥", "JPY"),
+    ("日元", "JPY"),
+    ("gbp", "GBP"),
+    ("£", "GBP"),
+    ("英镑", "GBP"),
+    ("hkd", "HKD"),
+    ("港币", "HKD"),
+    ("twd", "TWD"),
+    ("krw", "KRW"),
+    ("韩元", "KRW"),
+    ("aud", "AUD"),
+    ("cad", "CAD"),
+    ("chf", "CHF"),
+    ("sgd", "SGD"),
+    ("inr", "INR"),
+    ("rub", "RUB"),
+];
+
+/// 换算分隔符，如 `10km to mi`
+const CONVERSION_SEPARATORS: &[&str] =
+    &[" to ", " in ", " as ", "->", "=>", "换成", "转换为", "转为", "="];
+
+/// 温度单位别名
+const TEMPERATURE_ALIASES: &[(&str, TemperatureUnit)] = &[
+    ("c", TemperatureUnit::Celsius),
+    ("°c", TemperatureUnit::Celsius),
+    ("℃", TemperatureUnit::Celsius),
+    ("摄氏度", TemperatureUnit::Celsius),
+    ("f", TemperatureUnit::Fahrenheit),
+    ("°f", TemperatureUnit::Fahrenheit),
+    ("℉", TemperatureUnit::Fahrenheit),
+    ("华氏度", TemperatureUnit::Fahrenheit),
+    ("kelvin", TemperatureUnit::Kelvin),
+    ("开尔文", TemperatureUnit::Kelvin),
+];
+
+impl Intent {
+    /// 识别查询意图
+    pub fn detect(query: &str) -> Option<Self> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+
+        Self::detect_conversion(&query)
+            .or_else(|| Self::detect_temperature(&query))
+            .or_else(|| Self::detect_currency(&query))
+            .or_else(|| Self::detect_city_time(&query))
+    }
+
+    /// 处理该意图的插件 ID
+    pub fn plugin_id(&self) -> &'static str {
+        match self {
+            Intent::Currency { .. } | Intent::Temperature { .. } | Intent::Conversion { .. } => {
+                "calculator"
+            },
+            Intent::CityTime { .. } => "world_clock",
+        }
+    }
+
+    /// 换算：数字 + 单位 + 分隔符 + 目标单位，两侧须同为单位或同为货币
+    fn detect_conversion(query: &str) -> Option<Self> {
+        let query = query.replace(',', "");
+        let (left, right) = CONVERSION_SEPARATORS.iter().find_map(|separator| {
+            let index = query.rfind(separator)?;
+            Some((query[..index].trim(), query[index + separator.len()..].trim()))
+        })?;
+
+        if let Some((value, unit)) = split_number(left) {
+            if let (Some(from), Some(to)) = (units::lookup(unit), units::lookup(right)) {
+                return (from.dimension == to.dimension).then(|| Intent::Conversion {
+                    value,
+                    from: from.symbol.to_string(),
+                    to: to.symbol.to_string(),
+                });
+            }
+        }
+
+        let Some(Intent::Currency { amount, code }) = Self::detect_currency(left) else {
+            return None;
+        };
+        let to = currency_code(right)?;
+        Some(Intent::Conversion { value: amount, from: code, to: to.to_string() })
+    }
+
+    /// 温度：数字 + 单位
+    fn detect_temperature(query: &str) -> Option<Self> {
+        let (value, unit) = split_number(query)?;
+        let unit = TEMPERATURE_ALIASES.iter().find(|(alias, _)| *alias == unit)?.1;
+        Some(Intent::Temperature { value, unit })
+    }
+
+    /// 货币：数字 + 代码，或符号 + 数字
+    fn detect_currency(query: &str) -> Option<Self> {
+        let query = query.replace(',', "");
+        let (amount, code) = match split_number(&query) {
+            Some((amount, code)) => (amount, code.to_string()),
+            None => {
+                // 货币符号前置
+                let (symbol, iso) = CURRENCY_ALIASES.iter().find(|(alias, _)| {
+                    !alias.chars().all(char::is_alphabetic) && query.starts_with(alias)
+                })?;
+                let amount = query[symbol.len()..].trim().parse::<f64>().ok()?;
+                (amount, iso.to_string())
+            },
+        };
+
+        let code = currency_code(&code)?;
+        Some(Intent::Currency { amount, code: code.to_string() })
+    }
+
+    /// 城市时间：`5pm tokyo`、`tokyo 17:30` 或仅城市名
+    fn detect_city_time(query: &str) -> Option<Self> {
+        let tokens: Vec<&str> = query.split_whitespace().collect();
+
+        for split in 0..=tokens.len() {
+            let (head, tail) = tokens.split_at(split);
+            let candidates = [(head, tail), (tail, head)];
+
+            for (time_tokens, city_tokens) in candidates {
+                if city_tokens.is_empty() {
+                    continue;
+                }
+
+                let time = if time_tokens.is_empty() {
+                    None
+                } else {
+                    match parse_clock(&time_tokens.join("")) {
+                        Some(time) => Some(time),
+                        None => continue,
+                    }
+                };
+
+                if let Some(city) = timezone::lookup_city(&city_tokens.join(" ")) {
+                    return Some(Intent::CityTime { city: city.name.to_string(), time });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// 按别名或 ISO 代码查找货币
+fn currency_code(name: &str) -> Option<&'static str> {
+    CURRENCY_ALIASES
+        .iter()
+        .find(|(alias, iso)| *alias == name || iso.eq_ignore_ascii_case(name))
+        .map(|(_, iso)| *iso)
+}
+
+/// 拆分开头的数字和后缀（如 `72f` -> `(72.0, "f")`），后缀不能为空
+fn split_number(text: &str) -> Option<(f64, &str)> {
+    let end = text
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+        .map(|(i, _)| i)?;
+
+    let value = text[..end].parse::<f64>().ok()?;
+    let suffix = text[end..].trim();
+    (!suffix.is_empty()).then_some((value, suffix))
+}
+
+/// 解析时刻，必须带 am/pm 或冒号（如 `5pm`、`5:30pm`、`17:30`）
+fn parse_clock(text: &str) -> Option<(u32, u32)> {
+    let (clock, meridiem) = if let Some(rest) = text.strip_suffix("am") {
+        (rest, Some(false))
+    } else if let Some(rest) = text.strip_suffix("pm") {
+        (rest, Some(true))
+    } else if text.contains(':') {
+        (text, None)
+    } else {
+        return None;
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+
+    if minute >= 60 {
+        return None;
+    }
+
+    let hour = match meridiem {
+        Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None if hour < 24 => hour,
+        None => return None,
+    };
+
+    Some((hour, minute))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(title: &str, score: u32, sort_keys: SortKeys) -> SearchResult {
+        SearchResult::new(
+            format!("test:{}", title),
+            title.to_string(),
+            String::new(),
+            ResultType::File,
+            score,
+            ActionData::OpenFile { path: title.to_string() },
+        )
+        .with_sort_keys(sort_keys)
+    }
+
+    fn titles(results: &[SearchResult]) -> Vec<&str> {
+        results.iter().map(|r| r.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_shortcut_hints() {
+        let file = result("a.txt", 0, SortKeys::default());
+        assert_eq!(file.shortcut_hints(1), vec!["↵ 打开", "⌃K 1 个动作"]);
+
+        let command = SearchResult::new(
+            "custom_commands:git".to_string(),
+            "git".to_string(),
+            String::new(),
+            ResultType::Command,
+            0,
+            ActionData::ExecuteCommand { command: "git status".to_string() },
+        )
+        .with_secondary_actions(vec![SecondaryAction::new(
+            "复制命令",
+            ActionData::CopyToClipboard { text: "git status".to_string() },
+        )]);
+        // 次要动作加上以管理员身份运行
+        assert_eq!(command.shortcut_hints(0), vec!["↵ 运行", "⌃⇧↵ 管理员", "⌃K 2 个动作"]);
+
+        // 提权版本的 ID 与原结果不同，插件前缀不变
+        let elevated = command.elevated().unwrap();
+        assert_eq!(elevated.id, "custom_commands:git#elevated");
+        assert_eq!(elevated.plugin_id(), "custom_commands");
+        assert_eq!(command.with_paste(true).primary_label(), "粘贴");
+    }
+
+    #[test]
+    fn test_batch_items() {
+        let files =
+            vec![result("a.txt", 0, SortKeys::default()), result("b.txt", 0, SortKeys::default())];
+        let items = SearchResult::batch_items(&files);
+        assert_eq!(titles(&items), ["全部打开 (2)", "复制 2 个路径"]);
+        let ActionData::CopyToClipboard { text } = &items[1].action else {
+            panic!("复制路径应为复制动作");
+        };
+        assert_eq!(text.lines().count(), 2);
+        assert!(SearchResult::batch_items(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_action_payload() {
+        let text = "第一行\n第二行".to_string();
+        let copy = ActionData::CopyToClipboard { text: text.clone() };
+        assert_eq!(copy.payload(), Some(("文本", text.as_str())));
+        let custom = ActionData::Custom { plugin: "clipboard".to_string(), data: "1".to_string() };
+        assert_eq!(custom.payload(), None);
+    }
+
+    #[test]
+    fn test_split_queries_keep_groups() {
+        assert_eq!(
+            split_queries("code; notes.md ;; chrome"),
+            Some(vec!["code", "notes.md", "chrome"])
+        );
+        assert_eq!(split_queries("code;"), None);
+        assert_eq!(split_queries("> echo a; echo b"), None);
+
+        let mut results = vec![
+            result("b1", 10, SortKeys::default()).with_group(1, "b"),
+            result("a1", 30, SortKeys::default()).with_group(0, "a"),
+            result("b2", 50, SortKeys::default()).with_group(1, "b"),
+            result("a2", 20, SortKeys::default()).with_group(0, "a"),
+        ];
+        results.insert(0, SearchResult::batch(vec![results[1].clone(), results[2].clone()]));
+
+        SortOrder::Relevance.apply(&mut results);
+        assert_eq!(titles(&results), ["全部打开 (2)", "a1", "a2", "b2", "b1"]);
+    }
+
+    #[test]
+    fn test_sort_order_cycle() {
+        let mut order = SortOrder::default();
+        for _ in 0..4 {
+            order = order.next();
+        }
+        assert_eq!(order, SortOrder::Relevance);
+    }
+
+    #[test]
+    fn test_sort_missing_keys_last() {
+        let mut results = vec![
+            result("a", 10, SortKeys::default()),
+            result("b", 20, SortKeys { size: Some(5), ..Default::default() }),
+            result("c", 30, SortKeys { size: Some(50), ..Default::default() }),
+        ];
+
+        SortOrder::Size.apply(&mut results);
+        assert_eq!(titles(&results), ["c", "b", "a"]);
+
+        SortOrder::Alphabetical.apply(&mut results);
+        assert_eq!(titles(&results), ["a", "b", "c"]);
+
+        SortOrder::Relevance.apply(&mut results);
+        assert_eq!(titles(&results), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_detect_intent() {
+        assert_eq!(
+            Intent::detect("100usd"),
+            Some(Intent::Currency { amount: 100.0, code: "USD".to_string() })
+        );
+        assert_eq!(
+            Intent::detect("¥1,000"),
+            Some(Intent::Currency { amount: 1000.0, code: "JPY".to_string() })
+        );
+        assert_eq!(
+            Intent::detect("72F"),
+            Some(Intent::Temperature { value: 72.0, unit: TemperatureUnit::Fahrenheit })
+        );
+        assert_eq!(
+            Intent::detect("5pm tokyo"),
+            Some(Intent::CityTime { city: "tokyo".to_string(), time: Some((17, 0)) })
+        );
+        assert_eq!(
+            Intent::detect("new york 9:30am"),
+            Some(Intent::CityTime { city: "newyork".to_string(), time: Some((9, 30)) })
+        );
+        assert_eq!(Intent::detect("london").map(|i| i.plugin_id()), Some("world_clock"));
+
+        let conversion = |value: f64, from: &str, to: &str| {
+            Some(Intent::Conversion { value, from: from.to_string(), to: to.to_string() })
+        };
+        assert_eq!(Intent::detect("10km to mi"), conversion(10.0, "km", "mi"));
+        assert_eq!(Intent::detect("72f in c"), conversion(72.0, "°F", "°C"));
+        assert_eq!(Intent::detect("5 GB to MB"), conversion(5.0, "GB", "MB"));
+        assert_eq!(Intent::detect("10 in in cm"), conversion(10.0, "in", "cm"));
+        assert_eq!(Intent::detect("100 usd to eur"), conversion(100.0, "USD", "EUR"));
+        assert_eq!(Intent::detect("$1,000 换成 人民币"), conversion(1000.0, "USD", "CNY"));
+        assert_eq!(Intent::detect("10km to kg"), None);
+        assert_eq!(Intent::detect("100 usd to km"), None);
+
+        // 普通查询不应被误判
+        assert_eq!(Intent::detect("chrome"), None);
+        assert_eq!(Intent::detect("5 tokyo"), None);
+        assert_eq!(Intent::detect("1+2"), None);
+        // `k` 常表示千，不识别为开尔文
+        assert_eq!(Intent::detect("100k"), None);
+    }
+
+    #[test]
+    fn test_action_items_keep_plugin_prefix() {
+        let result = result("report.docx", 80, SortKeys::default()).with_secondary_actions(vec![
+            SecondaryAction::new("在资源管理器中显示", ActionData::RevealInExplorer {
+                path: "report.docx".to_string(),
+            }),
+            SecondaryAction::new("复制文件", ActionData::CopyFile {
+                path: "report.docx".to_string(),
+            }),
+        ]);
+
+        let items = result.action_items();
+        assert_eq!(titles(&items), ["在资源管理器中显示", "复制文件"]);
+        assert!(items.iter().all(|item| item.plugin_id() == "test"));
+        assert!(matches!(items[1].action, ActionData::CopyFile { .. }));
+    }
+}
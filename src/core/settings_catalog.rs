@@ -0,0 +1,270 @@
+/// 设置项目录
+///
+/// 按分节登记配置项的名称和说明，设置搜索按名称、说明或键名模糊匹配，
+/// 并能定位配置项在配置文件中的行
+use crate::utils::fuzzy::FuzzyMatcher;
+
+/// 设置分节
+#[derive(Debug, PartialEq, Eq)]
+pub struct SettingSection {
+    /// 配置文件中的表名（如 `theme.schedule`）
+    pub key: &'static str,
+    /// 显示名称
+    pub label: &'static str,
+}
+
+/// 设置项
+#[derive(Debug, PartialEq, Eq)]
+pub struct SettingEntry {
+    /// 所属分节的表名
+    pub section: &'static str,
+    /// 键名
+    pub key: &'static str,
+    /// 显示名称
+    pub label: &'static str,
+    /// 说明
+    pub description: &'static str,
+}
+
+impl SettingEntry {
+    /// 完整键名（如 `window.opacity`）
+    pub fn path(&self) -> String {
+        format!("{}.{}", self.section, self.key)
+    }
+
+    /// 从序列化后的配置中读取当前值，未设置的可选项返回 `None`
+    pub fn value(&self, config: &serde_json::Value) -> Option<String> {
+        let value = self
+            .section
+            .split('.')
+            .chain([self.key])
+            .try_fold(config, |value, key| value.get(key))
+            .filter(|value| !value.is_null())?;
+        Some(match value {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    }
+
+    /// 在配置文件内容中查找该项所在的行号（从 1 开始）
+    ///
+    /// 只识别 `[section]` 表头下未注释的 `key = ...` 行
+    pub fn line_number(&self, content: &str) -> Option<usize> {
+        let header = format!("[{}]", self.section);
+        let mut in_section = false;
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_section = line == header;
+            } else if in_section
+                && line
+                    .strip_prefix(self.key)
+                    .is_some_and(|rest| rest.trim_start().starts_with('='))
+            {
+                return Some(index + 1);
+            }
+        }
+        None
+    }
+}
+
+/// 分节（按配置文件中的顺序）
+pub const SECTIONS: &[SettingSection] = &[
+    SettingSection { key: "window", label: "窗口" },
+    SettingSection { key: "theme", label: "主题" },
+    SettingSection { key: "theme.schedule", label: "主题定时切换" },
+    SettingSection { key: "search", label: "搜索" },
+    SettingSection { key: "search.boundary_bonus", label: "匹配加分" },
+    SettingSection { key: "keybindings", label: "快捷键" },
+    SettingSection { key: "plugins", label: "插件" },
+    SettingSection { key: "privacy", label: "隐私" },
+    SettingSection { key: "confirmation", label: "执行确认" },
+    SettingSection { key: "quiet_hours", label: "免打扰时段" },
+    SettingSection { key: "memory", label: "内存" },
+    SettingSection { key: "hotstrings", label: "缩写展开" },
+    SettingSection { key: "startup", label: "启动" },
+    SettingSection { key: "notifications", label: "通知" },
+    SettingSection { key: "prefill", label: "预填查询" },
+];
+
+/// 设置项（按分节顺序）
+pub const SETTINGS: &[SettingEntry] = &[
+    // 窗口
+    entry("window", "width", "窗口宽度", "启动器窗口的宽度"),
+    entry("window", "height", "窗口高度", "启动器窗口的高度"),
+    entry("window", "opacity", "窗口透明度", "0 到 1 之间，1 为不透明"),
+    entry("window", "border_radius", "圆角半径", "窗口圆角的半径"),
+    entry("window", "always_on_top", "窗口置顶", "启动器窗口始终显示在最前"),
+    entry("window", "hide_on_blur", "失焦时隐藏", "窗口失去焦点时自动隐藏"),
+    entry("window", "animation_duration_ms", "动画时长", "显示和隐藏动画的时长 (毫秒)，0 关闭动画"),
+    entry("window", "preview", "预览面板", "auto、always 或 never，Ctrl+P 临时切换"),
+    entry("window", "backdrop", "背景材质", "none、mica 或 acrylic，需重启生效"),
+    entry("window", "density", "界面密度", "compact、default 或 comfortable，立即生效"),
+    entry("window", "monitor", "显示器", "cursor、focused_window 或 primary"),
+    entry("window", "show_index_hints", "数字快捷键提示", "在可见结果上显示 Alt+1..9"),
+    entry("window", "layout", "结果布局", "list 或 grid，可按插件 ID 覆盖"),
+    // 主题
+    entry("theme", "current_theme", "当前主题", "使用的主题名称"),
+    entry("theme", "follow_system", "跟随系统主题", "跟随系统的浅色/深色和高对比度模式"),
+    entry("theme", "light_theme", "浅色主题", "切换到浅色时使用的主题名称"),
+    entry("theme", "dark_theme", "深色主题", "切换到深色时使用的主题名称"),
+    entry("theme", "high_contrast", "高对比度", "始终使用高对比度主题"),
+    entry("theme", "result_colors", "结果类型颜色", "按结果类型覆盖强调色 (#RRGGBB)"),
+    entry("theme", "badge_style", "徽标样式", "结果类型徽标的样式：subtle、solid 或 outline"),
+    entry("theme.schedule", "mode", "定时切换方式", "off、times（按时间）或 sun（按日出日落）"),
+    entry("theme.schedule", "light_start", "浅色开始时间", "切换到浅色主题的时间 (HH:MM)"),
+    entry("theme.schedule", "dark_start", "深色开始时间", "切换到深色主题的时间 (HH:MM)"),
+    entry("theme.schedule", "latitude", "纬度", "用于计算日出日落，北纬为正"),
+    entry("theme.schedule", "longitude", "经度", "用于计算日出日落，东经为正"),
+    // 搜索
+    entry("search", "max_results", "最大结果数", "每次搜索最多显示的结果数"),
+    entry("search", "debounce_ms", "搜索延迟", "输入停止多久后开始搜索 (毫秒)"),
+    entry("search", "enable_file_search", "文件搜索", "是否显示文件搜索结果"),
+    entry("search", "file_search_paths", "文件搜索路径", "建立文件索引的目录"),
+    entry("search", "file_ignore_patterns", "忽略的文件", "文件索引忽略的 glob 模式"),
+    entry("search", "file_search_max_depth", "文件搜索深度", "文件索引的最大递归深度"),
+    entry("search", "file_search_depth_overrides", "按路径的搜索深度", "按搜索路径覆盖递归深度"),
+    entry("search", "include_hidden_files", "索引隐藏文件", "文件索引是否包含隐藏文件"),
+    entry("search", "pinned_apps", "固定应用", "查询为空时固定显示的应用"),
+    entry("search", "home_recent_apps", "常用应用数量", "查询为空时显示的最常用应用数量"),
+    entry("search", "fallbacks", "兜底结果", "没有结果时提供兜底结果的插件及顺序"),
+    entry("search.boundary_bonus", "start", "开头加分", "查询命中字符串开头时的加分"),
+    entry("search.boundary_bonus", "separator", "分隔符加分", "命中空格、连字符等之后字符的加分"),
+    entry("search.boundary_bonus", "case_change", "大小写切换加分", "命中 camelCase 切换处的加分"),
+    entry("search.boundary_bonus", "consecutive", "连续命中加分", "与上一个命中字符连续时的加分"),
+    // 快捷键
+    entry("keybindings", "toggle_launcher", "唤出快捷键", "显示/隐藏启动器的全局快捷键"),
+    entry("keybindings", "navigate_up", "向上导航", "在结果列表中向上移动"),
+    entry("keybindings", "navigate_down", "向下导航", "在结果列表中向下移动"),
+    entry("keybindings", "confirm", "确认选择", "执行选中的结果"),
+    entry("keybindings", "close", "关闭窗口", "隐藏启动器"),
+    entry("keybindings", "hotkey_passthrough", "快捷键透传", "把全局快捷键交还给前台窗口"),
+    entry("keybindings", "double_press_ms", "双击间隔", "双击快捷键的判定间隔 (毫秒)"),
+    entry("keybindings", "prefill_launcher", "预填快捷键", "以选中文本或剪贴板内容预填并唤出"),
+    // 插件
+    entry("plugins", "enabled", "启用的插件", "启用的插件 ID 列表"),
+    // 隐私
+    entry("privacy", "history_excluded_plugins", "不记录历史的插件", "不记录使用历史和查询日志"),
+    // 执行确认
+    entry("confirmation", "default", "默认确认策略", "never、destructive 或 always"),
+    entry("confirmation", "plugins", "按插件确认", "按插件 ID 覆盖确认策略"),
+    entry("confirmation", "actions", "按动作确认", "按动作类型覆盖确认策略"),
+    // 免打扰时段
+    entry("quiet_hours", "enabled", "免打扰", "时段内推迟后台刷新、不显示非关键通知"),
+    entry("quiet_hours", "start", "免打扰开始时间", "免打扰时段的开始时间 (HH:MM)"),
+    entry("quiet_hours", "end", "免打扰结束时间", "免打扰时段的结束时间 (HH:MM)"),
+    // 内存
+    entry("memory", "budget_mb", "内存预算", "缓存内存预算 (MB)"),
+    entry("memory", "trim_interval_secs", "裁剪间隔", "检查并裁剪缓存的间隔（秒）"),
+    // 缩写展开
+    entry("hotstrings", "enabled", "缩写展开", "在任意程序中输入缩写时替换为展开内容"),
+    entry("hotstrings", "excluded_processes", "不展开的程序", "不展开缩写的进程"),
+    entry("hotstrings", "abbreviations", "缩写列表", "缩写和对应的展开内容"),
+    // 启动
+    entry("startup", "launch_at_login", "登录时启动", "登录时在后台启动并预热索引"),
+    entry("startup", "warm_up_timeout_secs", "预热超时", "预热的最长等待时间（秒）"),
+    // 通知
+    entry("notifications", "enabled", "显示通知", "重建索引完成等后台事件的通知"),
+    entry("notifications", "muted", "静音事件", "不显示通知的事件"),
+    // 预填查询
+    entry("prefill", "source", "预填来源", "selection、clipboard 或 selection_or_clipboard"),
+    entry("prefill", "simulate_copy", "模拟复制", "读取不到选中文本时模拟 Ctrl+C 复制"),
+    entry("prefill", "prefix", "预填前缀", "填入内容前加的前缀，如 g 直接网页搜索"),
+    entry("prefill", "on_toggle", "唤出时预填", "普通唤出快捷键也预填搜索框"),
+];
+
+/// 登记设置项
+const fn entry(
+    section: &'static str,
+    key: &'static str,
+    label: &'static str,
+    description: &'static str,
+) -> SettingEntry {
+    SettingEntry { section, key, label, description }
+}
+
+/// 分节的显示名称
+pub fn section_label(section: &str) -> &'static str {
+    SECTIONS.iter().find(|s| s.key == section).map_or("其他", |s| s.label)
+}
+
+/// 搜索设置项
+///
+/// 名称、说明和完整键名中取最高分（名称优先）；结果按分节归组，
+/// 最佳匹配所在的分节排在前面，分节内按分数排序。查询为空时按目录顺序返回全部
+pub fn search(matcher: &FuzzyMatcher, query: &str) -> Vec<(&'static SettingEntry, u32)> {
+    let query = query.trim();
+    if query.is_empty() {
+        return SETTINGS.iter().map(|entry| (entry, 0)).collect();
+    }
+
+    let mut matches: Vec<(&'static SettingEntry, u32)> = SETTINGS
+        .iter()
+        .filter_map(|entry| {
+            let texts = [
+                (entry.label.to_string(), 2),
+                (entry.path(), 1),
+                (entry.description.to_string(), 0),
+            ];
+            let score = texts
+                .into_iter()
+                .filter_map(|(text, bonus)| {
+                    let (matched, score) = matcher.fuzzy_match(query, &text);
+                    matched.then_some(score + bonus)
+                })
+                .max()?;
+            Some((entry, score))
+        })
+        .collect();
+
+    let section_best =
+        |section: &str| matches.iter().filter(|(e, _)| e.section == section).map(|(_, s)| *s).max();
+    let mut order: Vec<(&str, u32)> = SECTIONS
+        .iter()
+        .filter_map(|section| Some((section.key, section_best(section.key)?)))
+        .collect();
+    order.sort_by_key(|(_, best)| std::cmp::Reverse(*best));
+
+    let rank = |section: &str| order.iter().position(|(key, _)| *key == section);
+    matches.sort_by_key(|(entry, score)| (rank(entry.section), std::cmp::Reverse(*score)));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{AppConfig, BoundaryBonus};
+
+    #[test]
+    fn test_search_and_locate() {
+        // 每个设置项都属于登记的分节
+        assert!(SETTINGS.iter().all(|e| SECTIONS.iter().any(|s| s.key == e.section)));
+
+        let matcher = FuzzyMatcher::new(BoundaryBonus::default());
+        let results = search(&matcher, "透明");
+        assert_eq!(results[0].0.path(), "window.opacity");
+        assert_eq!(search(&matcher, "").len(), SETTINGS.len());
+
+        // 同一分节的结果相邻
+        let results = search(&matcher, "start");
+        let sections: Vec<&str> = results.iter().map(|(e, _)| e.section).collect();
+        let mut deduped = sections.clone();
+        deduped.dedup();
+        let mut unique = deduped.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(deduped.len(), unique.len(), "{:?}", sections);
+
+        let content =
+            "[window]\nwidth = 800.0\n\n[theme.schedule]\n# mode = \"sun\"\nmode = \"off\"\n";
+        let mode = SETTINGS.iter().find(|e| e.path() == "theme.schedule.mode").unwrap();
+        assert_eq!(mode.line_number(content), Some(6));
+        let height = SETTINGS.iter().find(|e| e.path() == "window.height").unwrap();
+        assert_eq!(height.line_number(content), None);
+
+        let config = serde_json::to_value(AppConfig::default()).unwrap();
+        assert_eq!(mode.value(&config).as_deref(), Some("off"));
+        assert_eq!(section_label(mode.section), "主题定时切换");
+    }
+}
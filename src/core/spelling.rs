@@ -0,0 +1,196 @@
+/// 查询拼写纠正
+///
+/// 查询没有任何结果时，用已索引的应用名称、文件名中的词与查询中的词比较编辑距离，
+/// 给出"您是不是要找"的建议查询
+use std::collections::HashMap;
+
+use crate::core::search::{ActionData, ResultType, SearchResult};
+
+/// 建议结果的 ID 前缀，后接纠正后的查询；选中时由窗口替换输入框中的查询
+pub const SUGGESTION_ID_PREFIX: &str = "__suggestion__:";
+
+/// 最多给出的建议数
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// 参与纠正的最短词长（字符数），更短的词误报太多
+const MIN_WORD_CHARS: usize = 3;
+
+/// 允许的编辑距离：短词 1，长词 2
+fn max_distance(word: &str) -> usize {
+    if word.chars().count() <= 4 {
+        1
+    } else {
+        2
+    }
+}
+
+/// 编辑距离（插入、删除、替换和相邻字符交换各算一次）
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // 保留前两行以计算相邻交换
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+/// 拆分为小写的词（字母和数字组成），忽略过短的词
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= MIN_WORD_CHARS)
+        .map(str::to_lowercase)
+}
+
+/// 索引词汇表
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    /// 词 -> 出现次数
+    words: HashMap<String, usize>,
+}
+
+impl Vocabulary {
+    /// 从应用名称、文件名等文本建立词汇表
+    pub fn from_texts<I, S>(texts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut words = HashMap::new();
+        for text in texts {
+            for word in tokenize(text.as_ref()) {
+                *words.entry(word).or_insert(0) += 1;
+            }
+        }
+        Self { words }
+    }
+
+    /// 词的数量
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// 与词相近的词，按编辑距离、出现次数排序；词本身在词汇表中时返回空列表
+    fn corrections(&self, word: &str) -> Vec<&str> {
+        if self.words.contains_key(word) {
+            return Vec::new();
+        }
+
+        let limit = max_distance(word);
+        let length = word.chars().count();
+        let mut candidates: Vec<(usize, usize, &str)> = self
+            .words
+            .iter()
+            .filter(|(candidate, _)| candidate.chars().count().abs_diff(length) <= limit)
+            .map(|(candidate, count)| (edit_distance(word, candidate), *count, candidate.as_str()))
+            .filter(|(distance, ..)| *distance <= limit)
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then(a.2.cmp(b.2)));
+        candidates.into_iter().map(|(.., candidate)| candidate).collect()
+    }
+
+    /// 纠正后的查询，最可能的在前；查询中的词都在词汇表中或没有相近的词时返回空列表
+    ///
+    /// 第一条建议把每个拼错的词替换为最相近的词，其余建议依次换用第一个拼错的词的其他候选
+    pub fn suggest(&self, query: &str) -> Vec<String> {
+        let words: Vec<&str> = query.split_whitespace().collect();
+        let corrections: Vec<Vec<&str>> = words
+            .iter()
+            .map(|word| {
+                let lower = word.to_lowercase();
+                if lower.chars().count() < MIN_WORD_CHARS
+                    || !lower.chars().all(char::is_alphanumeric)
+                {
+                    return Vec::new();
+                }
+                self.corrections(&lower)
+            })
+            .collect();
+
+        let Some(first) = corrections.iter().position(|candidates| !candidates.is_empty()) else {
+            return Vec::new();
+        };
+
+        let build = |alternative: &str| {
+            let corrected: Vec<&str> = words
+                .iter()
+                .zip(&corrections)
+                .enumerate()
+                .map(|(index, (word, candidates))| {
+                    if index == first {
+                        alternative
+                    } else {
+                        candidates.first().copied().unwrap_or(word)
+                    }
+                })
+                .collect();
+            corrected.join(" ")
+        };
+
+        corrections[first]
+            .iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|alternative| build(alternative))
+            .collect()
+    }
+}
+
+/// 建议查询的结果项
+pub fn suggestion_result(query: &str) -> SearchResult {
+    SearchResult::new(
+        format!("{}{}", SUGGESTION_ID_PREFIX, query),
+        format!("您是不是要找: {}", query),
+        "按 Enter 使用该查询重新搜索".to_string(),
+        ResultType::Custom("suggestion".to_string()),
+        0,
+        ActionData::Custom { plugin: "suggestion".to_string(), data: query.to_string() },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance_and_suggest() {
+        assert_eq!(edit_distance("chrome", "chrome"), 0);
+        assert_eq!(edit_distance("chrme", "chrome"), 1);
+        assert_eq!(edit_distance("chorme", "chrome"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+
+        let vocabulary = Vocabulary::from_texts([
+            "Google Chrome",
+            "Visual Studio Code",
+            "report-2024.docx",
+            "reports.xlsx",
+            "Report Q3.pptx",
+        ]);
+        assert!(vocabulary.words.contains_key("report"));
+        assert!(!vocabulary.words.contains_key("q3"));
+
+        assert_eq!(vocabulary.suggest("chorme"), ["chrome"]);
+        assert_eq!(vocabulary.suggest("visaul studoi"), ["visual studio"]);
+        // 出现次数多的候选在前，未拼错的词和短词保持原样
+        assert_eq!(vocabulary.suggest("Code reprot q3"), ["Code report q3", "Code reports q3"]);
+        assert!(vocabulary.suggest("chrome").is_empty());
+        assert!(vocabulary.suggest("xyzzy").is_empty());
+        assert!(vocabulary.suggest("").is_empty());
+    }
+}
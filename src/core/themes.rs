@@ -0,0 +1,383 @@
+/// 主题定时切换和用户主题
+///
+/// 按配置的时间或所在地的日出日落决定浅色/深色，未启用定时切换时跟随系统；
+/// 手动切换主题后暂停自动切换，直到下一个切换时刻。
+/// 除内置主题外，还从 `~/.config/werun/themes/*.json` 加载用户主题，文件修改后重新加载
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+
+use crate::{
+    core::config::{ScheduleMode, ThemeSchedule},
+    utils::registry::{self, RegistryRoot},
+};
+
+/// 明暗模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Appearance {
+    /// 浅色
+    Light,
+    /// 深色
+    Dark,
+}
+
+/// 内置主题目录
+pub const BUILTIN_THEMES_DIR: &str = "./themes";
+
+/// 系统个性化设置所在的注册表键
+const PERSONALIZE_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+/// 系统明暗模式是否已变化（收到系统设置变化广播后设置，界面定时取出）
+static SYSTEM_THEME_CHANGED: AtomicBool = AtomicBool::new(false);
+
+/// 系统为应用选择的明暗模式（设置 > 个性化 > 颜色 > 应用模式），读取失败时返回 `None`
+pub fn system_appearance() -> Option<Appearance> {
+    let light =
+        registry::dword_value(RegistryRoot::CurrentUser, PERSONALIZE_KEY, "AppsUseLightTheme")?;
+    Some(if light == 0 { Appearance::Dark } else { Appearance::Light })
+}
+
+/// 标记系统明暗模式或高对比度设置已变化
+pub fn notify_system_theme_changed() {
+    SYSTEM_THEME_CHANGED.store(true, Ordering::SeqCst);
+}
+
+/// 系统明暗模式自上次调用以来是否已变化
+pub fn take_system_theme_changed() -> bool {
+    SYSTEM_THEME_CHANGED.swap(false, Ordering::SeqCst)
+}
+
+/// 主题文件中的主题
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeEntry {
+    /// 主题名称
+    pub name: String,
+    /// 明暗模式
+    pub appearance: Appearance,
+    /// 所在的主题文件
+    pub path: PathBuf,
+    /// 是否为用户主题
+    pub is_user: bool,
+}
+
+/// 用户主题目录 `~/.config/werun/themes`
+pub fn user_themes_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".config").join("werun").join("themes"))
+}
+
+/// 目录中的主题文件（`*.json`，按文件名排序）
+pub fn theme_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// 读取主题文件中各主题的名称和明暗模式
+///
+/// 主题文件与内置主题格式相同：`themes` 数组中每项包含 `name`、`mode`、`colors`，
+/// 以及可选的 `radius`、`font.size`、`font.family` 等
+pub fn parse_theme_entries(json: &str, path: &Path, is_user: bool) -> Vec<ThemeEntry> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+    let themes = value.get("themes").and_then(|themes| themes.as_array());
+    themes
+        .into_iter()
+        .flatten()
+        .filter_map(|theme| {
+            let name = theme.get("name")?.as_str()?.to_string();
+            let appearance = match theme.get("mode").and_then(|mode| mode.as_str()) {
+                Some("dark") => Appearance::Dark,
+                _ => Appearance::Light,
+            };
+            Some(ThemeEntry { name, appearance, path: path.to_path_buf(), is_user })
+        })
+        .collect()
+}
+
+/// 所有可用的主题：用户主题在前，与用户主题同名的内置主题被覆盖
+pub fn list_themes() -> Vec<ThemeEntry> {
+    let user_files = user_themes_dir().map(|dir| theme_files(&dir)).unwrap_or_default();
+    let builtin_files = theme_files(Path::new(BUILTIN_THEMES_DIR));
+    let files = user_files
+        .into_iter()
+        .map(|path| (path, true))
+        .chain(builtin_files.into_iter().map(|path| (path, false)));
+
+    let mut entries: Vec<ThemeEntry> = Vec::new();
+    for (path, is_user) in files {
+        let json = std::fs::read_to_string(&path).unwrap_or_default();
+        for entry in parse_theme_entries(&json, &path, is_user) {
+            if !entries.iter().any(|existing| existing.name == entry.name) {
+                entries.push(entry);
+            }
+        }
+    }
+    entries
+}
+
+/// 待应用的主题（由主题插件请求，界面定时取出应用）
+static REQUESTED_THEME: Mutex<Option<String>> = Mutex::new(None);
+
+/// 用户主题文件是否已修改
+static USER_THEMES_CHANGED: AtomicBool = AtomicBool::new(false);
+
+/// 用户主题目录的监视器，需保持存活
+static USER_THEMES_WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+/// 请求切换到指定主题
+pub fn request_theme(name: &str) {
+    *REQUESTED_THEME.lock().unwrap() = Some(name.to_string());
+}
+
+/// 取出待应用的主题
+pub fn take_theme_request() -> Option<String> {
+    REQUESTED_THEME.lock().unwrap().take()
+}
+
+/// 监视用户主题目录（不存在时创建），文件修改后由 [`take_user_themes_changed`] 报告
+pub fn watch_user_themes() {
+    let Some(dir) = user_themes_dir() else {
+        return;
+    };
+    if let Err(e) = start_watcher(&dir) {
+        log::warn!("监视用户主题目录 {} 失败: {:?}", dir.display(), e);
+    }
+}
+
+/// 创建目录并开始监视
+fn start_watcher(dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    let mut watcher =
+        notify::recommended_watcher(|res: notify::Result<notify::Event>| match res {
+            Ok(event) => {
+                if !matches!(event.kind, EventKind::Access(_)) {
+                    USER_THEMES_CHANGED.store(true, Ordering::SeqCst);
+                }
+            },
+            Err(e) => log::warn!("主题目录监视错误: {:?}", e),
+        })?;
+    watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+    *USER_THEMES_WATCHER.lock().unwrap() = Some(watcher);
+    log::info!("监视用户主题目录: {}", dir.display());
+    Ok(())
+}
+
+/// 用户主题文件自上次调用以来是否已修改
+pub fn take_user_themes_changed() -> bool {
+    USER_THEMES_CHANGED.swap(false, Ordering::SeqCst)
+}
+
+/// 计算某天的日出和日落时间（本地时间）
+///
+/// 使用 NOAA 的简化日出方程，误差在几分钟以内；极昼或极夜时返回 `None`
+pub fn sun_times(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    offset: FixedOffset,
+) -> Option<(NaiveTime, NaiveTime)> {
+    // 自 J2000.0 起的天数（当天正午）
+    let days = (date - NaiveDate::from_ymd_opt(2000, 1, 1)?).num_days() as f64;
+    let mean_solar_noon = days - longitude / 360.0;
+
+    let anomaly = (357.5291 + 0.985_600_28 * mean_solar_noon).rem_euclid(360.0).to_radians();
+    let center =
+        1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+    let ecliptic_longitude =
+        (anomaly.to_degrees() + center + 180.0 + 102.9372).rem_euclid(360.0).to_radians();
+    let transit =
+        mean_solar_noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+
+    let declination = (ecliptic_longitude.sin() * 23.4397_f64.to_radians().sin()).asin();
+    let latitude = latitude.to_radians();
+    let cos_hour_angle = ((-0.833_f64).to_radians().sin() - latitude.sin() * declination.sin())
+        / (latitude.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+    let hour_angle = cos_hour_angle.acos().to_degrees() / 360.0;
+
+    // 儒略日相对 J2000.0 正午的天数 -> 本地时间
+    let to_local = |day: f64| {
+        let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1)?.and_hms_opt(12, 0, 0)?;
+        let utc = j2000 + Duration::seconds((day * 86_400.0).round() as i64);
+        Some((utc + Duration::seconds(offset.local_minus_utc() as i64)).time())
+    };
+    Some((to_local(transit - hour_angle)?, to_local(transit + hour_angle)?))
+}
+
+/// 主题定时切换调度器
+pub struct ThemeScheduler {
+    /// 定时切换配置
+    schedule: ThemeSchedule,
+    /// 本地时区
+    offset: FixedOffset,
+    /// 手动切换后暂停自动切换的截止时间
+    override_until: Option<NaiveDateTime>,
+}
+
+impl ThemeScheduler {
+    /// 创建调度器
+    pub fn new(schedule: ThemeSchedule, offset: FixedOffset) -> Self {
+        Self { schedule, offset, override_until: None }
+    }
+
+    /// 更新配置和时区（配置可能已修改，时区可能因夏令时变化）
+    pub fn update(&mut self, schedule: ThemeSchedule, offset: FixedOffset) {
+        self.schedule = schedule;
+        self.offset = offset;
+    }
+
+    /// 某天切换到浅色和深色的时刻，未启用、时间格式无效或极昼极夜时返回 `None`
+    fn day_times(&self, date: NaiveDate) -> Option<(NaiveTime, NaiveTime)> {
+        let schedule = &self.schedule;
+        match schedule.mode {
+            ScheduleMode::Off => None,
+            ScheduleMode::Times => {
+                let parse = |value: &str| NaiveTime::parse_from_str(value.trim(), "%H:%M").ok();
+                let times = parse(&schedule.light_start).zip(parse(&schedule.dark_start));
+                if times.is_none() {
+                    log::warn!(
+                        "主题切换时间格式无效: {} / {}",
+                        schedule.light_start,
+                        schedule.dark_start
+                    );
+                }
+                times
+            },
+            ScheduleMode::Sun => {
+                sun_times(date, schedule.latitude, schedule.longitude, self.offset)
+            },
+        }
+    }
+
+    /// 按定时切换配置应使用的明暗模式
+    pub fn scheduled(&self, now: NaiveDateTime) -> Option<Appearance> {
+        let (light, dark) = self.day_times(now.date())?;
+        let time = now.time();
+        // 浅色时段可以跨越午夜（如 `light_start = "20:00"`）
+        let is_light =
+            if light <= dark { light <= time && time < dark } else { time >= light || time < dark };
+        Some(if is_light { Appearance::Light } else { Appearance::Dark })
+    }
+
+    /// 下一个切换时刻
+    pub fn next_transition(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        [now.date(), now.date().succ_opt()?]
+            .into_iter()
+            .filter_map(|date| self.day_times(date).map(|times| (date, times)))
+            .flat_map(|(date, (light, dark))| [date.and_time(light), date.and_time(dark)])
+            .filter(|transition| *transition > now)
+            .min()
+    }
+
+    /// 手动切换主题，暂停自动切换直到下一个切换时刻；未启用定时切换时一直保持
+    pub fn override_until_next(&mut self, now: NaiveDateTime) {
+        self.override_until = Some(self.next_transition(now).unwrap_or(NaiveDateTime::MAX));
+    }
+
+    /// 当前应使用的明暗模式，`system` 为跟随系统时的系统明暗模式
+    ///
+    /// 手动切换后到下一个切换时刻之前返回 `None`，保持手动选择的主题
+    pub fn appearance(
+        &mut self,
+        now: NaiveDateTime,
+        system: Option<Appearance>,
+    ) -> Option<Appearance> {
+        if self.override_until.is_some_and(|until| now < until) {
+            return None;
+        }
+        self.override_until = None;
+        self.scheduled(now).or(system)
+    }
+}
+
+/// 全局主题调度器
+static THEME_SCHEDULER: Lazy<Mutex<ThemeScheduler>> = Lazy::new(|| {
+    Mutex::new(ThemeScheduler::new(ThemeSchedule::default(), FixedOffset::east_opt(0).unwrap()))
+});
+
+/// 获取全局主题调度器
+pub fn global_theme_scheduler() -> &'static Mutex<ThemeScheduler> {
+    &THEME_SCHEDULER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: NaiveDate, time: &str) -> NaiveDateTime {
+        date.and_time(NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+    }
+
+    #[test]
+    fn test_schedule_and_override() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+
+        // 北京夏至：日出约 04:46，日落约 19:46
+        let (sunrise, sunset) = sun_times(date, 39.9, 116.4, beijing).unwrap();
+        let minutes = |time: NaiveTime| (time - NaiveTime::MIN).num_minutes();
+        assert!((minutes(sunrise) - (4 * 60 + 46)).abs() <= 5, "日出 {}", sunrise);
+        assert!((minutes(sunset) - (19 * 60 + 46)).abs() <= 5, "日落 {}", sunset);
+        // 北极圈内夏至为极昼
+        assert!(sun_times(date, 80.0, 0.0, beijing).is_none());
+
+        let schedule = ThemeSchedule { mode: ScheduleMode::Times, ..ThemeSchedule::default() };
+        let mut scheduler = ThemeScheduler::new(schedule, beijing);
+        assert_eq!(scheduler.scheduled(at(date, "12:00")), Some(Appearance::Light));
+        assert_eq!(scheduler.scheduled(at(date, "06:59")), Some(Appearance::Dark));
+        assert_eq!(
+            scheduler.next_transition(at(date, "20:00")),
+            Some(at(date.succ_opt().unwrap(), "07:00"))
+        );
+
+        // 手动切换后保持到下一个切换时刻
+        scheduler.override_until_next(at(date, "12:00"));
+        assert_eq!(scheduler.appearance(at(date, "18:00"), None), None);
+        assert_eq!(scheduler.appearance(at(date, "19:00"), None), Some(Appearance::Dark));
+
+        // 未启用定时切换时跟随系统
+        let mut scheduler = ThemeScheduler::new(ThemeSchedule::default(), beijing);
+        assert_eq!(
+            scheduler.appearance(at(date, "12:00"), Some(Appearance::Dark)),
+            Some(Appearance::Dark)
+        );
+    }
+
+    #[test]
+    fn test_parse_theme_entries() {
+        let path = Path::new("mine.json");
+        let json = r##"{
+            "name": "Mine",
+            "themes": [
+                { "name": "Mine Dark", "mode": "dark", "colors": { "background": "#101010" } },
+                { "name": "Mine Light", "colors": {} },
+                { "mode": "dark" }
+            ]
+        }"##;
+        let entries = parse_theme_entries(json, path, true);
+        let names: Vec<_> = entries.iter().map(|e| (e.name.as_str(), e.appearance)).collect();
+        assert_eq!(names, [("Mine Dark", Appearance::Dark), ("Mine Light", Appearance::Light)]);
+        assert!(parse_theme_entries("{", path, true).is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+/// 登录时预热
+///
+/// 以 `--background` 登录启动时窗口保持隐藏，等待插件建立索引并预读首页结果的图标后
+/// 才注册全局快捷键，使当天第一次唤出与之后一样快
+use std::{
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::core::{plugin::PluginIcon, progress::global_progress, search::SearchResult};
+
+/// 检查索引进度的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 预热状态
+#[derive(Default)]
+pub struct WarmUp {
+    /// 首页结果的图标文件，插件初始化完成前为 `None`
+    icons: Mutex<Option<Vec<String>>>,
+}
+
+impl WarmUp {
+    /// 插件初始化完成，记录首页结果中需要预读的图标文件
+    pub fn plugins_ready(&self, results: &[SearchResult]) {
+        let icons = results
+            .iter()
+            .filter_map(|result| match PluginIcon::parse(result.icon.as_deref()?) {
+                PluginIcon::Image(path) => Some(path),
+                PluginIcon::Named(_) => None,
+            })
+            .collect();
+        *self.icons.lock().unwrap() = Some(icons);
+    }
+
+    /// 等待插件初始化和后台索引完成，然后预读图标；超时返回 `false`
+    pub fn wait(&self, timeout: Duration) -> bool {
+        let started = Instant::now();
+        loop {
+            // 先等待一个间隔，插件刚初始化时后台任务可能还未登记
+            std::thread::sleep(POLL_INTERVAL);
+
+            let icons = self.icons.lock().unwrap().clone();
+            if let Some(icons) = icons.filter(|_| !global_progress().is_busy()) {
+                let count = prefetch_files(&icons);
+                log::info!("预热完成，用时 {:?}，预读 {} 个图标", started.elapsed(), count);
+                return true;
+            }
+            if started.elapsed() >= timeout {
+                log::warn!("预热超时 ({:?})，不再等待索引完成", timeout);
+                return false;
+            }
+        }
+    }
+}
+
+/// 读取文件内容使其进入系统文件缓存，返回成功读取的文件数
+pub fn prefetch_files(paths: &[String]) -> usize {
+    paths.iter().filter(|path| std::fs::read(Path::new(path)).is_ok()).count()
+}
+
+/// 全局预热状态
+static GLOBAL_WARM_UP: Lazy<WarmUp> = Lazy::new(WarmUp::default);
+
+/// 获取全局预热状态
+pub fn global_warm_up() -> &'static WarmUp {
+    &GLOBAL_WARM_UP
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::search::{ActionData, ResultType};
+
+    #[test]
+    fn test_wait_for_plugins_and_prefetch() {
+        let dir = std::env::temp_dir().join(format!("werun-warm-up-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let icon = dir.join("app.png");
+        std::fs::write(&icon, b"png").unwrap();
+
+        let result = |icon: Option<String>| {
+            SearchResult::new(
+                "app_launcher:app".to_string(),
+                "App".to_string(),
+                String::new(),
+                ResultType::Application,
+                100,
+                ActionData::OpenFile { path: String::new() },
+            )
+            .with_icon(icon)
+        };
+
+        // 插件尚未初始化时等待超时
+        let warm_up = WarmUp::default();
+        assert!(!warm_up.wait(Duration::ZERO));
+
+        // 只预读图片图标，内置图标名称和不存在的文件跳过
+        let missing = dir.join("missing.png").to_string_lossy().to_string();
+        warm_up.plugins_ready(&[
+            result(Some(icon.to_string_lossy().to_string())),
+            result(Some("calculator".to_string())),
+            result(Some(missing.clone())),
+            result(None),
+        ]);
+        assert_eq!(warm_up.icons.lock().unwrap().as_ref().map(Vec::len), Some(2));
+        assert_eq!(prefetch_files(&[icon.to_string_lossy().to_string(), missing]), 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
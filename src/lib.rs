@@ -9,3 +9,4 @@ pub mod platform;
 pub mod plugins;
 pub mod ui;
 pub mod utils;
+pub mod window_manager;
@@ -14,11 +14,27 @@ mod ui;
 mod utils;
 mod window_manager;
 
-use core::config_manager::global_config;
+use core::{
+    config::{AppConfig, PrefillSource},
+    config_manager::global_config,
+    launch::LaunchCommand,
+    prefill::prefill_query,
+    warm_up::global_warm_up,
+};
 
 use app::LauncherApp;
 use gpui_component_assets::Assets;
-use ui::create_new_window;
+use platform::{
+    autostart, jump_list,
+    windows::{
+        hotkeys_paused, selected_text, set_hotkeys_paused, CommandListener, HotstringListener,
+        TrayCommand, TrayIcon,
+    },
+};
+use plugins::clipboard::is_sensitive_clipboard;
+use ui::{create_background_window, create_new_window};
+use utils::clipboard::ClipboardManager;
+use window_manager::global_window_manager;
 
 /// 枚举窗口数据结构
 struct EnumData {
@@ -34,57 +50,135 @@ fn main() {
 
     log::info!("WeRun 启动器初始化...");
     log::info!("配置目录: {:?}", dirs::config_dir());
+
+    // 跳转列表任务等带参数启动时，已有实例在运行则转发命令后退出
+    let command = LaunchCommand::parse(std::env::args().skip(1));
+    if let Some(command) = &command {
+        if CommandListener::send(&command.encode()) {
+            log::info!("已将启动命令转发给运行中的 WeRun: {:?}", command);
+            return;
+        }
+    }
+
+    // 登录时后台启动：预热期间以后台优先级运行，不显示窗口
+    let background = matches!(command, Some(LaunchCommand::Background(_)));
+    if let Some(LaunchCommand::Background(dir)) = &command {
+        log::info!("登录时后台启动，开始预热");
+        // 配置文件按工作目录查找，回到登记启动项时的目录
+        if let Some(dir) = dir {
+            if let Err(e) = std::env::set_current_dir(dir) {
+                log::warn!("切换工作目录失败: {} ({})", dir, e);
+            }
+        }
+        autostart::set_background_priority(true);
+    }
+
     let app = gpui_platform::application().with_assets(Assets);
 
     // 启动 GPUI 应用
     app.run(move |cx: &mut App| {
         // 初始化 werun
         ui::init(cx);
-        // 激活应用
-        cx.activate(true);
+        // 激活应用，后台启动时保持在后台
+        if !background {
+            cx.activate(true);
+        }
 
         // 加载配置
         let config = global_config().get_config();
         log::info!("当前主题: {}", config.theme.current_theme);
         log::info!("窗口大小: {}x{}", config.window.width, config.window.height);
 
+        // 启动参数中的查询在窗口创建后填入，启动时窗口已显示，无需切换
+        match command {
+            Some(LaunchCommand::Query(query)) => global_window_manager().set_pending_query(&query),
+            Some(LaunchCommand::Settings) => open_settings(),
+            Some(LaunchCommand::Toggle | LaunchCommand::Background(_)) | None => {},
+        }
+
         // 打开启动器窗口
-        create_new_window("WeRun", LauncherApp::view, cx);
+        if background {
+            create_background_window("WeRun", LauncherApp::view, cx);
+        } else {
+            create_new_window("WeRun", LauncherApp::view, cx);
+        }
+
+        // 配置中明确设置时才登记或移除登录时启动项
+        if let Some(enabled) = config.startup.launch_at_login {
+            if let Err(e) = autostart::set_launch_at_login(enabled) {
+                e.log();
+            }
+        }
+
+        // 接收之后再次启动时转发的命令，并更新任务栏跳转列表
+        start_command_listener();
+        jump_list::refresh();
+
+        // 通知区域图标，窗口隐藏时也能找到启动器
+        if let Err(e) = TrayIcon::start(handle_tray_command) {
+            e.log();
+        }
 
-        // 注册全局快捷键
-        register_global_hotkey();
+        // 按配置启用在任意程序中的缩写展开
+        if let Err(e) = HotstringListener::start(global_config().hotstrings()) {
+            e.log();
+        }
+
+        // 注册全局快捷键，后台启动时预热完成后才注册
+        register_global_hotkey(background);
     });
 
-    // 应用退出时保存配置
+    // 应用退出时移除托盘图标并保存配置
+    TrayIcon::remove();
     if let Err(e) = global_config().save() {
-        log::error!("保存配置失败: {:?}", e);
+        e.log();
     }
 }
 
 /// 注册全局快捷键 Alt+Space
-fn register_global_hotkey() {
+///
+/// `warm_up` 时先等待索引建立和图标预读完成，再恢复正常优先级并注册
+fn register_global_hotkey(warm_up: bool) {
     use platform::windows::GlobalHotkeyManager;
 
     // 从配置中读取快捷键
-    let toggle_key = global_config().get_config().keybindings.toggle_launcher;
-    log::info!("注册全局快捷键: {}", toggle_key);
+    let keybindings = global_config().get_config().keybindings;
+    log::info!("注册全局快捷键: {}", keybindings.toggle_launcher);
 
     std::thread::spawn(move || {
-        // 等待窗口创建完成
-        std::thread::sleep(std::time::Duration::from_millis(2000));
+        if warm_up {
+            let timeout = global_config().startup().warm_up_timeout_secs;
+            global_warm_up().wait(std::time::Duration::from_secs(timeout));
+            autostart::set_background_priority(false);
+        } else {
+            // 等待窗口创建完成
+            std::thread::sleep(std::time::Duration::from_millis(2000));
+        }
 
         log::info!("开始注册全局快捷键...");
 
         match GlobalHotkeyManager::new() {
             Ok(mut manager) => {
                 log::info!("快捷键管理器创建成功");
+                manager.set_passthrough(
+                    keybindings.hotkey_passthrough,
+                    std::time::Duration::from_millis(keybindings.double_press_ms),
+                    is_launcher_visible,
+                );
                 if let Err(e) = manager.register_alt_space(|| {
                     log::info!("Alt+Space 快捷键被触发");
                     // 切换窗口显示/隐藏
                     toggle_launcher_window();
                 }) {
-                    log::error!("注册全局快捷键失败: {:?}", e);
+                    e.log();
                 } else {
+                    let prefill = keybindings.prefill_launcher.clone();
+                    if let Err(e) = manager.register_prefill(&prefill, || {
+                        // 模拟复制需要等待剪贴板更新，不阻塞快捷键的消息循环
+                        std::thread::spawn(show_prefilled_launcher);
+                    }) {
+                        e.log();
+                    }
                     // 将 manager 放入全局变量，防止被 Drop
                     unsafe {
                         HOTKEY_MANAGER = Some(Box::new(manager));
@@ -93,17 +187,138 @@ fn register_global_hotkey() {
                 }
             },
             Err(e) => {
-                log::error!("创建快捷键管理器失败: {:?}", e);
+                e.log();
             },
         }
     });
 }
 
+/// 监听其他实例转发的启动命令
+fn start_command_listener() {
+    let result = CommandListener::start(|message| match LaunchCommand::decode(&message) {
+        Some(command) => handle_launch_command(command),
+        None => log::warn!("无法识别的启动命令: {:?}", message),
+    });
+
+    if let Err(e) = result {
+        e.log();
+    }
+}
+
+/// 处理转发的启动命令
+fn handle_launch_command(command: LaunchCommand) {
+    match command {
+        LaunchCommand::Toggle => toggle_launcher_window(),
+        LaunchCommand::Settings => open_settings(),
+        LaunchCommand::Query(query) => {
+            // 查询在窗口激活时填入搜索框
+            global_window_manager().set_pending_query(&query);
+            show_launcher_window();
+        },
+        // 已在运行，无需再次预热
+        LaunchCommand::Background(_) => {},
+    }
+}
+
+/// 处理托盘菜单命令
+///
+/// 重新扫描和退出需要在界面线程中进行，由启动器窗口的定时任务取出执行
+fn handle_tray_command(command: TrayCommand) {
+    match command {
+        TrayCommand::Toggle => toggle_launcher_window(),
+        TrayCommand::Settings => open_settings(),
+        TrayCommand::Rescan => global_window_manager().request_rescan(),
+        TrayCommand::PauseHotkeys => set_hotkeys_paused(!hotkeys_paused()),
+        TrayCommand::Quit => global_window_manager().request_quit(),
+    }
+}
+
+/// 用默认程序打开配置文件
+fn open_settings() {
+    let path = AppConfig::config_path();
+    log::info!("打开配置文件: {}", path.display());
+
+    let result = std::process::Command::new("cmd").args(["/c", "start", ""]).arg(&path).spawn();
+    if let Err(e) = result {
+        log::warn!("打开配置文件失败: {}", e);
+    }
+}
+
+/// 显示并激活启动器窗口，已显示时只激活
+fn show_launcher_window() {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        IsWindowVisible, SetForegroundWindow, ShowWindow, SW_SHOW,
+    };
+
+    let Some(hwnd) = find_launcher_window() else {
+        log::warn!("未找到 WeRun 窗口");
+        return;
+    };
+
+    unsafe {
+        if !IsWindowVisible(hwnd).as_bool() {
+            global_window_manager().capture_foreground();
+            global_window_manager().place_window(hwnd.0 as isize);
+            let _ = ShowWindow(hwnd, SW_SHOW);
+        }
+        let _ = SetForegroundWindow(hwnd);
+    }
+}
+
+/// 以前台程序中选中的文本或剪贴板内容预填搜索框，再显示启动器
+fn show_prefilled_launcher() {
+    if !is_launcher_visible() {
+        prefill_pending_query();
+    }
+    show_launcher_window();
+}
+
+/// 按预填配置读取选中的文本或剪贴板内容，作为窗口激活时填入的查询
+///
+/// 需在显示启动器之前调用，此时前台仍是用户正在使用的程序
+fn prefill_pending_query() {
+    let config = global_config().prefill();
+    let selection = (config.source != PrefillSource::Clipboard)
+        .then(|| selected_text(config.simulate_copy))
+        .flatten();
+    // 剪贴板中的敏感内容（密码、令牌等）不填入搜索框
+    let clipboard = (config.source != PrefillSource::Selection)
+        .then(|| {
+            let manager = ClipboardManager::new();
+            let text = manager.get_text().ok()?;
+            (!is_sensitive_clipboard(&manager, &text)).then_some(text)
+        })
+        .flatten();
+
+    match prefill_query(&config, selection.as_deref(), clipboard.as_deref()) {
+        Some(query) => {
+            log::info!("预填查询: {}", query);
+            global_window_manager().set_pending_query(&query);
+        },
+        None => log::info!("没有可预填的内容"),
+    }
+}
+
 /// 切换窗口显示/隐藏（供快捷键调用）
 fn toggle_launcher_window() {
     log::info!("请求切换窗口状态");
 
-    // 使用 Windows API 直接操作窗口
+    match find_launcher_window() {
+        Some(hwnd) => unsafe { toggle_window_visibility(hwnd) },
+        None => log::warn!("未找到 WeRun 窗口"),
+    }
+}
+
+/// 启动器窗口是否可见
+fn is_launcher_visible() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::IsWindowVisible;
+
+    find_launcher_window().is_some_and(|hwnd| unsafe { IsWindowVisible(hwnd).as_bool() })
+}
+
+/// 查找启动器窗口
+fn find_launcher_window() -> Option<windows::Win32::Foundation::HWND> {
+    // 使用 Windows API 直接查找窗口
     use windows::Win32::{
         Foundation::LPARAM,
         UI::WindowsAndMessaging::{EnumWindows, FindWindowW},
@@ -119,8 +334,7 @@ fn toggle_launcher_window() {
         match FindWindowW(None, windows::core::PCWSTR(window_name.as_ptr())) {
             Ok(hwnd) => {
                 log::info!("找到窗口 (通过标题): {:?}", hwnd);
-                toggle_window_visibility(hwnd);
-                return;
+                return Some(hwnd);
             },
             Err(e) => {
                 log::warn!("通过标题查找窗口失败: {:?}", e);
@@ -136,26 +350,30 @@ fn toggle_launcher_window() {
 
         if let Some(hwnd) = enum_data.found_hwnd {
             log::info!("找到窗口 (通过枚举): {:?}", hwnd);
-            toggle_window_visibility(hwnd);
-            return;
         }
-
-        log::warn!("未找到 WeRun 窗口");
+        enum_data.found_hwnd
     }
 }
 
 /// 切换窗口可见性
 unsafe fn toggle_window_visibility(hwnd: windows::Win32::Foundation::HWND) {
     use windows::Win32::UI::WindowsAndMessaging::{
-        IsWindowVisible, SetForegroundWindow, ShowWindow, SW_HIDE, SW_SHOW,
+        IsWindowVisible, SetForegroundWindow, ShowWindow, SW_SHOW,
     };
 
     // 检查窗口是否可见
     if IsWindowVisible(hwnd).as_bool() {
         log::info!("窗口当前可见，执行隐藏");
-        let _ = ShowWindow(hwnd, SW_HIDE);
+        global_window_manager().hide_launcher(hwnd.0 as isize, true);
     } else {
         log::info!("窗口当前隐藏，执行显示");
+        if global_config().prefill().on_toggle {
+            // 模拟复制需要等待剪贴板更新，不阻塞快捷键的消息循环，读取后再显示
+            std::thread::spawn(show_prefilled_launcher);
+            return;
+        }
+        global_window_manager().capture_foreground();
+        global_window_manager().place_window(hwnd.0 as isize);
         let _ = ShowWindow(hwnd, SW_SHOW);
         // 激活窗口
         let _ = SetForegroundWindow(hwnd);
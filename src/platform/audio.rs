@@ -0,0 +1,206 @@
+/// 音频设备控制
+///
+/// 通过 Core Audio 枚举播放和录制设备、读取和设置默认设备的音量与静音；
+/// 切换默认设备使用系统声音设置同样调用的 IPolicyConfig 接口（未公开，Windows 7 起可用）
+use windows::{
+    core::{interface, IUnknown, IUnknown_Vtbl, GUID, HRESULT, HSTRING, PCWSTR},
+    Win32::{
+        Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+        Media::Audio::{
+            eCapture, eCommunications, eConsole, eMultimedia, eRender, EDataFlow, ERole,
+            Endpoints::IAudioEndpointVolume, IMMDevice, IMMDeviceEnumerator, MMDeviceEnumerator,
+            DEVICE_STATE_ACTIVE,
+        },
+        System::Com::{
+            CoCreateInstance, CoTaskMemFree, CLSCTX_ALL, COINIT_MULTITHREADED, STGM_READ,
+        },
+    },
+};
+
+use crate::{
+    core::error::{Result, WerunError},
+    platform::com,
+};
+
+/// CPolicyConfigClient
+const POLICY_CONFIG_CLIENT: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+
+/// 设置默认设备的接口，只声明到 SetDefaultEndpoint，之前的方法按顺序占位
+#[interface("f8679f50-850a-41cf-9c72-430f290290c8")]
+unsafe trait IPolicyConfig: IUnknown {
+    fn get_mix_format(&self, device: PCWSTR, format: *mut *mut std::ffi::c_void) -> HRESULT;
+    fn get_device_format(
+        &self,
+        device: PCWSTR,
+        default: i32,
+        format: *mut *mut std::ffi::c_void,
+    ) -> HRESULT;
+    fn reset_device_format(&self, device: PCWSTR) -> HRESULT;
+    fn set_device_format(
+        &self,
+        device: PCWSTR,
+        endpoint_format: *mut std::ffi::c_void,
+        mix_format: *mut std::ffi::c_void,
+    ) -> HRESULT;
+    fn get_processing_period(
+        &self,
+        device: PCWSTR,
+        default: i32,
+        default_period: *mut i64,
+        min_period: *mut i64,
+    ) -> HRESULT;
+    fn set_processing_period(&self, device: PCWSTR, period: *mut i64) -> HRESULT;
+    fn get_share_mode(&self, device: PCWSTR, mode: *mut std::ffi::c_void) -> HRESULT;
+    fn set_share_mode(&self, device: PCWSTR, mode: *mut std::ffi::c_void) -> HRESULT;
+    fn get_property_value(
+        &self,
+        device: PCWSTR,
+        key: *const std::ffi::c_void,
+        value: *mut std::ffi::c_void,
+    ) -> HRESULT;
+    fn set_property_value(
+        &self,
+        device: PCWSTR,
+        key: *const std::ffi::c_void,
+        value: *mut std::ffi::c_void,
+    ) -> HRESULT;
+    fn set_default_endpoint(&self, device: PCWSTR, role: ERole) -> HRESULT;
+}
+
+/// 设备类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceKind {
+    /// 播放设备
+    Playback,
+    /// 录制设备
+    Recording,
+}
+
+impl DeviceKind {
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeviceKind::Playback => "播放",
+            DeviceKind::Recording => "录制",
+        }
+    }
+
+    /// 对应的数据流方向
+    fn data_flow(&self) -> EDataFlow {
+        match self {
+            DeviceKind::Playback => eRender,
+            DeviceKind::Recording => eCapture,
+        }
+    }
+}
+
+/// 音频设备
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioDevice {
+    /// 设备 ID
+    pub id: String,
+    /// 设备名称
+    pub name: String,
+    /// 设备类型
+    pub kind: DeviceKind,
+    /// 是否为默认设备
+    pub is_default: bool,
+    /// 音量（0 到 100）
+    pub volume: u8,
+    /// 是否静音
+    pub muted: bool,
+}
+
+/// 列出已启用的设备，默认设备在前
+pub fn devices(kind: DeviceKind) -> Result<Vec<AudioDevice>> {
+    with_com(|| unsafe {
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let default_id = enumerator
+            .GetDefaultAudioEndpoint(kind.data_flow(), eConsole)
+            .and_then(|device| device_id(&device))
+            .ok();
+
+        let collection = enumerator.EnumAudioEndpoints(kind.data_flow(), DEVICE_STATE_ACTIVE)?;
+        let mut devices = Vec::new();
+        for index in 0..collection.GetCount()? {
+            let device = collection.Item(index)?;
+            let id = device_id(&device)?;
+            let volume: IAudioEndpointVolume = device.Activate(CLSCTX_ALL, None)?;
+            devices.push(AudioDevice {
+                is_default: default_id.as_ref() == Some(&id),
+                id,
+                name: friendly_name(&device).unwrap_or_else(|_| "未知设备".to_string()),
+                kind,
+                volume: (volume.GetMasterVolumeLevelScalar()? * 100.0).round() as u8,
+                muted: volume.GetMute()?.as_bool(),
+            });
+        }
+
+        devices.sort_by_key(|device| !device.is_default);
+        Ok(devices)
+    })
+}
+
+/// 将设备设为所有用途（控制台、多媒体、通信）的默认设备
+pub fn set_default_device(id: &str) -> Result<()> {
+    with_com(|| unsafe {
+        let policy: IPolicyConfig = CoCreateInstance(&POLICY_CONFIG_CLIENT, None, CLSCTX_ALL)?;
+        let id = HSTRING::from(id);
+        for role in [eConsole, eMultimedia, eCommunications] {
+            policy.set_default_endpoint(PCWSTR(id.as_ptr()), role).ok()?;
+        }
+        Ok(())
+    })
+}
+
+/// 设置默认设备的音量（0 到 100），同时取消静音
+pub fn set_volume(kind: DeviceKind, volume: u8) -> Result<()> {
+    with_default_volume(kind, |endpoint| unsafe {
+        endpoint.SetMasterVolumeLevelScalar(f32::from(volume.min(100)) / 100.0, &GUID::zeroed())?;
+        endpoint.SetMute(false, &GUID::zeroed())
+    })
+}
+
+/// 切换默认设备的静音状态，返回切换后是否静音
+pub fn toggle_mute(kind: DeviceKind) -> Result<bool> {
+    with_default_volume(kind, |endpoint| unsafe {
+        let muted = !endpoint.GetMute()?.as_bool();
+        endpoint.SetMute(muted, &GUID::zeroed())?;
+        Ok(muted)
+    })
+}
+
+/// 对默认设备的音量控制执行操作
+fn with_default_volume<T>(
+    kind: DeviceKind,
+    operation: impl FnOnce(&IAudioEndpointVolume) -> windows::core::Result<T>,
+) -> Result<T> {
+    with_com(|| unsafe {
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let device = enumerator.GetDefaultAudioEndpoint(kind.data_flow(), eConsole)?;
+        let endpoint: IAudioEndpointVolume = device.Activate(CLSCTX_ALL, None)?;
+        operation(&endpoint)
+    })
+}
+
+/// 在已初始化 COM 的线程上执行操作
+fn with_com<T>(operation: impl FnOnce() -> windows::core::Result<T>) -> Result<T> {
+    com::with_com(COINIT_MULTITHREADED, operation).map_err(|e| WerunError::Audio(e.message()))
+}
+
+/// 设备 ID
+unsafe fn device_id(device: &IMMDevice) -> windows::core::Result<String> {
+    let id = device.GetId()?;
+    let text = id.to_string().unwrap_or_default();
+    CoTaskMemFree(Some(id.0 as *const _));
+    Ok(text)
+}
+
+/// 设备名称（如“扬声器 (Realtek High Definition Audio)”）
+unsafe fn friendly_name(device: &IMMDevice) -> windows::core::Result<String> {
+    let store = device.OpenPropertyStore(STGM_READ)?;
+    let value = store.GetValue(&PKEY_Device_FriendlyName)?;
+    Ok(value.to_string())
+}
@@ -0,0 +1,54 @@
+/// 登录时启动
+///
+/// 在当前用户的 Run 键下登记 `werun --background <工作目录>`，登录后以后台优先级预热，
+/// 预热期间降低 CPU、磁盘和内存优先级，避免拖慢登录
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, SetPriorityClass, PROCESS_MODE_BACKGROUND_BEGIN, PROCESS_MODE_BACKGROUND_END,
+};
+
+use crate::{
+    core::{
+        error::{Result, WerunError},
+        launch::LaunchCommand,
+    },
+    utils::registry::{self, RegistryRoot},
+};
+
+/// 登录时启动的程序登记键
+const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+
+/// 启动项名称
+const RUN_VALUE: &str = "WeRun";
+
+/// 按配置登记或移除登录时启动项，启动项已与配置一致时不写注册表
+pub fn set_launch_at_login(enabled: bool) -> Result<()> {
+    let current = registry::string_value(RegistryRoot::CurrentUser, RUN_KEY, Some(RUN_VALUE));
+    let result = if enabled {
+        let command = run_command().map_err(|e| WerunError::Autostart(e.to_string()))?;
+        if current.as_deref() == Some(command.as_str()) {
+            return Ok(());
+        }
+        registry::set_string_value(RegistryRoot::CurrentUser, RUN_KEY, RUN_VALUE, &command)
+    } else if current.is_some() {
+        registry::delete_value(RegistryRoot::CurrentUser, RUN_KEY, RUN_VALUE)
+    } else {
+        return Ok(());
+    };
+    result.map_err(|e| WerunError::Autostart(e.to_string()))
+}
+
+/// 启动项的命令行，带上当前工作目录以便登录后找到同一份配置文件
+fn run_command() -> std::io::Result<String> {
+    let exe = std::env::current_exe()?;
+    let dir = std::env::current_dir()?.to_string_lossy().to_string();
+    let command = LaunchCommand::Background(Some(dir));
+    Ok(format!("\"{}\" {}", exe.display(), command.command_line()))
+}
+
+/// 进入或退出后台优先级
+pub fn set_background_priority(enabled: bool) {
+    let mode = if enabled { PROCESS_MODE_BACKGROUND_BEGIN } else { PROCESS_MODE_BACKGROUND_END };
+    if let Err(e) = unsafe { SetPriorityClass(GetCurrentProcess(), mode) } {
+        log::warn!("切换后台优先级失败: {:?}", e);
+    }
+}
@@ -0,0 +1,131 @@
+/// 窗口背景材质
+///
+/// Windows 11 22H2 起通过 DWM 的系统背景类型设置云母、亚克力材质；
+/// 更早的系统退回到未公开的 `SetWindowCompositionAttribute` 模糊背景，都不支持时返回 `false`
+use std::ffi::c_void;
+
+use windows::{
+    core::{s, w},
+    Win32::{
+        Foundation::{BOOL, HWND},
+        Graphics::Dwm::{
+            DwmExtendFrameIntoClientArea, DwmSetWindowAttribute, DWMSBT_MAINWINDOW,
+            DWMSBT_TRANSIENTWINDOW, DWMWA_SYSTEMBACKDROP_TYPE, DWMWA_USE_IMMERSIVE_DARK_MODE,
+            DWM_SYSTEMBACKDROP_TYPE,
+        },
+        System::LibraryLoader::{GetModuleHandleW, GetProcAddress},
+        UI::Controls::MARGINS,
+    },
+};
+
+use crate::core::config::Backdrop;
+
+/// `SetWindowCompositionAttribute` 的强调效果属性
+const WCA_ACCENT_POLICY: u32 = 19;
+/// 强调效果：亚克力模糊背景
+const ACCENT_ENABLE_ACRYLICBLURBEHIND: u32 = 4;
+/// 模糊背景的叠加色（ABGR），深色主题
+const DARK_TINT: u32 = 0x99_20_20_20;
+/// 模糊背景的叠加色（ABGR），浅色主题
+const LIGHT_TINT: u32 = 0x99_F3_F3_F3;
+
+/// 强调效果参数
+#[repr(C)]
+struct AccentPolicy {
+    accent_state: u32,
+    accent_flags: u32,
+    gradient_color: u32,
+    animation_id: u32,
+}
+
+/// 窗口合成属性
+#[repr(C)]
+struct WindowCompositionAttribData {
+    attribute: u32,
+    data: *mut c_void,
+    size: usize,
+}
+
+/// `user32!SetWindowCompositionAttribute`
+type SetWindowCompositionAttributeFn =
+    unsafe extern "system" fn(HWND, *mut WindowCompositionAttribData) -> BOOL;
+
+/// 为窗口设置背景材质，`dark` 决定材质的明暗
+///
+/// 返回是否已生效；未启用或系统不支持时窗口保持原来的透明背景
+pub fn apply(hwnd: isize, backdrop: Backdrop, dark: bool) -> bool {
+    if backdrop == Backdrop::None {
+        return false;
+    }
+
+    let hwnd = HWND(hwnd as *mut c_void);
+    if apply_system_backdrop(hwnd, backdrop, dark) {
+        log::info!("已启用窗口背景材质: {:?}", backdrop);
+        return true;
+    }
+    if apply_accent_blur(hwnd, dark) {
+        log::info!("系统不支持 {:?} 背景材质，改用模糊背景", backdrop);
+        return true;
+    }
+
+    log::warn!("系统不支持窗口背景材质，保持透明背景");
+    false
+}
+
+/// 通过 DWM 系统背景类型设置材质（Windows 11 22H2 及以上）
+fn apply_system_backdrop(hwnd: HWND, backdrop: Backdrop, dark: bool) -> bool {
+    let backdrop_type: DWM_SYSTEMBACKDROP_TYPE = match backdrop {
+        Backdrop::Mica => DWMSBT_MAINWINDOW,
+        Backdrop::Acrylic => DWMSBT_TRANSIENTWINDOW,
+        Backdrop::None => return false,
+    };
+    let dark_mode = BOOL::from(dark);
+    let margins =
+        MARGINS { cxLeftWidth: -1, cxRightWidth: -1, cyTopHeight: -1, cyBottomHeight: -1 };
+
+    unsafe {
+        // 材质的明暗跟随窗口的深色模式属性，旧系统不认识该属性时忽略
+        let _ = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_USE_IMMERSIVE_DARK_MODE,
+            &dark_mode as *const BOOL as *const c_void,
+            std::mem::size_of::<BOOL>() as u32,
+        );
+        if DwmExtendFrameIntoClientArea(hwnd, &margins).is_err() {
+            return false;
+        }
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_SYSTEMBACKDROP_TYPE,
+            &backdrop_type as *const DWM_SYSTEMBACKDROP_TYPE as *const c_void,
+            std::mem::size_of::<DWM_SYSTEMBACKDROP_TYPE>() as u32,
+        )
+        .is_ok()
+    }
+}
+
+/// 通过 `SetWindowCompositionAttribute` 设置亚克力模糊背景（Windows 10 1803 及以上）
+fn apply_accent_blur(hwnd: HWND, dark: bool) -> bool {
+    unsafe {
+        let Ok(user32) = GetModuleHandleW(w!("user32.dll")) else {
+            return false;
+        };
+        let Some(proc) = GetProcAddress(user32, s!("SetWindowCompositionAttribute")) else {
+            return false;
+        };
+        let set_attribute: SetWindowCompositionAttributeFn = std::mem::transmute(proc);
+
+        let mut policy = AccentPolicy {
+            accent_state: ACCENT_ENABLE_ACRYLICBLURBEHIND,
+            accent_flags: 0,
+            gradient_color: if dark { DARK_TINT } else { LIGHT_TINT },
+            animation_id: 0,
+        };
+        let mut data = WindowCompositionAttribData {
+            attribute: WCA_ACCENT_POLICY,
+            data: &mut policy as *mut AccentPolicy as *mut c_void,
+            size: std::mem::size_of::<AccentPolicy>(),
+        };
+        set_attribute(hwnd, &mut data).as_bool()
+    }
+}
@@ -0,0 +1,17 @@
+/// COM 初始化
+///
+/// 平台功能多在后台线程中调用 COM 或 WinRT，调用前需要为当前线程初始化 COM
+use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT};
+
+/// 在已初始化 COM 的线程上执行操作
+///
+/// 当前线程已由其他代码以不同模式初始化时（RPC_E_CHANGED_MODE）仍可使用 COM，
+/// 但不能反初始化；只有本次初始化成功时才在操作结束后反初始化
+pub fn with_com<T>(mode: COINIT, operation: impl FnOnce() -> T) -> T {
+    let initialized = unsafe { CoInitializeEx(None, mode) }.is_ok();
+    let result = operation();
+    if initialized {
+        unsafe { CoUninitialize() };
+    }
+    result
+}
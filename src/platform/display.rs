@@ -0,0 +1,366 @@
+/// 显示器控制
+///
+/// 枚举显示器及其支持的分辨率和刷新率并切换，切换投影模式（仅电脑屏幕、复制、扩展、
+/// 仅第二屏幕），读取和设置亮度：内置屏幕通过 WMI，外接显示器通过 DDC/CI
+use std::fmt;
+
+use windows::{
+    core::{w, BSTR, HSTRING, PCWSTR, VARIANT},
+    Win32::{
+        Devices::Display::{
+            DestroyPhysicalMonitors, GetMonitorBrightness, GetNumberOfPhysicalMonitorsFromHMONITOR,
+            GetPhysicalMonitorsFromHMONITOR, SetDisplayConfig, SetMonitorBrightness,
+            PHYSICAL_MONITOR, SDC_APPLY, SDC_TOPOLOGY_CLONE, SDC_TOPOLOGY_EXTEND,
+            SDC_TOPOLOGY_EXTERNAL, SDC_TOPOLOGY_INTERNAL, SET_DISPLAY_CONFIG_FLAGS,
+        },
+        Foundation::{BOOL, ERROR_SUCCESS, HANDLE, LPARAM, RECT, TRUE},
+        Graphics::Gdi::{
+            ChangeDisplaySettingsExW, EnumDisplayDevicesW, EnumDisplayMonitors,
+            EnumDisplaySettingsW, CDS_UPDATEREGISTRY, DEVMODEW, DISPLAY_DEVICEW,
+            DISPLAY_DEVICE_ATTACHED_TO_DESKTOP, DISPLAY_DEVICE_PRIMARY_DEVICE,
+            DISP_CHANGE_SUCCESSFUL, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT, DM_PELSWIDTH,
+            ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE, HDC, HMONITOR,
+        },
+        System::{
+            Com::{CoCreateInstance, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED},
+            Wmi::{
+                IWbemClassObject, IWbemLocator, IWbemServices, WbemLocator, WBEM_FLAG_FORWARD_ONLY,
+                WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+            },
+        },
+    },
+};
+
+use crate::{
+    core::error::{Result, WerunError},
+    platform::com,
+};
+
+/// 显示模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplayMode {
+    /// 宽度（像素）
+    pub width: u32,
+    /// 高度（像素）
+    pub height: u32,
+    /// 刷新率（Hz）
+    pub refresh_rate: u32,
+}
+
+impl fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}×{} @ {} Hz", self.width, self.height, self.refresh_rate)
+    }
+}
+
+/// 显示器
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monitor {
+    /// 设备名（如 `\\.\DISPLAY1`）
+    pub device: String,
+    /// 显示器名称
+    pub name: String,
+    /// 是否为主显示器
+    pub is_primary: bool,
+    /// 当前模式
+    pub current: DisplayMode,
+    /// 支持的模式，按分辨率和刷新率从高到低排列
+    pub modes: Vec<DisplayMode>,
+}
+
+/// 投影模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Projection {
+    /// 仅电脑屏幕
+    PcScreenOnly,
+    /// 复制
+    Duplicate,
+    /// 扩展
+    Extend,
+    /// 仅第二屏幕
+    SecondScreenOnly,
+}
+
+impl Projection {
+    /// 全部投影模式，按系统投影菜单的顺序
+    pub const ALL: [Projection; 4] = [
+        Projection::PcScreenOnly,
+        Projection::Duplicate,
+        Projection::Extend,
+        Projection::SecondScreenOnly,
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Projection::PcScreenOnly => "仅电脑屏幕",
+            Projection::Duplicate => "复制",
+            Projection::Extend => "扩展",
+            Projection::SecondScreenOnly => "仅第二屏幕",
+        }
+    }
+
+    /// 对应的拓扑标志
+    fn topology(&self) -> SET_DISPLAY_CONFIG_FLAGS {
+        match self {
+            Projection::PcScreenOnly => SDC_TOPOLOGY_INTERNAL,
+            Projection::Duplicate => SDC_TOPOLOGY_CLONE,
+            Projection::Extend => SDC_TOPOLOGY_EXTEND,
+            Projection::SecondScreenOnly => SDC_TOPOLOGY_EXTERNAL,
+        }
+    }
+}
+
+/// 列出连接到桌面的显示器，主显示器在前
+pub fn monitors() -> Vec<Monitor> {
+    let mut monitors = Vec::new();
+
+    for index in 0.. {
+        let mut adapter = display_device();
+        if !unsafe { EnumDisplayDevicesW(PCWSTR::null(), index, &mut adapter, 0) }.as_bool() {
+            break;
+        }
+        if adapter.StateFlags & DISPLAY_DEVICE_ATTACHED_TO_DESKTOP == 0 {
+            continue;
+        }
+
+        let device = wide_to_string(&adapter.DeviceName);
+        let device_name = HSTRING::from(&device);
+        let Some(current) = display_mode(&device_name, ENUM_CURRENT_SETTINGS) else {
+            continue;
+        };
+
+        // 同一设备名下第一个子设备为显示器本身
+        let mut monitor = display_device();
+        let name = if unsafe { EnumDisplayDevicesW(&device_name, 0, &mut monitor, 0) }.as_bool() {
+            wide_to_string(&monitor.DeviceString)
+        } else {
+            wide_to_string(&adapter.DeviceString)
+        };
+
+        let mut modes: Vec<DisplayMode> = (0..)
+            .map_while(|mode| display_mode(&device_name, ENUM_DISPLAY_SETTINGS_MODE(mode)))
+            .collect();
+        modes.sort_by(|a, b| b.cmp(a));
+        modes.dedup();
+
+        monitors.push(Monitor {
+            device,
+            name,
+            is_primary: adapter.StateFlags & DISPLAY_DEVICE_PRIMARY_DEVICE != 0,
+            current,
+            modes,
+        });
+    }
+
+    monitors.sort_by_key(|monitor| !monitor.is_primary);
+    monitors
+}
+
+/// 切换显示器的分辨率和刷新率，并保存为默认设置
+pub fn set_mode(device: &str, mode: DisplayMode) -> Result<()> {
+    let device_name = HSTRING::from(device);
+    let mut devmode =
+        DEVMODEW { dmSize: std::mem::size_of::<DEVMODEW>() as u16, ..Default::default() };
+    devmode.dmPelsWidth = mode.width;
+    devmode.dmPelsHeight = mode.height;
+    devmode.dmDisplayFrequency = mode.refresh_rate;
+    devmode.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY;
+
+    let result = unsafe {
+        ChangeDisplaySettingsExW(&device_name, Some(&devmode), None, CDS_UPDATEREGISTRY, None)
+    };
+    if result == DISP_CHANGE_SUCCESSFUL {
+        Ok(())
+    } else {
+        Err(WerunError::DisplayPower(format!("切换 {} 到 {} 失败 ({})", device, mode, result.0)))
+    }
+}
+
+/// 切换投影模式
+pub fn set_projection(projection: Projection) -> Result<()> {
+    let status = unsafe { SetDisplayConfig(None, None, SDC_APPLY | projection.topology()) };
+    if status == ERROR_SUCCESS.0 as i32 {
+        Ok(())
+    } else {
+        Err(WerunError::DisplayPower(format!(
+            "切换投影模式到 {} 失败 ({})",
+            projection.label(),
+            status
+        )))
+    }
+}
+
+/// 当前亮度（0 到 100），内置屏幕优先；都不支持调节时返回 `None`
+pub fn brightness() -> Option<u8> {
+    com::with_com(COINIT_MULTITHREADED, || unsafe { wmi_brightness() }).or_else(|| {
+        let mut brightness = None;
+        for_each_physical_monitor(|handle| unsafe {
+            let (mut min, mut current, mut max) = (0, 0, 0);
+            if brightness.is_none()
+                && GetMonitorBrightness(handle, &mut min, &mut current, &mut max) != 0
+                && max > min
+            {
+                brightness = Some(((current - min) * 100 / (max - min)) as u8);
+            }
+        });
+        brightness
+    })
+}
+
+/// 设置所有支持调节的屏幕的亮度（0 到 100）
+pub fn set_brightness(percent: u8) -> Result<()> {
+    let percent = percent.min(100);
+    let mut applied =
+        com::with_com(COINIT_MULTITHREADED, || unsafe { set_wmi_brightness(percent) }).is_some();
+
+    for_each_physical_monitor(|handle| unsafe {
+        let (mut min, mut current, mut max) = (0, 0, 0);
+        if GetMonitorBrightness(handle, &mut min, &mut current, &mut max) != 0 && max > min {
+            let value = min + (max - min) * u32::from(percent) / 100;
+            applied |= SetMonitorBrightness(handle, value) != 0;
+        }
+    });
+
+    if applied {
+        Ok(())
+    } else {
+        Err(WerunError::DisplayPower("没有支持调节亮度的屏幕".to_string()))
+    }
+}
+
+/// 读取内置屏幕的亮度
+unsafe fn wmi_brightness() -> Option<u8> {
+    let services = wmi_services()?;
+    let object = wmi_first(&services, "SELECT CurrentBrightness FROM WmiMonitorBrightness")?;
+    let mut value = VARIANT::default();
+    object.Get(w!("CurrentBrightness"), 0, &mut value, None, None).ok()?;
+    i32::try_from(&value).ok().map(|value| value.clamp(0, 100) as u8)
+}
+
+/// 设置内置屏幕的亮度
+unsafe fn set_wmi_brightness(percent: u8) -> Option<()> {
+    let services = wmi_services()?;
+    let instance = wmi_first(&services, "SELECT * FROM WmiMonitorBrightnessMethods")?;
+    let mut path = VARIANT::default();
+    instance.Get(w!("__PATH"), 0, &mut path, None, None).ok()?;
+    let path = BSTR::try_from(&path).ok()?;
+
+    let mut class = None;
+    services
+        .GetObject(
+            &BSTR::from("WmiMonitorBrightnessMethods"),
+            Default::default(),
+            None,
+            Some(&mut class),
+            None,
+        )
+        .ok()?;
+    let (mut input, mut output) = (None, None);
+    class?.GetMethod(w!("WmiSetBrightness"), 0, &mut input, &mut output).ok()?;
+
+    let params = input?.SpawnInstance(0).ok()?;
+    params.Put(w!("Timeout"), 0, &VARIANT::from(0i32), 0).ok()?;
+    params.Put(w!("Brightness"), 0, &VARIANT::from(percent), 0).ok()?;
+    services
+        .ExecMethod(
+            &path,
+            &BSTR::from("WmiSetBrightness"),
+            Default::default(),
+            None,
+            &params,
+            None,
+            None,
+        )
+        .ok()
+}
+
+/// 连接 root\WMI 命名空间
+unsafe fn wmi_services() -> Option<IWbemServices> {
+    let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER).ok()?;
+    let empty = BSTR::new();
+    locator.ConnectServer(&BSTR::from(r"root\WMI"), &empty, &empty, &empty, 0, &empty, None).ok()
+}
+
+/// 查询结果中的第一个对象
+unsafe fn wmi_first(services: &IWbemServices, query: &str) -> Option<IWbemClassObject> {
+    let objects = services
+        .ExecQuery(
+            &BSTR::from("WQL"),
+            &BSTR::from(query),
+            WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+            None,
+        )
+        .ok()?;
+    let mut row = [None];
+    let mut returned = 0;
+    objects.Next(WBEM_INFINITE, &mut row, &mut returned).ok().ok()?;
+    let [object] = row;
+    object
+}
+
+/// 对各显示器的物理显示器句柄（用于 DDC/CI）执行操作，完成后释放句柄
+fn for_each_physical_monitor(mut operation: impl FnMut(HANDLE)) {
+    unsafe extern "system" fn collect(
+        monitor: HMONITOR,
+        _hdc: HDC,
+        _rect: *mut RECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let monitors = &mut *(data.0 as *mut Vec<HMONITOR>);
+        monitors.push(monitor);
+        TRUE
+    }
+
+    let mut monitors: Vec<HMONITOR> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(collect),
+            LPARAM(&mut monitors as *mut Vec<HMONITOR> as isize),
+        );
+    }
+
+    for monitor in monitors {
+        unsafe {
+            let mut count = 0;
+            if GetNumberOfPhysicalMonitorsFromHMONITOR(monitor, &mut count).is_err() || count == 0 {
+                continue;
+            }
+            let mut physical = vec![PHYSICAL_MONITOR::default(); count as usize];
+            if GetPhysicalMonitorsFromHMONITOR(monitor, &mut physical).is_err() {
+                continue;
+            }
+            for handle in physical.iter().map(|p| p.hPhysicalMonitor) {
+                operation(handle);
+            }
+            let _ = DestroyPhysicalMonitors(&physical);
+        }
+    }
+}
+
+/// 读取显示模式，模式编号超出范围时返回 `None`
+fn display_mode(device: &HSTRING, mode: ENUM_DISPLAY_SETTINGS_MODE) -> Option<DisplayMode> {
+    let mut devmode =
+        DEVMODEW { dmSize: std::mem::size_of::<DEVMODEW>() as u16, ..Default::default() };
+    if !unsafe { EnumDisplaySettingsW(device, mode, &mut devmode) }.as_bool() {
+        return None;
+    }
+    Some(DisplayMode {
+        width: devmode.dmPelsWidth,
+        height: devmode.dmPelsHeight,
+        refresh_rate: devmode.dmDisplayFrequency,
+    })
+}
+
+/// 空的显示设备信息
+fn display_device() -> DISPLAY_DEVICEW {
+    DISPLAY_DEVICEW { cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32, ..Default::default() }
+}
+
+/// 以 0 结尾的 UTF-16 字符串
+fn wide_to_string(wide: &[u16]) -> String {
+    let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len]).trim().to_string()
+}
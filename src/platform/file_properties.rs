@@ -0,0 +1,54 @@
+/// 文件属性
+///
+/// 通过 Windows 属性系统读取文档和图片的元数据（标题、作者、页数、尺寸），
+/// 由对应格式的属性处理程序提供，如 Office 文档和常见图片格式
+use std::path::Path;
+
+use windows::{
+    core::{HSTRING, PROPVARIANT},
+    Win32::{
+        Storage::EnhancedStorage::{
+            PKEY_Author, PKEY_Document_PageCount, PKEY_Document_WordCount, PKEY_Image_Dimensions,
+            PKEY_Title,
+        },
+        System::Com::{IBindCtx, COINIT_APARTMENTTHREADED},
+        UI::Shell::PropertiesSystem::{
+            IPropertyStore, SHGetPropertyStoreFromParsingName, GPS_DEFAULT,
+        },
+    },
+};
+
+use crate::platform::com;
+
+/// 读取文件的文档属性，返回显示名称和值，没有对应的属性处理程序时为空
+///
+/// 会初始化当前线程的 COM，应在后台线程中调用
+pub fn read(path: &Path) -> Vec<(String, String)> {
+    com::with_com(COINIT_APARTMENTTHREADED, || unsafe { read_store(path) }).unwrap_or_default()
+}
+
+/// 从属性存储中读取各属性，跳过空值
+unsafe fn read_store(path: &Path) -> windows::core::Result<Vec<(String, String)>> {
+    let store: IPropertyStore = SHGetPropertyStoreFromParsingName(
+        &HSTRING::from(path.as_os_str()),
+        None::<&IBindCtx>,
+        GPS_DEFAULT,
+    )?;
+
+    // 读取的属性及其显示名称
+    let properties = [
+        (PKEY_Title, "标题"),
+        (PKEY_Author, "作者"),
+        (PKEY_Document_PageCount, "页数"),
+        (PKEY_Document_WordCount, "字数"),
+        (PKEY_Image_Dimensions, "尺寸"),
+    ];
+    Ok(properties
+        .iter()
+        .filter_map(|(key, label)| {
+            let value: PROPVARIANT = store.GetValue(key).ok()?;
+            let text = value.to_string();
+            (!value.is_empty() && !text.is_empty()).then(|| (label.to_string(), text))
+        })
+        .collect())
+}
@@ -0,0 +1,140 @@
+/// 任务栏跳转列表
+///
+/// 在 werun 任务栏图标的右键菜单中列出常用任务和固定的应用，不用快捷键也能打开主要功能
+use std::path::Path;
+
+use windows::{
+    core::{Interface, HSTRING, PROPVARIANT},
+    Win32::{
+        Storage::EnhancedStorage::PKEY_Title,
+        System::Com::{CoCreateInstance, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED},
+        UI::Shell::{
+            Common::{IObjectArray, IObjectCollection},
+            DestinationList, EnumerableObjectCollection, ICustomDestinationList, IShellLinkW,
+            PropertiesSystem::IPropertyStore,
+            ShellLink,
+        },
+    },
+};
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        error::{Result, WerunError},
+        launch::LaunchCommand,
+    },
+    platform::com,
+};
+
+/// 固定应用的分类名称
+const PINNED_CATEGORY: &str = "固定的应用";
+
+/// 跳转列表条目
+struct JumpListItem {
+    /// 显示的标题
+    title: String,
+    /// 目标程序路径
+    path: String,
+    /// 启动参数
+    args: String,
+}
+
+/// 常用任务：标题和启动命令
+fn tasks() -> Vec<(&'static str, LaunchCommand)> {
+    vec![
+        ("显示/隐藏启动器", LaunchCommand::Toggle),
+        ("剪贴板历史", LaunchCommand::Query("/clipboard ".to_string())),
+        ("打开设置", LaunchCommand::Settings),
+    ]
+}
+
+/// 在后台线程中按当前配置更新跳转列表
+///
+/// 固定的应用变化后再次调用即可刷新
+pub fn refresh() {
+    std::thread::spawn(|| {
+        if let Err(e) = update() {
+            e.log();
+        }
+    });
+}
+
+/// 更新跳转列表
+fn update() -> Result<()> {
+    let exe = std::env::current_exe().map_err(|e| WerunError::JumpList(e.into()))?;
+    let exe = exe.to_string_lossy().to_string();
+    // 配置文件按工作目录查找，任务需在当前目录中启动
+    let dir = std::env::current_dir().map_err(|e| WerunError::JumpList(e.into()))?;
+
+    let tasks: Vec<_> = tasks()
+        .into_iter()
+        .map(|(title, command)| JumpListItem {
+            title: title.to_string(),
+            path: exe.clone(),
+            args: command.command_line(),
+        })
+        .collect();
+    let apps: Vec<_> = global_config()
+        .pinned_apps()
+        .into_iter()
+        .map(|path| JumpListItem {
+            title: Path::new(&path)
+                .file_stem()
+                .map_or_else(|| path.clone(), |stem| stem.to_string_lossy().to_string()),
+            path,
+            args: String::new(),
+        })
+        .collect();
+
+    com::with_com(COINIT_APARTMENTTHREADED, || unsafe {
+        commit(&tasks, &apps, &dir.to_string_lossy())
+    })
+    .map_err(|e| WerunError::JumpList(e.into()))?;
+
+    log::info!("已更新跳转列表: {} 个任务，{} 个固定应用", tasks.len(), apps.len());
+    Ok(())
+}
+
+/// 提交跳转列表
+unsafe fn commit(
+    tasks: &[JumpListItem],
+    apps: &[JumpListItem],
+    dir: &str,
+) -> windows::core::Result<()> {
+    let list: ICustomDestinationList =
+        CoCreateInstance(&DestinationList, None, CLSCTX_INPROC_SERVER)?;
+    let mut min_slots = 0;
+    let _removed: IObjectArray = list.BeginList(&mut min_slots)?;
+
+    if !apps.is_empty() {
+        let apps = collection(apps, dir)?.cast::<IObjectArray>()?;
+        list.AppendCategory(&HSTRING::from(PINNED_CATEGORY), &apps)?;
+    }
+    list.AddUserTasks(&collection(tasks, dir)?.cast::<IObjectArray>()?)?;
+    list.CommitList()
+}
+
+/// 为条目创建快捷方式集合
+unsafe fn collection(
+    items: &[JumpListItem],
+    dir: &str,
+) -> windows::core::Result<IObjectCollection> {
+    let collection: IObjectCollection =
+        CoCreateInstance(&EnumerableObjectCollection, None, CLSCTX_INPROC_SERVER)?;
+
+    for item in items {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER)?;
+        link.SetPath(&HSTRING::from(&item.path))?;
+        link.SetArguments(&HSTRING::from(&item.args))?;
+        link.SetIconLocation(&HSTRING::from(&item.path), 0)?;
+        link.SetWorkingDirectory(&HSTRING::from(dir))?;
+
+        // 跳转列表显示的标题取自快捷方式的 Title 属性
+        let store: IPropertyStore = link.cast()?;
+        store.SetValue(&PKEY_Title, &PROPVARIANT::from(item.title.as_str()))?;
+        store.Commit()?;
+
+        collection.AddObject(&link)?;
+    }
+    Ok(collection)
+}
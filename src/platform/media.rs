@@ -0,0 +1,143 @@
+/// 媒体播放控制
+///
+/// 通过 GlobalSystemMediaTransportControls 读取各程序（Spotify、浏览器等）正在播放的曲目，
+/// 并发送播放/暂停、下一首、上一首命令
+use windows::{
+    Media::Control::{
+        GlobalSystemMediaTransportControlsSession as Session,
+        GlobalSystemMediaTransportControlsSessionManager as SessionManager,
+        GlobalSystemMediaTransportControlsSessionPlaybackStatus as Status,
+    },
+    Win32::System::Com::COINIT_MULTITHREADED,
+};
+
+use crate::{
+    core::error::{Result, WerunError},
+    platform::com,
+};
+
+/// 播放状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaybackStatus {
+    /// 正在播放
+    Playing,
+    /// 已暂停
+    Paused,
+    /// 已停止
+    Stopped,
+    /// 其他（打开中、切换中）
+    Other,
+}
+
+impl PlaybackStatus {
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlaybackStatus::Playing => "正在播放",
+            PlaybackStatus::Paused => "已暂停",
+            PlaybackStatus::Stopped => "已停止",
+            PlaybackStatus::Other => "未播放",
+        }
+    }
+}
+
+/// 播放控制命令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaCommand {
+    /// 播放/暂停
+    TogglePlayPause,
+    /// 下一首
+    Next,
+    /// 上一首
+    Previous,
+}
+
+/// 媒体会话（一个正在播放或暂停的程序）
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaSession {
+    /// 程序的 AppUserModelID（如 `Spotify.exe`）
+    pub app_id: String,
+    /// 曲目标题
+    pub title: String,
+    /// 艺术家
+    pub artist: String,
+    /// 专辑
+    pub album: String,
+    /// 播放状态
+    pub status: PlaybackStatus,
+    /// 是否为系统当前的会话
+    pub is_current: bool,
+    /// 是否支持播放/暂停
+    pub can_play_pause: bool,
+    /// 是否支持下一首
+    pub can_next: bool,
+    /// 是否支持上一首
+    pub can_previous: bool,
+}
+
+/// 列出媒体会话，系统当前的会话在前
+pub fn sessions() -> Result<Vec<MediaSession>> {
+    with_winrt(|| {
+        let manager = SessionManager::RequestAsync()?.get()?;
+        let current_id = manager.GetCurrentSession().and_then(|s| s.SourceAppUserModelId()).ok();
+
+        let mut sessions = Vec::new();
+        for session in manager.GetSessions()? {
+            let app_id = session.SourceAppUserModelId()?.to_string();
+            let properties = session.TryGetMediaPropertiesAsync()?.get()?;
+            let playback = session.GetPlaybackInfo()?;
+            let controls = playback.Controls()?;
+
+            sessions.push(MediaSession {
+                is_current: current_id.as_ref().is_some_and(|id| *id == app_id),
+                app_id,
+                title: properties.Title()?.to_string(),
+                artist: properties.Artist()?.to_string(),
+                album: properties.AlbumTitle()?.to_string(),
+                status: match playback.PlaybackStatus()? {
+                    Status::Playing => PlaybackStatus::Playing,
+                    Status::Paused => PlaybackStatus::Paused,
+                    Status::Stopped => PlaybackStatus::Stopped,
+                    _ => PlaybackStatus::Other,
+                },
+                can_play_pause: controls.IsPlayPauseToggleEnabled()?,
+                can_next: controls.IsNextEnabled()?,
+                can_previous: controls.IsPreviousEnabled()?,
+            });
+        }
+
+        sessions.sort_by_key(|session| !session.is_current);
+        Ok(sessions)
+    })
+}
+
+/// 向指定程序的媒体会话发送命令，会话已结束时返回错误
+pub fn send(app_id: &str, command: MediaCommand) -> Result<()> {
+    let accepted = with_winrt(|| {
+        let manager = SessionManager::RequestAsync()?.get()?;
+        let session = manager
+            .GetSessions()?
+            .into_iter()
+            .find(|session: &Session| session.SourceAppUserModelId().is_ok_and(|id| id == app_id));
+        let Some(session) = session else {
+            return Ok(false);
+        };
+
+        match command {
+            MediaCommand::TogglePlayPause => session.TryTogglePlayPauseAsync()?.get(),
+            MediaCommand::Next => session.TrySkipNextAsync()?.get(),
+            MediaCommand::Previous => session.TrySkipPreviousAsync()?.get(),
+        }
+    })?;
+
+    if accepted {
+        Ok(())
+    } else {
+        Err(WerunError::Media(format!("{} 未响应命令 {:?}", app_id, command)))
+    }
+}
+
+/// 在已初始化 COM 的线程上调用 WinRT
+fn with_winrt<T>(operation: impl FnOnce() -> windows::core::Result<T>) -> Result<T> {
+    com::with_com(COINIT_MULTITHREADED, operation).map_err(|e| WerunError::Media(e.message()))
+}
@@ -1,4 +1,18 @@
 /// 平台相关模块
 ///
 /// 提供 Windows 平台特定的功能实现
+pub mod audio;
+pub mod autostart;
+pub mod backdrop;
+pub mod com;
+pub mod display;
+pub mod file_properties;
+pub mod jump_list;
+pub mod media;
+pub mod monitor;
+pub mod power;
+pub mod recycle_bin;
+pub mod screen_color;
+pub mod services;
+pub mod toast;
 pub mod windows;
@@ -0,0 +1,104 @@
+/// 窗口所在的显示器
+///
+/// 唤出启动器时按配置选择鼠标所在、前台窗口所在的显示器或主显示器，
+/// 在该显示器的工作区内居中，窗口大小按该显示器的 DPI 换算
+use std::ffi::c_void;
+
+use windows::Win32::{
+    Foundation::{HWND, POINT, RECT},
+    Graphics::Gdi::{
+        GetMonitorInfoW, MonitorFromPoint, MonitorFromWindow, HMONITOR, MONITORINFO,
+        MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY,
+    },
+    UI::{
+        HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+        WindowsAndMessaging::{
+            GetCursorPos, SetWindowPos, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER,
+        },
+    },
+};
+
+use crate::core::config::MonitorTarget;
+
+/// 100% 缩放时的 DPI
+const BASE_DPI: u32 = 96;
+/// 窗口最多占工作区的比例
+const MAX_WORK_AREA_RATIO: f32 = 0.85;
+
+/// 将窗口移到目标显示器，并在其工作区内居中
+///
+/// `size` 为窗口的逻辑大小，`foreground` 为唤出前的前台窗口，目标为前台窗口所在的显示器时使用
+pub fn center_on_monitor(
+    hwnd: isize,
+    target: MonitorTarget,
+    size: (f32, f32),
+    foreground: Option<isize>,
+) {
+    let monitor = target_monitor(target, foreground);
+    let Some((work_area, dpi)) = monitor_metrics(monitor) else {
+        log::warn!("无法获取显示器信息，窗口保持原位置");
+        return;
+    };
+    let bounds = centered_bounds(work_area, size, dpi as f32 / BASE_DPI as f32);
+
+    let hwnd = HWND(hwnd as *mut c_void);
+    let flags = SWP_NOZORDER | SWP_NOACTIVATE;
+    let (x, y) = (bounds.left, bounds.top);
+    unsafe {
+        // 先移到目标显示器：跨越 DPI 不同的显示器时窗口会按新的 DPI 调整大小，之后再设置最终大小
+        let _ = SetWindowPos(hwnd, HWND::default(), x, y, 0, 0, flags | SWP_NOSIZE);
+        let (width, height) = (bounds.right - bounds.left, bounds.bottom - bounds.top);
+        if let Err(e) = SetWindowPos(hwnd, HWND::default(), x, y, width, height, flags) {
+            log::warn!("移动窗口到目标显示器失败: {}", e);
+        }
+    }
+}
+
+/// 目标显示器，没有前台窗口时使用鼠标所在的显示器
+fn target_monitor(target: MonitorTarget, foreground: Option<isize>) -> HMONITOR {
+    unsafe {
+        match (target, foreground) {
+            (MonitorTarget::Primary, _) => {
+                // 主显示器的左上角总是虚拟屏幕的原点
+                MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY)
+            },
+            (MonitorTarget::FocusedWindow, Some(hwnd)) => {
+                MonitorFromWindow(HWND(hwnd as *mut c_void), MONITOR_DEFAULTTONEAREST)
+            },
+            _ => {
+                let mut cursor = POINT::default();
+                let _ = GetCursorPos(&mut cursor);
+                MonitorFromPoint(cursor, MONITOR_DEFAULTTONEAREST)
+            },
+        }
+    }
+}
+
+/// 显示器的工作区（不含任务栏）和 DPI
+fn monitor_metrics(monitor: HMONITOR) -> Option<(RECT, u32)> {
+    let mut info =
+        MONITORINFO { cbSize: std::mem::size_of::<MONITORINFO>() as u32, ..Default::default() };
+    let (mut dpi_x, mut dpi_y) = (BASE_DPI, BASE_DPI);
+    unsafe {
+        if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+            return None;
+        }
+        // 获取失败时按 100% 缩放
+        if GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y).is_err() {
+            dpi_x = BASE_DPI;
+        }
+    }
+    Some((info.rcWork, dpi_x))
+}
+
+/// 在工作区内居中的窗口范围（物理像素），大小不超过工作区的一定比例
+fn centered_bounds(work_area: RECT, size: (f32, f32), scale: f32) -> RECT {
+    let work_width = work_area.right - work_area.left;
+    let work_height = work_area.bottom - work_area.top;
+    let width = (size.0 * scale).min(work_width as f32 * MAX_WORK_AREA_RATIO).round() as i32;
+    let height = (size.1 * scale).min(work_height as f32 * MAX_WORK_AREA_RATIO).round() as i32;
+
+    let left = work_area.left + (work_width - width) / 2;
+    let top = work_area.top + (work_height - height) / 2;
+    RECT { left, top, right: left + width, bottom: top + height }
+}
@@ -0,0 +1,110 @@
+/// 电源计划
+///
+/// 通过电源管理接口枚举电源计划（平衡、高性能等，包括 OEM 和用户自建的计划）并切换
+use windows::{
+    core::GUID,
+    Win32::{
+        Foundation::{LocalFree, ERROR_SUCCESS, HLOCAL},
+        System::Power::{
+            PowerEnumerate, PowerGetActiveScheme, PowerReadFriendlyName, PowerSetActiveScheme,
+            ACCESS_SCHEME,
+        },
+    },
+};
+
+use crate::core::error::{Result, WerunError};
+
+/// 电源计划
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowerPlan {
+    /// 计划 GUID（如 `381B4222-F694-41F0-9685-FF5BB260DF2E`）
+    pub id: String,
+    /// 名称
+    pub name: String,
+    /// 是否为当前计划
+    pub is_active: bool,
+}
+
+/// 列出电源计划
+pub fn plans() -> Vec<PowerPlan> {
+    let active = active_scheme();
+    let mut plans = Vec::new();
+
+    for index in 0.. {
+        let mut guid = GUID::zeroed();
+        let mut size = std::mem::size_of::<GUID>() as u32;
+        let status = unsafe {
+            PowerEnumerate(
+                None,
+                None,
+                None,
+                ACCESS_SCHEME,
+                index,
+                Some(&mut guid as *mut GUID as *mut u8),
+                &mut size,
+            )
+        };
+        if status != ERROR_SUCCESS {
+            break;
+        }
+
+        plans.push(PowerPlan {
+            id: format!("{:?}", guid),
+            name: friendly_name(&guid).unwrap_or_else(|| format!("{:?}", guid)),
+            is_active: active == Some(guid),
+        });
+    }
+    plans
+}
+
+/// 切换到指定的电源计划
+pub fn set_active(id: &str) -> Result<()> {
+    let guid = u128::from_str_radix(&id.replace('-', ""), 16)
+        .map(GUID::from_u128)
+        .map_err(|_| WerunError::DisplayPower(format!("无效的电源计划: {}", id)))?;
+    let status = unsafe { PowerSetActiveScheme(None, Some(&guid)) };
+    if status == ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(WerunError::DisplayPower(format!("切换电源计划失败 ({})", status.0)))
+    }
+}
+
+/// 当前电源计划
+fn active_scheme() -> Option<GUID> {
+    unsafe {
+        let mut scheme: *mut GUID = std::ptr::null_mut();
+        if PowerGetActiveScheme(None, &mut scheme) != ERROR_SUCCESS || scheme.is_null() {
+            return None;
+        }
+        let guid = *scheme;
+        let _ = LocalFree(HLOCAL(scheme as *mut _));
+        Some(guid)
+    }
+}
+
+/// 电源计划的显示名称（已按系统语言本地化）
+fn friendly_name(guid: &GUID) -> Option<String> {
+    unsafe {
+        let mut size = 0u32;
+        if PowerReadFriendlyName(None, Some(guid), None, None, None, &mut size) != ERROR_SUCCESS {
+            return None;
+        }
+
+        let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+        let status = PowerReadFriendlyName(
+            None,
+            Some(guid),
+            None,
+            None,
+            Some(buffer.as_mut_ptr() as *mut u8),
+            &mut size,
+        );
+        if status != ERROR_SUCCESS {
+            return None;
+        }
+
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Some(String::from_utf16_lossy(&buffer[..len]))
+    }
+}
@@ -0,0 +1,51 @@
+/// 回收站
+///
+/// 查询所有驱动器上回收站的大小和项目数，以及清空回收站
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        Foundation::HWND,
+        UI::Shell::{
+            SHEmptyRecycleBinW, SHQueryRecycleBinW, SHERB_NOCONFIRMATION, SHERB_NOPROGRESSUI,
+            SHERB_NOSOUND, SHQUERYRBINFO,
+        },
+    },
+};
+
+use crate::core::error::{Result, WerunError};
+
+/// 回收站占用
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RecycleBinInfo {
+    /// 总大小（字节）
+    pub size: u64,
+    /// 项目数
+    pub items: u64,
+}
+
+/// 查询所有驱动器上的回收站
+pub fn info() -> Option<RecycleBinInfo> {
+    let mut info =
+        SHQUERYRBINFO { cbSize: std::mem::size_of::<SHQUERYRBINFO>() as u32, ..Default::default() };
+    unsafe { SHQueryRecycleBinW(PCWSTR::null(), &mut info).ok()? };
+
+    Some(RecycleBinInfo { size: info.i64Size.max(0) as u64, items: info.i64NumItems.max(0) as u64 })
+}
+
+/// 清空所有驱动器上的回收站，不显示系统的确认框和进度（确认由启动器负责）
+pub fn empty() -> Result<()> {
+    // 回收站已空时系统接口会返回错误，直接视为成功
+    if info().is_some_and(|info| info.items == 0) {
+        log::info!("回收站已是空的");
+        return Ok(());
+    }
+
+    unsafe {
+        SHEmptyRecycleBinW(
+            HWND::default(),
+            PCWSTR::null(),
+            SHERB_NOCONFIRMATION | SHERB_NOPROGRESSUI | SHERB_NOSOUND,
+        )
+    }
+    .map_err(|e| WerunError::RecycleBin(e.to_string()))
+}
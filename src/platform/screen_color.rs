@@ -0,0 +1,132 @@
+/// 屏幕取色
+///
+/// 在整个虚拟屏幕上覆盖一层几乎透明的窗口并显示十字光标，左键单击读取光标下的像素颜色，
+/// Esc 或右键取消
+use std::cell::Cell;
+
+use windows::Win32::{
+    Foundation::{COLORREF, HWND, LPARAM, LRESULT, POINT, WPARAM},
+    Graphics::Gdi::{GetDC, GetPixel, ReleaseDC, CLR_INVALID},
+    System::LibraryLoader::GetModuleHandleW,
+    UI::{
+        Input::KeyboardAndMouse::VK_ESCAPE,
+        WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetCursorPos,
+            GetMessageW, GetSystemMetrics, LoadCursorW, PostQuitMessage, RegisterClassW,
+            SetForegroundWindow, SetLayeredWindowAttributes, ShowWindow, TranslateMessage,
+            IDC_CROSS, LWA_ALPHA, MSG, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, SM_XVIRTUALSCREEN,
+            SM_YVIRTUALSCREEN, SW_HIDE, WM_DESTROY, WM_KEYDOWN, WM_LBUTTONDOWN, WM_RBUTTONDOWN,
+            WNDCLASSW, WS_EX_LAYERED, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_POPUP, WS_VISIBLE,
+        },
+    },
+};
+
+use crate::core::error::{Result, WerunError};
+
+/// 覆盖窗口的类名
+const OVERLAY_CLASS_NAME: &str = "WeRunColorPicker";
+
+thread_local! {
+    /// 取色结果：`None` 表示仍在取色，`Some(None)` 表示已取消
+    static PICKED: Cell<Option<Option<(u8, u8, u8)>>> = const { Cell::new(None) };
+}
+
+/// 等待用户在屏幕上单击并返回该处的颜色，取消时返回 `None`
+///
+/// 阻塞直到取色结束，应在后台线程中调用
+pub fn pick() -> Result<Option<(u8, u8, u8)>> {
+    PICKED.with(|picked| picked.set(None));
+
+    unsafe {
+        let hwnd = create_overlay().map_err(|e| WerunError::ScreenColor(e.to_string()))?;
+        let _ = SetForegroundWindow(hwnd);
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+
+    Ok(PICKED.with(|picked| picked.take()).flatten())
+}
+
+/// 创建覆盖整个虚拟屏幕的置顶窗口
+unsafe fn create_overlay() -> windows::core::Result<HWND> {
+    let hinstance = GetModuleHandleW(None)?;
+    let class_name: Vec<u16> =
+        OVERLAY_CLASS_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+    let wnd_class = WNDCLASSW {
+        lpfnWndProc: Some(window_proc),
+        hInstance: hinstance.into(),
+        hCursor: LoadCursorW(None, IDC_CROSS)?,
+        lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+        ..Default::default()
+    };
+    // 重复取色时类已注册，忽略失败
+    RegisterClassW(&wnd_class);
+
+    let hwnd = CreateWindowExW(
+        WS_EX_TOPMOST | WS_EX_LAYERED | WS_EX_TOOLWINDOW,
+        windows::core::PCWSTR(class_name.as_ptr()),
+        windows::core::PCWSTR(class_name.as_ptr()),
+        WS_POPUP | WS_VISIBLE,
+        GetSystemMetrics(SM_XVIRTUALSCREEN),
+        GetSystemMetrics(SM_YVIRTUALSCREEN),
+        GetSystemMetrics(SM_CXVIRTUALSCREEN),
+        GetSystemMetrics(SM_CYVIRTUALSCREEN),
+        None,
+        None,
+        hinstance,
+        None,
+    )?;
+    // 完全透明的窗口收不到鼠标消息，保留最低的不透明度
+    SetLayeredWindowAttributes(hwnd, COLORREF(0), 1, LWA_ALPHA)?;
+    Ok(hwnd)
+}
+
+/// 读取屏幕坐标处的像素颜色
+unsafe fn pixel_at(point: POINT) -> Option<(u8, u8, u8)> {
+    let hdc = GetDC(None);
+    let color = GetPixel(hdc, point.x, point.y);
+    ReleaseDC(None, hdc);
+
+    (color.0 != CLR_INVALID).then_some((color.0 as u8, (color.0 >> 8) as u8, (color.0 >> 16) as u8))
+}
+
+/// 覆盖窗口的窗口过程
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    let finish = |color: Option<(u8, u8, u8)>| {
+        PICKED.with(|picked| picked.set(Some(color)));
+        let _ = DestroyWindow(hwnd);
+    };
+
+    match msg {
+        WM_LBUTTONDOWN => {
+            // 先隐藏覆盖层，避免读到叠加后的颜色
+            let _ = ShowWindow(hwnd, SW_HIDE);
+            let mut point = POINT::default();
+            let color = GetCursorPos(&mut point).ok().and_then(|_| pixel_at(point));
+            finish(color);
+            LRESULT(0)
+        },
+        WM_RBUTTONDOWN => {
+            finish(None);
+            LRESULT(0)
+        },
+        WM_KEYDOWN if wparam.0 == VK_ESCAPE.0 as usize => {
+            finish(None);
+            LRESULT(0)
+        },
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        },
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
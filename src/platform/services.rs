@@ -0,0 +1,322 @@
+/// Windows 服务
+///
+/// 通过服务控制管理器枚举服务及其运行状态，启动、停止和重启服务；
+/// 当前用户没有权限时通过 UAC 以管理员身份执行 `net start`/`net stop`
+use std::time::{Duration, Instant};
+
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::{ERROR_ACCESS_DENIED, ERROR_SERVICE_NOT_ACTIVE},
+        System::Services::{
+            CloseServiceHandle, ControlService, EnumDependentServicesW, EnumServicesStatusExW,
+            OpenSCManagerW, OpenServiceW, QueryServiceStatus, StartServiceW, ENUM_SERVICE_STATUSW,
+            ENUM_SERVICE_STATUS_PROCESSW, SC_ENUM_PROCESS_INFO, SC_HANDLE, SC_MANAGER_CONNECT,
+            SC_MANAGER_ENUMERATE_SERVICE, SERVICE_ACTIVE, SERVICE_CONTINUE_PENDING,
+            SERVICE_CONTROL_STOP, SERVICE_ENUMERATE_DEPENDENTS, SERVICE_PAUSED,
+            SERVICE_PAUSE_PENDING, SERVICE_QUERY_STATUS, SERVICE_RUNNING, SERVICE_START,
+            SERVICE_START_PENDING, SERVICE_STATE_ALL, SERVICE_STATUS, SERVICE_STOP,
+            SERVICE_STOPPED, SERVICE_STOP_PENDING, SERVICE_WIN32,
+        },
+    },
+};
+
+use crate::{
+    core::error::{Result, WerunError},
+    utils::elevation,
+};
+
+/// 重启时等待服务停止的最长时间
+const STOP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 查询服务状态的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 服务运行状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServiceState {
+    /// 已停止
+    Stopped,
+    /// 正在启动
+    Starting,
+    /// 正在停止
+    Stopping,
+    /// 正在运行
+    Running,
+    /// 已暂停（含正在暂停、正在继续）
+    Paused,
+}
+
+impl ServiceState {
+    /// 显示名称
+    pub fn label(self) -> &'static str {
+        match self {
+            ServiceState::Stopped => "已停止",
+            ServiceState::Starting => "正在启动",
+            ServiceState::Stopping => "正在停止",
+            ServiceState::Running => "正在运行",
+            ServiceState::Paused => "已暂停",
+        }
+    }
+}
+
+/// 服务信息
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceInfo {
+    /// 服务名称（`net start` 使用的名称）
+    pub name: String,
+    /// 显示名称
+    pub display_name: String,
+    /// 运行状态
+    pub state: ServiceState,
+    /// 服务进程 ID，未运行时为 0
+    pub pid: u32,
+}
+
+/// 服务控制命令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServiceCommand {
+    /// 启动
+    Start,
+    /// 停止
+    Stop,
+    /// 重启（先停止再启动）
+    Restart,
+}
+
+impl ServiceCommand {
+    /// 显示名称
+    pub fn label(self) -> &'static str {
+        match self {
+            ServiceCommand::Start => "启动",
+            ServiceCommand::Stop => "停止",
+            ServiceCommand::Restart => "重启",
+        }
+    }
+
+    /// 以管理员身份执行时 `cmd.exe` 的参数
+    ///
+    /// 有依赖服务运行时已在执行前拒绝，不需要 `/y` 连带停止依赖服务
+    fn elevated_args(self, name: &str) -> String {
+        match self {
+            ServiceCommand::Start => format!("/c net start \"{}\"", name),
+            ServiceCommand::Stop => format!("/c net stop \"{}\"", name),
+            ServiceCommand::Restart => {
+                format!("/c net stop \"{}\" && net start \"{}\"", name, name)
+            },
+        }
+    }
+
+    /// 打开服务时需要的访问权限
+    fn access(self) -> u32 {
+        match self {
+            ServiceCommand::Start => SERVICE_START | SERVICE_QUERY_STATUS,
+            ServiceCommand::Stop => SERVICE_STOP | SERVICE_QUERY_STATUS,
+            ServiceCommand::Restart => SERVICE_START | SERVICE_STOP | SERVICE_QUERY_STATUS,
+        }
+    }
+}
+
+/// 列出全部 Win32 服务（不含驱动程序）
+pub fn list() -> Vec<ServiceInfo> {
+    let Ok(manager) =
+        (unsafe { OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_ENUMERATE_SERVICE) })
+    else {
+        return Vec::new();
+    };
+
+    let mut services = Vec::new();
+    unsafe {
+        // 先查询所需的缓冲区大小，再留出余量以应对两次调用之间新增的服务
+        let (mut needed, mut returned) = (0u32, 0u32);
+        let _ = EnumServicesStatusExW(
+            manager,
+            SC_ENUM_PROCESS_INFO,
+            SERVICE_WIN32,
+            SERVICE_STATE_ALL,
+            None,
+            &mut needed,
+            &mut returned,
+            None,
+            PCWSTR::null(),
+        );
+
+        // 按 8 字节对齐分配，缓冲区开头是 ENUM_SERVICE_STATUS_PROCESSW 数组
+        let mut buffer = vec![0u64; (needed as usize + 4096).div_ceil(8)];
+        let bytes =
+            std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8);
+        let status = EnumServicesStatusExW(
+            manager,
+            SC_ENUM_PROCESS_INFO,
+            SERVICE_WIN32,
+            SERVICE_STATE_ALL,
+            Some(bytes),
+            &mut needed,
+            &mut returned,
+            None,
+            PCWSTR::null(),
+        );
+
+        if status.is_ok() {
+            let entries = std::slice::from_raw_parts(
+                buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW,
+                returned as usize,
+            );
+            services.extend(entries.iter().map(|entry| {
+                let process = &entry.ServiceStatusProcess;
+                ServiceInfo {
+                    name: entry.lpServiceName.to_string().unwrap_or_default(),
+                    display_name: entry.lpDisplayName.to_string().unwrap_or_default(),
+                    state: match process.dwCurrentState {
+                        SERVICE_RUNNING => ServiceState::Running,
+                        SERVICE_START_PENDING => ServiceState::Starting,
+                        SERVICE_STOP_PENDING => ServiceState::Stopping,
+                        SERVICE_PAUSED | SERVICE_PAUSE_PENDING | SERVICE_CONTINUE_PENDING => {
+                            ServiceState::Paused
+                        },
+                        _ => ServiceState::Stopped,
+                    },
+                    pid: process.dwProcessId,
+                }
+            }));
+        } else {
+            log::warn!("枚举服务失败: {:?}", status);
+        }
+
+        let _ = CloseServiceHandle(manager);
+    }
+
+    services
+}
+
+/// 启动、停止或重启服务，会阻塞到命令完成（重启时等待服务停止）
+///
+/// 没有权限时弹出 UAC 提示，以管理员身份重新执行。停止或重启会连带停止依赖它的服务，
+/// 且重启后这些服务不会自动恢复，因此有依赖服务正在运行时拒绝执行
+pub fn control(name: &str, command: ServiceCommand) -> Result<()> {
+    log::info!("{}服务: {}", command.label(), name);
+
+    if command != ServiceCommand::Start {
+        let dependents = active_dependents(name);
+        if !dependents.is_empty() {
+            return Err(WerunError::Service(format!(
+                "{} 有正在运行的依赖服务（{}），请先停止这些服务",
+                name,
+                dependents.join("、")
+            )));
+        }
+    }
+
+    match control_directly(name, command) {
+        Ok(()) => Ok(()),
+        Err(e) if e.code() == ERROR_ACCESS_DENIED.to_hresult() => {
+            log::info!("没有权限{}服务 {}，以管理员身份重试", command.label(), name);
+            elevation::run_elevated("cmd.exe", &command.elevated_args(name), None)
+        },
+        Err(e) => Err(WerunError::Service(format!("{}: {}", name, e))),
+    }
+}
+
+/// 正在运行的依赖服务的显示名称，无法查询时返回空列表
+fn active_dependents(name: &str) -> Vec<String> {
+    let name = HSTRING::from(name);
+    let mut dependents = Vec::new();
+
+    unsafe {
+        let Ok(manager) = OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_CONNECT) else {
+            return dependents;
+        };
+        if let Ok(service) =
+            OpenServiceW(manager, PCWSTR(name.as_ptr()), SERVICE_ENUMERATE_DEPENDENTS)
+        {
+            // 先查询所需的缓冲区大小，没有依赖服务时第一次调用就会成功
+            let (mut needed, mut returned) = (0u32, 0u32);
+            let empty = EnumDependentServicesW(
+                service,
+                SERVICE_ACTIVE,
+                None,
+                0,
+                &mut needed,
+                &mut returned,
+            );
+            if empty.is_err() && needed > 0 {
+                let mut buffer = vec![0u64; (needed as usize).div_ceil(8)];
+                let status = EnumDependentServicesW(
+                    service,
+                    SERVICE_ACTIVE,
+                    Some(buffer.as_mut_ptr() as *mut ENUM_SERVICE_STATUSW),
+                    (buffer.len() * 8) as u32,
+                    &mut needed,
+                    &mut returned,
+                );
+                if status.is_ok() {
+                    let entries = std::slice::from_raw_parts(
+                        buffer.as_ptr() as *const ENUM_SERVICE_STATUSW,
+                        returned as usize,
+                    );
+                    dependents.extend(
+                        entries
+                            .iter()
+                            .map(|entry| entry.lpDisplayName.to_string().unwrap_or_default()),
+                    );
+                }
+            }
+            let _ = CloseServiceHandle(service);
+        }
+        let _ = CloseServiceHandle(manager);
+    }
+
+    dependents
+}
+
+/// 以当前用户的权限控制服务
+fn control_directly(name: &str, command: ServiceCommand) -> windows::core::Result<()> {
+    let name = HSTRING::from(name);
+
+    unsafe {
+        let manager = OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_CONNECT)?;
+        let result =
+            OpenServiceW(manager, PCWSTR(name.as_ptr()), command.access()).and_then(|service| {
+                let result = run_command(service, command);
+                let _ = CloseServiceHandle(service);
+                result
+            });
+        let _ = CloseServiceHandle(manager);
+        result
+    }
+}
+
+/// 对已打开的服务执行命令
+unsafe fn run_command(service: SC_HANDLE, command: ServiceCommand) -> windows::core::Result<()> {
+    if command != ServiceCommand::Start {
+        let mut status = SERVICE_STATUS::default();
+        match ControlService(service, SERVICE_CONTROL_STOP, &mut status) {
+            // 重启未运行的服务时直接启动
+            Err(e)
+                if command == ServiceCommand::Restart
+                    && e.code() == ERROR_SERVICE_NOT_ACTIVE.to_hresult() => {},
+            result => result?,
+        }
+    }
+
+    if command == ServiceCommand::Restart {
+        wait_until_stopped(service)?;
+    }
+    if command != ServiceCommand::Stop {
+        StartServiceW(service, None)?;
+    }
+    Ok(())
+}
+
+/// 等待服务停止，超时后仍尝试启动，由启动失败的错误说明原因
+unsafe fn wait_until_stopped(service: SC_HANDLE) -> windows::core::Result<()> {
+    let started = Instant::now();
+    loop {
+        let mut status = SERVICE_STATUS::default();
+        QueryServiceStatus(service, &mut status)?;
+        if status.dwCurrentState == SERVICE_STOPPED || started.elapsed() > STOP_TIMEOUT {
+            return Ok(());
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
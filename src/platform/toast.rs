@@ -0,0 +1,58 @@
+/// 系统通知
+///
+/// 通过 WinRT 的 ToastNotificationManager 在 Windows 通知中心显示通知；
+/// 未打包的程序需要先在注册表中登记 AppUserModelID 和显示名称，否则通知不会出现
+use std::sync::Once;
+
+use windows::{
+    core::HSTRING,
+    Win32::System::Com::COINIT_MULTITHREADED,
+    UI::Notifications::{ToastNotification, ToastNotificationManager, ToastTemplateType},
+};
+
+use crate::{
+    core::error::{Result, WerunError},
+    platform::com,
+    utils::registry::{self, RegistryRoot},
+};
+
+/// 通知使用的 AppUserModelID
+const APP_USER_MODEL_ID: &str = "WeRun.Launcher";
+/// 登记 AppUserModelID 的注册表项
+const APP_ID_KEY: &str = r"Software\Classes\AppUserModelId\WeRun.Launcher";
+/// 通知中心显示的程序名称
+const DISPLAY_NAME: &str = "WeRun";
+
+/// 登记 AppUserModelID（每次运行只写一次注册表）
+static REGISTER_APP_ID: Once = Once::new();
+
+/// 显示一条系统通知
+pub fn show(title: &str, message: &str) -> Result<()> {
+    REGISTER_APP_ID.call_once(|| {
+        if let Err(e) = registry::set_string_value(
+            RegistryRoot::CurrentUser,
+            APP_ID_KEY,
+            "DisplayName",
+            DISPLAY_NAME,
+        ) {
+            log::warn!("登记通知的 AppUserModelID 失败: {}", e);
+        }
+    });
+
+    com::with_com(COINIT_MULTITHREADED, || show_toast(title, message))
+        .map_err(|e| WerunError::Toast(e.message()))
+}
+
+/// 用两行文本模板构造并显示通知，文本以节点写入，无需转义
+fn show_toast(title: &str, message: &str) -> windows::core::Result<()> {
+    let document = ToastNotificationManager::GetTemplateContent(ToastTemplateType::ToastText02)?;
+    let texts = document.GetElementsByTagName(&HSTRING::from("text"))?;
+    for (index, text) in [title, message].into_iter().enumerate() {
+        let node = document.CreateTextNode(&HSTRING::from(text))?;
+        texts.Item(index as u32)?.AppendChild(&node)?;
+    }
+
+    let toast = ToastNotification::CreateToastNotification(&document)?;
+    ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_USER_MODEL_ID))?
+        .Show(&toast)
+}
@@ -1,22 +1,81 @@
 /// Windows 平台特定功能
 ///
-/// 提供全局快捷键、窗口管理等 Windows API 封装
-use std::sync::Mutex;
-
-use windows::Win32::{
-    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
-    UI::{
-        Input::KeyboardAndMouse::{
-            RegisterHotKey, UnregisterHotKey, HOT_KEY_MODIFIERS, MOD_ALT, VK_SPACE,
+/// 提供全局快捷键、剪贴板监听、窗口管理等 Windows API 封装
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicIsize, Ordering},
+        mpsc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use windows::{
+    core::{Interface, PWSTR, VARIANT},
+    Win32::{
+        Foundation::{
+            CloseHandle, ERROR_HOTKEY_ALREADY_REGISTERED, HINSTANCE, HWND, LPARAM, LRESULT, POINT,
+            WPARAM,
         },
-        WindowsAndMessaging::{
-            CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
-            TranslateMessage, CS_HREDRAW, CS_VREDRAW, CW_USEDEFAULT, MSG, WM_HOTKEY, WNDCLASSW,
-            WS_EX_NOACTIVATE, WS_OVERLAPPED,
+        System::{
+            Com::{CoCreateInstance, CLSCTX_ALL, COINIT_MULTITHREADED},
+            DataExchange::{
+                AddClipboardFormatListener, GetClipboardSequenceNumber,
+                RemoveClipboardFormatListener, COPYDATASTRUCT,
+            },
+            Threading::{
+                GetCurrentProcessId, OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+                PROCESS_QUERY_LIMITED_INFORMATION,
+            },
+        },
+        UI::{
+            Accessibility::{
+                CUIAutomation, IUIAutomation, IUIAutomationTextPattern, NotificationKind_Other,
+                NotificationProcessing_ImportantMostRecent, TreeScope_Descendants,
+                UIA_ControlTypePropertyId, UIA_DocumentControlTypeId, UIA_TextPatternId,
+                UiaClientsAreListening, UiaHostProviderFromHwnd, UiaRaiseNotificationEvent,
+            },
+            Input::KeyboardAndMouse::{
+                GetAsyncKeyState, GetKeyState, GetKeyboardLayout, RegisterHotKey, SendInput,
+                ToUnicodeEx, UnregisterHotKey, HOT_KEY_MODIFIERS, INPUT, INPUT_0, INPUT_KEYBOARD,
+                KEYBDINPUT, KEYBD_EVENT_FLAGS, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE, MOD_ALT,
+                VIRTUAL_KEY, VK_BACK, VK_C, VK_CAPITAL, VK_CONTROL, VK_LSHIFT, VK_LWIN, VK_MENU,
+                VK_RETURN, VK_RSHIFT, VK_RWIN, VK_SHIFT, VK_SPACE, VK_V,
+            },
+            Shell::{
+                IShellWindows, IWebBrowserApp, ShellWindows, Shell_NotifyIconW, NIF_ICON,
+                NIF_MESSAGE, NIF_TIP, NIM_ADD, NIM_DELETE, NOTIFYICONDATAW,
+            },
+            WindowsAndMessaging::{
+                AllowSetForegroundWindow, AppendMenuW, CallNextHookEx, CreatePopupMenu,
+                CreateWindowExW, DefWindowProcW, DestroyMenu, DispatchMessageW, FindWindowExW,
+                GetClassNameW, GetCursorPos, GetForegroundWindow, GetMessageW, GetWindowTextW,
+                GetWindowThreadProcessId, IsWindow, KillTimer, LoadIconW, PostMessageW,
+                RegisterClassW, RegisterWindowMessageW, SendMessageTimeoutW, SetForegroundWindow,
+                SetTimer, SetWindowsHookExW, ShowWindow, TrackPopupMenu, TranslateMessage,
+                ASFW_ANY, CS_HREDRAW, CS_VREDRAW, CW_USEDEFAULT, HC_ACTION, HHOOK, HWND_MESSAGE,
+                IDI_APPLICATION, KBDLLHOOKSTRUCT, LLKHF_INJECTED, MF_CHECKED, MF_SEPARATOR,
+                MF_STRING, MSG, SMTO_ABORTIFHUNG, SPI_SETHIGHCONTRAST, SW_HIDE, TPM_RETURNCMD,
+                TPM_RIGHTBUTTON, WH_KEYBOARD_LL, WINDOW_EX_STYLE, WINDOW_STYLE, WM_APP,
+                WM_CLIPBOARDUPDATE, WM_CONTEXTMENU, WM_COPYDATA, WM_DESTROY, WM_HOTKEY, WM_KEYDOWN,
+                WM_LBUTTONUP, WM_NULL, WM_RBUTTONUP, WM_SETTINGCHANGE, WM_SYSKEYDOWN, WM_TIMER,
+                WNDCLASSW, WS_EX_NOACTIVATE, WS_OVERLAPPED,
+            },
         },
     },
 };
 
+use crate::{
+    core::{
+        config::{HotkeyPassthrough, HotstringConfig},
+        error::{Result, WerunError},
+        hotkey::HotkeyChord,
+        hotstring::{expand_placeholders, HotstringMatcher},
+        plugin::ForegroundInfo,
+    },
+    platform::com,
+    utils::clipboard::ClipboardManager,
+};
+
 /// 全局快捷键管理器
 pub struct GlobalHotkeyManager {
     /// 窗口句柄
@@ -28,22 +87,168 @@ pub struct GlobalHotkeyManager {
 /// 热键 ID
 const HOTKEY_ID: i32 = 1;
 
+/// 预填快捷键的热键 ID
+const PREFILL_HOTKEY_ID: i32 = 2;
+
+/// RegisterHotKey 的修饰键：按住不放时不重复触发
+const MOD_NOREPEAT: u32 = 0x4000;
+
+/// 透传后重新注册快捷键的定时器 ID
+const REREGISTER_TIMER_ID: usize = 1;
+
+/// 透传后重新注册快捷键的延迟 (毫秒)，等待注入的按键被系统处理
+const REREGISTER_DELAY_MS: u32 = 100;
+
 /// 全局窗口类名
 const WINDOW_CLASS_NAME: &str = "WeRunHotkeyWindow";
 
+/// 剪贴板监听窗口类名
+const CLIPBOARD_CLASS_NAME: &str = "WeRunClipboardListener";
+
+/// 启动命令监听窗口类名
+const COMMAND_CLASS_NAME: &str = "WeRunCommandListener";
+
+/// 转发启动命令的 WM_COPYDATA 标识
+const COMMAND_COPYDATA_ID: usize = 0x5752;
+
+/// 转发启动命令的超时 (毫秒)
+const COMMAND_SEND_TIMEOUT_MS: u32 = 2000;
+
+/// 托盘图标窗口类名
+const TRAY_CLASS_NAME: &str = "WeRunTrayIcon";
+
+/// 托盘图标 ID
+const TRAY_ICON_ID: u32 = 1;
+
+/// 托盘图标的鼠标事件消息
+const WM_TRAY_ICON: u32 = WM_APP + 1;
+
+/// 可执行文件中应用图标的资源 ID（见 werun.rc）
+const APP_ICON_RESOURCE_ID: usize = 1;
+
+/// 播报通知的活动 ID，读屏软件据此把同一来源的通知合并
+const ANNOUNCE_ACTIVITY_ID: &str = "WeRun.Announcement";
+
+/// 切换前台窗口后，等待目标窗口处理激活再发送粘贴按键的时间
+const PASTE_DELAY: Duration = Duration::from_millis(50);
+
+/// 模拟复制前等待用户松开快捷键修饰键的最长时间
+const MODIFIER_RELEASE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// 模拟复制后等待剪贴板更新的最长时间
+const COPY_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// 恢复剪贴板后继续忽略剪贴板变化的时间，等待变化通知送达监听线程
+const CLIPBOARD_RESTORE_GRACE: Duration = Duration::from_millis(500);
+
 /// 全局回调函数（使用 Mutex 包装以支持线程安全）
 static HOTKEY_CALLBACK: Mutex<Option<Box<dyn Fn() + Send + Sync>>> = Mutex::new(None);
 
+/// 剪贴板内容变化时的回调
+static CLIPBOARD_CALLBACK: Mutex<Option<Box<dyn Fn() + Send + Sync>>> = Mutex::new(None);
+
+/// 剪贴板监听线程是否已启动
+static CLIPBOARD_LISTENING: AtomicBool = AtomicBool::new(false);
+
+/// 在此时间之前忽略剪贴板变化（模拟复制读取选中文本期间）
+static CLIPBOARD_IGNORED_UNTIL: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// 预填快捷键触发时的回调
+static PREFILL_CALLBACK: Mutex<Option<Box<dyn Fn() + Send + Sync>>> = Mutex::new(None);
+
+/// 启动命令回调
+type CommandCallback = Box<dyn Fn(String) + Send + Sync>;
+
+/// 收到其他实例转发的启动命令时的回调
+static COMMAND_CALLBACK: Mutex<Option<CommandCallback>> = Mutex::new(None);
+
+/// 托盘菜单回调
+type TrayCallback = Box<dyn Fn(TrayCommand) + Send + Sync>;
+
+/// 选择托盘菜单项时的回调
+static TRAY_CALLBACK: Mutex<Option<TrayCallback>> = Mutex::new(None);
+
+/// 托盘图标窗口句柄，未创建时为 0
+static TRAY_WINDOW: AtomicIsize = AtomicIsize::new(0);
+
+/// 资源管理器重启后广播的消息，收到时重新添加托盘图标
+static TASKBAR_CREATED: AtomicIsize = AtomicIsize::new(0);
+
+/// 系统明暗模式或高对比度设置变化时的回调
+static THEME_CHANGE_CALLBACK: Mutex<Option<Box<dyn Fn() + Send + Sync>>> = Mutex::new(None);
+
+/// 是否暂停全局快捷键和缩写展开
+static HOTKEYS_PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// 快捷键透传设置
+static PASSTHROUGH: Mutex<Option<Passthrough>> = Mutex::new(None);
+
+/// 缩写展开状态（键盘钩子中使用）
+static HOTSTRINGS: Mutex<Option<HotstringState>> = Mutex::new(None);
+
+/// 键盘钩子线程是否已启动
+static HOTSTRINGS_LISTENING: AtomicBool = AtomicBool::new(false);
+
+/// 快捷键透传设置和状态
+struct Passthrough {
+    /// 透传模式
+    mode: HotkeyPassthrough,
+    /// 双击判定间隔
+    double_press: Duration,
+    /// 启动器窗口是否可见
+    is_visible: Box<dyn Fn() -> bool + Send + Sync>,
+    /// 上次由启动器处理的触发时间
+    last_trigger: Option<Instant>,
+}
+
+/// 快捷键触发后的处理方式
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HotkeyResponse {
+    /// 由启动器处理（切换显示）
+    Toggle,
+    /// 透传给前台窗口
+    Forward,
+    /// 先关闭启动器再透传
+    ToggleAndForward,
+}
+
+impl Passthrough {
+    /// 决定本次触发的处理方式
+    fn decide(&mut self) -> HotkeyResponse {
+        match self.mode {
+            HotkeyPassthrough::Off => HotkeyResponse::Toggle,
+            HotkeyPassthrough::WhenVisible => {
+                if (self.is_visible)() && !is_own_foreground() {
+                    HotkeyResponse::Forward
+                } else {
+                    HotkeyResponse::Toggle
+                }
+            },
+            HotkeyPassthrough::DoublePress => {
+                let now = Instant::now();
+                let double = self.last_trigger.is_some_and(|t| now - t <= self.double_press);
+                // 透传后重新计时，避免连按三次时再次透传
+                self.last_trigger = (!double).then_some(now);
+                if double {
+                    HotkeyResponse::ToggleAndForward
+                } else {
+                    HotkeyResponse::Toggle
+                }
+            },
+        }
+    }
+}
+
 impl GlobalHotkeyManager {
     /// 创建新的全局快捷键管理器
-    pub fn new() -> anyhow::Result<Self> {
-        let hwnd = Self::create_message_window()?;
+    pub fn new() -> Result<Self> {
+        let hwnd = Self::create_message_window().map_err(WerunError::HotkeySetup)?;
 
         Ok(Self { hwnd, registered: false })
     }
 
     /// 注册 Alt+Space 全局快捷键
-    pub fn register_alt_space<F>(&mut self, callback: F) -> anyhow::Result<()>
+    pub fn register_alt_space<F>(&mut self, callback: F) -> Result<()>
     where
         F: Fn() + Send + Sync + 'static,
     {
@@ -58,7 +263,14 @@ impl GlobalHotkeyManager {
 
         // 注册全局快捷键 Alt+Space
         unsafe {
-            RegisterHotKey(self.hwnd, HOTKEY_ID, HOT_KEY_MODIFIERS(MOD_ALT.0), VK_SPACE.0 as u32)?;
+            RegisterHotKey(self.hwnd, HOTKEY_ID, HOT_KEY_MODIFIERS(MOD_ALT.0), VK_SPACE.0 as u32)
+                .map_err(|e| {
+                if e.code() == ERROR_HOTKEY_ALREADY_REGISTERED.to_hresult() {
+                    WerunError::HotkeyInUse("Alt+Space".to_string())
+                } else {
+                    WerunError::HotkeySetup(e.into())
+                }
+            })?;
         }
 
         self.registered = true;
@@ -72,14 +284,69 @@ impl GlobalHotkeyManager {
         Ok(())
     }
 
+    /// 注册预填快捷键，`chord` 为 `Alt+Shift+Space` 形式的配置
+    ///
+    /// 需在 [`Self::register_alt_space`] 之后调用，共用其消息循环
+    pub fn register_prefill<F>(&mut self, chord: &str, callback: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let Some(parsed) = HotkeyChord::parse(chord) else {
+            return Err(WerunError::HotkeySetup(anyhow::anyhow!("无法识别的快捷键: {}", chord)));
+        };
+
+        if let Ok(mut guard) = PREFILL_CALLBACK.lock() {
+            *guard = Some(Box::new(callback));
+        }
+
+        unsafe {
+            let modifiers = HOT_KEY_MODIFIERS(parsed.modifiers | MOD_NOREPEAT);
+            RegisterHotKey(self.hwnd, PREFILL_HOTKEY_ID, modifiers, parsed.key).map_err(|e| {
+                if e.code() == ERROR_HOTKEY_ALREADY_REGISTERED.to_hresult() {
+                    WerunError::HotkeyInUse(chord.to_string())
+                } else {
+                    WerunError::HotkeySetup(e.into())
+                }
+            })?;
+        }
+
+        log::info!("预填快捷键 {} 注册成功", chord);
+        Ok(())
+    }
+
+    /// 设置快捷键透传
+    ///
+    /// 透传时把 Alt+Space 交还给前台窗口，而不是由启动器吞掉；`is_visible` 查询启动器窗口是否可见
+    pub fn set_passthrough<V>(
+        &mut self,
+        mode: HotkeyPassthrough,
+        double_press: Duration,
+        is_visible: V,
+    ) where
+        V: Fn() -> bool + Send + Sync + 'static,
+    {
+        if let Ok(mut guard) = PASSTHROUGH.lock() {
+            *guard = Some(Passthrough {
+                mode,
+                double_press,
+                is_visible: Box::new(is_visible),
+                last_trigger: None,
+            });
+        }
+        log::info!("快捷键透传模式: {:?}", mode);
+    }
+
     /// 注销快捷键
-    pub fn unregister(&mut self) -> anyhow::Result<()> {
+    pub fn unregister(&mut self) -> Result<()> {
         if !self.registered {
             return Ok(());
         }
 
         unsafe {
-            UnregisterHotKey(self.hwnd, HOTKEY_ID)?;
+            UnregisterHotKey(self.hwnd, HOTKEY_ID)
+                .map_err(|e| WerunError::HotkeySetup(e.into()))?;
+            // 预填快捷键可能未注册
+            let _ = UnregisterHotKey(self.hwnd, PREFILL_HOTKEY_ID);
         }
 
         self.registered = false;
@@ -145,8 +412,32 @@ impl GlobalHotkeyManager {
                 if wparam.0 as i32 == HOTKEY_ID {
                     log::debug!("全局快捷键 Alt+Space 被触发");
 
+                    // 暂停时把按键交还给前台窗口，如同没有注册快捷键
+                    let response = if hotkeys_paused() {
+                        HotkeyResponse::Forward
+                    } else {
+                        PASSTHROUGH
+                            .lock()
+                            .ok()
+                            .and_then(|mut guard| guard.as_mut().map(Passthrough::decide))
+                            .unwrap_or(HotkeyResponse::Toggle)
+                    };
+
                     // 调用回调函数
-                    if let Ok(guard) = HOTKEY_CALLBACK.lock() {
+                    if response != HotkeyResponse::Forward {
+                        if let Ok(guard) = HOTKEY_CALLBACK.lock() {
+                            if let Some(callback) = guard.as_ref() {
+                                callback();
+                            }
+                        }
+                    }
+
+                    if response != HotkeyResponse::Toggle {
+                        Self::forward_chord(hwnd);
+                    }
+                } else if wparam.0 as i32 == PREFILL_HOTKEY_ID && !hotkeys_paused() {
+                    log::debug!("预填快捷键被触发");
+                    if let Ok(guard) = PREFILL_CALLBACK.lock() {
                         if let Some(callback) = guard.as_ref() {
                             callback();
                         }
@@ -154,10 +445,45 @@ impl GlobalHotkeyManager {
                 }
                 LRESULT(0)
             },
+            WM_TIMER if wparam.0 == REREGISTER_TIMER_ID => {
+                let _ = KillTimer(hwnd, REREGISTER_TIMER_ID);
+                if let Err(e) =
+                    RegisterHotKey(hwnd, HOTKEY_ID, HOT_KEY_MODIFIERS(MOD_ALT.0), VK_SPACE.0 as u32)
+                {
+                    WerunError::HotkeySetup(e.into()).log();
+                }
+                LRESULT(0)
+            },
             _ => DefWindowProcW(hwnd, msg, wparam, lparam),
         }
     }
 
+    /// 把 Alt+Space 转发给前台窗口
+    ///
+    /// 快捷键注册期间注入的按键同样会被拦截，因此先注销，待按键处理后由定时器重新注册
+    unsafe fn forward_chord(hwnd: HWND) {
+        log::info!("透传 Alt+Space 到前台窗口");
+        let _ = UnregisterHotKey(hwnd, HOTKEY_ID);
+
+        // 触发时 Alt 通常仍被按住，只需补发空格
+        let alt_held = GetAsyncKeyState(VK_MENU.0 as i32) < 0;
+        let mut inputs = Vec::new();
+        if !alt_held {
+            inputs.push(key_input(VK_MENU, false));
+        }
+        inputs.push(key_input(VK_SPACE, false));
+        inputs.push(key_input(VK_SPACE, true));
+        if !alt_held {
+            inputs.push(key_input(VK_MENU, true));
+        }
+
+        if SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) as usize != inputs.len() {
+            log::warn!("注入 Alt+Space 按键失败");
+        }
+
+        SetTimer(hwnd, REREGISTER_TIMER_ID, REREGISTER_DELAY_MS, None);
+    }
+
     /// 消息循环
     fn message_loop() {
         unsafe {
@@ -186,6 +512,1059 @@ impl GlobalHotkeyManager {
     }
 }
 
+/// 剪贴板监听器
+///
+/// 在后台线程中创建仅消息窗口，通过 AddClipboardFormatListener 订阅 WM_CLIPBOARDUPDATE，
+/// 每次剪贴板内容变化（包括启动器自己写入）时调用回调
+pub struct ClipboardListener;
+
+impl ClipboardListener {
+    /// 开始监听，回调在监听线程中调用
+    ///
+    /// 监听线程只启动一次，再次调用时只替换回调
+    pub fn start<F>(callback: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        if let Ok(mut guard) = CLIPBOARD_CALLBACK.lock() {
+            *guard = Some(Box::new(callback));
+        }
+
+        if CLIPBOARD_LISTENING.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        // 窗口消息发送到创建窗口的线程，窗口需要在监听线程中创建
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            if let Err(e) = unsafe { Self::create_window() } {
+                let _ = ready_tx.send(Err(e));
+                return;
+            }
+            let _ = ready_tx.send(Ok(()));
+
+            log::info!("剪贴板监听已启动");
+            GlobalHotkeyManager::message_loop();
+        });
+
+        let ready = ready_rx
+            .recv()
+            .map_err(|e| WerunError::Clipboard(e.to_string()))
+            .and_then(|ready| ready.map_err(|e| WerunError::Clipboard(e.to_string())));
+        if ready.is_err() {
+            CLIPBOARD_LISTENING.store(false, Ordering::SeqCst);
+        }
+        ready
+    }
+
+    /// 创建仅消息窗口并订阅剪贴板变化
+    unsafe fn create_window() -> windows::core::Result<()> {
+        let hinstance: HINSTANCE =
+            windows::Win32::System::LibraryLoader::GetModuleHandleW(None)?.into();
+
+        let class_name: Vec<u16> =
+            CLIPBOARD_CLASS_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: hinstance,
+            lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        RegisterClassW(&wnd_class);
+
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            windows::core::PCWSTR(class_name.as_ptr()),
+            windows::core::PCWSTR(class_name.as_ptr()),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            None,
+            hinstance,
+            None,
+        )?;
+
+        AddClipboardFormatListener(hwnd)
+    }
+
+    /// 窗口过程函数
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_CLIPBOARDUPDATE => {
+                log::debug!("剪贴板内容已变化");
+                if clipboard_ignored() {
+                    log::debug!("读取选中文本期间的剪贴板变化，不记录");
+                    return LRESULT(0);
+                }
+                if let Ok(guard) = CLIPBOARD_CALLBACK.lock() {
+                    if let Some(callback) = guard.as_ref() {
+                        callback();
+                    }
+                }
+                LRESULT(0)
+            },
+            WM_DESTROY => {
+                let _ = RemoveClipboardFormatListener(hwnd);
+                CLIPBOARD_LISTENING.store(false, Ordering::SeqCst);
+                LRESULT(0)
+            },
+            _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+        }
+    }
+}
+
+/// 启动命令监听器
+///
+/// 跳转列表等方式再次启动 werun 时，新进程通过 WM_COPYDATA 把命令转发给已运行的实例后退出
+pub struct CommandListener;
+
+impl CommandListener {
+    /// 开始监听，回调在监听线程中调用
+    pub fn start<F>(callback: F) -> Result<()>
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        if let Ok(mut guard) = COMMAND_CALLBACK.lock() {
+            *guard = Some(Box::new(callback));
+        }
+
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            // HWND 不能跨线程发送，只回传创建结果
+            if let Err(e) = unsafe { Self::create_window() } {
+                let _ = ready_tx.send(Err(e));
+                return;
+            }
+            let _ = ready_tx.send(Ok(()));
+
+            log::info!("启动命令监听已启动");
+            GlobalHotkeyManager::message_loop();
+        });
+
+        ready_rx
+            .recv()
+            .map_err(|e| WerunError::JumpList(e.into()))
+            .and_then(|ready| ready.map_err(|e| WerunError::JumpList(e.into())))
+    }
+
+    /// 把命令发送给已运行的实例，没有运行中的实例时返回 `false`
+    pub fn send(message: &str) -> bool {
+        unsafe {
+            let class_name = windows::core::HSTRING::from(COMMAND_CLASS_NAME);
+            let Ok(hwnd) = FindWindowExW(HWND_MESSAGE, None, &class_name, None) else {
+                return false;
+            };
+
+            // 新进程持有前台权限，允许已运行的实例激活启动器窗口
+            let _ = AllowSetForegroundWindow(ASFW_ANY);
+
+            let data = COPYDATASTRUCT {
+                dwData: COMMAND_COPYDATA_ID,
+                cbData: message.len() as u32,
+                lpData: message.as_ptr() as *mut _,
+            };
+            let mut handled = 0;
+            SendMessageTimeoutW(
+                hwnd,
+                WM_COPYDATA,
+                WPARAM(0),
+                LPARAM(&data as *const _ as isize),
+                SMTO_ABORTIFHUNG,
+                COMMAND_SEND_TIMEOUT_MS,
+                Some(&mut handled),
+            );
+            handled != 0
+        }
+    }
+
+    /// 创建仅消息窗口
+    unsafe fn create_window() -> windows::core::Result<HWND> {
+        let hinstance: HINSTANCE =
+            windows::Win32::System::LibraryLoader::GetModuleHandleW(None)?.into();
+
+        let class_name: Vec<u16> =
+            COMMAND_CLASS_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: hinstance,
+            lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        RegisterClassW(&wnd_class);
+
+        CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            windows::core::PCWSTR(class_name.as_ptr()),
+            windows::core::PCWSTR(class_name.as_ptr()),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            None,
+            hinstance,
+            None,
+        )
+    }
+
+    /// 窗口过程函数
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg != WM_COPYDATA {
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+
+        let data = &*(lparam.0 as *const COPYDATASTRUCT);
+        if data.dwData != COMMAND_COPYDATA_ID || data.lpData.is_null() {
+            return LRESULT(0);
+        }
+        let bytes = std::slice::from_raw_parts(data.lpData as *const u8, data.cbData as usize);
+        let message = String::from_utf8_lossy(bytes).to_string();
+        log::info!("收到转发的启动命令: {:?}", message);
+
+        if let Ok(guard) = COMMAND_CALLBACK.lock() {
+            if let Some(callback) = guard.as_ref() {
+                callback(message);
+            }
+        }
+        LRESULT(1)
+    }
+}
+
+/// 托盘菜单命令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrayCommand {
+    /// 显示或隐藏启动器（也由单击图标触发）
+    Toggle,
+    /// 打开设置
+    Settings,
+    /// 重新扫描应用和文件索引
+    Rescan,
+    /// 暂停或恢复全局快捷键
+    PauseHotkeys,
+    /// 退出
+    Quit,
+}
+
+impl TrayCommand {
+    /// 菜单项（按显示顺序）
+    const MENU: [TrayCommand; 5] = [
+        TrayCommand::Toggle,
+        TrayCommand::Settings,
+        TrayCommand::Rescan,
+        TrayCommand::PauseHotkeys,
+        TrayCommand::Quit,
+    ];
+
+    /// 菜单项文本
+    fn label(self) -> &'static str {
+        match self {
+            TrayCommand::Toggle => "显示/隐藏启动器",
+            TrayCommand::Settings => "设置",
+            TrayCommand::Rescan => "重新扫描索引",
+            TrayCommand::PauseHotkeys => "暂停快捷键",
+            TrayCommand::Quit => "退出",
+        }
+    }
+
+    /// 菜单项 ID（从 1 开始，TrackPopupMenu 未选择时返回 0）
+    fn menu_id(self) -> usize {
+        Self::MENU.iter().position(|&command| command == self).unwrap_or_default() + 1
+    }
+
+    /// 从菜单项 ID 还原
+    fn from_menu_id(id: usize) -> Option<Self> {
+        id.checked_sub(1).and_then(|index| Self::MENU.get(index)).copied()
+    }
+}
+
+/// 通知区域（托盘）图标
+///
+/// 在后台线程中创建隐藏窗口并通过 Shell_NotifyIcon 添加图标，单击切换启动器，
+/// 右键弹出菜单；资源管理器重启后自动重新添加。该窗口是顶层窗口，
+/// 同时接收系统设置变化的广播（仅消息窗口收不到广播）
+pub struct TrayIcon;
+
+impl TrayIcon {
+    /// 添加托盘图标，回调在托盘线程中调用
+    pub fn start<F>(callback: F) -> Result<()>
+    where
+        F: Fn(TrayCommand) + Send + Sync + 'static,
+    {
+        if let Ok(mut guard) = TRAY_CALLBACK.lock() {
+            *guard = Some(Box::new(callback));
+        }
+
+        // 弹出菜单需要由拥有窗口的线程处理消息
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let created = unsafe { Self::create_window() };
+            let failed = created.is_err();
+            let _ = ready_tx.send(created);
+            if !failed {
+                log::info!("托盘图标已添加");
+                GlobalHotkeyManager::message_loop();
+            }
+        });
+
+        ready_rx
+            .recv()
+            .map_err(|e| WerunError::TrayIcon(e.to_string()))
+            .and_then(|ready| ready.map_err(|e| WerunError::TrayIcon(e.to_string())))
+    }
+
+    /// 设置系统明暗模式或高对比度变化时的回调，回调在托盘线程中调用
+    pub fn on_theme_changed<F>(callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        if let Ok(mut guard) = THEME_CHANGE_CALLBACK.lock() {
+            *guard = Some(Box::new(callback));
+        }
+    }
+
+    /// 移除托盘图标（退出前调用，否则图标会残留到鼠标经过时）
+    pub fn remove() {
+        let hwnd = TRAY_WINDOW.swap(0, Ordering::SeqCst);
+        if hwnd != 0 {
+            unsafe {
+                let _ = Shell_NotifyIconW(NIM_DELETE, &Self::icon_data(HWND(hwnd as _)));
+            }
+        }
+    }
+
+    /// 创建接收图标消息的隐藏窗口并添加图标
+    ///
+    /// 不使用仅消息窗口：弹出菜单前需要把窗口设为前台，菜单才会在点击别处时关闭
+    unsafe fn create_window() -> windows::core::Result<()> {
+        let hinstance: HINSTANCE =
+            windows::Win32::System::LibraryLoader::GetModuleHandleW(None)?.into();
+
+        let class_name: Vec<u16> =
+            TRAY_CLASS_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: hinstance,
+            lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        RegisterClassW(&wnd_class);
+
+        let hwnd = CreateWindowExW(
+            WS_EX_NOACTIVATE,
+            windows::core::PCWSTR(class_name.as_ptr()),
+            windows::core::PCWSTR(class_name.as_ptr()),
+            WS_OVERLAPPED,
+            0,
+            0,
+            0,
+            0,
+            None,
+            None,
+            hinstance,
+            None,
+        )?;
+
+        let message = RegisterWindowMessageW(windows::core::w!("TaskbarCreated"));
+        TASKBAR_CREATED.store(message as isize, Ordering::SeqCst);
+        TRAY_WINDOW.store(hwnd.0 as isize, Ordering::SeqCst);
+        Self::add_icon(hwnd).ok()
+    }
+
+    /// 图标数据：可执行文件中的应用图标，缺失时使用系统默认图标
+    unsafe fn icon_data(hwnd: HWND) -> NOTIFYICONDATAW {
+        let hinstance: Option<HINSTANCE> =
+            windows::Win32::System::LibraryLoader::GetModuleHandleW(None).ok().map(Into::into);
+        let icon = hinstance
+            .and_then(|hinstance| {
+                LoadIconW(hinstance, windows::core::PCWSTR(APP_ICON_RESOURCE_ID as *const u16)).ok()
+            })
+            .or_else(|| LoadIconW(None, IDI_APPLICATION).ok())
+            .unwrap_or_default();
+
+        let mut data = NOTIFYICONDATAW {
+            cbSize: std::mem::size_of::<NOTIFYICONDATAW>() as u32,
+            hWnd: hwnd,
+            uID: TRAY_ICON_ID,
+            uFlags: NIF_ICON | NIF_MESSAGE | NIF_TIP,
+            uCallbackMessage: WM_TRAY_ICON,
+            hIcon: icon,
+            ..Default::default()
+        };
+        for (dst, src) in data.szTip.iter_mut().zip("WeRun".encode_utf16()) {
+            *dst = src;
+        }
+        data
+    }
+
+    /// 添加图标
+    unsafe fn add_icon(hwnd: HWND) -> windows::Win32::Foundation::BOOL {
+        Shell_NotifyIconW(NIM_ADD, &Self::icon_data(hwnd))
+    }
+
+    /// 在鼠标位置弹出菜单，返回选择的命令
+    unsafe fn show_menu(hwnd: HWND) -> Option<TrayCommand> {
+        let menu = CreatePopupMenu().ok()?;
+        for command in TrayCommand::MENU {
+            if command == TrayCommand::Quit {
+                let _ = AppendMenuW(menu, MF_SEPARATOR, 0, None);
+            }
+            let flags = if command == TrayCommand::PauseHotkeys && hotkeys_paused() {
+                MF_STRING | MF_CHECKED
+            } else {
+                MF_STRING
+            };
+            let label = windows::core::HSTRING::from(command.label());
+            let _ = AppendMenuW(menu, flags, command.menu_id(), &label);
+        }
+
+        let mut cursor = POINT::default();
+        let _ = GetCursorPos(&mut cursor);
+        let _ = SetForegroundWindow(hwnd);
+        let selected = TrackPopupMenu(
+            menu,
+            TPM_RETURNCMD | TPM_RIGHTBUTTON,
+            cursor.x,
+            cursor.y,
+            0,
+            hwnd,
+            None,
+        );
+        // 按文档要求发送一条消息，保证下次弹出的菜单能正常关闭
+        let _ = PostMessageW(hwnd, WM_NULL, WPARAM(0), LPARAM(0));
+        let _ = DestroyMenu(menu);
+
+        TrayCommand::from_menu_id(selected.0 as usize)
+    }
+
+    /// 处理 WM_SETTINGCHANGE：参数为 `ImmersiveColorSet` 时应用的明暗模式可能已变化，
+    /// `SPI_SETHIGHCONTRAST` 为高对比度变化
+    unsafe fn setting_changed(wparam: WPARAM, lparam: LPARAM) {
+        let area = if lparam.0 == 0 {
+            String::new()
+        } else {
+            windows::core::PCWSTR(lparam.0 as *const u16).to_string().unwrap_or_default()
+        };
+        if area != "ImmersiveColorSet" && wparam.0 != SPI_SETHIGHCONTRAST.0 as usize {
+            return;
+        }
+
+        log::info!("系统主题设置已变化");
+        if let Ok(guard) = THEME_CHANGE_CALLBACK.lock() {
+            if let Some(callback) = guard.as_ref() {
+                callback();
+            }
+        }
+    }
+
+    /// 调用回调
+    fn dispatch(command: TrayCommand) {
+        log::info!("托盘菜单: {:?}", command);
+        if let Ok(guard) = TRAY_CALLBACK.lock() {
+            if let Some(callback) = guard.as_ref() {
+                callback(command);
+            }
+        }
+    }
+
+    /// 窗口过程函数
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_TRAY_ICON {
+            match (lparam.0 & 0xFFFF) as u32 {
+                WM_LBUTTONUP => Self::dispatch(TrayCommand::Toggle),
+                WM_RBUTTONUP | WM_CONTEXTMENU => {
+                    if let Some(command) = Self::show_menu(hwnd) {
+                        Self::dispatch(command);
+                    }
+                },
+                _ => {},
+            }
+            return LRESULT(0);
+        }
+
+        if msg == WM_SETTINGCHANGE {
+            Self::setting_changed(wparam, lparam);
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+
+        let taskbar_created = TASKBAR_CREATED.load(Ordering::SeqCst);
+        if taskbar_created != 0 && msg as isize == taskbar_created {
+            log::info!("资源管理器已重启，重新添加托盘图标");
+            let _ = Self::add_icon(hwnd);
+            return LRESULT(0);
+        }
+
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+}
+
+/// 通过 UI 自动化通知事件让读屏软件朗读消息
+///
+/// GPUI 没有向系统暴露无障碍树，借用窗口的宿主提供程序发出通知；
+/// 新的通知会打断尚未读完的旧通知，没有读屏软件监听时不做任何事
+pub fn announce(hwnd: isize, message: &str) {
+    unsafe {
+        if !UiaClientsAreListening().as_bool() {
+            return;
+        }
+
+        let result = UiaHostProviderFromHwnd(HWND(hwnd as _)).and_then(|provider| {
+            UiaRaiseNotificationEvent(
+                &provider,
+                NotificationKind_Other,
+                NotificationProcessing_ImportantMostRecent,
+                &windows::core::BSTR::from(message),
+                &windows::core::BSTR::from(ANNOUNCE_ACTIVITY_ID),
+            )
+        });
+        if let Err(e) = result {
+            log::debug!("播报消息失败: {}", e);
+        }
+    }
+}
+
+/// 全局快捷键和缩写展开是否已暂停
+pub fn hotkeys_paused() -> bool {
+    HOTKEYS_PAUSED.load(Ordering::SeqCst)
+}
+
+/// 暂停或恢复全局快捷键和缩写展开
+pub fn set_hotkeys_paused(paused: bool) {
+    HOTKEYS_PAUSED.store(paused, Ordering::SeqCst);
+    log::info!("全局快捷键已{}", if paused { "暂停" } else { "恢复" });
+}
+
+/// 当前前台窗口的句柄，前台窗口属于启动器时返回 `None`
+pub fn foreground_window() -> Option<isize> {
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        (!hwnd.is_invalid() && !is_own_foreground()).then_some(hwnd.0 as isize)
+    }
+}
+
+/// 缩写展开状态
+struct HotstringState {
+    /// 缩写匹配器
+    matcher: HotstringMatcher,
+    /// 缩写展开配置
+    config: HotstringConfig,
+    /// 最近一次按键时的前台窗口，切换窗口后清空输入记录
+    foreground: isize,
+}
+
+/// 缩写展开监听器
+///
+/// 在后台线程中安装低级键盘钩子，跟踪在任意程序中输入的字符，
+/// 输入以缩写结尾时删除缩写并通过 SendInput 输入展开内容
+pub struct HotstringListener;
+
+impl HotstringListener {
+    /// 按配置开始监听，未启用或没有缩写时不安装钩子
+    ///
+    /// 钩子线程只启动一次，再次调用时只替换缩写和排除列表
+    pub fn start(config: HotstringConfig) -> Result<()> {
+        let matcher = HotstringMatcher::new(&config.abbreviations);
+        let enabled = config.enabled && !matcher.is_empty();
+        if let Ok(mut guard) = HOTSTRINGS.lock() {
+            *guard = enabled.then_some(HotstringState { matcher, config, foreground: 0 });
+        }
+
+        if !enabled || HOTSTRINGS_LISTENING.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        // 低级键盘钩子由安装它的线程的消息循环调用
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let hook = unsafe {
+                windows::Win32::System::LibraryLoader::GetModuleHandleW(None).and_then(|module| {
+                    SetWindowsHookExW(
+                        WH_KEYBOARD_LL,
+                        Some(Self::keyboard_proc),
+                        HINSTANCE::from(module),
+                        0,
+                    )
+                })
+            };
+            if let Err(e) = hook {
+                let _ = ready_tx.send(Err(e));
+                return;
+            }
+            let _ = ready_tx.send(Ok(()));
+
+            log::info!("缩写展开已启用");
+            GlobalHotkeyManager::message_loop();
+        });
+
+        let ready = ready_rx
+            .recv()
+            .map_err(|e| WerunError::Hotstring(e.to_string()))
+            .and_then(|ready| ready.map_err(|e| WerunError::Hotstring(e.to_string())));
+        if ready.is_err() {
+            HOTSTRINGS_LISTENING.store(false, Ordering::SeqCst);
+        }
+        ready
+    }
+
+    /// 低级键盘钩子回调，展开缩写时吞掉触发的最后一个按键
+    unsafe extern "system" fn keyboard_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code == HC_ACTION as i32 && matches!(wparam.0 as u32, WM_KEYDOWN | WM_SYSKEYDOWN) {
+            let info = &*(lparam.0 as *const KBDLLHOOKSTRUCT);
+            // 忽略注入的按键，包括自己输入的展开内容
+            if info.flags.0 & LLKHF_INJECTED.0 == 0 && !hotkeys_paused() && Self::handle_key(info) {
+                return LRESULT(1);
+            }
+        }
+        CallNextHookEx(HHOOK(std::ptr::null_mut()), code, wparam, lparam)
+    }
+
+    /// 处理按键，返回是否吞掉该按键
+    unsafe fn handle_key(info: &KBDLLHOOKSTRUCT) -> bool {
+        let Ok(mut guard) = HOTSTRINGS.lock() else {
+            return false;
+        };
+        let Some(state) = guard.as_mut() else {
+            return false;
+        };
+
+        let foreground = GetForegroundWindow();
+        if foreground.0 as isize != state.foreground {
+            state.foreground = foreground.0 as isize;
+            state.matcher.reset();
+        }
+
+        let key = VIRTUAL_KEY(info.vkCode as u16);
+        if key == VK_BACK {
+            state.matcher.backspace();
+            return false;
+        }
+        if matches!(key, VK_SHIFT | VK_LSHIFT | VK_RSHIFT | VK_CAPITAL) {
+            return false;
+        }
+        // 快捷键、方向键等不产生字符的按键之后无法确定光标位置
+        let Some(c) = key_char(info, foreground) else {
+            state.matcher.reset();
+            return false;
+        };
+        let Some(expansion) = state.matcher.push(c) else {
+            return false;
+        };
+
+        let process = window_process_name(foreground).unwrap_or_default();
+        if state.config.is_excluded(&process) {
+            return false;
+        }
+
+        let text = expand_placeholders(&expansion.template, chrono::Local::now().naive_local());
+        log::info!("在 {} 中展开缩写", process);
+        // 钩子回调需尽快返回，在其他线程中注入按键；最后一个字符已被吞掉，不必删除
+        std::thread::spawn(move || type_text(expansion.erase - 1, &text));
+        true
+    }
+}
+
+/// 按键在前台窗口的键盘布局下产生的字符，按住 Ctrl 或 Alt 时返回 `None`
+unsafe fn key_char(info: &KBDLLHOOKSTRUCT, foreground: HWND) -> Option<char> {
+    if GetAsyncKeyState(VK_CONTROL.0 as i32) < 0 || GetAsyncKeyState(VK_MENU.0 as i32) < 0 {
+        return None;
+    }
+
+    let mut keyboard = [0u8; 256];
+    if GetAsyncKeyState(VK_SHIFT.0 as i32) < 0 {
+        keyboard[VK_SHIFT.0 as usize] = 0x80;
+    }
+    if GetKeyState(VK_CAPITAL.0 as i32) & 1 != 0 {
+        keyboard[VK_CAPITAL.0 as usize] = 0x01;
+    }
+
+    let layout = GetKeyboardLayout(GetWindowThreadProcessId(foreground, None));
+    let mut buffer = [0u16; 4];
+    // 标志 0x4：不改变键盘状态，避免影响死键输入
+    let len = ToUnicodeEx(info.vkCode, info.scanCode, &keyboard, &mut buffer, 0x4, layout);
+    if len != 1 {
+        return None;
+    }
+    char::from_u32(buffer[0] as u32).filter(|c| !c.is_control())
+}
+
+/// 删除 `erase` 个字符后输入文本，换行以回车键输入
+fn type_text(erase: usize, text: &str) {
+    let mut inputs = Vec::new();
+    for _ in 0..erase {
+        inputs.push(key_input(VK_BACK, false));
+        inputs.push(key_input(VK_BACK, true));
+    }
+    for c in text.chars().filter(|c| *c != '\r') {
+        if c == '\n' {
+            inputs.push(key_input(VK_RETURN, false));
+            inputs.push(key_input(VK_RETURN, true));
+            continue;
+        }
+        let mut units = [0u16; 2];
+        for unit in c.encode_utf16(&mut units) {
+            inputs.push(unicode_input(*unit, false));
+            inputs.push(unicode_input(*unit, true));
+        }
+    }
+
+    unsafe {
+        if SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) as usize != inputs.len() {
+            log::warn!("输入缩写展开内容失败");
+        }
+    }
+}
+
+/// 资源管理器文件夹窗口的类名
+const EXPLORER_CLASS_NAME: &str = "CabinetWClass";
+
+/// 读取选中文本的最大长度
+const MAX_SELECTION_LEN: i32 = 1024;
+
+/// 读取前台窗口的应用信息：进程名、类名、标题
+///
+/// 只调用几个快速的 Win32 函数，在显示启动器前调用；
+/// 资源管理器的文件夹和浏览器的选中文本由 [`load_foreground_details`] 在后台读取
+pub fn foreground_info(hwnd: isize) -> ForegroundInfo {
+    let window = HWND(hwnd as *mut _);
+    ForegroundInfo {
+        hwnd,
+        process: window_process_name(window).unwrap_or_default(),
+        class_name: window_class_name(window),
+        title: window_title(window),
+        ..Default::default()
+    }
+}
+
+/// 读取资源管理器窗口当前的文件夹、浏览器网页中选中的文本
+///
+/// 按窗口读取，启动器获得焦点后仍能读到；跨进程的 COM 调用可能较慢，应在后台线程中调用
+pub fn load_foreground_details(info: &mut ForegroundInfo) {
+    let window = HWND(info.hwnd as *mut _);
+    com::with_com(COINIT_MULTITHREADED, || unsafe {
+        if info.class_name == EXPLORER_CLASS_NAME {
+            info.folder = explorer_folder(window).ok().flatten();
+        } else if info.is_browser() {
+            info.selection = document_selection(window).ok().flatten();
+        }
+    });
+}
+
+/// 窗口标题
+fn window_title(hwnd: HWND) -> String {
+    let mut title = [0u16; 512];
+    let len = unsafe { GetWindowTextW(hwnd, &mut title) };
+    String::from_utf16_lossy(&title[..len.max(0) as usize])
+}
+
+/// 窗口类名
+fn window_class_name(hwnd: HWND) -> String {
+    let mut name = [0u16; 256];
+    let len = unsafe { GetClassNameW(hwnd, &mut name) };
+    String::from_utf16_lossy(&name[..len.max(0) as usize])
+}
+
+/// 窗口所属进程的可执行文件名（小写）
+fn window_process_name(hwnd: HWND) -> Option<String> {
+    unsafe {
+        let mut process_id = 0;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id as *mut u32));
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+
+        let mut path = [0u16; 1024];
+        let mut len = path.len() as u32;
+        let queried = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(path.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        queried.ok()?;
+
+        let path = String::from_utf16_lossy(&path[..len as usize]);
+        let name = path.rsplit('\\').next().unwrap_or(&path);
+        Some(name.to_lowercase())
+    }
+}
+
+/// 资源管理器窗口当前打开的文件夹（虚拟文件夹如“此电脑”返回 `None`）
+unsafe fn explorer_folder(hwnd: HWND) -> windows::core::Result<Option<String>> {
+    let windows: IShellWindows = CoCreateInstance(&ShellWindows, None, CLSCTX_ALL)?;
+    for index in 0..windows.Count()? {
+        let Ok(window) = windows.Item(&VARIANT::from(index)) else {
+            continue;
+        };
+        let Ok(browser) = window.cast::<IWebBrowserApp>() else {
+            continue;
+        };
+        if browser.HWND().map_or(true, |handle| handle.0 != hwnd.0 as isize) {
+            continue;
+        }
+        return Ok(folder_from_url(&browser.LocationURL()?.to_string()));
+    }
+    Ok(None)
+}
+
+/// 将 `file:///C:/Users/%E6%96%87` 形式的地址转换为本地路径
+fn folder_from_url(url: &str) -> Option<String> {
+    let path = url.strip_prefix("file:///")?;
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| path.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            },
+        }
+    }
+    Some(String::from_utf8_lossy(&decoded).replace('/', "\\"))
+}
+
+/// 通过 UI 自动化读取焦点元素中选中的文本
+unsafe fn focused_selection() -> windows::core::Result<Option<String>> {
+    let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_ALL)?;
+    let pattern: IUIAutomationTextPattern =
+        automation.GetFocusedElement()?.GetCurrentPatternAs(UIA_TextPatternId)?;
+    pattern_selection(&pattern)
+}
+
+/// 窗口中第一个文档元素（浏览器的网页内容）里选中的文本
+unsafe fn document_selection(hwnd: HWND) -> windows::core::Result<Option<String>> {
+    let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_ALL)?;
+    let condition = automation.CreatePropertyCondition(
+        UIA_ControlTypePropertyId,
+        &VARIANT::from(UIA_DocumentControlTypeId.0),
+    )?;
+    let document =
+        automation.ElementFromHandle(hwnd)?.FindFirst(TreeScope_Descendants, &condition)?;
+    let pattern: IUIAutomationTextPattern = document.GetCurrentPatternAs(UIA_TextPatternId)?;
+    pattern_selection(&pattern)
+}
+
+/// 文本模式中选中的文本，没有选中内容时返回 `None`
+unsafe fn pattern_selection(
+    pattern: &IUIAutomationTextPattern,
+) -> windows::core::Result<Option<String>> {
+    let ranges = pattern.GetSelection()?;
+
+    let mut selection = String::new();
+    for index in 0..ranges.Length()? {
+        selection.push_str(&ranges.GetElement(index)?.GetText(MAX_SELECTION_LEN)?.to_string());
+    }
+    let selection = selection.trim();
+    Ok((!selection.is_empty()).then(|| selection.to_string()))
+}
+
+/// 读取前台程序中选中的文本
+///
+/// 先通过 UI 自动化读取焦点元素中的选中内容；程序不支持且 `simulate_copy` 时模拟 Ctrl+C 复制，
+/// 读取后恢复原剪贴板内容。需在显示启动器之前调用
+pub fn selected_text(simulate_copy: bool) -> Option<String> {
+    let selection =
+        com::with_com(COINIT_MULTITHREADED, || unsafe { focused_selection().ok().flatten() });
+
+    if selection.is_some() || !simulate_copy || is_own_foreground() {
+        return selection;
+    }
+    copy_selection()
+}
+
+/// 模拟 Ctrl+C 复制选中的文本，期间的剪贴板变化不记录到历史，读取后恢复原内容
+fn copy_selection() -> Option<String> {
+    let clipboard = ClipboardManager::new();
+    let previous = clipboard.get_content().ok();
+    ignore_clipboard_for(MODIFIER_RELEASE_TIMEOUT + COPY_TIMEOUT + CLIPBOARD_RESTORE_GRACE);
+
+    // 快捷键的修饰键仍被按住时，注入的 Ctrl+C 会变成其他组合键
+    let deadline = Instant::now() + MODIFIER_RELEASE_TIMEOUT;
+    let held = || {
+        [VK_MENU, VK_SHIFT, VK_CONTROL, VK_LWIN, VK_RWIN]
+            .iter()
+            .any(|key| unsafe { GetAsyncKeyState(key.0 as i32) } < 0)
+    };
+    while held() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let sequence = unsafe { GetClipboardSequenceNumber() };
+    let inputs = [
+        key_input(VK_CONTROL, false),
+        key_input(VK_C, false),
+        key_input(VK_C, true),
+        key_input(VK_CONTROL, true),
+    ];
+    unsafe {
+        if SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) as usize != inputs.len() {
+            log::warn!("注入 Ctrl+C 按键失败");
+            return None;
+        }
+    }
+
+    // 没有选中内容时多数程序不会更新剪贴板
+    let deadline = Instant::now() + COPY_TIMEOUT;
+    while unsafe { GetClipboardSequenceNumber() } == sequence {
+        if Instant::now() >= deadline {
+            log::debug!("模拟复制后剪贴板没有变化，没有选中的文本");
+            return None;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // 复制方可能仍占用剪贴板，稍后重试
+    let copied = (0..3).find_map(|attempt| {
+        if attempt > 0 {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        clipboard.get_text().ok()
+    });
+    if let Some(previous) = previous {
+        if let Err(e) = clipboard.set_content(&previous) {
+            log::warn!("恢复剪贴板内容失败: {}", e);
+        }
+    }
+    ignore_clipboard_for(CLIPBOARD_RESTORE_GRACE);
+
+    copied.map(|text| text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+/// 在接下来的 `duration` 内忽略剪贴板变化
+fn ignore_clipboard_for(duration: Duration) {
+    if let Ok(mut until) = CLIPBOARD_IGNORED_UNTIL.lock() {
+        *until = Some(Instant::now() + duration);
+    }
+}
+
+/// 当前是否忽略剪贴板变化
+fn clipboard_ignored() -> bool {
+    CLIPBOARD_IGNORED_UNTIL
+        .lock()
+        .ok()
+        .and_then(|until| *until)
+        .is_some_and(|until| Instant::now() < until)
+}
+
+/// 隐藏启动器窗口 `launcher`
+///
+/// 启动器仍在前台时先将焦点还给 `previous` 窗口，避免焦点停留在隐藏的窗口上；
+/// 执行结果后焦点已交给新打开的窗口时保持不变
+pub fn hide_launcher(launcher: isize, previous: Option<isize>) {
+    unsafe {
+        let launcher = HWND(launcher as *mut _);
+        let previous = previous.map(|hwnd| HWND(hwnd as *mut _));
+        if GetForegroundWindow() == launcher {
+            if let Some(previous) = previous.filter(|hwnd| IsWindow(*hwnd).as_bool()) {
+                if !SetForegroundWindow(previous).as_bool() {
+                    log::warn!("无法将焦点还给之前的窗口");
+                }
+            }
+        }
+        let _ = ShowWindow(launcher, SW_HIDE);
+    }
+}
+
+/// 隐藏启动器，将焦点还给 `target` 窗口并发送 Ctrl+V 粘贴
+///
+/// 只有前台进程有权切换前台窗口，因此先激活目标窗口再隐藏启动器
+pub fn paste_into(target: isize) -> Result<()> {
+    unsafe {
+        let target = HWND(target as *mut _);
+        if !IsWindow(target).as_bool() {
+            return Err(WerunError::Paste("目标窗口已关闭".to_string()));
+        }
+
+        let launcher = is_own_foreground().then(|| GetForegroundWindow());
+        if !SetForegroundWindow(target).as_bool() {
+            return Err(WerunError::Paste("无法激活目标窗口".to_string()));
+        }
+        if let Some(launcher) = launcher {
+            let _ = ShowWindow(launcher, SW_HIDE);
+        }
+    }
+
+    std::thread::spawn(|| {
+        std::thread::sleep(PASTE_DELAY);
+        let inputs = [
+            key_input(VK_CONTROL, false),
+            key_input(VK_V, false),
+            key_input(VK_V, true),
+            key_input(VK_CONTROL, true),
+        ];
+        unsafe {
+            if SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) as usize != inputs.len() {
+                log::warn!("注入 Ctrl+V 按键失败");
+            }
+        }
+    });
+    Ok(())
+}
+
+/// 构造键盘输入事件
+fn key_input(key: VIRTUAL_KEY, up: bool) -> INPUT {
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: key,
+                dwFlags: if up { KEYEVENTF_KEYUP } else { KEYBD_EVENT_FLAGS(0) },
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// 构造 Unicode 字符输入事件
+fn unicode_input(unit: u16, up: bool) -> INPUT {
+    let flags = if up { KEYEVENTF_UNICODE | KEYEVENTF_KEYUP } else { KEYEVENTF_UNICODE };
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: VIRTUAL_KEY(0),
+                wScan: unit,
+                dwFlags: flags,
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// 前台窗口是否属于启动器进程
+fn is_own_foreground() -> bool {
+    unsafe {
+        let mut process_id = 0;
+        GetWindowThreadProcessId(GetForegroundWindow(), Some(&mut process_id as *mut u32));
+        process_id == GetCurrentProcessId()
+    }
+}
+
 impl Drop for GlobalHotkeyManager {
     fn drop(&mut self) {
         let _ = self.unregister();
@@ -1,15 +1,40 @@
 use std::{
+    collections::{BTreeSet, HashSet},
+    path::{Path, PathBuf},
     process::Command,
-    sync::{Arc, Mutex},
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 
 use anyhow::Result;
+use notify::{event::ModifyKind, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
 
 /// 应用启动插件
 ///
-/// 扫描并启动 Windows 应用程序
-use crate::core::plugin::Plugin;
-use crate::core::search::{ActionData, ResultType, SearchResult};
+/// 扫描并启动 Windows 应用程序；常用应用可以配置多个启动配置（参数、工作目录、
+/// 管理员权限、窗口状态），在动作面板中选择
+use crate::core::plugin::{Plugin, PluginIcon};
+use crate::{
+    core::{
+        config_manager::global_config,
+        history::global_history,
+        preview::{Preview, PreviewRow},
+        progress::{global_progress, ProgressTask},
+        scheduler::global_scheduler,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    platform::jump_list,
+    utils::{
+        elevation,
+        fuzzy::{word_prefix_match, FuzzyMatcher},
+        registry::{self, RegistryRoot},
+        shell_link,
+    },
+};
 
 /// 应用信息
 #[derive(Clone, Debug)]
@@ -22,6 +47,141 @@ pub struct AppInfo {
     pub description: String,
     /// 图标路径
     pub icon: Option<String>,
+    /// 对应的已安装程序（来自注册表卸载信息）
+    pub program: Option<InstalledProgram>,
+}
+
+/// 已安装程序
+///
+/// 来自注册表中的卸载信息，用于应用结果的卸载、打开安装目录等动作
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstalledProgram {
+    /// 显示名称
+    pub name: String,
+    /// 卸载命令行
+    pub uninstall_command: Option<String>,
+    /// 安装目录
+    pub install_location: Option<String>,
+    /// 版本
+    pub version: Option<String>,
+    /// 发布者
+    pub publisher: Option<String>,
+}
+
+/// 应用商店应用的启动路径前缀，后接 AUMID
+const APPS_FOLDER_PREFIX: &str = "shell:AppsFolder\\";
+
+/// 注册表中登记应用路径的键（HKCU 与 HKLM 下各一份）
+const APP_PATHS_KEY: &str = "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\App Paths";
+
+/// PATHEXT 未设置时的可执行文件扩展名
+const DEFAULT_PATHEXT: &str = ".COM;.EXE;.BAT;.CMD";
+
+/// 登记已安装程序的卸载信息键（HKCU 与 HKLM 下各一份，另有 32 位程序的视图）
+const UNINSTALL_KEYS: [&str; 2] = [
+    "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+];
+
+/// 开始菜单变更后等待再重新扫描的时间，合并安装程序连续创建的快捷方式
+const RESCAN_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// 卸载信息的轮询间隔
+const UNINSTALL_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 手动重新扫描命令的结果 ID
+const RESCAN_RESULT_ID: &str = "app_launcher:__rescan__";
+
+/// 首页结果的最高分数
+const HOME_SCORE: u32 = 1000;
+
+/// 查找首页常用应用时读取的历史记录数量
+const MAX_HOME_HISTORY: usize = 200;
+
+/// 卸载动作数据的前缀，后接卸载命令行
+const UNINSTALL_PREFIX: &str = "uninstall:";
+
+/// 固定动作数据的前缀，后接应用路径
+const PIN_PREFIX: &str = "pin:";
+
+/// 取消固定动作数据的前缀，后接应用路径
+const UNPIN_PREFIX: &str = "unpin:";
+
+/// 手动重新扫描命令的搜索关键词
+const RESCAN_KEYWORDS: &[&str] = &["重新扫描应用", "rescan applications"];
+
+/// 按启动配置启动动作数据的前缀，后接 [`ProfileLaunch`] 的 JSON
+const PROFILE_PREFIX: &str = "profile:";
+
+/// 启动后的窗口状态
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowPlacement {
+    /// 由程序决定
+    #[default]
+    Normal,
+    /// 最大化
+    Maximized,
+    /// 最小化
+    Minimized,
+}
+
+/// 应用的启动配置，如 "Chrome – 工作"、"Terminal – werun"
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchProfile {
+    /// 应用名称或路径（不区分大小写）
+    pub app: String,
+    /// 配置名称
+    pub name: String,
+    /// 启动参数
+    pub args: Vec<String>,
+    /// 工作目录
+    pub working_dir: Option<String>,
+    /// 是否以管理员身份运行
+    pub elevated: bool,
+    /// 窗口状态，以管理员身份运行时由程序决定
+    pub window: WindowPlacement,
+}
+
+impl LaunchProfile {
+    /// 是否属于该应用：按应用名称、完整路径或文件名匹配
+    fn applies_to(&self, app: &AppInfo) -> bool {
+        let target = self.app.trim();
+        let file_name = app.path.rsplit(['\\', '/']).next().unwrap_or_default();
+        !target.is_empty()
+            && (target.eq_ignore_ascii_case(&app.name)
+                || target.eq_ignore_ascii_case(&app.path)
+                || target.eq_ignore_ascii_case(file_name))
+    }
+}
+
+/// 按启动配置启动的动作数据
+#[derive(Serialize, Deserialize)]
+struct ProfileLaunch {
+    /// 应用路径
+    path: String,
+    /// 启动配置
+    profile: LaunchProfile,
+}
+
+/// 插件配置（`[plugins.settings.app_launcher]`）
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct AppLauncherSettings {
+    /// 应用的启动配置
+    profiles: Vec<LaunchProfile>,
+}
+
+/// `Get-StartApps` 输出的开始菜单应用
+#[derive(Deserialize)]
+struct StartApp {
+    /// 显示名称
+    #[serde(rename = "Name")]
+    name: String,
+    /// 应用 ID（打包应用为 AUMID，形如 `PackageFamilyName!AppId`）
+    #[serde(rename = "AppID")]
+    app_id: String,
 }
 
 /// 应用启动插件
@@ -30,48 +190,425 @@ pub struct AppLauncherPlugin {
     enabled: bool,
     /// 已索引的应用列表
     apps: Arc<Mutex<Vec<AppInfo>>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+    /// 开始菜单监视器（保持存活以持续接收变更）
+    watcher: Mutex<Option<RecommendedWatcher>>,
+    /// 插件配置
+    settings: AppLauncherSettings,
 }
 
 impl AppLauncherPlugin {
     /// 创建新的应用启动插件
     pub fn new() -> Self {
-        Self { enabled: true, apps: Arc::new(Mutex::new(Vec::new())) }
+        Self {
+            enabled: true,
+            apps: Arc::new(Mutex::new(Vec::new())),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+            watcher: Mutex::new(None),
+            settings: AppLauncherSettings::default(),
+        }
     }
 
-    /// 扫描开始菜单中的应用
-    fn scan_start_menu(&self) -> Result<Vec<AppInfo>> {
-        let mut apps = Vec::new();
+    /// 在后台线程扫描开始菜单、应用商店、App Paths 和 PATH，完成后替换应用列表
+    ///
+    /// 扫描进行中再次调用时取消旧的扫描
+    fn start_background_scan(apps: Arc<Mutex<Vec<AppInfo>>>) {
+        let task = global_progress().start("app_launcher", "正在索引应用");
+
+        // 以上次索引的应用数作为预计总数
+        if let Ok(guard) = apps.lock() {
+            task.set_total(guard.len());
+        }
+
+        std::thread::spawn(move || {
+            let mut scanned = Self::scan_start_menu(&task);
+            if !task.is_cancelled() {
+                scanned.extend(Self::scan_packaged_apps(&task));
+            }
+            // 命令行程序与已有应用同名时不重复添加
+            if !task.is_cancelled() {
+                Self::extend_unique(&mut scanned, Self::scan_app_paths(&task));
+            }
+            if !task.is_cancelled() {
+                Self::extend_unique(&mut scanned, Self::scan_path_executables(&task));
+            }
+            if !task.is_cancelled() {
+                Self::attach_programs(&mut scanned, &Self::scan_installed_programs());
+            }
+            task.finish();
+
+            if task.is_cancelled() {
+                log::info!("应用索引已取消: 已扫描 {} 个应用", scanned.len());
+                return;
+            }
+
+            if let Ok(mut guard) = apps.lock() {
+                *guard = scanned;
+                log::info!("已索引 {} 个应用", guard.len());
+            }
+        });
+    }
+
+    /// 监视开始菜单目录和卸载信息，应用安装或卸载后自动重新扫描
+    ///
+    /// 卸载信息分散在多个注册表键下，定期对比子键快照即可发现变化，无需逐个键注册变更通知；
+    /// 监视器被替换或释放时后台线程随之退出
+    fn start_watcher(&self) -> Result<()> {
+        let (tx, rx) = mpsc::channel();
+
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+                Ok(event) => {
+                    if matches!(
+                        event.kind,
+                        EventKind::Create(_)
+                            | EventKind::Remove(_)
+                            | EventKind::Modify(ModifyKind::Name(_))
+                    ) {
+                        let _ = tx.send(());
+                    }
+                },
+                Err(e) => log::warn!("开始菜单监视错误: {:?}", e),
+            })?;
+
+        for path in Self::start_menu_paths().iter().filter(|path| path.exists()) {
+            if let Err(e) = watcher.watch(path, RecursiveMode::Recursive) {
+                log::warn!("监视目录 {:?} 失败: {:?}", path, e);
+            }
+        }
+
+        *self.watcher.lock().unwrap() = Some(watcher);
+
+        let apps = self.apps.clone();
+        std::thread::spawn(move || Self::watch_changes(&rx, apps));
+
+        Ok(())
+    }
+
+    /// 等待开始菜单变更或卸载信息变化，触发重新扫描
+    fn watch_changes(rx: &Receiver<()>, apps: Arc<Mutex<Vec<AppInfo>>>) {
+        let mut snapshot = Self::uninstall_snapshot();
+        let mut pending: Option<Instant> = None;
+
+        loop {
+            let timeout = pending.map_or(UNINSTALL_POLL_INTERVAL, |deadline| {
+                deadline.saturating_duration_since(Instant::now())
+            });
+
+            match rx.recv_timeout(timeout) {
+                Ok(()) => {
+                    // 每次变更都推迟扫描，直到开始菜单安静下来
+                    pending = Some(Instant::now() + RESCAN_DEBOUNCE);
+                    continue;
+                },
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {},
+            }
+
+            let current = Self::uninstall_snapshot();
+            if pending.take().is_some() {
+                log::info!("开始菜单已变更，重新扫描应用");
+            } else if current != snapshot {
+                log::info!("已安装程序已变更，重新扫描应用");
+            } else {
+                continue;
+            }
+
+            snapshot = current;
+            let apps = apps.clone();
+            global_scheduler()
+                .run_or_defer("app_rescan", move || Self::start_background_scan(apps));
+        }
+
+        log::info!("应用监视已停止");
+    }
+
+    /// 卸载信息的子键快照，用于检测程序的安装和卸载
+    fn uninstall_snapshot() -> BTreeSet<(RegistryRoot, &'static str, String)> {
+        RegistryRoot::ALL
+            .into_iter()
+            .flat_map(|root| UNINSTALL_KEYS.into_iter().map(move |key| (root, key)))
+            .flat_map(|(root, key)| {
+                registry::subkeys(root, key).into_iter().map(move |name| (root, key, name))
+            })
+            .collect()
+    }
+
+    /// 读取注册表卸载信息中的已安装程序，跳过没有显示名称的条目（通常是更新补丁）
+    fn scan_installed_programs() -> Vec<InstalledProgram> {
+        let mut programs = Vec::new();
+
+        for (root, key, name) in Self::uninstall_snapshot() {
+            let path = format!("{}\\{}", key, name);
+            let value = |name: &str| registry::string_value(root, &path, Some(name));
+            let Some(display_name) = value("DisplayName") else {
+                continue;
+            };
+
+            // 没有登记安装目录时退而使用图标所在的目录
+            let install_location = value("InstallLocation")
+                .map(|location| location.trim().trim_matches('"').to_string())
+                .or_else(|| value("DisplayIcon").and_then(|icon| Self::icon_directory(&icon)));
+
+            programs.push(InstalledProgram {
+                name: display_name.trim().to_string(),
+                uninstall_command: value("UninstallString"),
+                install_location,
+                version: value("DisplayVersion"),
+                publisher: value("Publisher"),
+            });
+        }
+
+        log::info!("已读取 {} 个已安装程序", programs.len());
+        programs
+    }
+
+    /// DisplayIcon（形如 `"C:\\App\\app.exe",0`）所在的目录
+    fn icon_directory(icon: &str) -> Option<String> {
+        let path = icon.rsplit_once(',').map_or(icon, |(path, _)| path).trim().trim_matches('"');
+        let dir = Path::new(path).parent()?.to_string_lossy().to_string();
+        (!dir.is_empty()).then_some(dir)
+    }
+
+    /// 为应用关联已安装程序
+    ///
+    /// 优先按目标路径位于安装目录下匹配（取最长的安装目录），其次按名称匹配；
+    /// 快捷方式按其目标路径匹配，应用商店应用不在卸载信息中
+    fn attach_programs(apps: &mut [AppInfo], programs: &[InstalledProgram]) {
+        for app in apps.iter_mut().filter(|app| !app.path.starts_with(APPS_FOLDER_PREFIX)) {
+            let target = if app.path.to_lowercase().ends_with(".lnk") {
+                shell_link::read_target(Path::new(&app.path))
+            } else {
+                Some(app.path.clone())
+            };
+            let target = target.map(|t| t.to_lowercase().replace('/', "\\"));
+
+            let by_location = target.as_deref().and_then(|target| {
+                programs
+                    .iter()
+                    .filter_map(|program| {
+                        let location = program.install_location.as_deref()?;
+                        let location = location.to_lowercase().replace('/', "\\");
+                        let location = location.trim_end_matches('\\');
+                        let inside = !location.is_empty()
+                            && target.strip_prefix(location).is_some_and(|r| r.starts_with('\\'));
+                        inside.then_some((location.len(), program))
+                    })
+                    .max_by_key(|(len, _)| *len)
+                    .map(|(_, program)| program)
+            });
+
+            // 程序名称常带版本号或架构后缀，如 "7-Zip 23.01 (x64)"
+            let name = app.name.to_lowercase();
+            let by_name = || {
+                programs.iter().find(|program| {
+                    let program_name = program.name.to_lowercase();
+                    program_name
+                        .strip_prefix(&name)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+                })
+            };
+            app.program = by_location.or_else(by_name).cloned();
+        }
+    }
+
+    /// 已安装程序的次要动作：卸载、打开安装目录
+    fn program_actions(program: &InstalledProgram) -> Vec<SecondaryAction> {
+        let mut actions = Vec::new();
+
+        if let Some(command) = &program.uninstall_command {
+            actions.push(
+                SecondaryAction::new("卸载", ActionData::Custom {
+                    plugin: "app_launcher".to_string(),
+                    data: format!("{}{}", UNINSTALL_PREFIX, command),
+                })
+                .with_destructive(true),
+            );
+        }
+        if let Some(location) = &program.install_location {
+            actions.push(SecondaryAction::new("打开安装目录", ActionData::OpenFile {
+                path: location.clone(),
+            }));
+        }
+
+        actions
+    }
+
+    /// 已安装程序的预览：名称、版本、发布者和安装目录
+    fn program_preview(program: &InstalledProgram) -> Preview {
+        let rows = [
+            ("名称", Some(&program.name)),
+            ("版本", program.version.as_ref()),
+            ("发布者", program.publisher.as_ref()),
+            ("安装目录", program.install_location.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| Some(PreviewRow::new(label, value?.clone())))
+        .collect();
+
+        Preview::new().section("程序信息", rows)
+    }
+
+    /// 拆分命令行为程序和参数
+    ///
+    /// 程序路径可能带引号；未加引号的路径可能含空格，在第一个 `.exe` 之后分割
+    fn split_command_line(command: &str) -> (String, String) {
+        let command = command.trim();
+
+        if let Some((program, args)) =
+            command.strip_prefix('"').and_then(|rest| rest.split_once('"'))
+        {
+            return (program.to_string(), args.trim().to_string());
+        }
+
+        let split = command
+            .to_lowercase()
+            .find(".exe")
+            .map(|pos| pos + ".exe".len())
+            .or_else(|| command.find(' '))
+            .unwrap_or(command.len());
+        (command[..split].to_string(), command[split..].trim().to_string())
+    }
+
+    /// 运行卸载命令
+    ///
+    /// 卸载程序通常需要管理员权限，统一以管理员身份运行
+    fn uninstall(&self, command: &str) -> Result<()> {
+        let (program, args) = Self::split_command_line(command);
+        log::info!("卸载程序: {} {}", program, args);
+        elevation::run_elevated(&program, &args, None)?;
+        Ok(())
+    }
+
+    /// 应用的启动配置动作，标题为 "应用 – 配置名称"
+    fn profile_actions(&self, app: &AppInfo) -> Vec<SecondaryAction> {
+        self.settings
+            .profiles
+            .iter()
+            .filter(|profile| profile.applies_to(app))
+            .filter_map(|profile| {
+                let launch = ProfileLaunch { path: app.path.clone(), profile: profile.clone() };
+                let data = serde_json::to_string(&launch).ok()?;
+                Some(SecondaryAction::new(
+                    format!("{} – {}", app.name, profile.name),
+                    ActionData::Custom {
+                        plugin: "app_launcher".to_string(),
+                        data: format!("{}{}", PROFILE_PREFIX, data),
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// 应用的搜索结果，次要动作包括启动配置、固定/取消固定和已安装程序的动作
+    fn app_result(&self, app: &AppInfo, score: u32, pinned: bool) -> SearchResult {
+        let pin_action = if pinned {
+            SecondaryAction::new("取消固定", ActionData::Custom {
+                plugin: "app_launcher".to_string(),
+                data: format!("{}{}", UNPIN_PREFIX, app.path),
+            })
+        } else {
+            SecondaryAction::new("固定到首页", ActionData::Custom {
+                plugin: "app_launcher".to_string(),
+                data: format!("{}{}", PIN_PREFIX, app.path),
+            })
+        };
+
+        let mut secondary_actions = self.profile_actions(app);
+        secondary_actions.push(pin_action);
+        secondary_actions
+            .extend(app.program.as_ref().map(Self::program_actions).unwrap_or_default());
+
+        SearchResult::new(
+            format!("app_launcher:{}", app.path),
+            app.name.clone(),
+            app.description.clone(),
+            ResultType::Application,
+            score,
+            ActionData::LaunchApp { path: app.path.clone(), args: Vec::new(), elevated: false },
+        )
+        .with_icon(app.icon.clone())
+        .with_secondary_actions(secondary_actions)
+        .with_preview(app.program.as_ref().map(Self::program_preview).unwrap_or_default())
+    }
+
+    /// 查询为空时的默认结果：固定的应用按配置顺序在前，其后是最常用的应用
+    ///
+    /// 已卸载或尚未扫描到的应用不显示；分数按显示顺序递减，使排序后顺序不变
+    fn home_results(&self, limit: usize) -> Vec<SearchResult> {
+        let apps = self.apps.lock().unwrap();
+        let find = |path: &str| apps.iter().find(|app| app.path == path);
+        let pinned = global_config().pinned_apps();
+
+        let recent: Vec<String> = global_history()
+            .top_entries(MAX_HOME_HISTORY)
+            .into_iter()
+            .filter(|entry| entry.plugin_id == self.id())
+            .filter_map(|entry| entry.result_id.strip_prefix("app_launcher:").map(String::from))
+            .filter(|path| !pinned.contains(path))
+            .filter(|path| find(path).is_some())
+            .take(global_config().home_recent_apps())
+            .collect();
 
-        // 获取开始菜单路径
-        let start_menu_paths = [
+        let pinned_apps = pinned.iter().filter_map(|path| find(path)).map(|app| (app, true));
+        let recent_apps = recent.iter().filter_map(|path| find(path)).map(|app| (app, false));
+
+        pinned_apps
+            .chain(recent_apps)
+            .take(limit)
+            .enumerate()
+            .map(|(index, (app, pinned))| {
+                let mut result = self.app_result(app, HOME_SCORE - index as u32, pinned);
+                if pinned {
+                    result.description = format!("已固定 · {}", result.description);
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// 开始菜单程序目录（当前用户和所有用户）
+    fn start_menu_paths() -> [PathBuf; 2] {
+        [
             dirs::data_dir()
                 .map(|p| p.join("Microsoft\\Windows\\Start Menu\\Programs"))
                 .unwrap_or_default(),
-            std::path::PathBuf::from("C:\\ProgramData\\Microsoft\\Windows\\Start Menu\\Programs"),
-        ];
+            PathBuf::from("C:\\ProgramData\\Microsoft\\Windows\\Start Menu\\Programs"),
+        ]
+    }
 
-        for path in &start_menu_paths {
+    /// 扫描开始菜单中的应用
+    fn scan_start_menu(task: &ProgressTask) -> Vec<AppInfo> {
+        let mut apps = Vec::new();
+
+        for path in &Self::start_menu_paths() {
             if path.exists() {
-                self.scan_directory(path, &mut apps)?;
+                Self::scan_directory(path, &mut apps, task);
             }
         }
 
-        Ok(apps)
+        apps
     }
 
     /// 递归扫描目录
-    fn scan_directory(&self, path: &std::path::Path, apps: &mut Vec<AppInfo>) -> Result<()> {
+    fn scan_directory(path: &std::path::Path, apps: &mut Vec<AppInfo>, task: &ProgressTask) {
+        if task.is_cancelled() {
+            return;
+        }
+
         if let Ok(entries) = std::fs::read_dir(path) {
             for entry in entries.flatten() {
                 let path = entry.path();
 
                 if path.is_dir() {
                     // 递归扫描子目录
-                    let _ = self.scan_directory(&path, apps);
+                    Self::scan_directory(&path, apps, task);
                 } else if path.extension().map(|e| e == "lnk").unwrap_or(false) {
                     // 解析快捷方式
-                    if let Some(app) = self.parse_shortcut(&path) {
+                    if let Some(app) = Self::parse_shortcut(&path) {
                         apps.push(app);
+                        task.advance(1);
                     }
                 } else if path.extension().map(|e| e == "exe").unwrap_or(false) {
                     // 可执行文件
@@ -85,16 +622,185 @@ impl AppLauncherPlugin {
                         path: path.to_string_lossy().to_string(),
                         description: "应用程序".to_string(),
                         icon: None,
+                        program: None,
                     });
+                    task.advance(1);
                 }
             }
         }
+    }
 
-        Ok(())
+    /// 枚举应用商店（UWP/MSIX）应用
+    ///
+    /// 通过 `Get-StartApps` 读取 AppsFolder 中的应用，只保留带 AUMID 的打包应用，
+    /// 普通桌面应用已由开始菜单扫描覆盖
+    fn scan_packaged_apps(task: &ProgressTask) -> Vec<AppInfo> {
+        let mut cmd = Command::new("powershell");
+        cmd.args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            "Get-StartApps | ConvertTo-Json -Compress",
+        ]);
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        let output = match cmd.output() {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => {
+                log::warn!("枚举应用商店应用失败: {}", String::from_utf8_lossy(&output.stderr));
+                return Vec::new();
+            },
+            Err(e) => {
+                log::warn!("无法运行 Get-StartApps: {}", e);
+                return Vec::new();
+            },
+        };
+
+        let apps = Self::parse_start_apps(&String::from_utf8_lossy(&output.stdout));
+        task.advance(apps.len());
+        log::info!("已枚举 {} 个应用商店应用", apps.len());
+        apps
+    }
+
+    /// 解析 `Get-StartApps` 的 JSON 输出
+    ///
+    /// 只有一个应用时 `ConvertTo-Json` 输出单个对象而不是数组
+    fn parse_start_apps(json: &str) -> Vec<AppInfo> {
+        let start_apps: Vec<StartApp> = match serde_json::from_str::<serde_json::Value>(json) {
+            Ok(value @ serde_json::Value::Array(_)) => {
+                serde_json::from_value(value).unwrap_or_default()
+            },
+            Ok(value) => serde_json::from_value(value).map(|app| vec![app]).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+
+        start_apps
+            .into_iter()
+            .filter(|app| app.app_id.contains('!'))
+            .map(|app| AppInfo {
+                name: app.name,
+                path: format!("{}{}", APPS_FOLDER_PREFIX, app.app_id),
+                description: "应用商店应用".to_string(),
+                icon: None,
+                program: None,
+            })
+            .collect()
+    }
+
+    /// 读取注册表 App Paths 中登记的程序
+    ///
+    /// 子键名为程序文件名（如 `chrome.exe`），默认值为完整路径
+    fn scan_app_paths(task: &ProgressTask) -> Vec<AppInfo> {
+        let mut apps = Vec::new();
+
+        for root in RegistryRoot::ALL {
+            for name in registry::subkeys(root, APP_PATHS_KEY) {
+                // 子键的默认值（REG_EXPAND_SZ 会自动展开）
+                let key = format!("{}\\{}", APP_PATHS_KEY, name);
+                if let Some(app) =
+                    registry::string_value(root, &key, None).and_then(|p| Self::app_path_entry(&p))
+                {
+                    apps.push(app);
+                    task.advance(1);
+                }
+            }
+        }
+
+        apps
+    }
+
+    /// 由 App Paths 中的路径构造应用信息，路径可能带引号
+    fn app_path_entry(path: &str) -> Option<AppInfo> {
+        let path = path.trim().trim_matches('"');
+        let name = Path::new(path).file_stem()?.to_string_lossy().to_string();
+
+        Some(AppInfo {
+            name,
+            path: path.to_string(),
+            description: format!("App Paths · {}", path),
+            icon: None,
+            program: None,
+        })
+    }
+
+    /// 扫描 PATH 目录中的可执行文件
+    fn scan_path_executables(task: &ProgressTask) -> Vec<AppInfo> {
+        let Some(path_var) = std::env::var_os("PATH") else {
+            return Vec::new();
+        };
+        let extensions = Self::executable_extensions(
+            &std::env::var("PATHEXT").unwrap_or_else(|_| DEFAULT_PATHEXT.to_string()),
+        );
+
+        let mut seen_dirs = HashSet::new();
+        let mut apps = Vec::new();
+
+        for dir in std::env::split_paths(&path_var) {
+            if task.is_cancelled() {
+                break;
+            }
+            if !seen_dirs.insert(dir.to_string_lossy().to_lowercase()) {
+                continue;
+            }
+
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+
+            // 不检查是否为普通文件：WindowsApps 中的应用执行别名（如 wt.exe）是重解析点
+            for entry in entries.flatten() {
+                if let Some(app) = Self::path_command(&entry.path(), &extensions) {
+                    apps.push(app);
+                    task.advance(1);
+                }
+            }
+        }
+
+        apps
+    }
+
+    /// 解析 PATHEXT，返回小写且不带点的扩展名
+    fn executable_extensions(pathext: &str) -> Vec<String> {
+        pathext
+            .split(';')
+            .map(|ext| ext.trim().trim_start_matches('.').to_lowercase())
+            .filter(|ext| !ext.is_empty())
+            .collect()
+    }
+
+    /// 扩展名属于可执行类型时构造命令行程序信息
+    fn path_command(path: &Path, extensions: &[String]) -> Option<AppInfo> {
+        let extension = path.extension()?.to_string_lossy().to_lowercase();
+        if !extensions.contains(&extension) {
+            return None;
+        }
+
+        let name = path.file_stem()?.to_string_lossy().to_string();
+        let dir = path.parent().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+
+        Some(AppInfo {
+            name,
+            path: path.to_string_lossy().to_string(),
+            description: format!("命令行程序 · {}", dir),
+            icon: None,
+            program: None,
+        })
+    }
+
+    /// 追加应用，跳过与已有应用同名（忽略大小写）的条目
+    fn extend_unique(apps: &mut Vec<AppInfo>, new_apps: Vec<AppInfo>) {
+        let mut names: HashSet<String> = apps.iter().map(|app| app.name.to_lowercase()).collect();
+        apps.extend(new_apps.into_iter().filter(|app| names.insert(app.name.to_lowercase())));
     }
 
     /// 解析快捷方式文件
-    fn parse_shortcut(&self, path: &std::path::Path) -> Option<AppInfo> {
+    fn parse_shortcut(path: &std::path::Path) -> Option<AppInfo> {
         // TODO: 使用 lnk crate 解析快捷方式
         // 目前简化处理，仅提取文件名
         let name = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
@@ -104,9 +810,45 @@ impl AppLauncherPlugin {
             path: path.to_string_lossy().to_string(),
             description: "快捷方式".to_string(),
             icon: None,
+            program: None,
         })
     }
 
+    /// 按启动配置启动应用
+    fn launch_profile(&self, launch: &ProfileLaunch) -> Result<()> {
+        let ProfileLaunch { path, profile } = launch;
+        log::info!("按启动配置启动: {} ({})", path, profile.name);
+
+        if profile.elevated {
+            let args = elevation::join_args(&profile.args);
+            elevation::run_elevated(path, &args, profile.working_dir.as_deref())?;
+            return Ok(());
+        }
+
+        // 应用商店应用不接受参数，只能通过 AppsFolder 启动
+        if path.starts_with(APPS_FOLDER_PREFIX) {
+            return self.launch_app(path);
+        }
+
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/c", "start", ""]);
+        if let Some(dir) = &profile.working_dir {
+            cmd.args(["/D", dir]);
+        }
+        match profile.window {
+            WindowPlacement::Normal => {},
+            WindowPlacement::Maximized => {
+                cmd.arg("/MAX");
+            },
+            WindowPlacement::Minimized => {
+                cmd.arg("/MIN");
+            },
+        }
+        cmd.arg(path).args(&profile.args).spawn()?;
+
+        Ok(())
+    }
+
     /// 启动应用
     fn launch_app(&self, path: &str) -> Result<()> {
         // 解析快捷方式获取实际目标
@@ -117,6 +859,12 @@ impl AppLauncherPlugin {
             path.to_string()
         };
 
+        // 应用商店应用通过 AppsFolder 按 AUMID 启动
+        if target_path.starts_with(APPS_FOLDER_PREFIX) {
+            Command::new("explorer").arg(&target_path).spawn()?;
+            return Ok(());
+        }
+
         // 启动应用
         Command::new("cmd").args(["/c", "start", "", &target_path]).spawn()?;
 
@@ -141,6 +889,10 @@ impl Plugin for AppLauncherPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("app-window")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -151,14 +903,15 @@ impl Plugin for AppLauncherPlugin {
 
     fn initialize(&mut self) -> Result<()> {
         log::info!("初始化应用启动插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
 
-        // 扫描应用
-        let apps = self.scan_start_menu()?;
+        // 后台扫描应用，期间沿用已有的应用列表
+        Self::start_background_scan(self.apps.clone());
 
-        // 存储应用列表
-        if let Ok(mut guard) = self.apps.lock() {
-            *guard = apps;
-            log::info!("已索引 {} 个应用", guard.len());
+        // 监视失败时仍可通过手动重新扫描更新
+        if let Err(e) = self.start_watcher() {
+            log::warn!("无法监视应用变更: {:?}", e);
         }
 
         Ok(())
@@ -166,35 +919,70 @@ impl Plugin for AppLauncherPlugin {
 
     fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
         let apps = self.apps.lock().unwrap();
+        let pinned = global_config().pinned_apps();
         let mut results = Vec::new();
 
         for app in apps.iter() {
-            // 简单的模糊匹配
-            if app.name.to_lowercase().contains(&query.to_lowercase()) {
-                results.push(
-                    SearchResult::new(
-                        format!("app_launcher:{}", app.path),
-                        app.name.clone(),
-                        app.description.clone(),
-                        ResultType::Application,
-                        100, // TODO: 实现更好的评分算法
-                        ActionData::LaunchApp { path: app.path.clone(), args: Vec::new() },
-                    )
-                    .with_icon(app.icon.clone()),
-                );
-
-                if results.len() >= limit {
-                    break;
-                }
+            // 模糊匹配，单词边界命中的应用排在前面
+            let (matched, score) = self.matcher.fuzzy_match(query, &app.name);
+            if matched {
+                results.push(self.app_result(app, score, pinned.contains(&app.path)));
             }
         }
 
+        // 按匹配分数排序后截断
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+
+        // 手动重新扫描命令
+        if RESCAN_KEYWORDS.iter().any(|keyword| word_prefix_match(query, keyword)) {
+            results.push(SearchResult::new(
+                RESCAN_RESULT_ID.to_string(),
+                "重新扫描应用".to_string(),
+                "重新索引开始菜单、应用商店、App Paths 和 PATH 中的程序".to_string(),
+                ResultType::SystemCommand,
+                90,
+                ActionData::Custom { plugin: self.id().to_string(), data: "rescan".to_string() },
+            ));
+        }
+
         Ok(results)
     }
 
+    fn default_results(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.home_results(limit))
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        self.apps.lock().unwrap().iter().map(|app| app.name.clone()).collect()
+    }
+
     fn execute(&self, result: &SearchResult) -> Result<()> {
-        if let ActionData::LaunchApp { path, .. } = &result.action {
-            self.launch_app(path)?;
+        match &result.action {
+            ActionData::LaunchApp { path, .. } => self.launch_app(path)?,
+            ActionData::Custom { data, .. } if data == "rescan" => {
+                log::info!("手动重新扫描应用");
+                Self::start_background_scan(self.apps.clone());
+            },
+            ActionData::Custom { data, .. } => {
+                if let Some(command) = data.strip_prefix(UNINSTALL_PREFIX) {
+                    self.uninstall(command)?;
+                } else if let Some(launch) = data.strip_prefix(PROFILE_PREFIX) {
+                    self.launch_profile(&serde_json::from_str(launch)?)?;
+                } else if let Some(path) = data.strip_prefix(PIN_PREFIX) {
+                    log::info!("固定应用: {}", path);
+                    global_config().set_app_pinned(path, true)?;
+                    jump_list::refresh();
+                } else if let Some(path) = data.strip_prefix(UNPIN_PREFIX) {
+                    log::info!("取消固定应用: {}", path);
+                    global_config().set_app_pinned(path, false)?;
+                    jump_list::refresh();
+                }
+            },
+            ActionData::OpenFile { path } => {
+                Command::new("explorer").arg(path).spawn()?;
+            },
+            _ => {},
         }
         Ok(())
     }
@@ -209,3 +997,126 @@ impl Default for AppLauncherPlugin {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_start_apps() {
+        let json = r#"[
+            {"Name":"Terminal","AppID":"Microsoft.WindowsTerminal_8wekyb3d8bbwe!App"},
+            {"Name":"记事本","AppID":"{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\notepad.exe"}
+        ]"#;
+        let apps = AppLauncherPlugin::parse_start_apps(json);
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].name, "Terminal");
+        assert_eq!(apps[0].path, "shell:AppsFolder\\Microsoft.WindowsTerminal_8wekyb3d8bbwe!App");
+
+        // 单个应用时输出为对象
+        let single =
+            r#"{"Name":"Calculator","AppID":"Microsoft.WindowsCalculator_8wekyb3d8bbwe!App"}"#;
+        assert_eq!(AppLauncherPlugin::parse_start_apps(single).len(), 1);
+        assert!(AppLauncherPlugin::parse_start_apps("").is_empty());
+    }
+
+    #[test]
+    fn test_path_commands_and_dedup() {
+        let extensions = AppLauncherPlugin::executable_extensions(".COM;.EXE; .Cmd;;");
+        assert_eq!(extensions, ["com", "exe", "cmd"]);
+
+        let pwsh = AppLauncherPlugin::path_command(Path::new("C:/pwsh/pwsh.EXE"), &extensions);
+        assert_eq!(pwsh.as_ref().map(|app| app.name.as_str()), Some("pwsh"));
+        assert!(AppLauncherPlugin::path_command(Path::new("C:/readme.txt"), &extensions).is_none());
+
+        // App Paths 中的路径可能带引号
+        let chrome = AppLauncherPlugin::app_path_entry("\"C:/Chrome/chrome.exe\"").unwrap();
+        assert_eq!(chrome.name, "chrome");
+        assert_eq!(chrome.path, "C:/Chrome/chrome.exe");
+
+        let mut apps = vec![chrome.clone()];
+        AppLauncherPlugin::extend_unique(&mut apps, vec![chrome].into_iter().chain(pwsh).collect());
+        assert_eq!(apps.len(), 2);
+    }
+
+    #[test]
+    fn test_launch_profiles() {
+        let settings: AppLauncherSettings = serde_json::from_value(serde_json::json!({
+            "profiles": [
+                { "app": "Google Chrome", "name": "工作", "args": ["--profile-directory=Work"] },
+                {
+                    "app": "wt.exe",
+                    "name": "werun",
+                    "working_dir": "D:/werun",
+                    "window": "maximized",
+                },
+            ]
+        }))
+        .unwrap();
+        assert_eq!(settings.profiles[0].window, WindowPlacement::Normal);
+        assert_eq!(settings.profiles[1].window, WindowPlacement::Maximized);
+
+        let app = |name: &str, path: &str| AppInfo {
+            name: name.to_string(),
+            path: path.to_string(),
+            description: String::new(),
+            icon: None,
+            program: None,
+        };
+        let chrome = app("google chrome", r"C:\ProgramData\Chrome.lnk");
+        let terminal = app("Terminal", r"C:\Users\me\AppData\Local\Microsoft\WindowsApps\wt.exe");
+        assert!(settings.profiles[0].applies_to(&chrome));
+        assert!(!settings.profiles[0].applies_to(&terminal));
+        assert!(settings.profiles[1].applies_to(&terminal));
+        assert!(!LaunchProfile::default().applies_to(&chrome));
+
+        let plugin = AppLauncherPlugin { settings, ..AppLauncherPlugin::new() };
+        let titles: Vec<_> =
+            plugin.profile_actions(&chrome).into_iter().map(|action| action.title).collect();
+        assert_eq!(titles, ["google chrome – 工作"]);
+    }
+
+    #[test]
+    fn test_uninstall_command_and_programs() {
+        let split = AppLauncherPlugin::split_command_line;
+        assert_eq!(
+            split(r#""C:\Program Files\App\uninst.exe" /S"#),
+            (r"C:\Program Files\App\uninst.exe".to_string(), "/S".to_string())
+        );
+        assert_eq!(
+            split(r"C:\Program Files\7-Zip\Uninstall.exe"),
+            (r"C:\Program Files\7-Zip\Uninstall.exe".to_string(), String::new())
+        );
+        assert_eq!(
+            split("MsiExec.exe /X{1234}"),
+            ("MsiExec.exe".to_string(), "/X{1234}".to_string())
+        );
+
+        let program = |name: &str, location: Option<&str>| InstalledProgram {
+            name: name.to_string(),
+            install_location: location.map(String::from),
+            ..Default::default()
+        };
+        let programs = [
+            program("Tools", Some(r"C:\Tools")),
+            program("Editor", Some(r"C:\Tools\Editor\")),
+            program("7-Zip 23.01 (x64)", None),
+        ];
+        let app = |name: &str, path: &str| AppInfo {
+            name: name.to_string(),
+            path: path.to_string(),
+            description: String::new(),
+            icon: None,
+            program: None,
+        };
+        let mut apps = vec![
+            app("Edit", r"C:\Tools\Editor\edit.exe"),
+            app("7-Zip", r"D:\7z.exe"),
+            app("Tool", r"C:\ToolsX\tool.exe"),
+        ];
+        AppLauncherPlugin::attach_programs(&mut apps, &programs);
+        let names: Vec<_> =
+            apps.iter().map(|app| app.program.as_ref().map(|p| p.name.as_str())).collect();
+        assert_eq!(names, [Some("Editor"), Some("7-Zip 23.01 (x64)"), None]);
+    }
+}
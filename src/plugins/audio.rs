@@ -0,0 +1,281 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// 音频插件
+///
+/// `audio` 列出播放和录制设备，选中后设为默认设备，并可切换默认设备的静音；
+/// `vol 30` 将默认播放设备的音量设为 30%
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    platform::audio::{self, AudioDevice, DeviceKind},
+};
+
+/// 设备列表的查询关键字
+const AUDIO_KEYWORD: &str = "audio";
+
+/// 音量的查询关键字
+const VOLUME_KEYWORD: &str = "vol";
+
+/// 全部关键字
+pub const KEYWORDS: &[&str] = &[AUDIO_KEYWORD, VOLUME_KEYWORD];
+
+/// 音频动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum AudioAction {
+    /// 设为默认设备
+    SetDefault { id: String, name: String },
+    /// 设置默认播放设备的音量
+    SetVolume { volume: u8 },
+    /// 切换默认设备的静音
+    ToggleMute { recording: bool },
+}
+
+/// 解析音量参数（`30` 或 `30%`），超出 0 到 100 时返回 `None`
+pub fn parse_volume(text: &str) -> Option<u8> {
+    let volume: u8 = text.trim().trim_end_matches('%').trim().parse().ok()?;
+    (volume <= 100).then_some(volume)
+}
+
+/// 设备的状态描述，如 "当前播放设备 · 音量 40% · 已静音"
+pub fn device_description(device: &AudioDevice) -> String {
+    let mut description = if device.is_default {
+        format!("当前{}设备 · 音量 {}%", device.kind.label(), device.volume)
+    } else {
+        format!("{}设备 · 按 Enter 设为默认", device.kind.label())
+    };
+    if device.muted {
+        description.push_str(" · 已静音");
+    }
+    description
+}
+
+/// 音频插件
+pub struct AudioPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl AudioPlugin {
+    /// 创建新的音频插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 读取设备列表，失败时记录日志并返回空列表
+    fn devices(&self, kind: DeviceKind) -> Vec<AudioDevice> {
+        audio::devices(kind).unwrap_or_else(|e| {
+            e.log();
+            Vec::new()
+        })
+    }
+
+    /// 设备列表和静音切换
+    fn device_results(&self, filter: &str) -> Vec<SearchResult> {
+        let filter = filter.to_lowercase();
+        let mut results = Vec::new();
+
+        for kind in [DeviceKind::Playback, DeviceKind::Recording] {
+            let devices = self.devices(kind);
+            if let Some(current) = devices.iter().find(|device| device.is_default) {
+                let title = if current.muted { "取消静音" } else { "静音" };
+                if filter.is_empty() || title.contains(&filter) || "mute".contains(&filter) {
+                    results.push(self.result(
+                        format!("audio:mute:{}", kind.label()),
+                        format!("{}{}设备", title, kind.label()),
+                        current.name.clone(),
+                        1000,
+                        AudioAction::ToggleMute { recording: kind == DeviceKind::Recording },
+                    ));
+                }
+            }
+
+            for (index, device) in devices.into_iter().enumerate() {
+                if !device.name.to_lowercase().contains(&filter) {
+                    continue;
+                }
+                results.push(self.result(
+                    format!("audio:device:{}", device.id),
+                    device.name.clone(),
+                    device_description(&device),
+                    900u32.saturating_sub(index as u32),
+                    AudioAction::SetDefault { id: device.id, name: device.name },
+                ));
+            }
+        }
+
+        results
+    }
+
+    /// 音量：无参数时显示当前音量，有参数时设置音量
+    fn volume_results(&self, argument: &str) -> Vec<SearchResult> {
+        let devices = self.devices(DeviceKind::Playback);
+        let Some(current) = devices.into_iter().find(|device| device.is_default) else {
+            return Vec::new();
+        };
+
+        if argument.is_empty() {
+            let state = if current.muted { "已静音" } else { "按 Enter 静音" };
+            let title = format!("音量 {}%", current.volume);
+            return vec![self.result(
+                "audio:volume".to_string(),
+                title,
+                format!("{} · {}", current.name, state),
+                1000,
+                AudioAction::ToggleMute { recording: false },
+            )];
+        }
+
+        match parse_volume(argument) {
+            Some(volume) => vec![self.result(
+                "audio:volume".to_string(),
+                format!("将音量设为 {}%", volume),
+                format!("{} · 当前 {}%", current.name, current.volume),
+                1000,
+                AudioAction::SetVolume { volume },
+            )],
+            None => Vec::new(),
+        }
+    }
+
+    /// 构造结果项
+    fn result(
+        &self,
+        id: String,
+        title: String,
+        description: String,
+        score: u32,
+        action: AudioAction,
+    ) -> SearchResult {
+        SearchResult::new(
+            id,
+            title,
+            description,
+            ResultType::SystemCommand,
+            score,
+            ActionData::Custom {
+                plugin: self.id().to_string(),
+                data: serde_json::to_string(&action).unwrap_or_default(),
+            },
+        )
+    }
+
+    /// 执行音频动作
+    fn run_action(&self, action: AudioAction) -> Result<()> {
+        match action {
+            AudioAction::SetDefault { id, name } => {
+                audio::set_default_device(&id)?;
+                log::info!("已切换默认音频设备: {}", name);
+            },
+            AudioAction::SetVolume { volume } => {
+                audio::set_volume(DeviceKind::Playback, volume)?;
+                log::info!("已将音量设为 {}%", volume);
+            },
+            AudioAction::ToggleMute { recording } => {
+                let kind = if recording { DeviceKind::Recording } else { DeviceKind::Playback };
+                let muted = audio::toggle_mute(kind)?;
+                log::info!("{}设备{}", kind.label(), if muted { "已静音" } else { "已取消静音" });
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Plugin for AudioPlugin {
+    fn id(&self) -> &str {
+        "audio"
+    }
+
+    fn name(&self) -> &str {
+        "音频"
+    }
+
+    fn description(&self) -> &str {
+        "切换默认音频设备、静音和调节音量"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("settings")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化音频插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim_start();
+        for keyword in KEYWORDS {
+            let Some(rest) = query.strip_prefix(keyword) else {
+                continue;
+            };
+            if !rest.is_empty() && !rest.starts_with(' ') {
+                continue;
+            }
+
+            return Ok(if *keyword == AUDIO_KEYWORD {
+                self.device_results(rest.trim())
+            } else {
+                self.volume_results(rest.trim())
+            });
+        }
+        Ok(Vec::new())
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::Custom { data, .. } = &result.action {
+            self.run_action(serde_json::from_str(data)?)?;
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for AudioPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_and_description() {
+        assert_eq!(parse_volume("30"), Some(30));
+        assert_eq!(parse_volume(" 100% "), Some(100));
+        assert_eq!(parse_volume("101"), None);
+        assert_eq!(parse_volume("loud"), None);
+
+        let mut device = AudioDevice {
+            id: "{0.0.0.00000000}.{id}".to_string(),
+            name: "扬声器".to_string(),
+            kind: DeviceKind::Playback,
+            is_default: true,
+            volume: 40,
+            muted: true,
+        };
+        assert_eq!(device_description(&device), "当前播放设备 · 音量 40% · 已静音");
+        device.is_default = false;
+        device.muted = false;
+        assert_eq!(device_description(&device), "播放设备 · 按 Enter 设为默认");
+    }
+}
@@ -0,0 +1,514 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use rusqlite::{Connection, OpenFlags};
+use serde::Deserialize;
+
+/// 浏览器历史插件
+///
+/// 读取 Chrome、Edge、Firefox 的浏览历史，按页面标题或网址模糊搜索并按访问频率和
+/// 最近访问时间排序；浏览器运行时数据库被锁定，因此先复制到临时目录再读取
+use crate::{
+    core::{
+        cache::{global_caches, Cache},
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction, SortKeys},
+    },
+    utils::{clipboard::ClipboardManager, fuzzy::FuzzyMatcher, shell},
+};
+
+/// 只显示浏览历史的查询关键字，可跟随过滤词，如 `history rust`
+const KEYWORD: &str = "history";
+
+/// 普通查询至少需要的字符数
+const MIN_QUERY_CHARS: usize = 2;
+
+/// 每个浏览器配置最多读取的记录数（最近访问的）
+const MAX_ENTRIES_PER_PROFILE: usize = 5000;
+
+/// 重新读取浏览历史的间隔
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Chromium 时间戳（1601-01-01 起的微秒）与 Unix 纪元相差的秒数
+const CHROMIUM_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// 浏览器
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Browser {
+    /// Google Chrome
+    Chrome,
+    /// Microsoft Edge
+    Edge,
+    /// Mozilla Firefox
+    Firefox,
+}
+
+impl Browser {
+    /// 显示名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Browser::Chrome => "Chrome",
+            Browser::Edge => "Edge",
+            Browser::Firefox => "Firefox",
+        }
+    }
+
+    /// 各配置的历史数据库路径
+    fn databases(&self) -> Vec<PathBuf> {
+        match self {
+            Browser::Chrome | Browser::Edge => {
+                let vendor = if *self == Browser::Chrome {
+                    ["Google", "Chrome"]
+                } else {
+                    ["Microsoft", "Edge"]
+                };
+                let Some(local) = dirs::data_local_dir() else {
+                    return Vec::new();
+                };
+                let user_data = local.join(vendor[0]).join(vendor[1]).join("User Data");
+                // 默认配置为 `Default`，其他配置为 `Profile N`
+                subdirectories(&user_data)
+                    .into_iter()
+                    .filter(|dir| {
+                        dir.file_name().is_some_and(|name| {
+                            let name = name.to_string_lossy();
+                            name == "Default" || name.starts_with("Profile ")
+                        })
+                    })
+                    .map(|dir| dir.join("History"))
+                    .filter(|path| path.exists())
+                    .collect()
+            },
+            Browser::Firefox => {
+                let Some(roaming) = dirs::data_dir() else {
+                    return Vec::new();
+                };
+                subdirectories(&roaming.join("Mozilla").join("Firefox").join("Profiles"))
+                    .into_iter()
+                    .map(|dir| dir.join("places.sqlite"))
+                    .filter(|path| path.exists())
+                    .collect()
+            },
+        }
+    }
+
+    /// 读取历史记录的 SQL，时间统一换算为 Unix 时间戳（秒）
+    fn query(&self) -> String {
+        match self {
+            Browser::Chrome | Browser::Edge => format!(
+                "SELECT url, title, visit_count, last_visit_time / 1000000 - {}
+                 FROM urls WHERE hidden = 0 ORDER BY last_visit_time DESC LIMIT ?1",
+                CHROMIUM_EPOCH_OFFSET_SECS
+            ),
+            Browser::Firefox => "SELECT url, title, visit_count, last_visit_date / 1000000
+                 FROM moz_places
+                 WHERE hidden = 0 AND last_visit_date IS NOT NULL
+                 ORDER BY last_visit_date DESC LIMIT ?1"
+                .to_string(),
+        }
+    }
+}
+
+/// 插件配置（`[plugins.settings.history]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct HistorySettings {
+    /// 读取的浏览器
+    browsers: Vec<Browser>,
+    /// 普通查询中最多显示的历史记录数
+    max_results: usize,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self { browsers: vec![Browser::Chrome, Browser::Edge, Browser::Firefox], max_results: 5 }
+    }
+}
+
+/// 历史记录
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// 网址
+    pub url: String,
+    /// 页面标题
+    pub title: String,
+    /// 浏览器
+    pub browser: Browser,
+    /// 访问次数
+    pub visit_count: u32,
+    /// 最近访问时间 (Unix 时间戳，秒)
+    pub last_visit: i64,
+}
+
+impl HistoryEntry {
+    /// 显示标题，没有标题时显示网址
+    fn display_title(&self) -> &str {
+        if self.title.trim().is_empty() {
+            &self.url
+        } else {
+            &self.title
+        }
+    }
+}
+
+/// 按访问次数和最近访问时间计算的排序权重（0 - 100）
+///
+/// 与 Firefox 的 frecency 类似：最近访问的时间段决定权重上限，访问次数按对数增长
+pub fn frecency(visit_count: u32, last_visit: i64, now: i64) -> u32 {
+    let age_days = (now - last_visit).max(0) / 86_400;
+    let recency = match age_days {
+        0..=3 => 100.0,
+        4..=13 => 70.0,
+        14..=30 => 50.0,
+        31..=90 => 30.0,
+        _ => 10.0,
+    };
+    let visits = (1.0 + visit_count.min(100) as f64).ln() / 101f64.ln();
+    (recency * visits).round() as u32
+}
+
+/// 合并多个浏览器和配置中的同一网址，按排序权重从高到低排列
+pub fn merge_entries(entries: Vec<HistoryEntry>, now: i64) -> Vec<HistoryEntry> {
+    let mut by_url: HashMap<String, HistoryEntry> = HashMap::new();
+    for entry in entries {
+        match by_url.get_mut(&entry.url) {
+            Some(existing) => {
+                existing.visit_count = existing.visit_count.saturating_add(entry.visit_count);
+                if entry.last_visit > existing.last_visit {
+                    existing.last_visit = entry.last_visit;
+                    existing.browser = entry.browser;
+                    if !entry.title.is_empty() {
+                        existing.title = entry.title;
+                    }
+                }
+            },
+            None => {
+                by_url.insert(entry.url.clone(), entry);
+            },
+        }
+    }
+
+    let mut merged: Vec<HistoryEntry> = by_url.into_values().collect();
+    merged.sort_by_key(|e| {
+        (std::cmp::Reverse(frecency(e.visit_count, e.last_visit, now)), e.url.clone())
+    });
+    merged
+}
+
+/// 子目录列表
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 复制历史数据库到临时目录后读取，避免浏览器运行时数据库被锁定
+fn read_history(browser: Browser, database: &Path, index: usize) -> Result<Vec<HistoryEntry>> {
+    let copy = std::env::temp_dir().join(format!(
+        "werun-history-{}-{}-{}.sqlite",
+        browser.name().to_lowercase(),
+        index,
+        std::process::id()
+    ));
+    std::fs::copy(database, &copy)?;
+    // 未合并到主文件的最近记录在 WAL 文件中
+    let wal = format!("{}-wal", database.display());
+    if Path::new(&wal).exists() {
+        std::fs::copy(&wal, format!("{}-wal", copy.display()))?;
+    }
+
+    let result = (|| -> Result<Vec<HistoryEntry>> {
+        // 副本以读写方式打开，SQLite 才能读取 WAL 文件中的记录
+        let conn = Connection::open_with_flags(&copy, OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+        let mut stmt = conn.prepare(&browser.query())?;
+        let rows = stmt.query_map([MAX_ENTRIES_PER_PROFILE as i64], |row| {
+            Ok(HistoryEntry {
+                url: row.get(0)?,
+                title: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+                browser,
+                visit_count: row.get::<_, i64>(2)?.max(0) as u32,
+                last_visit: row.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    })();
+
+    for suffix in ["-wal", "-shm", ""] {
+        let _ = std::fs::remove_file(format!("{}{}", copy.display(), suffix));
+    }
+    result
+}
+
+/// 内存中的浏览历史，登记到缓存注册表
+///
+/// 裁剪时移出排序权重最低的记录，下次重新读取时恢复
+struct HistoryCache(Arc<Mutex<Vec<HistoryEntry>>>);
+
+impl HistoryCache {
+    /// 记录占用的内存（估算）
+    fn entry_size(entry: &HistoryEntry) -> usize {
+        std::mem::size_of::<HistoryEntry>() + entry.url.len() + entry.title.len()
+    }
+}
+
+impl Cache for HistoryCache {
+    fn name(&self) -> &str {
+        "浏览历史"
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.0.lock().unwrap().iter().map(Self::entry_size).sum()
+    }
+
+    fn trim(&self, target: usize) -> usize {
+        let mut entries = self.0.lock().unwrap();
+        let mut usage: usize = entries.iter().map(Self::entry_size).sum();
+        let mut freed = 0;
+        while usage > target {
+            let Some(entry) = entries.pop() else {
+                break;
+            };
+            let size = Self::entry_size(&entry);
+            usage -= size;
+            freed += size;
+        }
+        freed
+    }
+}
+
+/// 浏览器历史插件
+pub struct BrowserHistoryPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 历史记录（按排序权重从高到低）
+    entries: Arc<Mutex<Vec<HistoryEntry>>>,
+    /// 最近一次读取完成的时间
+    loaded_at: Arc<Mutex<Option<Instant>>>,
+    /// 是否正在读取
+    loading: Arc<AtomicBool>,
+    /// 插件配置
+    settings: HistorySettings,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl BrowserHistoryPlugin {
+    /// 创建新的浏览器历史插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            entries: Arc::new(Mutex::new(Vec::new())),
+            loaded_at: Arc::new(Mutex::new(None)),
+            loading: Arc::new(AtomicBool::new(false)),
+            settings: HistorySettings::default(),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 没有读取过或超过刷新间隔时在后台重新读取，同一时间只读取一次
+    fn reload_if_stale(&self) {
+        let fresh =
+            self.loaded_at.lock().unwrap().is_some_and(|at| at.elapsed() < REFRESH_INTERVAL);
+        if fresh || self.loading.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let browsers = self.settings.browsers.clone();
+        let entries = self.entries.clone();
+        let loaded_at = self.loaded_at.clone();
+        let loading = self.loading.clone();
+        std::thread::spawn(move || {
+            let started = Instant::now();
+            let mut all = Vec::new();
+            for browser in browsers {
+                for (index, database) in browser.databases().iter().enumerate() {
+                    match read_history(browser, database, index) {
+                        Ok(mut history) => all.append(&mut history),
+                        Err(e) => log::warn!("读取 {} 浏览历史失败: {:?}", database.display(), e),
+                    }
+                }
+            }
+
+            let merged = merge_entries(all, chrono::Utc::now().timestamp());
+            log::info!("已读取浏览历史: {} 条，耗时 {:?}", merged.len(), started.elapsed());
+            *entries.lock().unwrap() = merged;
+            *loaded_at.lock().unwrap() = Some(Instant::now());
+            loading.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// 按标题或网址匹配历史记录，`filter` 为空时按排序权重列出
+    fn history_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        self.reload_if_stale();
+
+        let now = chrono::Utc::now().timestamp();
+        let entries = self.entries.lock().unwrap();
+        let mut results: Vec<SearchResult> = entries
+            .iter()
+            .filter_map(|entry| {
+                let score = if filter.is_empty() {
+                    0
+                } else {
+                    let (title_matched, title_score) =
+                        self.matcher.fuzzy_match(filter, entry.display_title());
+                    let (url_matched, url_score) = self.matcher.fuzzy_match(filter, &entry.url);
+                    match (title_matched, url_matched) {
+                        (true, _) => title_score,
+                        (false, true) => url_score / 2,
+                        (false, false) => return None,
+                    }
+                };
+                let score = score + frecency(entry.visit_count, entry.last_visit, now);
+                Some(self.entry_result(entry, score))
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 构造历史记录的结果
+    fn entry_result(&self, entry: &HistoryEntry, score: u32) -> SearchResult {
+        SearchResult::new(
+            format!("history:{}", entry.url),
+            entry.display_title().to_string(),
+            format!("{} · {}", entry.browser.name(), entry.url),
+            ResultType::Custom("history".to_string()),
+            score,
+            ActionData::OpenUrl { url: entry.url.clone() },
+        )
+        .with_sort_keys(SortKeys { recency: Some(entry.last_visit * 1000), size: None })
+        .with_secondary_actions(vec![SecondaryAction::new(
+            "复制网址",
+            ActionData::CopyToClipboard { text: entry.url.clone() },
+        )])
+    }
+}
+
+impl Plugin for BrowserHistoryPlugin {
+    fn id(&self) -> &str {
+        "history"
+    }
+
+    fn name(&self) -> &str {
+        "浏览历史"
+    }
+
+    fn description(&self) -> &str {
+        "搜索 Chrome、Edge、Firefox 的浏览历史"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("globe")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化浏览历史插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        global_caches().register(Arc::new(HistoryCache(self.entries.clone())));
+        self.reload_if_stale();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim();
+        if let Some(rest) = query.strip_prefix(KEYWORD) {
+            if rest.is_empty() || rest.starts_with(' ') {
+                return Ok(self.history_results(rest.trim(), limit));
+            }
+        }
+
+        if query.chars().count() < MIN_QUERY_CHARS {
+            return Ok(Vec::new());
+        }
+        Ok(self.history_results(query, limit.min(self.settings.max_results)))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::OpenUrl { url } => {
+                shell::open_url(url)?;
+            },
+            ActionData::CopyToClipboard { text } => self.clipboard_manager.set_text(text)?,
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.reload_if_stale();
+        Ok(())
+    }
+}
+
+impl Default for BrowserHistoryPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(url: &str, browser: Browser, visit_count: u32, last_visit: i64) -> HistoryEntry {
+        HistoryEntry {
+            url: url.to_string(),
+            title: String::new(),
+            browser,
+            visit_count,
+            last_visit,
+        }
+    }
+
+    #[test]
+    fn test_frecency_and_merge() {
+        let now = 100 * 86_400;
+        // 最近访问且访问次数多的权重最高
+        assert_eq!(frecency(100, now, now), 100);
+        assert!(frecency(5, now, now) > frecency(5, now - 20 * 86_400, now));
+        assert!(frecency(50, now, now) > frecency(2, now, now));
+        assert_eq!(frecency(0, now, now), 0);
+
+        let merged = merge_entries(
+            vec![
+                entry("https://a.com/", Browser::Chrome, 3, now - 86_400),
+                entry("https://b.com/", Browser::Firefox, 1, now - 60 * 86_400),
+                entry("https://a.com/", Browser::Edge, 4, now),
+            ],
+            now,
+        );
+        assert_eq!(merged.len(), 2);
+        assert_eq!((merged[0].url.as_str(), merged[0].visit_count), ("https://a.com/", 7));
+        assert_eq!((merged[0].browser, merged[0].last_visit), (Browser::Edge, now));
+    }
+}
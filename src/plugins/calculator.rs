@@ -1,116 +1,440 @@
+use std::sync::Mutex;
+
 use anyhow::Result;
+use serde::Deserialize;
 
 /// 计算器插件
 ///
-/// 提供数学计算功能
-use crate::core::plugin::Plugin;
-use crate::core::search::{ActionData, ResultType, SearchResult};
+/// 提供数学计算功能，支持运算符优先级、乘方、百分比、常用函数和常量，
+/// 单位换算、货币换算，以及进制转换和位运算（程序员模式）；
+/// 会话内记住计算历史，`ans` 引用上一次的结果，`x = 5` 定义后续可用的变量
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon, SettingField, SettingValue},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, Intent, ResultType, SearchResult, TemperatureUnit},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        exchange_rates::{global_rates, ExchangeRates},
+        expression::{self, format_number, is_variable_name, to_integer, Expr, Radix, Variables},
+        units::{self, Unit},
+    },
+};
+
+/// 重新下载汇率的自定义操作
+const REFRESH_RATES: &str = "refresh_rates";
+
+/// 查看计算历史的关键字，其后也可以直接输入表达式（`calc ans * 2`）
+const KEYWORD: &str = "calc";
+
+/// 引用上一次结果的变量名
+const ANS: &str = "ans";
+
+/// 会话内保留的计算历史条数
+const MAX_HISTORY: usize = 50;
+
+/// 表达式结果的 ID 前缀，执行时按 ID 中的表达式记录历史
+const EXPRESSION_ID_PREFIX: &str = "calculator:expr:";
+
+/// 插件配置（`[plugins.settings.calculator]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct CalculatorSettings {
+    /// 汇率接口，返回 `{"base_code": ..., "rates": {...}}` 格式的 JSON
+    rates_url: String,
+    /// 汇率有效期（小时），过期后在后台重新下载
+    rates_max_age_hours: u64,
+    /// 只输入金额（如 `100usd`）时换算到的货币
+    currencies: Vec<String>,
+}
+
+impl Default for CalculatorSettings {
+    fn default() -> Self {
+        Self {
+            rates_url: "https://open.er-api.com/v6/latest/USD".to_string(),
+            rates_max_age_hours: 24,
+            currencies: vec!["CNY".to_string(), "USD".to_string(), "EUR".to_string()],
+        }
+    }
+}
+
+/// 进制转换分隔符，如 `255 to hex`
+const RADIX_SEPARATORS: &[&str] = &[" to ", " in ", " as "];
+
+/// 拆分进制转换的目标（`255 to hex` -> `("255", Some(Radix::Hex))`）
+fn split_radix_target(query: &str) -> (&str, Option<Radix>) {
+    RADIX_SEPARATORS
+        .iter()
+        .find_map(|separator| {
+            let index = query.rfind(separator)?;
+            let radix = Radix::from_name(&query[index + separator.len()..])?;
+            Some((query[..index].trim(), Some(radix)))
+        })
+        .unwrap_or((query, None))
+}
+
+/// 保留 6 位小数
+fn round_converted(value: f64) -> f64 {
+    (value * 1e6).round() / 1e6
+}
+
+/// 拆分变量赋值（`x = 5` -> `("x", "5")`），变量名无效时返回 `None`
+fn split_assignment(query: &str) -> Option<(String, &str)> {
+    let (name, expr) = query.split_once('=')?;
+    let name = name.trim();
+    let expr = expr.trim();
+    (is_variable_name(name) && !name.eq_ignore_ascii_case(ANS) && !expr.is_empty())
+        .then(|| (name.to_lowercase(), expr))
+}
+
+/// 一次计算
+#[derive(Clone, Debug)]
+struct Calculation {
+    /// 输入的表达式（赋值时为 `=` 右侧）
+    expression: String,
+    /// 结果
+    value: f64,
+    /// 赋值的变量名
+    variable: Option<String>,
+}
+
+/// 计算会话：历史和变量只保存在内存中，退出后清空
+#[derive(Default)]
+struct Session {
+    /// 计算历史（最近的在最后）
+    history: Vec<Calculation>,
+    /// 用户定义的变量
+    variables: Variables,
+}
 
 /// 计算器插件
 pub struct CalculatorPlugin {
     /// 是否启用
     enabled: bool,
+    /// 插件配置
+    settings: CalculatorSettings,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+    /// 计算会话
+    session: Mutex<Session>,
 }
 
 impl CalculatorPlugin {
     /// 创建新的计算器插件
     pub fn new() -> Self {
-        Self { enabled: true }
+        Self {
+            enabled: true,
+            settings: CalculatorSettings::default(),
+            clipboard_manager: ClipboardManager::new(),
+            session: Mutex::new(Session::default()),
+        }
     }
 
-    /// 计算表达式
-    fn evaluate(&self, expression: &str) -> Option<f64> {
-        // 简单的表达式求值
-        // TODO: 使用更强大的表达式解析库
+    /// 当前可用的变量，包括上一次结果 `ans`
+    fn variables(&self) -> Variables {
+        let session = self.session.lock().unwrap();
+        let mut variables = session.variables.clone();
+        if let Some(last) = session.history.last() {
+            variables.insert(ANS.to_string(), last.value);
+        }
+        variables
+    }
+
+    /// 解析并计算表达式，返回赋值的变量名、表达式树和结果
+    fn evaluate(&self, input: &str) -> Option<(Option<String>, Expr, f64)> {
+        let (variable, input) = match split_assignment(input) {
+            Some((name, expr)) => (Some(name), expr),
+            None => (None, input),
+        };
+        let expr = expression::parse_with(input, &self.variables())?;
+        let value = expr.eval()?;
+        Some((variable, expr, value))
+    }
 
-        // 移除空格
-        let expr = expression.replace(' ', "");
+    /// 记录一次计算，赋值时同时定义变量
+    fn record(&self, input: &str) {
+        let Some((variable, expr, value)) = self.evaluate(input) else {
+            return;
+        };
 
-        // 尝试直接解析为数字
-        if let Ok(num) = expr.parse::<f64>() {
-            return Some(num);
+        let mut session = self.session.lock().unwrap();
+        if let Some(name) = &variable {
+            log::info!("定义变量: {} = {}", name, format_number(value));
+            session.variables.insert(name.clone(), value);
         }
+        session.history.push(Calculation { expression: expr.to_string(), value, variable });
+        if session.history.len() > MAX_HISTORY {
+            session.history.remove(0);
+        }
+    }
+
+    /// 最近的计算（最近的在前）和已定义的变量
+    fn history_results(&self) -> Vec<SearchResult> {
+        let session = self.session.lock().unwrap();
+        let calculations = session.history.iter().rev().map(|calculation| {
+            let value = format_number(calculation.value);
+            let title = match &calculation.variable {
+                Some(name) => format!("{} = {} = {}", name, calculation.expression, value),
+                None => format!("{} = {}", calculation.expression, value),
+            };
+            (title, "最近的计算，按 Enter 复制结果".to_string(), value)
+        });
+        let variables = session.variables.iter().map(|(name, value)| {
+            let value = format_number(*value);
+            (format!("{} = {}", name, value), format!("变量，可在表达式中使用 {}", name), value)
+        });
 
-        // 简单的四则运算
-        self.parse_expression(&expr)
+        calculations
+            .chain(variables)
+            .enumerate()
+            .map(|(index, (title, description, value))| {
+                SearchResult::new(
+                    format!("calculator:history:{}", index),
+                    title,
+                    description,
+                    ResultType::Calculator,
+                    1000 - index as u32,
+                    ActionData::CopyToClipboard { text: value },
+                )
+            })
+            .collect()
     }
 
-    /// 解析简单表达式
-    fn parse_expression(&self, expr: &str) -> Option<f64> {
-        // 支持 + - * / 和括号
-        // 这是一个简化的实现
+    /// 汇率有效期内不重新下载
+    fn refresh_rates_if_stale(&self) {
+        let max_age_secs = (self.settings.rates_max_age_hours * 3600) as i64;
+        global_rates().refresh_if_stale(&self.settings.rates_url, max_age_secs);
+    }
 
-        // 处理括号
-        if let Some(start) = expr.find('(') {
-            if let Some(end) = expr.rfind(')') {
-                let inner = &expr[start + 1..end];
-                if let Some(inner_result) = self.parse_expression(inner) {
-                    let new_expr =
-                        format!("{}{}{}", &expr[..start], inner_result, &expr[end + 1..]);
-                    return self.parse_expression(&new_expr);
-                }
-            }
-        }
+    /// 计算步骤预览：规范化表达式、表达式树和中间结果
+    fn expression_preview(&self, expr: &Expr, value: f64) -> Preview {
+        let tree = expr
+            .tree()
+            .into_iter()
+            .map(|(depth, node)| {
+                // 运算节点同时显示该子树的值
+                let value = match node {
+                    Expr::Number(_) => String::new(),
+                    _ => node.eval().map(format_number).unwrap_or_default(),
+                };
+                PreviewRow::new(node.label(), value).indented(depth)
+            })
+            .collect();
 
-        // 处理加法
-        if let Some(pos) = expr.find('+') {
-            let left = self.parse_expression(&expr[..pos])?;
-            let right = self.parse_expression(&expr[pos + 1..])?;
-            return Some(left + right);
-        }
+        let steps = expr
+            .steps()
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| {
+                PreviewRow::new(
+                    format!("{}. {}", index + 1, step.operation),
+                    format_number(step.value),
+                )
+            })
+            .collect();
 
-        // 处理减法
-        if let Some(pos) = expr.rfind('-') {
-            if pos > 0 {
-                let left = self.parse_expression(&expr[..pos])?;
-                let right = self.parse_expression(&expr[pos + 1..])?;
-                return Some(left - right);
-            }
-        }
+        Preview::new()
+            .section("表达式", vec![PreviewRow::new(expr.to_string(), format_number(value))])
+            .section("表达式树", tree)
+            .section("计算步骤", steps)
+    }
 
-        // 处理乘法
-        if let Some(pos) = expr.find('*') {
-            let left = self.parse_expression(&expr[..pos])?;
-            let right = self.parse_expression(&expr[pos + 1..])?;
-            return Some(left * right);
-        }
+    /// 程序员模式结果：同时给出各进制的表示，指定的目标进制排在最前
+    fn radix_results(
+        &self,
+        query: &str,
+        expr: &Expr,
+        value: i64,
+        target: Option<Radix>,
+    ) -> Vec<SearchResult> {
+        let rows = Radix::ALL
+            .iter()
+            .map(|radix| PreviewRow::new(radix.name(), radix.format(value)))
+            .collect();
+        let preview = self.expression_preview(expr, value as f64).section("进制", rows);
 
-        // 处理除法
-        if let Some(pos) = expr.find('/') {
-            let left = self.parse_expression(&expr[..pos])?;
-            let right = self.parse_expression(&expr[pos + 1..])?;
-            if right != 0.0 {
-                return Some(left / right);
-            }
+        let mut radixes = Radix::ALL.to_vec();
+        if let Some(target) = target {
+            radixes.retain(|radix| *radix != target);
+            radixes.insert(0, target);
         }
 
-        // 尝试解析为数字
-        expr.parse::<f64>().ok()
+        radixes
+            .into_iter()
+            .enumerate()
+            .map(|(index, radix)| {
+                let text = radix.format(value);
+                SearchResult::new(
+                    format!("calculator:{}:{}", query, radix.name()),
+                    text.clone(),
+                    format!("{}，按 Enter 复制结果", radix.name()),
+                    ResultType::Calculator,
+                    1000 - index as u32,
+                    ActionData::CopyToClipboard { text },
+                )
+                .with_preview(preview.clone())
+            })
+            .collect()
     }
 
-    /// 格式化结果
-    fn format_result(&self, value: f64) -> String {
-        if value == value.trunc() {
-            // 整数
-            format!("{:.0}", value)
-        } else {
-            // 小数，保留适当精度
-            format!("{:.6}", value).trim_end_matches('0').trim_end_matches('.').to_string()
+    /// 温度换算预览：换算公式和摄氏度中间值
+    fn temperature_preview(
+        &self,
+        value: f64,
+        unit: TemperatureUnit,
+        target: TemperatureUnit,
+        converted: &str,
+    ) -> Preview {
+        let celsius = unit.to_celsius(value);
+        let mut rows = Vec::new();
+
+        if unit != TemperatureUnit::Celsius {
+            rows.push(PreviewRow::new(
+                format!("1. {}", unit.to_celsius_formula()),
+                format!("{}°C", format_number((celsius * 100.0).round() / 100.0)),
+            ));
+        }
+        if target != TemperatureUnit::Celsius {
+            rows.push(PreviewRow::new(
+                format!("{}. {}", rows.len() + 1, target.from_celsius_formula()),
+                format!("{}{}", converted, target.symbol()),
+            ));
         }
+
+        Preview::new()
+            .section("换算", vec![PreviewRow::new(
+                format!("{}{}", format_number(value), unit.symbol()),
+                format!("{}{}", converted, target.symbol()),
+            )])
+            .section("计算步骤", rows)
     }
 
-    /// 检查是否是数学表达式
-    fn is_expression(&self, query: &str) -> bool {
-        let expr = query.replace(' ', "");
+    /// 温度换算结果（换算到其余两个单位）
+    fn temperature_results(
+        &self,
+        query: &str,
+        value: f64,
+        unit: TemperatureUnit,
+    ) -> Vec<SearchResult> {
+        let celsius = unit.to_celsius(value);
 
-        // 包含运算符
-        expr.contains('+')
-            || expr.contains('-')
-            || expr.contains('*')
-            || expr.contains('/')
-            || expr.contains('(')
-            || expr.contains(')')
-            || expr.parse::<f64>().is_ok()
+        [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit, TemperatureUnit::Kelvin]
+            .into_iter()
+            .filter(|target| *target != unit)
+            .map(|target| {
+                let converted =
+                    format_number((target.from_celsius(celsius) * 100.0).round() / 100.0);
+                let preview = self.temperature_preview(value, unit, target, &converted);
+                SearchResult::new(
+                    format!("calculator:{}:{}", query, target.symbol()),
+                    format!(
+                        "{}{} = {}{}",
+                        format_number(value),
+                        unit.symbol(),
+                        converted,
+                        target.symbol()
+                    ),
+                    "温度换算，按 Enter 复制结果".to_string(),
+                    ResultType::Calculator,
+                    1000,
+                    ActionData::CopyToClipboard { text: converted },
+                )
+                .with_preview(preview)
+            })
+            .collect()
+    }
+
+    /// 单位换算结果
+    fn unit_result(&self, query: &str, value: f64, from: &Unit, to: &Unit) -> Option<SearchResult> {
+        let converted = format_number(round_converted(units::convert(value, from, to)?));
+        Some(SearchResult::new(
+            format!("calculator:{}", query),
+            format!("{} {} = {} {}", format_number(value), from.symbol, converted, to.symbol),
+            format!("{}换算，按 Enter 复制结果", from.dimension.name()),
+            ResultType::Calculator,
+            1000,
+            ActionData::CopyToClipboard { text: converted },
+        ))
+    }
+
+    /// 货币换算结果，没有该货币的汇率时返回 `None`
+    fn currency_result(
+        &self,
+        query: &str,
+        rates: &ExchangeRates,
+        amount: f64,
+        from: &str,
+        to: &str,
+    ) -> Option<SearchResult> {
+        let converted = rates.convert(amount, from, to)?;
+        let converted = format_number((converted * 100.0).round() / 100.0);
+        let fetched_at = chrono::DateTime::from_timestamp(rates.fetched_at, 0)?
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M");
+
+        Some(
+            SearchResult::new(
+                format!("calculator:{}:{}", query, to),
+                format!("{} {} = {} {}", format_number(amount), from, converted, to),
+                format!("汇率更新于 {}，按 Enter 复制结果", fetched_at),
+                ResultType::Calculator,
+                1000,
+                ActionData::CopyToClipboard { text: converted },
+            )
+            .with_preview(Preview::new().section("汇率", vec![
+                PreviewRow::new(
+                    format!("1 {}", from),
+                    format!(
+                        "{} {}",
+                        format_number(round_converted(rates.convert(1.0, from, to)?)),
+                        to
+                    ),
+                ),
+                PreviewRow::new("基准货币", rates.base.clone()),
+            ])),
+        )
+    }
+
+    /// 货币换算结果（换算到指定货币），尚未下载汇率时提示下载
+    fn currency_results(
+        &self,
+        query: &str,
+        amount: f64,
+        from: &str,
+        targets: &[&str],
+    ) -> Vec<SearchResult> {
+        self.refresh_rates_if_stale();
+
+        let Some(rates) = global_rates().rates() else {
+            return vec![SearchResult::new(
+                "calculator:refresh_rates".to_string(),
+                "汇率数据尚未下载".to_string(),
+                "按 Enter 立即下载，下载完成后即可换算货币".to_string(),
+                ResultType::Calculator,
+                1000,
+                ActionData::Custom {
+                    plugin: self.id().to_string(),
+                    data: REFRESH_RATES.to_string(),
+                },
+            )];
+        };
+
+        targets
+            .iter()
+            .filter(|to| !to.eq_ignore_ascii_case(from))
+            .filter_map(|to| self.currency_result(query, &rates, amount, from, to))
+            .collect()
+    }
+
+    /// 检查是否可能是数学表达式
+    ///
+    /// 包含运算符、括号或数字（如 `sqrt 16`）时才尝试解析，单独的 `pi`、`e` 不作为表达式；
+    /// 单独的变量名（如 `ans`）需要使用 `calc` 关键字
+    fn is_expression(&self, query: &str) -> bool {
+        query.chars().any(|c| "+-−*×/÷%^()&|<>=".contains(c) || c.is_ascii_digit())
     }
 }
 
@@ -131,6 +455,14 @@ impl Plugin for CalculatorPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("calculator")
+    }
+
+    fn placeholder(&self) -> Option<&str> {
+        Some("输入算式，如 (1 + 2) * 3 或 sqrt 16")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -141,42 +473,140 @@ impl Plugin for CalculatorPlugin {
 
     fn initialize(&mut self) -> Result<()> {
         log::info!("初始化计算器插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        self.refresh_rates_if_stale();
         Ok(())
     }
 
     fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
         let mut results = Vec::new();
 
-        // 检查是否是数学表达式
-        if self.is_expression(query) {
-            if let Some(value) = self.evaluate(query) {
-                let result_str = self.format_result(value);
+        // `calc` 关键字：单独输入时显示计算历史，其后的内容按表达式计算
+        let keyword_rest = query
+            .trim()
+            .strip_prefix(KEYWORD)
+            .filter(|rest| rest.is_empty() || rest.starts_with(' '));
+        let query = match keyword_rest.map(str::trim) {
+            Some("") => return Ok(self.history_results()),
+            Some(rest) => rest,
+            None => query,
+        };
 
-                results.push(SearchResult::new(
-                    format!("calculator:{}", query),
-                    format!("{} = {}", query, result_str),
-                    "按 Enter 复制结果".to_string(),
-                    ResultType::Calculator,
-                    1000, // 计算器结果优先级很高
-                    ActionData::CopyToClipboard { text: result_str },
-                ));
+        // 单位、温度和货币换算
+        match Intent::detect(query) {
+            Some(Intent::Conversion { value, from, to }) => {
+                return Ok(match (units::lookup(&from), units::lookup(&to)) {
+                    (Some(from), Some(to)) => {
+                        self.unit_result(query, value, from, to).into_iter().collect()
+                    },
+                    _ => self.currency_results(query, value, &from, &[to.as_str()]),
+                });
+            },
+            Some(Intent::Temperature { value, unit }) => {
+                return Ok(self.temperature_results(query, value, unit));
+            },
+            Some(Intent::Currency { amount, code }) => {
+                let targets: Vec<&str> =
+                    self.settings.currencies.iter().map(String::as_str).collect();
+                return Ok(self.currency_results(query, amount, &code, &targets));
+            },
+            _ => {},
+        }
+
+        // 检查是否是数学表达式；`to hex` 等后缀或位运算、进制字面量进入程序员模式
+        let (query_expr, target) = split_radix_target(query);
+        if keyword_rest.is_some() || self.is_expression(query_expr) {
+            let parsed = self.evaluate(query_expr);
+            let programmer = parsed
+                .as_ref()
+                .filter(|(_, expr, _)| target.is_some() || expr.is_programmer())
+                .and_then(|(_, expr, value)| Some((expr, to_integer(*value)?)));
+
+            if let Some((expr, value)) = programmer {
+                results.extend(self.radix_results(query, expr, value, target));
+            } else if let Some((variable, expr, value)) = parsed.filter(|_| target.is_none()) {
+                let result_str = format_number(value);
+                let (title, description) = match &variable {
+                    Some(name) => (
+                        format!("{} = {}", name, result_str),
+                        format!("按 Enter 保存变量 {} 并复制结果", name),
+                    ),
+                    None => {
+                        (format!("{} = {}", query, result_str), "按 Enter 复制结果".to_string())
+                    },
+                };
+
+                results.push(
+                    SearchResult::new(
+                        format!("{}{}", EXPRESSION_ID_PREFIX, query),
+                        title,
+                        description,
+                        ResultType::Calculator,
+                        1000, // 计算器结果优先级很高
+                        ActionData::CopyToClipboard { text: result_str },
+                    )
+                    .with_preview(self.expression_preview(&expr, value)),
+                );
             }
         }
 
         Ok(results)
     }
 
+    fn default_results(&self, _limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.history_results())
+    }
+
     fn execute(&self, result: &SearchResult) -> Result<()> {
-        if let ActionData::CopyToClipboard { text } = &result.action {
-            // TODO: 复制到剪贴板
-            log::info!("复制到剪贴板: {}", text);
+        match &result.action {
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制到剪贴板: {}", text);
+                if let Some(input) = result.id.strip_prefix(EXPRESSION_ID_PREFIX) {
+                    self.record(input);
+                }
+            },
+            ActionData::Custom { data, .. } if data == REFRESH_RATES => {
+                let url = &self.settings.rates_url;
+                global_rates().refresh_if_stale(url, 0);
+            },
+            _ => {},
         }
         Ok(())
     }
 
     fn refresh(&mut self) -> Result<()> {
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        self.refresh_rates_if_stale();
         Ok(())
     }
+
+    fn settings(&self) -> Vec<SettingField> {
+        let settings = &self.settings;
+        vec![
+            SettingField::new(
+                "rates_url",
+                "汇率接口",
+                SettingValue::Text(settings.rates_url.clone()),
+            ),
+            SettingField::new(
+                "rates_max_age_hours",
+                "汇率有效期（小时）",
+                SettingValue::Integer {
+                    value: settings.rates_max_age_hours as i64,
+                    min: 1,
+                    max: 24 * 30,
+                },
+            ),
+            SettingField::new(
+                "currencies",
+                "只输入金额时换算到的货币",
+                SettingValue::List(settings.currencies.clone()),
+            ),
+        ]
+    }
 }
 
 impl Default for CalculatorPlugin {
@@ -1,12 +1,69 @@
-use std::sync::Mutex;
+use std::{sync::Mutex, time::Duration};
 
 use anyhow::Result;
 
-use crate::core::{
-    plugin::Plugin,
-    search::{ActionData, ResultType, SearchResult},
+use crate::{
+    core::{
+        launch::LaunchCommand,
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow, PreviewVisual},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    platform::{screen_color, windows::CommandListener},
+    utils::{
+        clipboard::ClipboardManager,
+        color_palette::{self, PaletteSource},
+    },
 };
 
+/// 触发屏幕取色的关键字，输入前缀即可
+const PICK_KEYWORDS: &[&str] = &["pick", "取色"];
+
+/// 屏幕取色动作的数据
+const PICK_ACTION: &str = "pick";
+
+/// 开始取色前等待启动器隐藏的时间
+const PICK_DELAY: Duration = Duration::from_millis(200);
+
+/// 列出 CSS 命名颜色的关键字
+const CSS_KEYWORD: &str = "css";
+
+/// 对比度查询中分隔两个颜色的词，如 `#333 on #fff`
+const CONTRAST_SEPARATORS: &[&str] = &[" on ", " vs ", " / "];
+
+/// 格式化为 `#RRGGBB`
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// 仅含色块的预览
+fn swatch((r, g, b): (u8, u8, u8)) -> Preview {
+    Preview::new().visual(PreviewVisual::Color(r, g, b))
+}
+
+/// Tailwind 类名：调色板令牌使用令牌名，其余使用任意值写法
+fn tailwind_class(color: &ColorValue) -> String {
+    match color_palette::lookup(&color.name) {
+        Some(named) if named.source == PaletteSource::Tailwind => format!("bg-{}", named.name),
+        _ => format!("bg-[{}]", color.hex),
+    }
+}
+
+/// SwiftUI 颜色写法
+fn swiftui_color((r, g, b): (u8, u8, u8)) -> String {
+    let unit = |c: u8| c as f64 / 255.0;
+    format!("Color(red: {:.3}, green: {:.3}, blue: {:.3})", unit(r), unit(g), unit(b))
+}
+
+/// 对比度是否达到 WCAG 阈值的描述
+fn verdict(ratio: f64, threshold: f64) -> &'static str {
+    if ratio >= threshold {
+        "通过"
+    } else {
+        "未通过"
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ColorValue {
     pub hex: String,
@@ -18,11 +75,16 @@ pub struct ColorValue {
 pub struct ColorPickerPlugin {
     enabled: bool,
     parsed_color: Mutex<Option<ColorValue>>,
+    clipboard_manager: ClipboardManager,
 }
 
 impl ColorPickerPlugin {
     pub fn new() -> Self {
-        Self { enabled: true, parsed_color: Mutex::new(None) }
+        Self {
+            enabled: true,
+            parsed_color: Mutex::new(None),
+            clipboard_manager: ClipboardManager::new(),
+        }
     }
 
     fn parse_hex(&self, input: &str) -> Option<ColorValue> {
@@ -118,10 +180,261 @@ impl ColorPickerPlugin {
         }
 
         if input.starts_with('#') || input.chars().all(|c| c.is_ascii_hexdigit()) {
-            return self.parse_hex(input);
+            if let Some(color) = self.parse_hex(input) {
+                return Some(color);
+            }
+        }
+
+        self.parse_named(input)
+    }
+
+    /// 解析 CSS 命名颜色或 Tailwind/Material 调色板令牌
+    fn parse_named(&self, input: &str) -> Option<ColorValue> {
+        let named = color_palette::lookup(input)?;
+        let (r, g, b) = named.rgb;
+
+        Some(ColorValue {
+            hex: named.hex(),
+            rgb: named.rgb,
+            hsl: self.rgb_to_hsl(r, g, b),
+            name: named.name,
+        })
+    }
+
+    /// 解析 `颜色 on 颜色` 形式的对比度查询
+    fn parse_pair(&self, input: &str) -> Option<(ColorValue, ColorValue)> {
+        CONTRAST_SEPARATORS.iter().find_map(|separator| {
+            let (foreground, background) = input.split_once(separator)?;
+            Some((self.parse_color(foreground)?, self.parse_color(background)?))
+        })
+    }
+
+    /// 屏幕取色结果，查询为取色关键字的前缀时显示
+    fn pick_result(&self, query: &str) -> Option<SearchResult> {
+        let query = query.trim().to_lowercase();
+        if query.chars().count() < 2 || !PICK_KEYWORDS.iter().any(|k| k.starts_with(&query)) {
+            return None;
         }
 
-        None
+        Some(SearchResult::new(
+            "color_picker:pick".to_string(),
+            "从屏幕取色".to_string(),
+            "隐藏启动器后单击屏幕上的任意位置读取颜色 · Esc 或右键取消".to_string(),
+            ResultType::Custom("color".to_string()),
+            900,
+            ActionData::Custom {
+                plugin: "color_picker".to_string(),
+                data: PICK_ACTION.to_string(),
+            },
+        ))
+    }
+
+    /// `css <前缀>` 列出名称匹配的 CSS 命名颜色
+    fn css_results(&self, query: &str) -> Vec<SearchResult> {
+        let Some(prefix) = query.trim().strip_prefix(CSS_KEYWORD) else {
+            return Vec::new();
+        };
+        if !prefix.is_empty() && !prefix.starts_with(' ') {
+            return Vec::new();
+        }
+
+        color_palette::search_css(prefix)
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| {
+                SearchResult::new(
+                    format!("color_picker:css:{}", color.name),
+                    color.name.clone(),
+                    format!("{} · 点击复制 HEX 值", color.hex()),
+                    ResultType::Custom("color".to_string()),
+                    800u32.saturating_sub(index as u32),
+                    ActionData::CopyToClipboard { text: color.hex() },
+                )
+                .with_preview(swatch(color.rgb))
+            })
+            .collect()
+    }
+
+    /// 两个颜色的 WCAG 对比度
+    fn contrast_result(&self, foreground: &ColorValue, background: &ColorValue) -> SearchResult {
+        let ratio = color_palette::contrast_ratio(foreground.rgb, background.rgb);
+        let ratio_text = format!("{:.2}:1", ratio);
+
+        SearchResult::new(
+            format!("color_picker:contrast:{}:{}", foreground.hex, background.hex),
+            format!("对比度 {}", ratio_text),
+            format!(
+                "{} 与 {} · 正常文本 AA {} · AAA {}",
+                foreground.hex,
+                background.hex,
+                verdict(ratio, 4.5),
+                verdict(ratio, 7.0)
+            ),
+            ResultType::Custom("color".to_string()),
+            1000,
+            ActionData::CopyToClipboard { text: ratio_text.clone() },
+        )
+        .with_preview(
+            Preview::new()
+                .section("颜色", vec![
+                    PreviewRow::new("前景", foreground.hex.clone()),
+                    PreviewRow::new("背景", background.hex.clone()),
+                    PreviewRow::new("对比度", ratio_text),
+                ])
+                .section("WCAG 2", vec![
+                    PreviewRow::new("正常文本 AA (4.5:1)", verdict(ratio, 4.5)),
+                    PreviewRow::new("正常文本 AAA (7:1)", verdict(ratio, 7.0)),
+                    PreviewRow::new("大号文本 AA (3:1)", verdict(ratio, 3.0)),
+                    PreviewRow::new("大号文本 AAA (4.5:1)", verdict(ratio, 4.5)),
+                ]),
+        )
+    }
+
+    /// 浅色、深色色阶和与黑白的对比度
+    fn scale_results(&self, color: &ColorValue, base_score: u32) -> Vec<SearchResult> {
+        let scales = [
+            ("tints", "浅色色阶", "浅色", color_palette::tints(color.rgb)),
+            ("shades", "深色色阶", "深色", color_palette::shades(color.rgb)),
+        ];
+
+        let mut results = Vec::new();
+        for (offset, (kind, title, section, steps)) in (0u32..).zip(scales) {
+            let hexes: Vec<String> = steps.iter().map(|(_, rgb)| hex(*rgb)).collect();
+            let rows = steps
+                .iter()
+                .zip(&hexes)
+                .map(|((percent, _), value)| {
+                    PreviewRow::new(format!("{}%", percent), value.clone())
+                })
+                .collect();
+            results.push(
+                SearchResult::new(
+                    format!("color_picker:{}:{}", kind, color.hex),
+                    title.to_string(),
+                    format!("{} → {} · 点击复制全部", hexes[0], hexes[hexes.len() - 1]),
+                    ResultType::Custom("color".to_string()),
+                    base_score.saturating_sub(100 + offset * 10),
+                    ActionData::CopyToClipboard { text: hexes.join(", ") },
+                )
+                .with_preview(Preview::new().section(section, rows)),
+            );
+        }
+
+        let on_white = color_palette::contrast_ratio(color.rgb, (255, 255, 255));
+        let on_black = color_palette::contrast_ratio(color.rgb, (0, 0, 0));
+        let (text_name, text_hex) =
+            if on_white >= on_black { ("白色", "#FFFFFF") } else { ("黑色", "#000000") };
+        results.push(SearchResult::new(
+            format!("color_picker:contrast:{}", color.hex),
+            format!("对比度：白色 {:.2}:1 · 黑色 {:.2}:1", on_white, on_black),
+            format!("作为背景色时适合{}文字 · 点击复制 {}", text_name, text_hex),
+            ResultType::Custom("color".to_string()),
+            base_score.saturating_sub(120),
+            ActionData::CopyToClipboard { text: text_hex.to_string() },
+        ));
+
+        results
+    }
+
+    /// 在后台线程中取色，等启动器隐藏后开始；取到颜色后以颜色值为查询重新打开启动器
+    fn start_pick(&self) {
+        std::thread::spawn(|| {
+            std::thread::sleep(PICK_DELAY);
+            match screen_color::pick() {
+                Ok(Some(rgb)) => {
+                    let color = hex(rgb);
+                    log::info!("屏幕取色: {}", color);
+                    if !CommandListener::send(&LaunchCommand::Query(color).encode()) {
+                        log::warn!("无法重新打开启动器显示取色结果");
+                    }
+                },
+                Ok(None) => log::info!("已取消屏幕取色"),
+                Err(e) => e.log(),
+            }
+        });
+    }
+
+    /// 颜色的预览：色块、RGB/HSL/HSV/CMYK 分量和各平台写法
+    fn color_preview(&self, color: &ColorValue) -> Preview {
+        let (r, g, b) = color.rgb;
+        let (h, s, l) = color.hsl;
+        let (hue, saturation, value) = color_palette::rgb_to_hsv(color.rgb);
+        let (c, m, y, k) = color_palette::rgb_to_cmyk(color.rgb);
+
+        swatch(color.rgb)
+            .section("分量", vec![
+                PreviewRow::new("HEX", color.hex.clone()),
+                PreviewRow::new("RGB", format!("{}, {}, {}", r, g, b)),
+                PreviewRow::new("HSL", format!("{}°, {}%, {}%", h, s, l)),
+                PreviewRow::new("HSV", format!("{}°, {}%, {}%", hue, saturation, value)),
+                PreviewRow::new("CMYK", format!("{}%, {}%, {}%, {}%", c, m, y, k)),
+            ])
+            .section("写法", vec![
+                PreviewRow::new("Tailwind", tailwind_class(color)),
+                PreviewRow::new("SwiftUI", swiftui_color(color.rgb)),
+            ])
+    }
+
+    /// 复制为其他写法的次要动作
+    fn copy_actions(&self, color: &ColorValue) -> Vec<SecondaryAction> {
+        vec![
+            SecondaryAction::new("复制为 Tailwind 类名", ActionData::CopyToClipboard {
+                text: tailwind_class(color),
+            }),
+            SecondaryAction::new("复制为 SwiftUI 颜色", ActionData::CopyToClipboard {
+                text: swiftui_color(color.rgb),
+            }),
+        ]
+    }
+
+    /// 生成调色板相关结果：最接近的命名颜色与 CSS 变量
+    fn palette_results(&self, color: &ColorValue, base_score: u32) -> Vec<SearchResult> {
+        let mut results = Vec::new();
+
+        let (css, css_distance) = color_palette::nearest_css(color.rgb);
+        let css_title = if css_distance == 0.0 {
+            format!("CSS 颜色：{}", css.name)
+        } else {
+            format!("最接近的 CSS 颜色：{}", css.name)
+        };
+        results.push(
+            SearchResult::new(
+                format!("color_picker:css:{}", color.hex),
+                css_title,
+                format!("{} · 点击复制颜色名称", css.hex()),
+                ResultType::Custom("color".to_string()),
+                base_score.saturating_sub(70),
+                ActionData::CopyToClipboard { text: css.name.clone() },
+            )
+            .with_preview(swatch(css.rgb)),
+        );
+
+        let (tailwind, _) = color_palette::nearest_tailwind(color.rgb);
+        results.push(
+            SearchResult::new(
+                format!("color_picker:tailwind:{}", color.hex),
+                format!("最接近的 Tailwind 颜色：{}", tailwind.name),
+                format!("{} · 点击复制令牌", tailwind.hex()),
+                ResultType::Custom("color".to_string()),
+                base_score.saturating_sub(80),
+                ActionData::CopyToClipboard { text: tailwind.name.clone() },
+            )
+            .with_preview(swatch(tailwind.rgb)),
+        );
+
+        // 输入本身是调色板令牌时使用其名称，否则使用最接近的 Tailwind 令牌命名变量
+        let variable = color_palette::lookup(&color.name).unwrap_or(tailwind);
+        let declaration = format!("{}: {};", variable.css_variable(), color.hex);
+        results.push(SearchResult::new(
+            format!("color_picker:var:{}", color.hex),
+            declaration.clone(),
+            format!("点击复制 CSS 变量 · 使用 var({})", variable.css_variable()),
+            ResultType::Custom("color".to_string()),
+            base_score.saturating_sub(90),
+            ActionData::CopyToClipboard { text: declaration },
+        ));
+
+        results
     }
 
     fn rgb_to_hsl(&self, r: u8, g: u8, b: u8) -> (u16, u8, u8) {
@@ -248,13 +561,17 @@ impl Plugin for ColorPickerPlugin {
     }
 
     fn description(&self) -> &str {
-        "识别颜色值并提供预览和复制"
+        "识别颜色值并提供预览和复制，支持屏幕取色、色阶和对比度检查"
     }
 
     fn version(&self) -> &str {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("palette")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -271,8 +588,21 @@ impl Plugin for ColorPickerPlugin {
     fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
         let mut results = Vec::new();
 
+        results.extend(self.pick_result(query));
+        results.extend(self.css_results(query));
+
+        if let Some((foreground, background)) = self.parse_pair(query) {
+            results.push(self.contrast_result(&foreground, &background));
+        }
+
         if let Some(color) = self.parse_color(query) {
-            let color_name = self.get_color_name(&color);
+            // 颜色名称可能与普通搜索词重叠，按名称解析的结果降低优先级
+            let base_score = if color.name.is_empty() { 1000 } else { 300 };
+            let color_name = if color.name.is_empty() {
+                self.get_color_name(&color)
+            } else {
+                color.name.clone()
+            };
             let (r, g, b) = color.rgb;
             let (h, s, l) = color.hsl;
 
@@ -280,52 +610,80 @@ impl Plugin for ColorPickerPlugin {
                 *guard = Some(color.clone());
             }
 
-            results.push(SearchResult::new(
-                format!("color_picker:{}", color.hex),
-                color_name.clone(),
-                format!(
-                    "HEX: {} | RGB:({}, {}, {}) | HSL({}, {}%, {}%)",
-                    color.hex, r, g, b, h, s, l
-                ),
-                ResultType::Custom("color".to_string()),
-                1000,
-                ActionData::CopyToClipboard { text: color.hex.clone() },
-            ));
-
-            results.push(SearchResult::new(
-                format!("color_picker:rgb:{}", color.hex),
-                format!("RGB({}, {}, {})", r, g, b),
-                "点击复制 RGB 值".to_string(),
-                ResultType::Custom("color".to_string()),
-                950,
-                ActionData::CopyToClipboard { text: format!("rgb({}, {}, {})", r, g, b) },
-            ));
-
-            results.push(SearchResult::new(
-                format!("color_picker:hsl:{}", color.hex),
-                format!("HSL({}, {}%, {}%)", h, s, l),
-                "点击复制 HSL 值".to_string(),
-                ResultType::Custom("color".to_string()),
-                940,
-                ActionData::CopyToClipboard { text: format!("hsl({}, {}%, {}%)", h, s, l) },
-            ));
-
-            results.push(SearchResult::new(
-                format!("color_picker:hex:{}", color.hex),
-                color.hex.clone(),
-                "点击复制 HEX 值".to_string(),
-                ResultType::Custom("color".to_string()),
-                960,
-                ActionData::CopyToClipboard { text: color.hex.clone() },
-            ));
+            // 颜色值的各种写法共用同一预览和复制动作
+            let preview = self.color_preview(&color);
+            let actions = self.copy_actions(&color);
+
+            results.push(
+                SearchResult::new(
+                    format!("color_picker:{}", color.hex),
+                    color_name.clone(),
+                    format!(
+                        "HEX: {} | RGB:({}, {}, {}) | HSL({}, {}%, {}%)",
+                        color.hex, r, g, b, h, s, l
+                    ),
+                    ResultType::Custom("color".to_string()),
+                    base_score,
+                    ActionData::CopyToClipboard { text: color.hex.clone() },
+                )
+                .with_preview(preview.clone())
+                .with_secondary_actions(actions.clone()),
+            );
+
+            results.push(
+                SearchResult::new(
+                    format!("color_picker:rgb:{}", color.hex),
+                    format!("RGB({}, {}, {})", r, g, b),
+                    "点击复制 RGB 值".to_string(),
+                    ResultType::Custom("color".to_string()),
+                    base_score - 50,
+                    ActionData::CopyToClipboard { text: format!("rgb({}, {}, {})", r, g, b) },
+                )
+                .with_preview(preview.clone())
+                .with_secondary_actions(actions.clone()),
+            );
+
+            results.push(
+                SearchResult::new(
+                    format!("color_picker:hsl:{}", color.hex),
+                    format!("HSL({}, {}%, {}%)", h, s, l),
+                    "点击复制 HSL 值".to_string(),
+                    ResultType::Custom("color".to_string()),
+                    base_score - 60,
+                    ActionData::CopyToClipboard { text: format!("hsl({}, {}%, {}%)", h, s, l) },
+                )
+                .with_preview(preview.clone())
+                .with_secondary_actions(actions.clone()),
+            );
+
+            results.push(
+                SearchResult::new(
+                    format!("color_picker:hex:{}", color.hex),
+                    color.hex.clone(),
+                    "点击复制 HEX 值".to_string(),
+                    ResultType::Custom("color".to_string()),
+                    base_score - 40,
+                    ActionData::CopyToClipboard { text: color.hex.clone() },
+                )
+                .with_preview(preview)
+                .with_secondary_actions(actions),
+            );
+
+            results.extend(self.palette_results(&color, base_score));
+            results.extend(self.scale_results(&color, base_score));
         }
 
         Ok(results)
     }
 
     fn execute(&self, result: &SearchResult) -> Result<()> {
-        if let ActionData::CopyToClipboard { text } = &result.action {
-            log::info!("复制颜色: {}", text);
+        match &result.action {
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制颜色: {}", text);
+            },
+            ActionData::Custom { data, .. } if data == PICK_ACTION => self.start_pick(),
+            _ => {},
         }
         Ok(())
     }
@@ -3,7 +3,7 @@ use std::process::Command;
 use anyhow::Result;
 
 use crate::core::{
-    plugin::Plugin,
+    plugin::{Plugin, PluginIcon},
     search::{ActionData, ResultType, SearchResult},
 };
 
@@ -101,6 +101,10 @@ impl Plugin for CommandExecutorPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("terminal")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -0,0 +1,209 @@
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDateTime};
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        cron::{self, CronExpr, WEEKDAY_LABELS},
+    },
+};
+
+/// 查询关键字
+const KEYWORD: &str = "cron";
+
+/// 列出的运行次数
+const NEXT_RUNS: usize = 5;
+
+/// 五段的名称
+const FIELD_NAMES: [&str; 5] = ["分钟", "小时", "日", "月", "星期"];
+
+/// 运行时间显示，如 "2024-01-02 周二 09:00"
+fn format_run(time: &NaiveDateTime) -> String {
+    let weekday = WEEKDAY_LABELS[time.weekday().num_days_from_sunday() as usize];
+    format!("{} {} {}", time.format("%Y-%m-%d"), weekday, time.format("%H:%M"))
+}
+
+/// cron 插件
+///
+/// `cron <表达式>` 显示表达式的中文描述和接下来的运行时间，`cron every weekday at 9am`、
+/// `cron 每天 9:30` 等短语生成对应的表达式，Enter 复制
+pub struct CronPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl CronPlugin {
+    /// 创建新的 cron 插件
+    pub fn new() -> Self {
+        Self { enabled: true, clipboard_manager: ClipboardManager::new() }
+    }
+
+    /// 结果项
+    fn result(&self, id: String, title: String, description: String, text: String) -> SearchResult {
+        SearchResult::new(
+            format!("cron:{}", id),
+            title,
+            description,
+            ResultType::Custom("cron".to_string()),
+            1000,
+            ActionData::CopyToClipboard { text },
+        )
+    }
+
+    /// 表达式的描述和接下来的运行时间；`from_phrase` 表示表达式由短语生成
+    fn expression_results(
+        &self,
+        expr: &str,
+        cron: &CronExpr,
+        from_phrase: bool,
+    ) -> Vec<SearchResult> {
+        let description = cron.describe();
+        let runs = cron.next_runs(Local::now().naive_local(), NEXT_RUNS);
+
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let mut preview = Preview::new().section(
+            "接下来的运行时间",
+            runs.iter()
+                .enumerate()
+                .map(|(index, run)| PreviewRow::new((index + 1).to_string(), format_run(run)))
+                .collect(),
+        );
+        if fields.len() == FIELD_NAMES.len() {
+            preview = preview.section(
+                "字段",
+                FIELD_NAMES
+                    .iter()
+                    .zip(&fields)
+                    .map(|(name, field)| PreviewRow::new(*name, *field))
+                    .collect(),
+            );
+        }
+
+        let main = if from_phrase {
+            self.result(
+                "expr".to_string(),
+                expr.to_string(),
+                format!("{} · 按 Enter 复制表达式", description),
+                expr.to_string(),
+            )
+        } else {
+            self.result(
+                "expr".to_string(),
+                description,
+                format!("{} · 按 Enter 复制表达式", expr),
+                expr.to_string(),
+            )
+        };
+        let mut results = vec![main.with_preview(preview)];
+
+        if runs.is_empty() {
+            results.push(self.result(
+                "never".to_string(),
+                "不会运行".to_string(),
+                "没有满足表达式的日期".to_string(),
+                String::new(),
+            ));
+        }
+        results.extend(runs.iter().enumerate().map(|(index, run)| {
+            let text = format_run(run);
+            self.result(
+                format!("run:{}", index),
+                text.clone(),
+                format!("第 {} 次运行 · 按 Enter 复制", index + 1),
+                text,
+            )
+        }));
+        results
+    }
+}
+
+impl Plugin for CronPlugin {
+    fn id(&self) -> &str {
+        "cron"
+    }
+
+    fn name(&self) -> &str {
+        "cron 表达式"
+    }
+
+    fn description(&self) -> &str {
+        "解释 cron 表达式、计算运行时间，或从短语生成表达式"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("calendar")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化 cron 插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let input = rest.trim();
+        if let Some(cron) = CronExpr::parse(input) {
+            let expr = input.split_whitespace().collect::<Vec<_>>().join(" ");
+            return Ok(self.expression_results(&expr, &cron, false));
+        }
+        if let Some(expr) = cron::from_phrase(input) {
+            if let Some(cron) = CronExpr::parse(&expr) {
+                return Ok(self.expression_results(&expr, &cron, true));
+            }
+        }
+
+        let title =
+            if input.is_empty() { "cron <表达式或短语>" } else { "无法识别的表达式" };
+        Ok(vec![self.result(
+            "usage".to_string(),
+            title.to_string(),
+            "如 cron 0 9 * * 1-5、cron every weekday at 9am、cron 每天 9:30".to_string(),
+            String::new(),
+        )])
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            if !text.is_empty() {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制: {}", text);
+            }
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for CronPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,9 +2,12 @@ use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
 
-use crate::core::{
-    plugin::Plugin,
-    search::{ActionData, ResultType, SearchResult},
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::elevation,
 };
 
 #[derive(Clone, Debug)]
@@ -218,6 +221,13 @@ impl CustomCommandsPlugin {
             format!("{} {}", command.command, args.join(" "))
         };
 
+        // 提权只能通过 ShellExecuteW 触发 UAC，不能用 Command 启动
+        if command.run_as_admin {
+            let parameters = elevation::join_args(&["/c".to_string(), full_command]);
+            elevation::run_elevated("cmd", &parameters, command.working_dir.as_deref())?;
+            return Ok(());
+        }
+
         let mut cmd = std::process::Command::new("cmd");
         cmd.args(["/c", &full_command]);
 
@@ -225,15 +235,6 @@ impl CustomCommandsPlugin {
             cmd.current_dir(dir);
         }
 
-        if command.run_as_admin {
-            #[cfg(target_os = "windows")]
-            {
-                use std::os::windows::process::CommandExt;
-                const CREATE_NO_WINDOW: u32 = 0x08000000;
-                cmd.creation_flags(CREATE_NO_WINDOW);
-            }
-        }
-
         cmd.spawn()?;
         Ok(())
     }
@@ -274,6 +275,10 @@ impl Plugin for CustomCommandsPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -0,0 +1,460 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{ForegroundInfo, Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::shell,
+};
+
+/// 查询关键字
+const KEYWORD: &str = "dev";
+
+/// 项目配置文件名
+const PROJECT_FILE: &str = "werun.toml";
+
+/// 从这些编辑器唤出时提供当前项目的操作
+pub const EDITOR_PROCESSES: &[&str] = &["code.exe", "code - insiders.exe"];
+
+/// 识别项目根目录的标记文件
+const ROOT_MARKERS: &[&str] = &[".git", PROJECT_FILE, "Cargo.toml", "package.json", "go.mod"];
+
+/// 插件配置（`[plugins.settings.dev_context]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct DevContextSettings {
+    /// 已知项目目录
+    projects: Vec<String>,
+    /// 编辑器命令
+    editor: String,
+}
+
+impl Default for DevContextSettings {
+    fn default() -> Self {
+        Self { projects: Vec::new(), editor: "code".to_string() }
+    }
+}
+
+/// 项目根目录下的 `werun.toml`
+#[derive(Debug, Default, Deserialize)]
+struct ProjectFile {
+    /// 命令名称 -> 命令行
+    #[serde(default)]
+    commands: BTreeMap<String, String>,
+}
+
+/// 检测到的项目
+#[derive(Clone, Debug, PartialEq)]
+pub struct Project {
+    /// 项目名称（根目录名）
+    pub name: String,
+    /// 项目根目录
+    pub root: PathBuf,
+}
+
+/// 项目动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum DevAction {
+    /// 在编辑器中打开
+    OpenInEditor { root: String },
+    /// 在项目目录中运行命令
+    RunCommand { root: String, name: String, command: String },
+}
+
+/// 开发上下文插件
+///
+/// 根据前台编辑器/终端的窗口标题或配置的项目列表识别当前项目，
+/// 提供在编辑器中打开、打开远程仓库、运行 `werun.toml` 中的命令等操作
+pub struct DevContextPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: DevContextSettings,
+    /// 本次显示期间检测到的项目的全部动作，避免每次按键都枚举窗口和读取项目文件
+    actions: OnceLock<Vec<SearchResult>>,
+}
+
+impl DevContextPlugin {
+    /// 创建新的开发上下文插件
+    pub fn new() -> Self {
+        Self { enabled: true, settings: DevContextSettings::default(), actions: OnceLock::new() }
+    }
+
+    /// 按 Z 序（最近激活在前）获取可见窗口标题
+    fn window_titles(&self) -> Vec<String> {
+        #[cfg(target_os = "windows")]
+        {
+            use windows::Win32::{
+                Foundation::{BOOL, HWND, LPARAM},
+                UI::WindowsAndMessaging::{EnumWindows, GetWindowTextW, IsWindowVisible},
+            };
+
+            unsafe extern "system" fn enum_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+                let titles = &mut *(lparam.0 as *mut Vec<String>);
+                if IsWindowVisible(hwnd).as_bool() {
+                    let mut buf = [0u16; 512];
+                    let len = GetWindowTextW(hwnd, &mut buf);
+                    if len > 0 {
+                        titles.push(String::from_utf16_lossy(&buf[..len as usize]));
+                    }
+                }
+                BOOL(1)
+            }
+
+            let mut titles: Vec<String> = Vec::new();
+            unsafe {
+                let _ = EnumWindows(Some(enum_callback), LPARAM(&mut titles as *mut _ as isize));
+            }
+            titles.retain(|t| !t.contains("WeRun") && t != "Program Manager");
+            titles
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// 检测当前项目
+    ///
+    /// 优先匹配最近激活的窗口标题；都未命中时使用配置中的第一个项目
+    fn detect_project(&self) -> Option<Project> {
+        let projects: Vec<PathBuf> = self.settings.projects.iter().map(PathBuf::from).collect();
+
+        self.window_titles()
+            .iter()
+            .find_map(|title| project_from_title(title, &projects))
+            .or_else(|| projects.iter().find(|p| p.is_dir()).map(|root| Project::at(root)))
+    }
+
+    /// 本次显示期间检测到的项目的全部动作，首次查询时检测
+    fn current_actions(&self) -> &[SearchResult] {
+        self.actions.get_or_init(|| {
+            self.detect_project().map(|project| self.project_actions(&project)).unwrap_or_default()
+        })
+    }
+
+    /// 项目的全部动作
+    fn project_actions(&self, project: &Project) -> Vec<SearchResult> {
+        let root = project.root.to_string_lossy().to_string();
+        let mut results = vec![self.action_result(
+            project,
+            "open_editor",
+            format!("在编辑器中打开 {}", project.name),
+            format!("{} {}", self.settings.editor, root),
+            ResultType::Command,
+            ActionData::Custom {
+                plugin: "dev_context".to_string(),
+                data: encode(&DevAction::OpenInEditor { root: root.clone() }),
+            },
+        )];
+
+        if let Some(url) = remote_url(&project.root) {
+            results.push(self.action_result(
+                project,
+                "open_remote",
+                format!("打开远程仓库 {}", project.name),
+                url.clone(),
+                ResultType::Command,
+                ActionData::OpenUrl { url },
+            ));
+        }
+
+        for (name, command) in project_commands(&project.root) {
+            results.push(self.action_result(
+                project,
+                &format!("run:{}", name),
+                format!("运行 {}", name),
+                command.clone(),
+                ResultType::Command,
+                ActionData::Custom {
+                    plugin: "dev_context".to_string(),
+                    data: encode(&DevAction::RunCommand { root: root.clone(), name, command }),
+                },
+            ));
+        }
+
+        results
+    }
+
+    /// 构造单个动作结果
+    fn action_result(
+        &self,
+        project: &Project,
+        key: &str,
+        title: String,
+        description: String,
+        result_type: ResultType,
+        action: ActionData,
+    ) -> SearchResult {
+        SearchResult::new(
+            format!("dev_context:{}:{}", project.root.display(), key),
+            title,
+            description,
+            result_type,
+            90,
+            action,
+        )
+    }
+
+    /// 执行项目动作
+    fn run_action(&self, action: DevAction) -> Result<()> {
+        match action {
+            DevAction::OpenInEditor { root } => {
+                log::info!("在编辑器中打开项目: {}", root);
+                std::process::Command::new("cmd")
+                    .args(["/c", &self.settings.editor, &root])
+                    .spawn()?;
+            },
+            DevAction::RunCommand { root, name, command } => {
+                log::info!("在 {} 中运行 {}: {}", root, name, command);
+                // 在新的控制台窗口中运行，保留输出
+                std::process::Command::new("cmd")
+                    .args(["/c", "start", &format!("werun: {}", name), "cmd", "/k", &command])
+                    .current_dir(&root)
+                    .spawn()?;
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Project {
+    /// 以目录创建项目
+    fn at(root: &Path) -> Self {
+        let name = root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| root.display().to_string());
+        Self { name, root: root.to_path_buf() }
+    }
+}
+
+/// 按标题或说明包含 `filter` 过滤动作
+fn filter_actions(mut results: Vec<SearchResult>, filter: &str) -> Vec<SearchResult> {
+    let filter = filter.to_lowercase();
+    results.retain(|r| {
+        filter.is_empty()
+            || r.title.to_lowercase().contains(&filter)
+            || r.description.to_lowercase().contains(&filter)
+    });
+    results
+}
+
+/// 序列化项目动作
+fn encode(action: &DevAction) -> String {
+    serde_json::to_string(action).unwrap_or_default()
+}
+
+/// 从窗口标题中识别项目
+///
+/// 依次尝试：标题中包含已配置项目的目录名（编辑器标题通常为 "文件 - 项目 - 编辑器"），
+/// 标题中包含存在的绝对路径（终端标题），向上查找项目根目录
+fn project_from_title(title: &str, projects: &[PathBuf]) -> Option<Project> {
+    if let Some(root) = projects.iter().find(|root| {
+        root.file_name().is_some_and(|name| contains_name(title, &name.to_string_lossy()))
+    }) {
+        return Some(Project::at(root));
+    }
+
+    title
+        .split(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+        .map(|part| part.trim_end_matches([':', '>', ',']))
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute() && path.exists())
+        .find_map(|path| find_root(&path))
+}
+
+/// 标题中是否包含完整的项目名（前后不与字母、数字、`-`、`_` 相连）
+fn contains_name(title: &str, name: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let title = title.to_lowercase();
+    let name = name.to_lowercase();
+
+    title.match_indices(&name).any(|(start, _)| {
+        let before = title[..start].chars().next_back();
+        let after = title[start + name.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+/// 向上查找包含项目标记文件的目录
+fn find_root(path: &Path) -> Option<Project> {
+    path.ancestors()
+        .find(|dir| ROOT_MARKERS.iter().any(|marker| dir.join(marker).exists()))
+        .map(Project::at)
+}
+
+/// 读取项目的远程仓库网页地址（origin）
+fn remote_url(root: &Path) -> Option<String> {
+    let config = std::fs::read_to_string(root.join(".git").join("config")).ok()?;
+    parse_origin_url(&config).map(|url| to_web_url(&url))
+}
+
+/// 从 git 配置中解析 origin 地址
+fn parse_origin_url(config: &str) -> Option<String> {
+    let mut in_origin = false;
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == "[remote \"origin\"]";
+        } else if in_origin {
+            if let Some(url) = line.strip_prefix("url").map(str::trim) {
+                return url.strip_prefix('=').map(|u| u.trim().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// 将 git 远程地址转换为网页地址
+///
+/// `git@github.com:user/repo.git` -> `https://github.com/user/repo`
+fn to_web_url(remote: &str) -> String {
+    let url = match remote.strip_prefix("git@") {
+        Some(rest) => format!("https://{}", rest.replacen(':', "/", 1)),
+        None => remote.replacen("ssh://git@", "https://", 1),
+    };
+    url.trim_end_matches(".git").to_string()
+}
+
+/// 读取项目 `werun.toml` 中的命令
+fn project_commands(root: &Path) -> BTreeMap<String, String> {
+    std::fs::read_to_string(root.join(PROJECT_FILE))
+        .ok()
+        .and_then(|content| match toml::from_str::<ProjectFile>(&content) {
+            Ok(file) => Some(file.commands),
+            Err(e) => {
+                log::warn!("解析 {} 失败: {:?}", PROJECT_FILE, e);
+                None
+            },
+        })
+        .unwrap_or_default()
+}
+
+impl Plugin for DevContextPlugin {
+    fn id(&self) -> &str {
+        "dev_context"
+    }
+
+    fn name(&self) -> &str {
+        "开发上下文"
+    }
+
+    fn description(&self) -> &str {
+        "识别当前项目并提供编辑器、仓库和构建命令操作"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("square-terminal")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化开发上下文插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let mut results = filter_actions(self.current_actions().to_vec(), rest.trim());
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    fn contextual_results(
+        &self,
+        foreground: &ForegroundInfo,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        if !foreground.is_process(EDITOR_PROCESSES) {
+            return Ok(Vec::new());
+        }
+
+        // 只按唤出前的编辑器窗口标题识别，不回退到配置的第一个项目
+        let projects: Vec<PathBuf> = self.settings.projects.iter().map(PathBuf::from).collect();
+        let mut results = project_from_title(&foreground.title, &projects)
+            .map(|project| self.project_actions(&project))
+            .unwrap_or_default();
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::Custom { data, .. } => self.run_action(serde_json::from_str(data)?),
+            ActionData::OpenUrl { url } => {
+                shell::open_url(url)?;
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.actions = OnceLock::new();
+        self.initialize()
+    }
+
+    fn on_activate(&mut self) {
+        // 前台窗口可能已经切换，下次查询时重新检测项目
+        self.actions = OnceLock::new();
+    }
+}
+
+impl Default for DevContextPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_origin_url() {
+        let config = "[core]\n\tbare = false\n[remote \"origin\"]\n\turl = \
+                      git@github.com:linruohan/werun.git\n";
+        let url = parse_origin_url(config).unwrap();
+        assert_eq!(to_web_url(&url), "https://github.com/linruohan/werun");
+        assert_eq!(to_web_url("https://github.com/a/b.git"), "https://github.com/a/b");
+    }
+
+    #[test]
+    fn test_project_from_editor_title() {
+        let projects = vec![PathBuf::from("/code/werun"), PathBuf::from("/code/other")];
+        let project =
+            project_from_title("main.rs - werun - Visual Studio Code", &projects).unwrap();
+        assert_eq!(project.name, "werun");
+        assert_eq!(project.root, PathBuf::from("/code/werun"));
+        assert!(project_from_title("Inbox - Outlook", &projects).is_none());
+        assert!(project_from_title("werun-cli - Visual Studio Code", &projects).is_none());
+    }
+}
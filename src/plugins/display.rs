@@ -0,0 +1,357 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// 显示与电源插件
+///
+/// `display` 列出夜间模式开关、投影模式和各显示器支持的分辨率与刷新率（可输入 `1080`、
+/// `144` 等过滤）；`power` 列出电源计划并切换；`bright 60` 将亮度设为 60%
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    platform::{
+        display::{self, DisplayMode, Monitor, Projection},
+        power,
+    },
+    utils::night_light,
+};
+
+/// 显示器的查询关键字
+const DISPLAY_KEYWORD: &str = "display";
+
+/// 电源计划的查询关键字
+const POWER_KEYWORD: &str = "power";
+
+/// 亮度的查询关键字
+const BRIGHTNESS_KEYWORD: &str = "bright";
+
+/// 全部关键字
+pub const KEYWORDS: &[&str] = &[DISPLAY_KEYWORD, POWER_KEYWORD, BRIGHTNESS_KEYWORD];
+
+/// 显示与电源动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum DisplayAction {
+    /// 切换电源计划
+    SetPowerPlan { id: String, name: String },
+    /// 设置亮度
+    SetBrightness { percent: u8 },
+    /// 切换夜间模式
+    ToggleNightLight,
+    /// 切换投影模式（`Projection::ALL` 中的序号）
+    SetProjection { index: usize },
+    /// 切换显示模式
+    SetMode { device: String, width: u32, height: u32, refresh_rate: u32 },
+}
+
+/// 解析亮度参数（`60` 或 `60%`），超出 0 到 100 时返回 `None`
+pub fn parse_brightness(text: &str) -> Option<u8> {
+    let percent: u8 = text.trim().trim_end_matches('%').trim().parse().ok()?;
+    (percent <= 100).then_some(percent)
+}
+
+/// 显示模式是否匹配过滤文本，如 `1080`、`144hz`、`2560x1440`
+pub fn mode_matches(mode: &DisplayMode, filter: &str) -> bool {
+    let filter = filter.to_lowercase().replace(['×', '*'], "x").replace(' ', "");
+    let label = format!("{}x{}@{}hz", mode.width, mode.height, mode.refresh_rate);
+    label.contains(&filter)
+}
+
+/// 显示与电源插件
+pub struct DisplayPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl DisplayPlugin {
+    /// 创建新的显示与电源插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 夜间模式、投影模式和显示模式
+    fn display_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let filter = filter.to_lowercase();
+        let mut results = Vec::new();
+
+        if let Some(enabled) = night_light::is_enabled() {
+            let title = if enabled { "关闭夜间模式" } else { "开启夜间模式" };
+            if title.contains(&filter) || "night light".contains(&filter) {
+                results.push(self.result(
+                    "display:night_light".to_string(),
+                    title.to_string(),
+                    format!("夜间模式当前{}", if enabled { "已开启" } else { "已关闭" }),
+                    1000,
+                    DisplayAction::ToggleNightLight,
+                ));
+            }
+        }
+
+        for (index, projection) in Projection::ALL.iter().enumerate() {
+            let label = projection.label();
+            let keywords = ["pc screen only", "duplicate", "extend", "second screen only"][index];
+            if label.contains(&filter) || keywords.contains(&filter) {
+                results.push(self.result(
+                    format!("display:projection:{}", index),
+                    format!("投影: {}", label),
+                    "切换多显示器的投影模式".to_string(),
+                    950u32.saturating_sub(index as u32),
+                    DisplayAction::SetProjection { index },
+                ));
+            }
+        }
+
+        for monitor in display::monitors() {
+            results.extend(self.mode_results(&monitor, &filter));
+        }
+
+        results.truncate(limit);
+        results
+    }
+
+    /// 显示器的各显示模式，当前模式在前
+    fn mode_results(&self, monitor: &Monitor, filter: &str) -> Vec<SearchResult> {
+        let primary = if monitor.is_primary { " · 主显示器" } else { "" };
+        let name_matches = monitor.name.to_lowercase().contains(filter);
+
+        monitor
+            .modes
+            .iter()
+            .filter(|mode| name_matches || mode_matches(mode, filter))
+            .enumerate()
+            .map(|(index, mode)| {
+                let is_current = *mode == monitor.current;
+                let state = if is_current { "当前模式" } else { "按 Enter 切换" };
+                self.result(
+                    format!("display:mode:{}:{}", monitor.device, mode),
+                    mode.to_string(),
+                    format!("{}{} · {}", monitor.name, primary, state),
+                    if is_current { 900 } else { 800u32.saturating_sub(index as u32) },
+                    DisplayAction::SetMode {
+                        device: monitor.device.clone(),
+                        width: mode.width,
+                        height: mode.height,
+                        refresh_rate: mode.refresh_rate,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// 电源计划
+    fn power_results(&self, filter: &str) -> Vec<SearchResult> {
+        let filter = filter.to_lowercase();
+        power::plans()
+            .into_iter()
+            .filter(|plan| plan.name.to_lowercase().contains(&filter))
+            .enumerate()
+            .map(|(index, plan)| {
+                let description = if plan.is_active {
+                    "当前电源计划"
+                } else {
+                    "按 Enter 切换到此电源计划"
+                };
+                self.result(
+                    format!("display:power:{}", plan.id),
+                    plan.name.clone(),
+                    description.to_string(),
+                    1000u32.saturating_sub(index as u32),
+                    DisplayAction::SetPowerPlan { id: plan.id, name: plan.name },
+                )
+            })
+            .collect()
+    }
+
+    /// 亮度：无参数时显示当前亮度，有参数时设置亮度
+    fn brightness_results(&self, argument: &str) -> Vec<SearchResult> {
+        let current = display::brightness();
+
+        if argument.is_empty() {
+            let Some(current) = current else {
+                return vec![SearchResult::new(
+                    "display:brightness".to_string(),
+                    "无法读取亮度".to_string(),
+                    "屏幕不支持调节亮度".to_string(),
+                    ResultType::SystemCommand,
+                    1000,
+                    ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+                )];
+            };
+            return [25, 50, 75, 100]
+                .into_iter()
+                .enumerate()
+                .map(|(index, percent)| {
+                    self.result(
+                        format!("display:brightness:{}", percent),
+                        format!("将亮度设为 {}%", percent),
+                        format!("当前亮度 {}%", current),
+                        1000u32.saturating_sub(index as u32),
+                        DisplayAction::SetBrightness { percent },
+                    )
+                })
+                .collect();
+        }
+
+        match parse_brightness(argument) {
+            Some(percent) => vec![self.result(
+                "display:brightness".to_string(),
+                format!("将亮度设为 {}%", percent),
+                match current {
+                    Some(current) => format!("当前亮度 {}%", current),
+                    None => "屏幕可能不支持调节亮度".to_string(),
+                },
+                1000,
+                DisplayAction::SetBrightness { percent },
+            )],
+            None => Vec::new(),
+        }
+    }
+
+    /// 构造结果项
+    fn result(
+        &self,
+        id: String,
+        title: String,
+        description: String,
+        score: u32,
+        action: DisplayAction,
+    ) -> SearchResult {
+        SearchResult::new(
+            id,
+            title,
+            description,
+            ResultType::SystemCommand,
+            score,
+            ActionData::Custom {
+                plugin: self.id().to_string(),
+                data: serde_json::to_string(&action).unwrap_or_default(),
+            },
+        )
+    }
+
+    /// 执行显示与电源动作
+    fn run_action(&self, action: DisplayAction) -> Result<()> {
+        match action {
+            DisplayAction::SetPowerPlan { id, name } => {
+                power::set_active(&id)?;
+                log::info!("已切换电源计划: {}", name);
+            },
+            DisplayAction::SetBrightness { percent } => {
+                display::set_brightness(percent)?;
+                log::info!("已将亮度设为 {}%", percent);
+            },
+            DisplayAction::ToggleNightLight => {
+                let enabled = night_light::toggle()?;
+                log::info!("夜间模式{}", if enabled { "已开启" } else { "已关闭" });
+            },
+            DisplayAction::SetProjection { index } => {
+                let Some(&projection) = Projection::ALL.get(index) else {
+                    return Ok(());
+                };
+                display::set_projection(projection)?;
+                log::info!("已切换投影模式: {}", projection.label());
+            },
+            DisplayAction::SetMode { device, width, height, refresh_rate } => {
+                let mode = DisplayMode { width, height, refresh_rate };
+                display::set_mode(&device, mode)?;
+                log::info!("已将 {} 切换到 {}", device, mode);
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Plugin for DisplayPlugin {
+    fn id(&self) -> &str {
+        "display"
+    }
+
+    fn name(&self) -> &str {
+        "显示与电源"
+    }
+
+    fn description(&self) -> &str {
+        "切换电源计划、亮度、夜间模式、分辨率和投影模式"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("settings")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化显示与电源插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim_start();
+        for keyword in KEYWORDS {
+            let Some(rest) = query.strip_prefix(keyword) else {
+                continue;
+            };
+            if !rest.is_empty() && !rest.starts_with(' ') {
+                continue;
+            }
+
+            let rest = rest.trim();
+            return Ok(match *keyword {
+                DISPLAY_KEYWORD => self.display_results(rest, limit),
+                POWER_KEYWORD => self.power_results(rest),
+                _ => self.brightness_results(rest),
+            });
+        }
+        Ok(Vec::new())
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.run_action(serde_json::from_str(data)?)
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for DisplayPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brightness_and_mode_filter() {
+        assert_eq!(parse_brightness("60"), Some(60));
+        assert_eq!(parse_brightness(" 100% "), Some(100));
+        assert_eq!(parse_brightness("120"), None);
+
+        let mode = DisplayMode { width: 2560, height: 1440, refresh_rate: 144 };
+        assert_eq!(mode.to_string(), "2560×1440 @ 144 Hz");
+        assert!(mode_matches(&mode, ""));
+        assert!(mode_matches(&mode, "1440"));
+        assert!(mode_matches(&mode, "144Hz"));
+        assert!(mode_matches(&mode, "2560×1440"));
+        assert!(!mode_matches(&mode, "1080"));
+    }
+}
@@ -0,0 +1,300 @@
+use anyhow::Result;
+use chrono::{Local, TimeZone};
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        encoding::{self, Jwt},
+    },
+};
+
+/// 查询关键字
+const KEYWORD: &str = "enc";
+
+/// 以时间戳表示的 JWT 声明
+const TIME_CLAIMS: &[&str] = &["exp", "iat", "nbf", "auth_time"];
+
+/// 编解码转换
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+    /// JWT 解码
+    JwtDecode,
+    /// Base64 解码
+    Base64Decode,
+    /// URL 解码
+    UrlDecode,
+    /// HTML 反转义
+    HtmlUnescape,
+    /// 十六进制转文本
+    HexDecode,
+    /// Base64 编码
+    Base64Encode,
+    /// URL 编码
+    UrlEncode,
+    /// HTML 转义
+    HtmlEscape,
+    /// 文本转十六进制
+    HexEncode,
+}
+
+impl Transform {
+    /// 全部转换，解码在前
+    pub const ALL: [Transform; 9] = [
+        Transform::JwtDecode,
+        Transform::Base64Decode,
+        Transform::UrlDecode,
+        Transform::HtmlUnescape,
+        Transform::HexDecode,
+        Transform::Base64Encode,
+        Transform::UrlEncode,
+        Transform::HtmlEscape,
+        Transform::HexEncode,
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Transform::JwtDecode => "JWT 解码",
+            Transform::Base64Decode => "Base64 解码",
+            Transform::UrlDecode => "URL 解码",
+            Transform::HtmlUnescape => "HTML 反转义",
+            Transform::HexDecode => "十六进制转文本",
+            Transform::Base64Encode => "Base64 编码",
+            Transform::UrlEncode => "URL 编码",
+            Transform::HtmlEscape => "HTML 转义",
+            Transform::HexEncode => "文本转十六进制",
+        }
+    }
+
+    /// 转换输入，不适用或结果与输入相同时返回 `None`
+    pub fn apply(&self, input: &str) -> Option<String> {
+        let output = match self {
+            Transform::JwtDecode => {
+                encoding::jwt_decode(input).map(|jwt| compact_json(&jwt.payload))
+            },
+            Transform::Base64Decode => encoding::base64_decode(input),
+            Transform::UrlDecode => encoding::url_decode(input),
+            Transform::HtmlUnescape => Some(encoding::html_unescape(input)),
+            Transform::HexDecode => encoding::hex_decode(input),
+            Transform::Base64Encode => Some(encoding::base64_encode(input)),
+            Transform::UrlEncode => Some(encoding::url_encode(input)),
+            Transform::HtmlEscape => Some(encoding::html_escape(input)),
+            Transform::HexEncode => Some(encoding::hex_encode(input)),
+        }?;
+        (output != input).then_some(output)
+    }
+
+    /// 输入看起来是否就是这种编码的结果
+    fn is_likely(&self, input: &str) -> bool {
+        let input = input.trim();
+        match self {
+            Transform::JwtDecode | Transform::HtmlUnescape => true,
+            Transform::Base64Decode => {
+                input.len() >= 4
+                    && input.bytes().all(|b| b.is_ascii_alphanumeric() || b"+/-_=".contains(&b))
+            },
+            Transform::UrlDecode => input
+                .as_bytes()
+                .windows(3)
+                .any(|w| w[0] == b'%' && w[1].is_ascii_hexdigit() && w[2].is_ascii_hexdigit()),
+            Transform::HexDecode => input.len() >= 4,
+            _ => false,
+        }
+    }
+}
+
+/// 按可能性排列适用的转换及其结果：像是某种编码结果的解码在前，其余解码和编码在后
+pub fn detect(input: &str) -> Vec<(Transform, String)> {
+    let mut applicable: Vec<(bool, Transform, String)> = Transform::ALL
+        .iter()
+        .filter_map(|transform| {
+            let output = transform.apply(input)?;
+            Some((transform.is_likely(input), *transform, output))
+        })
+        .collect();
+    // 稳定排序，同一档内保持 `Transform::ALL` 的顺序
+    applicable.sort_by_key(|(likely, ..)| !likely);
+    applicable.into_iter().map(|(_, transform, output)| (transform, output)).collect()
+}
+
+/// 单行 JSON
+fn compact_json(value: &serde_json::Value) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// JSON 对象的预览行，时间戳声明附上本地时间
+fn claim_rows(value: &serde_json::Value) -> Vec<PreviewRow> {
+    let Some(object) = value.as_object() else {
+        return vec![PreviewRow::new("值", compact_json(value))];
+    };
+
+    object
+        .iter()
+        .map(|(key, value)| {
+            let text = match value {
+                serde_json::Value::String(text) => text.clone(),
+                value => compact_json(value),
+            };
+            let time = value
+                .as_i64()
+                .filter(|_| TIME_CLAIMS.contains(&key.as_str()))
+                .and_then(|secs| Local.timestamp_opt(secs, 0).single());
+            match time {
+                Some(time) => {
+                    PreviewRow::new(key, format!("{} ({})", time.format("%Y-%m-%d %H:%M:%S"), text))
+                },
+                None => PreviewRow::new(key, text),
+            }
+        })
+        .collect()
+}
+
+/// JWT 的预览：头部、载荷，并提示未校验签名
+fn jwt_preview(jwt: &Jwt) -> Preview {
+    Preview::new()
+        .section("头部", claim_rows(&jwt.header))
+        .section("载荷", claim_rows(&jwt.payload))
+        .section("签名", vec![PreviewRow::new("状态", "未校验")])
+}
+
+/// 编解码插件
+///
+/// `enc <文本>` 列出 Base64、URL、HTML 实体、十六进制的编码和解码结果以及 JWT 的内容，
+/// 根据输入的特征把最可能需要的转换排在最前，Enter 复制结果
+pub struct EncodingPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl EncodingPlugin {
+    /// 创建新的编解码插件
+    pub fn new() -> Self {
+        Self { enabled: true, clipboard_manager: ClipboardManager::new() }
+    }
+}
+
+impl Plugin for EncodingPlugin {
+    fn id(&self) -> &str {
+        "enc"
+    }
+
+    fn name(&self) -> &str {
+        "编解码"
+    }
+
+    fn description(&self) -> &str {
+        "Base64、URL、HTML 实体、十六进制编解码和 JWT 解码"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化编解码插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+        let input = rest.trim();
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let results = detect(input)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (transform, output))| {
+                let jwt = (transform == Transform::JwtDecode)
+                    .then(|| encoding::jwt_decode(input))
+                    .flatten();
+                // JWT 复制格式化后的头部和载荷
+                let copy_text = match &jwt {
+                    Some(jwt) => serde_json::to_string_pretty(&serde_json::json!({
+                        "header": jwt.header,
+                        "payload": jwt.payload,
+                    }))
+                    .unwrap_or_default(),
+                    None => output.clone(),
+                };
+
+                let result = SearchResult::new(
+                    format!("enc:{:?}", transform),
+                    output,
+                    format!("{} · 按 Enter 复制", transform.label()),
+                    ResultType::Custom("enc".to_string()),
+                    1000u32.saturating_sub(index as u32),
+                    ActionData::CopyToClipboard { text: copy_text },
+                );
+                match &jwt {
+                    Some(jwt) => result.with_preview(jwt_preview(jwt)),
+                    None => result,
+                }
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            self.clipboard_manager.set_text(text)?;
+            log::info!("复制编解码结果: {}", result.id);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for EncodingPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_ranks_likely_decoding_first() {
+        let first = |input: &str| detect(input).first().map(|(transform, _)| *transform);
+
+        assert_eq!(first("aGVsbG8gd29ybGQ="), Some(Transform::Base64Decode));
+        assert_eq!(first("a%20b%2Fc"), Some(Transform::UrlDecode));
+        assert_eq!(first("&lt;div&gt;"), Some(Transform::HtmlUnescape));
+        assert_eq!(first("68656c6c6f"), Some(Transform::HexDecode));
+        assert_eq!(first("hello world"), Some(Transform::Base64Encode));
+
+        let (_, payload) = detect("eyJhbGciOiJub25lIn0.eyJzdWIiOiJ3ZXJ1biJ9.")[0].clone();
+        assert_eq!(payload, r#"{"sub":"werun"}"#);
+        assert!(detect("plain").iter().all(|(t, _)| *t != Transform::HtmlUnescape));
+    }
+}
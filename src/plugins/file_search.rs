@@ -1,95 +1,272 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
 
 use anyhow::Result;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 
 /// 文件搜索插件
 ///
 /// 提供文件搜索功能
-use crate::core::plugin::Plugin;
+use crate::core::plugin::{ForegroundInfo, Plugin, PluginIcon};
 use crate::{
-    core::search::{ActionData, ResultType, SearchResult},
-    utils::fuzzy::fuzzy_match,
+    core::{
+        cache::global_caches,
+        config_manager::global_config,
+        progress::{global_progress, ProgressTask},
+        query::{FilterKind, ParsedQuery},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction, SortKeys},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        file_index::{FileIndex, FileInfo, PathFilter},
+        fuzzy::FuzzyMatcher,
+        recent_docs::{self, RecentDocument},
+        shell,
+    },
 };
 
-/// 文件信息
-#[derive(Clone, Debug)]
-pub struct FileInfo {
-    /// 文件名称
-    pub name: String,
-    /// 完整路径
-    pub path: String,
-    /// 文件大小
-    pub size: u64,
-    /// 是否目录
-    pub is_dir: bool,
-    /// 修改时间
-    pub modified: std::time::SystemTime,
+/// 每批写入索引的文件数量
+const BATCH_SIZE: usize = 500;
+
+/// 每次查询从索引取出的最大候选数量
+const MAX_CANDIDATES: usize = 1000;
+
+/// 最多读取的最近文档数量
+const MAX_RECENT: usize = 200;
+
+/// 显示最近文档的查询关键字，可跟随过滤词，如 `recent report`
+const RECENT_KEYWORD: &str = "recent";
+
+/// 拼写纠正最多使用的文件名数量（最近修改的文件）
+const MAX_VOCABULARY_FILES: usize = 20_000;
+
+/// 在文件夹中打开终端的动作前缀
+const TERMINAL_PREFIX: &str = "terminal:";
+
+/// 用 Everything 搜索的动作前缀
+const EVERYTHING_PREFIX: &str = "everything:";
+
+/// Everything 的默认安装位置
+const EVERYTHING_PATHS: &[&str] = &[
+    r"C:\Program Files\Everything\Everything.exe",
+    r"C:\Program Files (x86)\Everything\Everything.exe",
+];
+
+/// 索引状态
+#[derive(Default)]
+pub struct IndexProgress {
+    /// 是否正在扫描
+    indexing: AtomicBool,
+    /// 当前扫描编号
+    scan_id: AtomicI64,
 }
 
-/// 文件搜索插件
-pub struct FileSearchPlugin {
-    /// 是否启用
-    enabled: bool,
-    /// 索引的文件列表
-    files: Arc<Mutex<Vec<FileInfo>>>,
-    /// 搜索路径
-    search_paths: Vec<String>,
-    /// 忽略的目录
-    ignore_dirs: Vec<String>,
+impl IndexProgress {
+    /// 是否正在扫描
+    pub fn is_indexing(&self) -> bool {
+        self.indexing.load(Ordering::Relaxed)
+    }
+}
+
+/// 搜索根目录
+#[derive(Clone, Debug, PartialEq)]
+struct SearchRoot {
+    /// 目录路径
+    path: PathBuf,
     /// 最大递归深度
     max_depth: usize,
 }
 
-impl FileSearchPlugin {
-    /// 创建新的文件搜索插件
-    pub fn new() -> Self {
-        let search_paths = vec![
-            dirs::desktop_dir().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
-            dirs::document_dir().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
-            dirs::download_dir().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
-        ];
-
-        let ignore_dirs = vec![
-            "node_modules".to_string(),
-            ".git".to_string(),
-            "target".to_string(),
-            "dist".to_string(),
-            "build".to_string(),
-            ".idea".to_string(),
-            ".vscode".to_string(),
-        ];
+/// 扫描规则（来自配置）
+#[derive(Clone, Debug, PartialEq)]
+struct ScanRules {
+    /// 搜索根目录
+    roots: Vec<SearchRoot>,
+    /// 忽略的 glob 模式
+    ignore_patterns: Vec<String>,
+    /// 是否包含隐藏文件
+    include_hidden: bool,
+}
+
+impl ScanRules {
+    /// 从配置读取扫描规则
+    ///
+    /// 未配置搜索路径时使用桌面/文档/下载目录
+    fn from_config() -> Self {
+        let search = global_config().get_config().search;
+
+        let mut paths: Vec<String> =
+            search.file_search_paths.into_iter().filter(|p| !p.trim().is_empty()).collect();
+        if paths.is_empty() {
+            paths = [dirs::desktop_dir(), dirs::document_dir(), dirs::download_dir()]
+                .into_iter()
+                .flatten()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect();
+        }
+
+        let roots = paths
+            .into_iter()
+            .map(|path| SearchRoot {
+                max_depth: search
+                    .file_search_depth_overrides
+                    .get(&path)
+                    .copied()
+                    .unwrap_or(search.file_search_max_depth),
+                path: PathBuf::from(path),
+            })
+            .collect();
 
         Self {
-            enabled: true,
-            files: Arc::new(Mutex::new(Vec::new())),
-            search_paths,
-            ignore_dirs,
-            max_depth: 3,
+            roots,
+            ignore_patterns: search.file_ignore_patterns,
+            include_hidden: search.include_hidden_files,
+        }
+    }
+}
+
+/// 文件扫描器
+///
+/// 全量扫描、文件监视和查询结果过滤共用同一套忽略/深度规则
+#[derive(Clone)]
+struct Scanner {
+    /// 扫描规则
+    rules: ScanRules,
+    /// 编译后的忽略模式
+    ignore: GlobSet,
+}
+
+impl Scanner {
+    /// 根据规则创建扫描器，无效的 glob 模式会被跳过
+    fn new(rules: ScanRules) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &rules.ignore_patterns {
+            match GlobBuilder::new(pattern).case_insensitive(cfg!(target_os = "windows")).build() {
+                Ok(glob) => {
+                    builder.add(glob);
+                },
+                Err(e) => log::warn!("无效的忽略模式 {}: {}", pattern, e),
+            }
+        }
+
+        let ignore = builder.build().unwrap_or_else(|e| {
+            log::warn!("编译忽略模式失败: {}", e);
+            GlobSet::empty()
+        });
+
+        Self { rules, ignore }
+    }
+
+    /// 查找路径所属的根目录（取最具体的一个）和相对路径
+    fn root_of<'a>(&self, path: &'a Path) -> Option<(&SearchRoot, &'a Path)> {
+        self.rules
+            .roots
+            .iter()
+            .filter_map(|root| path.strip_prefix(&root.path).ok().map(|rel| (root, rel)))
+            .max_by_key(|(root, _)| root.path.components().count())
+    }
+
+    /// 相对路径是否被忽略
+    ///
+    /// 忽略模式依次匹配每一级名称和整个相对路径；未开启隐藏文件时跳过点号开头的名称
+    fn is_ignored(&self, rel: &Path) -> bool {
+        if self.ignore.is_match(rel) {
+            return true;
         }
+
+        rel.components().any(|c| {
+            let name = c.as_os_str();
+            self.ignore.is_match(name)
+                || (!self.rules.include_hidden && name.to_string_lossy().starts_with('.'))
+        })
     }
 
-    /// 扫描文件
-    fn scan_files(&self) -> Result<Vec<FileInfo>> {
-        let mut files = Vec::new();
+    /// 路径是否应出现在索引和结果中（不访问文件系统）
+    fn allows(&self, path: &Path) -> bool {
+        match self.root_of(path) {
+            Some((root, rel)) => {
+                let depth = rel.components().count();
+                depth > 0 && depth <= root.max_depth && !self.is_ignored(rel)
+            },
+            None => false,
+        }
+    }
+
+    /// 是否为带隐藏属性的文件（仅 Windows，点号开头的名称由 `is_ignored` 处理）
+    fn is_hidden_attribute(&self, path: &Path) -> bool {
+        if self.rules.include_hidden {
+            return false;
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::fs::MetadataExt;
 
-        for path_str in &self.search_paths {
-            let path = std::path::Path::new(path_str);
-            if path.exists() {
-                self.scan_directory(path, &mut files, self.max_depth)?;
+            const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+            std::fs::symlink_metadata(path)
+                .map(|m| m.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+                .unwrap_or(false)
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            let _ = path;
+            false
+        }
+    }
+
+    /// 全量扫描所有根目录，分批写入索引
+    ///
+    /// 取消时保留已写入的文件，不清理本轮未扫描到的旧记录
+    fn scan(&self, index: &FileIndex, progress: &IndexProgress, task: &ProgressTask) -> Result<()> {
+        // 以上次索引的文件数作为预计总数
+        task.set_total(index.count()?);
+
+        let scan_id = index.begin_scan()?;
+        progress.scan_id.store(scan_id, Ordering::Relaxed);
+
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        for root in &self.rules.roots {
+            if root.path.exists() {
+                self.scan_directory(
+                    &root.path,
+                    root.max_depth,
+                    index,
+                    progress,
+                    Some(task),
+                    &mut batch,
+                )?;
             }
         }
+        self.flush(index, progress, Some(task), &mut batch)?;
+
+        if task.is_cancelled() {
+            log::info!("文件索引已取消: 已扫描 {} 个文件", task.done());
+            return Ok(());
+        }
 
-        Ok(files)
+        let removed = index.finish_scan(scan_id)?;
+        log::info!("文件索引完成: 扫描 {} 个文件，移除 {} 个", task.done(), removed);
+        Ok(())
     }
 
     /// 递归扫描目录
     fn scan_directory(
         &self,
-        path: &std::path::Path,
-        files: &mut Vec<FileInfo>,
+        path: &Path,
         depth: usize,
+        index: &FileIndex,
+        progress: &IndexProgress,
+        task: Option<&ProgressTask>,
+        batch: &mut Vec<FileInfo>,
     ) -> Result<()> {
-        if depth == 0 {
+        if depth == 0 || task.is_some_and(|t| t.is_cancelled()) {
             return Ok(());
         }
 
@@ -97,37 +274,24 @@ impl FileSearchPlugin {
             for entry in entries.flatten() {
                 let path = entry.path();
 
-                // 检查是否应该忽略
-                if let Some(name) = path.file_name() {
-                    let name_str = name.to_string_lossy().to_string();
-                    if self.ignore_dirs.contains(&name_str) {
-                        continue;
-                    }
+                // 检查是否应该忽略（父目录已检查过，只需检查当前项）
+                if !self.allows(&path) || self.is_hidden_attribute(&path) {
+                    continue;
                 }
 
-                let metadata = entry.metadata().ok();
-
-                let name =
-                    path.file_name().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
-
-                let is_dir = path.is_dir();
-                let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
-                let modified = metadata
-                    .as_ref()
-                    .and_then(|m| m.modified().ok())
-                    .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+                let Some(file) = FileInfo::from_path(&path) else {
+                    continue;
+                };
+                let is_dir = file.is_dir;
+                batch.push(file);
 
-                files.push(FileInfo {
-                    name: name.clone(),
-                    path: path.to_string_lossy().to_string(),
-                    size,
-                    is_dir,
-                    modified,
-                });
+                if batch.len() >= BATCH_SIZE {
+                    self.flush(index, progress, task, batch)?;
+                }
 
                 // 递归扫描子目录
                 if is_dir && depth > 1 {
-                    let _ = self.scan_directory(&path, files, depth - 1);
+                    self.scan_directory(&path, depth - 1, index, progress, task, batch)?;
                 }
             }
         }
@@ -135,6 +299,157 @@ impl FileSearchPlugin {
         Ok(())
     }
 
+    /// 写入一批文件并更新进度
+    fn flush(
+        &self,
+        index: &FileIndex,
+        progress: &IndexProgress,
+        task: Option<&ProgressTask>,
+        batch: &mut Vec<FileInfo>,
+    ) -> Result<()> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        index.upsert(batch, progress.scan_id.load(Ordering::Relaxed))?;
+        if let Some(task) = task {
+            task.advance(batch.len());
+            log::debug!("文件索引进度: 已扫描 {} 个文件", task.done());
+        }
+        batch.clear();
+        Ok(())
+    }
+
+    /// 处理文件系统变更事件
+    ///
+    /// 路径仍存在则写入（目录连同子项），不存在则从索引移除
+    fn apply_change(&self, path: &Path, index: &FileIndex, progress: &IndexProgress) {
+        let Some((root, rel)) = self.root_of(path) else {
+            return;
+        };
+        let depth = rel.components().count();
+
+        if !self.allows(path) || self.is_hidden_attribute(path) {
+            return;
+        }
+
+        let result = match FileInfo::from_path(path) {
+            Some(file) => {
+                let is_dir = file.is_dir;
+                let mut batch = vec![file];
+                if is_dir {
+                    let _ = self.scan_directory(
+                        path,
+                        root.max_depth - depth,
+                        index,
+                        progress,
+                        None,
+                        &mut batch,
+                    );
+                }
+                index.upsert(&batch, progress.scan_id.load(Ordering::Relaxed))
+            },
+            None => index.remove(&path.to_string_lossy()).map(|_| ()),
+        };
+
+        if let Err(e) = result {
+            log::warn!("更新文件索引失败 {:?}: {:?}", path, e);
+        }
+    }
+}
+
+/// 文件搜索插件
+pub struct FileSearchPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 持久化文件索引
+    index: Option<Arc<FileIndex>>,
+    /// 索引进度
+    progress: Arc<IndexProgress>,
+    /// 扫描器（搜索路径、忽略模式和深度）
+    scanner: Scanner,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+    /// 文件监视器（保持存活以持续接收变更）
+    watcher: Mutex<Option<RecommendedWatcher>>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+    /// 最近文档，每次显示启动器后首次用到时读取
+    recent: OnceLock<Vec<RecentDocument>>,
+}
+
+impl FileSearchPlugin {
+    /// 创建新的文件搜索插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            index: None,
+            progress: Arc::new(IndexProgress::default()),
+            scanner: Scanner::new(ScanRules::from_config()),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+            watcher: Mutex::new(None),
+            clipboard_manager: ClipboardManager::new(),
+            recent: OnceLock::new(),
+        }
+    }
+
+    /// 索引状态
+    pub fn progress(&self) -> Arc<IndexProgress> {
+        self.progress.clone()
+    }
+
+    /// 在后台线程中对比文件系统和索引，补齐离线期间的变更
+    fn start_background_scan(&self, index: Arc<FileIndex>) {
+        if self.progress.indexing.swap(true, Ordering::SeqCst) {
+            log::info!("文件索引正在进行中，跳过");
+            return;
+        }
+
+        let scanner = self.scanner.clone();
+        let progress = self.progress.clone();
+        let task = global_progress().start("file_search", "正在索引文件");
+
+        std::thread::spawn(move || {
+            let started = std::time::Instant::now();
+            if let Err(e) = scanner.scan(&index, &progress, &task) {
+                log::error!("文件索引失败: {:?}", e);
+            }
+            task.finish();
+            progress.indexing.store(false, Ordering::SeqCst);
+            log::info!("文件索引耗时 {:?}", started.elapsed());
+        });
+    }
+
+    /// 监视搜索路径，增量更新索引
+    fn start_watcher(&self, index: Arc<FileIndex>) -> Result<()> {
+        let scanner = self.scanner.clone();
+        let roots: Vec<PathBuf> = scanner.rules.roots.iter().map(|r| r.path.clone()).collect();
+        let progress = self.progress.clone();
+
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+                Ok(event) => {
+                    if matches!(event.kind, EventKind::Access(_)) {
+                        return;
+                    }
+                    for path in &event.paths {
+                        scanner.apply_change(path, &index, &progress);
+                    }
+                },
+                Err(e) => log::warn!("文件监视错误: {:?}", e),
+            })?;
+
+        for root in roots.iter().filter(|root| root.exists()) {
+            if let Err(e) = watcher.watch(root, RecursiveMode::Recursive) {
+                log::warn!("监视目录 {:?} 失败: {:?}", root, e);
+            }
+        }
+
+        *self.watcher.lock().unwrap() = Some(watcher);
+        log::info!("已监视 {} 个文件搜索路径", roots.len());
+        Ok(())
+    }
+
     /// 格式化文件大小
     fn format_size(&self, size: u64) -> String {
         const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
@@ -154,6 +469,164 @@ impl FileSearchPlugin {
         std::process::Command::new("explorer").arg(path).spawn()?;
         Ok(())
     }
+
+    /// 在资源管理器中显示并选中
+    fn reveal_in_explorer(&self, path: &str) -> Result<()> {
+        shell::reveal_in_explorer(path)?;
+        Ok(())
+    }
+
+    /// 在 Everything 中搜索，索引之外的文件也能找到
+    fn search_everything(&self, query: &str) -> Result<()> {
+        let path = everything_path().ok_or_else(|| anyhow::anyhow!("未安装 Everything"))?;
+        std::process::Command::new(path).args(["-search", query]).spawn()?;
+        Ok(())
+    }
+
+    /// 在文件夹中打开命令提示符
+    fn open_terminal(&self, folder: &str) -> Result<()> {
+        std::process::Command::new("cmd")
+            .args(["/c", "start", "cmd"])
+            .current_dir(folder)
+            .spawn()?;
+        Ok(())
+    }
+
+    /// 资源管理器当前文件夹的操作
+    fn folder_results(&self, folder: &str) -> Vec<SearchResult> {
+        let name = Path::new(folder)
+            .file_name()
+            .map_or_else(|| folder.to_string(), |n| n.to_string_lossy().to_string());
+        let result = |suffix: &str, title: String, score: u32, action: ActionData| {
+            SearchResult::new(
+                format!("file_search:folder:{}:{}", suffix, folder),
+                title,
+                folder.to_string(),
+                ResultType::Folder,
+                score,
+                action,
+            )
+        };
+
+        vec![
+            result("terminal", format!("在 {} 中打开终端", name), 1000, ActionData::Custom {
+                plugin: self.id().to_string(),
+                data: format!("{}{}", TERMINAL_PREFIX, folder),
+            }),
+            result(
+                "copy",
+                format!("复制 {} 的路径", name),
+                990,
+                ActionData::CopyToClipboard { text: folder.to_string() },
+            ),
+        ]
+    }
+
+    /// 弹出系统的"打开方式"对话框
+    fn open_with(&self, path: &str) -> Result<()> {
+        #[cfg(target_os = "windows")]
+        {
+            use windows::{
+                core::PCWSTR,
+                Win32::{
+                    Foundation::HWND,
+                    UI::Shell::{
+                        SHOpenWithDialog, OAIF_ALLOW_REGISTRATION, OAIF_EXEC, OAIF_REGISTER_EXT,
+                        OPENASINFO,
+                    },
+                },
+            };
+
+            let wide_path: Vec<u16> = path.encode_utf16().chain(std::iter::once(0)).collect();
+            let info = OPENASINFO {
+                pcszFile: PCWSTR(wide_path.as_ptr()),
+                pcszClass: PCWSTR::null(),
+                oaifInFlags: OAIF_ALLOW_REGISTRATION | OAIF_REGISTER_EXT | OAIF_EXEC,
+            };
+
+            unsafe { SHOpenWithDialog(HWND(std::ptr::null_mut()), &info)? };
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            log::warn!("当前平台不支持打开方式对话框: {}", path);
+        }
+
+        Ok(())
+    }
+
+    /// 解析最近文档查询，返回过滤词
+    fn recent_filter(query: &str) -> Option<&str> {
+        let rest = query.trim().strip_prefix(RECENT_KEYWORD)?;
+        (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim())
+    }
+
+    /// 最近文档（快捷方式和跳转列表只在每次显示启动器后读取一次）
+    fn recent_documents(&self) -> &[RecentDocument] {
+        self.recent.get_or_init(|| recent_docs::read_recent(MAX_RECENT))
+    }
+
+    /// 最近文档结果，按访问时间倒序
+    fn recent_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        self.recent_documents()
+            .iter()
+            .enumerate()
+            .filter_map(|(rank, document)| {
+                let name = Path::new(&document.path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| document.path.clone());
+
+                // 有过滤词时按文件名模糊匹配，分数相同时保持时间顺序
+                let (matched, score) = self.matcher.fuzzy_match(filter, &name);
+                let recency = 999u32.saturating_sub(rank as u32);
+                matched.then(|| self.recent_result(document.clone(), name, score * 1000 + recency))
+            })
+            .take(limit)
+            .collect()
+    }
+
+    /// 构造最近文档结果项
+    fn recent_result(&self, document: RecentDocument, name: String, score: u32) -> SearchResult {
+        let is_dir = Path::new(&document.path).is_dir();
+        let result_type = if is_dir { ResultType::Folder } else { ResultType::File };
+        let parent = Path::new(&document.path)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        SearchResult::new(
+            format!("file_search:{}", document.path),
+            name,
+            format!("{} · {}", document.source.display(), parent),
+            result_type,
+            score,
+            ActionData::OpenFile { path: document.path.clone() },
+        )
+        .with_sort_keys(SortKeys { recency: Some(document.accessed_millis()), size: None })
+        .with_secondary_actions(Self::secondary_actions(&document.path, is_dir))
+    }
+
+    /// 文件结果的次要动作
+    fn secondary_actions(path: &str, is_dir: bool) -> Vec<SecondaryAction> {
+        let path = path.to_string();
+        let mut actions = vec![SecondaryAction::new(
+            "在资源管理器中显示",
+            ActionData::RevealInExplorer { path: path.clone() },
+        )];
+
+        if !is_dir {
+            actions.push(SecondaryAction::new("打开方式…", ActionData::OpenWith {
+                path: path.clone(),
+            }));
+        }
+
+        actions.push(SecondaryAction::new("复制完整路径", ActionData::CopyToClipboard {
+            text: path.clone(),
+        }));
+        actions.push(SecondaryAction::new("复制文件", ActionData::CopyFile { path }));
+        actions
+    }
 }
 
 impl Plugin for FileSearchPlugin {
@@ -173,6 +646,14 @@ impl Plugin for FileSearchPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("folder")
+    }
+
+    fn placeholder(&self) -> Option<&str> {
+        Some("搜索文件名，ext:pdf 按扩展名、in:Downloads 按目录过滤")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -184,30 +665,49 @@ impl Plugin for FileSearchPlugin {
     fn initialize(&mut self) -> Result<()> {
         log::info!("初始化文件搜索插件...");
 
-        // 扫描文件
-        let files = self.scan_files()?;
+        let index = Arc::new(FileIndex::open(&FileIndex::default_path())?);
+        log::info!("已加载文件索引: {} 个文件", index.count().unwrap_or(0));
 
-        // 存储文件列表
-        if let Ok(mut guard) = self.files.lock() {
-            *guard = files;
-            log::info!("已索引 {} 个文件", guard.len());
-        }
+        self.index = Some(index.clone());
+        global_caches().register(index.clone());
+        self.start_watcher(index.clone())?;
+        self.start_background_scan(index);
 
         Ok(())
     }
 
     fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
-        // 文件搜索需要至少 2 个字符
-        if query.len() < 2 {
+        if let Some(filter) = Self::recent_filter(query) {
+            return Ok(self.recent_results(filter, limit));
+        }
+
+        // `ext:`、`in:` 过滤标记只参与过滤，不参与名称匹配
+        let parsed = ParsedQuery::parse(query);
+        let query = parsed.text.as_str();
+
+        // 文件搜索需要至少 2 个字符，有过滤标记时可以只按过滤标记列出文件
+        if query.len() < 2 && !parsed.has_file_filters() {
             return Ok(Vec::new());
         }
 
-        let files = self.files.lock().unwrap();
+        let Some(index) = &self.index else {
+            return Ok(Vec::new());
+        };
+
         let mut results = Vec::new();
 
-        for file in files.iter() {
+        for file in index.candidates(query, &path_filter(&parsed), MAX_CANDIDATES)? {
+            // 索引可能早于当前规则，查询时再过滤一次；过滤标记也按完整规则再检查一次
+            if !self.scanner.allows(Path::new(&file.path)) || !parsed.matches_path(&file.path) {
+                continue;
+            }
+
             // 使用模糊匹配
-            let (matched, score) = fuzzy_match(query, &file.name);
+            let (matched, score) = if query.is_empty() {
+                (true, 0)
+            } else {
+                self.matcher.fuzzy_match(query, &file.name)
+            };
 
             if matched {
                 let result_type = if file.is_dir { ResultType::Folder } else { ResultType::File };
@@ -218,36 +718,115 @@ impl Plugin for FileSearchPlugin {
                     format!("文件 · {}", self.format_size(file.size))
                 };
 
-                results.push(SearchResult::new(
-                    format!("file_search:{}", file.path),
-                    file.name.clone(),
-                    description,
-                    result_type,
-                    score,
-                    ActionData::OpenFile { path: file.path.clone() },
-                ));
-
-                if results.len() >= limit {
-                    break;
-                }
+                results.push(
+                    SearchResult::new(
+                        format!("file_search:{}", file.path),
+                        file.name.clone(),
+                        description,
+                        result_type,
+                        score,
+                        ActionData::OpenFile { path: file.path.clone() },
+                    )
+                    .with_sort_keys(SortKeys {
+                        recency: Some(file.modified_millis()),
+                        size: if file.is_dir { None } else { Some(file.size) },
+                    })
+                    .with_secondary_actions(Self::secondary_actions(&file.path, file.is_dir)),
+                );
             }
         }
 
         // 按匹配分数排序
         results.sort_by_key(|b| std::cmp::Reverse(b.score));
+        results.truncate(limit);
 
         Ok(results)
     }
 
+    fn default_results(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.recent_results("", limit))
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let Some(index) = &self.index else {
+            return Vec::new();
+        };
+        index.recent_names(MAX_VOCABULARY_FILES).unwrap_or_else(|e| {
+            log::warn!("读取拼写纠正词汇失败: {:?}", e);
+            Vec::new()
+        })
+    }
+
+    fn contextual_results(
+        &self,
+        foreground: &ForegroundInfo,
+        _limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        Ok(foreground
+            .folder
+            .as_deref()
+            .map(|folder| self.folder_results(folder))
+            .unwrap_or_default())
+    }
+
+    fn fallback(&self, query: &str) -> Vec<SearchResult> {
+        if everything_path().is_none() {
+            return Vec::new();
+        }
+
+        vec![SearchResult::new(
+            format!("file_search:everything:{}", query),
+            format!("在 Everything 搜索 \"{}\"", query),
+            "在索引之外的所有文件中搜索".to_string(),
+            ResultType::Command,
+            0,
+            ActionData::Custom {
+                plugin: self.id().to_string(),
+                data: format!("{}{}", EVERYTHING_PREFIX, query),
+            },
+        )]
+    }
+
     fn execute(&self, result: &SearchResult) -> Result<()> {
-        if let ActionData::OpenFile { path } = &result.action {
-            self.open_file(path)?;
+        match &result.action {
+            ActionData::OpenFile { path } => self.open_file(path)?,
+            ActionData::RevealInExplorer { path } => self.reveal_in_explorer(path)?,
+            ActionData::OpenWith { path } => self.open_with(path)?,
+            ActionData::CopyToClipboard { text } => self.clipboard_manager.set_text(text)?,
+            ActionData::CopyFile { path } => {
+                self.clipboard_manager.set_files(std::slice::from_ref(path))?
+            },
+            ActionData::Custom { data, .. } => {
+                if let Some(folder) = data.strip_prefix(TERMINAL_PREFIX) {
+                    self.open_terminal(folder)?;
+                } else if let Some(query) = data.strip_prefix(EVERYTHING_PREFIX) {
+                    self.search_everything(query)?;
+                }
+            },
+            _ => {},
         }
         Ok(())
     }
 
     fn refresh(&mut self) -> Result<()> {
-        self.initialize()
+        // 监视器负责增量更新，规则变化后重新监视；扫描补齐监视遗漏的变更
+        let rules = ScanRules::from_config();
+        match self.index.clone() {
+            Some(index) => {
+                if rules != self.scanner.rules {
+                    self.scanner = Scanner::new(rules);
+                    self.start_watcher(index.clone())?;
+                }
+                self.start_background_scan(index);
+                Ok(())
+            },
+            None => self.initialize(),
+        }
+    }
+
+    fn on_activate(&mut self) {
+        // 启动器隐藏期间可能打开过新的文档
+        self.recent = OnceLock::new();
     }
 }
 
@@ -256,3 +835,61 @@ impl Default for FileSearchPlugin {
         Self::new()
     }
 }
+
+/// 查询中的 `ext:`、`in:` 过滤标记对应的索引过滤条件
+fn path_filter(parsed: &ParsedQuery) -> PathFilter {
+    let mut filter = PathFilter::default();
+    for query_filter in &parsed.filters {
+        match query_filter.kind {
+            FilterKind::Extension => {
+                filter.extensions.push(query_filter.value.split(',').map(str::to_string).collect())
+            },
+            FilterKind::Folder => filter.folders.push(query_filter.value.clone()),
+            FilterKind::Plugin => {},
+        }
+    }
+    filter
+}
+
+/// 已安装的 Everything 路径
+fn everything_path() -> Option<&'static str> {
+    EVERYTHING_PATHS.iter().copied().find(|path| Path::new(path).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn home_scanner(include_hidden: bool) -> Scanner {
+        Scanner::new(ScanRules {
+            roots: vec![SearchRoot { path: PathBuf::from("/home"), max_depth: 2 }, SearchRoot {
+                path: PathBuf::from("/home/code"),
+                max_depth: 4,
+            }],
+            ignore_patterns: vec![
+                "node_modules".to_string(),
+                "*.log".to_string(),
+                "docs/**/*.bak".to_string(),
+            ],
+            include_hidden,
+        })
+    }
+
+    #[test]
+    fn test_scanner_rules() {
+        let scanner = home_scanner(false);
+
+        assert!(scanner.allows(Path::new("/home/notes.txt")));
+        assert!(!scanner.allows(Path::new("/home/app.log")));
+        assert!(!scanner.allows(Path::new("/home/web/node_modules")));
+        assert!(!scanner.allows(Path::new("/home/docs/old/a.bak")));
+        assert!(!scanner.allows(Path::new("/home/.config")));
+        assert!(!scanner.allows(Path::new("/other/notes.txt")));
+
+        // 深度按最具体的根目录计算
+        assert!(!scanner.allows(Path::new("/home/a/b/c.txt")));
+        assert!(scanner.allows(Path::new("/home/code/a/b/c.txt")));
+
+        assert!(home_scanner(true).allows(Path::new("/home/.config")));
+    }
+}
@@ -0,0 +1,417 @@
+use std::{
+    path::Path,
+    process::Command,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+        spelling::SUGGESTION_ID_PREFIX,
+    },
+    utils::{
+        folder_bookmarks::{global_folders, same_folder},
+        fuzzy::FuzzyMatcher,
+        recent_docs,
+    },
+};
+
+/// 加载插件的关键字
+pub const KEYWORDS: &[&str] = &["cd", "go"];
+
+/// 最多读取的最近文档数（从中挑出文件夹）
+const MAX_RECENT_DOCS: usize = 200;
+
+/// 最近文件夹的缓存时间
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// 路径补全最多列出的子文件夹数
+const MAX_COMPLETIONS: usize = 20;
+
+/// 文件夹操作
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum FolderAction {
+    /// 在资源管理器中打开
+    Explorer { path: String },
+    /// 在 Windows Terminal 中打开
+    Terminal { path: String },
+    /// 在 VS Code 中打开
+    Code { path: String },
+    /// 添加书签
+    Bookmark { path: String },
+    /// 移除书签
+    Unbookmark { path: String },
+}
+
+/// 候选文件夹的来源
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Source {
+    /// 书签
+    Bookmark,
+    /// 通过本插件打开过，附带打开次数
+    Visited(u32),
+    /// Windows 最近使用记录
+    Recent,
+}
+
+/// 输入是否为路径（含分隔符、以盘符或 `~` 开头）
+pub fn looks_like_path(input: &str) -> bool {
+    input.contains(['\\', '/']) || input.starts_with('~') || input.chars().nth(1) == Some(':')
+}
+
+/// 展开开头的 `~` 为用户目录，并统一使用 `\` 分隔
+pub fn expand_path(input: &str, home: &str) -> String {
+    let expanded = match input.strip_prefix('~') {
+        Some(rest) => format!("{}{}", home.trim_end_matches(['\\', '/']), rest),
+        None => input.to_string(),
+    };
+    expanded.replace('/', "\\")
+}
+
+/// 将输入的路径拆成已输入完整的父目录和正在输入的文件夹名前缀
+///
+/// `C:\Users\ad` -> (`C:\Users\`, `ad`)，`C:` -> (`C:\`, ``)
+pub fn split_partial(path: &str) -> (String, &str) {
+    if path.len() == 2 && path.ends_with(':') {
+        return (format!("{}\\", path), "");
+    }
+    match path.rfind('\\') {
+        Some(index) => (path[..=index].to_string(), &path[index + 1..]),
+        None => (String::new(), path),
+    }
+}
+
+/// 目录下名称以 `prefix` 开头（忽略大小写）的子文件夹名，按名称排序
+fn subfolders(dir: &str, prefix: &str) -> Vec<String> {
+    let prefix = prefix.to_lowercase();
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.to_lowercase().starts_with(&prefix))
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names.truncate(MAX_COMPLETIONS);
+    names
+}
+
+/// 文件夹的显示名称（最后一级目录名，根目录为盘符）
+fn folder_name(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('\\');
+    trimmed.rsplit('\\').next().filter(|name| !name.is_empty()).unwrap_or(path)
+}
+
+/// 文件夹跳转插件
+///
+/// `cd`（或 `go`）列出书签、常用文件夹和 Windows 最近使用的文件夹，跟随文字时按名称过滤；
+/// 输入路径时列出匹配的子文件夹，Enter 补全到输入框。Enter 在资源管理器中打开文件夹，动作
+/// 面板中可以在终端或 VS Code 中打开、添加或移除书签；通过本插件打开的文件夹会被记住
+pub struct FolderJumpPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// Windows 最近使用的文件夹缓存
+    recent: Mutex<Option<(Instant, Vec<String>)>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+}
+
+impl FolderJumpPlugin {
+    /// 创建新的文件夹跳转插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            recent: Mutex::new(None),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+        }
+    }
+
+    /// Windows 最近使用记录中仍然存在的文件夹（缓存一段时间）
+    fn recent_folders(&self) -> Vec<String> {
+        let mut cached = self.recent.lock().unwrap();
+        if let Some((loaded_at, folders)) = cached.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return folders.clone();
+            }
+        }
+
+        let folders: Vec<String> = recent_docs::read_recent(MAX_RECENT_DOCS)
+            .into_iter()
+            .map(|document| document.path)
+            .filter(|path| Path::new(path).is_dir())
+            .collect();
+        *cached = Some((Instant::now(), folders.clone()));
+        folders
+    }
+
+    /// 候选文件夹：书签、打开过的文件夹、最近使用的文件夹，去重并跳过已不存在的
+    fn candidates(&self) -> Vec<(String, Source)> {
+        let store = global_folders();
+        let bookmarks = store.bookmarks().into_iter().map(|path| (path, Source::Bookmark));
+        let visited =
+            store.frecent().into_iter().map(|visit| (visit.path, Source::Visited(visit.count)));
+        let recent = self.recent_folders().into_iter().map(|path| (path, Source::Recent));
+
+        let mut candidates: Vec<(String, Source)> = Vec::new();
+        for (path, source) in bookmarks.chain(visited).chain(recent) {
+            let duplicate = candidates.iter().any(|(p, _)| same_folder(p, &path));
+            if !duplicate && Path::new(&path).is_dir() {
+                candidates.push((path, source));
+            }
+        }
+        candidates
+    }
+
+    /// 匹配文件夹名称的结果，`filter` 为空时按书签、常用、最近的顺序列出
+    fn folder_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = self
+            .candidates()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, (path, source))| {
+                let score = if filter.is_empty() {
+                    1000u32.saturating_sub(index as u32)
+                } else {
+                    let (matched, score) = self.matcher.fuzzy_match(filter, folder_name(&path));
+                    if !matched {
+                        return None;
+                    }
+                    score
+                };
+                Some(self.folder_result(&path, source_label(source), score))
+            })
+            .collect();
+
+        if results.is_empty() && filter.is_empty() {
+            return vec![self.usage_result()];
+        }
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 输入路径时的结果：路径本身（若存在）和补全的子文件夹
+    fn path_results(&self, keyword: &str, input: &str) -> Vec<SearchResult> {
+        let home = dirs::home_dir().map(|home| home.to_string_lossy().to_string());
+        let path = expand_path(input, home.as_deref().unwrap_or_default());
+
+        let mut results = Vec::new();
+        if Path::new(&path).is_dir() {
+            let label = if global_folders().is_bookmarked(&path) { "书签" } else { "文件夹" };
+            results.push(self.folder_result(&path, label.to_string(), 1000));
+        }
+
+        let (dir, prefix) = split_partial(&path);
+        for (index, name) in subfolders(&dir, prefix).into_iter().enumerate() {
+            let completed = format!("{}{}\\", dir, name);
+            if same_folder(&completed, &path) {
+                continue;
+            }
+            let query = format!("{} {}", keyword, completed);
+            results.push(SearchResult::new(
+                format!("{}{}", SUGGESTION_ID_PREFIX, query),
+                completed,
+                "按 Enter 补全路径".to_string(),
+                ResultType::Folder,
+                900u32.saturating_sub(index as u32),
+                ActionData::Custom { plugin: "suggestion".to_string(), data: query },
+            ));
+        }
+        results
+    }
+
+    /// 构造文件夹结果，Enter 在资源管理器中打开
+    fn folder_result(&self, path: &str, label: String, score: u32) -> SearchResult {
+        let bookmark = if global_folders().is_bookmarked(path) {
+            ("移除书签", FolderAction::Unbookmark { path: path.to_string() })
+        } else {
+            ("添加书签", FolderAction::Bookmark { path: path.to_string() })
+        };
+        let secondary_actions = [
+            ("在终端中打开", FolderAction::Terminal { path: path.to_string() }),
+            ("在 VS Code 中打开", FolderAction::Code { path: path.to_string() }),
+            bookmark,
+        ]
+        .into_iter()
+        .map(|(title, action)| SecondaryAction::new(title, self.action(&action)))
+        .collect();
+
+        SearchResult::new(
+            format!("cd:{}", path),
+            folder_name(path).to_string(),
+            format!("{} · {}", label, path),
+            ResultType::Folder,
+            score,
+            self.action(&FolderAction::Explorer { path: path.to_string() }),
+        )
+        .with_secondary_actions(secondary_actions)
+    }
+
+    /// 没有任何候选文件夹时的用法提示
+    fn usage_result(&self) -> SearchResult {
+        SearchResult::new(
+            "cd:usage".to_string(),
+            "cd <路径或文件夹名>".to_string(),
+            "输入路径补全子文件夹，打开过的文件夹会出现在这里，Ctrl+K 添加书签".to_string(),
+            ResultType::Folder,
+            1000,
+            ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+        )
+    }
+
+    /// 文件夹操作对应的动作数据
+    fn action(&self, action: &FolderAction) -> ActionData {
+        ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(action).unwrap_or_default(),
+        }
+    }
+
+    /// 执行文件夹操作，打开文件夹时记录一次访问
+    fn run_action(&self, action: FolderAction) -> Result<()> {
+        let store = global_folders();
+        match action {
+            FolderAction::Explorer { path } => {
+                log::info!("在资源管理器中打开: {}", path);
+                Command::new("explorer").arg(&path).spawn()?;
+                store.record_visit(&path);
+            },
+            FolderAction::Terminal { path } => {
+                log::info!("在终端中打开: {}", path);
+                Command::new("wt.exe").args(["-d", &path]).spawn()?;
+                store.record_visit(&path);
+            },
+            FolderAction::Code { path } => {
+                log::info!("在 VS Code 中打开: {}", path);
+                Command::new("code.cmd").arg(&path).spawn()?;
+                store.record_visit(&path);
+            },
+            FolderAction::Bookmark { path } => {
+                log::info!("添加文件夹书签: {}", path);
+                store.add_bookmark(&path);
+            },
+            FolderAction::Unbookmark { path } => {
+                log::info!("移除文件夹书签: {}", path);
+                store.remove_bookmark(&path);
+            },
+        }
+        Ok(())
+    }
+}
+
+/// 候选文件夹来源的显示文字
+fn source_label(source: Source) -> String {
+    match source {
+        Source::Bookmark => "书签".to_string(),
+        Source::Visited(count) => format!("打开过 {} 次", count),
+        Source::Recent => "最近使用".to_string(),
+    }
+}
+
+impl Plugin for FolderJumpPlugin {
+    fn id(&self) -> &str {
+        "cd"
+    }
+
+    fn name(&self) -> &str {
+        "文件夹跳转"
+    }
+
+    fn description(&self) -> &str {
+        "快速打开收藏和常用的文件夹，输入路径补全子文件夹"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("folder")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化文件夹跳转插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim_start();
+        let Some((keyword, rest)) =
+            KEYWORDS.iter().find_map(|keyword| Some((*keyword, query.strip_prefix(keyword)?)))
+        else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let input = rest.trim();
+        if looks_like_path(input) {
+            return Ok(self.path_results(keyword, input));
+        }
+        Ok(self.folder_results(input, limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::Custom { data, .. } = &result.action {
+            if let Ok(action) = serde_json::from_str(data) {
+                self.run_action(action)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.recent.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for FolderJumpPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_and_completion() {
+        assert!(looks_like_path(r"C:\Users"));
+        assert!(looks_like_path("d:"));
+        assert!(looks_like_path("~"));
+        assert!(!looks_like_path("werun"));
+
+        assert_eq!(expand_path("~/code", r"C:\Users\me\"), r"C:\Users\me\code");
+        assert_eq!(split_partial(r"C:\Users\ad"), (r"C:\Users\".to_string(), "ad"));
+        assert_eq!(split_partial(r"C:\Users\"), (r"C:\Users\".to_string(), ""));
+        assert_eq!(split_partial("C:"), (r"C:\".to_string(), ""));
+        assert_eq!(folder_name(r"D:\code\werun\"), "werun");
+        assert_eq!(folder_name(r"D:\"), "D:");
+
+        let dir = std::env::temp_dir().join(format!("werun-cd-{}", std::process::id()));
+        for name in ["Alpha", "apple", "beta"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+        }
+        std::fs::write(dir.join("about.txt"), "").unwrap();
+        assert_eq!(subfolders(&dir.to_string_lossy(), "A"), ["Alpha", "apple"]);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
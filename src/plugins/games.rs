@@ -0,0 +1,500 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use chrono::{Local, TimeZone};
+use serde_json::{Map, Value};
+
+/// 游戏启动插件
+///
+/// 读取 Steam（`libraryfolders.vdf` 和 `appmanifest_*.acf`）和 Epic Games 启动器（`.item`
+/// 清单）中已安装的游戏，输入游戏名即可通过对应的协议链接启动，显示安装大小和上次游玩时间
+use crate::{
+    core::{
+        cache::format_bytes,
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{
+        fuzzy::FuzzyMatcher,
+        registry::{self, RegistryRoot},
+        shell,
+    },
+};
+
+/// 列出全部游戏的查询关键字，可跟随过滤词，如 `games portal`
+const KEYWORD: &str = "games";
+
+/// 普通查询至少需要的字符数
+const MIN_QUERY_CHARS: usize = 2;
+
+/// 游戏列表的缓存时间
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Steam 客户端在注册表中的位置（当前用户）
+const STEAM_REGISTRY_KEY: &str = r"Software\Valve\Steam";
+
+/// Steam 的默认安装目录
+const STEAM_DEFAULT_DIR: &str = r"C:\Program Files (x86)\Steam";
+
+/// 不是游戏的 Steam 应用（Steamworks 公共运行库）
+const STEAM_NON_GAMES: &[&str] = &["228980"];
+
+/// 游戏平台
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Store {
+    /// Steam
+    Steam,
+    /// Epic Games
+    Epic,
+}
+
+impl Store {
+    /// 显示名称
+    fn label(self) -> &'static str {
+        match self {
+            Store::Steam => "Steam",
+            Store::Epic => "Epic Games",
+        }
+    }
+}
+
+/// 已安装的游戏
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstalledGame {
+    /// 游戏名称
+    pub name: String,
+    /// 所属平台
+    pub store: Store,
+    /// 启动游戏的协议链接
+    pub launch_url: String,
+    /// 安装目录
+    pub install_dir: PathBuf,
+    /// 安装大小（字节）
+    pub size: Option<u64>,
+    /// 上次游玩时间（Unix 时间戳，秒）
+    pub last_played: Option<i64>,
+}
+
+/// 解析 Valve 的 KeyValues 文本格式（`.vdf`、`.acf`）
+///
+/// 键和值为带引号或不带引号的字符串，`{ ... }` 为嵌套的对象，`//` 之后为注释；重复的键
+/// 保留最后一个。格式错误时返回已解析的部分
+pub fn parse_vdf(text: &str) -> Map<String, Value> {
+    let mut tokens = VdfTokens { chars: text.chars().peekable() };
+    parse_vdf_object(&mut tokens)
+}
+
+/// KeyValues 文本的词法单元
+#[derive(Debug, PartialEq)]
+enum VdfToken {
+    /// 字符串
+    Text(String),
+    /// `{`
+    Open,
+    /// `}`
+    Close,
+}
+
+/// KeyValues 文本的词法分析器
+struct VdfTokens<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl Iterator for VdfTokens<'_> {
+    type Item = VdfToken;
+
+    fn next(&mut self) -> Option<VdfToken> {
+        loop {
+            match self.chars.next()? {
+                c if c.is_whitespace() => {},
+                '{' => return Some(VdfToken::Open),
+                '}' => return Some(VdfToken::Close),
+                '/' if self.chars.peek() == Some(&'/') => {
+                    self.chars.find(|&c| c == '\n');
+                },
+                '"' => {
+                    let mut text = String::new();
+                    while let Some(c) = self.chars.next() {
+                        match c {
+                            '"' => break,
+                            '\\' => match self.chars.next() {
+                                Some('n') => text.push('\n'),
+                                Some('t') => text.push('\t'),
+                                Some(c) => text.push(c),
+                                None => break,
+                            },
+                            c => text.push(c),
+                        }
+                    }
+                    return Some(VdfToken::Text(text));
+                },
+                c => {
+                    let mut text = c.to_string();
+                    while let Some(&c) = self.chars.peek() {
+                        if c.is_whitespace() || matches!(c, '{' | '}' | '"') {
+                            break;
+                        }
+                        text.push(c);
+                        self.chars.next();
+                    }
+                    return Some(VdfToken::Text(text));
+                },
+            }
+        }
+    }
+}
+
+/// 解析对象内的键值对，直到 `}` 或文本结束
+fn parse_vdf_object(tokens: &mut VdfTokens) -> Map<String, Value> {
+    let mut object = Map::new();
+    while let Some(VdfToken::Text(key)) = tokens.next() {
+        let value = match tokens.next() {
+            Some(VdfToken::Text(value)) => Value::String(value),
+            Some(VdfToken::Open) => Value::Object(parse_vdf_object(tokens)),
+            _ => break,
+        };
+        object.insert(key, value);
+    }
+    object
+}
+
+/// 读取对象中的字符串字段（KeyValues 中数字也以字符串保存）
+fn vdf_str<'a>(object: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    object.get(key)?.as_str().filter(|value| !value.is_empty())
+}
+
+/// 从 `libraryfolders.vdf` 中读取全部 Steam 库目录
+///
+/// 兼容新版的 `"0" { "path" "..." }` 和旧版的 `"1" "D:\\SteamLibrary"` 两种写法
+pub fn parse_library_folders(text: &str) -> Vec<PathBuf> {
+    let root = parse_vdf(text);
+    let Some(Value::Object(folders)) =
+        root.get("libraryfolders").or_else(|| root.get("LibraryFolders"))
+    else {
+        return Vec::new();
+    };
+
+    folders
+        .iter()
+        .filter(|(key, _)| key.chars().all(|c| c.is_ascii_digit()))
+        .filter_map(|(_, value)| match value {
+            Value::String(path) => Some(path.as_str()),
+            Value::Object(folder) => vdf_str(folder, "path"),
+            _ => None,
+        })
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// 解析 Steam 库目录下的 `appmanifest_<appid>.acf`
+pub fn parse_app_manifest(text: &str, library: &Path) -> Option<InstalledGame> {
+    let root = parse_vdf(text);
+    let state = root.get("AppState")?.as_object()?;
+    let app_id = vdf_str(state, "appid")?;
+    if STEAM_NON_GAMES.contains(&app_id) {
+        return None;
+    }
+
+    let install_dir = library.join("steamapps").join("common").join(vdf_str(state, "installdir")?);
+    Some(InstalledGame {
+        name: vdf_str(state, "name")?.to_string(),
+        store: Store::Steam,
+        launch_url: format!("steam://rungameid/{}", app_id),
+        install_dir,
+        size: vdf_str(state, "SizeOnDisk").and_then(|size| size.parse().ok()),
+        last_played: vdf_str(state, "LastPlayed")
+            .and_then(|time| time.parse().ok())
+            .filter(|&time| time > 0),
+    })
+}
+
+/// 解析 Epic Games 启动器的 `.item` 清单，跳过 DLC 等不能单独启动的项目
+pub fn parse_epic_manifest(text: &str) -> Option<InstalledGame> {
+    let item: Value = serde_json::from_str(text).ok()?;
+    let field = |key: &str| item.get(key)?.as_str().filter(|value| !value.is_empty());
+    if field("MainGameAppName").is_some_and(|main| Some(main) != field("AppName")) {
+        return None;
+    }
+
+    // 启动链接为 `命名空间:商品 ID:应用名`，冒号需要编码
+    let launch_id = [field("CatalogNamespace")?, field("CatalogItemId")?, field("AppName")?];
+    Some(InstalledGame {
+        name: field("DisplayName")?.to_string(),
+        store: Store::Epic,
+        launch_url: format!(
+            "com.epicgames.launcher://apps/{}?action=launch",
+            launch_id.join("%3A")
+        ),
+        install_dir: PathBuf::from(field("InstallLocation")?),
+        size: item.get("InstallSize").and_then(Value::as_u64).filter(|&size| size > 0),
+        last_played: None,
+    })
+}
+
+/// Steam 的安装目录：注册表中的 `SteamPath`，找不到时使用默认目录
+fn steam_dir() -> PathBuf {
+    registry::string_value(RegistryRoot::CurrentUser, STEAM_REGISTRY_KEY, Some("SteamPath"))
+        .map(|path| PathBuf::from(path.replace('/', "\\")))
+        .unwrap_or_else(|| PathBuf::from(STEAM_DEFAULT_DIR))
+}
+
+/// 读取全部 Steam 库中已安装的游戏
+fn steam_games() -> Vec<InstalledGame> {
+    let steam = steam_dir();
+    let mut libraries = std::fs::read_to_string(steam.join("steamapps").join("libraryfolders.vdf"))
+        .map(|text| parse_library_folders(&text))
+        .unwrap_or_default();
+    if !libraries.iter().any(|library| library == &steam) {
+        libraries.insert(0, steam);
+    }
+
+    let mut games = Vec::new();
+    for library in libraries {
+        let Ok(entries) = std::fs::read_dir(library.join("steamapps")) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.starts_with("appmanifest_") || !file_name.ends_with(".acf") {
+                continue;
+            }
+            if let Ok(text) = std::fs::read_to_string(entry.path()) {
+                games.extend(parse_app_manifest(&text, &library));
+            }
+        }
+    }
+    games
+}
+
+/// 读取 Epic Games 启动器中已安装的游戏
+fn epic_games() -> Vec<InstalledGame> {
+    let program_data = std::env::var_os("ProgramData")
+        .map_or_else(|| PathBuf::from(r"C:\ProgramData"), PathBuf::from);
+    let manifests =
+        program_data.join("Epic").join("EpicGamesLauncher").join("Data").join("Manifests");
+
+    std::fs::read_dir(manifests)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "item"))
+        .filter_map(|entry| std::fs::read_to_string(entry.path()).ok())
+        .filter_map(|text| parse_epic_manifest(&text))
+        .collect()
+}
+
+/// 游戏启动插件
+pub struct GamesPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 游戏列表缓存
+    games: Mutex<Option<(Instant, Vec<InstalledGame>)>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+}
+
+impl GamesPlugin {
+    /// 创建新的游戏启动插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            games: Mutex::new(None),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+        }
+    }
+
+    /// 仍然存在的已安装游戏，最近玩过的在前（缓存一段时间）
+    fn games(&self) -> Vec<InstalledGame> {
+        let mut cached = self.games.lock().unwrap();
+        if let Some((loaded_at, games)) = cached.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return games.clone();
+            }
+        }
+
+        let mut games: Vec<InstalledGame> = steam_games()
+            .into_iter()
+            .chain(epic_games())
+            .filter(|game| game.install_dir.exists())
+            .collect();
+        games.sort_by(|a, b| b.last_played.cmp(&a.last_played).then_with(|| a.name.cmp(&b.name)));
+
+        log::debug!("读取到 {} 个已安装的游戏", games.len());
+        *cached = Some((Instant::now(), games.clone()));
+        games
+    }
+
+    /// 匹配游戏名称的结果，`filter` 为空时按上次游玩的顺序列出
+    fn game_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = self
+            .games()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, game)| {
+                let score = if filter.is_empty() {
+                    1000u32.saturating_sub(index as u32)
+                } else {
+                    let (matched, score) = self.matcher.fuzzy_match(filter, &game.name);
+                    if !matched {
+                        return None;
+                    }
+                    score
+                };
+                Some(self.game_result(game, score))
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 构造游戏结果，描述为平台、安装大小和上次游玩时间
+    fn game_result(&self, game: InstalledGame, score: u32) -> SearchResult {
+        let mut details = vec![game.store.label().to_string()];
+        if let Some(size) = game.size {
+            details.push(format_bytes(size as usize));
+        }
+        match game.last_played.and_then(|time| Local.timestamp_opt(time, 0).single()) {
+            Some(time) => details.push(format!("上次游玩 {}", time.format("%Y-%m-%d"))),
+            None if game.store == Store::Steam => details.push("尚未游玩".to_string()),
+            None => {},
+        }
+
+        SearchResult::new(
+            format!("games:{}", game.launch_url),
+            game.name,
+            details.join(" · "),
+            ResultType::Application,
+            score,
+            ActionData::OpenUrl { url: game.launch_url },
+        )
+        .with_secondary_actions(vec![SecondaryAction::new(
+            "在资源管理器中显示",
+            ActionData::RevealInExplorer { path: game.install_dir.to_string_lossy().to_string() },
+        )])
+    }
+}
+
+impl Plugin for GamesPlugin {
+    fn id(&self) -> &str {
+        "games"
+    }
+
+    fn name(&self) -> &str {
+        "游戏"
+    }
+
+    fn description(&self) -> &str {
+        "启动 Steam 和 Epic Games 中已安装的游戏"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("app-window")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化游戏启动插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim();
+        if let Some(rest) = query.strip_prefix(KEYWORD) {
+            if rest.is_empty() || rest.starts_with(' ') {
+                return Ok(self.game_results(rest.trim(), limit));
+            }
+        }
+
+        if query.chars().count() < MIN_QUERY_CHARS {
+            return Ok(Vec::new());
+        }
+        Ok(self.game_results(query, limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::OpenUrl { url } => {
+                log::info!("启动游戏: {} ({})", result.title, url);
+                shell::open_url(url)?;
+            },
+            ActionData::RevealInExplorer { path } => {
+                Command::new("explorer").arg(path).spawn()?;
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.games.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for GamesPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_installed_games() {
+        let folders = r#""libraryfolders"
+        {
+            "0" { "path" "C:\\Program Files (x86)\\Steam" "apps" { "620" "12" } }
+            // 旧版写法
+            "1" "D:\\SteamLibrary"
+            "contentstatsid" "-123"
+        }"#;
+        assert_eq!(parse_library_folders(folders), [
+            PathBuf::from(r"C:\Program Files (x86)\Steam"),
+            PathBuf::from(r"D:\SteamLibrary")
+        ]);
+
+        let manifest = r#""AppState" { "appid" "620" "name" "Portal 2" "installdir" "Portal 2"
+            "SizeOnDisk" "12884901888" "LastPlayed" "1700000000" }"#;
+        let game = parse_app_manifest(manifest, Path::new(r"D:\SteamLibrary")).unwrap();
+        assert_eq!(game.launch_url, "steam://rungameid/620");
+        assert_eq!(
+            game.install_dir,
+            Path::new(r"D:\SteamLibrary").join("steamapps/common/Portal 2")
+        );
+        assert_eq!((game.size, game.last_played), (Some(12884901888), Some(1700000000)));
+        let redist = r#""AppState" { "appid" "228980" "name" "Redist" "installdir" "x" }"#;
+        assert_eq!(parse_app_manifest(redist, Path::new("D:")), None);
+
+        let item = serde_json::json!({
+            "DisplayName": "Hades", "AppName": "Min", "MainGameAppName": "Min",
+            "CatalogNamespace": "ns", "CatalogItemId": "id", "InstallLocation": r"E:\Epic\Hades",
+            "InstallSize": 15000000000u64
+        });
+        let game = parse_epic_manifest(&item.to_string()).unwrap();
+        assert_eq!(game.launch_url, "com.epicgames.launcher://apps/ns%3Aid%3AMin?action=launch");
+        assert_eq!(game.size, Some(15000000000));
+        let dlc = serde_json::json!({ "AppName": "Dlc", "MainGameAppName": "Min" });
+        assert_eq!(parse_epic_manifest(&dlc.to_string()), None);
+    }
+}
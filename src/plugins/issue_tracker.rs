@@ -0,0 +1,407 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{clipboard::ClipboardManager, encoding::base64_encode, http, shell},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "issue";
+
+/// 最多保留的工单查询结果数
+const MAX_LOOKUPS: usize = 32;
+
+/// 输入停止多久后才查询工单，输入工单号中途的数字不发起请求
+const INPUT_DELAY: Duration = Duration::from_millis(500);
+
+/// 工单号，如 `PROJ-123`
+static ISSUE_KEY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([A-Za-z][A-Za-z0-9_]*)-(\d+)$").unwrap());
+
+/// 查询标题和状态的接口类型
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum IssueApi {
+    /// 不查询
+    #[default]
+    None,
+    /// Jira REST API（`fields.summary`、`fields.status.name`）
+    Jira,
+    /// GitLab REST API（`title`、`state`）
+    Gitlab,
+}
+
+/// 插件配置（`[plugins.settings.issue]`）
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct IssueSettings {
+    /// 在浏览器中打开工单的链接模板
+    url: String,
+    /// 查询标题和状态的接口类型
+    api: IssueApi,
+    /// 接口链接模板
+    api_url: String,
+    /// 接口的访问令牌，`用户:令牌` 形式使用 Basic 认证，否则使用 Bearer 认证
+    token: String,
+    /// 直接输入工单号时识别的项目前缀，为空时只识别大写输入的工单号
+    projects: Vec<String>,
+    /// 项目前缀对应的 GitLab 项目路径（替换 `{project}`），如 `WEB = "group/web"`
+    project_paths: HashMap<String, String>,
+}
+
+/// 工单号
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssueKey {
+    /// 项目前缀（大写）
+    pub project: String,
+    /// 编号
+    pub number: u64,
+}
+
+impl IssueKey {
+    /// 解析工单号，项目前缀转为大写
+    pub fn parse(text: &str) -> Option<Self> {
+        let captures = ISSUE_KEY.captures(text.trim())?;
+        Some(Self { project: captures[1].to_uppercase(), number: captures[2].parse().ok()? })
+    }
+
+    /// 填充链接模板：`{key}` 为完整工单号，`{project}` 为项目（可映射为项目路径并编码），
+    /// `{number}` 为编号
+    pub fn fill(&self, template: &str, project_paths: &HashMap<String, String>) -> String {
+        let project = project_paths
+            .get(&self.project)
+            .map_or_else(|| self.project.clone(), |path| urlencoding::encode(path).into_owned());
+        template
+            .replace("{key}", &self.to_string())
+            .replace("{project}", &project)
+            .replace("{number}", &self.number.to_string())
+    }
+}
+
+impl std::fmt::Display for IssueKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.project, self.number)
+    }
+}
+
+/// 工单的标题和状态
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssueSummary {
+    /// 标题
+    pub title: String,
+    /// 状态
+    pub status: String,
+}
+
+/// 解析接口响应中的标题和状态
+fn parse_issue(json: &str, api: IssueApi) -> Option<IssueSummary> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let (title, status) = match api {
+        IssueApi::None => return None,
+        IssueApi::Jira => {
+            let fields = value.get("fields")?;
+            (fields.get("summary")?, fields.get("status")?.get("name")?)
+        },
+        IssueApi::Gitlab => (value.get("title")?, value.get("state")?),
+    };
+    Some(IssueSummary { title: title.as_str()?.to_string(), status: status.as_str()?.to_string() })
+}
+
+/// 访问令牌对应的认证请求头
+fn authorization(token: &str) -> String {
+    if token.contains(':') {
+        format!("Basic {}", base64_encode(token))
+    } else {
+        format!("Bearer {}", token)
+    }
+}
+
+/// 工单查询的结果
+#[derive(Clone)]
+enum IssueLookup {
+    /// 正在查询
+    Pending,
+    /// 查询完成
+    Done(std::result::Result<IssueSummary, String>),
+}
+
+/// 工单插件
+///
+/// 直接输入工单号（如 `PROJ-123`）或 `issue <工单号>`，Enter 按配置的链接模板在浏览器中打开；
+/// 配置了 Jira 或 GitLab 接口时在后台查询标题和状态，显示在结果中
+pub struct IssueTrackerPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: IssueSettings,
+    /// 已查询的工单，键为工单号
+    lookups: Arc<Mutex<HashMap<String, IssueLookup>>>,
+    /// 最近一次查询的工单号
+    latest: Arc<Mutex<String>>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl IssueTrackerPlugin {
+    /// 创建新的工单插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            settings: IssueSettings::default(),
+            lookups: Arc::new(Mutex::new(HashMap::new())),
+            latest: Arc::new(Mutex::new(String::new())),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 去掉查询中的关键字，不以关键字开头时返回 `None`
+    fn strip_keyword(query: &str) -> Option<&str> {
+        query.trim().strip_prefix(KEYWORD).filter(|rest| rest.starts_with(' '))
+    }
+
+    /// 解析查询中的工单号；不带关键字时只识别配置的项目，未配置项目时要求大写输入
+    fn issue_key(&self, query: &str) -> Option<IssueKey> {
+        let query = query.trim();
+        if let Some(rest) = Self::strip_keyword(query) {
+            return IssueKey::parse(rest);
+        }
+
+        let key = IssueKey::parse(query)?;
+        let known = if self.settings.projects.is_empty() {
+            !query.chars().any(|c| c.is_ascii_lowercase())
+        } else {
+            self.settings.projects.iter().any(|project| project.eq_ignore_ascii_case(&key.project))
+        };
+        known.then_some(key)
+    }
+
+    /// 工单的查询结果，未查询过的工单在后台查询
+    fn lookup(&self, key: &IssueKey) -> IssueLookup {
+        let id = key.to_string();
+        *self.latest.lock().unwrap() = id.clone();
+        let mut lookups = self.lookups.lock().unwrap();
+        if lookups.len() >= MAX_LOOKUPS && !lookups.contains_key(&id) {
+            lookups.clear();
+        }
+        lookups
+            .entry(id)
+            .or_insert_with(|| {
+                self.start_fetch(key);
+                IssueLookup::Pending
+            })
+            .clone()
+    }
+
+    /// 在后台查询工单的标题和状态，输入停止一段时间后才查询，期间工单号变化时放弃
+    fn start_fetch(&self, key: &IssueKey) {
+        let url = key.fill(&self.settings.api_url, &self.settings.project_paths);
+        let (lookups, id, api) = (self.lookups.clone(), key.to_string(), self.settings.api);
+        let (latest, token) = (self.latest.clone(), self.settings.token.clone());
+
+        std::thread::spawn(move || {
+            std::thread::sleep(INPUT_DELAY);
+            if *latest.lock().unwrap() != id {
+                lookups.lock().unwrap().remove(&id);
+                return;
+            }
+
+            let auth = authorization(&token);
+            let headers: &[(&str, &str)] =
+                if token.is_empty() { &[] } else { &[("Authorization", &auth)] };
+            let summary = http::download_with_headers(&url, headers)
+                .and_then(|body| {
+                    parse_issue(&body, api).ok_or_else(|| anyhow!("无法解析工单接口的响应"))
+                })
+                .map_err(|e| {
+                    log::warn!("查询工单失败: {}: {}", id, e);
+                    e.to_string()
+                });
+            lookups.lock().unwrap().insert(id, IssueLookup::Done(summary));
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// 工单的结果项，Enter 在浏览器中打开
+    fn issue_result(&self, key: &IssueKey) -> SearchResult {
+        let url = key.fill(&self.settings.url, &self.settings.project_paths);
+        let queried = self.settings.api != IssueApi::None && !self.settings.api_url.is_empty();
+        let (title, description) = match queried.then(|| self.lookup(key)) {
+            Some(IssueLookup::Done(Ok(summary))) => {
+                (format!("{} {}", key, summary.title), format!("{} · {}", summary.status, url))
+            },
+            Some(IssueLookup::Done(Err(e))) => {
+                (format!("打开 {}", key), format!("查询失败: {}", e))
+            },
+            Some(IssueLookup::Pending) => {
+                (format!("打开 {}", key), format!("正在查询标题和状态… · {}", url))
+            },
+            None => (format!("打开 {}", key), url.clone()),
+        };
+
+        SearchResult::new(
+            format!("issue:{}", key),
+            title,
+            description,
+            ResultType::Custom("issue".to_string()),
+            1000,
+            ActionData::OpenUrl { url: url.clone() },
+        )
+        .with_secondary_actions(vec![
+            SecondaryAction::new("复制链接", ActionData::CopyToClipboard { text: url }),
+            SecondaryAction::new("复制工单号", ActionData::CopyToClipboard {
+                text: key.to_string(),
+            }),
+        ])
+    }
+
+    /// 不可执行的提示结果项
+    fn hint(&self, title: &str, description: &str) -> SearchResult {
+        SearchResult::new(
+            "issue:hint".to_string(),
+            title.to_string(),
+            description.to_string(),
+            ResultType::Custom("issue".to_string()),
+            1000,
+            ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+        )
+    }
+}
+
+impl Plugin for IssueTrackerPlugin {
+    fn id(&self) -> &str {
+        "issue"
+    }
+
+    fn name(&self) -> &str {
+        "工单"
+    }
+
+    fn description(&self) -> &str {
+        "识别 PROJ-123 形式的工单号，在 Jira、GitLab 等工单系统中打开"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("list-todo")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化工单插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(key) = self.issue_key(query) else {
+            if query.trim() == KEYWORD {
+                return Ok(vec![
+                    self.hint("issue <工单号>", "如 issue PROJ-123，Enter 在浏览器中打开")
+                ]);
+            }
+            return Ok(Vec::new());
+        };
+
+        // 未配置链接时只对带关键字的查询给出提示，不干扰直接输入的工单号
+        if self.settings.url.is_empty() {
+            if Self::strip_keyword(query).is_none() {
+                return Ok(Vec::new());
+            }
+            return Ok(vec![self.hint(
+                &format!("无法打开 {}", key),
+                "请在配置文件的 [plugins.settings.issue] 中设置 url",
+            )]);
+        }
+        Ok(vec![self.issue_result(&key)])
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::OpenUrl { url } => {
+                log::info!("打开工单: {}", url);
+                shell::open_url(url)?;
+            },
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制工单信息: {}", text);
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.lookups.lock().unwrap().clear();
+        Ok(())
+    }
+}
+
+impl Default for IssueTrackerPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_keys_and_responses() {
+        let key = IssueKey::parse("web-42").unwrap();
+        assert_eq!(key.to_string(), "WEB-42");
+        assert_eq!(IssueKey::parse("UTF8"), None);
+        assert_eq!(IssueKey::parse("PROJ-12a"), None);
+
+        let paths = HashMap::from([("WEB".to_string(), "group/web".to_string())]);
+        let api = "https://gitlab.com/api/v4/projects/{project}/issues/{number}";
+        assert_eq!(
+            key.fill(api, &paths),
+            "https://gitlab.com/api/v4/projects/group%2Fweb/issues/42"
+        );
+        let browse = "https://jira.example.com/browse/{key}";
+        assert_eq!(key.fill(browse, &HashMap::new()), "https://jira.example.com/browse/WEB-42");
+
+        let plugin = IssueTrackerPlugin::new();
+        assert_eq!(plugin.issue_key("PROJ-7"), IssueKey::parse("PROJ-7"));
+        assert_eq!(plugin.issue_key("utf-8"), None);
+        assert_eq!(plugin.issue_key("issue utf-8"), IssueKey::parse("UTF-8"));
+
+        let jira =
+            r#"{"key":"WEB-42","fields":{"summary":"登录失败","status":{"name":"In Progress"}}}"#;
+        let summary =
+            IssueSummary { title: "登录失败".to_string(), status: "In Progress".to_string() };
+        assert_eq!(parse_issue(jira, IssueApi::Jira), Some(summary));
+        let gitlab = r#"{"iid":42,"title":"Fix login","state":"opened"}"#;
+        assert_eq!(parse_issue(gitlab, IssueApi::Gitlab).unwrap().status, "opened");
+        assert_eq!(parse_issue(gitlab, IssueApi::Jira), None);
+
+        assert_eq!(authorization("abc"), "Bearer abc");
+        assert_eq!(authorization("me@x.io:abc"), format!("Basic {}", base64_encode("me@x.io:abc")));
+    }
+}
@@ -0,0 +1,651 @@
+use std::{
+    collections::HashMap,
+    process::Command,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::clipboard::ClipboardManager,
+};
+
+/// 查询关键字
+const KEYWORD: &str = "k8s";
+
+/// kubectl 输出的缓存时间，过期后在后台重新获取，获取期间仍显示旧的结果
+const CACHE_TTL: Duration = Duration::from_secs(15);
+
+/// 查看日志时显示的最近行数
+const LOG_TAIL_LINES: &str = "200";
+
+/// kubeconfig 中的上下文
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KubeContext {
+    /// 上下文名称
+    pub name: String,
+    /// 集群名称
+    pub cluster: String,
+    /// 默认命名空间
+    pub namespace: Option<String>,
+}
+
+/// kubeconfig（`kubectl config view -o json` 的输出，已合并 `KUBECONFIG` 中的多个文件）
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KubeConfig {
+    /// 当前上下文
+    pub current_context: Option<String>,
+    /// 全部上下文
+    pub contexts: Vec<KubeContext>,
+}
+
+impl KubeConfig {
+    /// 解析 kubeconfig 的 JSON 形式
+    pub fn parse(json: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(json).ok()?;
+        let text = |value: &Value, key: &str| {
+            value.get(key)?.as_str().filter(|text| !text.is_empty()).map(str::to_string)
+        };
+
+        let contexts = value
+            .get("contexts")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let context = entry.get("context")?;
+                Some(KubeContext {
+                    name: text(entry, "name")?,
+                    cluster: text(context, "cluster").unwrap_or_default(),
+                    namespace: text(context, "namespace"),
+                })
+            })
+            .collect();
+        Some(Self { current_context: text(&value, "current-context"), contexts })
+    }
+
+    /// 当前上下文及其命名空间（未设置时为 `default`）
+    pub fn current(&self) -> Option<(&str, &str)> {
+        let name = self.current_context.as_deref()?;
+        let namespace = self
+            .contexts
+            .iter()
+            .find(|context| context.name == name)
+            .and_then(|context| context.namespace.as_deref())
+            .unwrap_or("default");
+        Some((name, namespace))
+    }
+}
+
+/// Pod
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pod {
+    /// 名称
+    pub name: String,
+    /// 状态，与 `kubectl get pods` 的 STATUS 列一致（如 `Running`、`CrashLoopBackOff`）
+    pub status: String,
+    /// 就绪的容器数
+    pub ready: usize,
+    /// 容器总数
+    pub containers: usize,
+    /// 重启次数
+    pub restarts: u64,
+}
+
+/// 解析 `kubectl get pods -o json` 的输出
+pub fn parse_pods(json: &str) -> Vec<Pod> {
+    let Ok(value) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+
+    value
+        .get("items")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let name = item.get("metadata")?.get("name")?.as_str()?.to_string();
+            let status = item.get("status")?;
+            let containers: Vec<&Value> = status
+                .get("containerStatuses")
+                .and_then(Value::as_array)
+                .map(|statuses| statuses.iter().collect())
+                .unwrap_or_default();
+
+            // 容器等待或退出的原因比 Pod 阶段更具体
+            let reason = containers.iter().find_map(|container| {
+                let state = container.get("state")?;
+                let detail = state.get("waiting").or_else(|| state.get("terminated"))?;
+                detail.get("reason")?.as_str()
+            });
+            let phase = status.get("phase").and_then(Value::as_str).unwrap_or("Unknown");
+            let deleting = item.get("metadata")?.get("deletionTimestamp").is_some();
+
+            Some(Pod {
+                name,
+                status: if deleting { "Terminating" } else { reason.unwrap_or(phase) }.to_string(),
+                ready: containers
+                    .iter()
+                    .filter(|container| {
+                        container.get("ready").and_then(Value::as_bool) == Some(true)
+                    })
+                    .count(),
+                containers: containers.len(),
+                restarts: containers
+                    .iter()
+                    .filter_map(|container| container.get("restartCount")?.as_u64())
+                    .sum(),
+            })
+        })
+        .collect()
+}
+
+/// 解析 `kubectl get namespaces -o json` 的输出
+pub fn parse_namespaces(json: &str) -> Vec<String> {
+    let Ok(value) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+
+    value
+        .get("items")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| Some(item.get("metadata")?.get("name")?.as_str()?.to_string()))
+        .collect()
+}
+
+/// 运行 kubectl 并返回标准输出，不显示控制台窗口
+fn kubectl(args: &[String]) -> Result<String> {
+    let mut cmd = Command::new("kubectl");
+    cmd.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        return Err(anyhow!("{}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 缓存的 kubectl 输出
+#[derive(Clone, Default)]
+struct CachedOutput {
+    /// 获取时间
+    fetched_at: Option<Instant>,
+    /// 最近一次的输出或错误
+    result: Option<std::result::Result<String, String>>,
+    /// 是否正在获取
+    fetching: bool,
+}
+
+/// Kubernetes 操作
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum KubeAction {
+    /// 切换当前上下文
+    UseContext { context: String },
+    /// 设置上下文的默认命名空间
+    UseNamespace { context: String, namespace: String },
+    /// 在终端中查看 Pod 日志
+    Logs { context: String, namespace: String, pod: String },
+    /// 删除 Pod
+    DeletePod { context: String, namespace: String, pod: String },
+}
+
+/// 查询的列表
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum View {
+    /// 上下文
+    Contexts,
+    /// 当前上下文的命名空间
+    Namespaces,
+    /// 当前命名空间的 Pod
+    Pods,
+}
+
+/// 解析关键字之后的参数，返回列表和过滤词
+fn parse_view(rest: &str) -> (View, &str) {
+    let rest = rest.trim();
+    let (first, filter) = rest.split_once(' ').unwrap_or((rest, ""));
+    match first {
+        "ns" => (View::Namespaces, filter.trim()),
+        "pods" | "po" => (View::Pods, filter.trim()),
+        _ => (View::Contexts, rest),
+    }
+}
+
+/// 忽略大小写的子串匹配，过滤词为空时全部匹配
+fn matches_filter(text: &str, filter: &str) -> bool {
+    text.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Kubernetes 插件
+///
+/// `k8s` 列出 kubeconfig 中的上下文，Enter 切换；`k8s ns` 切换当前上下文的命名空间；
+/// `k8s pods` 列出当前命名空间的 Pod 及状态，Enter 复制名称，动作面板中可以在终端中查看日志
+/// 或删除 Pod。均可在之后跟随过滤词，kubectl 在后台运行
+pub struct KubernetesPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// kubectl 输出缓存，键为参数
+    outputs: Arc<Mutex<HashMap<Vec<String>, CachedOutput>>>,
+    /// 最近一次操作失败的原因，下次操作前显示在结果顶部
+    failure: Arc<Mutex<Option<String>>>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl KubernetesPlugin {
+    /// 创建新的 Kubernetes 插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            outputs: Arc::new(Mutex::new(HashMap::new())),
+            failure: Arc::new(Mutex::new(None)),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// kubectl 的输出，没有获取过或已过期时在后台获取；尚未获取到时返回 `None`
+    fn output(&self, args: &[&str]) -> Option<std::result::Result<String, String>> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut outputs = self.outputs.lock().unwrap();
+        let cached = outputs.entry(args.clone()).or_default();
+
+        let fresh = cached.fetched_at.is_some_and(|at| at.elapsed() < CACHE_TTL);
+        if !fresh && !cached.fetching {
+            cached.fetching = true;
+            let outputs = self.outputs.clone();
+            std::thread::spawn(move || {
+                let result = kubectl(&args).map_err(|e| {
+                    log::warn!("kubectl {} 失败: {}", args.join(" "), e);
+                    e.to_string()
+                });
+                let cached = CachedOutput {
+                    fetched_at: Some(Instant::now()),
+                    result: Some(result),
+                    fetching: false,
+                };
+                outputs.lock().unwrap().insert(args, cached);
+                global_progress().mark_results_changed();
+            });
+        }
+        cached.result.clone()
+    }
+
+    /// 读取 kubeconfig，尚未读取到或出错时返回提示结果
+    fn config(&self) -> std::result::Result<KubeConfig, Vec<SearchResult>> {
+        match self.output(&["config", "view", "-o", "json"]) {
+            None => Err(vec![self.status("正在读取 kubeconfig…", "kubectl config view")]),
+            Some(Err(e)) => Err(vec![self.status("无法运行 kubectl", &e)]),
+            Some(Ok(json)) => KubeConfig::parse(&json).ok_or_else(|| {
+                vec![self.status("无法解析 kubeconfig", "kubectl config view -o json")]
+            }),
+        }
+    }
+
+    /// 上下文结果
+    fn context_results(&self, filter: &str) -> Vec<SearchResult> {
+        let config = match self.config() {
+            Ok(config) => config,
+            Err(status) => return status,
+        };
+
+        let current = config.current_context.as_deref();
+        config
+            .contexts
+            .iter()
+            .filter(|context| matches_filter(&context.name, filter))
+            .enumerate()
+            .map(|(index, context)| {
+                let is_current = current == Some(context.name.as_str());
+                let namespace = context.namespace.as_deref().unwrap_or("default");
+                let mut description = format!("集群 {} · 命名空间 {}", context.cluster, namespace);
+                description.push_str(if is_current {
+                    " · 当前上下文"
+                } else {
+                    " · 按 Enter 切换"
+                });
+                let action = KubeAction::UseContext { context: context.name.clone() };
+                self.result(
+                    format!("k8s:context:{}", context.name),
+                    context.name.clone(),
+                    description,
+                    1000u32.saturating_sub(index as u32),
+                    self.action(&action),
+                )
+            })
+            .collect()
+    }
+
+    /// 当前上下文的命名空间结果
+    fn namespace_results(&self, filter: &str) -> Vec<SearchResult> {
+        let config = match self.config() {
+            Ok(config) => config,
+            Err(status) => return status,
+        };
+        let Some((context, current)) = config.current() else {
+            return vec![self.status("没有当前上下文", "先用 k8s 选择上下文")];
+        };
+
+        let namespaces =
+            match self.output(&["--context", context, "get", "namespaces", "-o", "json"]) {
+                None => return vec![self.status("正在获取命名空间…", context)],
+                Some(Err(e)) => return vec![self.status("无法获取命名空间", &e)],
+                Some(Ok(json)) => parse_namespaces(&json),
+            };
+
+        namespaces
+            .into_iter()
+            .filter(|namespace| matches_filter(namespace, filter))
+            .enumerate()
+            .map(|(index, namespace)| {
+                let description = if namespace == current {
+                    format!("{} · 当前命名空间", context)
+                } else {
+                    format!("{} · 按 Enter 设为默认命名空间", context)
+                };
+                let action = KubeAction::UseNamespace {
+                    context: context.to_string(),
+                    namespace: namespace.clone(),
+                };
+                self.result(
+                    format!("k8s:ns:{}", namespace),
+                    namespace,
+                    description,
+                    1000u32.saturating_sub(index as u32),
+                    self.action(&action),
+                )
+            })
+            .collect()
+    }
+
+    /// 当前命名空间的 Pod 结果
+    fn pod_results(&self, filter: &str) -> Vec<SearchResult> {
+        let config = match self.config() {
+            Ok(config) => config,
+            Err(status) => return status,
+        };
+        let Some((context, namespace)) = config.current() else {
+            return vec![self.status("没有当前上下文", "先用 k8s 选择上下文")];
+        };
+
+        let args = ["--context", context, "-n", namespace, "get", "pods", "-o", "json"];
+        let pods = match self.output(&args) {
+            None => return vec![self.status("正在获取 Pod 列表…", namespace)],
+            Some(Err(e)) => return vec![self.status("无法获取 Pod 列表", &e)],
+            Some(Ok(json)) => parse_pods(&json),
+        };
+        if pods.is_empty() {
+            return vec![self.status("没有 Pod", &format!("{} · {}", context, namespace))];
+        }
+
+        pods.into_iter()
+            .filter(|pod| matches_filter(&pod.name, filter))
+            .enumerate()
+            .map(|(index, pod)| {
+                let description = format!(
+                    "{} · {}/{} 就绪 · 重启 {} 次 · {}",
+                    pod.status, pod.ready, pod.containers, pod.restarts, namespace
+                );
+                let logs = KubeAction::Logs {
+                    context: context.to_string(),
+                    namespace: namespace.to_string(),
+                    pod: pod.name.clone(),
+                };
+                let delete = KubeAction::DeletePod {
+                    context: context.to_string(),
+                    namespace: namespace.to_string(),
+                    pod: pod.name.clone(),
+                };
+                self.result(
+                    format!("k8s:pod:{}", pod.name),
+                    pod.name.clone(),
+                    description,
+                    1000u32.saturating_sub(index as u32),
+                    ActionData::CopyToClipboard { text: pod.name },
+                )
+                .with_secondary_actions(vec![
+                    SecondaryAction::new("在终端中查看日志", self.action(&logs)),
+                    SecondaryAction::new("删除 Pod", self.action(&delete)).with_destructive(true),
+                ])
+            })
+            .collect()
+    }
+
+    /// 构造结果项
+    fn result(
+        &self,
+        id: String,
+        title: String,
+        description: String,
+        score: u32,
+        action: ActionData,
+    ) -> SearchResult {
+        SearchResult::new(
+            id,
+            title,
+            description,
+            ResultType::Custom("k8s".to_string()),
+            score,
+            action,
+        )
+    }
+
+    /// 不可执行的状态或错误结果项
+    fn status(&self, title: &str, description: &str) -> SearchResult {
+        self.result(
+            "k8s:status".to_string(),
+            title.to_string(),
+            description.to_string(),
+            1000,
+            ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+        )
+    }
+
+    /// 操作对应的动作数据
+    fn action(&self, action: &KubeAction) -> ActionData {
+        ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(action).unwrap_or_default(),
+        }
+    }
+
+    /// 执行操作，修改集群或配置的命令在后台运行
+    fn run_action(&self, action: KubeAction) -> Result<()> {
+        let args: Vec<String> = match &action {
+            KubeAction::UseContext { context } => {
+                log::info!("切换 Kubernetes 上下文: {}", context);
+                vec!["config".into(), "use-context".into(), context.clone()]
+            },
+            KubeAction::UseNamespace { context, namespace } => {
+                log::info!("设置 {} 的默认命名空间: {}", context, namespace);
+                let namespace = format!("--namespace={}", namespace);
+                vec!["config".into(), "set-context".into(), context.clone(), namespace]
+            },
+            KubeAction::Logs { context, namespace, pod } => {
+                log::info!("查看 Pod 日志: {}/{}", namespace, pod);
+                Command::new("wt.exe")
+                    .args(["kubectl", "--context", context])
+                    .args(["-n", namespace, "logs", "-f", "--tail", LOG_TAIL_LINES, pod])
+                    .spawn()?;
+                return Ok(());
+            },
+            KubeAction::DeletePod { context, namespace, pod } => {
+                log::info!("删除 Pod: {}/{}", namespace, pod);
+                ["--context", context, "-n", namespace, "delete", "pod", pod, "--wait=false"]
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect()
+            },
+        };
+
+        self.start_kubectl(args);
+        Ok(())
+    }
+
+    /// 在后台运行修改集群或 kubeconfig 的 kubectl 命令，完成后清空输出缓存并通知界面
+    fn start_kubectl(&self, args: Vec<String>) {
+        let (outputs, failure) = (self.outputs.clone(), self.failure.clone());
+        *failure.lock().unwrap() = None;
+
+        std::thread::spawn(move || {
+            if let Err(e) = kubectl(&args) {
+                log::warn!("kubectl {} 失败: {}", args.join(" "), e);
+                *failure.lock().unwrap() = Some(e.to_string());
+            }
+            outputs.lock().unwrap().clear();
+            global_progress().mark_results_changed();
+        });
+    }
+}
+
+impl Plugin for KubernetesPlugin {
+    fn id(&self) -> &str {
+        "k8s"
+    }
+
+    fn name(&self) -> &str {
+        "Kubernetes"
+    }
+
+    fn description(&self) -> &str {
+        "切换上下文和命名空间，查看 Pod 状态和日志"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("layout-dashboard")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化 Kubernetes 插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let (view, filter) = parse_view(rest);
+        let mut results = Vec::new();
+        if let Some(failure) = self.failure.lock().unwrap().as_deref() {
+            results.push(self.result(
+                "k8s:failure".to_string(),
+                "kubectl 执行失败".to_string(),
+                failure.to_string(),
+                1001,
+                ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+            ));
+        }
+        results.extend(match view {
+            View::Contexts => self.context_results(filter),
+            View::Namespaces => self.namespace_results(filter),
+            View::Pods => self.pod_results(filter),
+        });
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制 Pod 名称: {}", text);
+            },
+            ActionData::Custom { data, .. } => {
+                if let Ok(action) = serde_json::from_str(data) {
+                    self.run_action(action)?;
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.outputs.lock().unwrap().clear();
+        *self.failure.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for KubernetesPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kubectl_output() {
+        let config = r#"{
+            "current-context": "prod",
+            "contexts": [
+                { "name": "dev", "context": { "cluster": "kind-dev" } },
+                { "name": "prod", "context": { "cluster": "eks-prod", "namespace": "shop" } }
+            ]
+        }"#;
+        let config = KubeConfig::parse(config).unwrap();
+        assert_eq!(config.contexts.len(), 2);
+        assert_eq!(config.contexts[0].namespace, None);
+        assert_eq!(config.current(), Some(("prod", "shop")));
+
+        let pods = r#"{ "items": [
+            { "metadata": { "name": "web-1" }, "status": { "phase": "Running",
+              "containerStatuses": [
+                { "ready": true, "restartCount": 0, "state": { "running": {} } },
+                { "ready": false, "restartCount": 7,
+                  "state": { "waiting": { "reason": "CrashLoopBackOff" } } }
+              ] } },
+            { "metadata": { "name": "job-1", "deletionTimestamp": "2024-01-01T00:00:00Z" },
+              "status": { "phase": "Succeeded" } }
+        ] }"#;
+        let pods = parse_pods(pods);
+        assert_eq!(pods[0], Pod {
+            name: "web-1".to_string(),
+            status: "CrashLoopBackOff".to_string(),
+            ready: 1,
+            containers: 2,
+            restarts: 7,
+        });
+        assert_eq!(pods[1].status, "Terminating");
+
+        let namespaces = r#"{ "items": [ { "metadata": { "name": "default" } } ] }"#;
+        assert_eq!(parse_namespaces(namespaces), ["default"]);
+
+        assert_eq!(parse_view(" pods web "), (View::Pods, "web"));
+        assert_eq!(parse_view(" ns"), (View::Namespaces, ""));
+        assert_eq!(parse_view(" prod"), (View::Contexts, "prod"));
+    }
+}
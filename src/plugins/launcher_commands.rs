@@ -0,0 +1,281 @@
+use anyhow::Result;
+
+/// 启动器命令插件
+///
+/// 以 `>` 开头的查询列出启动器自身的命令（打开设置、管理插件、重新加载插件、重建索引、
+/// 切换主题、切换预览面板、退出），按中英文名称模糊匹配；命令由启动器窗口拦截执行，
+/// 结果排在同一前缀的 Shell 命令执行结果之前
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::fuzzy::{match_ranges, FuzzyMatcher},
+};
+
+/// 插件 ID，也是命令结果 `ActionData::Custom` 的插件名
+pub const PLUGIN_ID: &str = "commands";
+
+/// 查询前缀
+const PREFIX: char = '>';
+
+/// 命令结果的基础分数，高于命令执行器对同一查询给出的结果
+const BASE_SCORE: u32 = 1000;
+
+/// 启动器命令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LauncherCommand {
+    /// 打开配置文件
+    OpenSettings,
+    /// 打开插件页
+    ManagePlugins,
+    /// 重新读取配置并刷新所有插件
+    ReloadPlugins,
+    /// 重新扫描应用和文件索引
+    RebuildIndex,
+    /// 进入主题切换
+    SwitchTheme,
+    /// 切换预览面板的显示方式
+    TogglePreview,
+    /// 退出启动器
+    Quit,
+}
+
+impl LauncherCommand {
+    /// 所有命令，按列出的顺序排列
+    pub const ALL: [LauncherCommand; 7] = [
+        LauncherCommand::OpenSettings,
+        LauncherCommand::ManagePlugins,
+        LauncherCommand::ReloadPlugins,
+        LauncherCommand::RebuildIndex,
+        LauncherCommand::SwitchTheme,
+        LauncherCommand::TogglePreview,
+        LauncherCommand::Quit,
+    ];
+
+    /// 结果数据中的名称
+    pub fn key(self) -> &'static str {
+        match self {
+            LauncherCommand::OpenSettings => "open_settings",
+            LauncherCommand::ManagePlugins => "manage_plugins",
+            LauncherCommand::ReloadPlugins => "reload_plugins",
+            LauncherCommand::RebuildIndex => "rebuild_index",
+            LauncherCommand::SwitchTheme => "switch_theme",
+            LauncherCommand::TogglePreview => "toggle_preview",
+            LauncherCommand::Quit => "quit",
+        }
+    }
+
+    /// 由结果数据中的名称解析命令
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|command| command.key() == key)
+    }
+
+    /// 由命令结果解析命令，其他插件的结果返回 `None`
+    pub fn from_result(result: &SearchResult) -> Option<Self> {
+        match &result.action {
+            ActionData::Custom { plugin, data } if plugin == PLUGIN_ID => Self::from_key(data),
+            _ => None,
+        }
+    }
+
+    /// 标题
+    fn title(self) -> &'static str {
+        match self {
+            LauncherCommand::OpenSettings => "打开设置",
+            LauncherCommand::ManagePlugins => "管理插件",
+            LauncherCommand::ReloadPlugins => "重新加载插件",
+            LauncherCommand::RebuildIndex => "重建索引",
+            LauncherCommand::SwitchTheme => "切换主题",
+            LauncherCommand::TogglePreview => "切换预览面板",
+            LauncherCommand::Quit => "退出 WeRun",
+        }
+    }
+
+    /// 英文名称，用于匹配英文查询
+    fn english(self) -> &'static str {
+        match self {
+            LauncherCommand::OpenSettings => "Open settings",
+            LauncherCommand::ManagePlugins => "Manage plugins",
+            LauncherCommand::ReloadPlugins => "Reload plugins",
+            LauncherCommand::RebuildIndex => "Rebuild index",
+            LauncherCommand::SwitchTheme => "Switch theme",
+            LauncherCommand::TogglePreview => "Toggle preview panel",
+            LauncherCommand::Quit => "Quit",
+        }
+    }
+
+    /// 说明
+    fn description(self) -> &'static str {
+        match self {
+            LauncherCommand::OpenSettings => "用默认程序打开配置文件",
+            LauncherCommand::ManagePlugins => "启用或关闭插件，设置别名、优先级和插件设置",
+            LauncherCommand::ReloadPlugins => "重新读取配置文件并刷新所有插件",
+            LauncherCommand::RebuildIndex => "重新扫描应用和文件索引",
+            LauncherCommand::SwitchTheme => "列出可用的主题，Enter 立即切换",
+            LauncherCommand::TogglePreview => "在自动、显示、隐藏之间切换（Ctrl+P）",
+            LauncherCommand::Quit => "退出启动器，停止所有后台任务",
+        }
+    }
+}
+
+/// 启动器命令插件
+pub struct LauncherCommandsPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl LauncherCommandsPlugin {
+    /// 创建新的启动器命令插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 去掉查询前缀，查询不以前缀开头时返回 `None`
+    fn strip_prefix(query: &str) -> Option<&str> {
+        query.trim().strip_prefix(PREFIX).map(str::trim)
+    }
+
+    /// 按中英文名称模糊匹配命令，查询为空时按固定顺序列出
+    fn filter_commands(query: &str) -> Vec<(LauncherCommand, u32)> {
+        let count = LauncherCommand::ALL.len() as u32;
+        if query.is_empty() {
+            return LauncherCommand::ALL
+                .into_iter()
+                .enumerate()
+                .map(|(index, command)| (command, count - index as u32))
+                .collect();
+        }
+
+        let matcher = FuzzyMatcher::new(global_config().get_config().search.boundary_bonus);
+        let mut matched: Vec<(LauncherCommand, u32)> = LauncherCommand::ALL
+            .into_iter()
+            .filter_map(|command| {
+                [command.title(), command.english()]
+                    .into_iter()
+                    .filter_map(|name| {
+                        let (is_match, score) = matcher.fuzzy_match(query, name);
+                        is_match.then_some(score)
+                    })
+                    .max()
+                    .map(|score| (command, score))
+            })
+            .collect();
+        matched.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        matched
+    }
+
+    /// 构造命令的结果
+    fn command_result(&self, command: LauncherCommand, query: &str, score: u32) -> SearchResult {
+        SearchResult::new(
+            format!("{}:{}", PLUGIN_ID, command.key()),
+            command.title().to_string(),
+            format!("{} · {}", command.english(), command.description()),
+            ResultType::Command,
+            BASE_SCORE + score,
+            ActionData::Custom { plugin: PLUGIN_ID.to_string(), data: command.key().to_string() },
+        )
+        .with_title_highlights(match_ranges(query, command.title()))
+    }
+
+    /// 搜索命令
+    fn command_results(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        Self::filter_commands(query)
+            .into_iter()
+            .take(limit)
+            .map(|(command, score)| self.command_result(command, query, score))
+            .collect()
+    }
+}
+
+impl Plugin for LauncherCommandsPlugin {
+    fn id(&self) -> &str {
+        PLUGIN_ID
+    }
+
+    fn name(&self) -> &str {
+        "启动器命令"
+    }
+
+    fn description(&self) -> &str {
+        "输入 > 查找并执行启动器自身的命令"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn placeholder(&self) -> Option<&str> {
+        Some("输入 > 和命令名称，如 > 重建索引")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化启动器命令插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = Self::strip_prefix(query) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self.command_results(rest, limit))
+    }
+
+    fn default_results(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.command_results("", limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        // 命令需要操作启动器窗口，由窗口在执行结果前拦截
+        anyhow::bail!("启动器命令只能在启动器窗口中执行: {}", result.title)
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for LauncherCommandsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_commands() {
+        assert_eq!(LauncherCommandsPlugin::strip_prefix("> reload"), Some("reload"));
+        assert_eq!(LauncherCommandsPlugin::strip_prefix("reload"), None);
+
+        let all = LauncherCommandsPlugin::filter_commands("");
+        assert_eq!(all.len(), LauncherCommand::ALL.len());
+        assert_eq!(all[0].0, LauncherCommand::OpenSettings);
+
+        let matched = LauncherCommandsPlugin::filter_commands("rebuild");
+        assert_eq!(matched[0].0, LauncherCommand::RebuildIndex);
+        let matched = LauncherCommandsPlugin::filter_commands("主题");
+        assert_eq!(matched[0].0, LauncherCommand::SwitchTheme);
+
+        for command in LauncherCommand::ALL {
+            assert_eq!(LauncherCommand::from_key(command.key()), Some(command));
+        }
+        assert_eq!(LauncherCommand::from_key("shutdown"), None);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        links::{self, Page},
+        shell,
+    },
+};
+
+/// 查询关键字
+const KEYWORD: &str = "link";
+
+/// 最多保留的网页结果数
+const MAX_PAGES: usize = 32;
+
+/// 打开链接的结果
+#[derive(Clone)]
+enum PageLookup {
+    /// 正在打开
+    Pending,
+    /// 打开完成
+    Done(std::result::Result<Page, String>),
+}
+
+/// 链接工具插件
+///
+/// `link <链接>` 去除链接中的 `utm_*`、`fbclid` 等跟踪参数，在后台跟随重定向展开短链接并读取
+/// 网页标题，生成 Markdown 链接；只输入 `link` 时处理剪贴板中的链接，Enter 复制结果
+pub struct LinkToolsPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 已打开的链接，键为清理后的链接
+    pages: Arc<Mutex<HashMap<String, PageLookup>>>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl LinkToolsPlugin {
+    /// 创建新的链接工具插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            pages: Arc::new(Mutex::new(HashMap::new())),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 构造可复制的结果项，附带在浏览器中打开的动作
+    fn result(&self, id: &str, title: String, description: String, url: &str) -> SearchResult {
+        SearchResult::new(
+            format!("link:{}", id),
+            title.clone(),
+            description,
+            ResultType::Custom("link".to_string()),
+            1000,
+            ActionData::CopyToClipboard { text: title },
+        )
+        .with_secondary_actions(vec![SecondaryAction::new(
+            "在浏览器中打开",
+            ActionData::OpenUrl { url: url.to_string() },
+        )])
+    }
+
+    /// 不可复制的提示或状态结果项
+    fn status(&self, id: &str, title: String, description: String) -> SearchResult {
+        SearchResult::new(
+            format!("link:{}", id),
+            title,
+            description,
+            ResultType::Custom("link".to_string()),
+            1000,
+            ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+        )
+    }
+
+    /// 打开链接的结果，未打开过的链接在后台打开
+    fn lookup(&self, url: &str) -> PageLookup {
+        let mut pages = self.pages.lock().unwrap();
+        if pages.len() >= MAX_PAGES && !pages.contains_key(url) {
+            pages.clear();
+        }
+        pages
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                self.start_fetch(url);
+                PageLookup::Pending
+            })
+            .clone()
+    }
+
+    /// 在后台跟随重定向打开链接并读取标题
+    fn start_fetch(&self, url: &str) {
+        let (pages, url) = (self.pages.clone(), url.to_string());
+        std::thread::spawn(move || {
+            let page = links::fetch_page(&url).map_err(|e| {
+                log::warn!("打开链接失败: {}: {}", url, e);
+                e.to_string()
+            });
+            pages.lock().unwrap().insert(url, PageLookup::Done(page));
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// 链接的各项结果：清理后的链接、展开后的链接和 Markdown 链接
+    fn link_results(&self, url: &str) -> Vec<SearchResult> {
+        let (cleaned, removed) = links::strip_tracking(url);
+        let description = if removed > 0 {
+            format!("已去除 {} 个跟踪参数 · 按 Enter 复制", removed)
+        } else {
+            "没有跟踪参数 · 按 Enter 复制".to_string()
+        };
+        let mut results = vec![self.result("clean", cleaned.clone(), description, &cleaned)];
+
+        match self.lookup(&cleaned) {
+            PageLookup::Pending => {
+                results.push(self.status(
+                    "pending",
+                    format!("正在打开 {}…", cleaned),
+                    "跟随重定向并读取网页标题".to_string(),
+                ));
+            },
+            PageLookup::Done(Err(e)) => {
+                results.push(self.status("error", "无法打开链接".to_string(), e));
+            },
+            PageLookup::Done(Ok(page)) => {
+                let (expanded, _) = links::strip_tracking(&page.url);
+                if expanded != cleaned {
+                    results.push(self.result(
+                        "expanded",
+                        expanded.clone(),
+                        "展开后的链接（已去除跟踪参数） · 按 Enter 复制".to_string(),
+                        &expanded,
+                    ));
+                }
+                let title = page.title.as_deref().unwrap_or(&expanded);
+                results.push(self.result(
+                    "markdown",
+                    links::markdown_link(title, &expanded),
+                    "Markdown 链接 · 按 Enter 复制".to_string(),
+                    &expanded,
+                ));
+            },
+        }
+
+        results
+    }
+}
+
+impl Plugin for LinkToolsPlugin {
+    fn id(&self) -> &str {
+        "link"
+    }
+
+    fn name(&self) -> &str {
+        "链接工具"
+    }
+
+    fn description(&self) -> &str {
+        "展开短链接、去除跟踪参数、生成 Markdown 链接"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("globe")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化链接工具插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let typed = rest.trim();
+        let url = if typed.is_empty() {
+            self.clipboard_manager.get_text().unwrap_or_default().trim().to_string()
+        } else {
+            typed.to_string()
+        };
+        if !links::is_url(&url) {
+            return Ok(vec![self.status(
+                "usage",
+                "link <链接>".to_string(),
+                "展开短链接、去除跟踪参数、生成 Markdown 链接".to_string(),
+            )]);
+        }
+
+        Ok(self.link_results(&url))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制链接: {}", text);
+            },
+            ActionData::OpenUrl { url } => {
+                shell::open_url(url)?;
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.pages.lock().unwrap().clear();
+        Ok(())
+    }
+}
+
+impl Default for LinkToolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
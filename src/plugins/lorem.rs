@@ -0,0 +1,547 @@
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{clipboard::ClipboardManager, random},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "lorem";
+
+/// 标题显示的最大字符数
+const TITLE_CHARS: usize = 100;
+
+/// 段落的固定开头
+const LOREM_OPENING: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.";
+
+/// 占位文本的单词
+static LOREM_WORDS: Lazy<Vec<&str>> = Lazy::new(|| {
+    "lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor incididunt ut \
+     labore et dolore magna aliqua enim ad minim veniam quis nostrud exercitation ullamco laboris \
+     nisi aliquip ex ea commodo consequat duis aute irure in reprehenderit voluptate velit esse \
+     cillum fugiat nulla pariatur excepteur sint occaecat cupidatat non proident sunt culpa qui \
+     officia deserunt mollit anim id est laborum"
+        .split_whitespace()
+        .collect()
+});
+
+/// 英文名
+const FIRST_NAMES: &[&str] = &[
+    "James",
+    "Mary",
+    "John",
+    "Patricia",
+    "Robert",
+    "Jennifer",
+    "Michael",
+    "Linda",
+    "William",
+    "Elizabeth",
+    "David",
+    "Barbara",
+    "Richard",
+    "Susan",
+    "Joseph",
+    "Jessica",
+    "Thomas",
+    "Sarah",
+];
+
+/// 英文姓
+const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Williams", "Brown", "Jones", "Garcia", "Miller", "Davis", "Wilson",
+    "Anderson", "Taylor", "Moore", "Jackson", "Martin", "Lee", "Harris", "Clark", "Lewis",
+];
+
+/// 中文姓
+const SURNAMES: &[&str] = &[
+    "王", "李", "张", "刘", "陈", "杨", "黄", "赵", "吴", "周", "徐", "孙", "马", "朱", "胡", "林",
+];
+
+/// 中文名
+const GIVEN_NAMES: &[&str] = &[
+    "伟", "芳", "娜", "敏", "静", "磊", "洋", "杰", "涛", "超", "秀英", "建华", "志强", "晓东",
+    "雨轩", "子涵", "浩然", "欣怡",
+];
+
+/// 邮箱域名（保留给文档和示例使用的域名）
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+/// 英文地址的街道名
+const STREETS: &[&str] =
+    &["Maple", "Oak", "Pine", "Cedar", "Elm", "Washington", "Lake", "Hill", "Park", "Main"];
+
+/// 英文地址的街道类型
+const STREET_SUFFIXES: &[&str] = &["St", "Ave", "Rd", "Blvd", "Ln", "Dr"];
+
+/// 英文地址的城市、州和邮编前缀
+const CITIES: &[(&str, &str, &str)] = &[
+    ("Springfield", "IL", "627"),
+    ("Portland", "OR", "972"),
+    ("Austin", "TX", "787"),
+    ("Denver", "CO", "802"),
+    ("Columbus", "OH", "432"),
+    ("Madison", "WI", "537"),
+];
+
+/// 中文地址的城区
+const DISTRICTS: &[&str] = &[
+    "北京市朝阳区",
+    "上海市浦东新区",
+    "广州市天河区",
+    "深圳市南山区",
+    "杭州市西湖区",
+    "成都市武侯区",
+];
+
+/// 中文地址的道路
+const ROADS: &[&str] = &["建国路", "人民路", "解放路", "中山路", "和平路", "长江路", "新华路"];
+
+/// 美国电话区号（号码使用虚构的 555-01XX 段）
+const AREA_CODES: &[&str] = &["201", "212", "312", "415", "503", "617", "713"];
+
+/// 中国手机号段
+const MOBILE_PREFIXES: &[&str] = &["130", "135", "138", "139", "150", "158", "186", "188"];
+
+/// 插件配置（`[plugins.settings.lorem]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct LoremSettings {
+    /// 姓名、地址和电话的风格：`zh` 或 `en`
+    locale: String,
+}
+
+impl Default for LoremSettings {
+    fn default() -> Self {
+        Self { locale: "zh".to_string() }
+    }
+}
+
+/// 生成的数据类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// 段落
+    Paragraphs,
+    /// 句子
+    Sentences,
+    /// 单词
+    Words,
+    /// 姓名
+    Name,
+    /// 邮箱
+    Email,
+    /// 地址
+    Address,
+    /// 电话
+    Phone,
+    /// 国际银行账号
+    Iban,
+}
+
+impl Kind {
+    /// 全部类型及其名称，按列出顺序
+    const ALL: [(Kind, &'static [&'static str]); 8] = [
+        (Kind::Paragraphs, &["p", "para", "paragraph", "paragraphs", "段落"]),
+        (Kind::Sentences, &["s", "sentence", "sentences", "句子"]),
+        (Kind::Words, &["w", "word", "words", "单词"]),
+        (Kind::Name, &["name", "names", "姓名"]),
+        (Kind::Email, &["email", "emails", "mail", "邮箱"]),
+        (Kind::Address, &["address", "addresses", "addr", "地址"]),
+        (Kind::Phone, &["phone", "phones", "tel", "电话"]),
+        (Kind::Iban, &["iban", "ibans"]),
+    ];
+
+    /// 按名称查找
+    fn from_name(name: &str) -> Option<Kind> {
+        let name = name.to_lowercase();
+        Kind::ALL.iter().find(|(_, names)| names.contains(&name.as_str())).map(|(kind, _)| *kind)
+    }
+
+    /// 显示名称
+    fn label(&self) -> &'static str {
+        match self {
+            Kind::Paragraphs => "段落",
+            Kind::Sentences => "句子",
+            Kind::Words => "单词",
+            Kind::Name => "姓名",
+            Kind::Email => "邮箱",
+            Kind::Address => "地址",
+            Kind::Phone => "电话",
+            Kind::Iban => "IBAN",
+        }
+    }
+
+    /// 是否为占位文本（数量为段落、句子或单词数，只生成一个结果）
+    fn is_text(&self) -> bool {
+        matches!(self, Kind::Paragraphs | Kind::Sentences | Kind::Words)
+    }
+
+    /// 默认数量
+    fn default_count(&self) -> usize {
+        match self {
+            Kind::Paragraphs => 1,
+            Kind::Sentences => 3,
+            Kind::Words => 10,
+            _ => 5,
+        }
+    }
+
+    /// 数量上限
+    fn max_count(&self) -> usize {
+        match self {
+            Kind::Paragraphs => 20,
+            Kind::Sentences => 50,
+            Kind::Words => 500,
+            _ => 20,
+        }
+    }
+}
+
+/// 随机选取一项
+fn pick<'a>(items: &[&'a str]) -> Result<&'a str> {
+    Ok(items[random::below(items.len() as u64)? as usize])
+}
+
+/// 闭区间内的随机数
+fn between(min: usize, max: usize) -> Result<usize> {
+    Ok(random::in_range(min as i64, max as i64)? as usize)
+}
+
+/// 指定位数的随机数字串
+fn digits(count: usize) -> Result<String> {
+    (0..count).map(|_| Ok(char::from(b'0' + random::below(10)? as u8))).collect()
+}
+
+/// 首字母大写
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// 随机单词
+fn words(count: usize) -> Result<String> {
+    Ok((0..count).map(|_| pick(&LOREM_WORDS)).collect::<Result<Vec<_>>>()?.join(" "))
+}
+
+/// 随机句子：6 到 14 个单词，较长的句子中间加逗号
+fn sentence() -> Result<String> {
+    let count = between(6, 14)?;
+    let mut words: Vec<String> =
+        (0..count).map(|_| pick(&LOREM_WORDS).map(str::to_string)).collect::<Result<_>>()?;
+    if count >= 10 {
+        let comma = between(3, count - 3)?;
+        words[comma].push(',');
+    }
+    Ok(format!("{}.", capitalize(&words.join(" "))))
+}
+
+/// 随机段落：4 到 7 个句子，第一段以 "Lorem ipsum dolor sit amet" 开头
+fn paragraph(first: bool) -> Result<String> {
+    let mut sentences: Vec<String> =
+        (0..between(4, 7)?).map(|_| sentence()).collect::<Result<_>>()?;
+    if first {
+        sentences[0] = LOREM_OPENING.to_string();
+    }
+    Ok(sentences.join(" "))
+}
+
+/// IBAN：国家代码 + 校验码 + BBAN，每 4 个字符一组
+///
+/// 校验码按 ISO 13616：BBAN + 国家代码 + "00" 中的字母换为 10-35，对 97 取余，98 减去余数
+pub fn iban(country: &str, bban: &str) -> String {
+    let rearranged = format!("{}{}00", bban, country);
+    let remainder = rearranged.chars().fold(0u32, |remainder, c| {
+        let value = c.to_digit(36).unwrap_or_default();
+        let width = if value >= 10 { 100 } else { 10 };
+        (remainder * width + value) % 97
+    });
+    let compact = format!("{}{:02}{}", country, 98 - remainder, bban);
+    compact
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 单行显示：超长截断
+fn display_line(text: &str) -> String {
+    if text.chars().count() > TITLE_CHARS {
+        format!("{}...", text.chars().take(TITLE_CHARS).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+/// 解析参数：类型和数量，顺序不限，如 `p 3`、`5 email`
+fn parse_request(argument: &str) -> Option<(Kind, usize)> {
+    let parts: Vec<&str> = argument.split_whitespace().collect();
+    let (kind, count) = match parts.as_slice() {
+        [name] => (Kind::from_name(name)?, None),
+        [first, second] => match (Kind::from_name(first), Kind::from_name(second)) {
+            (Some(kind), None) => (kind, Some(second.parse::<usize>().ok()?)),
+            (None, Some(kind)) => (kind, Some(first.parse::<usize>().ok()?)),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let count = count.unwrap_or(kind.default_count());
+    (1..=kind.max_count()).contains(&count).then_some((kind, count))
+}
+
+/// 占位文本和假数据插件
+///
+/// `lorem` 列出各类示例，`lorem p 3`、`lorem words 50`、`lorem email 10` 等指定类型和数量：
+/// 占位文本生成一段文字，姓名、邮箱、地址、电话和 IBAN 每项一个结果，Enter 复制
+pub struct LoremPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: LoremSettings,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl LoremPlugin {
+    /// 创建新的占位文本插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            settings: LoremSettings::default(),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 是否生成中文风格的姓名、地址和电话
+    fn chinese(&self) -> bool {
+        self.settings.locale != "en"
+    }
+
+    /// 生成占位文本，段落之间空一行
+    fn text(&self, kind: Kind, count: usize) -> Result<String> {
+        Ok(match kind {
+            Kind::Paragraphs => (0..count)
+                .map(|index| paragraph(index == 0))
+                .collect::<Result<Vec<_>>>()?
+                .join("\n\n"),
+            Kind::Sentences => {
+                (0..count).map(|_| sentence()).collect::<Result<Vec<_>>>()?.join(" ")
+            },
+            _ => words(count)?,
+        })
+    }
+
+    /// 生成一项假数据
+    fn record(&self, kind: Kind) -> Result<String> {
+        Ok(match kind {
+            Kind::Name if self.chinese() => format!("{}{}", pick(SURNAMES)?, pick(GIVEN_NAMES)?),
+            Kind::Name => format!("{} {}", pick(FIRST_NAMES)?, pick(LAST_NAMES)?),
+            Kind::Email => format!(
+                "{}.{}{}@{}",
+                pick(FIRST_NAMES)?.to_lowercase(),
+                pick(LAST_NAMES)?.to_lowercase(),
+                between(1, 99)?,
+                pick(EMAIL_DOMAINS)?
+            ),
+            Kind::Address if self.chinese() => {
+                format!("{}{} {} 号", pick(DISTRICTS)?, pick(ROADS)?, between(1, 300)?)
+            },
+            Kind::Address => {
+                let (city, state, zip) = CITIES[random::below(CITIES.len() as u64)? as usize];
+                format!(
+                    "{} {} {}, {}, {} {}{}",
+                    between(100, 9999)?,
+                    pick(STREETS)?,
+                    pick(STREET_SUFFIXES)?,
+                    city,
+                    state,
+                    zip,
+                    digits(2)?
+                )
+            },
+            Kind::Phone if self.chinese() => {
+                format!("{} {} {}", pick(MOBILE_PREFIXES)?, digits(4)?, digits(4)?)
+            },
+            Kind::Phone => format!("({}) 555-01{}", pick(AREA_CODES)?, digits(2)?),
+            Kind::Iban => match random::below(3)? {
+                0 => iban("DE", &digits(18)?),
+                1 => iban("ES", &digits(20)?),
+                _ => iban("NL", &format!("{}{}", pick(&["ABNA", "INGB", "RABO"])?, digits(10)?)),
+            },
+            _ => self.text(kind, 1)?,
+        })
+    }
+
+    /// 结果项
+    fn result(&self, id: String, text: String, description: String, score: u32) -> SearchResult {
+        SearchResult::new(
+            format!("lorem:{}", id),
+            display_line(&text.replace("\n\n", " ¶ ")),
+            description,
+            ResultType::Custom("lorem".to_string()),
+            score,
+            ActionData::CopyToClipboard { text },
+        )
+    }
+
+    /// 占位文本结果，多段时在预览中逐段显示
+    fn text_result(&self, kind: Kind, count: usize, score: u32) -> Result<SearchResult> {
+        let text = self.text(kind, count)?;
+        let paragraphs: Vec<PreviewRow> = text
+            .split("\n\n")
+            .enumerate()
+            .map(|(index, paragraph)| PreviewRow::new((index + 1).to_string(), paragraph))
+            .collect();
+        let description = format!("{} 个{} · 按 Enter 复制", count, kind.label());
+        let result = self.result(format!("{:?}", kind), text, description, score);
+        Ok(if paragraphs.len() > 1 {
+            result.with_preview(Preview::new().section("段落", paragraphs))
+        } else {
+            result
+        })
+    }
+
+    /// 指定类型和数量的结果
+    fn kind_results(&self, kind: Kind, count: usize) -> Result<Vec<SearchResult>> {
+        if kind.is_text() {
+            return Ok(vec![self.text_result(kind, count, 1000)?]);
+        }
+
+        let records: Vec<String> = (0..count).map(|_| self.record(kind)).collect::<Result<_>>()?;
+        let mut results = Vec::new();
+        if records.len() > 1 {
+            results.push(self.result(
+                format!("{:?}:all", kind),
+                records.join("\n"),
+                format!("全部 {} 个{}（每行一个） · 按 Enter 复制", records.len(), kind.label()),
+                1000,
+            ));
+        }
+        for (index, record) in records.into_iter().enumerate() {
+            results.push(self.result(
+                format!("{:?}:{}", kind, index),
+                record,
+                format!("{} · 按 Enter 复制", kind.label()),
+                999u32.saturating_sub(index as u32),
+            ));
+        }
+        Ok(results)
+    }
+}
+
+impl Plugin for LoremPlugin {
+    fn id(&self) -> &str {
+        "lorem"
+    }
+
+    fn name(&self) -> &str {
+        "占位文本"
+    }
+
+    fn description(&self) -> &str {
+        "生成 Lorem ipsum 占位文本和姓名、邮箱、地址等假数据"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化占位文本插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        if rest.trim().is_empty() {
+            return Kind::ALL
+                .iter()
+                .enumerate()
+                .map(|(index, (kind, names))| {
+                    let score = 1000u32.saturating_sub(index as u32);
+                    if kind.is_text() {
+                        return self.text_result(*kind, kind.default_count(), score);
+                    }
+                    let description =
+                        format!("{} · 按 Enter 复制，lorem {} 10 生成多个", kind.label(), names[0]);
+                    Ok(self.result(format!("{:?}", kind), self.record(*kind)?, description, score))
+                })
+                .collect();
+        }
+
+        match parse_request(rest) {
+            Some((kind, count)) => self.kind_results(kind, count),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            self.clipboard_manager.set_text(text)?;
+            log::info!("复制占位数据: {}", result.id);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for LoremPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iban_and_requests() {
+        // ISO 13616 的德国示例
+        assert_eq!(iban("DE", "370400440532013000"), "DE89 3704 0044 0532 0130 00");
+        assert_eq!(iban("GB", "NWBK60161331926819"), "GB29 NWBK 6016 1331 9268 19");
+
+        assert_eq!(parse_request("p 3"), Some((Kind::Paragraphs, 3)));
+        assert_eq!(parse_request("5 email"), Some((Kind::Email, 5)));
+        assert_eq!(parse_request("words"), Some((Kind::Words, 10)));
+        assert_eq!(parse_request("iban 0"), None);
+        assert_eq!(parse_request("p x"), None);
+
+        let text = LoremPlugin::new().text(Kind::Paragraphs, 2).unwrap();
+        assert!(text.starts_with(LOREM_OPENING));
+        assert_eq!(text.split("\n\n").count(), 2);
+    }
+}
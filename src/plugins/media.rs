@@ -0,0 +1,261 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// 媒体插件
+///
+/// `media` 显示各程序正在播放的曲目，Enter 播放/暂停，次要动作切换上一首、下一首；
+/// 当前会话另外列出单独的控制项，可直接搜索“下一首”等执行
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    platform::media::{self, MediaCommand, MediaSession, PlaybackStatus},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "media";
+
+/// 媒体动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct MediaAction {
+    /// 程序的 AppUserModelID
+    app_id: String,
+    /// 命令
+    command: Command,
+}
+
+/// 可序列化的播放控制命令
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+enum Command {
+    TogglePlayPause,
+    Next,
+    Previous,
+}
+
+impl From<Command> for MediaCommand {
+    fn from(command: Command) -> Self {
+        match command {
+            Command::TogglePlayPause => MediaCommand::TogglePlayPause,
+            Command::Next => MediaCommand::Next,
+            Command::Previous => MediaCommand::Previous,
+        }
+    }
+}
+
+/// 程序的显示名称
+///
+/// 桌面程序的 ID 为可执行文件名（`Spotify.exe`），商店应用为 `包名!应用名`，取应用名
+pub fn app_display_name(app_id: &str) -> String {
+    let name = app_id.rsplit('!').next().unwrap_or(app_id);
+    let name = name.strip_suffix(".exe").or_else(|| name.strip_suffix(".EXE")).unwrap_or(name);
+    name.to_string()
+}
+
+/// 曲目的显示标题，如 "Song — Artist"
+pub fn track_title(session: &MediaSession) -> String {
+    match (session.title.is_empty(), session.artist.is_empty()) {
+        (true, _) => "未知曲目".to_string(),
+        (false, true) => session.title.clone(),
+        (false, false) => format!("{} — {}", session.title, session.artist),
+    }
+}
+
+/// 媒体插件
+pub struct MediaPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl MediaPlugin {
+    /// 创建新的媒体插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 播放控制动作
+    fn action(&self, session: &MediaSession, command: Command) -> ActionData {
+        let action = MediaAction { app_id: session.app_id.clone(), command };
+        ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(&action).unwrap_or_default(),
+        }
+    }
+
+    /// 会话的曲目和播放控制
+    fn session_results(&self, filter: &str) -> Vec<SearchResult> {
+        let sessions = media::sessions().unwrap_or_else(|e| {
+            e.log();
+            Vec::new()
+        });
+        if sessions.is_empty() {
+            return vec![SearchResult::new(
+                "media:none".to_string(),
+                "没有正在播放的媒体".to_string(),
+                "支持 Spotify、浏览器等接入系统媒体控制的程序".to_string(),
+                ResultType::Custom("media".to_string()),
+                1000,
+                ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+            )];
+        }
+
+        let filter = filter.to_lowercase();
+        let mut results = Vec::new();
+
+        for (index, session) in sessions.iter().enumerate() {
+            let app = app_display_name(&session.app_id);
+            let mut secondary = Vec::new();
+            if session.can_next {
+                secondary.push(SecondaryAction::new("下一首", self.action(session, Command::Next)));
+            }
+            if session.can_previous {
+                secondary
+                    .push(SecondaryAction::new("上一首", self.action(session, Command::Previous)));
+            }
+
+            let title = track_title(session);
+            let hint = if session.status == PlaybackStatus::Playing { "暂停" } else { "播放" };
+            let description = format!("{} · {} · 按 Enter {}", app, session.status.label(), hint);
+            let matched = [&title, &session.album, &app]
+                .iter()
+                .any(|text| text.to_lowercase().contains(&filter));
+            if matched {
+                results.push(
+                    SearchResult::new(
+                        format!("media:{}", session.app_id),
+                        title,
+                        description,
+                        ResultType::Custom("media".to_string()),
+                        1000u32.saturating_sub(index as u32 * 10),
+                        self.action(session, Command::TogglePlayPause),
+                    )
+                    .with_secondary_actions(secondary),
+                );
+            }
+
+            // 当前会话的控制项单独列出，便于直接输入“下一首”执行
+            if !session.is_current {
+                continue;
+            }
+            let controls = [
+                (session.can_play_pause, "播放/暂停", "play pause", Command::TogglePlayPause),
+                (session.can_next, "下一首", "next", Command::Next),
+                (session.can_previous, "上一首", "previous", Command::Previous),
+            ];
+            for (enabled, label, keywords, command) in controls {
+                if !enabled || !(label.contains(&filter) || keywords.contains(&filter)) {
+                    continue;
+                }
+                results.push(SearchResult::new(
+                    format!("media:{}:{:?}", session.app_id, command),
+                    label.to_string(),
+                    format!("{} · {}", app, track_title(session)),
+                    ResultType::Custom("media".to_string()),
+                    if filter.is_empty() { 900 } else { 1000 },
+                    self.action(session, command),
+                ));
+            }
+        }
+
+        results
+    }
+}
+
+impl Plugin for MediaPlugin {
+    fn id(&self) -> &str {
+        "media"
+    }
+
+    fn name(&self) -> &str {
+        "媒体控制"
+    }
+
+    fn description(&self) -> &str {
+        "查看正在播放的曲目并控制播放"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化媒体插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.session_results(rest.trim()))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let action: MediaAction = serde_json::from_str(data)?;
+        media::send(&action.app_id, action.command.into())?;
+        log::info!("媒体控制: {} {:?}", action.app_id, action.command);
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for MediaPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_names() {
+        assert_eq!(app_display_name("Spotify.exe"), "Spotify");
+        assert_eq!(app_display_name("SpotifyAB.SpotifyMusic_zpdnekdrzrea0!Spotify"), "Spotify");
+        assert_eq!(app_display_name("MSEdge"), "MSEdge");
+
+        let mut session = MediaSession {
+            app_id: "Spotify.exe".to_string(),
+            title: "Song".to_string(),
+            artist: "Artist".to_string(),
+            album: String::new(),
+            status: PlaybackStatus::Playing,
+            is_current: true,
+            can_play_pause: true,
+            can_next: true,
+            can_previous: false,
+        };
+        assert_eq!(track_title(&session), "Song — Artist");
+        session.artist.clear();
+        assert_eq!(track_title(&session), "Song");
+        session.title.clear();
+        assert_eq!(track_title(&session), "未知曲目");
+    }
+}
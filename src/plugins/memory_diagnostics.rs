@@ -0,0 +1,151 @@
+use anyhow::Result;
+
+/// 内存诊断插件
+///
+/// `/memory` 或 `memory` 查询显示已登记缓存的内存占用和预算，可立即裁剪或释放单个缓存
+use crate::core::{
+    cache::{format_bytes, global_caches},
+    config_manager::global_config,
+    plugin::{Plugin, PluginIcon},
+    preview::{Preview, PreviewRow},
+    search::{ActionData, ResultType, SearchResult},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "memory";
+
+/// 按预算裁剪的动作
+const TRIM_ACTION: &str = "trim";
+
+/// 释放单个缓存的动作前缀
+const RELEASE_PREFIX: &str = "release:";
+
+/// 内存诊断插件
+pub struct MemoryDiagnosticsPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl MemoryDiagnosticsPlugin {
+    /// 创建新的内存诊断插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 诊断结果：总占用和预算在前，之后按占用从大到小列出各缓存
+    fn usage_results(&self, filter: &str) -> Vec<SearchResult> {
+        let usage = global_caches().usage();
+        let total: usize = usage.iter().map(|u| u.bytes).sum();
+        let budget = global_config().memory().budget_bytes();
+
+        let rows = usage.iter().map(|u| PreviewRow::new(&u.name, format_bytes(u.bytes))).collect();
+        let mut results = vec![SearchResult::new(
+            "memory:summary".to_string(),
+            format!("缓存占用 {} / 预算 {}", format_bytes(total), format_bytes(budget)),
+            "按 Enter 立即将缓存裁剪到预算以内".to_string(),
+            ResultType::SystemCommand,
+            1000,
+            self.action(TRIM_ACTION.to_string()),
+        )
+        .with_preview(Preview::new().section("缓存", rows))];
+
+        let filter = filter.to_lowercase();
+        for (index, u) in usage.iter().enumerate() {
+            if !u.name.to_lowercase().contains(&filter) {
+                continue;
+            }
+
+            let percent = if total == 0 { 0.0 } else { u.bytes as f64 * 100.0 / total as f64 };
+            results.push(SearchResult::new(
+                format!("memory:cache:{}", u.name),
+                u.name.clone(),
+                format!("{} · 占 {:.0}% · 按 Enter 释放此缓存", format_bytes(u.bytes), percent),
+                ResultType::Custom("memory".to_string()),
+                900u32.saturating_sub(index as u32),
+                self.action(format!("{}{}", RELEASE_PREFIX, u.name)),
+            ));
+        }
+
+        results
+    }
+
+    /// 插件动作
+    fn action(&self, data: String) -> ActionData {
+        ActionData::Custom { plugin: self.id().to_string(), data }
+    }
+}
+
+impl Plugin for MemoryDiagnosticsPlugin {
+    fn id(&self) -> &str {
+        "memory"
+    }
+
+    fn name(&self) -> &str {
+        "内存诊断"
+    }
+
+    fn description(&self) -> &str {
+        "查看各缓存的内存占用并裁剪"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("layout-dashboard")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化内存诊断插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.usage_results(rest.trim()))
+    }
+
+    fn default_results(&self, _limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.usage_results(""))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+
+        if data == TRIM_ACTION {
+            let freed = global_caches().trim();
+            log::info!("按预算裁剪缓存，共释放 {}", format_bytes(freed));
+        } else if let Some(name) = data.strip_prefix(RELEASE_PREFIX) {
+            let freed = global_caches().release(name);
+            log::info!("释放缓存 {}: {}", name, format_bytes(freed));
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for MemoryDiagnosticsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,13 +2,44 @@
 ///
 /// 提供启动器的各种功能插件
 pub mod app_launcher;
+pub mod audio;
+pub mod browser_history;
 pub mod calculator;
 pub mod clipboard;
 pub mod color_picker;
 pub mod command_executor;
+pub mod cron;
 pub mod custom_commands;
+pub mod dev_context;
+pub mod display;
+pub mod encoding;
 pub mod file_search;
+pub mod folder_jump;
+pub mod games;
+pub mod issue_tracker;
+pub mod kubernetes;
+pub mod launcher_commands;
+pub mod link_tools;
+pub mod lorem;
+pub mod media;
+pub mod memory_diagnostics;
+pub mod network_tools;
+pub mod password_generator;
+pub mod password_vault;
+pub mod projects;
+pub mod quick_note;
+pub mod quote;
+pub mod random_data;
+pub mod services;
+pub mod settings_search;
+#[cfg(any(debug_assertions, test))]
+pub mod stress_test;
 pub mod system_commands;
+pub mod system_info;
 pub mod task_manager;
+pub mod terminal;
+pub mod text_tools;
+pub mod theme_switcher;
 pub mod web_search;
 pub mod window_switcher;
+pub mod world_clock;
@@ -0,0 +1,626 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, ToSocketAddrs, UdpSocket},
+    process::Command,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+/// 网络工具插件
+///
+/// `ip` 显示本机和公网 IP，`ping <主机>` 在后台连续 ping 并在结果中实时显示延迟，
+/// `dns <域名>` 解析地址，`port <端口>` 查看监听该端口的进程
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        progress::{global_progress, ProgressTask},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{clipboard::ClipboardManager, http::download},
+};
+
+/// 查询关键字
+pub const KEYWORDS: &[&str] = &["ip", "ping", "dns", "port"];
+
+/// 每次 ping 的次数
+const PING_COUNT: usize = 10;
+
+/// 单次 ping 的超时时间（毫秒）
+const PING_TIMEOUT_MS: u32 = 1000;
+
+/// 两次 ping 之间的间隔
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 输入停止多久后才开始 ping 或解析，输入中途的主机名不发起请求
+const INPUT_DELAY: Duration = Duration::from_millis(500);
+
+/// 查询公网 IP 的接口
+const PUBLIC_IP_URL: &str = "https://api.ipify.org";
+
+/// 公网 IP 的缓存时间
+const PUBLIC_IP_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// 端口监听信息的缓存时间
+const LISTENERS_TTL: Duration = Duration::from_secs(5);
+
+/// 最多保留的 DNS 解析结果数
+const MAX_DNS_ENTRIES: usize = 32;
+
+/// 提取单次 ping 的延迟（毫秒），没有收到回复时返回 `None`
+///
+/// 只依赖 `TTL=` 和 `时间=12ms`/`time<1ms` 中的 ASCII 部分，兼容各语言的 Windows
+pub fn ping_latency(output: &str) -> Option<u32> {
+    let line = output.lines().find(|line| line.contains("TTL="))?;
+    let end = line.find("ms")?;
+    let start = line[..end].rfind(['=', '<'])? + 1;
+    line[start..end].trim().parse().ok()
+}
+
+/// ping 统计，如 "12 ms · 平均 14 ms · 最小 10 / 最大 20 · 丢包 1/5"
+pub fn ping_summary(samples: &[Option<u32>]) -> String {
+    let replies: Vec<u32> = samples.iter().flatten().copied().collect();
+    let lost = samples.len() - replies.len();
+    let Some(&last) = replies.last() else {
+        return format!("无响应 · 丢包 {}/{}", lost, samples.len());
+    };
+
+    let average = replies.iter().sum::<u32>() / replies.len() as u32;
+    let min = replies.iter().min().copied().unwrap_or_default();
+    let max = replies.iter().max().copied().unwrap_or_default();
+    let current = match samples.last() {
+        Some(Some(_)) => format!("{} ms", last),
+        _ => "超时".to_string(),
+    };
+    format!(
+        "{} · 平均 {} ms · 最小 {} / 最大 {} · 丢包 {}/{}",
+        current,
+        average,
+        min,
+        max,
+        lost,
+        samples.len()
+    )
+}
+
+/// 监听端口的进程
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Listener {
+    /// 协议（TCP、UDP）
+    pub protocol: String,
+    /// 本地地址
+    pub address: String,
+    /// 端口
+    pub port: u16,
+    /// 进程 ID
+    pub pid: u32,
+}
+
+/// 解析 `netstat -ano` 的输出，保留监听中的 TCP 端口和绑定的 UDP 端口
+///
+/// TCP 监听的远程地址为 `0.0.0.0:0` 或 `[::]:0`；不依赖状态列的文字（随系统语言变化）
+pub fn parse_listeners(output: &str) -> Vec<Listener> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let protocol = columns.first()?.to_uppercase();
+            let (address, remote) = (*columns.get(1)?, *columns.get(2)?);
+            let listening = match protocol.as_str() {
+                "TCP" => remote.ends_with(":0"),
+                "UDP" => true,
+                _ => false,
+            };
+            if !listening {
+                return None;
+            }
+
+            let port = address.rsplit(':').next()?.parse().ok()?;
+            let pid = columns.last()?.parse().ok()?;
+            Some(Listener { protocol, address: address.to_string(), port, pid })
+        })
+        .collect()
+}
+
+/// 解析 `tasklist /FO CSV /NH` 的输出为 PID -> 进程名
+pub fn parse_tasklist(output: &str) -> HashMap<u32, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split("\",\"").map(|field| field.trim_matches('"'));
+            let name = fields.next()?.to_string();
+            let pid = fields.next()?.parse().ok()?;
+            Some((pid, name))
+        })
+        .collect()
+}
+
+/// 运行命令并返回标准输出，不显示控制台窗口
+fn run(program: &str, args: &[&str]) -> Result<String> {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = cmd.output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 本机用于访问外网的地址（通过 UDP 连接选择路由，不发送数据）
+fn local_address(remote: &str, bind: &str) -> Option<IpAddr> {
+    let socket = UdpSocket::bind(bind).ok()?;
+    socket.connect(remote).ok()?;
+    Some(socket.local_addr().ok()?.ip())
+}
+
+/// 进行中的 ping
+struct PingSession {
+    /// 目标主机
+    host: String,
+    /// 每次 ping 的延迟，超时为 `None`
+    samples: Vec<Option<u32>>,
+    /// 后台任务
+    task: Arc<ProgressTask>,
+}
+
+/// DNS 解析结果
+#[derive(Clone)]
+enum DnsLookup {
+    /// 正在解析
+    Pending,
+    /// 解析完成
+    Done(std::result::Result<Vec<IpAddr>, String>),
+}
+
+/// 公网 IP 及获取时间（获取失败时为 `None`）
+type PublicIp = Option<(Instant, Option<String>)>;
+
+/// 监听端口的进程、PID 到进程名的映射及读取时间
+type ListenerCache = Option<(Instant, Vec<Listener>, HashMap<u32, String>)>;
+
+/// 网络工具插件
+pub struct NetworkToolsPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 最近一次 ping
+    ping: Arc<Mutex<Option<PingSession>>>,
+    /// DNS 解析结果
+    dns: Arc<Mutex<HashMap<String, DnsLookup>>>,
+    /// 最近一次查询的域名
+    dns_query: Arc<Mutex<String>>,
+    /// 公网 IP 及获取时间
+    public_ip: Arc<Mutex<PublicIp>>,
+    /// 是否正在获取公网 IP
+    fetching_ip: Arc<AtomicBool>,
+    /// 端口监听信息缓存
+    listeners: Mutex<ListenerCache>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl NetworkToolsPlugin {
+    /// 创建新的网络工具插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            ping: Arc::new(Mutex::new(None)),
+            dns: Arc::new(Mutex::new(HashMap::new())),
+            dns_query: Arc::new(Mutex::new(String::new())),
+            public_ip: Arc::new(Mutex::new(None)),
+            fetching_ip: Arc::new(AtomicBool::new(false)),
+            listeners: Mutex::new(None),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 构造可复制的结果项
+    fn result(&self, id: String, title: String, description: String, text: String) -> SearchResult {
+        SearchResult::new(
+            format!("net:{}", id),
+            title,
+            description,
+            ResultType::Custom("net".to_string()),
+            900,
+            ActionData::CopyToClipboard { text },
+        )
+    }
+
+    /// 本机和公网 IP
+    fn ip_results(&self) -> Vec<SearchResult> {
+        let mut results = Vec::new();
+        let locals = [
+            ("IPv4", local_address("8.8.8.8:80", "0.0.0.0:0")),
+            ("IPv6", local_address("[2001:4860:4860::8888]:80", "[::]:0")),
+        ];
+        for (family, address) in locals {
+            if let Some(address) = address {
+                results.push(self.result(
+                    format!("ip:local:{}", family),
+                    address.to_string(),
+                    format!("本机 {} 地址 · 按 Enter 复制", family),
+                    address.to_string(),
+                ));
+            }
+        }
+
+        let cached = self.public_ip.lock().unwrap().clone();
+        self.fetch_public_ip_if_stale(cached.as_ref().map(|(fetched_at, _)| *fetched_at));
+        match cached {
+            Some((_, Some(ip))) => results.push(self.result(
+                "ip:public".to_string(),
+                ip.clone(),
+                "公网 IP 地址 · 按 Enter 复制".to_string(),
+                ip,
+            )),
+            Some((_, None)) => results.push(self.result(
+                "ip:public".to_string(),
+                "无法获取公网 IP".to_string(),
+                "请检查网络连接".to_string(),
+                String::new(),
+            )),
+            None => results.push(self.result(
+                "ip:public".to_string(),
+                "正在获取公网 IP…".to_string(),
+                PUBLIC_IP_URL.to_string(),
+                String::new(),
+            )),
+        }
+
+        results
+    }
+
+    /// 没有获取过或已过期时在后台获取公网 IP
+    fn fetch_public_ip_if_stale(&self, fetched_at: Option<Instant>) {
+        let fresh = fetched_at.is_some_and(|at| at.elapsed() < PUBLIC_IP_TTL);
+        if fresh || self.fetching_ip.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let public_ip = self.public_ip.clone();
+        let fetching = self.fetching_ip.clone();
+        std::thread::spawn(move || {
+            let ip = download(PUBLIC_IP_URL)
+                .map(|body| body.trim().to_string())
+                .ok()
+                .filter(|body| body.parse::<IpAddr>().is_ok());
+            if ip.is_none() {
+                log::warn!("获取公网 IP 失败");
+            }
+            *public_ip.lock().unwrap() = Some((Instant::now(), ip));
+            fetching.store(false, Ordering::SeqCst);
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// ping 结果，目标变化时取消上一次 ping 并重新开始
+    fn ping_results(&self, host: &str) -> Vec<SearchResult> {
+        if host.is_empty() {
+            return vec![self.result(
+                "ping".to_string(),
+                "ping <主机>".to_string(),
+                "连续 ping 并实时显示延迟，如 ping example.com".to_string(),
+                String::new(),
+            )];
+        }
+
+        let mut session = self.ping.lock().unwrap();
+        let same_host = session.as_ref().is_some_and(|s| s.host == host);
+        if !same_host {
+            if let Some(previous) = session.take() {
+                previous.task.cancel();
+            }
+            *session = Some(self.start_ping(host));
+        }
+
+        let Some(current) = session.as_ref() else {
+            return Vec::new();
+        };
+        let description = if current.samples.is_empty() {
+            "正在 ping…".to_string()
+        } else {
+            ping_summary(&current.samples)
+        };
+        vec![self.result(
+            format!("ping:{}", host),
+            format!("ping {}", host),
+            description.clone(),
+            format!("ping {}: {}", host, description),
+        )]
+    }
+
+    /// 在后台连续 ping，每次收到结果后通知界面刷新
+    ///
+    /// 输入停止一段时间后才开始，期间目标变化时本次 ping 已被取消
+    fn start_ping(&self, host: &str) -> PingSession {
+        let task = global_progress().start("net:ping", &format!("正在 ping {}", host));
+        task.set_total(PING_COUNT);
+
+        let (ping, host_owned, worker_task) = (self.ping.clone(), host.to_string(), task.clone());
+        std::thread::spawn(move || {
+            let timeout = PING_TIMEOUT_MS.to_string();
+            for index in 0..PING_COUNT {
+                if worker_task.is_cancelled() {
+                    break;
+                }
+                std::thread::sleep(if index == 0 { INPUT_DELAY } else { PING_INTERVAL });
+                if worker_task.is_cancelled() {
+                    break;
+                }
+
+                let latency = run("ping", &["-n", "1", "-w", &timeout, &host_owned])
+                    .ok()
+                    .and_then(|output| ping_latency(&output));
+                if worker_task.is_cancelled() {
+                    break;
+                }
+                if let Some(session) = ping.lock().unwrap().as_mut() {
+                    session.samples.push(latency);
+                }
+                worker_task.advance(1);
+                global_progress().mark_results_changed();
+            }
+            worker_task.finish();
+        });
+
+        PingSession { host: host.to_string(), samples: Vec::new(), task }
+    }
+
+    /// DNS 解析结果，未解析过的域名在后台解析
+    fn dns_results(&self, domain: &str) -> Vec<SearchResult> {
+        if domain.is_empty() {
+            return vec![self.result(
+                "dns".to_string(),
+                "dns <域名>".to_string(),
+                "解析域名的 A / AAAA 记录，如 dns example.com".to_string(),
+                String::new(),
+            )];
+        }
+
+        *self.dns_query.lock().unwrap() = domain.to_string();
+        let lookup = {
+            let mut dns = self.dns.lock().unwrap();
+            if dns.len() >= MAX_DNS_ENTRIES && !dns.contains_key(domain) {
+                dns.clear();
+            }
+            dns.entry(domain.to_string()).or_insert_with(|| {
+                self.start_lookup(domain);
+                DnsLookup::Pending
+            });
+            dns[domain].clone()
+        };
+
+        match lookup {
+            DnsLookup::Pending => vec![self.result(
+                format!("dns:{}", domain),
+                format!("正在解析 {}…", domain),
+                String::new(),
+                String::new(),
+            )],
+            DnsLookup::Done(Err(e)) => vec![self.result(
+                format!("dns:{}", domain),
+                format!("无法解析 {}", domain),
+                e,
+                String::new(),
+            )],
+            DnsLookup::Done(Ok(addresses)) => addresses
+                .into_iter()
+                .map(|address| {
+                    let record = if address.is_ipv4() { "A" } else { "AAAA" };
+                    self.result(
+                        format!("dns:{}:{}", domain, address),
+                        address.to_string(),
+                        format!("{} {} 记录 · 按 Enter 复制", domain, record),
+                        address.to_string(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// 在后台解析域名，输入停止一段时间后才解析，期间查询变化时放弃
+    fn start_lookup(&self, domain: &str) {
+        let (dns, query, domain) = (self.dns.clone(), self.dns_query.clone(), domain.to_string());
+        std::thread::spawn(move || {
+            std::thread::sleep(INPUT_DELAY);
+            if *query.lock().unwrap() != domain {
+                dns.lock().unwrap().remove(&domain);
+                return;
+            }
+
+            let result = (domain.as_str(), 0)
+                .to_socket_addrs()
+                .map(|addrs| {
+                    let mut addresses: Vec<IpAddr> = Vec::new();
+                    for addr in addrs {
+                        if !addresses.contains(&addr.ip()) {
+                            addresses.push(addr.ip());
+                        }
+                    }
+                    addresses
+                })
+                .map_err(|e| e.to_string());
+            dns.lock().unwrap().insert(domain, DnsLookup::Done(result));
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// 监听端口的进程和进程名（缓存几秒，避免每次按键都运行 netstat）
+    fn listeners(&self) -> (Vec<Listener>, HashMap<u32, String>) {
+        let mut cached = self.listeners.lock().unwrap();
+        if let Some((listed_at, listeners, names)) = cached.as_ref() {
+            if listed_at.elapsed() < LISTENERS_TTL {
+                return (listeners.clone(), names.clone());
+            }
+        }
+
+        let listeners = run("netstat", &["-ano"]).map(|o| parse_listeners(&o)).unwrap_or_default();
+        let names = run("tasklist", &["/FO", "CSV", "/NH"])
+            .map(|output| parse_tasklist(&output))
+            .unwrap_or_default();
+        *cached = Some((Instant::now(), listeners.clone(), names.clone()));
+        (listeners, names)
+    }
+
+    /// 监听端口的进程
+    fn port_results(&self, port: &str) -> Vec<SearchResult> {
+        let Ok(port) = port.parse::<u16>() else {
+            return vec![self.result(
+                "port".to_string(),
+                "port <端口>".to_string(),
+                "查看监听该端口的进程，如 port 8080".to_string(),
+                String::new(),
+            )];
+        };
+
+        let (listeners, names) = self.listeners();
+        let matched: Vec<&Listener> = listeners.iter().filter(|l| l.port == port).collect();
+        if matched.is_empty() {
+            return vec![self.result(
+                format!("port:{}", port),
+                format!("没有进程监听端口 {}", port),
+                String::new(),
+                String::new(),
+            )];
+        }
+
+        matched
+            .into_iter()
+            .map(|listener| {
+                let name = names.get(&listener.pid).map_or("未知进程", String::as_str);
+                self.result(
+                    format!("port:{}:{}:{}", listener.protocol, listener.address, listener.pid),
+                    format!("{} (PID {})", name, listener.pid),
+                    format!("{} {} · 按 Enter 复制 PID", listener.protocol, listener.address),
+                    listener.pid.to_string(),
+                )
+                .with_secondary_actions(vec![SecondaryAction::new(
+                    "结束进程",
+                    ActionData::ExecuteCommand {
+                        command: format!("taskkill /PID {} /F", listener.pid),
+                    },
+                )
+                .with_destructive(true)])
+            })
+            .collect()
+    }
+}
+
+impl Plugin for NetworkToolsPlugin {
+    fn id(&self) -> &str {
+        "net"
+    }
+
+    fn name(&self) -> &str {
+        "网络工具"
+    }
+
+    fn description(&self) -> &str {
+        "查看 IP、ping、DNS 解析和端口占用"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("globe")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化网络工具插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim();
+        let (keyword, argument) = query.split_once(' ').unwrap_or((query, ""));
+        let argument = argument.trim();
+
+        Ok(match keyword.to_lowercase().as_str() {
+            "ip" if argument.is_empty() => self.ip_results(),
+            "ping" => self.ping_results(argument),
+            "dns" => self.dns_results(argument),
+            "port" => self.port_results(argument),
+            _ => Vec::new(),
+        })
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::CopyToClipboard { text } if !text.is_empty() => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制: {}", text);
+            },
+            ActionData::ExecuteCommand { command } => {
+                log::info!("执行: {}", command);
+                run("cmd", &["/c", command])?;
+                *self.listeners.lock().unwrap() = None;
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.listeners.lock().unwrap() = None;
+        self.dns.lock().unwrap().clear();
+        Ok(())
+    }
+}
+
+impl Default for NetworkToolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ping_and_listeners() {
+        let reply = "\r\nPinging example.com [93.184.216.34] with 32 bytes of data:\r\nReply from \
+                     93.184.216.34: bytes=32 time=87ms TTL=56\r\n";
+        assert_eq!(ping_latency(reply), Some(87));
+        assert_eq!(ping_latency("来自 127.0.0.1 的回复: 字节=32 时间<1ms TTL=128"), Some(1));
+        assert_eq!(ping_latency("Request timed out."), None);
+        assert_eq!(ping_latency("Reply from 10.0.0.1: Destination host unreachable."), None);
+
+        assert_eq!(
+            ping_summary(&[Some(10), None, Some(20)]),
+            "20 ms · 平均 15 ms · 最小 10 / 最大 20 · 丢包 1/3"
+        );
+        assert_eq!(ping_summary(&[None, None]), "无响应 · 丢包 2/2");
+
+        let netstat = "\
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    0.0.0.0:8080           0.0.0.0:0              LISTENING       4242
+  TCP    127.0.0.1:8080         127.0.0.1:51234        ESTABLISHED     4242
+  TCP    [::]:8080              [::]:0                 LISTENING       4242
+  UDP    0.0.0.0:5353           *:*                                    1800
+";
+        let listeners = parse_listeners(netstat);
+        assert_eq!(listeners.len(), 3);
+        assert_eq!((listeners[1].address.as_str(), listeners[1].port), ("[::]:8080", 8080));
+        assert_eq!((listeners[2].protocol.as_str(), listeners[2].pid), ("UDP", 1800));
+
+        let names = parse_tasklist("\"node.exe\",\"4242\",\"Console\",\"1\",\"52,400 K\"\r\n");
+        assert_eq!(names.get(&4242).map(String::as_str), Some("node.exe"));
+    }
+}
@@ -0,0 +1,358 @@
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{clipboard::ClipboardManager, random},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "pwgen";
+
+/// 生成口令的子命令
+const WORDS_COMMAND: &str = "words";
+
+/// 每次生成的候选数量
+const CANDIDATES: usize = 3;
+
+/// 密码长度范围
+const LENGTH_RANGE: std::ops::RangeInclusive<usize> = 4..=256;
+
+/// 口令词数范围
+const WORDS_RANGE: std::ops::RangeInclusive<usize> = 2..=20;
+
+/// 易混淆的字符
+const AMBIGUOUS: &str = "0O1lI|";
+
+/// 口令词表（1024 个常用英文单词，每个单词 10 位熵）
+static WORDS: Lazy<Vec<&'static str>> =
+    Lazy::new(|| include_str!("../../assets/passphrase_words.txt").lines().collect());
+
+/// 插件配置（`[plugins.settings.pwgen]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct PasswordSettings {
+    /// 默认密码长度
+    length: usize,
+    /// 是否包含小写字母
+    lowercase: bool,
+    /// 是否包含大写字母
+    uppercase: bool,
+    /// 是否包含数字
+    digits: bool,
+    /// 符号字符集，为空时不包含符号
+    symbols: String,
+    /// 是否排除易混淆的字符（`0O1lI|`）
+    exclude_ambiguous: bool,
+    /// 默认口令词数
+    words: usize,
+    /// 口令单词间的分隔符
+    separator: String,
+}
+
+impl Default for PasswordSettings {
+    fn default() -> Self {
+        Self {
+            length: 20,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: "!@#$%^&*-_=+?".to_string(),
+            exclude_ambiguous: false,
+            words: 5,
+            separator: "-".to_string(),
+        }
+    }
+}
+
+impl PasswordSettings {
+    /// 启用的字符类别，排除易混淆字符后为空的类别被忽略
+    fn char_classes(&self) -> Vec<Vec<char>> {
+        let classes = [
+            (self.lowercase, "abcdefghijklmnopqrstuvwxyz"),
+            (self.uppercase, "ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+            (self.digits, "0123456789"),
+            (true, self.symbols.as_str()),
+        ];
+
+        classes
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, chars)| {
+                let mut chars: Vec<char> = chars
+                    .chars()
+                    .filter(|c| !(self.exclude_ambiguous && AMBIGUOUS.contains(*c)))
+                    .collect();
+                chars.sort_unstable();
+                chars.dedup();
+                chars
+            })
+            .filter(|chars| !chars.is_empty())
+            .collect()
+    }
+}
+
+/// 生成请求
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Request {
+    /// 指定长度的密码
+    Password(usize),
+    /// 指定词数的口令
+    Passphrase(usize),
+}
+
+/// 复制动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CopyAction {
+    /// 生成的密码
+    text: String,
+}
+
+/// 解析参数：空为默认长度的密码，`24` 为 24 位密码，`words 5` 为 5 个单词的口令
+pub fn parse_request(
+    argument: &str,
+    default_length: usize,
+    default_words: usize,
+) -> Option<Request> {
+    let mut parts = argument.split_whitespace();
+    let request = match parts.next() {
+        None => Request::Password(default_length),
+        Some(WORDS_COMMAND) => match parts.next() {
+            None => Request::Passphrase(default_words),
+            Some(count) => Request::Passphrase(count.parse().ok()?),
+        },
+        Some(length) => Request::Password(length.parse().ok()?),
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    match request {
+        Request::Password(length) => LENGTH_RANGE.contains(&length).then_some(request),
+        Request::Passphrase(count) => WORDS_RANGE.contains(&count).then_some(request),
+    }
+}
+
+/// 均匀分布在 `0..bound` 中的随机下标
+fn random_index(bound: usize) -> Result<usize> {
+    Ok(random::below(bound as u64)? as usize)
+}
+
+/// 生成密码，长度足够时每个字符类别至少出现一次
+pub fn generate_password(classes: &[Vec<char>], length: usize) -> Result<String> {
+    let pool: Vec<char> = classes.concat();
+    if pool.is_empty() {
+        anyhow::bail!("没有启用任何字符类别");
+    }
+
+    let mut chars = Vec::with_capacity(length);
+    if length >= classes.len() {
+        for class in classes {
+            chars.push(class[random_index(class.len())?]);
+        }
+    }
+    while chars.len() < length {
+        chars.push(pool[random_index(pool.len())?]);
+    }
+
+    // 打乱顺序，使必选字符的位置也是随机的
+    for i in (1..chars.len()).rev() {
+        chars.swap(i, random_index(i + 1)?);
+    }
+    Ok(chars.into_iter().collect())
+}
+
+/// 生成由词表中的单词组成的口令
+pub fn generate_passphrase(count: usize, separator: &str) -> Result<String> {
+    let words =
+        (0..count).map(|_| Ok(WORDS[random_index(WORDS.len())?])).collect::<Result<Vec<_>>>()?;
+    Ok(words.join(separator))
+}
+
+/// 从 `pool` 个等可能的符号中独立选取 `count` 次的熵（位）
+pub fn entropy_bits(pool: usize, count: usize) -> f64 {
+    count as f64 * (pool as f64).log2()
+}
+
+/// 强度描述
+fn strength_label(bits: f64) -> &'static str {
+    match bits {
+        b if b < 50.0 => "弱",
+        b if b < 80.0 => "中",
+        b if b < 128.0 => "强",
+        _ => "非常强",
+    }
+}
+
+/// 密码生成插件
+///
+/// `pwgen` 生成默认长度的密码，`pwgen 24` 生成 24 位密码，`pwgen words 5` 生成 5 个单词的口令；
+/// 随机数来自系统安全随机数生成器，复制时标记为不记录剪贴板历史
+pub struct PasswordGeneratorPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: PasswordSettings,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl PasswordGeneratorPlugin {
+    /// 创建新的密码生成插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            settings: PasswordSettings::default(),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 生成候选结果
+    fn generate(&self, request: Request) -> Result<Vec<SearchResult>> {
+        let classes = self.settings.char_classes();
+        let pool = classes.iter().map(Vec::len).sum();
+
+        let mut results = Vec::new();
+        for index in 0..CANDIDATES {
+            let (text, kind, bits) = match request {
+                Request::Password(length) => (
+                    generate_password(&classes, length)?,
+                    format!("{} 位密码", length),
+                    entropy_bits(pool, length),
+                ),
+                Request::Passphrase(count) => (
+                    generate_passphrase(count, &self.settings.separator)?,
+                    format!("{} 个单词的口令", count),
+                    entropy_bits(WORDS.len(), count),
+                ),
+            };
+
+            let description = format!(
+                "{} · 约 {:.0} 位熵 · {} · 按 Enter 复制（不记录剪贴板历史）",
+                kind,
+                bits,
+                strength_label(bits)
+            );
+            results.push(
+                SearchResult::new(
+                    format!("pwgen:{}", index),
+                    text.clone(),
+                    description,
+                    ResultType::Custom("pwgen".to_string()),
+                    1000 - index as u32,
+                    ActionData::Custom {
+                        plugin: self.id().to_string(),
+                        data: serde_json::to_string(&CopyAction { text }).unwrap_or_default(),
+                    },
+                )
+                .with_sensitive(true),
+            );
+        }
+
+        Ok(results)
+    }
+}
+
+impl Plugin for PasswordGeneratorPlugin {
+    fn id(&self) -> &str {
+        "pwgen"
+    }
+
+    fn name(&self) -> &str {
+        "密码生成"
+    }
+
+    fn description(&self) -> &str {
+        "生成安全的随机密码和口令"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化密码生成插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        match parse_request(rest, self.settings.length, self.settings.words) {
+            Some(request) => self.generate(request),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::Custom { data, .. } = &result.action {
+            let action: CopyAction = serde_json::from_str(data)?;
+            self.clipboard_manager.set_sensitive_text(&action.text)?;
+            log::info!("已复制生成的密码");
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for PasswordGeneratorPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_generate() {
+        assert_eq!(parse_request("", 20, 5), Some(Request::Password(20)));
+        assert_eq!(parse_request(" 24 ", 20, 5), Some(Request::Password(24)));
+        assert_eq!(parse_request("words", 20, 5), Some(Request::Passphrase(5)));
+        assert_eq!(parse_request("words 7", 20, 5), Some(Request::Passphrase(7)));
+        assert_eq!(parse_request("2", 20, 5), None);
+        assert_eq!(parse_request("words 1", 20, 5), None);
+        assert_eq!(parse_request("24 extra", 20, 5), None);
+
+        let settings = PasswordSettings { exclude_ambiguous: true, ..Default::default() };
+        let classes = settings.char_classes();
+        assert_eq!(classes.len(), 4);
+        let password = generate_password(&classes, 12).unwrap();
+        assert_eq!(password.chars().count(), 12);
+        assert!(classes.iter().all(|class| password.chars().any(|c| class.contains(&c))));
+        assert!(!password.chars().any(|c| AMBIGUOUS.contains(c)));
+
+        assert_eq!(WORDS.len(), 1024);
+        let passphrase = generate_passphrase(5, "-").unwrap();
+        assert_eq!(passphrase.split('-').count(), 5);
+        assert_eq!(entropy_bits(WORDS.len(), 5), 50.0);
+    }
+}
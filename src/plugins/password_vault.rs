@@ -0,0 +1,723 @@
+use std::{
+    process::Command,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{clipboard::ClipboardManager, fuzzy::FuzzyMatcher},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "vault";
+
+/// 条目列表的缓存时间
+const ITEMS_TTL: Duration = Duration::from_secs(300);
+
+/// 弹出 Bitwarden 主密码输入框并解锁的 PowerShell 脚本，标准输出为会话密钥
+///
+/// 主密码通过环境变量交给 `bw`，不出现在命令行中
+const BITWARDEN_UNLOCK_SCRIPT: &str = concat!(
+    "$c = Get-Credential -UserName 'Bitwarden' -Message '输入 Bitwarden 主密码解锁密码库'; ",
+    "if (-not $c) { exit 1 }; ",
+    "$env:WERUN_BW_PASSWORD = $c.GetNetworkCredential().Password; ",
+    "bw unlock --raw --passwordenv WERUN_BW_PASSWORD",
+);
+
+/// 密码管理器
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+enum Provider {
+    /// Bitwarden 命令行工具 `bw`
+    #[default]
+    #[serde(rename = "bitwarden")]
+    Bitwarden,
+    /// 1Password 命令行工具 `op`，通过桌面应用集成解锁
+    #[serde(rename = "1password")]
+    OnePassword,
+}
+
+impl Provider {
+    /// 显示名称
+    fn label(self) -> &'static str {
+        match self {
+            Provider::Bitwarden => "Bitwarden",
+            Provider::OnePassword => "1Password",
+        }
+    }
+
+    /// 命令行程序
+    fn program(self) -> &'static str {
+        match self {
+            Provider::Bitwarden => "bw",
+            Provider::OnePassword => "op",
+        }
+    }
+
+    /// 读取条目字段的参数
+    fn field_args(self, id: &str, field: Field) -> Vec<String> {
+        let args: &[&str] = match (self, field) {
+            (Provider::Bitwarden, Field::Username) => &["get", "username", id],
+            (Provider::Bitwarden, Field::Password) => &["get", "password", id],
+            (Provider::Bitwarden, Field::Totp) => &["get", "totp", id],
+            (Provider::OnePassword, Field::Username) => {
+                &["item", "get", id, "--fields", "username"]
+            },
+            (Provider::OnePassword, Field::Password) => {
+                &["item", "get", id, "--fields", "password", "--reveal"]
+            },
+            (Provider::OnePassword, Field::Totp) => &["item", "get", id, "--otp"],
+        };
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+}
+
+/// 插件配置（`[plugins.settings.vault]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct VaultSettings {
+    /// 密码管理器
+    provider: Provider,
+    /// 复制密码或 TOTP 后清空剪贴板的秒数，0 为不清空
+    clear_after_secs: u64,
+}
+
+impl Default for VaultSettings {
+    fn default() -> Self {
+        Self { provider: Provider::Bitwarden, clear_after_secs: 30 }
+    }
+}
+
+/// 密码库状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VaultState {
+    /// 未登录（仅 Bitwarden）
+    Unauthenticated,
+    /// 已锁定
+    Locked,
+    /// 已解锁
+    Unlocked,
+}
+
+/// 解析 `bw status` 的输出
+fn parse_bitwarden_status(json: &str) -> Option<VaultState> {
+    let value: Value = serde_json::from_str(json).ok()?;
+    match value.get("status")?.as_str()? {
+        "unauthenticated" => Some(VaultState::Unauthenticated),
+        "locked" => Some(VaultState::Locked),
+        "unlocked" => Some(VaultState::Unlocked),
+        _ => None,
+    }
+}
+
+/// 密码库中的登录条目，只保留名称和用户名，不含任何密码
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultItem {
+    /// 条目 ID
+    pub id: String,
+    /// 名称
+    pub name: String,
+    /// 用户名
+    pub username: String,
+    /// 是否配置了 TOTP（1Password 的列表不含此信息，总是为 `true`）
+    pub has_totp: bool,
+}
+
+/// 解析 `bw list items` 的输出，只保留登录条目
+pub fn parse_bitwarden_items(json: &str) -> Vec<VaultItem> {
+    let Ok(Value::Array(items)) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter(|item| item.get("type").and_then(Value::as_u64) == Some(1))
+        .filter_map(|item| {
+            let login = item.get("login")?;
+            let text = |key: &str| login.get(key).and_then(Value::as_str).unwrap_or_default();
+            Some(VaultItem {
+                id: item.get("id")?.as_str()?.to_string(),
+                name: item.get("name")?.as_str()?.to_string(),
+                username: text("username").to_string(),
+                has_totp: !text("totp").is_empty(),
+            })
+        })
+        .collect()
+}
+
+/// 解析 `op item list --format json` 的输出
+pub fn parse_onepassword_items(json: &str) -> Vec<VaultItem> {
+    let Ok(Value::Array(items)) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(VaultItem {
+                id: item.get("id")?.as_str()?.to_string(),
+                name: item.get("title")?.as_str()?.to_string(),
+                username: item
+                    .get("additional_information")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                has_totp: true,
+            })
+        })
+        .collect()
+}
+
+/// 运行密码管理器命令行工具（不显示窗口），返回去掉首尾空白的标准输出
+///
+/// Bitwarden 的会话密钥通过 `BW_SESSION` 环境变量传递
+fn cli(provider: Provider, session: Option<&str>, args: &[String]) -> Result<String> {
+    let mut cmd = Command::new(provider.program());
+    cmd.args(args);
+    if let Some(session) = session {
+        cmd.env("BW_SESSION", session);
+    }
+    hide_window(&mut cmd);
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        return Err(anyhow!("{}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 不显示子进程的控制台窗口
+fn hide_window(_cmd: &mut Command) {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        _cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+}
+
+/// 查询密码库状态
+fn check_state(provider: Provider, session: Option<&str>) -> Result<VaultState> {
+    match provider {
+        Provider::Bitwarden => {
+            let output = cli(provider, session, &["status".to_string()])?;
+            parse_bitwarden_status(&output).ok_or_else(|| anyhow!("无法解析 bw status 的输出"))
+        },
+        // 1Password 未登录与已锁定都通过桌面应用解锁，不区分
+        Provider::OnePassword => Ok(match cli(provider, None, &["whoami".to_string()]) {
+            Ok(_) => VaultState::Unlocked,
+            Err(_) => VaultState::Locked,
+        }),
+    }
+}
+
+/// 解锁密码库，返回 Bitwarden 的会话密钥
+fn unlock(provider: Provider) -> Result<Option<String>> {
+    match provider {
+        Provider::Bitwarden => {
+            // Get-Credential 需要交互，不能使用 -NonInteractive
+            let mut cmd = Command::new("powershell");
+            cmd.args(["-NoProfile", "-Command", BITWARDEN_UNLOCK_SCRIPT]);
+            hide_window(&mut cmd);
+
+            let output = cmd.output()?;
+            let session = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !output.status.success() || session.is_empty() {
+                return Err(anyhow!("解锁失败或已取消"));
+            }
+            Ok(Some(session))
+        },
+        // 由 1Password 桌面应用弹出系统验证
+        Provider::OnePassword => {
+            cli(provider, None, &["signin".to_string()])?;
+            Ok(None)
+        },
+    }
+}
+
+/// 读取登录条目列表
+fn list_items(provider: Provider, session: Option<&str>) -> Result<Vec<VaultItem>> {
+    let args: &[&str] = match provider {
+        Provider::Bitwarden => &["list", "items"],
+        Provider::OnePassword => &["item", "list", "--categories", "Login", "--format", "json"],
+    };
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    let output = cli(provider, session, &args)?;
+    Ok(match provider {
+        Provider::Bitwarden => parse_bitwarden_items(&output),
+        Provider::OnePassword => parse_onepassword_items(&output),
+    })
+}
+
+/// 条目字段
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Field {
+    /// 用户名
+    Username,
+    /// 密码
+    Password,
+    /// 当前的 TOTP 验证码
+    Totp,
+}
+
+impl Field {
+    /// 显示名称
+    fn label(self) -> &'static str {
+        match self {
+            Field::Username => "用户名",
+            Field::Password => "密码",
+            Field::Totp => "TOTP 验证码",
+        }
+    }
+}
+
+/// 密码库操作
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum VaultAction {
+    /// 解锁
+    Unlock,
+    /// 在终端中登录
+    Login,
+    /// 锁定
+    Lock,
+    /// 复制条目字段
+    Copy { id: String, field: Field },
+}
+
+/// 后台操作完成后对状态的更新
+type VaultUpdate = Box<dyn FnOnce(&mut Vault) + Send>;
+
+/// 密码库的运行时状态，会话密钥只保存在内存中
+#[derive(Default)]
+struct Vault {
+    /// 密码库状态，尚未检查时为 `None`
+    state: Option<VaultState>,
+    /// Bitwarden 会话密钥
+    session: Option<String>,
+    /// 条目列表及其读取时间，不含任何密码
+    items: Option<(Instant, Vec<VaultItem>)>,
+    /// 正在后台执行的操作
+    busy: Option<&'static str>,
+    /// 最近一次失败的错误
+    error: Option<String>,
+}
+
+/// 密码管理器插件
+///
+/// `vault <名称>` 搜索 Bitwarden 或 1Password 密码库中的登录条目，Enter 复制密码，
+/// 动作面板中可以复制用户名和 TOTP 验证码。密码库锁定时先解锁；只缓存条目名称和用户名，
+/// 密码和验证码在复制时才读取，并在一段时间后从剪贴板清除
+pub struct PasswordVaultPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: VaultSettings,
+    /// 密码库状态
+    vault: Arc<Mutex<Vault>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+}
+
+impl PasswordVaultPlugin {
+    /// 创建新的密码管理器插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            settings: VaultSettings::default(),
+            vault: Arc::new(Mutex::new(Vault::default())),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+        }
+    }
+
+    /// 在后台执行操作，完成后更新状态并通知界面
+    fn spawn(
+        &self,
+        busy: &'static str,
+        task: impl FnOnce(Provider, Option<String>) -> Result<VaultUpdate> + Send + 'static,
+    ) {
+        let session = {
+            let mut vault = self.vault.lock().unwrap();
+            if vault.busy.is_some() {
+                return;
+            }
+            vault.busy = Some(busy);
+            vault.session.clone()
+        };
+
+        let (vault, provider) = (self.vault.clone(), self.settings.provider);
+        std::thread::spawn(move || {
+            let result = task(provider, session);
+            let mut vault = vault.lock().unwrap();
+            vault.busy = None;
+            match result {
+                Ok(update) => {
+                    vault.error = None;
+                    update(&mut vault);
+                },
+                Err(e) => {
+                    log::warn!("{} 失败: {}", busy, e);
+                    vault.error = Some(e.to_string());
+                },
+            }
+            drop(vault);
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// 检查密码库状态
+    fn start_check(&self) {
+        self.spawn("正在检查密码库状态…", |provider, session| {
+            let state = check_state(provider, session.as_deref())?;
+            Ok(Box::new(move |vault: &mut Vault| vault.state = Some(state)))
+        });
+    }
+
+    /// 解锁密码库
+    fn start_unlock(&self) {
+        self.spawn("正在解锁密码库…", |provider, _| {
+            let session = unlock(provider)?;
+            log::info!("已解锁 {} 密码库", provider.label());
+            Ok(Box::new(move |vault: &mut Vault| {
+                vault.session = session;
+                vault.state = Some(VaultState::Unlocked);
+                vault.items = None;
+            }))
+        });
+    }
+
+    /// 读取条目列表
+    fn start_load_items(&self) {
+        self.spawn("正在读取密码库条目…", |provider, session| {
+            let items = list_items(provider, session.as_deref())?;
+            Ok(Box::new(move |vault: &mut Vault| vault.items = Some((Instant::now(), items))))
+        });
+    }
+
+    /// 当前状态对应的结果：未解锁时为解锁提示，解锁后为匹配的条目
+    fn vault_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let (state, items, busy, error) = {
+            let vault = self.vault.lock().unwrap();
+            let items = vault
+                .items
+                .as_ref()
+                .filter(|(loaded_at, _)| loaded_at.elapsed() < ITEMS_TTL)
+                .map(|(_, items)| items.clone());
+            (vault.state, items, vault.busy, vault.error.clone())
+        };
+        let label = self.settings.provider.label();
+
+        if let Some(busy) = busy {
+            return vec![self.status_result(busy, label, None)];
+        }
+
+        match state {
+            None => match error {
+                Some(error) => {
+                    let title = format!("无法运行 {}", self.settings.provider.program());
+                    vec![self.status_result(&title, &error, None)]
+                },
+                None => {
+                    self.start_check();
+                    vec![self.status_result("正在检查密码库状态…", label, None)]
+                },
+            },
+            Some(VaultState::Unauthenticated) => vec![self.status_result(
+                &format!("尚未登录 {}", label),
+                "按 Enter 在终端中运行 bw login",
+                Some(VaultAction::Login),
+            )],
+            Some(VaultState::Locked) => {
+                let description = match &error {
+                    Some(error) => format!("{} · 按 Enter 重试", error),
+                    None => "按 Enter 解锁".to_string(),
+                };
+                vec![self.status_result(
+                    &format!("{} 密码库已锁定", label),
+                    &description,
+                    Some(VaultAction::Unlock),
+                )]
+            },
+            Some(VaultState::Unlocked) => match items {
+                // 复制等操作在后台失败时，在条目前显示原因
+                Some(items) => error
+                    .map(|error| self.status_result("操作失败", &error, None))
+                    .into_iter()
+                    .chain(self.item_results(items, filter, limit))
+                    .collect(),
+                None => match error {
+                    // 会话失效等原因读取失败时，重新解锁后再读取
+                    Some(error) => vec![self.status_result(
+                        "无法读取密码库条目",
+                        &format!("{} · 按 Enter 重新解锁", error),
+                        Some(VaultAction::Unlock),
+                    )],
+                    None => {
+                        self.start_load_items();
+                        vec![self.status_result("正在读取密码库条目…", label, None)]
+                    },
+                },
+            },
+        }
+    }
+
+    /// 匹配名称或用户名的条目结果
+    fn item_results(&self, items: Vec<VaultItem>, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = items
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let score = if filter.is_empty() {
+                    1000u32.saturating_sub(index as u32)
+                } else {
+                    let (by_name, name_score) = self.matcher.fuzzy_match(filter, &item.name);
+                    let (by_user, user_score) = self.matcher.fuzzy_match(filter, &item.username);
+                    if !by_name && !by_user {
+                        return None;
+                    }
+                    name_score.max(user_score)
+                };
+                Some(self.item_result(item, score))
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 构造条目结果
+    fn item_result(&self, item: VaultItem, score: u32) -> SearchResult {
+        let copy = |field| VaultAction::Copy { id: item.id.clone(), field };
+        let mut secondary_actions =
+            vec![SecondaryAction::new("复制用户名", self.action(&copy(Field::Username)))];
+        if item.has_totp {
+            secondary_actions
+                .push(SecondaryAction::new("复制 TOTP 验证码", self.action(&copy(Field::Totp))));
+        }
+        secondary_actions.push(SecondaryAction::new("锁定密码库", self.action(&VaultAction::Lock)));
+
+        let description = if item.username.is_empty() {
+            "按 Enter 复制密码".to_string()
+        } else {
+            format!("{} · 按 Enter 复制密码", item.username)
+        };
+        SearchResult::new(
+            format!("vault:{}", item.id),
+            item.name.clone(),
+            description,
+            ResultType::Custom("vault".to_string()),
+            score,
+            self.action(&copy(Field::Password)),
+        )
+        .with_sensitive(true)
+        .with_secondary_actions(secondary_actions)
+    }
+
+    /// 状态提示结果，`action` 为空时按 Enter 不执行任何操作
+    fn status_result(
+        &self,
+        title: &str,
+        description: &str,
+        action: Option<VaultAction>,
+    ) -> SearchResult {
+        let action = match action {
+            Some(action) => self.action(&action),
+            None => ActionData::Custom { plugin: self.id().to_string(), data: String::new() },
+        };
+        SearchResult::new(
+            "vault:status".to_string(),
+            title.to_string(),
+            description.to_string(),
+            ResultType::Custom("vault".to_string()),
+            1000,
+            action,
+        )
+    }
+
+    /// 操作对应的动作数据
+    fn action(&self, action: &VaultAction) -> ActionData {
+        ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(action).unwrap_or_default(),
+        }
+    }
+
+    /// 在后台读取条目字段并复制，密码和验证码不进入剪贴板历史并定时清除
+    fn start_copy(&self, id: String, field: Field) {
+        let clear_after = self.settings.clear_after_secs;
+        self.spawn("正在读取条目…", move |provider, session| {
+            let value = cli(provider, session.as_deref(), &provider.field_args(&id, field))?;
+            if value.is_empty() {
+                return Err(anyhow!("该条目没有{}", field.label()));
+            }
+            copy_value(&value, field, clear_after)?;
+            Ok(Box::new(|_: &mut Vault| {}))
+        });
+    }
+
+    /// 在后台锁定密码库，完成后丢弃会话密钥和条目列表
+    fn start_lock(&self) {
+        self.spawn("正在锁定密码库…", |provider, session| {
+            let args = match provider {
+                Provider::Bitwarden => "lock",
+                Provider::OnePassword => "signout",
+            };
+            cli(provider, session.as_deref(), &[args.to_string()])?;
+            log::info!("已锁定 {} 密码库", provider.label());
+            Ok(Box::new(|vault: &mut Vault| *vault = Vault::default()))
+        });
+    }
+}
+
+/// 复制条目字段，密码和验证码到期后从剪贴板清除
+fn copy_value(value: &str, field: Field, clear_after: u64) -> Result<()> {
+    let clipboard_manager = ClipboardManager::new();
+    if field == Field::Username {
+        clipboard_manager.set_text(value)?;
+        log::info!("已复制密码库条目的用户名");
+        return Ok(());
+    }
+
+    clipboard_manager.set_sensitive_text(value)?;
+    log::info!("已复制密码库条目的{}", field.label());
+
+    if clear_after > 0 {
+        let value = value.to_string();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(clear_after));
+            match ClipboardManager::new().clear_if_text(&value) {
+                Ok(true) => log::info!("已清除剪贴板中的{}", field.label()),
+                Ok(false) => {},
+                Err(e) => e.log(),
+            }
+        });
+    }
+    Ok(())
+}
+
+impl Plugin for PasswordVaultPlugin {
+    fn id(&self) -> &str {
+        "vault"
+    }
+
+    fn name(&self) -> &str {
+        "密码库"
+    }
+
+    fn description(&self) -> &str {
+        "搜索 Bitwarden 或 1Password 密码库，复制用户名、密码和 TOTP 验证码"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("file-box")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化密码库插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.vault_results(rest.trim(), limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+        let Ok(action) = serde_json::from_str::<VaultAction>(data) else {
+            return Ok(());
+        };
+
+        match action {
+            VaultAction::Unlock => self.start_unlock(),
+            VaultAction::Login => {
+                Command::new("wt.exe").args([Provider::Bitwarden.program(), "login"]).spawn()?;
+                // 登录后重新检查状态
+                self.vault.lock().unwrap().state = None;
+            },
+            VaultAction::Lock => self.start_lock(),
+            VaultAction::Copy { id, field } => self.start_copy(id, field),
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let mut vault = self.vault.lock().unwrap();
+        vault.state = None;
+        vault.items = None;
+        vault.error = None;
+        Ok(())
+    }
+}
+
+impl Default for PasswordVaultPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vault_output() {
+        assert_eq!(parse_bitwarden_status(r#"{"status":"locked"}"#), Some(VaultState::Locked));
+
+        let items = r#"[
+            { "id": "a1", "type": 1, "name": "GitHub",
+              "login": { "username": "octo", "password": "secret", "totp": "JBSWY3DP" } },
+            { "id": "b2", "type": 2, "name": "Recovery codes", "notes": "..." },
+            { "id": "c3", "type": 1, "name": "Router", "login": { "password": "admin" } }
+        ]"#;
+        assert_eq!(parse_bitwarden_items(items), [
+            VaultItem {
+                id: "a1".to_string(),
+                name: "GitHub".to_string(),
+                username: "octo".to_string(),
+                has_totp: true,
+            },
+            VaultItem {
+                id: "c3".to_string(),
+                name: "Router".to_string(),
+                username: String::new(),
+                has_totp: false,
+            },
+        ]);
+
+        let items = r#"[{ "id": "x9", "title": "AWS", "additional_information": "ops" }]"#;
+        let items = parse_onepassword_items(items);
+        assert_eq!((items[0].name.as_str(), items[0].username.as_str()), ("AWS", "ops"));
+    }
+}
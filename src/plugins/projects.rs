@@ -0,0 +1,443 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// 最近项目插件
+///
+/// 读取 VS Code 和 JetBrains IDE 最近打开的项目，输入项目名即可在对应的编辑器中打开；
+/// 已不存在的路径会被过滤掉
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{fuzzy::FuzzyMatcher, shell},
+};
+
+/// 列出全部最近项目的查询关键字，可跟随过滤词，如 `projects werun`
+const KEYWORD: &str = "projects";
+
+/// 普通查询至少需要的字符数
+const MIN_QUERY_CHARS: usize = 2;
+
+/// 项目列表的缓存时间
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// VS Code 记录最近打开项目的键
+const VSCODE_HISTORY_KEY: &str = "history.recentlyOpenedPathsList";
+
+/// VS Code 的各个版本：（配置目录名，显示名称，命令）
+const VSCODE_EDITIONS: &[(&str, &str, &str)] =
+    &[("Code", "VS Code", "code"), ("Code - Insiders", "VS Code Insiders", "code-insiders")];
+
+/// JetBrains IDE：（配置目录名前缀，显示名称，命令）
+///
+/// 命令为 Toolbox 生成的脚本名，也是安装目录 `bin` 下 `<命令>64.exe` 的文件名
+const JETBRAINS_PRODUCTS: &[(&str, &str, &str)] = &[
+    ("IntelliJIdea", "IntelliJ IDEA", "idea"),
+    ("IdeaIC", "IntelliJ IDEA CE", "idea"),
+    ("PyCharmCE", "PyCharm CE", "pycharm"),
+    ("PyCharm", "PyCharm", "pycharm"),
+    ("WebStorm", "WebStorm", "webstorm"),
+    ("CLion", "CLion", "clion"),
+    ("GoLand", "GoLand", "goland"),
+    ("RustRover", "RustRover", "rustrover"),
+    ("Rider", "Rider", "rider"),
+    ("PhpStorm", "PhpStorm", "phpstorm"),
+    ("DataGrip", "DataGrip", "datagrip"),
+];
+
+/// 最近项目
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentProject {
+    /// 项目名称（目录名或工作区文件名）
+    pub name: String,
+    /// 项目路径
+    pub path: String,
+    /// 编辑器显示名称
+    pub editor: String,
+    /// 打开项目的命令
+    pub command: String,
+}
+
+impl RecentProject {
+    /// 以路径创建项目，名称取最后一级目录或文件名（去掉 `.code-workspace`）
+    fn new(path: String, editor: &str, command: &str) -> Self {
+        let name = path
+            .trim_end_matches(['\\', '/'])
+            .rsplit(['\\', '/'])
+            .next()
+            .unwrap_or(&path)
+            .trim_end_matches(".code-workspace")
+            .to_string();
+        Self { name, path, editor: editor.to_string(), command: command.to_string() }
+    }
+}
+
+/// 将 `file://` URI 转换为本地路径，其他协议（远程、WSL 等）返回 `None`
+///
+/// `file:///d%3A/code/werun` -> `D:\code\werun`
+pub fn file_uri_to_path(uri: &str) -> Option<String> {
+    let path = uri.strip_prefix("file:///")?;
+    let path = urlencoding::decode(path).ok()?.replace('/', "\\");
+    let mut chars = path.chars();
+    Some(match (chars.next(), chars.next()) {
+        (Some(drive), Some(':')) => format!("{}{}", drive.to_ascii_uppercase(), &path[1..]),
+        // 不带盘符的路径按原样返回
+        _ => path,
+    })
+}
+
+/// 解析 VS Code 的最近打开列表（文件夹和工作区，忽略单个文件）
+///
+/// 兼容 `state.vscdb` 中的 `{"entries": [...]}` 和旧版 `storage.json` 中的
+/// `{"openedPathsList": {"entries": [...], "workspaces3": [...]}}`
+pub fn parse_vscode_history(value: &serde_json::Value) -> Vec<String> {
+    let list = value.get("openedPathsList").unwrap_or(value);
+    let entries = list.get("entries").and_then(|e| e.as_array()).into_iter().flatten();
+    let workspaces = list.get("workspaces3").and_then(|e| e.as_array()).into_iter().flatten();
+
+    entries
+        .chain(workspaces)
+        .filter_map(|entry| {
+            entry
+                .as_str()
+                .or_else(|| entry.get("folderUri")?.as_str())
+                .or_else(|| entry.get("workspace")?.get("configPath")?.as_str())
+                .or_else(|| entry.get("configURIPath")?.as_str())
+        })
+        .filter_map(file_uri_to_path)
+        .collect()
+}
+
+/// 解析 JetBrains 的 `recentProjects.xml`，`$USER_HOME$` 替换为用户目录
+pub fn parse_jetbrains_recent(xml: &str, home: &str) -> Vec<String> {
+    xml.match_indices("<entry key=\"")
+        .filter_map(|(start, pattern)| {
+            let rest = &xml[start + pattern.len()..];
+            let key = &rest[..rest.find('"')?];
+            Some(key.replace("$USER_HOME$", home).replace("&amp;", "&").replace('/', "\\"))
+        })
+        .collect()
+}
+
+/// 按配置目录名识别 JetBrains IDE（如 `PyCharm2024.1`），返回（显示名称，命令）
+fn jetbrains_product(dir_name: &str) -> Option<(&'static str, &'static str)> {
+    JETBRAINS_PRODUCTS.iter().find_map(|(prefix, name, command)| {
+        let version = dir_name.strip_prefix(prefix)?;
+        version.starts_with(|c: char| c.is_ascii_digit()).then_some((*name, *command))
+    })
+}
+
+/// 读取 VS Code 的最近项目
+fn vscode_projects() -> Vec<RecentProject> {
+    let Some(roaming) = dirs::data_dir() else {
+        return Vec::new();
+    };
+
+    let mut projects = Vec::new();
+    for (dir, editor, command) in VSCODE_EDITIONS {
+        let storage = roaming.join(dir).join("User").join("globalStorage");
+        let history = read_vscode_state(&storage.join("state.vscdb")).or_else(|| {
+            let content = std::fs::read_to_string(storage.join("storage.json")).ok()?;
+            serde_json::from_str(&content).ok()
+        });
+        if let Some(history) = history {
+            projects.extend(
+                parse_vscode_history(&history)
+                    .into_iter()
+                    .map(|path| RecentProject::new(path, editor, command)),
+            );
+        }
+    }
+    projects
+}
+
+/// 从 `state.vscdb` 读取最近打开列表
+fn read_vscode_state(database: &Path) -> Option<serde_json::Value> {
+    if !database.exists() {
+        return None;
+    }
+    let conn = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_ONLY).ok()?;
+    let value: Option<String> = conn
+        .query_row("SELECT value FROM ItemTable WHERE key = ?1", [VSCODE_HISTORY_KEY], |row| {
+            row.get(0)
+        })
+        .optional()
+        .ok()?;
+    serde_json::from_str(&value?).ok()
+}
+
+/// 读取 JetBrains IDE 的最近项目，同一 IDE 的多个版本只读取最新的配置目录
+fn jetbrains_projects() -> Vec<RecentProject> {
+    let (Some(roaming), Some(home)) = (dirs::data_dir(), dirs::home_dir()) else {
+        return Vec::new();
+    };
+    let home = home.to_string_lossy().replace('\\', "/");
+
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(roaming.join("JetBrains"))
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    // 目录名按版本号排序，新版本在前
+    dirs.sort_by(|a, b| b.file_name().cmp(&a.file_name()));
+
+    let mut seen_products = Vec::new();
+    let mut projects = Vec::new();
+    for dir in dirs {
+        let dir_name = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let Some((editor, command)) = jetbrains_product(&dir_name) else {
+            continue;
+        };
+        if seen_products.contains(&editor) {
+            continue;
+        }
+        let Ok(xml) = std::fs::read_to_string(dir.join("options").join("recentProjects.xml"))
+        else {
+            continue;
+        };
+
+        seen_products.push(editor);
+        projects.extend(
+            parse_jetbrains_recent(&xml, &home)
+                .into_iter()
+                .map(|path| RecentProject::new(path, editor, command)),
+        );
+    }
+    projects
+}
+
+/// JetBrains IDE 的启动程序：Toolbox 脚本或独立安装的可执行文件，都找不到时使用命令名
+fn jetbrains_launcher(command: &str) -> String {
+    let Some(local) = dirs::data_local_dir() else {
+        return command.to_string();
+    };
+    let script =
+        local.join("JetBrains").join("Toolbox").join("scripts").join(format!("{}.cmd", command));
+    if script.exists() {
+        return script.to_string_lossy().to_string();
+    }
+
+    let exe = format!("{}64.exe", command);
+    std::fs::read_dir(local.join("Programs"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path().join("bin").join(&exe))
+        .find(|path| path.exists())
+        .map_or_else(|| command.to_string(), |path| path.to_string_lossy().to_string())
+}
+
+/// 最近项目插件
+pub struct ProjectsPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 项目列表缓存
+    projects: Mutex<Option<(Instant, Vec<RecentProject>)>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+}
+
+impl ProjectsPlugin {
+    /// 创建新的最近项目插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            projects: Mutex::new(None),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+        }
+    }
+
+    /// 仍然存在的最近项目（缓存一段时间）
+    fn projects(&self) -> Vec<RecentProject> {
+        let mut cached = self.projects.lock().unwrap();
+        if let Some((loaded_at, projects)) = cached.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return projects.clone();
+            }
+        }
+
+        let mut projects: Vec<RecentProject> = Vec::new();
+        for project in vscode_projects().into_iter().chain(jetbrains_projects()) {
+            let duplicate = projects.iter().any(|p| {
+                p.command == project.command && p.path.eq_ignore_ascii_case(&project.path)
+            });
+            if !duplicate && Path::new(&project.path).exists() {
+                projects.push(project);
+            }
+        }
+
+        *cached = Some((Instant::now(), projects.clone()));
+        projects
+    }
+
+    /// 匹配项目名称的结果，`filter` 为空时按最近打开的顺序列出
+    fn project_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = self
+            .projects()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, project)| {
+                let score = if filter.is_empty() {
+                    1000u32.saturating_sub(index as u32)
+                } else {
+                    let (matched, score) = self.matcher.fuzzy_match(filter, &project.name);
+                    if !matched {
+                        return None;
+                    }
+                    score
+                };
+                Some(self.project_result(project, score))
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 构造项目结果
+    fn project_result(&self, project: RecentProject, score: u32) -> SearchResult {
+        SearchResult::new(
+            format!("projects:{}:{}", project.command, project.path),
+            project.name.clone(),
+            format!("{} · {}", project.editor, project.path),
+            ResultType::Folder,
+            score,
+            ActionData::Custom {
+                plugin: self.id().to_string(),
+                data: serde_json::to_string(&project).unwrap_or_default(),
+            },
+        )
+        .with_secondary_actions(vec![SecondaryAction::new(
+            "在资源管理器中显示",
+            ActionData::RevealInExplorer { path: project.path },
+        )])
+    }
+
+    /// 在对应的编辑器中打开项目
+    fn open_project(&self, project: &RecentProject) -> Result<()> {
+        log::info!("在 {} 中打开项目: {}", project.editor, project.path);
+        let is_vscode = VSCODE_EDITIONS.iter().any(|(_, _, command)| *command == project.command);
+        let launcher =
+            if is_vscode { project.command.clone() } else { jetbrains_launcher(&project.command) };
+
+        Command::new("cmd").args(["/c", &launcher]).arg(&project.path).spawn()?;
+        Ok(())
+    }
+}
+
+impl Plugin for ProjectsPlugin {
+    fn id(&self) -> &str {
+        "projects"
+    }
+
+    fn name(&self) -> &str {
+        "最近项目"
+    }
+
+    fn description(&self) -> &str {
+        "在 VS Code 或 JetBrains IDE 中打开最近的项目"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("folder")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化最近项目插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim();
+        if let Some(rest) = query.strip_prefix(KEYWORD) {
+            if rest.is_empty() || rest.starts_with(' ') {
+                return Ok(self.project_results(rest.trim(), limit));
+            }
+        }
+
+        if query.chars().count() < MIN_QUERY_CHARS {
+            return Ok(Vec::new());
+        }
+        Ok(self.project_results(query, limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::Custom { data, .. } => self.open_project(&serde_json::from_str(data)?)?,
+            ActionData::RevealInExplorer { path } => {
+                shell::reveal_in_explorer(path)?;
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.projects.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for ProjectsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recent_projects() {
+        assert_eq!(file_uri_to_path("file:///d%3A/code/werun").as_deref(), Some(r"D:\code\werun"));
+        assert_eq!(file_uri_to_path("vscode-remote://wsl+ubuntu/home/me"), None);
+
+        let history = serde_json::json!({
+            "entries": [
+                { "folderUri": "file:///c%3A/src/my%20app" },
+                { "workspace": { "id": "1", "configPath": "file:///c%3A/ws/team.code-workspace" } },
+                { "fileUri": "file:///c%3A/notes.txt" },
+                { "folderUri": "vscode-remote://ssh-remote+box/srv" }
+            ]
+        });
+        let paths = parse_vscode_history(&history);
+        assert_eq!(paths, [r"C:\src\my app", r"C:\ws\team.code-workspace"]);
+        assert_eq!(RecentProject::new(paths[1].clone(), "VS Code", "code").name, "team");
+
+        let xml = r#"<application><component name="RecentProjectsManager">
+            <option name="additionalInfo"><map>
+              <entry key="$USER_HOME$/PycharmProjects/scraper"><value /></entry>
+              <entry key="D:/work/R&amp;D"><value /></entry>
+            </map></option></component></application>"#;
+        assert_eq!(parse_jetbrains_recent(xml, "C:/Users/me"), [
+            r"C:\Users\me\PycharmProjects\scraper",
+            r"D:\work\R&D"
+        ]);
+
+        assert_eq!(jetbrains_product("PyCharm2024.1"), Some(("PyCharm", "pycharm")));
+        assert_eq!(jetbrains_product("PyCharmCE2023.3"), Some(("PyCharm CE", "pycharm")));
+        assert_eq!(jetbrains_product("consentOptions"), None);
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use chrono::{Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    config_manager::global_config,
+    plugin::{Plugin, PluginIcon},
+    progress::global_progress,
+    search::{ActionData, ResultType, SearchResult, SecondaryAction},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "note";
+
+/// 笔记条目中的时间格式
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// 每日笔记的文件名格式
+const DAILY_FILE_FORMAT: &str = "%Y-%m-%d.md";
+
+/// 插件配置（`[plugins.settings.note]`）
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct QuickNoteSettings {
+    /// 笔记文件路径，`daily` 时为存放每日笔记的目录；为空时使用数据目录下的 `notes.md`（`notes`）
+    path: String,
+    /// 是否每天写入单独的文件（`2024-05-01.md`）
+    daily: bool,
+}
+
+/// 笔记条目
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoteEntry {
+    /// 记录时间
+    pub time: NaiveDateTime,
+    /// 内容
+    pub text: String,
+    /// 所在的原始行，删除时按整行匹配
+    pub line: String,
+}
+
+/// 笔记动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum NoteAction {
+    /// 追加笔记
+    Add { text: String },
+    /// 从文件中删除笔记
+    Delete { path: String, line: String },
+}
+
+/// 格式化笔记条目，如 `- [2024-05-01 09:30] buy milk`
+pub fn format_entry(time: NaiveDateTime, text: &str) -> String {
+    format!("- [{}] {}", time.format(TIME_FORMAT), text.trim())
+}
+
+/// 解析 Markdown 中的笔记条目（最近记录的在前），忽略不是笔记条目的行
+pub fn parse_entries(content: &str) -> Vec<NoteEntry> {
+    let mut entries: Vec<NoteEntry> = content
+        .lines()
+        .filter_map(|line| {
+            let (time, text) = line.strip_prefix("- [")?.split_once("] ")?;
+            Some(NoteEntry {
+                time: NaiveDateTime::parse_from_str(time, TIME_FORMAT).ok()?,
+                text: text.trim().to_string(),
+                line: line.to_string(),
+            })
+        })
+        .collect();
+    entries.reverse();
+    entries
+}
+
+/// 删除内容中与 `line` 相同的最后一行，没有该行时返回 `None`
+pub fn remove_line(content: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let index = lines.iter().rposition(|l| *l == line)?;
+    lines.remove(index);
+
+    let mut content = lines.join("\n");
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    Some(content)
+}
+
+/// 快速笔记插件
+///
+/// `note buy milk` 把带时间戳的条目追加到 Markdown 文件（单个文件或每日一个文件），
+/// 只输入 `note` 时列出最近的笔记，可复制、打开笔记文件或删除
+pub struct QuickNotePlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: QuickNoteSettings,
+}
+
+impl QuickNotePlugin {
+    /// 创建新的快速笔记插件
+    pub fn new() -> Self {
+        Self { enabled: true, settings: QuickNoteSettings::default() }
+    }
+
+    /// 配置的路径，未配置时为数据目录下的默认位置
+    fn base_path(&self) -> PathBuf {
+        if !self.settings.path.trim().is_empty() {
+            return PathBuf::from(self.settings.path.trim());
+        }
+
+        let dir = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from(".")).join("werun");
+        if self.settings.daily {
+            dir.join("notes")
+        } else {
+            dir.join("notes.md")
+        }
+    }
+
+    /// 新笔记写入的文件
+    fn current_file(&self) -> PathBuf {
+        let base = self.base_path();
+        if self.settings.daily {
+            base.join(Local::now().format(DAILY_FILE_FORMAT).to_string())
+        } else {
+            base
+        }
+    }
+
+    /// 笔记文件，最新的在前
+    fn note_files(&self) -> Vec<PathBuf> {
+        let base = self.base_path();
+        if !self.settings.daily {
+            return vec![base];
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(&base)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        // 每日文件以日期命名，按文件名倒序即按日期倒序
+        files.sort_by(|a, b| b.cmp(a));
+        files
+    }
+
+    /// 最近的笔记及其所在文件，最多 `limit` 条
+    fn recent_entries(&self, filter: &str, limit: usize) -> Vec<(PathBuf, NoteEntry)> {
+        let filter = filter.to_lowercase();
+        let mut entries = Vec::new();
+
+        for file in self.note_files() {
+            let Ok(content) = fs::read_to_string(&file) else {
+                continue;
+            };
+            for entry in parse_entries(&content) {
+                if entry.text.to_lowercase().contains(&filter) {
+                    entries.push((file.clone(), entry));
+                }
+                if entries.len() >= limit {
+                    return entries;
+                }
+            }
+        }
+
+        entries
+    }
+
+    /// 笔记条目的结果项：Enter 复制内容，次要动作打开文件或删除
+    fn entry_result(&self, file: &Path, entry: NoteEntry, score: u32) -> SearchResult {
+        let path = file.to_string_lossy().to_string();
+        let delete = NoteAction::Delete { path: path.clone(), line: entry.line.clone() };
+
+        SearchResult::new(
+            format!("note:{}:{}", path, entry.line),
+            entry.text.clone(),
+            format!("{} · 按 Enter 复制", entry.time.format(TIME_FORMAT)),
+            ResultType::Custom("note".to_string()),
+            score,
+            ActionData::CopyToClipboard { text: entry.text },
+        )
+        .with_secondary_actions(vec![
+            SecondaryAction::new("打开笔记文件", ActionData::OpenFile { path }),
+            SecondaryAction::new("删除", self.action(&delete)).with_destructive(true),
+        ])
+    }
+
+    /// 自定义动作
+    fn action(&self, action: &NoteAction) -> ActionData {
+        ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(action).unwrap_or_default(),
+        }
+    }
+
+    /// 追加笔记
+    fn append(&self, text: &str) -> Result<()> {
+        let file = self.current_file();
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let entry = format_entry(Local::now().naive_local(), text);
+        let mut handle = fs::OpenOptions::new().create(true).append(true).open(&file)?;
+        // 文件末尾没有换行时先补上，避免与上一行连在一起
+        let needs_newline =
+            fs::read(&file).is_ok_and(|content| content.last().is_some_and(|&b| b != b'\n'));
+        if needs_newline {
+            writeln!(handle)?;
+        }
+        writeln!(handle, "{}", entry)?;
+
+        log::info!("已记录笔记到 {}", file.display());
+        Ok(())
+    }
+
+    /// 删除笔记
+    fn delete(&self, path: &str, line: &str) -> Result<()> {
+        let content = fs::read_to_string(path)?;
+        let Some(updated) = remove_line(&content, line) else {
+            log::warn!("笔记已不存在: {}", line);
+            return Ok(());
+        };
+
+        fs::write(path, updated)?;
+        log::info!("已删除笔记: {}", line);
+        global_progress().mark_results_changed();
+        Ok(())
+    }
+}
+
+impl Plugin for QuickNotePlugin {
+    fn id(&self) -> &str {
+        "note"
+    }
+
+    fn name(&self) -> &str {
+        "快速笔记"
+    }
+
+    fn description(&self) -> &str {
+        "把想法追加到 Markdown 笔记文件并查看最近的笔记"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("file")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化快速笔记插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let text = rest.trim();
+        let mut results = Vec::new();
+        if !text.is_empty() {
+            let file = self.current_file();
+            results.push(SearchResult::new(
+                "note:add".to_string(),
+                format!("记录笔记: {}", text),
+                format!("追加到 {}", file.display()),
+                ResultType::Custom("note".to_string()),
+                1000,
+                self.action(&NoteAction::Add { text: text.to_string() }),
+            ));
+        }
+
+        let entries = self.recent_entries(text, limit.saturating_sub(results.len()));
+        for (index, (file, entry)) in entries.into_iter().enumerate() {
+            results.push(self.entry_result(&file, entry, 900u32.saturating_sub(index as u32)));
+        }
+
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+
+        match serde_json::from_str(data)? {
+            NoteAction::Add { text } => self.append(&text),
+            NoteAction::Delete { path, line } => self.delete(&path, &line),
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for QuickNotePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_round_trip() {
+        let time = NaiveDateTime::parse_from_str("2024-05-01 09:30", TIME_FORMAT).unwrap();
+        let first = format_entry(time, "  buy milk ");
+        assert_eq!(first, "- [2024-05-01 09:30] buy milk");
+
+        let second = format_entry(time + chrono::Duration::minutes(5), "call Alice");
+        let content = format!("# Notes\n\n{}\n{}\n- plain bullet\n", first, second);
+
+        let entries = parse_entries(&content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].text, "call Alice");
+        assert_eq!(entries[1].time, time);
+
+        let removed = remove_line(&content, &first).unwrap();
+        assert_eq!(removed, format!("# Notes\n\n{}\n- plain bullet\n", second));
+        assert_eq!(remove_line(&removed, &first), None);
+    }
+}
@@ -0,0 +1,286 @@
+use anyhow::Result;
+use chrono::{Local, TimeZone};
+use serde::Deserialize;
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon, SettingField, SettingValue},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        quotes::{self, global_quotes, Quote, QuoteFormat},
+    },
+};
+
+/// 查询关键字
+const KEYWORD: &str = "quote";
+
+/// 加载插件的关键字：`quote` 和可直接输入的加密货币代码（与 `quotes::CRYPTO` 一致）
+pub const KEYWORDS: &[&str] = &["quote", "btc", "eth", "sol", "bnb", "xrp", "doge", "ada", "ltc"];
+
+/// 重新下载行情的自定义操作前缀，其后为代码
+const REFRESH_PREFIX: &str = "refresh:";
+
+/// 插件配置（`[plugins.settings.quote]`）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+struct QuoteSettings {
+    /// 行情接口，`{symbol}` 替换为代码
+    url: String,
+    /// 接口的响应格式
+    format: QuoteFormat,
+    /// 只输入 `quote` 时列出的代码
+    symbols: Vec<String>,
+    /// 行情有效期（分钟），过期后在后台重新下载
+    max_age_minutes: u64,
+}
+
+impl Default for QuoteSettings {
+    fn default() -> Self {
+        Self {
+            url: "https://query1.finance.yahoo.com/v8/finance/chart/{symbol}?range=1d&interval=1d"
+                .to_string(),
+            format: QuoteFormat::Yahoo,
+            symbols: vec!["AAPL".to_string(), "BTC".to_string(), "USD/CNY".to_string()],
+            max_age_minutes: 5,
+        }
+    }
+}
+
+/// 价格显示：大于 100 保留 2 位小数，大于 1 保留 4 位，更小的保留 6 位，去掉多余的 0
+pub fn format_price(price: f64) -> String {
+    let decimals = match price.abs() {
+        p if p >= 100.0 => 2,
+        p if p >= 1.0 => 4,
+        _ => 6,
+    };
+    let text = format!("{:.*}", decimals, price);
+    let trimmed = text.trim_end_matches('0');
+    // 至少保留 2 位小数
+    let kept = trimmed.len().max(text.len() - decimals + 2);
+    text[..kept].to_string()
+}
+
+/// 涨跌显示，如 "+1.25 (+0.66%)"
+pub fn format_change(quote: &Quote) -> String {
+    format!("{:+.2} ({:+.2}%)", quote.change(), quote.change_percent())
+}
+
+/// 行情插件
+///
+/// `quote` 列出配置的代码，`quote AAPL MSFT`、`quote usd/cny` 查询指定代码，也可以直接输入
+/// `btc`、`eth` 等加密货币代码；显示最新价格和涨跌，Enter 复制价格，动作面板中可立即刷新
+pub struct QuotePlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 插件配置
+    settings: QuoteSettings,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl QuotePlugin {
+    /// 创建新的行情插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            settings: QuoteSettings::default(),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 解析查询，返回要显示的代码
+    fn symbols(&self, query: &str) -> Vec<String> {
+        let query = query.trim();
+        if quotes::is_crypto(query) {
+            return vec![quotes::normalize_symbol(query)];
+        }
+
+        let Some(rest) = query.strip_prefix(KEYWORD) else {
+            return Vec::new();
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Vec::new();
+        }
+        let mut symbols: Vec<&str> = rest.split_whitespace().collect();
+        if symbols.is_empty() {
+            symbols = self.settings.symbols.iter().map(String::as_str).collect();
+        }
+        symbols.into_iter().map(quotes::normalize_symbol).collect()
+    }
+
+    /// 代码的结果项，没有行情时在后台下载并显示占位结果
+    fn quote_result(&self, symbol: &str, score: u32) -> SearchResult {
+        let cache = global_quotes();
+        let max_age_secs = (self.settings.max_age_minutes * 60) as i64;
+        cache.refresh_if_stale(&self.settings.url, self.settings.format, symbol, max_age_secs);
+
+        let refresh = SecondaryAction::new("刷新行情", ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: format!("{}{}", REFRESH_PREFIX, symbol),
+        });
+        let id = format!("quote:{}", symbol);
+
+        let Some(quote) = cache.get(symbol) else {
+            let (title, description) = match cache.error(symbol) {
+                Some(error) if !cache.is_fetching(symbol) => {
+                    (format!("无法获取 {} 的行情", symbol), error)
+                },
+                _ => (format!("正在获取 {} 的行情…", symbol), self.settings.url.clone()),
+            };
+            return SearchResult::new(
+                id,
+                title,
+                description,
+                ResultType::Custom("quote".to_string()),
+                score,
+                ActionData::Custom {
+                    plugin: self.id().to_string(),
+                    data: format!("{}{}", REFRESH_PREFIX, symbol),
+                },
+            );
+        };
+
+        let price = format_price(quote.price);
+        let updated = Local
+            .timestamp_opt(quote.fetched_at, 0)
+            .single()
+            .map(|time| time.format("%H:%M").to_string())
+            .unwrap_or_default();
+        let mut description = format!("{} · {} 更新", format_change(&quote), updated);
+        if cache.is_fetching(symbol) {
+            description.push_str(" · 正在刷新…");
+        }
+        if let Some(name) = &quote.name {
+            description = format!("{} · {}", name, description);
+        }
+
+        SearchResult::new(
+            id,
+            format!("{} {} {}", quote.symbol, price, quote.currency).trim_end().to_string(),
+            format!("{} · 按 Enter 复制价格", description),
+            ResultType::Custom("quote".to_string()),
+            score,
+            ActionData::CopyToClipboard { text: price },
+        )
+        .with_secondary_actions(vec![refresh])
+    }
+}
+
+impl Plugin for QuotePlugin {
+    fn id(&self) -> &str {
+        "quote"
+    }
+
+    fn name(&self) -> &str {
+        "行情"
+    }
+
+    fn description(&self) -> &str {
+        "查询股票、外汇和加密货币的价格与涨跌"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化行情插件...");
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self
+            .symbols(query)
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| self.quote_result(symbol, 1000u32.saturating_sub(index as u32)))
+            .collect())
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制价格: {}", text);
+            },
+            ActionData::Custom { data, .. } => {
+                if let Some(symbol) = data.strip_prefix(REFRESH_PREFIX) {
+                    let (url, format) = (&self.settings.url, self.settings.format);
+                    global_quotes().refresh_if_stale(url, format, symbol, 0);
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        self.settings =
+            serde_json::from_value(global_config().plugin_settings(self.id())).unwrap_or_default();
+        Ok(())
+    }
+
+    fn settings(&self) -> Vec<SettingField> {
+        let settings = &self.settings;
+        vec![
+            SettingField::new("url", "行情接口", SettingValue::Text(settings.url.clone())),
+            SettingField::new(
+                "symbols",
+                "只输入 quote 时列出的代码",
+                SettingValue::List(settings.symbols.clone()),
+            ),
+            SettingField::new(
+                "max_age_minutes",
+                "行情有效期（分钟）",
+                SettingValue::Integer {
+                    value: settings.max_age_minutes as i64,
+                    min: 1,
+                    max: 24 * 60,
+                },
+            ),
+        ]
+    }
+}
+
+impl Default for QuotePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbols_and_formatting() {
+        let plugin = QuotePlugin::new();
+        assert_eq!(plugin.symbols("btc"), ["BTC"]);
+        assert_eq!(plugin.symbols("quote aapl usd/cny"), ["AAPL", "USD/CNY"]);
+        assert_eq!(plugin.symbols("quote"), ["AAPL", "BTC", "USD/CNY"]);
+        assert!(plugin.symbols("quotes").is_empty());
+
+        assert_eq!(format_price(189.8449), "189.84");
+        assert_eq!(format_price(7.2), "7.20");
+        assert_eq!(format_price(7.23456), "7.2346");
+        assert_eq!(format_price(0.1234), "0.1234");
+        assert_eq!(format_price(67000.0), "67000.00");
+    }
+}
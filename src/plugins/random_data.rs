@@ -0,0 +1,297 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{clipboard::ClipboardManager, random},
+};
+
+/// 查询关键字
+const KEYWORD: &str = "uuid";
+
+/// 指定类型时生成的候选数量
+const CANDIDATES: usize = 3;
+
+/// 随机字节数的上限
+const MAX_BYTES: usize = 1024;
+
+/// ULID 使用的 Crockford Base32 字母表
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// nanoid 的 URL 安全字母表（64 个字符，每个字节取低 6 位即均匀分布）
+const NANOID_ALPHABET: &[u8; 64] =
+    b"useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+
+/// 随机数据类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// 随机 UUID
+    UuidV4,
+    /// 按时间排序的 UUID
+    UuidV7,
+    /// ULID
+    Ulid,
+    /// 指定长度的 nanoid
+    NanoId(usize),
+    /// 指定字节数的十六进制串
+    Hex(usize),
+    /// 指定字节数的 Base64 串
+    Base64(usize),
+    /// 闭区间内的随机整数
+    Number(i64, i64),
+}
+
+impl Kind {
+    /// 无参数时的各类型，按列出顺序
+    const DEFAULTS: [(&'static str, Kind); 7] = [
+        ("v4", Kind::UuidV4),
+        ("v7", Kind::UuidV7),
+        ("ulid", Kind::Ulid),
+        ("nanoid", Kind::NanoId(21)),
+        ("hex", Kind::Hex(16)),
+        ("base64", Kind::Base64(32)),
+        ("num", Kind::Number(1, 100)),
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> String {
+        match self {
+            Kind::UuidV4 => "UUID v4".to_string(),
+            Kind::UuidV7 => "UUID v7".to_string(),
+            Kind::Ulid => "ULID".to_string(),
+            Kind::NanoId(len) => format!("nanoid（{} 个字符）", len),
+            Kind::Hex(bytes) => format!("十六进制（{} 字节）", bytes),
+            Kind::Base64(bytes) => format!("Base64（{} 字节）", bytes),
+            Kind::Number(min, max) => format!("{} 到 {} 的随机数", min, max),
+        }
+    }
+
+    /// 生成一个值
+    fn generate(&self) -> Result<String> {
+        let unix_ms = || chrono::Utc::now().timestamp_millis() as u64;
+        Ok(match *self {
+            Kind::UuidV4 => uuid_v4(random_array()?),
+            Kind::UuidV7 => uuid_v7(unix_ms(), random_array()?),
+            Kind::Ulid => ulid(unix_ms(), random_array()?),
+            Kind::NanoId(len) => nanoid(&random::bytes(len)?),
+            Kind::Hex(bytes) => {
+                random::bytes(bytes)?.iter().map(|b| format!("{:02x}", b)).collect()
+            },
+            Kind::Base64(bytes) => STANDARD.encode(random::bytes(bytes)?),
+            Kind::Number(min, max) => random::in_range(min, max)?.to_string(),
+        })
+    }
+}
+
+/// 随机字节数组
+fn random_array<const N: usize>() -> Result<[u8; N]> {
+    let mut array = [0u8; N];
+    random::fill(&mut array)?;
+    Ok(array)
+}
+
+/// 按 8-4-4-4-12 格式输出 UUID
+fn format_uuid(bytes: [u8; 16]) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+/// 由 16 个随机字节生成 UUID v4（RFC 9562）
+pub fn uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0F) | 0x40;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    format_uuid(bytes)
+}
+
+/// 由毫秒时间戳和 10 个随机字节生成 UUID v7（RFC 9562），按生成时间排序
+pub fn uuid_v7(unix_ms: u64, random: [u8; 10]) -> String {
+    let mut bytes = [0u8; 16];
+    bytes[..6].copy_from_slice(&unix_ms.to_be_bytes()[2..]);
+    bytes[6..].copy_from_slice(&random);
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    format_uuid(bytes)
+}
+
+/// 由毫秒时间戳和 10 个随机字节生成 ULID：48 位时间 + 80 位随机数，Crockford Base32 编码
+pub fn ulid(unix_ms: u64, random: [u8; 10]) -> String {
+    let mut value = u128::from(unix_ms & 0xFFFF_FFFF_FFFF) << 80;
+    for (index, byte) in random.iter().enumerate() {
+        value |= u128::from(*byte) << (72 - index * 8);
+    }
+    // 128 位按 5 位一组编码为 26 个字符，第一个字符只有 3 位
+    (0..26).rev().map(|group| CROCKFORD[((value >> (group * 5)) & 0x1F) as usize] as char).collect()
+}
+
+/// 由随机字节生成 nanoid，每个字节对应一个字符
+pub fn nanoid(random: &[u8]) -> String {
+    random.iter().map(|b| NANOID_ALPHABET[(b & 0x3F) as usize] as char).collect()
+}
+
+/// 解析参数，返回要生成的类型；参数为空或为类型名前缀时返回多个默认类型
+///
+/// 支持 `v4`、`v7`、`ulid`、`nanoid [长度]`、`hex [字节数]`、`base64 [字节数]`、
+/// `num [最小值] 最大值`
+pub fn parse_kinds(argument: &str) -> Vec<Kind> {
+    let parts: Vec<&str> = argument.split_whitespace().collect();
+    let Some((&name, args)) = parts.split_first() else {
+        return Kind::DEFAULTS.iter().map(|(_, kind)| *kind).collect();
+    };
+    let name = name.to_lowercase();
+
+    let size = |default: usize| match args {
+        [] => Some(default),
+        [size] => size.parse().ok().filter(|size| (1..=MAX_BYTES).contains(size)),
+        _ => None,
+    };
+    let kind = match name.as_str() {
+        "v4" if args.is_empty() => Some(Kind::UuidV4),
+        "v7" if args.is_empty() => Some(Kind::UuidV7),
+        "ulid" if args.is_empty() => Some(Kind::Ulid),
+        "nanoid" => size(21).map(Kind::NanoId),
+        "hex" => size(16).map(Kind::Hex),
+        "base64" => size(32).map(Kind::Base64),
+        "num" => match args {
+            [] => Some(Kind::Number(1, 100)),
+            [max] => max.parse().ok().map(|max| Kind::Number(1, max)),
+            [min, max] => min.parse().ok().zip(max.parse().ok()).map(|(a, b)| Kind::Number(a, b)),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match kind {
+        Some(kind) => vec![kind],
+        None if args.is_empty() => Kind::DEFAULTS
+            .iter()
+            .filter(|(prefix, _)| prefix.starts_with(&name))
+            .map(|(_, kind)| *kind)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// 随机数据插件
+///
+/// `uuid` 列出 UUID v4/v7、ULID、nanoid、随机十六进制和 Base64 串以及随机数，
+/// `uuid hex 32`、`uuid num 1 6` 等指定类型和参数时生成多个候选，Enter 复制
+pub struct RandomDataPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl RandomDataPlugin {
+    /// 创建新的随机数据插件
+    pub fn new() -> Self {
+        Self { enabled: true, clipboard_manager: ClipboardManager::new() }
+    }
+}
+
+impl Plugin for RandomDataPlugin {
+    fn id(&self) -> &str {
+        "uuid"
+    }
+
+    fn name(&self) -> &str {
+        "随机数据"
+    }
+
+    fn description(&self) -> &str {
+        "生成 UUID、ULID、nanoid、随机字符串和随机数"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化随机数据插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let kinds = parse_kinds(rest);
+        let count = if kinds.len() == 1 { CANDIDATES } else { 1 };
+
+        let mut results = Vec::new();
+        for kind in kinds {
+            for _ in 0..count {
+                let value = kind.generate()?;
+                results.push(SearchResult::new(
+                    format!("uuid:{}", results.len()),
+                    value.clone(),
+                    format!("{} · 按 Enter 复制", kind.label()),
+                    ResultType::Custom("uuid".to_string()),
+                    1000u32.saturating_sub(results.len() as u32),
+                    ActionData::CopyToClipboard { text: value },
+                ));
+            }
+        }
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            self.clipboard_manager.set_text(text)?;
+            log::info!("复制随机数据: {}", text);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for RandomDataPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_and_parsing() {
+        assert_eq!(uuid_v4([0xFF; 16]), "ffffffff-ffff-4fff-bfff-ffffffffffff");
+        // RFC 9562 附录 A.6 的示例
+        let random = [0x0C, 0xC3, 0x98, 0xC4, 0xDC, 0x0C, 0x0C, 0x07, 0x39, 0x8F];
+        assert_eq!(uuid_v7(0x017F22E279B0, random), "017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+        assert_eq!(ulid(0, [0; 10]), "00000000000000000000000000");
+        assert_eq!(ulid(0xFFFF_FFFF_FFFF, [0xFF; 10]), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert_eq!(nanoid(&[0, 63, 64]), "utu");
+
+        assert_eq!(parse_kinds("").len(), Kind::DEFAULTS.len());
+        assert_eq!(parse_kinds("hex 32"), vec![Kind::Hex(32)]);
+        assert_eq!(parse_kinds("num 6"), vec![Kind::Number(1, 6)]);
+        assert_eq!(parse_kinds("num -5 5"), vec![Kind::Number(-5, 5)]);
+        assert_eq!(parse_kinds("V"), vec![Kind::UuidV4, Kind::UuidV7]);
+        assert!(parse_kinds("hex 0").is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    collections::HashMap,
+    process::Command,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+    },
+    platform::services::{self, ServiceCommand, ServiceInfo, ServiceState},
+    utils::fuzzy::FuzzyMatcher,
+};
+
+/// 查询关键字
+const KEYWORD: &str = "svc";
+
+/// 服务列表的缓存时间，服务状态变化较快，只做短暂缓存
+const CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// 打开服务管理器的自定义操作
+const OPEN_MANAGER: &str = "open";
+
+/// 服务列表缓存：加载时间和服务列表
+type ServiceCache = Option<(Instant, Vec<ServiceInfo>)>;
+
+/// 服务操作的状态
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ControlStatus {
+    /// 正在执行
+    Running(ServiceCommand),
+    /// 执行失败
+    Failed(String),
+}
+
+/// 自定义操作的数据：`start:<服务名>`、`stop:<服务名>`、`restart:<服务名>`
+fn command_data(command: ServiceCommand, name: &str) -> String {
+    let verb = match command {
+        ServiceCommand::Start => "start",
+        ServiceCommand::Stop => "stop",
+        ServiceCommand::Restart => "restart",
+    };
+    format!("{}:{}", verb, name)
+}
+
+/// 解析自定义操作的数据
+fn parse_command_data(data: &str) -> Option<(ServiceCommand, &str)> {
+    let (verb, name) = data.split_once(':')?;
+    let command = match verb {
+        "start" => ServiceCommand::Start,
+        "stop" => ServiceCommand::Stop,
+        "restart" => ServiceCommand::Restart,
+        _ => return None,
+    };
+    Some((command, name))
+}
+
+/// 服务按 Enter 时执行的命令：运行中的服务重启，其余启动
+fn primary_command(state: ServiceState) -> ServiceCommand {
+    match state {
+        ServiceState::Running | ServiceState::Paused => ServiceCommand::Restart,
+        ServiceState::Stopped | ServiceState::Starting | ServiceState::Stopping => {
+            ServiceCommand::Start
+        },
+    }
+}
+
+/// Windows 服务插件
+///
+/// `svc` 列出服务及其运行状态，`svc <名称>` 按服务名或显示名称过滤；Enter 重启运行中的服务
+/// 或启动已停止的服务，动作面板中可以停止服务。没有权限时弹出 UAC 提示以管理员身份执行
+pub struct ServicesPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 服务列表缓存
+    services: Arc<Mutex<ServiceCache>>,
+    /// 正在执行或最近失败的操作，键为服务名
+    status: Arc<Mutex<HashMap<String, ControlStatus>>>,
+    /// 模糊匹配器
+    matcher: FuzzyMatcher,
+}
+
+impl ServicesPlugin {
+    /// 创建新的服务插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            services: Arc::new(Mutex::new(None)),
+            status: Arc::new(Mutex::new(HashMap::new())),
+            matcher: FuzzyMatcher::new(global_config().get_config().search.boundary_bonus),
+        }
+    }
+
+    /// 全部服务，运行中的在前，其余按显示名称排序（缓存一段时间）
+    fn services(&self) -> Vec<ServiceInfo> {
+        let mut cached = self.services.lock().unwrap();
+        if let Some((loaded_at, services)) = cached.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return services.clone();
+            }
+        }
+
+        let mut services = services::list();
+        services.sort_by_cached_key(|service| {
+            (service.state != ServiceState::Running, service.display_name.to_lowercase())
+        });
+        *cached = Some((Instant::now(), services.clone()));
+        services
+    }
+
+    /// 匹配服务名或显示名称的结果，`filter` 为空时按列表顺序
+    fn service_results(&self, filter: &str, limit: usize) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = self
+            .services()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, service)| {
+                let score = if filter.is_empty() {
+                    1000u32.saturating_sub(index as u32)
+                } else {
+                    let (by_display, display_score) =
+                        self.matcher.fuzzy_match(filter, &service.display_name);
+                    let (by_name, name_score) = self.matcher.fuzzy_match(filter, &service.name);
+                    if !by_display && !by_name {
+                        return None;
+                    }
+                    display_score.max(name_score)
+                };
+                Some(self.service_result(service, score))
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        results.truncate(limit);
+        results
+    }
+
+    /// 构造服务结果，描述为运行状态、服务名和进程 ID
+    fn service_result(&self, service: ServiceInfo, score: u32) -> SearchResult {
+        let primary = primary_command(service.state);
+        let mut details = vec![service.state.label().to_string(), service.name.clone()];
+        if service.pid != 0 {
+            details.push(format!("PID {}", service.pid));
+        }
+        match self.status.lock().unwrap().get(&service.name) {
+            Some(ControlStatus::Running(command)) => {
+                details.push(format!("正在{}…", command.label()));
+            },
+            Some(ControlStatus::Failed(error)) => details.push(error.clone()),
+            None => details.push(format!("按 Enter {}", primary.label())),
+        }
+
+        let mut secondary_actions = Vec::new();
+        if service.state == ServiceState::Running {
+            secondary_actions.push(
+                SecondaryAction::new(
+                    "停止服务",
+                    self.action(command_data(ServiceCommand::Stop, &service.name)),
+                )
+                .with_destructive(true),
+            );
+        }
+        secondary_actions
+            .push(SecondaryAction::new("打开服务管理器", self.action(OPEN_MANAGER.to_string())));
+
+        SearchResult::new(
+            format!("svc:{}", service.name),
+            service.display_name.clone(),
+            details.join(" · "),
+            ResultType::SystemCommand,
+            score,
+            self.action(command_data(primary, &service.name)),
+        )
+        .with_destructive(primary == ServiceCommand::Restart)
+        .with_secondary_actions(secondary_actions)
+    }
+
+    /// 自定义动作数据
+    fn action(&self, data: String) -> ActionData {
+        ActionData::Custom { plugin: self.id().to_string(), data }
+    }
+
+    /// 在后台执行服务命令，完成后刷新服务列表并通知界面
+    fn start_control(&self, command: ServiceCommand, name: &str) {
+        let (services, status, name) =
+            (self.services.clone(), self.status.clone(), name.to_string());
+        status.lock().unwrap().insert(name.clone(), ControlStatus::Running(command));
+
+        std::thread::spawn(move || {
+            let result = services::control(&name, command);
+            let mut status = status.lock().unwrap();
+            match result {
+                Ok(()) => {
+                    status.remove(&name);
+                },
+                Err(e) => {
+                    e.log();
+                    status.insert(name, ControlStatus::Failed(e.user_message()));
+                },
+            }
+            *services.lock().unwrap() = None;
+            global_progress().mark_results_changed();
+        });
+    }
+}
+
+impl Plugin for ServicesPlugin {
+    fn id(&self) -> &str {
+        "svc"
+    }
+
+    fn name(&self) -> &str {
+        "Windows 服务"
+    }
+
+    fn description(&self) -> &str {
+        "查看服务状态，启动、停止或重启服务"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("settings-2")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化服务插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.service_results(rest.trim(), limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        let ActionData::Custom { data, .. } = &result.action else {
+            return Ok(());
+        };
+
+        if data == OPEN_MANAGER {
+            Command::new("cmd").args(["/c", "start", "", "services.msc"]).spawn()?;
+        } else if let Some((command, name)) = parse_command_data(data) {
+            self.start_control(command, name);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.services.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for ServicesPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_data() {
+        let data = command_data(ServiceCommand::Restart, "Spooler");
+        assert_eq!(data, "restart:Spooler");
+        assert_eq!(parse_command_data(&data), Some((ServiceCommand::Restart, "Spooler")));
+        assert_eq!(parse_command_data("stop:wuauserv"), Some((ServiceCommand::Stop, "wuauserv")));
+        assert_eq!(parse_command_data("open"), None);
+        assert_eq!(parse_command_data("pause:Spooler"), None);
+
+        assert_eq!(primary_command(ServiceState::Running), ServiceCommand::Restart);
+        assert_eq!(primary_command(ServiceState::Stopped), ServiceCommand::Start);
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use anyhow::Result;
+
+/// 设置搜索插件
+///
+/// `settings <关键字>` 或 `设置 <关键字>` 在所有设置项的名称和说明中模糊搜索，
+/// 结果按分节归组并高亮命中的字符，Enter 打开配置文件并定位到该项所在的行；
+/// 界面密度可以在动作面板中直接切换
+use crate::{
+    core::{
+        config::{AppConfig, Density},
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+        settings_catalog::{self, SettingEntry},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        fuzzy::{match_ranges, FuzzyMatcher},
+        shell,
+    },
+};
+
+/// 查询关键字
+const KEYWORDS: &[&str] = &["settings", "设置"];
+
+/// 界面密度设置项的键
+const DENSITY_PATH: &str = "window.density";
+
+/// 设置搜索插件
+pub struct SettingsSearchPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+    /// 配置文件内容及读取时的修改时间，文件未修改时逐键搜索不重复读取
+    config_content: Mutex<Option<(SystemTime, Arc<String>)>>,
+}
+
+impl SettingsSearchPlugin {
+    /// 创建新的设置搜索插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            clipboard_manager: ClipboardManager::new(),
+            config_content: Mutex::new(None),
+        }
+    }
+
+    /// 配置文件内容，用于定位设置项所在的行；修改时间变化后才重新读取
+    fn config_content(&self) -> Arc<String> {
+        let path = AppConfig::config_path();
+        let modified = std::fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+        let mut cached = self.config_content.lock().unwrap();
+        if let (Some(modified), Some((cached_at, content))) = (modified, cached.as_ref()) {
+            if *cached_at == modified {
+                return content.clone();
+            }
+        }
+
+        let content = Arc::new(std::fs::read_to_string(&path).unwrap_or_default());
+        *cached = modified.map(|modified| (modified, content.clone()));
+        content
+    }
+
+    /// 去掉查询关键字，查询不以关键字开头时返回 `None`
+    fn strip_keyword(query: &str) -> Option<&str> {
+        let query = query.trim();
+        KEYWORDS.iter().find_map(|keyword| {
+            let rest = query.strip_prefix(keyword)?;
+            (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim())
+        })
+    }
+
+    /// 搜索设置项，结果按分节归组
+    fn settings_results(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let config = global_config().get_config();
+        let matcher = FuzzyMatcher::new(config.search.boundary_bonus);
+        let values = serde_json::to_value(&config).unwrap_or_default();
+        let content = self.config_content();
+
+        // 按归组后的顺序给分，保证同一分节的结果相邻
+        settings_catalog::search(&matcher, query)
+            .into_iter()
+            .take(limit)
+            .enumerate()
+            .map(|(index, (entry, _))| {
+                let score = 1000u32.saturating_sub(index as u32);
+                self.entry_result(entry, query, &values, &content, score)
+            })
+            .collect()
+    }
+
+    /// 构造设置项的结果
+    fn entry_result(
+        &self,
+        entry: &SettingEntry,
+        query: &str,
+        values: &serde_json::Value,
+        content: &str,
+        score: u32,
+    ) -> SearchResult {
+        let path = entry.path();
+        let value = entry.value(values).unwrap_or_default();
+        let line = entry.line_number(content);
+        let section = settings_catalog::section_label(entry.section);
+        let section_index = settings_catalog::SECTIONS
+            .iter()
+            .position(|s| s.key == entry.section)
+            .unwrap_or_default();
+
+        let location = match line {
+            Some(line) => format!("config.toml 第 {} 行", line),
+            None => "config.toml 中未设置，使用默认值".to_string(),
+        };
+        let rows = vec![
+            PreviewRow::new("键", &path),
+            PreviewRow::new("当前值", &value),
+            PreviewRow::new("位置", &location),
+        ];
+
+        SearchResult::new(
+            format!("settings_search:{}", path),
+            entry.label.to_string(),
+            format!("{} = {} · {}", path, value, entry.description),
+            ResultType::Settings,
+            score,
+            ActionData::Custom { plugin: self.id().to_string(), data: path.clone() },
+        )
+        .with_title_highlights(match_ranges(query, entry.label))
+        .with_group(section_index, section)
+        .with_preview(Preview::new().section(entry.description, rows))
+        .with_secondary_actions(self.entry_actions(path))
+    }
+
+    /// 设置项的次要动作：复制键名，界面密度另外提供各档位的切换
+    fn entry_actions(&self, path: String) -> Vec<SecondaryAction> {
+        let mut actions = Vec::new();
+        if path == DENSITY_PATH {
+            actions.extend(Density::ALL.into_iter().map(|density| {
+                let data = format!("{}={}", DENSITY_PATH, density.key());
+                let action = ActionData::Custom { plugin: self.id().to_string(), data };
+                SecondaryAction::new(format!("切换为{}", density.display()), action)
+            }));
+        }
+        let copy = ActionData::CopyToClipboard { text: path };
+        actions.push(SecondaryAction::new("复制键名", copy));
+        actions
+    }
+
+    /// 切换界面密度，下次绘制时生效
+    fn set_density(key: &str) -> Result<()> {
+        let Some(density) = Density::ALL.into_iter().find(|density| density.key() == key) else {
+            return Ok(());
+        };
+        log::info!("界面密度切换为：{}", density.display());
+        global_config().set_density(density)?;
+        Ok(())
+    }
+}
+
+/// 打开配置文件，`path` 为设置项的路径；能确定行号时用 VS Code 定位到该行，否则用默认程序打开
+pub fn open_config(path: Option<&str>) -> Result<()> {
+    let config_path = AppConfig::config_path();
+    let content = std::fs::read_to_string(&config_path).unwrap_or_default();
+    let line = path.and_then(|path| {
+        let entry = settings_catalog::SETTINGS.iter().find(|entry| entry.path() == path)?;
+        entry.line_number(&content)
+    });
+    log::info!("打开设置: {:?} ({}:{:?})", path, config_path.display(), line);
+
+    // 直接启动 VS Code 的命令脚本，未安装时启动失败，不等待编辑器退出
+    if let Some(line) = line {
+        let target = format!("{}:{}", config_path.display(), line);
+        let opened =
+            std::process::Command::new("code.cmd").args(["--goto", &target]).spawn().is_ok();
+        if opened {
+            return Ok(());
+        }
+    }
+
+    shell::open_path(&config_path.to_string_lossy())?;
+    Ok(())
+}
+
+impl Plugin for SettingsSearchPlugin {
+    fn id(&self) -> &str {
+        "settings_search"
+    }
+
+    fn name(&self) -> &str {
+        "设置搜索"
+    }
+
+    fn description(&self) -> &str {
+        "按名称和说明搜索设置项"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("settings")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化设置搜索插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = Self::strip_keyword(query) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self.settings_results(rest, limit))
+    }
+
+    fn default_results(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.settings_results("", limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::Custom { data, .. } => match data.split_once('=') {
+                Some((DENSITY_PATH, key)) => Self::set_density(key)?,
+                _ => open_config(Some(data))?,
+            },
+            ActionData::CopyToClipboard { text } => {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制设置键名: {}", text);
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for SettingsSearchPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
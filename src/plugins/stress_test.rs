@@ -0,0 +1,127 @@
+use anyhow::Result;
+
+use crate::core::{
+    plugin::{Plugin, PluginIcon},
+    search::{ActionData, ResultType, SearchResult, SortKeys},
+};
+
+/// 默认生成的合成结果数量
+const DEFAULT_COUNT: usize = 50_000;
+
+/// 允许生成的最大结果数量
+const MAX_COUNT: usize = 500_000;
+
+/// 压力测试插件
+///
+/// 输入 `stress` 或 `stress <数量>` 生成大量合成结果，
+/// 用于验证结果列表的虚拟化渲染和滚动流畅度
+pub struct StressTestPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl StressTestPlugin {
+    /// 创建新的压力测试插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 解析要生成的结果数量
+    ///
+    /// 仅响应以 `stress` 开头的查询，避免在普通搜索中生成大量结果
+    fn parse_count(&self, query: &str) -> Option<usize> {
+        let rest = query.trim().strip_prefix("stress")?;
+
+        if rest.is_empty() {
+            return Some(DEFAULT_COUNT);
+        }
+
+        if !rest.starts_with(' ') {
+            return None;
+        }
+
+        rest.trim().parse::<usize>().ok().map(|count| count.min(MAX_COUNT))
+    }
+
+    /// 生成合成结果
+    fn generate(&self, count: usize) -> Vec<SearchResult> {
+        (0..count)
+            .map(|i| {
+                SearchResult::new(
+                    format!("stress_test:{}", i),
+                    format!("合成结果 #{}", i + 1),
+                    format!("压力测试 · 第 {} / {} 行", i + 1, count),
+                    ResultType::Custom("stress".to_string()),
+                    (count - i) as u32,
+                    ActionData::Custom { plugin: "stress_test".to_string(), data: i.to_string() },
+                )
+                .with_sort_keys(SortKeys { recency: Some(i as i64), size: Some(i as u64) })
+            })
+            .collect()
+    }
+}
+
+impl Plugin for StressTestPlugin {
+    fn id(&self) -> &str {
+        "stress_test"
+    }
+
+    fn name(&self) -> &str {
+        "压力测试"
+    }
+
+    fn description(&self) -> &str {
+        "生成大量合成结果以测试列表渲染性能"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("info")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化压力测试插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        // 压力测试需要绕过结果数量限制，因此忽略 limit
+        match self.parse_count(query) {
+            Some(count) => {
+                let started = std::time::Instant::now();
+                let results = self.generate(count);
+                log::info!("生成 {} 个合成结果，耗时 {:?}", count, started.elapsed());
+                Ok(results)
+            },
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::Custom { data, .. } = &result.action {
+            log::info!("压力测试结果被执行: {}", data);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for StressTestPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,10 +1,43 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
 use anyhow::Result;
 
-use crate::core::{
-    plugin::Plugin,
-    search::{ActionData, ResultType, SearchResult},
+use crate::{
+    core::{
+        cache::format_bytes,
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    platform::recycle_bin::{self, RecycleBinInfo},
+    utils::{fuzzy::word_prefix_match, system_info},
 };
 
+/// 会中断当前会话的命令，界面以危险色标出
+const DESTRUCTIVE_COMMANDS: &[&str] = &["shutdown", "restart", "logoff"];
+
+/// 清空回收站动作的数据
+const EMPTY_RECYCLE_BIN: &str = "empty_recycle_bin";
+
+/// 磁盘清理动作数据的前缀，后接盘符（如 `cleanup:C`）
+const CLEANUP_PREFIX: &str = "cleanup:";
+
+/// 匹配清空回收站的词
+const RECYCLE_BIN_TERMS: &[&str] = &["清空回收站", "recycle bin", "empty trash"];
+
+/// 匹配磁盘清理的词
+const CLEANUP_TERMS: &[&str] = &["磁盘清理", "disk cleanup", "cleanmgr"];
+
+/// 回收站占用的缓存时间，查询需要遍历所有驱动器的回收站
+const RECYCLE_BIN_TTL: Duration = Duration::from_secs(30);
+
+/// 查询为任一词中某个单词的开头时匹配
+fn matches_terms(query: &str, terms: &[&str]) -> bool {
+    terms.iter().any(|term| word_prefix_match(query, term))
+}
+
 #[derive(Clone, Debug)]
 pub struct SystemCommand {
     pub id: String,
@@ -17,6 +50,8 @@ pub struct SystemCommand {
 pub struct SystemCommandsPlugin {
     enabled: bool,
     commands: Vec<SystemCommand>,
+    /// 回收站占用及查询时间
+    recycle_bin: Mutex<Option<(Instant, Option<RecycleBinInfo>)>>,
 }
 
 impl SystemCommandsPlugin {
@@ -134,6 +169,13 @@ impl SystemCommandsPlugin {
                 command: "devmgmt.msc".to_string(),
                 icon: None,
             },
+            SystemCommand {
+                id: "storagesense".to_string(),
+                name: "存储感知".to_string(),
+                description: "打开存储设置，自动清理临时文件".to_string(),
+                command: "ms-settings:storagesense".to_string(),
+                icon: None,
+            },
             SystemCommand {
                 id: "diskmgmt".to_string(),
                 name: "磁盘管理".to_string(),
@@ -255,13 +297,89 @@ impl SystemCommandsPlugin {
             },
         ];
 
-        Self { enabled: true, commands }
+        Self { enabled: true, commands, recycle_bin: Mutex::new(None) }
     }
 
     fn execute_command(&self, command: &str) -> Result<()> {
         std::process::Command::new("cmd").args(["/c", "start", "", command]).spawn()?;
         Ok(())
     }
+
+    /// 回收站占用（缓存一段时间，避免每次按键都遍历回收站）
+    fn recycle_bin_info(&self) -> Option<RecycleBinInfo> {
+        let mut cached = self.recycle_bin.lock().unwrap();
+        if let Some((queried_at, info)) = *cached {
+            if queried_at.elapsed() < RECYCLE_BIN_TTL {
+                return info;
+            }
+        }
+
+        let info = recycle_bin::info();
+        *cached = Some((Instant::now(), info));
+        info
+    }
+
+    /// 清空回收站，描述中显示当前占用
+    fn recycle_bin_result(&self) -> SearchResult {
+        let description = match self.recycle_bin_info() {
+            Some(info) if info.items == 0 => "回收站是空的".to_string(),
+            Some(info) => {
+                format!("{} 个项目，共 {} · 永久删除", info.items, format_bytes(info.size as usize))
+            },
+            None => "永久删除回收站中的所有项目".to_string(),
+        };
+
+        SearchResult::new(
+            "system_commands:recycle_bin".to_string(),
+            "清空回收站".to_string(),
+            description,
+            ResultType::Command,
+            90,
+            ActionData::Custom {
+                plugin: "system_commands".to_string(),
+                data: EMPTY_RECYCLE_BIN.to_string(),
+            },
+        )
+        .with_destructive(true)
+    }
+
+    /// 每个本地磁盘的磁盘清理
+    fn cleanup_results(&self) -> Vec<SearchResult> {
+        system_info::disk_usage()
+            .into_iter()
+            .filter_map(|disk| {
+                let drive = disk.root.chars().next()?;
+                Some(SearchResult::new(
+                    format!("system_commands:cleanup:{}", drive),
+                    format!("磁盘清理 {}:", drive),
+                    format!(
+                        "可用 {} / 共 {} · 运行磁盘清理",
+                        format_bytes(disk.free as usize),
+                        format_bytes(disk.total as usize)
+                    ),
+                    ResultType::Command,
+                    85,
+                    ActionData::Custom {
+                        plugin: "system_commands".to_string(),
+                        data: format!("{}{}", CLEANUP_PREFIX, drive),
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// 执行回收站和磁盘清理动作
+    fn execute_maintenance(&self, data: &str) -> Result<()> {
+        if data == EMPTY_RECYCLE_BIN {
+            recycle_bin::empty()?;
+            *self.recycle_bin.lock().unwrap() = None;
+            log::info!("已清空回收站");
+        } else if let Some(drive) = data.strip_prefix(CLEANUP_PREFIX) {
+            std::process::Command::new("cleanmgr").args(["/d", &format!("{}:", drive)]).spawn()?;
+            log::info!("运行磁盘清理: {}:", drive);
+        }
+        Ok(())
+    }
 }
 
 impl Plugin for SystemCommandsPlugin {
@@ -281,6 +399,10 @@ impl Plugin for SystemCommandsPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("settings-2")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -313,7 +435,8 @@ impl Plugin for SystemCommandsPlugin {
                         90,
                         ActionData::ExecuteCommand { command: cmd.command.clone() },
                     )
-                    .with_icon(cmd.icon.clone()),
+                    .with_icon(cmd.icon.clone())
+                    .with_destructive(DESTRUCTIVE_COMMANDS.contains(&cmd.id.as_str())),
                 );
 
                 if results.len() >= limit {
@@ -322,12 +445,23 @@ impl Plugin for SystemCommandsPlugin {
             }
         }
 
+        let query = query_lower.trim();
+        if matches_terms(query, RECYCLE_BIN_TERMS) {
+            results.push(self.recycle_bin_result());
+        }
+        if matches_terms(query, CLEANUP_TERMS) {
+            results.extend(self.cleanup_results());
+        }
+        results.truncate(limit);
+
         Ok(results)
     }
 
     fn execute(&self, result: &SearchResult) -> Result<()> {
-        if let ActionData::ExecuteCommand { command } = &result.action {
-            self.execute_command(command)?;
+        match &result.action {
+            ActionData::ExecuteCommand { command } => self.execute_command(command)?,
+            ActionData::Custom { data, .. } => self.execute_maintenance(data)?,
+            _ => {},
         }
         Ok(())
     }
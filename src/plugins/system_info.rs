@@ -0,0 +1,374 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+/// 系统信息插件
+///
+/// `sysinfo` 查询显示 CPU、内存、磁盘占用，运行时间、电池、系统版本和显卡，
+/// 每项可复制，选中时在预览面板中显示详细数据；查询保持显示时每隔几秒刷新一次
+use crate::{
+    core::{
+        cache::format_bytes,
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        progress::global_progress,
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::{
+        clipboard::ClipboardManager,
+        system_info::{self, BatteryStatus, DiskUsage, MemoryUsage},
+    },
+};
+
+/// 查询关键字
+const KEYWORD: &str = "sysinfo";
+
+/// 计算 CPU 占用的采样间隔
+const CPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 数据过期后重新读取的间隔
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 一次读取的系统信息
+#[derive(Clone, Debug, Default)]
+pub struct SystemSnapshot {
+    /// CPU 占用百分比
+    pub cpu: Option<f32>,
+    /// 内存占用
+    pub memory: Option<MemoryUsage>,
+    /// 固定磁盘占用
+    pub disks: Vec<DiskUsage>,
+    /// 运行时间
+    pub uptime: Option<Duration>,
+    /// 电池状态
+    pub battery: Option<BatteryStatus>,
+    /// 系统版本
+    pub os: Option<String>,
+    /// 显卡名称
+    pub gpus: Vec<String>,
+}
+
+impl SystemSnapshot {
+    /// 读取当前系统信息（CPU 占用需要两次采样，耗时约半秒）
+    pub fn collect() -> Self {
+        let before = system_info::cpu_times();
+        std::thread::sleep(CPU_SAMPLE_INTERVAL);
+        let cpu = before.zip(system_info::cpu_times()).map(|(b, a)| a.usage_since(&b));
+
+        Self {
+            cpu,
+            memory: system_info::memory_usage(),
+            disks: system_info::disk_usage(),
+            uptime: system_info::uptime(),
+            battery: system_info::battery_status(),
+            os: system_info::os_version(),
+            gpus: system_info::gpu_names(),
+        }
+    }
+
+    /// 各项信息，顺序即显示顺序
+    pub fn items(&self) -> Vec<InfoItem> {
+        let mut items = Vec::new();
+
+        if let Some(cpu) = self.cpu {
+            items.push(InfoItem::new("cpu", "CPU", format!("{:.0}%", cpu)));
+        }
+
+        if let Some(memory) = &self.memory {
+            let percent = system_info::percent(memory.used(), memory.total);
+            let commit_used = memory.commit_total.saturating_sub(memory.commit_available);
+            items.push(
+                InfoItem::new("memory", "内存", usage_text(memory.used(), memory.total, percent))
+                    .with_details(vec![
+                        PreviewRow::new("已用", bytes(memory.used())),
+                        PreviewRow::new("可用", bytes(memory.available)),
+                        PreviewRow::new("总量", bytes(memory.total)),
+                        PreviewRow::new(
+                            "已提交",
+                            format!("{} / {}", bytes(commit_used), bytes(memory.commit_total)),
+                        ),
+                    ]),
+            );
+        }
+
+        for disk in &self.disks {
+            let percent = system_info::percent(disk.used(), disk.total);
+            let root = disk.root.trim_end_matches('\\');
+            items.push(
+                InfoItem::new(
+                    &format!("disk:{}", root),
+                    &format!("磁盘 {}", root),
+                    usage_text(disk.used(), disk.total, percent),
+                )
+                .with_details(vec![
+                    PreviewRow::new("已用", bytes(disk.used())),
+                    PreviewRow::new("剩余", bytes(disk.free)),
+                    PreviewRow::new("总量", bytes(disk.total)),
+                ]),
+            );
+        }
+
+        if let Some(uptime) = self.uptime {
+            items.push(InfoItem::new("uptime", "运行时间", system_info::format_duration(uptime)));
+        }
+
+        if let Some(battery) = &self.battery {
+            let state = match (battery.plugged_in, battery.remaining) {
+                (true, _) => "已接通电源".to_string(),
+                (false, Some(remaining)) => {
+                    format!("剩余 {}", system_info::format_duration(remaining))
+                },
+                (false, None) => "使用电池".to_string(),
+            };
+            items.push(InfoItem::new(
+                "battery",
+                "电池",
+                format!("{}% · {}", battery.percent, state),
+            ));
+        }
+
+        if let Some(os) = &self.os {
+            items.push(InfoItem::new("os", "系统", os.clone()));
+        }
+
+        for (index, gpu) in self.gpus.iter().enumerate() {
+            let label = if self.gpus.len() > 1 {
+                format!("显卡 {}", index + 1)
+            } else {
+                "显卡".to_string()
+            };
+            items.push(InfoItem::new(&format!("gpu:{}", index), &label, gpu.clone()));
+        }
+
+        items
+    }
+}
+
+/// 单项系统信息
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfoItem {
+    /// 唯一键
+    pub key: String,
+    /// 名称
+    pub label: String,
+    /// 值
+    pub value: String,
+    /// 预览面板中显示的详细数据
+    pub details: Vec<PreviewRow>,
+}
+
+impl InfoItem {
+    /// 创建信息项
+    fn new(key: &str, label: &str, value: String) -> Self {
+        Self { key: key.to_string(), label: label.to_string(), value, details: Vec::new() }
+    }
+
+    /// 设置详细数据
+    fn with_details(mut self, details: Vec<PreviewRow>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// 复制到剪贴板的文本
+    pub fn copy_text(&self) -> String {
+        format!("{}: {}", self.label, self.value)
+    }
+}
+
+/// 字节数的显示文本
+fn bytes(value: u64) -> String {
+    format_bytes(value as usize)
+}
+
+/// 占用的显示文本，如 "8.0 GB / 16.0 GB (50%)"
+fn usage_text(used: u64, total: u64, percent: f32) -> String {
+    format!("{} / {} ({:.0}%)", bytes(used), bytes(total), percent)
+}
+
+/// 系统信息插件
+pub struct SystemInfoPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 最近一次读取的系统信息及读取时间
+    snapshot: Arc<Mutex<Option<(Instant, SystemSnapshot)>>>,
+    /// 是否正在后台读取
+    refreshing: Arc<AtomicBool>,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl SystemInfoPlugin {
+    /// 创建新的系统信息插件
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            snapshot: Arc::new(Mutex::new(None)),
+            refreshing: Arc::new(AtomicBool::new(false)),
+            clipboard_manager: ClipboardManager::new(),
+        }
+    }
+
+    /// 数据过期时在后台重新读取，读取完成后通知界面刷新
+    fn refresh_if_stale(&self) {
+        let fresh = self
+            .snapshot
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|(read_at, _)| read_at.elapsed() < REFRESH_INTERVAL);
+        if fresh || self.refreshing.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let (snapshot, refreshing) = (self.snapshot.clone(), self.refreshing.clone());
+        std::thread::spawn(move || {
+            let collected = SystemSnapshot::collect();
+            *snapshot.lock().unwrap() = Some((Instant::now(), collected));
+            refreshing.store(false, Ordering::SeqCst);
+            global_progress().mark_results_changed();
+        });
+    }
+
+    /// 系统信息结果，`filter` 按名称或值过滤
+    fn info_results(&self, filter: &str) -> Vec<SearchResult> {
+        self.refresh_if_stale();
+
+        let Some((_, snapshot)) = self.snapshot.lock().unwrap().clone() else {
+            return vec![SearchResult::new(
+                "sysinfo:loading".to_string(),
+                "正在读取系统信息…".to_string(),
+                String::new(),
+                ResultType::Custom("sysinfo".to_string()),
+                1000,
+                ActionData::CopyToClipboard { text: String::new() },
+            )];
+        };
+
+        let items = snapshot.items();
+        let overview: Vec<PreviewRow> =
+            items.iter().map(|item| PreviewRow::new(&item.label, &item.value)).collect();
+        let filter = filter.to_lowercase();
+
+        items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                item.label.to_lowercase().contains(&filter)
+                    || item.value.to_lowercase().contains(&filter)
+            })
+            .map(|(index, item)| {
+                SearchResult::new(
+                    format!("sysinfo:{}", item.key),
+                    format!("{} {}", item.label, item.value),
+                    "按 Enter 复制".to_string(),
+                    ResultType::Custom("sysinfo".to_string()),
+                    1000u32.saturating_sub(index as u32),
+                    ActionData::CopyToClipboard { text: item.copy_text() },
+                )
+                .with_preview(
+                    Preview::new()
+                        .section(&item.label, item.details.clone())
+                        .section("概览", overview.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Plugin for SystemInfoPlugin {
+    fn id(&self) -> &str {
+        "sysinfo"
+    }
+
+    fn name(&self) -> &str {
+        "系统信息"
+    }
+
+    fn description(&self) -> &str {
+        "查看 CPU、内存、磁盘、电池和系统版本"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("info")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化系统信息插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.info_results(rest.trim()))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            if !text.is_empty() {
+                self.clipboard_manager.set_text(text)?;
+                log::info!("复制系统信息: {}", text);
+            }
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.snapshot.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for SystemInfoPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_items() {
+        let snapshot = SystemSnapshot {
+            cpu: Some(12.4),
+            disks: vec![DiskUsage { root: "C:\\".to_string(), total: 4 << 30, free: 1 << 30 }],
+            battery: Some(BatteryStatus { percent: 80, plugged_in: true, remaining: None }),
+            gpus: vec!["GPU A".to_string(), "GPU B".to_string()],
+            ..Default::default()
+        };
+
+        let items = snapshot.items();
+        let keys: Vec<&str> = items.iter().map(|item| item.key.as_str()).collect();
+        assert_eq!(keys, ["cpu", "disk:C:", "battery", "gpu:0", "gpu:1"]);
+        assert_eq!(items[0].copy_text(), "CPU: 12%");
+        assert_eq!(items[1].value, "3.0 GB / 4.0 GB (75%)");
+        assert_eq!(items[1].details.len(), 3);
+        assert_eq!(items[2].value, "80% · 已接通电源");
+        assert_eq!(items[4].label, "显卡 2");
+    }
+}
@@ -6,8 +6,8 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
 use crate::core::{
-    plugin::Plugin,
-    search::{ActionData, ResultType, SearchResult},
+    plugin::{Plugin, PluginIcon},
+    search::{ActionData, ResultType, SearchResult, SortKeys},
 };
 
 /// 任务状态
@@ -296,6 +296,10 @@ impl Plugin for TaskManagerPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("list-todo")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -335,14 +339,20 @@ impl Plugin for TaskManagerPlugin {
                 };
 
                 for task in tasks.iter().take(limit) {
-                    results.push(SearchResult::new(
-                        format!("task:{}", task.id),
-                        task.display(),
-                        "按 Enter 查看详情".to_string(),
-                        ResultType::Task,
-                        800 + task.priority.weight(),
-                        ActionData::ViewTask { task: task.clone() },
-                    ));
+                    results.push(
+                        SearchResult::new(
+                            format!("task:{}", task.id),
+                            task.display(),
+                            "按 Enter 查看详情".to_string(),
+                            ResultType::Task,
+                            800 + task.priority.weight(),
+                            ActionData::ViewTask { task: task.clone() },
+                        )
+                        .with_sort_keys(SortKeys {
+                            recency: Some(task.updated_at.timestamp_millis()),
+                            size: None,
+                        }),
+                    );
                 }
 
                 // 添加创建任务的快捷选项
@@ -366,6 +376,21 @@ impl Plugin for TaskManagerPlugin {
         Ok(results)
     }
 
+    fn fallback(&self, query: &str) -> Vec<SearchResult> {
+        vec![SearchResult::new(
+            format!("task_manager:fallback:{}", query),
+            format!("创建任务 \"{}\"", query),
+            "没有找到结果，按 Enter 记为任务".to_string(),
+            ResultType::Task,
+            0,
+            ActionData::CreateTask {
+                title: query.to_string(),
+                description: String::new(),
+                priority: TaskPriority::Medium,
+            },
+        )]
+    }
+
     fn execute(&self, result: &SearchResult) -> Result<()> {
         match &result.action {
             ActionData::CreateTask { title, description, priority } => {
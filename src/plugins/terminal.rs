@@ -0,0 +1,451 @@
+use std::{
+    path::PathBuf,
+    process::Command,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// 终端插件
+///
+/// `wt` 列出 Windows Terminal 的配置文件，`wsl` 列出 WSL 发行版，选中后启动；
+/// 关键字后依次可跟过滤词和起始目录，如 `wsl ubuntu ~/projects`
+use crate::core::{
+    plugin::{Plugin, PluginIcon},
+    search::{ActionData, ResultType, SearchResult},
+};
+
+/// Windows Terminal 配置文件的查询关键字
+const WT_KEYWORD: &str = "wt";
+
+/// WSL 发行版的查询关键字
+const WSL_KEYWORD: &str = "wsl";
+
+/// WSL 发行版列表的缓存时间，避免每次按键都运行 `wsl -l -v`
+const WSL_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Windows Terminal 配置文件
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TerminalProfile {
+    /// 名称
+    pub name: String,
+    /// 启动命令
+    #[serde(default)]
+    pub commandline: Option<String>,
+    /// 是否在菜单中隐藏
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// WSL 发行版
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WslDistro {
+    /// 名称
+    pub name: String,
+    /// 运行状态（`Running`、`Stopped`）
+    pub state: String,
+    /// WSL 版本
+    pub version: String,
+    /// 是否为默认发行版
+    pub is_default: bool,
+}
+
+/// 终端动作
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum TerminalAction {
+    /// 启动 Windows Terminal 配置文件
+    Profile { name: String, dir: Option<String> },
+    /// 启动 WSL 发行版
+    Wsl { distro: String, dir: Option<String> },
+}
+
+/// 去掉 JSONC 中的注释和尾随逗号（Windows Terminal 的配置文件允许两者）
+pub fn strip_jsonc(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {},
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            },
+            ('/', Some('/')) => while chars.next_if(|&c| c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            },
+            (']' | '}', _) => {
+                // 去掉尾随逗号
+                let trimmed = output.trim_end().len();
+                if output[..trimmed].ends_with(',') {
+                    output.truncate(trimmed - 1);
+                }
+                output.push(c);
+            },
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+/// 解析 Windows Terminal 配置中的配置文件列表，跳过隐藏的配置文件
+///
+/// 兼容 `"profiles": {"list": [...]}` 和旧版的 `"profiles": [...]` 两种格式
+pub fn parse_profiles(settings: &str) -> Vec<TerminalProfile> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&strip_jsonc(settings)) else {
+        return Vec::new();
+    };
+    let profiles = value.get("profiles");
+    let list = profiles.and_then(|p| p.get("list")).or(profiles).and_then(|p| p.as_array());
+
+    list.into_iter()
+        .flatten()
+        .filter_map(|profile| serde_json::from_value::<TerminalProfile>(profile.clone()).ok())
+        .filter(|profile| !profile.hidden)
+        .collect()
+}
+
+/// 解析 `wsl -l -v` 的输出
+///
+/// ```text
+///   NAME            STATE           VERSION
+/// * Ubuntu          Running         2
+///   Debian          Stopped         2
+/// ```
+pub fn parse_wsl_list(output: &str) -> Vec<WslDistro> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let line = line.trim();
+            let (is_default, line) = match line.strip_prefix('*') {
+                Some(rest) => (true, rest.trim_start()),
+                None => (false, line),
+            };
+            // 名称可能包含空格，状态和版本在最后两列
+            let mut columns: Vec<&str> = line.split_whitespace().collect();
+            let version = columns.pop()?.to_string();
+            let state = columns.pop()?.to_string();
+            let name = columns.join(" ");
+            (!name.is_empty()).then_some(WslDistro { name, state, version, is_default })
+        })
+        .collect()
+}
+
+/// 解码命令输出，`wsl.exe` 输出 UTF-16LE
+fn decode_output(bytes: &[u8]) -> String {
+    // ASCII 字符的 UTF-16LE 编码高字节为 0
+    if bytes.iter().skip(1).step_by(2).any(|&b| b == 0) {
+        let units: Vec<u16> =
+            bytes.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect();
+        String::from_utf16_lossy(&units).trim_start_matches('\u{feff}').to_string()
+    } else {
+        String::from_utf8_lossy(bytes).to_string()
+    }
+}
+
+/// 拆分关键字之后的参数：第一个词为过滤词，其余为起始目录
+fn split_args(rest: &str) -> (&str, Option<&str>) {
+    let rest = rest.trim();
+    match rest.split_once(char::is_whitespace) {
+        Some((filter, dir)) => (filter, Some(dir.trim()).filter(|dir| !dir.is_empty())),
+        None => (rest, None),
+    }
+}
+
+/// Windows Terminal 配置文件的路径（商店版、预览版、非打包版）
+fn settings_paths() -> Vec<PathBuf> {
+    let Some(local) = dirs::data_local_dir() else {
+        return Vec::new();
+    };
+    let packages = local.join("Packages");
+    vec![
+        packages.join("Microsoft.WindowsTerminal_8wekyb3d8bbwe").join("LocalState"),
+        packages.join("Microsoft.WindowsTerminalPreview_8wekyb3d8bbwe").join("LocalState"),
+        local.join("Microsoft").join("Windows Terminal"),
+    ]
+    .into_iter()
+    .map(|dir| dir.join("settings.json"))
+    .collect()
+}
+
+/// 终端插件
+pub struct TerminalPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// WSL 发行版缓存
+    distros: Mutex<Option<(Instant, Vec<WslDistro>)>>,
+}
+
+impl TerminalPlugin {
+    /// 创建新的终端插件
+    pub fn new() -> Self {
+        Self { enabled: true, distros: Mutex::new(None) }
+    }
+
+    /// 读取 Windows Terminal 的配置文件列表
+    fn profiles(&self) -> Vec<TerminalProfile> {
+        settings_paths()
+            .iter()
+            .find_map(|path| std::fs::read_to_string(path).ok())
+            .map(|settings| parse_profiles(&settings))
+            .unwrap_or_default()
+    }
+
+    /// 已安装的 WSL 发行版
+    fn distros(&self) -> Vec<WslDistro> {
+        let mut cached = self.distros.lock().unwrap();
+        if let Some((listed_at, distros)) = cached.as_ref() {
+            if listed_at.elapsed() < WSL_CACHE_TTL {
+                return distros.clone();
+            }
+        }
+
+        let mut cmd = Command::new("wsl");
+        cmd.args(["-l", "-v"]);
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        let distros = match cmd.output() {
+            Ok(output) if output.status.success() => parse_wsl_list(&decode_output(&output.stdout)),
+            Ok(_) => Vec::new(),
+            Err(e) => {
+                log::warn!("获取 WSL 发行版失败: {}", e);
+                Vec::new()
+            },
+        };
+        *cached = Some((Instant::now(), distros.clone()));
+        distros
+    }
+
+    /// Windows Terminal 配置文件结果
+    fn profile_results(&self, filter: &str, dir: Option<&str>) -> Vec<SearchResult> {
+        let filter = filter.to_lowercase();
+        self.profiles()
+            .into_iter()
+            .filter(|profile| profile.name.to_lowercase().contains(&filter))
+            .enumerate()
+            .map(|(index, profile)| {
+                let description = match (dir, &profile.commandline) {
+                    (Some(dir), _) => format!("在 {} 中打开", dir),
+                    (None, Some(commandline)) => commandline.clone(),
+                    (None, None) => "Windows Terminal 配置文件".to_string(),
+                };
+                self.result(
+                    format!("terminal:wt:{}", profile.name),
+                    profile.name.clone(),
+                    description,
+                    900u32.saturating_sub(index as u32),
+                    TerminalAction::Profile { name: profile.name, dir: dir.map(String::from) },
+                )
+            })
+            .collect()
+    }
+
+    /// WSL 发行版结果，默认发行版在前
+    fn distro_results(&self, filter: &str, dir: Option<&str>) -> Vec<SearchResult> {
+        let filter = filter.to_lowercase();
+        let mut distros: Vec<WslDistro> = self
+            .distros()
+            .into_iter()
+            .filter(|distro| distro.name.to_lowercase().contains(&filter))
+            .collect();
+        distros.sort_by_key(|distro| !distro.is_default);
+
+        distros
+            .into_iter()
+            .enumerate()
+            .map(|(index, distro)| {
+                let mut description = format!("WSL {} · {}", distro.version, distro.state);
+                if distro.is_default {
+                    description.push_str(" · 默认");
+                }
+                if let Some(dir) = dir {
+                    description.push_str(&format!(" · 在 {} 中打开", dir));
+                }
+                self.result(
+                    format!("terminal:wsl:{}", distro.name),
+                    distro.name.clone(),
+                    description,
+                    900u32.saturating_sub(index as u32),
+                    TerminalAction::Wsl { distro: distro.name, dir: dir.map(String::from) },
+                )
+            })
+            .collect()
+    }
+
+    /// 构造结果项
+    fn result(
+        &self,
+        id: String,
+        title: String,
+        description: String,
+        score: u32,
+        action: TerminalAction,
+    ) -> SearchResult {
+        SearchResult::new(id, title, description, ResultType::Command, score, ActionData::Custom {
+            plugin: self.id().to_string(),
+            data: serde_json::to_string(&action).unwrap_or_default(),
+        })
+    }
+
+    /// 执行终端动作
+    fn run_action(&self, action: TerminalAction) -> Result<()> {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/c", "start", ""]);
+        match action {
+            TerminalAction::Profile { name, dir } => {
+                log::info!("启动 Windows Terminal 配置文件: {} ({:?})", name, dir);
+                cmd.args(["wt.exe", "-p", &name]);
+                if let Some(dir) = dir {
+                    cmd.args(["-d", &dir]);
+                }
+            },
+            TerminalAction::Wsl { distro, dir } => {
+                log::info!("启动 WSL 发行版: {} ({:?})", distro, dir);
+                cmd.args(["wsl.exe", "-d", &distro]);
+                // `--cd` 接受 Linux 路径和 `~`
+                if let Some(dir) = dir {
+                    cmd.args(["--cd", &dir]);
+                }
+            },
+        }
+        cmd.spawn()?;
+        Ok(())
+    }
+}
+
+impl Plugin for TerminalPlugin {
+    fn id(&self) -> &str {
+        "terminal"
+    }
+
+    fn name(&self) -> &str {
+        "终端"
+    }
+
+    fn description(&self) -> &str {
+        "启动 Windows Terminal 配置文件和 WSL 发行版"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("square-terminal")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化终端插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let query = query.trim_start();
+        for keyword in [WT_KEYWORD, WSL_KEYWORD] {
+            let Some(rest) = query.strip_prefix(keyword) else {
+                continue;
+            };
+            if !rest.is_empty() && !rest.starts_with(' ') {
+                continue;
+            }
+
+            let (filter, dir) = split_args(rest);
+            return Ok(if keyword == WT_KEYWORD {
+                self.profile_results(filter, dir)
+            } else {
+                self.distro_results(filter, dir)
+            });
+        }
+        Ok(Vec::new())
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::Custom { data, .. } = &result.action {
+            self.run_action(serde_json::from_str(data)?)?;
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        *self.distros.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl Default for TerminalPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profiles_and_distros() {
+        let settings = r#"{
+            // 注释
+            "profiles": {
+                "list": [
+                    { "name": "PowerShell", "commandline": "pwsh.exe" },
+                    { "name": "Azure // Cloud Shell", "hidden": true },
+                    /* 块注释 */ { "name": "Ubuntu", },
+                ],
+            },
+        }"#;
+        let names: Vec<String> = parse_profiles(settings).into_iter().map(|p| p.name).collect();
+        assert_eq!(names, ["PowerShell", "Ubuntu"]);
+        assert_eq!(strip_jsonc(r#"{"url": "https://x//y"}"#), r#"{"url": "https://x//y"}"#);
+
+        let output = "  NAME            STATE           VERSION\r\n\
+                      * Ubuntu-22.04    Running         2\r\n  \
+                      Kali Linux      Stopped         1\r\n";
+        let utf16: Vec<u8> = output.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let distros = parse_wsl_list(&decode_output(&utf16));
+        assert_eq!(distros.len(), 2);
+        assert!(distros[0].is_default);
+        assert_eq!(
+            (distros[0].name.as_str(), distros[0].state.as_str()),
+            ("Ubuntu-22.04", "Running")
+        );
+        assert_eq!((distros[1].name.as_str(), distros[1].version.as_str()), ("Kali Linux", "1"));
+
+        assert_eq!(split_args(" ubuntu ~/projects"), ("ubuntu", Some("~/projects")));
+        assert_eq!(split_args(""), ("", None));
+    }
+}
@@ -0,0 +1,375 @@
+use anyhow::Result;
+
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult},
+    },
+    utils::clipboard::ClipboardManager,
+};
+
+/// 查询关键字
+const KEYWORD: &str = "text";
+
+/// 标题显示的最大字符数
+const TITLE_CHARS: usize = 100;
+
+/// 预览显示的最大行数
+const PREVIEW_LINES: usize = 20;
+
+/// 文本转换
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// 全部大写
+    Upper,
+    /// 全部小写
+    Lower,
+    /// 每个单词首字母大写
+    Title,
+    /// camelCase
+    Camel,
+    /// snake_case
+    Snake,
+    /// kebab-case
+    Kebab,
+    /// URL 友好的短标识
+    Slug,
+    /// 反转字符
+    Reverse,
+    /// 去除首尾和每行末尾的空白
+    Trim,
+    /// 按行排序
+    SortLines,
+    /// 删除重复行
+    DedupeLines,
+}
+
+impl Operation {
+    /// 全部转换，按列出顺序
+    pub const ALL: [Operation; 11] = [
+        Operation::Upper,
+        Operation::Lower,
+        Operation::Title,
+        Operation::Camel,
+        Operation::Snake,
+        Operation::Kebab,
+        Operation::Slug,
+        Operation::Reverse,
+        Operation::Trim,
+        Operation::SortLines,
+        Operation::DedupeLines,
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Operation::Upper => "大写 UPPER",
+            Operation::Lower => "小写 lower",
+            Operation::Title => "首字母大写 Title",
+            Operation::Camel => "camelCase",
+            Operation::Snake => "snake_case",
+            Operation::Kebab => "kebab-case",
+            Operation::Slug => "slug",
+            Operation::Reverse => "反转 reverse",
+            Operation::Trim => "去除空白 trim",
+            Operation::SortLines => "按行排序 sort",
+            Operation::DedupeLines => "删除重复行 dedupe",
+        }
+    }
+
+    /// 转换文本
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Operation::Upper => text.to_uppercase(),
+            Operation::Lower => text.to_lowercase(),
+            Operation::Title => title_case(text),
+            Operation::Camel => split_words(text)
+                .iter()
+                .enumerate()
+                .map(
+                    |(index, word)| if index == 0 { word.to_lowercase() } else { capitalize(word) },
+                )
+                .collect(),
+            Operation::Snake => join_words(text, "_"),
+            Operation::Kebab => join_words(text, "-"),
+            Operation::Slug => slugify(text),
+            Operation::Reverse => text.chars().rev().collect(),
+            Operation::Trim => {
+                let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+                lines.join("\n").trim().to_string()
+            },
+            Operation::SortLines => {
+                let mut lines: Vec<&str> = text.lines().collect();
+                lines.sort_by_key(|line| line.to_lowercase());
+                lines.join("\n")
+            },
+            Operation::DedupeLines => {
+                let mut seen = std::collections::HashSet::new();
+                text.lines().filter(|line| seen.insert(*line)).collect::<Vec<_>>().join("\n")
+            },
+        }
+    }
+
+    /// 是否只对多行文本有意义
+    fn is_line_operation(&self) -> bool {
+        matches!(self, Operation::SortLines | Operation::DedupeLines)
+    }
+}
+
+/// 拆分单词：按非字母数字字符分隔，并在 camelCase 的大小写边界处拆开（`HTTPServer` →
+/// `HTTP`、`Server`）
+pub fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+
+    for part in text.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (index, &c) in chars.iter().enumerate() {
+            let boundary = index > 0 && c.is_uppercase() && {
+                let previous = chars[index - 1];
+                let next_is_lower = chars.get(index + 1).is_some_and(|n| n.is_lowercase());
+                previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lower)
+            };
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+
+    words
+}
+
+/// 首字母大写，其余小写
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+/// 小写单词以分隔符连接
+fn join_words(text: &str, separator: &str) -> String {
+    split_words(text).iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join(separator)
+}
+
+/// 每个单词首字母大写，保留原有的空白和标点
+pub fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_word_start = true;
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if at_word_start {
+                result.extend(c.to_uppercase());
+            } else {
+                result.extend(c.to_lowercase());
+            }
+            at_word_start = false;
+        } else {
+            result.push(c);
+            // 撇号不开始新单词（don't → Don't）
+            at_word_start = c != '\'';
+        }
+    }
+    result
+}
+
+/// 生成 slug：小写，字母数字以外的字符合并为单个 `-`
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// 统计描述，如 "12 个单词 · 60 个字符 · 3 行"
+pub fn statistics(text: &str) -> String {
+    format!(
+        "{} 个单词 · {} 个字符 · {} 行",
+        text.split_whitespace().count(),
+        text.chars().count(),
+        text.lines().count().max(1)
+    )
+}
+
+/// 单行显示：换行替换为 ↵，超长截断
+fn display_line(text: &str) -> String {
+    let line = text.replace("\r\n", "\n").replace('\n', " ↵ ");
+    if line.chars().count() > TITLE_CHARS {
+        format!("{}...", line.chars().take(TITLE_CHARS).collect::<String>())
+    } else {
+        line
+    }
+}
+
+/// 文本转换插件
+///
+/// `text <文本>` 转换输入的文本，只输入 `text` 时转换剪贴板中的文本：大小写、camelCase、
+/// snake_case、kebab-case、slug、反转、去除空白、按行排序和去重，以及字数统计，Enter 复制结果
+pub struct TextToolsPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl TextToolsPlugin {
+    /// 创建新的文本转换插件
+    pub fn new() -> Self {
+        Self { enabled: true, clipboard_manager: ClipboardManager::new() }
+    }
+
+    /// 结果项，多行结果在预览中显示全部行
+    fn result(&self, id: String, output: String, description: String, score: u32) -> SearchResult {
+        let lines: Vec<PreviewRow> = output
+            .lines()
+            .take(PREVIEW_LINES)
+            .enumerate()
+            .map(|(index, line)| PreviewRow::new((index + 1).to_string(), line))
+            .collect();
+        let result = SearchResult::new(
+            id,
+            display_line(&output),
+            description,
+            ResultType::Custom("text".to_string()),
+            score,
+            ActionData::CopyToClipboard { text: output },
+        );
+
+        if lines.len() > 1 {
+            result.with_preview(Preview::new().section("结果", lines))
+        } else {
+            result
+        }
+    }
+}
+
+impl Plugin for TextToolsPlugin {
+    fn id(&self) -> &str {
+        "text"
+    }
+
+    fn name(&self) -> &str {
+        "文本转换"
+    }
+
+    fn description(&self) -> &str {
+        "转换大小写和命名风格、排序去重、统计字数"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("command")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化文本转换插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = query.trim_start().strip_prefix(KEYWORD) else {
+            return Ok(Vec::new());
+        };
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            return Ok(Vec::new());
+        }
+
+        let typed = rest.trim();
+        let (text, source) = if typed.is_empty() {
+            (self.clipboard_manager.get_text().unwrap_or_default(), "剪贴板")
+        } else {
+            (typed.to_string(), "输入")
+        };
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let multiline = text.lines().nth(1).is_some();
+        let mut results = vec![self.result(
+            "text:count".to_string(),
+            statistics(&text),
+            format!("{}的字数统计 · 按 Enter 复制", source),
+            1000,
+        )];
+        for (index, operation) in Operation::ALL.iter().enumerate() {
+            if operation.is_line_operation() && !multiline {
+                continue;
+            }
+            let output = operation.apply(&text);
+            if output.is_empty() {
+                continue;
+            }
+            results.push(self.result(
+                format!("text:{:?}", operation),
+                output,
+                format!("{} · {} · 按 Enter 复制", operation.label(), source),
+                990u32.saturating_sub(index as u32),
+            ));
+        }
+        Ok(results)
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            self.clipboard_manager.set_text(text)?;
+            log::info!("复制转换结果: {}", display_line(text));
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for TextToolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operations() {
+        let apply = |operation: Operation, text: &str| operation.apply(text);
+
+        assert_eq!(split_words("parseHTTPServer v2Api"), ["parse", "HTTP", "Server", "v2", "Api"]);
+        assert_eq!(apply(Operation::Camel, "Hello big_world"), "helloBigWorld");
+        assert_eq!(apply(Operation::Snake, "helloBigWorld"), "hello_big_world");
+        assert_eq!(apply(Operation::Kebab, "Hello Big World"), "hello-big-world");
+        assert_eq!(apply(Operation::Title, "don't stop-me now"), "Don't Stop-Me Now");
+        assert_eq!(apply(Operation::Slug, "  Hello, World! 2024 "), "hello-world-2024");
+        assert_eq!(apply(Operation::Reverse, "abc中"), "中cba");
+        assert_eq!(apply(Operation::Trim, "  a  \n b  \n"), "a\n b");
+        assert_eq!(apply(Operation::SortLines, "b\nA\nc"), "A\nb\nc");
+        assert_eq!(apply(Operation::DedupeLines, "a\nb\na\nc\nb"), "a\nb\nc");
+        assert_eq!(statistics("one two\nthree"), "3 个单词 · 13 个字符 · 2 行");
+    }
+}
@@ -0,0 +1,211 @@
+use anyhow::Result;
+
+/// 主题切换插件
+///
+/// `theme <名称>` 或 `主题 <名称>` 列出内置主题和 `~/.config/werun/themes` 中的用户主题，
+/// Enter 立即切换；用户主题文件修改后自动重新加载
+use crate::{
+    core::{
+        config_manager::global_config,
+        plugin::{Plugin, PluginIcon},
+        preview::{Preview, PreviewRow},
+        search::{ActionData, ResultType, SearchResult, SecondaryAction},
+        themes::{self, Appearance, ThemeEntry},
+    },
+    utils::{
+        fuzzy::{match_ranges, FuzzyMatcher},
+        shell,
+    },
+};
+
+/// 查询关键字
+const KEYWORDS: &[&str] = &["theme", "主题"];
+
+/// 主题切换插件
+pub struct ThemeSwitcherPlugin {
+    /// 是否启用
+    enabled: bool,
+}
+
+impl ThemeSwitcherPlugin {
+    /// 创建新的主题切换插件
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// 去掉查询关键字，查询不以关键字开头时返回 `None`
+    fn strip_keyword(query: &str) -> Option<&str> {
+        let query = query.trim();
+        KEYWORDS.iter().find_map(|keyword| {
+            let rest = query.strip_prefix(keyword)?;
+            (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim())
+        })
+    }
+
+    /// 按名称模糊匹配主题，查询为空时按用户主题、内置主题的顺序列出
+    fn filter_themes(entries: Vec<ThemeEntry>, query: &str) -> Vec<(ThemeEntry, u32)> {
+        if query.is_empty() {
+            let count = entries.len() as u32;
+            return entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, entry)| (entry, count - index as u32))
+                .collect();
+        }
+
+        let matcher = FuzzyMatcher::new(global_config().get_config().search.boundary_bonus);
+        let mut matched: Vec<(ThemeEntry, u32)> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let (is_match, score) = matcher.fuzzy_match(query, &entry.name);
+                is_match.then_some((entry, score))
+            })
+            .collect();
+        matched.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        matched
+    }
+
+    /// 构造主题的结果
+    fn theme_result(&self, entry: &ThemeEntry, query: &str, score: u32) -> SearchResult {
+        let appearance = match entry.appearance {
+            Appearance::Light => "浅色",
+            Appearance::Dark => "深色",
+        };
+        let source = if entry.is_user { "用户主题" } else { "内置主题" };
+        let path = entry.path.to_string_lossy().to_string();
+
+        let open_file = ActionData::OpenFile { path: path.clone() };
+        let mut secondary_actions = vec![SecondaryAction::new("打开主题文件", open_file)];
+        if let Some(dir) = themes::user_themes_dir() {
+            let open_dir = ActionData::OpenFile { path: dir.to_string_lossy().to_string() };
+            secondary_actions.push(SecondaryAction::new("打开用户主题文件夹", open_dir));
+        }
+        let rows = vec![
+            PreviewRow::new("明暗", appearance),
+            PreviewRow::new("来源", source),
+            PreviewRow::new("文件", &path),
+        ];
+
+        SearchResult::new(
+            format!("theme:{}", entry.name),
+            entry.name.clone(),
+            format!("{} · {}", appearance, source),
+            ResultType::Settings,
+            score,
+            ActionData::Custom { plugin: self.id().to_string(), data: entry.name.clone() },
+        )
+        .with_title_highlights(match_ranges(query, &entry.name))
+        .with_preview(Preview::new().section("主题", rows))
+        .with_secondary_actions(secondary_actions)
+    }
+
+    /// 搜索主题
+    fn theme_results(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        Self::filter_themes(themes::list_themes(), query)
+            .into_iter()
+            .take(limit)
+            .map(|(entry, score)| self.theme_result(&entry, query, score))
+            .collect()
+    }
+}
+
+impl Plugin for ThemeSwitcherPlugin {
+    fn id(&self) -> &str {
+        "theme"
+    }
+
+    fn name(&self) -> &str {
+        "主题"
+    }
+
+    fn description(&self) -> &str {
+        "切换内置主题和用户主题"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("palette")
+    }
+
+    fn placeholder(&self) -> Option<&str> {
+        Some("输入主题名称，Enter 立即切换")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化主题切换插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(rest) = Self::strip_keyword(query) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self.theme_results(rest, limit))
+    }
+
+    fn default_results(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(self.theme_results("", limit))
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        match &result.action {
+            ActionData::Custom { data, .. } => {
+                log::info!("切换主题: {}", data);
+                themes::request_theme(data);
+            },
+            ActionData::OpenFile { path } => shell::open_path(path)?,
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for ThemeSwitcherPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_themes() {
+        let entry = |name: &str, is_user| ThemeEntry {
+            name: name.to_string(),
+            appearance: Appearance::Dark,
+            path: PathBuf::from("themes.json"),
+            is_user,
+        };
+        let entries = vec![entry("Mine Dark", true), entry("Ayu Dark", false)];
+
+        let all = ThemeSwitcherPlugin::filter_themes(entries.clone(), "");
+        assert_eq!(all[0].0.name, "Mine Dark");
+        assert!(all[0].1 > all[1].1);
+
+        let matched = ThemeSwitcherPlugin::filter_themes(entries, "ayu");
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].0.name, "Ayu Dark");
+        assert_eq!(ThemeSwitcherPlugin::strip_keyword("主题 ayu"), Some("ayu"));
+        assert_eq!(ThemeSwitcherPlugin::strip_keyword("themes"), None);
+    }
+}
@@ -3,9 +3,12 @@ use anyhow::Result;
 /// 网页搜索插件
 ///
 /// 支持多种搜索引擎快速搜索
-use crate::core::plugin::Plugin;
+use crate::core::plugin::{ForegroundInfo, Plugin, PluginIcon};
 use crate::core::search::{ActionData, ResultType, SearchResult};
 
+/// 选中文本在标题中显示的最大字符数
+const MAX_SELECTION_TITLE_CHARS: usize = 40;
+
 /// 搜索引擎配置
 #[derive(Clone, Debug)]
 pub struct SearchEngine {
@@ -111,6 +114,10 @@ impl Plugin for WebSearchPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("globe")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -125,71 +132,92 @@ impl Plugin for WebSearchPlugin {
         Ok(())
     }
 
-    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
-        let mut results = Vec::new();
-
-        // 如果查询以特定前缀开头，使用对应的搜索引擎
-        let (engine_id, search_query) = if let Some(stripped) = query.strip_prefix("g ") {
-            ("google", stripped)
-        } else if let Some(stripped) = query.strip_prefix("b ") {
-            ("bing", stripped)
-        } else if let Some(stripped) = query.strip_prefix("bd ") {
-            ("baidu", stripped)
-        } else if let Some(stripped) = query.strip_prefix("ddg ") {
-            ("duckduckgo", stripped)
-        } else if let Some(stripped) = query.strip_prefix("gh ") {
-            ("github", stripped)
-        } else if let Some(stripped) = query.strip_prefix("so ") {
-            ("stackoverflow", stripped)
-        } else {
-            // 默认使用 Google
-            (self.default_engine.as_str(), query)
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        // 只响应带搜索引擎前缀的查询，其他查询没有结果时由兜底结果提供网页搜索
+        let prefixes = [
+            ("g ", "google"),
+            ("b ", "bing"),
+            ("bd ", "baidu"),
+            ("ddg ", "duckduckgo"),
+            ("gh ", "github"),
+            ("so ", "stackoverflow"),
+        ];
+        let Some((engine_id, search_query)) = prefixes
+            .iter()
+            .find_map(|(prefix, engine_id)| Some((*engine_id, query.strip_prefix(prefix)?)))
+        else {
+            return Ok(Vec::new());
         };
 
+        let mut results = Vec::new();
         if !search_query.is_empty() {
-            if let Some(engine) = self.get_engine(engine_id) {
-                if let Some(url) = self.build_search_url(engine_id, search_query) {
-                    results.push(
-                        SearchResult::new(
-                            format!("web_search:{}:{}", engine_id, search_query),
-                            format!("在 {} 搜索 \"{}\"", engine.name, search_query),
-                            format!("使用 {} 搜索 \"{}\"", engine.name, search_query),
-                            ResultType::Command,
-                            80, // 较高的优先级
-                            ActionData::OpenUrl { url },
-                        )
-                        .with_icon(engine.icon.clone()),
-                    );
-                }
+            if let (Some(engine), Some(url)) =
+                (self.get_engine(engine_id), self.build_search_url(engine_id, search_query))
+            {
+                results.push(
+                    SearchResult::new(
+                        format!("web_search:{}:{}", engine_id, search_query),
+                        format!("在 {} 搜索 \"{}\"", engine.name, search_query),
+                        format!("使用 {} 搜索 \"{}\"", engine.name, search_query),
+                        ResultType::Command,
+                        80, // 较高的优先级
+                        ActionData::OpenUrl { url },
+                    )
+                    .with_icon(engine.icon.clone()),
+                );
             }
         }
 
-        // 如果查询不为空，添加所有搜索引擎的选项
-        if !query.is_empty() && !query.contains(' ') {
-            for engine in &self.engines {
-                if engine.id != engine_id {
-                    if let Some(url) = self.build_search_url(&engine.id, query) {
-                        results.push(
-                            SearchResult::new(
-                                format!("web_search:{}:{}", engine.id, query),
-                                format!("在 {} 搜索 \"{}\"", engine.name, query),
-                                format!("使用 {} 搜索 \"{}\"", engine.name, query),
-                                ResultType::Command,
-                                70,
-                                ActionData::OpenUrl { url },
-                            )
-                            .with_icon(engine.icon.clone()),
-                        );
-
-                        if results.len() >= limit {
-                            break;
-                        }
-                    }
-                }
-            }
+        Ok(results)
+    }
+
+    fn contextual_results(
+        &self,
+        foreground: &ForegroundInfo,
+        _limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        // 浏览器中选中了文本时提供搜索选中内容
+        let (Some(selection), Some(engine)) =
+            (foreground.selection.as_deref(), self.get_engine(&self.default_engine))
+        else {
+            return Ok(Vec::new());
+        };
+        let Some(url) = self.build_search_url(&engine.id, selection) else {
+            return Ok(Vec::new());
+        };
+
+        let mut title: String = selection.chars().take(MAX_SELECTION_TITLE_CHARS).collect();
+        if selection.chars().count() > MAX_SELECTION_TITLE_CHARS {
+            title.push('…');
         }
+        Ok(vec![SearchResult::new(
+            format!("web_search:selection:{}", selection),
+            format!("搜索选中的文本 \"{}\"", title),
+            format!("使用 {} 搜索选中的文本", engine.name),
+            ResultType::Command,
+            1000,
+            ActionData::OpenUrl { url },
+        )
+        .with_icon(engine.icon.clone())])
+    }
 
-        Ok(results)
+    fn fallback(&self, query: &str) -> Vec<SearchResult> {
+        let (Some(engine), Some(url)) = (
+            self.get_engine(&self.default_engine),
+            self.build_search_url(&self.default_engine, query),
+        ) else {
+            return Vec::new();
+        };
+
+        vec![SearchResult::new(
+            format!("web_search:fallback:{}", query),
+            format!("用 {} 搜索 \"{}\"", engine.name, query),
+            "没有找到结果，按 Enter 在网页中搜索".to_string(),
+            ResultType::Command,
+            0,
+            ActionData::OpenUrl { url },
+        )
+        .with_icon(engine.icon.clone())]
     }
 
     fn execute(&self, result: &SearchResult) -> Result<()> {
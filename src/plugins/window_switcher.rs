@@ -3,7 +3,7 @@ use std::sync::{Arc, Mutex};
 use anyhow::Result;
 
 use crate::core::{
-    plugin::Plugin,
+    plugin::{Plugin, PluginIcon},
     search::{ActionData, ResultType, SearchResult},
 };
 
@@ -182,6 +182,10 @@ impl Plugin for WindowSwitcherPlugin {
         "0.1.0"
     }
 
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("layout-dashboard")
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use chrono::{Local, Utc};
+
+/// 世界时钟插件
+///
+/// 查询城市当前时间，或将城市时间换算为本地时间（如 `5pm tokyo`）
+use crate::{
+    core::{
+        plugin::{Plugin, PluginIcon},
+        search::{ActionData, Intent, ResultType, SearchResult},
+    },
+    utils::{clipboard::ClipboardManager, timezone},
+};
+
+/// 世界时钟插件
+pub struct WorldClockPlugin {
+    /// 是否启用
+    enabled: bool,
+    /// 剪贴板管理器
+    clipboard_manager: ClipboardManager,
+}
+
+impl WorldClockPlugin {
+    /// 创建新的世界时钟插件
+    pub fn new() -> Self {
+        Self { enabled: true, clipboard_manager: ClipboardManager::new() }
+    }
+
+    /// 构造结果项
+    fn result(&self, id: String, title: String, description: String, text: String) -> SearchResult {
+        SearchResult::new(
+            format!("world_clock:{}", id),
+            title,
+            description,
+            ResultType::Custom("world_clock".to_string()),
+            900,
+            ActionData::CopyToClipboard { text },
+        )
+    }
+}
+
+impl Plugin for WorldClockPlugin {
+    fn id(&self) -> &str {
+        "world_clock"
+    }
+
+    fn name(&self) -> &str {
+        "世界时钟"
+    }
+
+    fn description(&self) -> &str {
+        "查询城市时间和时区换算"
+    }
+
+    fn version(&self) -> &str {
+        "0.1.0"
+    }
+
+    fn icon(&self) -> PluginIcon {
+        PluginIcon::named("calendar")
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        log::info!("初始化世界时钟插件...");
+        Ok(())
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        let Some(Intent::CityTime { city, time }) = Intent::detect(query) else {
+            return Ok(Vec::new());
+        };
+        let Some(city) = timezone::lookup_city(&city) else {
+            return Ok(Vec::new());
+        };
+
+        let now = Utc::now();
+        let result = match time {
+            Some((hour, minute)) => {
+                // 城市时间 -> 本地时间
+                let utc = timezone::city_time_to_utc(city, hour, minute, now);
+                let local = utc.with_timezone(&Local);
+                let text = local.format("%H:%M").to_string();
+
+                self.result(
+                    format!("{}:{:02}{:02}", city.name, hour, minute),
+                    format!("{} {:02}:{:02} = 本地 {}", city.display, hour, minute, text),
+                    format!("本地日期 {}，按 Enter 复制本地时间", local.format("%Y-%m-%d")),
+                    text,
+                )
+            },
+            None => {
+                let city_time = city.local_time(now);
+                let text = city_time.format("%Y-%m-%d %H:%M").to_string();
+
+                self.result(
+                    city.name.to_string(),
+                    format!("{} 现在 {}", city.display, city_time.format("%H:%M")),
+                    format!(
+                        "{} UTC{}，按 Enter 复制",
+                        city_time.format("%Y-%m-%d"),
+                        city_time.offset()
+                    ),
+                    text,
+                )
+            },
+        };
+
+        Ok(vec![result])
+    }
+
+    fn execute(&self, result: &SearchResult) -> Result<()> {
+        if let ActionData::CopyToClipboard { text } = &result.action {
+            self.clipboard_manager.set_text(text)?;
+            log::info!("复制时间: {}", text);
+        }
+        Ok(())
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Default for WorldClockPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
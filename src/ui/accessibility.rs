@@ -0,0 +1,70 @@
+/// 无障碍支持
+///
+/// GPUI 目前没有向系统暴露无障碍树，焦点变化和状态提示通过 UI 自动化通知事件播报，
+/// 窗口标题保持不变（全局快捷键按标题查找窗口）
+use gpui::Window;
+
+use crate::{core::search::SearchResult, platform, ui::window_hwnd};
+
+/// 结果项的无障碍名称：角色、名称、描述和位置
+pub fn result_label(result: &SearchResult, position: usize, total: usize) -> String {
+    let mut label = format!("{} {}", result.result_type.display(), result.title);
+
+    if result.destructive {
+        label.push_str("（危险操作）");
+    }
+
+    if !result.description.is_empty() {
+        label.push_str("，");
+        label.push_str(&result.description);
+    }
+
+    if let Some(note) = &result.note {
+        label.push_str("，备注：");
+        label.push_str(note);
+    }
+
+    label.push_str(&format!("，第 {} 项，共 {} 项", position + 1, total));
+    label
+}
+
+/// 播报消息，`None` 时不播报
+pub fn announce(window: &Window, message: Option<&str>) {
+    if let (Some(hwnd), Some(message)) = (window_hwnd(window), message) {
+        platform::windows::announce(hwnd, message);
+    }
+}
+
+/// 系统是否开启了高对比度模式
+pub fn is_system_high_contrast() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::UI::{
+            Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW},
+            WindowsAndMessaging::{
+                SystemParametersInfoW, SPI_GETHIGHCONTRAST, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+            },
+        };
+
+        let mut contrast = HIGHCONTRASTW {
+            cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+            ..Default::default()
+        };
+
+        let ok = unsafe {
+            SystemParametersInfoW(
+                SPI_GETHIGHCONTRAST,
+                contrast.cbSize,
+                Some(&mut contrast as *mut HIGHCONTRASTW as *mut _),
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )
+        };
+
+        ok.is_ok() && (contrast.dwFlags & HCF_HIGHCONTRASTON).0 != 0
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        false
+    }
+}
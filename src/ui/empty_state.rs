@@ -0,0 +1,54 @@
+/// 空状态
+///
+/// 没有结果时在列表区域显示图标、提示和示例查询，代替空白区域
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::{Icon, IconName, Sizable, Theme};
+
+/// 全局搜索输入框的占位提示
+pub const GLOBAL_PLACEHOLDER: &str = "搜索应用、文件和更多…";
+
+/// 全局搜索时展示的示例查询
+const EXAMPLE_QUERIES: &[&str] =
+    &["/file 报告", "ext:pdf in:Downloads", "(1 + 2) * 3", "theme dark"];
+
+/// 渲染空状态，`plugin_name` 为当前插件的名称
+pub fn render_empty_state(plugin_name: Option<&str>, query: &str, theme: &Theme) -> Div {
+    let query = query.trim();
+    let (title, tip) = match (plugin_name, query.is_empty()) {
+        (Some(name), true) => (format!("输入以在{}中搜索", name), "Backspace 返回全局搜索"),
+        (Some(name), false) => {
+            (format!("在{}中没有找到“{}”", name, query), "Esc 返回全局搜索，在所有插件中查找")
+        },
+        (None, true) => {
+            ("输入以搜索应用、文件和更多".to_string(), "输入 / 选择插件，输入 > 执行启动器命令")
+        },
+        (None, false) => (format!("没有找到“{}”", query), "检查拼写，或用 ext:、in: 过滤文件"),
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .items_center()
+        .justify_center()
+        .gap_2()
+        .py_8()
+        .child(Icon::new(IconName::Search).large().text_color(theme.muted_foreground))
+        .child(div().text_color(theme.foreground).child(title))
+        .child(div().text_sm().text_color(theme.muted_foreground).child(tip))
+        .when(plugin_name.is_none(), |this| {
+            this.child(
+                div().flex().flex_row().flex_wrap().justify_center().gap_1().pt_2().children(
+                    EXAMPLE_QUERIES.iter().map(|example| {
+                        div()
+                            .px_2()
+                            .py_0p5()
+                            .rounded_md()
+                            .text_xs()
+                            .bg(theme.secondary)
+                            .text_color(theme.secondary_foreground)
+                            .child(*example)
+                    }),
+                ),
+            )
+        })
+}
@@ -0,0 +1,74 @@
+/// 帧时间统计
+///
+/// 记录最近若干帧的渲染间隔，供内部帧时间浮层显示
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// 保留的采样帧数
+const MAX_SAMPLES: usize = 120;
+
+/// 超过该间隔视为空闲后重新开始计时，不计入统计
+const IDLE_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// 帧时间统计器
+#[derive(Default)]
+pub struct FrameStats {
+    /// 最近的帧间隔
+    samples: VecDeque<Duration>,
+    /// 上一帧的时间
+    last_frame: Option<Instant>,
+}
+
+impl FrameStats {
+    /// 创建新的统计器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一帧
+    pub fn record_frame(&mut self) {
+        let now = Instant::now();
+
+        if let Some(last) = self.last_frame {
+            let elapsed = now.duration_since(last);
+            if elapsed < IDLE_THRESHOLD {
+                self.samples.push_back(elapsed);
+                if self.samples.len() > MAX_SAMPLES {
+                    self.samples.pop_front();
+                }
+            }
+        }
+
+        self.last_frame = Some(now);
+    }
+
+    /// 平均帧时间 (毫秒)
+    pub fn average_ms(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+
+        let total: Duration = self.samples.iter().sum();
+        total.as_secs_f32() * 1000.0 / self.samples.len() as f32
+    }
+
+    /// 最大帧时间 (毫秒)
+    pub fn max_ms(&self) -> f32 {
+        self.samples.iter().max().map(|d| d.as_secs_f32() * 1000.0).unwrap_or(0.0)
+    }
+
+    /// 清空统计
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.last_frame = None;
+    }
+
+    /// 格式化为浮层文本
+    pub fn summary(&self) -> String {
+        let average = self.average_ms();
+        let fps = if average > 0.0 { 1000.0 / average } else { 0.0 };
+        format!("{:.1} ms · {:.0} fps · 峰值 {:.1} ms", average, fps, self.max_ms())
+    }
+}
@@ -1,27 +1,109 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use gpui::*;
+use gpui::{prelude::FluentBuilder, *};
 use gpui_component::{
     list::{List, ListEvent, ListState},
-    ActiveTheme, Icon, IconName,
+    notification::Notification,
+    ActiveTheme, Icon, WindowExt,
 };
 
+#[cfg(debug_assertions)]
+use crate::plugins::stress_test::StressTestPlugin;
 use crate::{
     core::{
-        plugin::PluginManager,
+        cache::global_caches,
+        config::{Backdrop, ConfirmationConfig, PreviewMode, ResultLayout},
+        history::global_history,
+        notes::global_notes,
+        notifications::{global_notifications, Notice, NoticeKind},
+        plugin::{PluginIcon, PluginManager},
+        preview::Preview,
+        progress::global_progress,
+        query::{self, FilterKind, ParsedQuery, QueryFilter},
         search::{ActionData, ResultType, SearchResult},
+        spelling::SUGGESTION_ID_PREFIX,
+        warm_up::global_warm_up,
     },
+    platform::{backdrop, file_properties, toast},
     plugins::{
-        app_launcher::AppLauncherPlugin, calculator::CalculatorPlugin, clipboard::ClipboardPlugin,
-        color_picker::ColorPickerPlugin, command_executor::CommandExecutorPlugin,
-        custom_commands::CustomCommandsPlugin, file_search::FileSearchPlugin,
-        system_commands::SystemCommandsPlugin, task_manager::TaskManagerPlugin,
-        web_search::WebSearchPlugin, window_switcher::WindowSwitcherPlugin,
+        app_launcher::AppLauncherPlugin,
+        audio::{self, AudioPlugin},
+        browser_history::BrowserHistoryPlugin,
+        calculator::CalculatorPlugin,
+        clipboard::ClipboardPlugin,
+        color_picker::ColorPickerPlugin,
+        command_executor::CommandExecutorPlugin,
+        cron::CronPlugin,
+        custom_commands::CustomCommandsPlugin,
+        dev_context::{self, DevContextPlugin},
+        display::{self, DisplayPlugin},
+        encoding::EncodingPlugin,
+        file_search::FileSearchPlugin,
+        folder_jump::{self, FolderJumpPlugin},
+        games::GamesPlugin,
+        issue_tracker::IssueTrackerPlugin,
+        kubernetes::KubernetesPlugin,
+        launcher_commands::{LauncherCommand, LauncherCommandsPlugin},
+        link_tools::LinkToolsPlugin,
+        lorem::LoremPlugin,
+        media::MediaPlugin,
+        memory_diagnostics::MemoryDiagnosticsPlugin,
+        network_tools::{self, NetworkToolsPlugin},
+        password_generator::PasswordGeneratorPlugin,
+        password_vault::PasswordVaultPlugin,
+        projects::ProjectsPlugin,
+        quick_note::QuickNotePlugin,
+        quote::{self, QuotePlugin},
+        random_data::RandomDataPlugin,
+        services::ServicesPlugin,
+        settings_search::{self, SettingsSearchPlugin},
+        system_commands::SystemCommandsPlugin,
+        system_info::SystemInfoPlugin,
+        task_manager::TaskManagerPlugin,
+        terminal::TerminalPlugin,
+        text_tools::TextToolsPlugin,
+        theme_switcher::ThemeSwitcherPlugin,
+        web_search::WebSearchPlugin,
+        window_switcher::WindowSwitcherPlugin,
+        world_clock::WorldClockPlugin,
+    },
+    ui::{
+        accessibility, create_new_window,
+        empty_state::GLOBAL_PLACEHOLDER,
+        frame_stats::FrameStats,
+        plugin_icon::render_plugin_icon,
+        plugins_page::PluginsPage,
+        preview_panel::PreviewPanelView,
+        quick_look::QuickLookView,
+        result_grid::{self, GridMove},
+        result_list::{
+            home_results, note_action, render_highlighted_text, result_type_icon,
+            ResultListDelegate, NOTE_ID_PREFIX,
+        },
+        themes::ResultTypeStyle,
+        window_hwnd,
     },
-    ui::result_list::ResultListDelegate,
-    utils::clipboard::ClipboardManager,
+    utils::{clipboard::ClipboardManager, file_preview},
+    window_manager::global_window_manager,
 };
 
+/// 预览面板按行滚动的距离（像素）
+const PREVIEW_LINE_STEP: f32 = 40.0;
+
+/// 缓存的文件预览数量上限，超出时清空重新加载
+const MAX_FILE_PREVIEWS: usize = 64;
+
+/// 启用背景材质时窗口背景色的不透明度，让材质透出来
+const BACKDROP_BACKGROUND_OPACITY: f32 = 0.6;
+
+/// 显示/隐藏动画中内容收缩到的边距（像素），随动画展开或收起，呈现缩放效果
+const TRANSITION_INSET: f32 = 12.0;
+
 /// 启动器窗口状态
 pub struct LauncherWindow {
     /// 列表状态
@@ -34,8 +116,46 @@ pub struct LauncherWindow {
     active_plugin_id: Option<String>,
     /// 列表事件订阅
     _list_subscription: Subscription,
+    /// 列表变化订阅，结果或选中项变化时在后台读取文件预览
+    _list_observation: Subscription,
+    /// 窗口激活订阅
+    _activation_subscription: Subscription,
+    /// 关闭事件订阅，播放隐藏动画后隐藏窗口
+    _dismiss_subscription: Subscription,
+    /// 窗口的原生句柄
+    hwnd: Option<isize>,
+    /// 显示和隐藏动画的时长，为零时不播放动画
+    animation_duration: Duration,
+    /// 动画的序号，每次显示或隐藏时递增以重新播放动画
+    transition_generation: usize,
+    /// 是否正在播放隐藏动画
+    hiding: bool,
     /// 快捷键配置
     keybindings: crate::core::config::KeybindingsConfig,
+    /// 执行确认配置
+    confirmation: ConfirmationConfig,
+    /// 帧时间统计
+    frame_stats: FrameStats,
+    /// 是否显示帧时间浮层
+    show_frame_overlay: bool,
+    /// 最近一次执行失败的提示
+    error_message: Option<String>,
+    /// 动作面板当前展开的结果
+    action_panel: Option<SearchResult>,
+    /// 快速查看浮层展开的结果
+    quick_look: Option<SearchResult>,
+    /// 等待再次按下 Enter 确认执行的结果
+    pending_confirmation: Option<SearchResult>,
+    /// 预览面板的滚动句柄
+    preview_scroll: ScrollHandle,
+    /// 预览面板显示方式，初始为配置的值，Ctrl+P 切换
+    preview_mode: PreviewMode,
+    /// 按路径缓存的文件预览，`None` 表示正在后台加载
+    file_previews: HashMap<String, Option<Preview>>,
+    /// 配置的窗口背景材质
+    backdrop: Backdrop,
+    /// 已生效的背景材质是否为深色，`None` 表示未启用或系统不支持
+    backdrop_dark: Option<bool>,
 }
 
 impl LauncherWindow {
@@ -46,13 +166,22 @@ impl LauncherWindow {
 
         // 初始化所有插件
         if let Err(e) = plugin_manager.initialize_all() {
-            log::error!("初始化插件失败: {:?}", e);
+            e.log();
         }
 
+        // 插件登记的缓存超出内存预算时定期裁剪
+        global_caches().start_periodic_trim();
+
         // 创建列表委托和状态（使用 List 内置搜索）
         let plugin_manager = Arc::new(plugin_manager);
-        let delegate =
-            ResultListDelegate::new(Vec::new()).with_plugin_manager(plugin_manager.clone());
+        // 查询为空时显示上下文结果和固定、常用的应用
+        let home = home_results(&plugin_manager);
+        // 登录时后台启动的实例等待插件初始化后预读首页结果的图标
+        global_warm_up().plugins_ready(&home);
+        let layout_config = crate::core::config_manager::global_config().get_config().window.layout;
+        let delegate = ResultListDelegate::new(home)
+            .with_plugin_manager(plugin_manager.clone())
+            .with_layout_config(layout_config);
         let list_state = cx.new(|cx| ListState::new(delegate, window, cx).searchable(true));
 
         // 订阅列表事件
@@ -60,18 +189,202 @@ impl LauncherWindow {
             cx.subscribe_in(&list_state, window, |this, _state, event: &ListEvent, window, cx| {
                 this.on_list_event(event, window, cx);
             });
+        let list_observation =
+            cx.observe(&list_state, |this, _state, cx| this.load_file_preview(cx));
+
+        // 后台索引期间定时刷新状态行，结束后再刷新一次以隐藏状态行，
+        // 查询仍为空时同时用扫描到的应用刷新默认结果；后台任务更新了结果时重新搜索；
+        // 同时处理托盘菜单发出的请求
+        cx.spawn(async move |this, cx| {
+            let mut was_busy = true;
+            loop {
+                cx.background_executor().timer(Duration::from_millis(500)).await;
+                let is_busy = global_progress().is_busy();
+                let results_changed = global_progress().take_results_changed();
+
+                // 托盘菜单的退出和重新扫描请求
+                if global_window_manager().take_quit_request() {
+                    let _ = cx.update(|cx| cx.quit());
+                    break;
+                }
+                let rescan = global_window_manager().take_rescan_request();
+
+                if is_busy || was_busy || results_changed || rescan {
+                    let updated = this.update(cx, |this, cx| {
+                        if rescan {
+                            // 插件刷新可能较慢，在后台线程中进行，进度由各插件登记
+                            let plugin_manager = this.plugin_manager.clone();
+                            std::thread::spawn(move || {
+                                let started = Instant::now();
+                                plugin_manager.refresh_all();
+                                let elapsed = started.elapsed().as_secs_f32();
+                                global_notifications().notify(
+                                    NoticeKind::IndexRebuilt,
+                                    "索引已重建",
+                                    format!("重新扫描用时 {:.1} 秒", elapsed),
+                                );
+                            });
+                        }
+                        if !is_busy {
+                            this.refresh_home_results(cx);
+                        }
+                        if results_changed {
+                            this.list_state.update(cx, |state, cx| {
+                                state.delegate_mut().rerun_search();
+                                cx.notify();
+                            });
+                        }
+                        cx.notify();
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+                was_busy = is_busy;
+            }
+        })
+        .detach();
+
+        // 后台事件的通知：窗口显示时在窗口内显示，隐藏时改用系统通知
+        cx.spawn_in(window, async move |this, cx| loop {
+            cx.background_executor().timer(Duration::from_millis(500)).await;
+            let notices = global_notifications().take_pending();
+            if notices.is_empty() {
+                continue;
+            }
+            let shown = this.update_in(cx, |_, window, cx| Self::show_notices(notices, window, cx));
+            if shown.is_err() {
+                break;
+            }
+        })
+        .detach();
+
+        // 窗口激活时按新的前台应用刷新默认结果，再应用跳转列表等填入的查询
+        let activation_subscription = cx.observe_window_activation(window, |this, window, cx| {
+            if window.is_window_active() {
+                // 每次显示时重新播放显示动画，也取消仍在进行的隐藏
+                this.hiding = false;
+                this.transition_generation += 1;
+                // 文件可能已在窗口隐藏期间修改
+                this.file_previews.clear();
+                // 标记只在一次显示期间有效
+                this.list_state.update(cx, |state, _cx| state.delegate_mut().clear_marked());
+                this.plugin_manager.notify_activated();
+                this.refresh_home_results(cx);
+                this.load_foreground_details(cx);
+                this.apply_pending_query(window, cx);
+            }
+        });
+
+        // 执行结果或按 Esc 关闭后播放隐藏动画，结束时隐藏窗口
+        let dismiss_subscription = cx.subscribe_self(|this, event: &DismissEvent, cx| {
+            this.hide_animated(matches!(event, DismissEvent::Cancelled), cx)
+        });
 
-        // 加载快捷键配置
-        let keybindings = crate::core::config_manager::global_config().get_config().keybindings;
+        // 加载快捷键和执行确认配置
+        let config = crate::core::config_manager::global_config().get_config();
 
-        Self {
+        let mut this = Self {
             list_state,
             plugin_manager,
             clipboard_manager: ClipboardManager::new(),
             active_plugin_id: None,
             _list_subscription: list_subscription,
-            keybindings,
+            _list_observation: list_observation,
+            _activation_subscription: activation_subscription,
+            _dismiss_subscription: dismiss_subscription,
+            hwnd: window_hwnd(window),
+            animation_duration: Duration::from_millis(config.window.animation_duration_ms),
+            transition_generation: 0,
+            hiding: false,
+            keybindings: config.keybindings,
+            confirmation: config.confirmation,
+            frame_stats: FrameStats::new(),
+            show_frame_overlay: false,
+            error_message: None,
+            action_panel: None,
+            quick_look: None,
+            pending_confirmation: None,
+            preview_scroll: ScrollHandle::new(),
+            preview_mode: config.window.preview,
+            file_previews: HashMap::new(),
+            backdrop: config.window.backdrop,
+            backdrop_dark: None,
+        };
+        this.apply_backdrop(window, cx);
+        this.apply_pending_query(window, cx);
+        this
+    }
+
+    /// 将待填入的查询写入搜索框
+    fn apply_pending_query(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(query) = global_window_manager().take_pending_query() else {
+            return;
+        };
+
+        log::info!("填入查询: {}", query);
+        self.action_panel = None;
+        self.pending_confirmation = None;
+        self.list_state.update(cx, |state, cx| {
+            state.set_query(&query, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// 显示后台事件的通知
+    fn show_notices(notices: Vec<Notice>, window: &mut Window, cx: &mut App) {
+        if window.is_window_active() {
+            for notice in notices {
+                let note = Notification::new().title(notice.title).message(notice.message);
+                window.push_notification(note, cx);
+            }
+            return;
+        }
+
+        // 显示系统通知需要初始化 COM，在后台线程中进行
+        std::thread::spawn(move || {
+            for notice in notices {
+                if let Err(e) = toast::show(&notice.title, &notice.message) {
+                    e.log();
+                }
+            }
+        });
+    }
+
+    /// 按配置设置窗口背景材质，材质的明暗跟随当前主题
+    fn apply_backdrop(&mut self, window: &Window, cx: &App) {
+        let dark = cx.theme().mode.is_dark();
+        let applied =
+            window_hwnd(window).is_some_and(|hwnd| backdrop::apply(hwnd, self.backdrop, dark));
+        self.backdrop_dark = applied.then_some(dark);
+    }
+
+    /// 播放隐藏动画，结束后隐藏窗口；`restore_focus` 为真时将焦点还给启动器显示前的应用
+    fn hide_animated(&mut self, restore_focus: bool, cx: &mut Context<Self>) {
+        if self.hiding {
+            return;
         }
+        self.hiding = true;
+        self.transition_generation += 1;
+        cx.notify();
+
+        let generation = self.transition_generation;
+        let duration = self.animation_duration;
+        cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(duration).await;
+            let _ = this.update(cx, |this, cx| {
+                // 动画期间窗口再次显示时不再隐藏
+                if !this.hiding || this.transition_generation != generation {
+                    return;
+                }
+                this.hiding = false;
+                if let Some(hwnd) = this.hwnd {
+                    global_window_manager().hide_launcher(hwnd, restore_focus);
+                }
+                cx.notify();
+            });
+        })
+        .detach();
     }
 
     /// 初始化插件
@@ -93,6 +406,9 @@ impl LauncherWindow {
         // 注册网页搜索插件
         manager.register(WebSearchPlugin::new());
 
+        // 注册浏览历史插件
+        manager.register(BrowserHistoryPlugin::new());
+
         // 注册系统命令插件
         manager.register(SystemCommandsPlugin::new());
 
@@ -111,7 +427,100 @@ impl LauncherWindow {
         // 注册任务管理器插件
         manager.register(TaskManagerPlugin::new());
 
-        log::info!("已注册 {} 个插件", manager.plugin_count());
+        // 注册启动器命令插件（输入 > 时列出启动器自身的命令）
+        manager.register(LauncherCommandsPlugin::new());
+
+        // 注册开发上下文插件（输入 dev 或从 VS Code 唤出时才加载）
+        manager.register_lazy("dev_context", &["dev"], DevContextPlugin::new);
+        manager.load_on_foreground("dev_context", dev_context::EDITOR_PROCESSES);
+
+        // 注册最近项目插件
+        manager.register(ProjectsPlugin::new());
+
+        // 注册游戏启动插件
+        manager.register(GamesPlugin::new());
+
+        // 注册工单插件（识别直接输入的工单号，不能按关键字延迟加载）
+        manager.register(IssueTrackerPlugin::new());
+
+        // 注册终端插件（输入 wt 或 wsl 时才加载）
+        manager.register_lazy("terminal", &["wt", "wsl"], TerminalPlugin::new);
+
+        // 注册世界时钟插件
+        manager.register(WorldClockPlugin::new());
+
+        // 注册内存诊断插件（输入 memory 时才加载）
+        manager.register_lazy("memory", &["memory"], MemoryDiagnosticsPlugin::new);
+
+        // 注册网络工具插件（输入 ip、ping、dns、port 时才加载）
+        manager.register_lazy("net", network_tools::KEYWORDS, NetworkToolsPlugin::new);
+
+        // 注册系统信息插件（输入 sysinfo 时才加载）
+        manager.register_lazy("sysinfo", &["sysinfo"], SystemInfoPlugin::new);
+
+        // 注册音频插件（输入 audio 或 vol 时才加载）
+        manager.register_lazy("audio", audio::KEYWORDS, AudioPlugin::new);
+
+        // 注册媒体控制插件（输入 media 时才加载）
+        manager.register_lazy("media", &["media"], MediaPlugin::new);
+
+        // 注册显示与电源插件（输入 display、power、bright 时才加载）
+        manager.register_lazy("display", display::KEYWORDS, DisplayPlugin::new);
+
+        // 注册快速笔记插件（输入 note 时才加载）
+        manager.register_lazy("note", &["note"], QuickNotePlugin::new);
+
+        // 注册密码生成插件（输入 pwgen 时才加载）
+        manager.register_lazy("pwgen", &["pwgen"], PasswordGeneratorPlugin::new);
+
+        // 注册随机数据插件（输入 uuid 时才加载）
+        manager.register_lazy("uuid", &["uuid"], RandomDataPlugin::new);
+
+        // 注册编解码插件（输入 enc 时才加载）
+        manager.register_lazy("enc", &["enc"], EncodingPlugin::new);
+
+        // 注册文本转换插件（输入 text 时才加载）
+        manager.register_lazy("text", &["text"], TextToolsPlugin::new);
+
+        // 注册行情插件（输入 quote 或 btc 等加密货币代码时才加载）
+        manager.register_lazy("quote", quote::KEYWORDS, QuotePlugin::new);
+
+        // 注册链接工具插件（输入 link 时才加载）
+        manager.register_lazy("link", &["link"], LinkToolsPlugin::new);
+
+        // 注册 cron 插件（输入 cron 时才加载）
+        manager.register_lazy("cron", &["cron"], CronPlugin::new);
+
+        // 注册占位文本插件（输入 lorem 时才加载）
+        manager.register_lazy("lorem", &["lorem"], LoremPlugin::new);
+
+        // 注册文件夹跳转插件（输入 cd 或 go 时才加载）
+        manager.register_lazy("cd", folder_jump::KEYWORDS, FolderJumpPlugin::new);
+
+        // 注册 Windows 服务插件（输入 svc 时才加载）
+        manager.register_lazy("svc", &["svc"], ServicesPlugin::new);
+
+        // 注册 Kubernetes 插件（输入 k8s 时才加载）
+        manager.register_lazy("k8s", &["k8s"], KubernetesPlugin::new);
+
+        // 注册密码库插件（输入 vault 时才加载）
+        manager.register_lazy("vault", &["vault"], PasswordVaultPlugin::new);
+
+        // 注册设置搜索插件（输入 settings 或 设置 时才加载）
+        manager.register_lazy("settings_search", &["settings", "设置"], SettingsSearchPlugin::new);
+
+        // 注册主题切换插件（输入 theme 或 主题 时才加载）
+        manager.register_lazy("theme", &["theme", "主题"], ThemeSwitcherPlugin::new);
+
+        // 注册压力测试插件（仅调试构建，输入 stress 时才加载）
+        #[cfg(debug_assertions)]
+        manager.register_lazy("stress_test", &["stress"], StressTestPlugin::new);
+
+        log::info!(
+            "已注册 {} 个插件，其中 {} 个按需加载",
+            manager.plugin_count(),
+            manager.lazy_plugin_count()
+        );
 
         manager
     }
@@ -124,15 +533,9 @@ impl LauncherWindow {
         let results = if !query.is_empty() {
             let mut results = self.plugin_manager.search_all(&query, 50);
 
-            // 为结果添加高亮
-            for result in &mut results {
-                let highlighted_title =
-                    crate::utils::fuzzy::highlight_matches(&query, &result.title);
-                result.highlighted_title = Some(highlighted_title);
-
-                let highlighted_desc =
-                    crate::utils::fuzzy::highlight_matches(&query, &result.description);
-                result.highlighted_description = Some(highlighted_desc);
+            // 为结果添加高亮（多查询搜索的结果已按各自的子查询高亮）
+            for result in results.iter_mut().filter(|r| r.title_highlights.is_none()) {
+                result.highlight(&query);
             }
 
             results
@@ -148,40 +551,26 @@ impl LauncherWindow {
     }
 
     /// 处理列表事件
-    fn on_list_event(&mut self, event: &ListEvent, _window: &mut Window, cx: &mut Context<Self>) {
+    fn on_list_event(&mut self, event: &ListEvent, window: &mut Window, cx: &mut Context<Self>) {
         match event {
+            ListEvent::Select(_) => {
+                self.pending_confirmation = None;
+                self.reset_preview_scroll();
+                self.announce_selection(window, cx);
+                // 刷新预览面板
+                cx.notify();
+            },
             ListEvent::Confirm(ix) => {
                 let result_opt = {
                     let delegate = self.list_state.read(cx).delegate();
-                    delegate.get_item(ix.row).cloned()
+                    delegate.get_item(delegate.item_index(*ix)).cloned()
                 };
 
                 if let Some(result) = result_opt {
-                    // 检查是否是插件选择
-                    if result.id.starts_with("__plugin__:") {
-                        if let ActionData::Custom { plugin: _, data } = &result.action {
-                            // 选择插件：设置活动插件
-                            let plugin_id = data.clone();
-                            self.active_plugin_id = Some(plugin_id.clone());
-
-                            // 更新 delegate 的活动插件
-                            self.list_state.update(cx, |state, _cx| {
-                                state.delegate_mut().set_active_plugin(Some(plugin_id.clone()));
-                            });
-
-                            log::info!("切换到插件: {}", plugin_id);
-                            return;
-                        }
-                    }
-
-                    log::info!("确认执行: {:?}", result);
-                    self.execute_result(&result);
-                    cx.emit(DismissEvent);
+                    self.confirm_result(result, window, cx);
                 }
             },
-            ListEvent::Cancel => {
-                cx.emit(DismissEvent);
-            },
+            ListEvent::Cancel => self.go_back(window, cx),
             _ => {},
         }
     }
@@ -200,25 +589,20 @@ impl LauncherWindow {
             } else {
                 self.plugin_manager.search_plugin(plugin_id, query, 50)
             }
+        } else if query.trim().is_empty() {
+            home_results(&self.plugin_manager)
+        } else if query.starts_with('/') {
+            self.handle_plugin_command(query)
         } else {
-            if query.starts_with('/') {
-                self.handle_plugin_command(query)
-            } else {
-                self.plugin_manager.search_all(query, 50)
-            }
+            self.plugin_manager.search_all(query, 50)
         };
 
         log::info!("搜索结果数量: {}", results.len());
 
-        // 添加高亮
+        // 添加高亮（多查询搜索的结果已按各自的子查询高亮）
         let mut results = results;
-        for result in &mut results {
-            let highlighted_title = crate::utils::fuzzy::highlight_matches(query, &result.title);
-            result.highlighted_title = Some(highlighted_title);
-
-            let highlighted_desc =
-                crate::utils::fuzzy::highlight_matches(query, &result.description);
-            result.highlighted_description = Some(highlighted_desc);
+        for result in results.iter_mut().filter(|r| r.title_highlights.is_none()) {
+            result.highlight(query);
         }
 
         // 更新列表
@@ -228,6 +612,38 @@ impl LauncherWindow {
         });
     }
 
+    /// 查询为空且未选择插件时重新加载默认结果
+    fn refresh_home_results(&mut self, cx: &mut Context<Self>) {
+        let delegate = self.list_state.read(cx).delegate();
+        if self.active_plugin_id.is_some()
+            || self.action_panel.is_some()
+            || delegate.editing_note().is_some()
+            || !delegate.search_query().trim().is_empty()
+        {
+            return;
+        }
+
+        let results = home_results(&self.plugin_manager);
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().update_from_search(results);
+            cx.notify();
+        });
+    }
+
+    /// 在后台读取资源管理器的文件夹、浏览器的选中文本，读到后刷新默认结果中的上下文结果
+    fn load_foreground_details(&self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this, cx| {
+            let loaded = cx
+                .background_executor()
+                .spawn(async move { global_window_manager().load_foreground_details() })
+                .await;
+            if loaded {
+                let _ = this.update(cx, |this, cx| this.refresh_home_results(cx));
+            }
+        })
+        .detach();
+    }
+
     /// 处理插件命令
     fn handle_plugin_command(&self, query: &str) -> Vec<SearchResult> {
         let query = query.trim_start_matches('/');
@@ -243,6 +659,8 @@ impl LauncherWindow {
         matches
             .into_iter()
             .map(|id| {
+                let icon =
+                    self.plugin_manager.plugin_icon(&id).map(|icon| icon.as_str().to_string());
                 SearchResult::new(
                     format!("__plugin__:{}", id),
                     format!("/{}", id),
@@ -251,6 +669,7 @@ impl LauncherWindow {
                     1000,
                     ActionData::Custom { plugin: "plugin_selector".to_string(), data: id },
                 )
+                .with_icon(icon)
             })
             .collect()
     }
@@ -263,9 +682,80 @@ impl LauncherWindow {
         cx: &mut Context<Self>,
     ) {
         let key = event.keystroke.key.as_str();
+        let is_close = key == self.keybindings.close.to_lowercase().as_str() || key == "escape";
+
+        // 快速查看打开时 Space 或 Escape 只关闭浮层，其余按键关闭浮层后照常处理
+        if self.quick_look.take().is_some() {
+            cx.notify();
+            if key == "space" || is_close {
+                cx.stop_propagation();
+                return;
+            }
+        }
+
+        if is_close {
+            self.go_back(window, cx);
+            return;
+        }
+
+        // Ctrl+Space 标记或取消标记选中结果，用于批量执行
+        if key == "space" && event.keystroke.modifiers.control {
+            self.toggle_marked(window, cx);
+            cx.stop_propagation();
+            return;
+        }
+
+        // 输入框为空时 Space、任何时候 Shift+Space 快速查看选中结果
+        if key == "space"
+            && (event.keystroke.modifiers.shift
+                || self.list_state.read(cx).delegate().search_query().is_empty())
+            && self.open_quick_look(cx)
+        {
+            cx.stop_propagation();
+            return;
+        }
+
+        // Ctrl+K 打开/关闭选中结果的动作面板
+        if key == "k" && event.keystroke.modifiers.control {
+            self.toggle_action_panel(window, cx);
+            return;
+        }
+
+        // F12 切换帧时间浮层
+        if key == "f12" {
+            self.show_frame_overlay = !self.show_frame_overlay;
+            self.frame_stats.reset();
+            cx.notify();
+            return;
+        }
+
+        // Ctrl+S 切换当前视图的排序方式
+        if key == "s" && event.keystroke.modifiers.control {
+            let order = self.list_state.update(cx, |state, cx| {
+                let order = state.delegate_mut().cycle_sort_order();
+                cx.notify();
+                order
+            });
+            log::info!("排序方式切换为：{}", order.display());
+            return;
+        }
 
-        if key == self.keybindings.close.to_lowercase().as_str() || key == "escape" {
-            cx.emit(DismissEvent);
+        // Ctrl+P 切换预览面板的显示方式
+        if key == "p" && event.keystroke.modifiers.control {
+            self.preview_mode = self.preview_mode.next();
+            self.reset_preview_scroll();
+            self.load_file_preview(cx);
+            log::info!("预览面板切换为：{}", self.preview_mode.display());
+            cx.notify();
+            return;
+        }
+
+        // 输入框为空时 Backspace 退出当前插件
+        if key == "backspace"
+            && self.active_plugin_id.is_some()
+            && self.list_state.read(cx).delegate().search_query().is_empty()
+        {
+            self.exit_plugin(window, cx);
             return;
         }
 
@@ -281,6 +771,22 @@ impl LauncherWindow {
             return;
         }
 
+        // Ctrl+↑/↓ 按行、Shift+PageUp/PageDown 按页滚动预览面板，不改变选中项
+        let modifiers = &event.keystroke.modifiers;
+        let preview_step = match key {
+            "up" | "arrowup" if modifiers.control => Some(-PREVIEW_LINE_STEP),
+            "down" | "arrowdown" if modifiers.control => Some(PREVIEW_LINE_STEP),
+            "pageup" if modifiers.shift => Some(-self.preview_page_step()),
+            "pagedown" if modifiers.shift => Some(self.preview_page_step()),
+            _ => None,
+        };
+        if let Some(step) = preview_step {
+            if self.preview_target(cx).is_some() {
+                self.scroll_preview(step, cx);
+                return;
+            }
+        }
+
         let items_count = self.list_state.read(cx).delegate().items_count();
         if items_count == 0 {
             return;
@@ -288,6 +794,62 @@ impl LauncherWindow {
 
         let current = self.list_state.read(cx).selected_index();
 
+        // PageUp/PageDown 按页移动选中项，Home/End 跳到第一个/最后一个结果；
+        // 输入框有内容时 Home/End 留给输入框移动光标，需同时按住 Ctrl
+        let query_empty = self.list_state.read(cx).delegate().search_query().is_empty();
+        let jump = {
+            let delegate = self.list_state.read(cx).delegate();
+            let page = delegate.visible_rows() as isize;
+            match key {
+                "pageup" => Some(delegate.offset_rows(-page)),
+                "pagedown" => Some(delegate.offset_rows(page)),
+                "home" if modifiers.control || query_empty => Some(0),
+                "end" if modifiers.control || query_empty => Some(items_count - 1),
+                _ => None,
+            }
+        };
+        if let Some(index) = jump {
+            self.select_item(index, window, cx);
+            cx.stop_propagation();
+            return;
+        }
+
+        // Alt+1..9 直接执行对应的可见结果
+        if modifiers.alt && !modifiers.control {
+            let number = key.parse::<usize>().ok();
+            let target = number.and_then(|n| self.list_state.read(cx).delegate().visible_item(n));
+            if let Some(index) = target {
+                self.select_item(index, window, cx);
+                let result = self.list_state.read(cx).delegate().get_item(index).cloned();
+                if let Some(result) = result {
+                    self.confirm_result(result, window, cx);
+                }
+                cx.stop_propagation();
+                return;
+            }
+        }
+
+        // 网格布局下方向键在行列间移动
+        if self.list_state.read(cx).delegate().layout() == ResultLayout::Grid {
+            let navigate_up = self.keybindings.navigate_up.to_lowercase();
+            let navigate_down = self.keybindings.navigate_down.to_lowercase();
+            let direction = match key {
+                "up" | "arrowup" => Some(GridMove::Up),
+                "down" | "arrowdown" => Some(GridMove::Down),
+                "left" | "arrowleft" => Some(GridMove::Left),
+                "right" | "arrowright" => Some(GridMove::Right),
+                _ if key == navigate_up => Some(GridMove::Up),
+                _ if key == navigate_down => Some(GridMove::Down),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                let selected = self.list_state.read(cx).delegate().selected_index;
+                let index = result_grid::move_selection(selected, items_count, direction);
+                self.select_item(index, window, cx);
+                return;
+            }
+        }
+
         if key == self.keybindings.navigate_up.to_lowercase().as_str() || key == "arrowup" {
             let new_index = if let Some(ix) = current {
                 if ix.row > 0 {
@@ -303,6 +865,10 @@ impl LauncherWindow {
                 self.list_state.update(cx, |state, cx| {
                     state.set_selected_index(Some(ix), window, cx);
                 });
+                self.pending_confirmation = None;
+                self.reset_preview_scroll();
+                self.announce_selection(window, cx);
+                cx.notify();
             }
             return;
         }
@@ -322,36 +888,561 @@ impl LauncherWindow {
                 self.list_state.update(cx, |state, cx| {
                     state.set_selected_index(Some(ix), window, cx);
                 });
+                self.pending_confirmation = None;
+                self.reset_preview_scroll();
+                self.announce_selection(window, cx);
+                cx.notify();
+            }
+            return;
+        }
+
+        // Ctrl+Shift+Enter 以管理员身份运行选中结果
+        if key == "enter" && event.keystroke.modifiers.control && event.keystroke.modifiers.shift {
+            let elevated = current.and_then(|ix| {
+                let delegate = self.list_state.read(cx).delegate();
+                delegate.get_item(delegate.item_index(ix)).and_then(|r| r.elevated())
+            });
+            let Some(result) = elevated else {
+                return;
+            };
+
+            let query = self.list_state.read(cx).delegate().search_query().to_string();
+            if self.execute_result(&result, &query) {
+                cx.emit(DismissEvent::Executed);
+            } else {
+                accessibility::announce(window, self.status_message().as_deref());
+                cx.notify();
             }
             return;
         }
 
-        if key == self.keybindings.confirm.to_lowercase().as_str() || key == "enter" {
+        let is_confirm = key == self.keybindings.confirm.to_lowercase().as_str() || key == "enter";
+
+        // 有标记的结果时 Enter 批量执行它们的主动作
+        if is_confirm && self.action_panel.is_none() && self.execute_marked(window, cx) {
+            cx.stop_propagation();
+            return;
+        }
+
+        if is_confirm {
             if let Some(ix) = current {
                 let result_opt = {
                     let delegate = self.list_state.read(cx).delegate();
-                    delegate.get_item(ix.row).cloned()
+                    delegate.get_item(delegate.item_index(ix)).cloned()
                 };
 
                 if let Some(result) = result_opt {
-                    if result.id.starts_with("__plugin__:") {
-                        if let ActionData::Custom { plugin: _, data } = &result.action {
-                            let plugin_id = data.clone();
-                            self.active_plugin_id = Some(plugin_id.clone());
-                            self.list_state.update(cx, |state, _cx| {
-                                state.delegate_mut().set_active_plugin(Some(plugin_id.clone()));
-                            });
-                            log::info!("切换到插件: {}", plugin_id);
-                            return;
-                        }
-                    }
+                    self.confirm_result(result, window, cx);
+                }
+            }
+        }
+    }
+
+    /// 确认选中的结果：编辑备注、应用补全建议、执行启动器命令、进入插件或执行结果
+    fn confirm_result(
+        &mut self,
+        result: SearchResult,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // 添加、保存备注由窗口处理
+        if result.id.starts_with(NOTE_ID_PREFIX) {
+            self.handle_note_item(&result, window, cx);
+            return;
+        }
+
+        // 拼写纠正建议：替换查询后重新搜索
+        if result.id.starts_with(SUGGESTION_ID_PREFIX) {
+            self.apply_suggestion(&result, window, cx);
+            return;
+        }
+
+        if let Some(command) = LauncherCommand::from_result(&result) {
+            self.run_launcher_command(command, window, cx);
+            return;
+        }
+
+        if result.id.starts_with("__plugin__:") {
+            if let ActionData::Custom { plugin: _, data } = &result.action {
+                let plugin_id = data.clone();
+                self.active_plugin_id = Some(plugin_id.clone());
+                self.list_state.update(cx, |state, _cx| {
+                    state.delegate_mut().set_active_plugin(Some(plugin_id.clone()));
+                });
+                log::info!("切换到插件: {}", plugin_id);
+                return;
+            }
+        }
+
+        log::info!("确认执行: {:?}", result);
+        let query = self.list_state.read(cx).delegate().search_query().to_string();
+        if self.execute_result(&result, &query) {
+            cx.emit(DismissEvent::Executed);
+        } else {
+            accessibility::announce(window, self.status_message().as_deref());
+            cx.notify();
+        }
+    }
+
+    /// 执行启动器命令；切换主题和预览面板只改变窗口状态，不隐藏窗口
+    fn run_launcher_command(
+        &mut self,
+        command: LauncherCommand,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        log::info!("执行启动器命令: {:?}", command);
+        match command {
+            LauncherCommand::OpenSettings => {
+                if let Err(e) = settings_search::open_config(None) {
+                    log::warn!("打开配置文件失败: {}", e);
+                }
+            },
+            LauncherCommand::ManagePlugins => {
+                let plugin_manager = self.plugin_manager.clone();
+                create_new_window(
+                    "插件",
+                    move |window, cx| cx.new(|cx| PluginsPage::new(plugin_manager, window, cx)),
+                    cx,
+                );
+            },
+            LauncherCommand::ReloadPlugins => {
+                // 重新读取配置后刷新插件，插件刷新时读取新的插件设置
+                if let Err(e) = crate::core::config_manager::global_config().reload() {
+                    e.log();
+                }
+                global_window_manager().request_rescan();
+            },
+            LauncherCommand::RebuildIndex => global_window_manager().request_rescan(),
+            LauncherCommand::SwitchTheme => {
+                self.list_state.update(cx, |state, cx| {
+                    state.set_query("theme ", window, cx);
+                });
+                cx.notify();
+                return;
+            },
+            LauncherCommand::TogglePreview => {
+                self.preview_mode = self.preview_mode.next();
+                self.reset_preview_scroll();
+                self.load_file_preview(cx);
+                log::info!("预览面板切换为：{}", self.preview_mode.display());
+                cx.notify();
+                return;
+            },
+            LauncherCommand::Quit => {
+                cx.quit();
+                return;
+            },
+        }
+        cx.emit(DismissEvent::Executed);
+    }
+
+    /// 选中结果，重置待确认的执行和预览滚动
+    fn select_item(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        self.list_state.update(cx, |state, cx| {
+            let row = state.delegate_mut().select_item(index);
+            state.set_selected_index(
+                Some(gpui_component::IndexPath::default().row(row)),
+                window,
+                cx,
+            );
+        });
+        self.pending_confirmation = None;
+        self.reset_preview_scroll();
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 当前选中的结果
+    fn selected_result(&self, cx: &App) -> Option<SearchResult> {
+        let list = self.list_state.read(cx);
+        let delegate = list.delegate();
+        list.selected_index().and_then(|ix| delegate.get_item(delegate.item_index(ix)).cloned())
+    }
 
-                    log::info!("确认执行: {:?}", result);
-                    self.execute_result(&result);
-                    cx.emit(DismissEvent);
+    /// 标记或取消标记选中结果
+    fn toggle_marked(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(ix) = self.list_state.read(cx).selected_index() else {
+            return;
+        };
+        let count = self.list_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            let index = delegate.item_index(ix);
+            let count = delegate.toggle_marked(index);
+            cx.notify();
+            count
+        });
+
+        // 批量结果的 ID 固定，标记改变后不能沿用对上一组标记的确认
+        self.pending_confirmation = None;
+        log::info!("已标记 {} 个结果", count);
+        accessibility::announce(window, Some(&format!("已标记 {} 项", count)));
+        cx.notify();
+    }
+
+    /// 批量执行标记结果的主动作，没有标记时返回 `false`
+    fn execute_marked(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let marked = self.list_state.read(cx).delegate().marked().to_vec();
+        let Some(batch) = SearchResult::batch_items(&marked).into_iter().next() else {
+            return false;
+        };
+
+        let query = self.list_state.read(cx).delegate().search_query().to_string();
+        if self.execute_result(&batch, &query) {
+            self.list_state.update(cx, |state, _cx| state.delegate_mut().clear_marked());
+            cx.emit(DismissEvent::Executed);
+        } else {
+            accessibility::announce(window, self.status_message().as_deref());
+            cx.notify();
+        }
+        true
+    }
+
+    /// 打开选中结果的快速查看浮层，动作面板打开、编辑备注或没有选中项时返回 `false`
+    fn open_quick_look(&mut self, cx: &mut Context<Self>) -> bool {
+        if self.action_panel.is_some() || self.is_editing_note(cx) {
+            return false;
+        }
+        let Some(result) = self.selected_result(cx) else {
+            return false;
+        };
+
+        log::info!("快速查看: {}", result.title);
+        self.quick_look = Some(result);
+        cx.notify();
+        true
+    }
+
+    /// 打开选中结果的动作面板，已打开时关闭
+    ///
+    /// 面板将次要动作作为结果项显示在列表中，执行方式与普通结果相同
+    fn toggle_action_panel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.action_panel.is_some() {
+            self.close_action_panel(window, cx);
+            return;
+        }
+
+        // 有标记的结果时显示批量动作
+        let marked = self.list_state.read(cx).delegate().marked().to_vec();
+        let (result, items) = if marked.is_empty() {
+            let Some(result) = self.selected_result(cx) else {
+                return;
+            };
+            // 任何结果都可以添加备注
+            let mut items = result.action_items();
+            items.push(note_action(&result));
+            (result, items)
+        } else {
+            let items = SearchResult::batch_items(&marked);
+            (items[0].clone(), items)
+        };
+
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().set_items(items);
+            state.set_selected_index(Some(gpui_component::IndexPath::default().row(0)), window, cx);
+            cx.notify();
+        });
+
+        log::info!("打开动作面板: {}", result.title);
+        self.action_panel = Some(result);
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 是否正在编辑备注
+    fn is_editing_note(&self, cx: &App) -> bool {
+        self.list_state.read(cx).delegate().editing_note().is_some()
+    }
+
+    /// 处理备注结果项：从动作面板开始编辑，或保存输入框中的备注
+    fn handle_note_item(
+        &mut self,
+        item: &SearchResult,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ActionData::Custom { data, .. } = &item.action else {
+            return;
+        };
+
+        if let Some(target) = self.list_state.read(cx).delegate().editing_note().cloned() {
+            global_notes().set(&target, data);
+            log::info!("保存备注: {} -> {}", target.title, data);
+            self.finish_note_editing(window, cx);
+            return;
+        }
+
+        let Some(target) = self.action_panel.take() else {
+            return;
+        };
+        log::info!("编辑备注: {}", target.title);
+        let note = target.note.clone().unwrap_or_default();
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().set_editing_note(Some(target), &note);
+            state.set_query(&note, window, cx);
+        });
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 使用拼写纠正建议的查询，修改输入框文本后由列表重新搜索
+    fn apply_suggestion(
+        &mut self,
+        item: &SearchResult,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ActionData::Custom { data, .. } = &item.action else {
+            return;
+        };
+
+        log::info!("使用纠正后的查询: {}", data);
+        self.list_state.update(cx, |state, cx| {
+            state.set_query(data, window, cx);
+        });
+        self.pending_confirmation = None;
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 逐级返回：先取消待确认的执行和备注编辑、关闭动作面板、清除标记，
+    /// 再退出插件回到全局搜索、清空查询，都没有时隐藏窗口
+    fn go_back(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.pending_confirmation.take().is_some() {
+            cx.notify();
+        } else if self.is_editing_note(cx) {
+            self.finish_note_editing(window, cx);
+        } else if self.action_panel.is_some() {
+            self.close_action_panel(window, cx);
+        } else if !self.list_state.read(cx).delegate().marked().is_empty() {
+            self.list_state.update(cx, |state, cx| {
+                state.delegate_mut().clear_marked();
+                cx.notify();
+            });
+            cx.notify();
+        } else if self.active_plugin_id.is_some() {
+            self.exit_plugin(window, cx);
+        } else if !self.list_state.read(cx).delegate().search_query().is_empty() {
+            self.list_state.update(cx, |state, cx| {
+                state.set_query("", window, cx);
+            });
+            self.announce_selection(window, cx);
+            cx.notify();
+        } else {
+            cx.emit(DismissEvent::Cancelled);
+        }
+    }
+
+    /// 退出当前插件，按现有查询回到全局搜索
+    fn exit_plugin(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(plugin_id) = self.active_plugin_id.take() {
+            log::info!("退出插件: {}", plugin_id);
+        }
+        self.list_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            delegate.set_active_plugin(None);
+            delegate.rerun_search();
+            cx.notify();
+        });
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 结束编辑备注，清空输入框并恢复默认结果
+    fn finish_note_editing(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().set_editing_note(None, "");
+            state.set_query("", window, cx);
+        });
+        self.announce_selection(window, cx);
+        cx.notify();
+    }
+
+    /// 关闭动作面板，恢复搜索结果
+    fn close_action_panel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.action_panel = None;
+        let query = self.list_state.read(cx).delegate().search_query().to_string();
+        self.perform_search(&query, cx);
+        self.announce_selection(window, cx);
+    }
+
+    /// 移除查询中的过滤标记
+    ///
+    /// 修改输入框文本后由列表重新搜索
+    fn remove_query_filter(
+        &mut self,
+        filter: &QueryFilter,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let query = self.list_state.read(cx).delegate().search_query().to_string();
+        let query = query::remove_filter(&query, filter);
+        log::info!("移除过滤标记: {}", filter.label());
+
+        self.list_state.update(cx, |state, cx| {
+            state.set_query(&query, window, cx);
+        });
+        self.pending_confirmation = None;
+        cx.notify();
+    }
+
+    /// 过滤标记的图标和标签，插件关键词显示为插件名称
+    fn filter_chip(&self, filter: &QueryFilter) -> (PluginIcon, String) {
+        match filter.kind {
+            FilterKind::Extension => (PluginIcon::named("file"), filter.label()),
+            FilterKind::Folder => (PluginIcon::named("folder"), filter.label()),
+            FilterKind::Plugin => {
+                let id = self.plugin_manager.match_plugin_ids(&filter.value).into_iter().next();
+                let icon = id.as_ref().and_then(|id| self.plugin_manager.plugin_icon(id));
+                let name = id.and_then(|id| self.plugin_manager.plugin_name(&id));
+                (
+                    icon.unwrap_or_else(|| PluginIcon::named("box")),
+                    name.unwrap_or_else(|| filter.label()),
+                )
+            },
+        }
+    }
+
+    /// 选中结果对应的文件路径，结果自带预览时不需要读取文件
+    fn selected_file_path(&self, cx: &App) -> Option<String> {
+        let list = self.list_state.read(cx);
+        let delegate = list.delegate();
+        let selected =
+            list.selected_index().and_then(|ix| delegate.get_item(delegate.item_index(ix)))?;
+        if selected.preview.is_some() {
+            return None;
+        }
+        selected.file_path().map(str::to_string)
+    }
+
+    /// 在后台读取选中文件的预览，完成后重绘，避免切换选中项时卡顿
+    fn load_file_preview(&mut self, cx: &mut Context<Self>) {
+        if self.preview_mode == PreviewMode::Never {
+            return;
+        }
+        let Some(path) = self.selected_file_path(cx) else {
+            return;
+        };
+        if self.file_previews.contains_key(&path) {
+            return;
+        }
+        if self.file_previews.len() >= MAX_FILE_PREVIEWS {
+            self.file_previews.clear();
+        }
+
+        self.file_previews.insert(path.clone(), None);
+        cx.spawn(async move |this, cx| {
+            let file = PathBuf::from(&path);
+            let preview = cx
+                .background_executor()
+                .spawn(async move { file_preview::load(&file, file_properties::read(&file)) })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                this.file_previews.insert(path, Some(preview));
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// 预览面板显示的内容：不显示面板时为 `None`，否则为选中的结果（可能没有选中项）
+    ///
+    /// 文件和文件夹结果附带读取到的文件预览，读取完成前只显示基本信息
+    fn preview_target(&self, cx: &App) -> Option<Option<SearchResult>> {
+        let list = self.list_state.read(cx);
+        let delegate = list.delegate();
+        let selected =
+            list.selected_index().and_then(|ix| delegate.get_item(delegate.item_index(ix)));
+        let selected = selected.cloned().map(|mut result| {
+            if result.preview.is_none() {
+                if let Some(path) = result.file_path() {
+                    result.preview = self.file_previews.get(path).cloned().flatten();
                 }
             }
+            result
+        });
+        match self.preview_mode {
+            PreviewMode::Auto => selected
+                .filter(|result| result.preview.is_some() || result.file_path().is_some())
+                .map(Some),
+            PreviewMode::Always => Some(selected),
+            PreviewMode::Never => None,
+        }
+    }
+
+    /// 滚动预览面板，`delta` 为正时向下滚动
+    fn scroll_preview(&mut self, delta: f32, cx: &mut Context<Self>) {
+        let offset = self.preview_scroll.offset();
+        let max = f32::from(self.preview_scroll.max_offset().height);
+        let y = (f32::from(offset.y) - delta).clamp(-max, 0.0);
+        self.preview_scroll.set_offset(point(offset.x, px(y)));
+        cx.notify();
+    }
+
+    /// 预览面板按页滚动的距离：可见高度减去一行，保留上下文
+    fn preview_page_step(&self) -> f32 {
+        let height = f32::from(self.preview_scroll.bounds().size.height);
+        (height - PREVIEW_LINE_STEP).max(PREVIEW_LINE_STEP)
+    }
+
+    /// 切换选中结果时预览回到顶部
+    fn reset_preview_scroll(&self) {
+        self.preview_scroll.set_offset(point(px(0.), px(0.)));
+    }
+
+    /// 执行未完成时的提示：待确认的结果或失败原因
+    fn status_message(&self) -> Option<String> {
+        self.pending_confirmation
+            .as_ref()
+            .map(|result| format!("确认执行「{}」？按 Enter 确认，Esc 取消", result.title))
+            .or_else(|| self.error_message.clone())
+    }
+
+    /// 底部快捷键提示，按选中结果的主要动作和可用的动作生成
+    fn footer_hints(&self, preview_shown: bool, cx: &App) -> String {
+        let list = self.list_state.read(cx);
+        let delegate = list.delegate();
+        let selected =
+            list.selected_index().and_then(|ix| delegate.get_item(delegate.item_index(ix)));
+
+        let marked = delegate.marked();
+        let mut hints = match (selected, &self.action_panel) {
+            // 动作面板中的项只有执行一种方式
+            (Some(result), Some(_)) => vec![format!("↵ {}", result.primary_label())],
+            // 有标记的结果时 Enter 和动作面板作用于所有标记的结果
+            (_, None) if !marked.is_empty() => {
+                let batch = SearchResult::batch_items(marked);
+                let label = batch.first().map_or_else(String::new, |item| item.title.clone());
+                vec![
+                    format!("已标记 {} 项", marked.len()),
+                    format!("↵ {}", label),
+                    "⌃K 批量动作".to_string(),
+                ]
+            },
+            // 任何结果的动作面板中都有添加备注
+            (Some(result), None) => result.shortcut_hints(1),
+            (None, _) => vec!["↑↓ 选择".to_string()],
+        };
+        // 预览面板显示时提示滚动快捷键
+        if preview_shown {
+            hints.push("⌃↑↓ 滚动预览".to_string());
         }
+        hints.push(if self.action_panel.is_some() { "Esc 返回" } else { "Esc 关闭" }.to_string());
+        hints.join(" · ")
+    }
+
+    /// 向读屏软件播报当前选中的结果
+    fn announce_selection(&self, window: &mut Window, cx: &mut Context<Self>) {
+        let list = self.list_state.read(cx);
+        let delegate = list.delegate();
+        let label = list.selected_index().and_then(|ix| {
+            let index = delegate.item_index(ix);
+            delegate
+                .get_item(index)
+                .map(|result| accessibility::result_label(result, index, delegate.items_count()))
+        });
+
+        accessibility::announce(window, label.as_deref());
     }
 
     /// 切换到下一个插件
@@ -414,68 +1505,226 @@ impl LauncherWindow {
     }
 
     /// 执行搜索结果
-    fn execute_result(&self, result: &SearchResult) {
+    ///
+    /// 返回是否执行成功；失败时记录面向用户的提示，窗口保持打开
+    fn execute_result(&mut self, result: &SearchResult, query: &str) -> bool {
+        self.error_message = None;
+        let pending = self.pending_confirmation.take();
+
         // 处理插件选择器的特殊 case
         if result.id.starts_with("__plugin__:") {
             if let ActionData::Custom { plugin: _, data } = &result.action {
                 log::info!("切换到插件: {}，请输入搜索内容", data);
                 // 选择插件后不执行任何操作，让用户在搜索框中继续输入
-                return;
+                return true;
             }
         }
 
+        // 按确认策略需要确认时，再次对同一结果按下 Enter 才执行
+        if self.confirmation.requires_confirmation(result)
+            && pending.map_or(true, |pending| pending.id != result.id)
+        {
+            log::info!("等待确认执行: {}", result.title);
+            self.pending_confirmation = Some(result.clone());
+            return false;
+        }
+
         // 尝试通过插件管理器执行
-        if let Err(e) = self.plugin_manager.execute(result) {
-            log::error!("通过插件执行失败: {:?}", e);
-
-            // 如果插件执行失败，尝试根据类型执行
-            match &result.action {
-                ActionData::LaunchApp { path, .. } => {
-                    log::info!("启动应用: {}", path);
-                    let _ =
-                        std::process::Command::new("cmd").args(["/c", "start", "", path]).spawn();
-                },
-                ActionData::OpenFile { path } => {
-                    log::info!("打开文件: {}", path);
-                    let _ = std::process::Command::new("explorer").arg(path).spawn();
-                },
-                ActionData::ExecuteCommand { command } => {
-                    log::info!("执行命令: {}", command);
-                    let _ = std::process::Command::new("cmd").args(["/c", command]).spawn();
-                },
-                ActionData::CopyToClipboard { text } => {
-                    log::info!("复制到剪贴板: {}", text);
-                    if let Err(e) = self.clipboard_manager.set_text(text) {
-                        log::error!("复制到剪贴板失败: {:?}", e);
-                    }
-                },
-                ActionData::OpenUrl { url } => {
-                    log::info!("打开 URL: {}", url);
-                    let _ =
-                        std::process::Command::new("cmd").args(["/c", "start", "", url]).spawn();
-                },
-                _ => {
-                    log::warn!("未知的动作类型");
-                },
+        let Err(e) = self.plugin_manager.execute(result) else {
+            self.action_panel = None;
+            // 隐私开关由历史模块统一检查
+            global_history().record_execution(result, query);
+            if result.paste {
+                if let Err(e) = global_window_manager().paste_to_previous() {
+                    e.log();
+                    self.error_message = Some(e.user_message());
+                    return false;
+                }
             }
+            return true;
+        };
+        e.log();
+
+        // 如果插件执行失败，尝试根据类型执行
+        match &result.action {
+            // 提权失败（如取消 UAC）时不降级为普通启动
+            ActionData::LaunchApp { path, elevated: false, .. } => {
+                log::info!("启动应用: {}", path);
+                let _ = std::process::Command::new("cmd").args(["/c", "start", "", path]).spawn();
+            },
+            ActionData::OpenFile { path } => {
+                log::info!("打开文件: {}", path);
+                let _ = std::process::Command::new("explorer").arg(path).spawn();
+            },
+            ActionData::ExecuteCommand { command } => {
+                log::info!("执行命令: {}", command);
+                let _ = std::process::Command::new("cmd").args(["/c", command]).spawn();
+            },
+            ActionData::CopyToClipboard { text } => {
+                log::info!("复制到剪贴板: {}", text);
+                if let Err(e) = self.clipboard_manager.set_text(text) {
+                    e.log();
+                    self.error_message = Some(e.user_message());
+                    return false;
+                }
+            },
+            ActionData::OpenUrl { url } => {
+                log::info!("打开 URL: {}", url);
+                let _ = std::process::Command::new("cmd").args(["/c", "start", "", url]).spawn();
+            },
+            _ => {
+                log::warn!("未知的动作类型");
+                self.error_message = Some(e.user_message());
+                return false;
+            },
         }
+
+        true
     }
 }
 
 impl Render for LauncherWindow {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // 帧时间浮层开启时持续请求重绘，以便测量实际帧间隔
+        if self.show_frame_overlay {
+            self.frame_stats.record_frame();
+            window.request_animation_frame();
+        }
+
+        // 切换主题后重新设置背景材质的明暗
+        if self.backdrop_dark.is_some_and(|dark| dark != cx.theme().mode.is_dark()) {
+            self.apply_backdrop(window, cx);
+        }
+
+        // 序号提示和 Alt+数字按列表实际的滚动位置对应可见结果
+        self.list_state.update(cx, |state, _cx| {
+            let offset = -f32::from(state.scroll_handle().offset().y);
+            state.delegate_mut().set_scroll_offset(offset);
+        });
+
+        // 列表高度按界面密度同时显示的结果数计算，密度在设置中切换后立即生效
+        let density = crate::core::config_manager::global_config().density();
+        let list_height = px(density.row_height() * density.visible_results() as f32);
+
         let theme = cx.theme();
+        let background = if self.backdrop_dark.is_some() {
+            theme.background.opacity(BACKDROP_BACKGROUND_OPACITY)
+        } else {
+            theme.background
+        };
 
-        // 获取列表中的结果数量
+        // 获取列表中的结果数量和排序方式
         let results_count = self.list_state.read(cx).delegate().items_count();
+        let sort_order = self.list_state.read(cx).delegate().sort_order();
+
+        // 按预览显示方式在列表右侧显示预览面板，选中项变化时随之更新
+        let preview_target = self.preview_target(cx);
+
+        let hints = self.footer_hints(preview_target.is_some(), cx);
+
+        // 底部状态：待确认或执行失败时用提示替换结果统计
+        let status = match (self.pending_confirmation.is_some(), self.status_message()) {
+            (true, Some(message)) => div().text_color(theme.warning).child(message),
+            (false, Some(message)) => div().text_color(theme.danger).child(message),
+            (_, None) => match &self.action_panel {
+                Some(result) => div().child(format!("动作 · {}", result.title)),
+                None => div().child(format!(
+                    "{} 个结果 · 排序：{}",
+                    results_count,
+                    sort_order.display()
+                )),
+            },
+        };
 
-        div()
+        // 进行中的后台索引任务
+        let tasks = global_progress().active();
+
+        // 输入框的占位提示，插件模式下由插件提供
+        let placeholder = self
+            .active_plugin_id
+            .as_ref()
+            .and_then(|id| self.plugin_manager.plugin_placeholder(id))
+            .unwrap_or_else(|| GLOBAL_PLACEHOLDER.to_string());
+
+        // 当前插件标签，点击 × 或在输入框为空时按 Backspace 回到全局搜索
+        let plugin_chip = self.active_plugin_id.as_ref().map(|id| {
+            let icon =
+                self.plugin_manager.plugin_icon(id).unwrap_or_else(|| PluginIcon::named("box"));
+            let name = self.plugin_manager.plugin_name(id).unwrap_or_else(|| id.clone());
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_1()
+                .px_2()
+                .py_0p5()
+                .rounded_full()
+                .text_xs()
+                .bg(theme.secondary)
+                .text_color(theme.secondary_foreground)
+                .child(render_plugin_icon(&icon, theme.secondary_foreground))
+                .child(name)
+                .child(div().text_color(theme.muted_foreground).child("Tab 切换"))
+                .child(
+                    div()
+                        .cursor_pointer()
+                        .text_color(theme.muted_foreground)
+                        .child("×")
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _: &MouseDownEvent, window, cx| {
+                                this.exit_plugin(window, cx);
+                                cx.stop_propagation();
+                            }),
+                        ),
+                )
+        });
+
+        // 查询中已完成的过滤标记，显示为可移除的标签
+        let query = self.list_state.read(cx).delegate().search_query().to_string();
+        let filter_chips: Vec<_> = ParsedQuery::parse(&query)
+            .filters
+            .into_iter()
+            .filter(|filter| filter.committed)
+            .map(|filter| {
+                let (icon, label) = self.filter_chip(&filter);
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .py_0p5()
+                    .rounded_full()
+                    .text_xs()
+                    .bg(theme.secondary)
+                    .text_color(theme.secondary_foreground)
+                    .child(render_plugin_icon(&icon, theme.primary))
+                    .child(label)
+                    .child(
+                        div()
+                            .cursor_pointer()
+                            .text_color(theme.muted_foreground)
+                            .child("×")
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |this, _: &MouseDownEvent, window, cx| {
+                                    this.remove_query_filter(&filter, window, cx);
+                                    cx.stop_propagation();
+                                }),
+                            ),
+                    )
+            })
+            .collect();
+
+        let content = div()
+            .relative()
             .size_full()
             .flex()
             .flex_col()
             .gap_2()
             .p_4()
-            .bg(theme.background)
+            .bg(background)
             .rounded_xl()
             .border_1()
             .border_color(theme.border)
@@ -483,8 +1732,73 @@ impl Render for LauncherWindow {
             .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
                 this.handle_key_event(event, window, cx);
             }))
+            // 当前插件标签和查询过滤标签
+            .when(plugin_chip.is_some() || !filter_chips.is_empty(), |this| {
+                this.child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .flex_wrap()
+                        .items_center()
+                        .gap_1()
+                        .children(plugin_chip)
+                        .children(filter_chips),
+                )
+            })
             // 列表（带搜索框）
-            .child(List::new(&self.list_state).max_h(px(400.)).p_1())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            // 右键结果时结果项已先被选中，随后打开它的动作面板
+                            .on_mouse_down(
+                                MouseButton::Right,
+                                cx.listener(|this, _: &MouseDownEvent, window, cx| {
+                                    this.toggle_action_panel(window, cx);
+                                }),
+                            )
+                            .child(
+                                List::new(&self.list_state)
+                                    .search_placeholder(placeholder)
+                                    .max_h(list_height)
+                                    .p_1(),
+                            ),
+                    )
+                    .when_some(preview_target, |this, result| {
+                        this.child(PreviewPanelView::new(result).track_scroll(&self.preview_scroll))
+                    }),
+            )
+            // 后台索引状态行
+            .when(!tasks.is_empty(), |this| {
+                this.child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_2()
+                        .px_2()
+                        .text_xs()
+                        .text_color(theme.muted_foreground)
+                        .child(
+                            tasks.iter().map(|task| task.status()).collect::<Vec<_>>().join(" · "),
+                        )
+                        .child(
+                            div()
+                                .cursor_pointer()
+                                .text_color(theme.primary)
+                                .child("取消")
+                                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                                    global_progress().cancel_all();
+                                    cx.stop_propagation();
+                                }),
+                        ),
+                )
+            })
             // 底部状态栏
             .child(
                 div()
@@ -496,111 +1810,59 @@ impl Render for LauncherWindow {
                     .py_1()
                     .text_sm()
                     .text_color(theme.muted_foreground)
-                    .child(format!("{} 个结果", results_count))
-                    .child("↑↓ 选择 · ↵ 执行 · Esc 关闭"),
+                    .child(status)
+                    .child(hints),
             )
-    }
-}
-
-/// 获取结果类型的图标
-fn get_result_icon(result_type: &ResultType) -> IconName {
-    match result_type {
-        ResultType::Application => IconName::AppWindow,
-        ResultType::File => IconName::File,
-        ResultType::Folder => IconName::Folder,
-        ResultType::Command => IconName::Terminal,
-        ResultType::Calculator => IconName::Calculator,
-        ResultType::Clipboard => IconName::Clipboard,
-        ResultType::Settings => IconName::Settings2,
-        ResultType::SystemCommand => IconName::Command,
-        ResultType::Task => IconName::ListTodo,
-        ResultType::Custom(_) => IconName::FileBox,
-    }
-}
+            // 快速查看浮层，点击关闭
+            .when_some(self.quick_look.clone(), |this, result| {
+                this.child(
+                    div()
+                        .absolute()
+                        .inset_0()
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _: &MouseDownEvent, _window, cx| {
+                                this.quick_look = None;
+                                cx.notify();
+                            }),
+                        )
+                        .child(QuickLookView::new(result)),
+                )
+            })
+            // 帧时间浮层（F12 切换）
+            .when(self.show_frame_overlay, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_2()
+                        .right_2()
+                        .px_2()
+                        .py_1()
+                        .rounded_md()
+                        .text_xs()
+                        .bg(theme.secondary.opacity(0.9))
+                        .text_color(theme.muted_foreground)
+                        .child(format!("{} · {} 行", self.frame_stats.summary(), results_count)),
+                )
+            });
 
-/// 解析高亮文本，返回普通文本和高亮文本的片段
-fn parse_highlighted_text(text: &str) -> Vec<(String, bool)> {
-    let mut fragments = Vec::new();
-    let mut current_text = String::new();
-    let mut in_bracket = false;
-
-    for ch in text.chars() {
-        match ch {
-            '[' => {
-                if !current_text.is_empty() {
-                    fragments.push((current_text.clone(), false));
-                    current_text.clear();
-                }
-                in_bracket = true;
-            },
-            ']' => {
-                if !current_text.is_empty() {
-                    fragments.push((current_text.clone(), true));
-                    current_text.clear();
-                }
-                in_bracket = false;
-            },
-            _ => {
-                current_text.push(ch);
-            },
+        // 显示时淡入并从中间展开，隐藏时淡出并收起
+        let container = div().size_full().child(content);
+        if self.animation_duration.is_zero() {
+            return container.into_any_element();
         }
+        let hiding = self.hiding;
+        container
+            .with_animation(
+                ("launcher-transition", self.transition_generation),
+                Animation::new(self.animation_duration).with_easing(ease_out_quint()),
+                move |container, delta| {
+                    let progress = if hiding { 1.0 - delta } else { delta };
+                    container.opacity(progress).p(px((1.0 - progress) * TRANSITION_INSET))
+                },
+            )
+            .into_any_element()
     }
-
-    // 添加剩余的文本
-    if !current_text.is_empty() {
-        fragments.push((current_text, in_bracket));
-    }
-
-    fragments
-}
-
-/// 渲染高亮文本
-///
-/// 样式规则：
-/// - 未选中：匹配字符橙色 + 粗体
-/// - 选中：匹配字符橙色 + 浅蓝边框 + 粗体
-fn render_highlighted_text(
-    text: &str,
-    theme: &gpui_component::Theme,
-    is_selected: bool,
-    is_title: bool,
-) -> impl IntoElement {
-    let fragments = parse_highlighted_text(text);
-
-    // 橙色 - 使用主题中的 warning 颜色（通常是橙色/黄色）
-    let orange_color = theme.warning;
-
-    // 基础颜色
-    let base_color = if is_selected {
-        theme.accent_foreground
-    } else if is_title {
-        theme.foreground
-    } else {
-        theme.muted_foreground
-    };
-
-    div().flex().flex_row().children(fragments.into_iter().map(move |(text, is_highlighted)| {
-        let mut div_element = div()
-            .text_color(if is_highlighted { orange_color } else { base_color })
-            .font_weight(if is_highlighted { FontWeight::BOLD } else { FontWeight::NORMAL });
-
-        if is_highlighted {
-            if is_selected {
-                // 选中状态：橙色 + 浅蓝边框 + 粗体
-                div_element = div_element
-                    .border_1()
-                    .border_color(theme.primary.opacity(0.5))
-                    .rounded_sm()
-                    .px_1()
-                    .py_0();
-            } else {
-                // 未选中状态：橙色 + 粗体（无边框）
-                div_element = div_element.px_1();
-            }
-        }
-
-        div_element.child(text)
-    }))
 }
 
 /// 渲染结果项
@@ -613,20 +1875,10 @@ fn render_result_item(
 
     let text_color = if is_selected { theme.accent_foreground } else { theme.foreground };
 
-    let type_name = match &result.result_type {
-        ResultType::Application => "应用",
-        ResultType::File => "文件",
-        ResultType::Folder => "文件夹",
-        ResultType::Command => "命令",
-        ResultType::Calculator => "计算",
-        ResultType::Clipboard => "剪贴板",
-        ResultType::Settings => "设置",
-        ResultType::SystemCommand => "系统命令",
-        ResultType::Task => "任务",
-        ResultType::Custom(_) => "其他",
-    };
-
-    let icon = get_result_icon(&result.result_type);
+    let type_name = result.result_type.display();
+    let style = ResultTypeStyle::resolve(result, theme, is_selected);
+
+    let icon = result_type_icon(&result.result_type);
 
     div()
         .flex()
@@ -646,8 +1898,12 @@ fn render_result_item(
                 .w_8()
                 .h_8()
                 .rounded_md()
-                .bg(if is_selected { theme.accent_foreground } else { theme.secondary })
-                .child(Icon::new(icon).text_color(text_color)),
+                .bg(style.icon_background)
+                .child(Icon::new(icon).text_color(if is_selected {
+                    text_color
+                } else {
+                    style.accent
+                })),
         )
         .child(
             div()
@@ -656,14 +1912,18 @@ fn render_result_item(
                 .flex_1()
                 .gap_1()
                 .child(div().text_sm().child(render_highlighted_text(
-                    result.display_title(),
+                    &result.title,
+                    result.title_highlights(),
                     theme,
+                    style.accent,
                     is_selected,
                     true, // 是标题
                 )))
                 .child(div().text_xs().child(render_highlighted_text(
-                    result.display_description(),
+                    &result.description,
+                    result.description_highlights(),
                     theme,
+                    style.accent,
                     is_selected,
                     false, // 是描述
                 ))),
@@ -674,13 +1934,20 @@ fn render_result_item(
                 .py_0()
                 .rounded_full()
                 .text_xs()
-                .bg(if is_selected { theme.accent_foreground } else { theme.secondary })
-                .text_color(if is_selected { theme.accent } else { theme.muted_foreground })
+                .border_1()
+                .border_color(style.badge_border)
+                .bg(style.badge_background)
+                .text_color(style.badge_foreground)
                 .child(type_name),
         )
 }
 
 /// 关闭窗口事件
-pub struct DismissEvent;
+pub enum DismissEvent {
+    /// 执行结果后关闭，焦点留给执行打开的窗口
+    Executed,
+    /// 按 Esc 取消，焦点还给启动器显示前的应用
+    Cancelled,
+}
 
 impl EventEmitter<DismissEvent> for LauncherWindow {}
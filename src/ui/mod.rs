@@ -1,9 +1,18 @@
 /// UI 模块
 ///
 /// 提供启动器的所有用户界面组件
+pub mod accessibility;
+pub mod empty_state;
+pub mod frame_stats;
 pub mod launcher_window;
+pub mod plugin_icon;
+pub mod plugins_page;
+pub mod preview_panel;
+pub mod quick_look;
+pub mod result_grid;
 pub mod result_item;
 pub mod result_list;
+pub mod swatch;
 pub mod themes;
 use gpui::{
     actions, div, px, size, Action, AnyView, App, AppContext, Bounds, Context, FocusHandle,
@@ -12,9 +21,10 @@ use gpui::{
     WindowKind, WindowOptions,
 };
 use gpui_component::{notification::Notification, scroll::ScrollbarShow, v_flex, Root, WindowExt};
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
 use serde::Deserialize;
 
-use crate::core::config_manager::global_config;
+use crate::{core::config_manager::global_config, window_manager::global_window_manager};
 
 /// 版本信息
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -188,15 +198,17 @@ struct EnumData {
 /// 切换窗口可见性
 unsafe fn toggle_window_visibility(hwnd: windows::Win32::Foundation::HWND) {
     use windows::Win32::UI::WindowsAndMessaging::{
-        BringWindowToTop, IsWindowVisible, SetForegroundWindow, ShowWindow, SW_HIDE, SW_RESTORE,
+        BringWindowToTop, IsWindowVisible, SetForegroundWindow, ShowWindow, SW_RESTORE,
     };
 
     // 检查窗口是否可见
     if IsWindowVisible(hwnd).as_bool() {
         log::info!("窗口当前可见，执行隐藏");
-        let _ = ShowWindow(hwnd, SW_HIDE);
+        global_window_manager().hide_launcher(hwnd.0 as isize, true);
     } else {
         log::info!("窗口当前隐藏，执行显示");
+        global_window_manager().capture_foreground();
+        global_window_manager().place_window(hwnd.0 as isize);
         // 使用 SW_RESTORE 恢复窗口（比 SW_SHOW 更可靠）
         let _ = ShowWindow(hwnd, SW_RESTORE);
         // 将窗口带到最前面
@@ -251,17 +263,35 @@ fn open_file(path: &str) {
     }
 }
 
+/// 窗口的原生句柄
+pub fn window_hwnd(window: &Window) -> Option<isize> {
+    match HasWindowHandle::window_handle(window).ok()?.as_raw() {
+        RawWindowHandle::Win32(handle) => Some(handle.hwnd.get()),
+        _ => None,
+    }
+}
+
 pub fn create_new_window<F, E>(title: &str, crate_view_fn: F, cx: &mut App)
 where
     E: Into<AnyView>,
     F: FnOnce(&mut Window, &mut App) -> E + Send + 'static,
 {
-    create_new_window_with_size(title, None, crate_view_fn, cx);
+    create_new_window_with_size(title, None, true, crate_view_fn, cx);
+}
+
+/// 创建不显示、不激活的窗口（登录时后台启动），之后由全局快捷键显示
+pub fn create_background_window<F, E>(title: &str, crate_view_fn: F, cx: &mut App)
+where
+    E: Into<AnyView>,
+    F: FnOnce(&mut Window, &mut App) -> E + Send + 'static,
+{
+    create_new_window_with_size(title, None, false, crate_view_fn, cx);
 }
 
 pub fn create_new_window_with_size<F, E>(
     title: &str,
     window_size: Option<Size<Pixels>>,
+    show: bool,
     crate_view_fn: F,
     cx: &mut App,
 ) where
@@ -300,8 +330,8 @@ pub fn create_new_window_with_size<F, E>(
         window_decorations: Some(gpui::WindowDecorations::Client),
         display_id: None,
         window_min_size: Some(size(px(600.0), px(400.0))),
-        focus: true,
-        show: true,
+        focus: show,
+        show,
         is_movable: false,
         app_id: Some("werun".to_string()),
         ..Default::default()
@@ -326,7 +356,9 @@ pub fn create_new_window_with_size<F, E>(
 
         window
             .update(cx, |_, window, _| {
-                window.activate_window();
+                if show {
+                    window.activate_window();
+                }
                 window.set_window_title(&title);
             })
             .expect("failed to update window");
@@ -0,0 +1,54 @@
+/// 插件图标渲染
+///
+/// 将插件声明的图标名称映射到图标库，图片路径则按本地图片加载
+use std::path::PathBuf;
+
+use gpui::{img, prelude::*, AnyElement, Hsla};
+use gpui_component::{Icon, IconName, Sizable};
+
+use crate::core::plugin::PluginIcon;
+
+/// 图标名称对应的内置图标，未知名称使用默认图标
+pub fn icon_name(name: &str) -> IconName {
+    match name {
+        "app-window" => IconName::AppWindow,
+        "calculator" => IconName::Calculator,
+        "calendar" => IconName::Calendar,
+        "clipboard" => IconName::Clipboard,
+        "command" => IconName::Command,
+        "file" => IconName::File,
+        "file-box" => IconName::FileBox,
+        "folder" => IconName::Folder,
+        "globe" => IconName::Globe,
+        "info" => IconName::Info,
+        "layout-dashboard" => IconName::LayoutDashboard,
+        "list-todo" => IconName::ListTodo,
+        "palette" => IconName::Palette,
+        "search" => IconName::Search,
+        "settings" => IconName::Settings,
+        "settings-2" => IconName::Settings2,
+        "square-terminal" => IconName::SquareTerminal,
+        "terminal" => IconName::Terminal,
+        _ => IconName::Box,
+    }
+}
+
+/// 渲染插件图标
+pub fn render_plugin_icon(icon: &PluginIcon, color: Hsla) -> AnyElement {
+    match icon {
+        PluginIcon::Named(name) => {
+            Icon::new(icon_name(name)).small().text_color(color).into_any_element()
+        },
+        PluginIcon::Image(path) => img(PathBuf::from(path)).size_4().into_any_element(),
+    }
+}
+
+/// 渲染大尺寸插件图标（网格布局）
+pub fn render_large_plugin_icon(icon: &PluginIcon, color: Hsla) -> AnyElement {
+    match icon {
+        PluginIcon::Named(name) => {
+            Icon::new(icon_name(name)).large().text_color(color).into_any_element()
+        },
+        PluginIcon::Image(path) => img(PathBuf::from(path)).size_8().into_any_element(),
+    }
+}
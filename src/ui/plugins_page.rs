@@ -0,0 +1,411 @@
+/// 插件页
+///
+/// 列出所有插件（包括已关闭的插件），可启用或关闭插件、编辑插件模式的别名、调整优先级、
+/// 重新加载插件，并按插件提供的设置项生成设置表单；修改立即写入配置文件
+use std::sync::Arc;
+
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    switch::Switch,
+    theme::ActiveTheme,
+    v_flex, Disableable, Sizable,
+};
+
+use crate::{
+    core::{
+        config::PRIORITY_RANGE,
+        config_manager::global_config,
+        plugin::{PluginInfo, PluginManager, SettingField, SettingValue},
+    },
+    ui::plugin_icon::render_plugin_icon,
+};
+
+/// 非开关设置项的输入框
+struct SettingInput {
+    /// 配置键
+    key: &'static str,
+    /// 输入框状态
+    state: Entity<InputState>,
+}
+
+/// 插件页中的一个插件
+struct PluginRow {
+    /// 插件信息
+    info: PluginInfo,
+    /// 别名输入框，多个别名以逗号分隔
+    keywords: Entity<InputState>,
+    /// 优先级
+    priority: i32,
+    /// 非开关设置项的输入框
+    inputs: Vec<SettingInput>,
+}
+
+/// 插件页视图
+pub struct PluginsPage {
+    /// 插件管理器
+    plugin_manager: Arc<PluginManager>,
+    /// 插件列表
+    rows: Vec<PluginRow>,
+    /// 最近一次操作的结果
+    status: Option<SharedString>,
+    /// 输入框事件订阅
+    _subscriptions: Vec<Subscription>,
+}
+
+impl PluginsPage {
+    /// 创建插件页
+    pub fn new(
+        plugin_manager: Arc<PluginManager>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let keywords = global_config().plugin_keywords();
+        let priorities = global_config().plugin_priorities();
+        let mut subscriptions = Vec::new();
+
+        let rows = plugin_manager
+            .plugin_infos()
+            .into_iter()
+            .enumerate()
+            .map(|(index, info)| {
+                let aliases =
+                    keywords.get(&info.id).map(|words| words.join(", ")).unwrap_or_default();
+                let keyword_input = cx.new(|cx| {
+                    InputState::new(window, cx).placeholder("别名，逗号分隔").default_value(aliases)
+                });
+                subscriptions.push(cx.subscribe_in(
+                    &keyword_input,
+                    window,
+                    move |this, _, event: &InputEvent, _, cx| {
+                        if is_commit(event) {
+                            this.save_keywords(index, cx);
+                        }
+                    },
+                ));
+
+                let inputs = info
+                    .settings
+                    .iter()
+                    .filter(|field| !matches!(field.value, SettingValue::Bool(_)))
+                    .map(|field| {
+                        let key = field.key;
+                        let text = field.value.to_text();
+                        let state = cx.new(|cx| InputState::new(window, cx).default_value(text));
+                        subscriptions.push(cx.subscribe_in(
+                            &state,
+                            window,
+                            move |this, _, event: &InputEvent, _, cx| {
+                                if is_commit(event) {
+                                    this.save_text_setting(index, key, cx);
+                                }
+                            },
+                        ));
+                        SettingInput { key, state }
+                    })
+                    .collect();
+
+                let priority = priorities.get(&info.id).copied().unwrap_or(0);
+                PluginRow { info, keywords: keyword_input, priority, inputs }
+            })
+            .collect();
+
+        Self { plugin_manager, rows, status: None, _subscriptions: subscriptions }
+    }
+
+    /// 启用或关闭插件
+    fn set_enabled(&mut self, index: usize, enabled: bool, cx: &mut Context<Self>) {
+        let id = self.rows[index].info.id.clone();
+        match self.plugin_manager.set_plugin_enabled(&id, enabled) {
+            Ok(()) => {
+                self.rows[index].info.enabled = enabled;
+                let action = if enabled { "已启用" } else { "已关闭" };
+                self.set_status(format!("{}: {}", action, self.rows[index].info.name), cx);
+            },
+            Err(e) => self.report_error(e, cx),
+        }
+    }
+
+    /// 保存插件模式的别名
+    fn save_keywords(&mut self, index: usize, cx: &mut Context<Self>) {
+        let row = &self.rows[index];
+        let (id, name) = (row.info.id.clone(), row.info.name.clone());
+        let text = row.keywords.read(cx).value().to_string();
+        let words = text
+            .split([',', '，', ' '])
+            .map(str::trim)
+            .filter(|word| !word.is_empty())
+            .map(str::to_string)
+            .collect();
+        match global_config().set_plugin_keywords(&id, words) {
+            Ok(()) => self.set_status(format!("已保存别名: {}", name), cx),
+            Err(e) => self.report_error(e, cx),
+        }
+    }
+
+    /// 调整优先级
+    fn change_priority(&mut self, index: usize, delta: i32, cx: &mut Context<Self>) {
+        let priority = (self.rows[index].priority + delta)
+            .clamp(*PRIORITY_RANGE.start(), *PRIORITY_RANGE.end());
+        let id = self.rows[index].info.id.clone();
+        match global_config().set_plugin_priority(&id, priority) {
+            Ok(()) => {
+                self.rows[index].priority = priority;
+                cx.notify();
+            },
+            Err(e) => self.report_error(e, cx),
+        }
+    }
+
+    /// 重新加载插件，刷新插件数据和设置项
+    fn reload(&mut self, index: usize, cx: &mut Context<Self>) {
+        let id = self.rows[index].info.id.clone();
+        match self.plugin_manager.reload_plugin(&id) {
+            Ok(()) => {
+                self.refresh_settings(index);
+                self.set_status(format!("已重新加载: {}", self.rows[index].info.name), cx);
+            },
+            Err(e) => self.report_error(e, cx),
+        }
+    }
+
+    /// 保存开关设置项
+    fn save_bool_setting(
+        &mut self,
+        index: usize,
+        key: &'static str,
+        value: bool,
+        cx: &mut Context<Self>,
+    ) {
+        self.save_setting(index, key, SettingValue::Bool(value), cx);
+    }
+
+    /// 按设置项的类型解析输入框的文本并保存，无法解析时只提示
+    fn save_text_setting(&mut self, index: usize, key: &'static str, cx: &mut Context<Self>) {
+        let row = &self.rows[index];
+        let Some(field) = row.info.settings.iter().find(|field| field.key == key) else {
+            return;
+        };
+        let Some(input) = row.inputs.iter().find(|input| input.key == key) else {
+            return;
+        };
+        let text = input.state.read(cx).value().to_string();
+        if text == field.value.to_text() {
+            return;
+        }
+        let label = field.label;
+        match field.value.parse_text(&text) {
+            Some(value) => self.save_setting(index, key, value, cx),
+            None => self.set_status(format!("{} 的值无效: {}", label, text), cx),
+        }
+    }
+
+    /// 写入插件设置并重新加载插件，使设置立即生效
+    fn save_setting(
+        &mut self,
+        index: usize,
+        key: &'static str,
+        value: SettingValue,
+        cx: &mut Context<Self>,
+    ) {
+        let id = self.rows[index].info.id.clone();
+        let saved = global_config()
+            .set_plugin_setting(&id, key, value.to_json())
+            .and_then(|()| self.plugin_manager.reload_plugin(&id));
+        match saved {
+            Ok(()) => {
+                self.refresh_settings(index);
+                self.set_status(format!("已保存设置: {}", self.rows[index].info.name), cx);
+            },
+            Err(e) => self.report_error(e, cx),
+        }
+    }
+
+    /// 重新读取插件的设置项（保存后插件可能调整了超出范围的值）
+    fn refresh_settings(&mut self, index: usize) {
+        let row = &mut self.rows[index];
+        if let Some(info) = self.plugin_manager.plugin_info(&row.info.id) {
+            row.info.settings = info.settings;
+        }
+    }
+
+    /// 显示操作结果
+    fn set_status(&mut self, status: String, cx: &mut Context<Self>) {
+        self.status = Some(status.into());
+        cx.notify();
+    }
+
+    /// 记录错误并显示给用户
+    fn report_error(&mut self, error: crate::core::error::WerunError, cx: &mut Context<Self>) {
+        error.log();
+        self.set_status(error.user_message(), cx);
+    }
+
+    /// 渲染一个设置项：开关显示为开关，其余显示为输入框（Enter 或失去焦点时保存）
+    fn render_setting(
+        &self,
+        index: usize,
+        field: &SettingField,
+        cx: &Context<Self>,
+    ) -> impl IntoElement {
+        let theme = cx.theme();
+        let key = field.key;
+        let control = match &field.value {
+            SettingValue::Bool(checked) => {
+                Switch::new(ElementId::Name(format!("setting-{}-{}", index, key).into()))
+                    .checked(*checked)
+                    .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                        this.save_bool_setting(index, key, *checked, cx);
+                    }))
+                    .into_any_element()
+            },
+            _ => match self.rows[index].inputs.iter().find(|input| input.key == key) {
+                Some(input) => Input::new(&input.state).small().into_any_element(),
+                None => div().into_any_element(),
+            },
+        };
+
+        h_flex()
+            .gap_3()
+            .items_center()
+            .child(
+                div()
+                    .w(px(200.0))
+                    .flex_shrink_0()
+                    .text_sm()
+                    .text_color(theme.muted_foreground)
+                    .child(field.label),
+            )
+            .child(div().flex_1().child(control))
+    }
+
+    /// 渲染一个插件
+    fn render_row(&self, index: usize, cx: &Context<Self>) -> impl IntoElement {
+        let row = &self.rows[index];
+        let info = &row.info;
+        let theme = cx.theme();
+        let muted = theme.muted_foreground;
+
+        v_flex()
+            .gap_2()
+            .px_4()
+            .py_3()
+            .border_b_1()
+            .border_color(theme.border)
+            .when(!info.enabled, |this| this.opacity(0.6))
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(render_plugin_icon(&info.icon, theme.foreground))
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .min_w_0()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .font_weight(FontWeight::MEDIUM)
+                                            .child(info.name.clone()),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(muted)
+                                            .child(format!("{} · v{}", info.id, info.version)),
+                                    ),
+                            )
+                            .child(
+                                div().text_sm().text_color(muted).child(info.description.clone()),
+                            ),
+                    )
+                    .child(Switch::new(("plugin-enabled", index)).checked(info.enabled).on_click(
+                        cx.listener(move |this, checked: &bool, _, cx| {
+                            this.set_enabled(index, *checked, cx);
+                        }),
+                    )),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(div().text_sm().text_color(muted).child("别名"))
+                    .child(div().flex_1().child(Input::new(&row.keywords).small()))
+                    .child(div().text_sm().text_color(muted).child("优先级"))
+                    .child(
+                        Button::new(("priority-down", index))
+                            .label("-")
+                            .small()
+                            .ghost()
+                            .disabled(row.priority <= *PRIORITY_RANGE.start())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.change_priority(index, -1, cx);
+                            })),
+                    )
+                    .child(div().w(px(24.0)).text_center().child(format!("{:+}", row.priority)))
+                    .child(
+                        Button::new(("priority-up", index))
+                            .label("+")
+                            .small()
+                            .ghost()
+                            .disabled(row.priority >= *PRIORITY_RANGE.end())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.change_priority(index, 1, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new(("plugin-reload", index))
+                            .label("重新加载")
+                            .small()
+                            .ghost()
+                            .on_click(cx.listener(move |this, _, _, cx| this.reload(index, cx))),
+                    ),
+            )
+            .children(info.settings.iter().map(|field| self.render_setting(index, field, cx)))
+    }
+}
+
+/// 输入框的修改是否应当保存：按 Enter 或失去焦点
+fn is_commit(event: &InputEvent) -> bool {
+    matches!(event, InputEvent::PressEnter { .. } | InputEvent::Blur)
+}
+
+impl Render for PluginsPage {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let rows: Vec<AnyElement> = (0..self.rows.len())
+            .map(|index| self.render_row(index, cx).into_any_element())
+            .collect();
+        let theme = cx.theme();
+
+        v_flex()
+            .size_full()
+            .bg(theme.background)
+            .text_color(theme.foreground)
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .border_b_1()
+                    .border_color(theme.border)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(format!("插件（{}）", self.rows.len())),
+            )
+            .child(div().id("plugins-page-rows").flex_1().overflow_y_scroll().children(rows))
+            .when_some(self.status.clone(), |this, status| {
+                this.child(
+                    div()
+                        .px_4()
+                        .py_2()
+                        .border_t_1()
+                        .border_color(theme.border)
+                        .text_sm()
+                        .text_color(theme.muted_foreground)
+                        .child(status),
+                )
+            })
+    }
+}
@@ -1,45 +1,124 @@
 /// 预览面板组件
 ///
 /// 显示选中结果的详细信息
-use gpui::*;
-use gpui_component::prelude::*;
-use gpui_component::theme::ActiveTheme;
-use gpui_component::IconName;
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::{theme::ActiveTheme, IconName, Sizable};
 
-use crate::core::search::{ResultType, SearchResult};
+use crate::{
+    core::{
+        preview::{PreviewBody, PreviewSection, TokenKind},
+        search::SearchResult,
+    },
+    ui::{result_list::result_type_icon, swatch::render_visual, themes::ResultTypeStyle},
+};
+
+/// 预览面板最大高度，与结果列表一致，超出时滚动
+pub const PREVIEW_MAX_HEIGHT: f32 = 400.0;
+
+/// 图片缩略图的最大高度
+const THUMBNAIL_MAX_HEIGHT: f32 = 160.0;
 
 /// 预览面板视图
+#[derive(IntoElement)]
 pub struct PreviewPanelView {
     /// 当前选中的结果
     result: Option<SearchResult>,
+    /// 内容滚动句柄，由窗口持有以便用键盘滚动
+    scroll_handle: Option<ScrollHandle>,
 }
 
 impl PreviewPanelView {
     /// 创建新的预览面板
     pub fn new(result: Option<SearchResult>) -> Self {
-        Self { result }
+        Self { result, scroll_handle: None }
+    }
+
+    /// 使用外部的滚动句柄
+    pub fn track_scroll(mut self, handle: &ScrollHandle) -> Self {
+        self.scroll_handle = Some(handle.clone());
+        self
+    }
+
+    /// 语法着色类别的颜色
+    fn token_color(kind: TokenKind, theme: &gpui_component::Theme) -> Hsla {
+        match kind {
+            TokenKind::Plain => theme.foreground,
+            TokenKind::Keyword => theme.blue,
+            TokenKind::String => theme.green,
+            TokenKind::Number => theme.yellow,
+            TokenKind::Comment => theme.muted_foreground,
+        }
     }
 
-    /// 获取结果类型的图标
-    fn get_result_icon(result_type: &ResultType) -> IconName {
-        match result_type {
-            ResultType::Application => IconName::AppWindow,
-            ResultType::File => IconName::File,
-            ResultType::Folder => IconName::Folder,
-            ResultType::Command => IconName::Terminal,
-            ResultType::Calculator => IconName::Calculator,
-            ResultType::Clipboard => IconName::Clipboard,
-            ResultType::Settings => IconName::Settings,
-            ResultType::Custom(_) => IconName::Box,
+    /// 渲染正文：等宽字体的着色文本行，或按比例缩放的图片缩略图
+    pub fn render_body(body: PreviewBody, cx: &App) -> AnyElement {
+        let theme = cx.theme();
+
+        match body {
+            PreviewBody::Code(lines) => div()
+                .flex()
+                .flex_col()
+                .p_2()
+                .rounded_md()
+                .bg(theme.background)
+                .overflow_hidden()
+                .font_family("Consolas")
+                .text_xs()
+                .children(lines.into_iter().map(|line| {
+                    div().flex().flex_row().min_h(px(16.0)).whitespace_nowrap().children(
+                        line.into_iter().map(|span| {
+                            div().text_color(Self::token_color(span.kind, theme)).child(span.text)
+                        }),
+                    )
+                }))
+                .into_any_element(),
+            PreviewBody::Image(path) => img(path)
+                .w_full()
+                .max_h(px(THUMBNAIL_MAX_HEIGHT))
+                .object_fit(ObjectFit::Contain)
+                .rounded_md()
+                .into_any_element(),
         }
     }
+
+    /// 渲染预览分组，行按层级缩进
+    pub fn render_section(section: PreviewSection, cx: &App) -> impl IntoElement {
+        let theme = cx.theme();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme.foreground)
+                    .child(section.title),
+            )
+            .children(section.rows.into_iter().map(|row| {
+                div()
+                    .flex()
+                    .flex_row()
+                    .justify_between()
+                    .gap_2()
+                    .pl(px(row.depth as f32 * 12.0))
+                    .text_sm()
+                    .child(div().text_color(theme.muted_foreground).child(row.label))
+                    .child(div().text_color(theme.foreground).child(row.value))
+            }))
+    }
 }
 
 impl RenderOnce for PreviewPanelView {
-    fn render(self, _window: &mut Window, cx: &mut AppContext) -> impl IntoElement {
-        let theme = cx.theme();
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme().clone();
 
         let content = if let Some(result) = self.result {
+            let style = ResultTypeStyle::resolve(&result, &theme, false);
+            let preview = result.preview.clone().unwrap_or_default();
+            let visual = preview.visual;
+
             div()
                 .flex()
                 .flex_col()
@@ -59,13 +138,13 @@ impl RenderOnce for PreviewPanelView {
                                 .w_12()
                                 .h_12()
                                 .rounded_lg()
-                                .bg(theme.secondary)
+                                .bg(style.icon_background)
                                 .child(
-                                    gpui_component::Icon::new(Self::get_result_icon(
+                                    gpui_component::Icon::new(result_type_icon(
                                         &result.result_type,
                                     ))
                                     .large()
-                                    .text_color(theme.foreground),
+                                    .text_color(style.accent),
                                 ),
                         )
                         .child(
@@ -90,6 +169,16 @@ impl RenderOnce for PreviewPanelView {
                 )
                 // 分隔线
                 .child(div().h_px().w_full().bg(theme.border))
+                // 插件提供的可视内容
+                .when_some(visual, |this, visual| {
+                    this.child(render_visual(&visual, theme.border).w_full().h_24())
+                })
+                // 正文，如文件内容或图片
+                .when_some(preview.body, |this, body| this.child(Self::render_body(body, cx)))
+                // 插件提供的预览内容
+                .children(
+                    preview.sections.into_iter().map(|section| Self::render_section(section, cx)),
+                )
                 // 详情信息
                 .child(
                     div()
@@ -111,9 +200,24 @@ impl RenderOnce for PreviewPanelView {
                         )
                         .child(
                             div()
+                                .flex()
+                                .flex_row()
+                                .items_center()
+                                .gap_2()
                                 .text_sm()
                                 .text_color(theme.muted_foreground)
-                                .child(format!("类型: {:?}", result.result_type)),
+                                .child("类型:")
+                                .child(
+                                    div()
+                                        .px_2()
+                                        .rounded_full()
+                                        .text_xs()
+                                        .border_1()
+                                        .border_color(style.badge_border)
+                                        .bg(style.badge_background)
+                                        .text_color(style.badge_foreground)
+                                        .child(result.result_type.display()),
+                                ),
                         ),
                 )
         } else {
@@ -138,8 +242,11 @@ impl RenderOnce for PreviewPanelView {
         };
 
         div()
+            .id("preview-panel")
             .w(px(280.0))
-            .h_full()
+            .max_h(px(PREVIEW_MAX_HEIGHT))
+            .overflow_y_scroll()
+            .when_some(self.scroll_handle, |this, handle| this.track_scroll(&handle))
             .p_4()
             .bg(theme.secondary)
             .border_l_1()
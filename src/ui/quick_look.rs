@@ -0,0 +1,118 @@
+/// 快速查看浮层
+///
+/// 将选中结果展开为覆盖整个窗口的大尺寸预览：完整的标题和描述、动作携带的完整内容
+/// （剪贴板文本、文件路径、命令等）以及插件提供的预览分节；只查看，不执行结果
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::theme::ActiveTheme;
+
+use crate::{core::search::SearchResult, ui::preview_panel::PreviewPanelView};
+
+/// 完整内容最多显示的行数，超出部分省略
+const MAX_PAYLOAD_LINES: usize = 500;
+
+/// 快速查看浮层视图
+#[derive(IntoElement)]
+pub struct QuickLookView {
+    /// 查看的结果
+    result: SearchResult,
+}
+
+impl QuickLookView {
+    /// 创建快速查看浮层
+    pub fn new(result: SearchResult) -> Self {
+        Self { result }
+    }
+
+    /// 渲染完整内容：等宽字体，保留换行
+    fn render_payload(label: &str, text: &str, cx: &App) -> impl IntoElement {
+        let theme = cx.theme();
+        let total = text.lines().count();
+        let lines = text.lines().take(MAX_PAYLOAD_LINES).map(|line| line.to_string());
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme.foreground)
+                    .child(label.to_string()),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .p_2()
+                    .rounded_md()
+                    .bg(theme.secondary)
+                    .font_family("Consolas")
+                    .text_sm()
+                    .text_color(theme.foreground)
+                    .children(lines.map(|line| div().min_h(px(18.0)).child(line)))
+                    .when(total > MAX_PAYLOAD_LINES, |this| {
+                        this.child(
+                            div()
+                                .text_color(theme.muted_foreground)
+                                .child(format!("… 还有 {} 行", total - MAX_PAYLOAD_LINES)),
+                        )
+                    }),
+            )
+    }
+}
+
+impl RenderOnce for QuickLookView {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let result = self.result;
+        let preview = result.preview.clone().unwrap_or_default();
+        let payload = result.action.payload().map(|(label, text)| (label, text.to_string()));
+
+        div()
+            .id("quick-look")
+            .size_full()
+            .flex()
+            .flex_col()
+            .gap_4()
+            .p_6()
+            .rounded_xl()
+            .border_1()
+            .border_color(theme.border)
+            .bg(theme.background)
+            .overflow_y_scroll()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_xl()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme.foreground)
+                            .child(result.title),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(theme.muted_foreground)
+                            .child(result.description),
+                    ),
+            )
+            .child(div().h_px().w_full().bg(theme.border))
+            .when_some(payload, |this, (label, text)| {
+                this.child(Self::render_payload(label, &text, cx))
+            })
+            .when_some(preview.body, |this, body| {
+                this.child(PreviewPanelView::render_body(body, cx))
+            })
+            .children(
+                preview
+                    .sections
+                    .into_iter()
+                    .map(|section| PreviewPanelView::render_section(section, cx)),
+            )
+            .child(div().text_xs().text_color(theme.muted_foreground).child("Space 或 Esc 关闭"))
+    }
+}
@@ -0,0 +1,138 @@
+/// 结果网格
+///
+/// 网格布局下列表的每一行包含若干个以大图标为主的单元格，选中项仍为结果的索引，
+/// 方向键在行列间移动
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::{Icon, Sizable, Theme};
+
+use crate::{
+    core::{plugin::PluginIcon, search::SearchResult},
+    ui::{
+        plugin_icon::render_large_plugin_icon, result_list::result_type_icon,
+        swatch::render_visual, themes::ResultTypeStyle,
+    },
+};
+
+/// 每行的单元格数
+pub const GRID_COLUMNS: usize = 6;
+
+/// 网格行高度
+pub const GRID_ROW_HEIGHT: f32 = 96.0;
+
+/// 网格中的移动方向
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridMove {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// 按方向移动后的选中索引，没有选中项时选中第一个结果
+///
+/// 左右越过两端时回绕；上下越过首末行时回到另一端的同一列，该列不存在时停在最后一个结果
+pub fn move_selection(current: Option<usize>, count: usize, direction: GridMove) -> usize {
+    let Some(current) = current.filter(|&index| index < count) else {
+        return 0;
+    };
+    let last = count - 1;
+    let column = current % GRID_COLUMNS;
+    let last_row_start = last / GRID_COLUMNS * GRID_COLUMNS;
+
+    match direction {
+        GridMove::Left if current == 0 => last,
+        GridMove::Left => current - 1,
+        GridMove::Right if current == last => 0,
+        GridMove::Right => current + 1,
+        GridMove::Up if current >= GRID_COLUMNS => current - GRID_COLUMNS,
+        GridMove::Up => (last_row_start + column).min(last),
+        GridMove::Down if current + GRID_COLUMNS <= last => current + GRID_COLUMNS,
+        // 下一行不满且没有同一列时移到最后一个结果
+        GridMove::Down if current < last_row_start => last,
+        GridMove::Down => column,
+    }
+}
+
+/// 渲染网格单元格：大图标（或颜色色块）在上，标题在下；
+/// 左上角显示 Alt+数字序号提示，已标记的结果在右上角显示 ✓
+pub fn render_grid_cell(
+    item: &SearchResult,
+    is_selected: bool,
+    is_marked: bool,
+    index_hint: Option<usize>,
+    theme: &Theme,
+) -> Div {
+    let style = ResultTypeStyle::resolve(item, theme, is_selected);
+    let bg_color = if is_selected { theme.accent } else { theme.background };
+    let hover_color = theme.secondary;
+    let text_color = if is_selected { theme.accent_foreground } else { theme.foreground };
+
+    let icon_color = if is_selected { text_color } else { style.accent };
+    let icon = match item.icon.as_deref().map(PluginIcon::parse) {
+        Some(icon) => render_large_plugin_icon(&icon, icon_color),
+        None => Icon::new(result_type_icon(&item.result_type))
+            .large()
+            .text_color(icon_color)
+            .into_any_element(),
+    };
+    // 预览带有可视内容（如颜色色块）时代替图标显示
+    let visual = item.preview.as_ref().and_then(|preview| preview.visual);
+
+    div()
+        .relative()
+        .flex_1()
+        .min_w_0()
+        .flex()
+        .flex_col()
+        .items_center()
+        .justify_center()
+        .gap_1()
+        .p_2()
+        .rounded_md()
+        .bg(bg_color)
+        .when(!is_selected, |this| this.hover(|this| this.bg(hover_color)))
+        .cursor_pointer()
+        .child(match visual {
+            Some(visual) => render_visual(&visual, theme.border).size_12(),
+            None => div()
+                .flex()
+                .items_center()
+                .justify_center()
+                .size_12()
+                .rounded_md()
+                .bg(style.icon_background)
+                .child(icon),
+        })
+        .child(
+            div()
+                .w_full()
+                .text_xs()
+                .text_center()
+                .truncate()
+                .text_color(text_color)
+                .child(item.title.clone()),
+        )
+        .when_some(index_hint, |this, number| {
+            let hint_color = if is_selected { text_color } else { theme.muted_foreground };
+            this.child(
+                div()
+                    .absolute()
+                    .top_1()
+                    .left_2()
+                    .text_xs()
+                    .text_color(hint_color)
+                    .child(format!("Alt+{}", number)),
+            )
+        })
+        .when(is_marked, |this| {
+            this.child(
+                div()
+                    .absolute()
+                    .top_1()
+                    .right_2()
+                    .text_sm()
+                    .text_color(if is_selected { text_color } else { theme.primary })
+                    .child("✓"),
+            )
+        })
+}
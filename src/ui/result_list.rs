@@ -1,23 +1,106 @@
-use std::sync::Arc;
+use std::{collections::HashMap, ops::Range, sync::Arc};
 
-use gpui::*;
+use gpui::{prelude::FluentBuilder, *};
 use gpui_component::{
-    list::{ListDelegate, ListItem, ListState},
+    list::{ListDelegate, ListEvent, ListItem, ListState},
     theme::ActiveTheme,
     IconName, IndexPath, Sizable,
 };
 
-use crate::core::{
-    plugin::PluginManager,
-    search::{ResultType, SearchResult},
+use crate::{
+    core::{
+        config::{Density, ResultLayout, ResultLayoutConfig},
+        config_manager::global_config,
+        notes::{global_notes, MAX_NOTE_CHARS},
+        plugin::{PluginIcon, PluginManager},
+        query::{self, ParsedQuery},
+        search::{ActionData, ResultType, SearchResult, SortOrder},
+    },
+    ui::{
+        empty_state::render_empty_state,
+        plugin_icon::render_plugin_icon,
+        result_grid::{render_grid_cell, GRID_COLUMNS, GRID_ROW_HEIGHT},
+        swatch::render_visual,
+        themes::ResultTypeStyle,
+    },
+    utils::fuzzy::split_highlights,
+    window_manager::global_window_manager,
 };
 
+/// 查询为空时提供默认结果的插件
+pub const HOME_PLUGIN_ID: &str = "app_launcher";
+
+/// 查询为空时最多显示的上下文结果数
+const CONTEXTUAL_RESULT_LIMIT: usize = 5;
+
+/// Alt+数字可直接执行的结果数
+pub const MAX_INDEX_HINTS: usize = 9;
+
+/// 查询为空时的结果：与启动器显示前的前台应用相关的结果在前，之后是固定和常用的应用
+pub fn home_results(manager: &PluginManager) -> Vec<SearchResult> {
+    let mut results = global_window_manager()
+        .foreground()
+        .map(|foreground| manager.contextual_results(&foreground, CONTEXTUAL_RESULT_LIMIT))
+        .unwrap_or_default();
+    results.extend(manager.default_results(HOME_PLUGIN_ID, 50));
+    results
+}
+
+/// 备注相关结果项的 ID 前缀，由启动器窗口处理而不是交给插件
+pub const NOTE_ID_PREFIX: &str = "__note__:";
+
+/// 动作面板中的"添加备注"项
+pub fn note_action(result: &SearchResult) -> SearchResult {
+    let title = if result.note.is_some() { "编辑备注" } else { "添加备注" };
+    SearchResult::new(
+        format!("{}edit", NOTE_ID_PREFIX),
+        title.to_string(),
+        result.title.clone(),
+        ResultType::Custom("note".to_string()),
+        result.score,
+        ActionData::Custom { plugin: "notes".to_string(), data: String::new() },
+    )
+}
+
+/// 编辑备注时的结果项：输入框中的内容即备注，清空后保存为删除备注
+fn note_editor_results(target: &SearchResult, text: &str) -> Vec<SearchResult> {
+    let text = text.trim();
+    let title = match (text.is_empty(), target.note.is_some()) {
+        (true, true) => "删除备注".to_string(),
+        (true, false) => "输入备注内容".to_string(),
+        (false, _) => {
+            format!("保存备注：{}", text.chars().take(MAX_NOTE_CHARS).collect::<String>())
+        },
+    };
+
+    vec![SearchResult::new(
+        format!("{}save", NOTE_ID_PREFIX),
+        title,
+        format!("为「{}」添加备注，按 Enter 保存，Esc 取消", target.title),
+        ResultType::Custom("note".to_string()),
+        1000,
+        ActionData::Custom { plugin: "notes".to_string(), data: text.to_string() },
+    )]
+}
+
 pub struct ResultListDelegate {
     pub items: Vec<SearchResult>,
     pub selected_index: Option<usize>,
     search_query: String,
     plugin_manager: Option<Arc<PluginManager>>,
     active_plugin_id: Option<String>,
+    /// 各视图的排序方式（键为激活的插件 ID，全局视图为空字符串）
+    sort_orders: HashMap<String, SortOrder>,
+    /// 正在编辑备注的结果，编辑期间输入框内容作为备注
+    editing_note: Option<SearchResult>,
+    /// 结果布局配置
+    layout_config: ResultLayoutConfig,
+    /// 当前结果使用的布局
+    layout: ResultLayout,
+    /// 标记的结果（按标记顺序），更换查询后仍然保留，用于批量执行
+    marked: Vec<SearchResult>,
+    /// 可见区域的第一行，由列表的滚动位置得出，用于 Alt+数字定位结果和序号提示
+    first_visible_row: usize,
 }
 
 impl ResultListDelegate {
@@ -28,6 +111,12 @@ impl ResultListDelegate {
             search_query: String::new(),
             plugin_manager: None,
             active_plugin_id: None,
+            sort_orders: HashMap::new(),
+            editing_note: None,
+            layout_config: ResultLayoutConfig::default(),
+            layout: ResultLayout::List,
+            marked: Vec::new(),
+            first_visible_row: 0,
         }
     }
 
@@ -36,9 +125,16 @@ impl ResultListDelegate {
         self
     }
 
+    pub fn with_layout_config(mut self, config: ResultLayoutConfig) -> Self {
+        self.layout_config = config;
+        self.update_layout();
+        self
+    }
+
     pub fn set_items(&mut self, items: Vec<SearchResult>) {
         self.items = items;
         self.selected_index = None;
+        self.update_layout();
     }
 
     pub fn items_count(&self) -> usize {
@@ -49,50 +145,215 @@ impl ResultListDelegate {
         self.items.get(index)
     }
 
-    pub fn update_from_search(&mut self, results: Vec<SearchResult>) {
+    pub fn update_from_search(&mut self, mut results: Vec<SearchResult>) {
+        self.sort_order().apply(&mut results);
+        self.patch_items(results);
+    }
+
+    /// 开始或结束编辑备注，并按输入框的新内容刷新结果
+    pub fn set_editing_note(&mut self, target: Option<SearchResult>, query: &str) {
+        self.editing_note = target;
+        self.search_query = query.to_string();
+        self.perform_search_internal(query);
+    }
+
+    /// 正在编辑备注的结果
+    pub fn editing_note(&self) -> Option<&SearchResult> {
+        self.editing_note.as_ref()
+    }
+
+    /// 标记或取消标记指定索引的结果，返回标记后的数量
+    pub fn toggle_marked(&mut self, index: usize) -> usize {
+        if let Some(item) = self.items.get(index) {
+            match self.marked.iter().position(|marked| marked.id == item.id) {
+                Some(position) => {
+                    self.marked.remove(position);
+                },
+                None => self.marked.push(item.clone()),
+            }
+        }
+        self.marked.len()
+    }
+
+    /// 标记的结果
+    pub fn marked(&self) -> &[SearchResult] {
+        &self.marked
+    }
+
+    /// 清除所有标记
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+    }
+
+    /// 结果是否已标记
+    fn is_marked(&self, item: &SearchResult) -> bool {
+        self.marked.iter().any(|marked| marked.id == item.id)
+    }
+
+    /// 更新结果，之前选中的条目（按 ID 比较）仍然存在时保持选中，
+    /// 避免边输入边搜索时选中项跳动
+    fn patch_items(&mut self, mut results: Vec<SearchResult>) {
+        global_notes().annotate(&mut results);
+
+        let selected_id =
+            self.selected_index.and_then(|ix| self.items.get(ix)).map(|item| item.id.clone());
         self.items = results;
-        self.selected_index = None;
+
+        self.selected_index =
+            selected_id.and_then(|id| self.items.iter().position(|item| item.id == id));
+        self.update_layout();
+    }
+
+    /// 按激活的插件和结果来源选择布局
+    fn update_layout(&mut self) {
+        let plugin_ids = self.items.iter().map(SearchResult::plugin_id);
+        self.layout = self.layout_config.layout(self.active_plugin_id.as_deref(), plugin_ids);
+    }
+
+    /// 当前结果使用的布局
+    pub fn layout(&self) -> ResultLayout {
+        self.layout
+    }
+
+    /// 列表行对应的结果索引
+    ///
+    /// 网格布局下一行包含多个结果：选中项在该行时保持不变，否则取该行中与选中项同一列的结果
+    pub fn item_index(&self, ix: IndexPath) -> usize {
+        if self.layout == ResultLayout::List {
+            return ix.row;
+        }
+        match self.selected_index {
+            Some(selected) if selected / GRID_COLUMNS == ix.row => selected,
+            selected => {
+                let column = selected.map_or(0, |selected| selected % GRID_COLUMNS);
+                (ix.row * GRID_COLUMNS + column).min(self.items.len().saturating_sub(1))
+            },
+        }
+    }
+
+    /// 结果所在的列表行
+    pub fn list_row(&self, index: usize) -> usize {
+        match self.layout {
+            ResultLayout::List => index,
+            ResultLayout::Grid => index / GRID_COLUMNS,
+        }
+    }
+
+    /// 选中结果，返回其所在的列表行
+    pub fn select_item(&mut self, index: usize) -> usize {
+        self.selected_index = Some(index);
+        self.list_row(index)
+    }
+
+    /// 列表区域同时显示的行数
+    pub fn visible_rows(&self) -> usize {
+        let density = global_config().density();
+        match self.layout {
+            ResultLayout::List => density.visible_results(),
+            ResultLayout::Grid => {
+                let height = density.row_height() * density.visible_results() as f32;
+                ((height / GRID_ROW_HEIGHT) as usize).max(1)
+            },
+        }
+    }
+
+    /// 每行的结果数
+    fn columns(&self) -> usize {
+        match self.layout {
+            ResultLayout::List => 1,
+            ResultLayout::Grid => GRID_COLUMNS,
+        }
+    }
+
+    /// 按列表的滚动距离（像素）更新可见区域的第一行，露出不到一半的行不计入
+    pub fn set_scroll_offset(&mut self, offset: f32) {
+        let row_height = match self.layout {
+            ResultLayout::List => global_config().density().row_height(),
+            ResultLayout::Grid => GRID_ROW_HEIGHT,
+        };
+        self.first_visible_row = (offset.max(0.0) / row_height).round() as usize;
+    }
+
+    /// 按列表行移动选中项后的结果索引，越过首尾时停在首尾
+    pub fn offset_rows(&self, rows: isize) -> usize {
+        let last = self.items.len().saturating_sub(1);
+        let current = self.selected_index.unwrap_or(0) as isize;
+        let target = current + rows * self.columns() as isize;
+        target.clamp(0, last as isize) as usize
+    }
+
+    /// 第 `number` 个（从 1 开始）可见结果的索引
+    pub fn visible_item(&self, number: usize) -> Option<usize> {
+        if !(1..=MAX_INDEX_HINTS).contains(&number) {
+            return None;
+        }
+        let index = self.first_visible_row * self.columns() + number - 1;
+        (index < self.items.len()).then_some(index)
+    }
+
+    /// 结果在可见结果中的序号（从 1 开始），超出数字快捷键范围时为 `None`
+    pub fn visible_number(&self, index: usize) -> Option<usize> {
+        let first = self.first_visible_row * self.columns();
+        index.checked_sub(first).map(|offset| offset + 1).filter(|&n| n <= MAX_INDEX_HINTS)
+    }
+
+    /// 当前视图的排序方式
+    pub fn sort_order(&self) -> SortOrder {
+        let view_key = self.active_plugin_id.as_deref().unwrap_or_default();
+        self.sort_orders.get(view_key).copied().unwrap_or_default()
+    }
+
+    /// 循环切换当前视图的排序方式，并对现有结果重新排序
+    pub fn cycle_sort_order(&mut self) -> SortOrder {
+        let order = self.sort_order().next();
+        let view_key = self.active_plugin_id.clone().unwrap_or_default();
+        self.sort_orders.insert(view_key, order);
+
+        order.apply(&mut self.items);
+        self.selected_index = if self.items.is_empty() { None } else { Some(0) };
+        order
     }
 
     pub fn set_active_plugin(&mut self, plugin_id: Option<String>) {
         self.active_plugin_id = plugin_id;
     }
 
+    /// 按当前查询重新搜索，保留选中项（后台任务更新结果后调用）
+    pub fn rerun_search(&mut self) {
+        let query = self.search_query.clone();
+        self.perform_search_internal(&query);
+    }
+
     fn perform_search_internal(&mut self, query: &str) {
         if let Some(manager) = &self.plugin_manager {
             let manager = manager.clone();
 
-            let results = if let Some(ref plugin_id) = self.active_plugin_id {
-                if query.is_empty() {
-                    Vec::new()
+            let results = if let Some(target) = &self.editing_note {
+                note_editor_results(target, query)
+            } else if let Some(ref plugin_id) = self.active_plugin_id {
+                // 去掉 / 前缀，查询为空时显示插件的默认结果
+                let search_query = query.trim_start_matches('/').trim();
+                if search_query.is_empty() {
+                    manager.default_results(plugin_id, 50)
                 } else {
-                    // 去掉 / 前缀
-                    let search_query = query.trim_start_matches('/').trim();
-                    if search_query.is_empty() {
-                        Vec::new()
-                    } else {
-                        manager.search_plugin(plugin_id, search_query, 50)
-                    }
+                    manager.search_plugin(plugin_id, search_query, 50)
                 }
+            } else if query.trim().is_empty() {
+                // 查询为空时显示上下文结果和固定、常用的应用
+                home_results(&manager)
             } else if query.starts_with('/') {
                 Self::handle_plugin_command_static(&manager, query)
+            } else if ParsedQuery::parse(query).has_file_filters() {
+                // 扩展名、目录过滤标记只对文件搜索有意义
+                manager.search_plugin("file_search", query, 50)
             } else {
                 manager.search_all(query, 50)
             };
 
+            // 高亮在渲染可见行时按需计算，避免大量结果时每次输入都遍历全部结果
             let mut results = results;
-            for result in &mut results {
-                let highlighted_title =
-                    crate::utils::fuzzy::highlight_matches(query, &result.title);
-                result.highlighted_title = Some(highlighted_title);
-
-                let highlighted_desc =
-                    crate::utils::fuzzy::highlight_matches(query, &result.description);
-                result.highlighted_description = Some(highlighted_desc);
-            }
-
-            self.items = results;
-            self.selected_index = None;
+            self.sort_order().apply(&mut results);
+            self.patch_items(results);
         }
     }
 
@@ -115,6 +376,7 @@ impl ResultListDelegate {
             matches
                 .into_iter()
                 .map(|id| {
+                    let icon = manager.plugin_icon(&id).map(|icon| icon.as_str().to_string());
                     SearchResult::new(
                         format!("__plugin__:{}", id),
                         format!("/{} ", id),
@@ -126,6 +388,7 @@ impl ResultListDelegate {
                             data: id,
                         },
                     )
+                    .with_icon(icon)
                 })
                 .collect()
         } else {
@@ -133,6 +396,16 @@ impl ResultListDelegate {
         }
     }
 
+    /// 为可见行按需计算高亮
+    fn ensure_highlighted(&mut self, row: usize) {
+        let query = ParsedQuery::parse(self.search_query.trim_start_matches('/')).text;
+        if let Some(item) = self.items.get_mut(row) {
+            if item.title_highlights.is_none() {
+                item.highlight(&query);
+            }
+        }
+    }
+
     pub fn select_plugin(&mut self, plugin_id: &str) {
         self.active_plugin_id = Some(plugin_id.to_string());
     }
@@ -144,13 +417,82 @@ impl ResultListDelegate {
     pub fn clear_active_plugin(&mut self) {
         self.active_plugin_id = None;
     }
+
+    /// 当前搜索查询
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    /// 为结果项加上鼠标交互：单击执行，右键选中，随后由启动器窗口打开动作面板
+    fn with_mouse_handlers(
+        element: Stateful<Div>,
+        index: usize,
+        cx: &Context<ListState<Self>>,
+    ) -> Stateful<Div> {
+        element
+            .on_click(cx.listener(move |state, _: &ClickEvent, window, cx| {
+                let ix = Self::select_from_mouse(state, index, window, cx);
+                cx.emit(ListEvent::Confirm(ix));
+            }))
+            .on_mouse_down(
+                MouseButton::Right,
+                cx.listener(move |state, _: &MouseDownEvent, window, cx| {
+                    Self::select_from_mouse(state, index, window, cx);
+                }),
+            )
+    }
+
+    /// 选中鼠标点击的结果，返回其所在的列表行
+    fn select_from_mouse(
+        state: &mut ListState<Self>,
+        index: usize,
+        window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> IndexPath {
+        let ix = IndexPath::default().row(state.delegate_mut().select_item(index));
+        state.set_selected_index(Some(ix), window, cx);
+        cx.emit(ListEvent::Select(ix));
+        ix
+    }
+
+    /// 渲染网格布局的一行，末行不满时用空白补齐以保持列宽一致
+    fn render_grid_row(&mut self, ix: IndexPath, cx: &mut Context<ListState<Self>>) -> ListItem {
+        let theme = cx.theme().clone();
+        let show_index_hints = global_config().show_index_hints();
+        let start = ix.row * GRID_COLUMNS;
+        let end = (start + GRID_COLUMNS).min(self.items.len());
+
+        let cells = (start..end).map(|index| {
+            let item = &self.items[index];
+            let is_selected = Some(index) == self.selected_index;
+            let index_hint = show_index_hints.then(|| self.visible_number(index)).flatten();
+            let cell =
+                render_grid_cell(item, is_selected, self.is_marked(item), index_hint, &theme);
+            Self::with_mouse_handlers(cell.id(("result", index)), index, cx)
+        });
+        let padding = (end - start..GRID_COLUMNS).map(|_| div().flex_1());
+
+        ListItem::new(ix).child(
+            div()
+                .flex()
+                .flex_row()
+                .gap_2()
+                .h(px(GRID_ROW_HEIGHT))
+                .py_1()
+                .children(cells)
+                .children(padding),
+        )
+    }
 }
 
 impl ListDelegate for ResultListDelegate {
     type Item = ListItem;
 
     fn items_count(&self, _section: usize, _cx: &App) -> usize {
-        self.items.len()
+        match self.layout {
+            ResultLayout::List => self.items.len(),
+            ResultLayout::Grid => self.items.len().div_ceil(GRID_COLUMNS),
+        }
     }
 
     fn render_item(
@@ -159,131 +501,306 @@ impl ListDelegate for ResultListDelegate {
         _window: &mut Window,
         cx: &mut Context<ListState<Self>>,
     ) -> Option<Self::Item> {
+        if self.layout == ResultLayout::Grid {
+            return Some(self.render_grid_row(ix, cx));
+        }
+
         let is_selected = Some(ix.row) == self.selected_index;
         let theme = cx.theme().clone();
+        // 密度在设置中切换后立即生效；List 基于虚拟化渲染，只绘制可见行，
+        // 同一密度下所有行使用固定高度，使行高测量结果可以复用，滚动时无需逐行重新布局
+        let density = global_config().density();
+        self.ensure_highlighted(ix.row);
 
-        self.items.get(ix.row).map(|item| {
-            let type_name = match &item.result_type {
-                ResultType::Application => "应用",
-                ResultType::File => "文件",
-                ResultType::Folder => "文件夹",
-                ResultType::Command => "命令",
-                ResultType::Calculator => "计算",
-                ResultType::Clipboard => "剪贴板",
-                ResultType::Settings => "设置",
-                ResultType::SystemCommand => "系统命令",
-                ResultType::Task => "任务",
-                ResultType::Custom(_) => "其他",
-            };
+        // 多查询搜索时在每组第一行标出子查询和结果所属插件的图标
+        let group_label = self.items.get(ix.row).and_then(|item| {
+            let group = item.group.as_ref()?;
+            let previous = ix.row.checked_sub(1).and_then(|row| self.items.get(row));
+            let is_first = previous.map_or(true, |prev| prev.group.as_ref() != Some(group));
+            let icon = self.plugin_manager.as_ref().and_then(|m| m.plugin_icon(item.plugin_id()));
+            is_first.then(|| (icon, format!("「{}」", group.query)))
+        });
 
-            let icon_name = match &item.result_type {
-                ResultType::Application => IconName::AppWindow,
-                ResultType::File => IconName::File,
-                ResultType::Folder => IconName::Folder,
-                ResultType::Command => IconName::Terminal,
-                ResultType::Calculator => IconName::Calculator,
-                ResultType::Clipboard => IconName::Clipboard,
-                ResultType::Settings => IconName::Settings,
-                ResultType::SystemCommand => IconName::Command,
-                ResultType::Task => IconName::ListTodo,
-                ResultType::Custom(_) => IconName::Search,
-            };
+        self.items.get(ix.row).map(|item| {
+            let type_name = item.result_type.display();
+            let is_marked = self.is_marked(item);
+            let index_hint =
+                global_config().show_index_hints().then(|| self.visible_number(ix.row)).flatten();
+            let style = ResultTypeStyle::resolve(item, &theme, is_selected);
 
             let bg_color = if is_selected { theme.accent } else { theme.background };
+            let hover_color = theme.secondary;
             let text_color = if is_selected { theme.accent_foreground } else { theme.foreground };
-            let muted_color = if is_selected {
-                theme.accent_foreground.opacity(0.7)
-            } else {
-                theme.muted_foreground
+
+            let icon_name = result_type_icon(&item.result_type);
+            // 结果自带图标（如插件目录中的插件图标）时优先显示
+            let icon_color = if is_selected { text_color } else { style.accent };
+            let icon = match item.icon.as_deref().map(PluginIcon::parse) {
+                Some(icon) => render_plugin_icon(&icon, icon_color),
+                None => gpui_component::Icon::new(icon_name)
+                    .small()
+                    .text_color(icon_color)
+                    .into_any_element(),
             };
+            // 预览带有可视内容（如颜色色块）时代替图标显示
+            let visual = item.preview.as_ref().and_then(|preview| preview.visual);
 
-            ListItem::new(ix)
-                .child(
-                    div()
+            let row = div()
+                .id(("result", ix.row))
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_3()
+                .h(px(density.row_height()))
+                .px(px(density.row_padding()))
+                .when(density != Density::Compact, |this| this.py_2())
+                .rounded_md()
+                .bg(bg_color)
+                // 鼠标悬停时高亮未选中的行
+                .when(!is_selected, |this| this.hover(|this| this.bg(hover_color)))
+                .when(group_label.is_some() && ix.row > 0, |this| {
+                    this.border_t_1().border_color(theme.border)
+                })
+                .child(match visual {
+                    Some(visual) => {
+                        render_visual(&visual, theme.border).size(px(density.icon_size()))
+                    },
+                    None => div()
                         .flex()
-                        .flex_row()
                         .items_center()
-                        .gap_3()
-                        .px_3()
-                        .py_2()
+                        .justify_center()
+                        .size(px(density.icon_size()))
                         .rounded_md()
-                        .bg(bg_color)
+                        .bg(style.icon_background)
+                        .child(icon),
+                })
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .flex_1()
+                        .gap_1()
                         .child(
                             div()
-                                .flex()
-                                .items_center()
-                                .justify_center()
-                                .w_8()
-                                .h_8()
-                                .rounded_md()
-                                .bg(if is_selected {
-                                    theme.accent_foreground.opacity(0.2)
-                                } else {
-                                    theme.secondary
-                                })
-                                .child(
-                                    gpui_component::Icon::new(icon_name)
-                                        .small()
-                                        .text_color(text_color),
-                                ),
+                                .text_sm()
+                                .font_weight(FontWeight::MEDIUM)
+                                .truncate()
+                                .child(render_highlighted_text(
+                                    &item.title,
+                                    item.title_highlights(),
+                                    &theme,
+                                    style.accent,
+                                    is_selected,
+                                    true,
+                                )),
                         )
                         .child(
                             div()
                                 .flex()
-                                .flex_col()
-                                .flex_1()
-                                .gap_1()
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .font_weight(FontWeight::MEDIUM)
-                                        .text_color(text_color)
-                                        .child(item.title.clone()),
-                                )
-                                .child(
-                                    div()
-                                        .text_xs()
-                                        .text_color(muted_color)
-                                        .child(item.description.clone()),
-                                ),
-                        )
-                        .child(
-                            div()
-                                .px_2()
-                                .py_0()
-                                .rounded_full()
+                                .items_center()
+                                .gap_2()
                                 .text_xs()
-                                .bg(if is_selected {
-                                    theme.accent_foreground.opacity(0.2)
-                                } else {
-                                    theme.secondary
-                                })
-                                .text_color(muted_color)
-                                .child(type_name),
+                                .child(div().flex_1().min_w_0().truncate().child(
+                                    render_highlighted_text(
+                                        &item.description,
+                                        item.description_highlights(),
+                                        &theme,
+                                        style.accent,
+                                        is_selected,
+                                        false,
+                                    ),
+                                ))
+                                // 用户备注
+                                .when_some(item.note.clone(), |this, note| {
+                                    this.child(
+                                        div()
+                                            .flex_none()
+                                            .px_1()
+                                            .rounded_sm()
+                                            .bg(theme.warning.opacity(0.15))
+                                            .text_color(if is_selected {
+                                                text_color
+                                            } else {
+                                                theme.warning
+                                            })
+                                            .child(note),
+                                    )
+                                }),
                         ),
                 )
+                .when_some(group_label, |this, (icon, label)| {
+                    this.child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .text_xs()
+                            .text_color(theme.muted_foreground)
+                            .when_some(icon, |this, icon| {
+                                this.child(render_plugin_icon(
+                                    &icon,
+                                    theme.muted_foreground,
+                                ))
+                            })
+                            .child(label),
+                    )
+                })
+                // 已标记的结果
+                .when(is_marked, |this| {
+                    this.child(
+                        div()
+                            .text_sm()
+                            .text_color(if is_selected { text_color } else { theme.primary })
+                            .child("✓"),
+                    )
+                })
+                .child(
+                    div()
+                        .px_2()
+                        .py_0()
+                        .rounded_full()
+                        .text_xs()
+                        .border_1()
+                        .border_color(style.badge_border)
+                        .bg(style.badge_background)
+                        .text_color(style.badge_foreground)
+                        .child(type_name),
+                )
+                // Alt+数字快捷键提示
+                .when_some(index_hint, |this, number| {
+                    let hint_color = if is_selected { text_color } else { theme.muted_foreground };
+                    this.child(
+                        div()
+                            .flex()
+                            .justify_end()
+                            .w(px(36.0))
+                            .text_xs()
+                            .text_color(hint_color)
+                            .child(format!("Alt+{}", number)),
+                    )
+                });
+
+            ListItem::new(ix)
+                .child(Self::with_mouse_handlers(row, ix.row, cx))
                 .selected(is_selected)
         })
     }
 
+    fn render_empty(
+        &mut self,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> impl IntoElement {
+        let plugin_name = self.active_plugin_id.as_ref().and_then(|id| {
+            self.plugin_manager.as_ref().and_then(|manager| manager.plugin_name(id))
+        });
+        render_empty_state(plugin_name.as_deref(), &self.search_query, cx.theme())
+    }
+
     fn set_selected_index(
         &mut self,
         ix: Option<IndexPath>,
         _window: &mut Window,
         _cx: &mut Context<ListState<Self>>,
     ) {
-        self.selected_index = ix.map(|i| i.row);
+        self.selected_index = ix.map(|ix| self.item_index(ix));
     }
 
     fn perform_search(
         &mut self,
         query: &str,
-        _window: &mut Window,
+        window: &mut Window,
         cx: &mut Context<ListState<Self>>,
     ) -> Task<()> {
+        // 退格删除已完成的过滤标记时整体移除，随后同步回输入框
+        let collapsed = query::collapse_deleted_filter(&self.search_query, query);
+        let query = collapsed.as_deref().unwrap_or(query);
+
         self.search_query = query.to_string();
         self.perform_search_internal(query);
         cx.notify();
-        Task::ready(())
+
+        // 将保留下来的选中项同步回列表状态
+        let selected = self.selected_index.map(|index| self.list_row(index));
+        cx.spawn_in(window, async move |state, cx| {
+            let _ = state.update_in(cx, |state, window, cx| {
+                if let Some(query) = &collapsed {
+                    state.set_query(query, window, cx);
+                }
+                state.set_selected_index(
+                    selected.map(|row| IndexPath::default().row(row)),
+                    window,
+                    cx,
+                );
+            });
+        })
+    }
+}
+
+/// 结果类型的默认图标
+pub fn result_type_icon(result_type: &ResultType) -> IconName {
+    match result_type {
+        ResultType::Application => IconName::AppWindow,
+        ResultType::File => IconName::File,
+        ResultType::Folder => IconName::Folder,
+        ResultType::Command => IconName::Terminal,
+        ResultType::Calculator => IconName::Calculator,
+        ResultType::Clipboard => IconName::Clipboard,
+        ResultType::Settings => IconName::Settings,
+        ResultType::SystemCommand => IconName::Command,
+        ResultType::Task => IconName::ListTodo,
+        ResultType::Custom(_) => IconName::Search,
     }
 }
+
+/// 渲染高亮文本
+///
+/// 样式规则：
+/// - 未选中：匹配字符使用结果类型强调色 + 粗体
+/// - 选中：匹配字符使用结果类型强调色 + 浅蓝边框 + 粗体
+pub fn render_highlighted_text(
+    text: &str,
+    ranges: &[Range<usize>],
+    theme: &gpui_component::Theme,
+    highlight_color: Hsla,
+    is_selected: bool,
+    is_title: bool,
+) -> impl IntoElement {
+    // 高亮范围为字节范围，按字符边界切分，多字节文本和含方括号的标题均可正确显示
+    let fragments: Vec<(String, bool)> = split_highlights(text, ranges)
+        .into_iter()
+        .map(|(fragment, is_highlighted)| (fragment.to_string(), is_highlighted))
+        .collect();
+
+    // 基础颜色
+    let base_color = if is_selected {
+        theme.accent_foreground
+    } else if is_title {
+        theme.foreground
+    } else {
+        theme.muted_foreground
+    };
+
+    div().flex().flex_row().children(fragments.into_iter().map(move |(text, is_highlighted)| {
+        let mut div_element = div()
+            .text_color(if is_highlighted { highlight_color } else { base_color })
+            .font_weight(if is_highlighted { FontWeight::BOLD } else { FontWeight::NORMAL });
+
+        if is_highlighted {
+            if is_selected {
+                // 选中状态：强调色 + 浅蓝边框 + 粗体
+                div_element = div_element
+                    .border_1()
+                    .border_color(theme.primary.opacity(0.5))
+                    .rounded_sm()
+                    .px_1()
+                    .py_0();
+            } else {
+                // 未选中状态：强调色 + 粗体（无边框）
+                div_element = div_element.px_1();
+            }
+        }
+
+        div_element.child(text)
+    }))
+}
@@ -0,0 +1,17 @@
+/// 可视内容渲染
+///
+/// 绘制预览附带的可视内容（如颜色色块），尺寸由调用方设置
+use gpui::{div, prelude::*, rgb, Div, Hsla};
+
+use crate::core::preview::PreviewVisual;
+
+/// 渲染可视内容，带描边以便浅色色块在浅色背景上可见
+pub fn render_visual(visual: &PreviewVisual, border: Hsla) -> Div {
+    match *visual {
+        PreviewVisual::Color(r, g, b) => div()
+            .rounded_md()
+            .border_1()
+            .border_color(border)
+            .bg(rgb(u32::from_be_bytes([0, r, g, b]))),
+    }
+}
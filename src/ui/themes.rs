@@ -1,11 +1,47 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf, rc::Rc, time::Duration};
 
-use gpui::{Action, App, SharedString};
-use gpui_component::{scroll::ScrollbarShow, ActiveTheme, Theme, ThemeMode, ThemeRegistry};
+use chrono::Offset;
+use gpui::{transparent_black, Action, App, Global, Hsla, Rgba, SharedString, WindowAppearance};
+use gpui_component::{
+    scroll::ScrollbarShow,
+    theme::{ThemeConfig, ThemeSet},
+    ActiveTheme, Theme, ThemeMode, ThemeRegistry,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    core::{
+        config::BadgeStyle,
+        config_manager::global_config,
+        search::SearchResult,
+        themes::{
+            global_theme_scheduler, notify_system_theme_changed, system_appearance,
+            take_system_theme_changed, take_theme_request, take_user_themes_changed, theme_files,
+            user_themes_dir, watch_user_themes, Appearance, BUILTIN_THEMES_DIR,
+        },
+    },
+    platform::windows::TrayIcon,
+    ui::accessibility,
+};
+
 const STATE_FILE: &str = "target/state.json";
 
+/// 高对比度主题名称 (themes/high-contrast.json)
+const HIGH_CONTRAST_DARK: &str = "High Contrast Dark";
+const HIGH_CONTRAST_LIGHT: &str = "High Contrast Light";
+
+/// 检查定时切换和系统明暗模式的间隔
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 检查用户主题文件修改、系统主题变化和主题切换请求的间隔
+const RELOAD_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 用户主题，与内置主题同名时优先使用
+#[derive(Default)]
+struct UserThemes(HashMap<SharedString, Rc<ThemeConfig>>);
+
+impl Global for UserThemes {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct State {
     theme: SharedString,
@@ -23,8 +59,13 @@ pub fn init(cx: &mut App) {
     let json = std::fs::read_to_string(STATE_FILE).unwrap_or_default();
     tracing::info!("Load themes...");
     let state = serde_json::from_str::<State>(&json).unwrap_or_default();
-    if let Err(err) = ThemeRegistry::watch_dir(PathBuf::from("./themes"), cx, move |cx| {
-        if let Some(theme) = ThemeRegistry::global(cx).themes().get(&state.theme).cloned() {
+    load_user_themes(cx);
+    watch_user_themes();
+    if let Err(err) = ThemeRegistry::watch_dir(PathBuf::from(BUILTIN_THEMES_DIR), cx, move |cx| {
+        // 高对比度优先于上次使用的主题
+        let theme_name =
+            high_contrast_theme(cx.theme().mode.is_dark()).unwrap_or_else(|| state.theme.clone());
+        if let Some(theme) = find_theme(&theme_name, cx) {
             Theme::global_mut(cx).apply_config(&theme);
         }
     }) {
@@ -37,6 +78,12 @@ pub fn init(cx: &mut App) {
     cx.refresh_windows();
 
     cx.observe_global::<Theme>(|cx| {
+        // 高对比度主题由配置/系统设置决定，不记为上次使用的主题
+        let theme_name = cx.theme().theme_name().to_string();
+        if theme_name == HIGH_CONTRAST_DARK || theme_name == HIGH_CONTRAST_LIGHT {
+            return;
+        }
+
         let state = State {
             theme: cx.theme().theme_name().clone(),
             scrollbar_show: Some(cx.theme().scrollbar_show),
@@ -49,18 +96,156 @@ pub fn init(cx: &mut App) {
     })
     .detach();
 
-    cx.on_action(|switch: &SwitchTheme, cx| {
-        let theme_name = switch.0.clone();
-        if let Some(theme_config) = ThemeRegistry::global(cx).themes().get(&theme_name).cloned() {
-            Theme::global_mut(cx).apply_config(&theme_config);
-        }
-        cx.refresh_windows();
-    });
+    // 手动切换主题后，定时切换暂停到下一个切换时刻
+    cx.on_action(|switch: &SwitchTheme, cx| switch_theme(&switch.0, cx));
     cx.on_action(|switch: &SwitchThemeMode, cx| {
+        pause_schedule();
         let mode = switch.0;
         Theme::change(mode, None, cx);
         cx.refresh_windows();
     });
+
+    // 系统切换明暗模式或高对比度时立即响应，定时检查作为后备
+    TrayIcon::on_theme_changed(notify_system_theme_changed);
+
+    apply_schedule(cx);
+    cx.spawn(async move |cx| loop {
+        cx.background_executor().timer(SCHEDULE_INTERVAL).await;
+        let _ = cx.update(apply_schedule);
+    })
+    .detach();
+
+    cx.spawn(async move |cx| loop {
+        cx.background_executor().timer(RELOAD_INTERVAL).await;
+        let _ = cx.update(apply_theme_changes);
+    })
+    .detach();
+}
+
+/// 读取用户主题目录中的主题文件，格式有误的文件跳过
+fn load_user_themes(cx: &mut App) {
+    let files = user_themes_dir().map(|dir| theme_files(&dir)).unwrap_or_default();
+    let mut themes = HashMap::new();
+    for path in files {
+        let theme_set = std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| Ok(serde_json::from_str::<ThemeSet>(&json)?));
+        match theme_set {
+            Ok(theme_set) => {
+                for theme in theme_set.themes {
+                    themes.insert(theme.name.clone(), Rc::new(theme));
+                }
+            },
+            Err(err) => tracing::warn!("加载主题文件 {} 失败: {}", path.display(), err),
+        }
+    }
+
+    tracing::info!("已加载 {} 个用户主题", themes.len());
+    cx.set_global(UserThemes(themes));
+}
+
+/// 按名称查找主题，用户主题优先
+fn find_theme(name: &SharedString, cx: &App) -> Option<Rc<ThemeConfig>> {
+    cx.try_global::<UserThemes>()
+        .and_then(|themes| themes.0.get(name).cloned())
+        .or_else(|| ThemeRegistry::global(cx).themes().get(name).cloned())
+}
+
+/// 切换到指定主题，手动切换后定时切换暂停到下一个切换时刻
+fn switch_theme(name: &SharedString, cx: &mut App) {
+    pause_schedule();
+    match find_theme(name, cx) {
+        Some(theme) => Theme::global_mut(cx).apply_config(&theme),
+        None => tracing::warn!("未找到主题: {}", name),
+    }
+    cx.refresh_windows();
+}
+
+/// 重新加载修改过的用户主题（正在使用时立即生效），响应系统主题变化，并应用主题插件请求的主题
+fn apply_theme_changes(cx: &mut App) {
+    if take_system_theme_changed() {
+        apply_schedule(cx);
+    }
+
+    if take_user_themes_changed() {
+        load_user_themes(cx);
+        let current = cx.theme().theme_name().clone();
+        if let Some(theme) = cx.global::<UserThemes>().0.get(&current).cloned() {
+            tracing::info!("主题文件已修改，重新应用主题: {}", current);
+            Theme::global_mut(cx).apply_config(&theme);
+            cx.refresh_windows();
+        }
+    }
+
+    if let Some(name) = take_theme_request() {
+        switch_theme(&name.into(), cx);
+    }
+}
+
+/// 按定时切换配置或系统明暗模式切换到配置的浅色/深色主题
+///
+/// 只在明暗模式或高对比度状态变化时切换，同一模式下手动选择的主题保持不变
+fn apply_schedule(cx: &mut App) {
+    let config = global_config().theme_config();
+    let now = chrono::Local::now();
+    // 优先读取注册表中的应用模式，读取失败时使用窗口的外观
+    let system = config.follow_system.then(|| {
+        system_appearance().unwrap_or(match cx.window_appearance() {
+            WindowAppearance::Light | WindowAppearance::VibrantLight => Appearance::Light,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => Appearance::Dark,
+        })
+    });
+
+    let appearance = {
+        let mut scheduler = global_theme_scheduler().lock().unwrap();
+        scheduler.update(config.schedule, now.offset().fix());
+        scheduler.appearance(now.naive_local(), system)
+    };
+    let Some(appearance) = appearance else {
+        return;
+    };
+
+    let mode = match appearance {
+        Appearance::Light => ThemeMode::Light,
+        Appearance::Dark => ThemeMode::Dark,
+    };
+    // 高对比度主题同样区分明暗
+    let high_contrast = high_contrast_theme(mode.is_dark());
+    let current = cx.theme().theme_name().to_string();
+    let showing_high_contrast = current == HIGH_CONTRAST_DARK || current == HIGH_CONTRAST_LIGHT;
+    if cx.theme().mode == mode && high_contrast.is_some() == showing_high_contrast {
+        return;
+    }
+
+    let theme_name = high_contrast.unwrap_or_else(|| {
+        if mode.is_dark() { config.dark_theme } else { config.light_theme }.into()
+    });
+    tracing::info!("切换到{}主题: {}", if mode.is_dark() { "深色" } else { "浅色" }, theme_name);
+    Theme::change(mode, None, cx);
+    match find_theme(&theme_name, cx) {
+        Some(theme) => Theme::global_mut(cx).apply_config(&theme),
+        None => tracing::warn!("未找到主题: {}，使用默认主题", theme_name),
+    }
+    cx.refresh_windows();
+}
+
+/// 手动切换主题时暂停定时切换
+fn pause_schedule() {
+    global_theme_scheduler()
+        .lock()
+        .unwrap()
+        .override_until_next(chrono::Local::now().naive_local());
+}
+
+/// 需要使用的高对比度主题
+///
+/// 配置强制开启，或跟随系统且系统开启了高对比度时，按明暗模式选择
+fn high_contrast_theme(is_dark: bool) -> Option<SharedString> {
+    let config = global_config().theme_config();
+    let enabled =
+        config.high_contrast || (config.follow_system && accessibility::is_system_high_contrast());
+
+    enabled.then(|| if is_dark { HIGH_CONTRAST_DARK } else { HIGH_CONTRAST_LIGHT }.into())
 }
 
 #[derive(Action, Clone, PartialEq)]
@@ -70,3 +255,74 @@ pub(crate) struct SwitchTheme(pub(crate) SharedString);
 #[derive(Action, Clone, PartialEq)]
 #[action(namespace = themes, no_json)]
 pub(crate) struct SwitchThemeMode(pub(crate) ThemeMode);
+
+/// 结果类型样式
+///
+/// 在 gpui-component 主题之上按结果类型着色：默认取主题的基础色
+/// （应用蓝、文件黄、破坏性命令红），可由 `[theme.result_colors]` 覆盖
+#[derive(Clone, Copy, Debug)]
+pub struct ResultTypeStyle {
+    /// 强调色（图标和匹配字符）
+    pub accent: Hsla,
+    /// 图标底色
+    pub icon_background: Hsla,
+    /// 徽标背景色
+    pub badge_background: Hsla,
+    /// 徽标文字颜色
+    pub badge_foreground: Hsla,
+    /// 徽标边框颜色
+    pub badge_border: Hsla,
+}
+
+impl ResultTypeStyle {
+    /// 根据结果类型、当前主题和配置计算样式
+    pub fn resolve(result: &SearchResult, theme: &Theme, is_selected: bool) -> Self {
+        let config = global_config().theme_config();
+        let key = result.color_key();
+        let accent = config
+            .result_colors
+            .get(key)
+            .and_then(|hex| Rgba::try_from(hex.as_str()).ok())
+            .map(Hsla::from)
+            .unwrap_or_else(|| Self::default_accent(key, theme));
+
+        if is_selected {
+            // 选中行以主题强调色为底，图标和徽标使用前景色保证对比度
+            let overlay = theme.accent_foreground.opacity(0.2);
+            return Self {
+                accent,
+                icon_background: overlay,
+                badge_background: overlay,
+                badge_foreground: theme.accent_foreground,
+                badge_border: transparent_black(),
+            };
+        }
+
+        let (badge_background, badge_foreground, badge_border) = match config.badge_style {
+            BadgeStyle::Subtle => (accent.opacity(0.15), accent, transparent_black()),
+            BadgeStyle::Solid => (accent, theme.background, accent),
+            BadgeStyle::Outline => (transparent_black(), accent, accent),
+        };
+
+        Self {
+            accent,
+            icon_background: accent.opacity(0.15),
+            badge_background,
+            badge_foreground,
+            badge_border,
+        }
+    }
+
+    /// 未配置覆盖时的默认强调色
+    fn default_accent(key: &str, theme: &Theme) -> Hsla {
+        match key {
+            "application" => theme.blue,
+            "file" | "folder" => theme.yellow,
+            "destructive" => theme.red,
+            "command" | "system_command" => theme.cyan,
+            "calculator" => theme.green,
+            "clipboard" | "task" => theme.magenta,
+            _ => theme.primary,
+        }
+    }
+}
@@ -1,15 +1,152 @@
 /// 剪贴板操作工具
 ///
-/// 提供 Windows 剪贴板读写功能
-use windows::Win32::Foundation::{HANDLE, HGLOBAL, HWND};
-use windows::Win32::System::{
-    DataExchange::{
-        CloseClipboard, EmptyClipboard, GetClipboardData, OpenClipboard, SetClipboardData,
+/// 提供 Windows 剪贴板读写功能，支持文本、图片 (CF_DIB/PNG) 和文件列表 (CF_HDROP)
+use windows::core::w;
+use windows::Win32::{
+    Foundation::{GlobalFree, HANDLE, HGLOBAL, HWND},
+    System::{
+        DataExchange::{
+            CloseClipboard, EmptyClipboard, GetClipboardData, GetClipboardOwner,
+            IsClipboardFormatAvailable, OpenClipboard, RegisterClipboardFormatW, SetClipboardData,
+        },
+        Memory::{GlobalAlloc, GlobalLock, GlobalSize, GlobalUnlock, GMEM_MOVEABLE},
+        Ole::{CF_DIB, CF_HDROP, CF_UNICODETEXT},
+    },
+    UI::{
+        Shell::{DragQueryFileW, DROPFILES, HDROP},
+        WindowsAndMessaging::GetClassNameW,
     },
-    Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE},
-    Ole::CF_UNICODETEXT,
 };
 
+use crate::core::error::{Result, WerunError};
+
+/// BMP 文件头大小 (BITMAPFILEHEADER)
+const BMP_FILE_HEADER_SIZE: usize = 14;
+
+/// 剪贴板内容
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClipboardContent {
+    /// 文本
+    Text(String),
+    /// 图片
+    Image(ClipboardImage),
+    /// 文件列表（完整路径）
+    Files(Vec<String>),
+}
+
+/// 剪贴板图片
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// CF_DIB 数据：BITMAPINFOHEADER（含掩码和调色板）+ 像素
+    pub dib: Vec<u8>,
+    /// PNG 数据，复制方同时提供 "PNG" 格式时保留，粘贴时一并还原以保留透明度
+    pub png: Option<Vec<u8>>,
+}
+
+impl ClipboardImage {
+    /// 图片宽高，DIB 头不完整时返回 `None`
+    pub fn size(&self) -> Option<(u32, u32)> {
+        let width = i32::from_le_bytes(self.dib.get(4..8)?.try_into().ok()?);
+        let height = i32::from_le_bytes(self.dib.get(8..12)?.try_into().ok()?);
+        Some((width.unsigned_abs(), height.unsigned_abs()))
+    }
+
+    /// 转换为 BMP 文件内容（BITMAPFILEHEADER + DIB）
+    pub fn to_bmp(&self) -> Option<Vec<u8>> {
+        let field = |range: std::ops::Range<usize>| -> Option<u32> {
+            let bytes = self.dib.get(range)?;
+            Some(bytes.iter().rev().fold(0, |value, &b| (value << 8) | b as u32))
+        };
+        let header_size = field(0..4)?;
+        let bit_count = field(14..16)?;
+        let compression = field(16..20)?;
+        let colors_used = field(32..36)?;
+
+        // BITMAPINFOHEADER 后可能紧跟颜色掩码 (BI_BITFIELDS/BI_ALPHABITFIELDS) 和调色板
+        let masks = match (header_size, compression) {
+            (40, 3) => 12,
+            (40, 6) => 16,
+            _ => 0,
+        };
+        let colors = match colors_used {
+            0 if bit_count <= 8 => 1 << bit_count,
+            count => count,
+        };
+        let pixel_offset = BMP_FILE_HEADER_SIZE as u32 + header_size + masks + colors * 4;
+        let file_size = (BMP_FILE_HEADER_SIZE + self.dib.len()) as u32;
+
+        let mut bmp = Vec::with_capacity(file_size as usize);
+        bmp.extend_from_slice(b"BM");
+        bmp.extend_from_slice(&file_size.to_le_bytes());
+        bmp.extend_from_slice(&[0; 4]);
+        bmp.extend_from_slice(&pixel_offset.to_le_bytes());
+        bmp.extend_from_slice(&self.dib);
+        Some(bmp)
+    }
+
+    /// 从 BMP 文件内容还原 DIB 数据
+    pub fn from_bmp(bmp: &[u8], png: Option<Vec<u8>>) -> Option<Self> {
+        if !bmp.starts_with(b"BM") || bmp.len() <= BMP_FILE_HEADER_SIZE {
+            return None;
+        }
+        Some(Self { dib: bmp[BMP_FILE_HEADER_SIZE..].to_vec(), png })
+    }
+}
+
+/// 已打开的剪贴板，离开作用域时关闭
+struct OpenedClipboard;
+
+impl OpenedClipboard {
+    /// 打开剪贴板
+    unsafe fn open() -> windows::core::Result<Self> {
+        OpenClipboard(HWND(std::ptr::null_mut()))?;
+        Ok(Self)
+    }
+}
+
+impl Drop for OpenedClipboard {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseClipboard();
+        }
+    }
+}
+
+/// 待写入剪贴板的全局内存块
+///
+/// SetClipboardData 成功后内存归系统所有，否则离开作用域时释放
+struct GlobalMemory(HGLOBAL);
+
+impl GlobalMemory {
+    /// 分配全局内存块并写入数据
+    unsafe fn alloc(data: &[u8]) -> anyhow::Result<Self> {
+        let memory = Self(GlobalAlloc(GMEM_MOVEABLE, data.len())?);
+        let ptr = GlobalLock(memory.0) as *mut u8;
+        if ptr.is_null() {
+            return Err(anyhow::anyhow!("无法锁定全局内存"));
+        }
+
+        std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+        let _ = GlobalUnlock(memory.0);
+        Ok(memory)
+    }
+
+    /// 以指定格式写入已打开的剪贴板，转移内存的所有权
+    unsafe fn set_clipboard_data(self, format: u32) -> windows::core::Result<()> {
+        SetClipboardData(format, HANDLE(self.0 .0))?;
+        std::mem::forget(self);
+        Ok(())
+    }
+}
+
+impl Drop for GlobalMemory {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = GlobalFree(self.0);
+        }
+    }
+}
+
 /// 剪贴板管理器
 pub struct ClipboardManager;
 
@@ -20,44 +157,233 @@ impl ClipboardManager {
     }
 
     /// 设置文本到剪贴板
-    pub fn set_text(&self, text: &str) -> anyhow::Result<()> {
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        self.try_set_text(text, false).map_err(|e| WerunError::Clipboard(e.to_string()))
+    }
+
+    /// 设置不应被记录的文本（如生成的密码）到剪贴板
+    ///
+    /// 附带 "ExcludeClipboardContentFromMonitorProcessing" 格式，并将
+    /// "CanIncludeInClipboardHistory"、 "CanUploadToCloudClipboard" 设为
+    /// 0：系统剪贴板历史、云剪贴板和本程序的剪贴板历史都不记录
+    pub fn set_sensitive_text(&self, text: &str) -> Result<()> {
+        self.try_set_text(text, true).map_err(|e| WerunError::Clipboard(e.to_string()))
+    }
+
+    /// 从剪贴板获取文本
+    pub fn get_text(&self) -> Result<String> {
+        self.try_get_text().map_err(|e| WerunError::Clipboard(e.to_string()))
+    }
+
+    /// 读取剪贴板内容，依次尝试文件列表、文本和图片
+    ///
+    /// 表格、文档等程序复制文本时常附带位图，有文本时优先记录文本
+    pub fn get_content(&self) -> Result<ClipboardContent> {
+        if let Some(files) = self.try_get_files().filter(|files| !files.is_empty()) {
+            return Ok(ClipboardContent::Files(files));
+        }
+        match self.get_text() {
+            Ok(text) if !text.is_empty() => Ok(ClipboardContent::Text(text)),
+            result => match self.try_get_image() {
+                Some(image) => Ok(ClipboardContent::Image(image)),
+                None => result.map(ClipboardContent::Text),
+            },
+        }
+    }
+
+    /// 以原始格式写入剪贴板内容
+    pub fn set_content(&self, content: &ClipboardContent) -> Result<()> {
+        match content {
+            ClipboardContent::Text(text) => self.set_text(text),
+            ClipboardContent::Image(image) => self.set_image(image),
+            ClipboardContent::Files(paths) => self.set_files(paths),
+        }
+    }
+
+    /// 写入图片到剪贴板 (CF_DIB，有 PNG 数据时同时写入 "PNG" 格式)
+    pub fn set_image(&self, image: &ClipboardImage) -> Result<()> {
+        self.try_set_image(image).map_err(|e| WerunError::Clipboard(e.to_string()))
+    }
+
+    /// 当前剪贴板内容所有者（复制方）窗口的类名
+    pub fn owner_class(&self) -> Option<String> {
         unsafe {
-            // 打开剪贴板
-            OpenClipboard(HWND(std::ptr::null_mut()))?;
+            let owner = GetClipboardOwner().ok()?;
+            let mut name = [0u16; 256];
+            let len = GetClassNameW(owner, &mut name);
+            (len > 0).then(|| String::from_utf16_lossy(&name[..len as usize]))
+        }
+    }
+
+    /// 复制方是否声明不记录到剪贴板历史
+    ///
+    /// 密码管理器等程序复制时附带 "ExcludeClipboardContentFromMonitorProcessing"、
+    /// "Clipboard Viewer Ignore" 格式，或值为 0 的 "CanIncludeInClipboardHistory" 格式
+    pub fn is_history_excluded(&self) -> bool {
+        unsafe {
+            let marked =
+                [w!("ExcludeClipboardContentFromMonitorProcessing"), w!("Clipboard Viewer Ignore")]
+                    .into_iter()
+                    .any(|name| IsClipboardFormatAvailable(RegisterClipboardFormatW(name)).is_ok());
+            if marked {
+                return true;
+            }
+
+            let format = RegisterClipboardFormatW(w!("CanIncludeInClipboardHistory"));
+            if IsClipboardFormatAvailable(format).is_err()
+                || OpenClipboard(HWND(std::ptr::null_mut())).is_err()
+            {
+                return false;
+            }
+            let value = GetClipboardData(format).ok().and_then(|handle| Self::read_global(handle));
+            let _ = CloseClipboard();
+            value.is_some_and(|value| value.starts_with(&[0, 0, 0, 0]))
+        }
+    }
+
+    /// 复制文件到剪贴板 (CF_HDROP)，可在资源管理器中粘贴
+    pub fn set_files(&self, paths: &[String]) -> Result<()> {
+        self.try_set_files(paths).map_err(|e| WerunError::Clipboard(e.to_string()))
+    }
+
+    /// 写入文件列表（Windows API 调用）
+    ///
+    /// 数据格式：DROPFILES 头 + 以 \0 分隔的宽字符路径 + 结尾的额外 \0
+    fn try_set_files(&self, paths: &[String]) -> anyhow::Result<()> {
+        let header_size = std::mem::size_of::<DROPFILES>();
+        let header =
+            DROPFILES { pFiles: header_size as u32, fWide: true.into(), ..Default::default() };
+
+        let mut data = vec![0u8; header_size];
+        unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut DROPFILES, header) };
+        for unit in paths.iter().flat_map(|path| path.encode_utf16().chain(std::iter::once(0))) {
+            data.extend(unit.to_le_bytes());
+        }
+        data.extend(0u16.to_le_bytes());
 
-            // 清空剪贴板
+        unsafe {
+            let files = GlobalMemory::alloc(&data)?;
+
+            let _clipboard = OpenedClipboard::open()?;
             EmptyClipboard()?;
+            files.set_clipboard_data(CF_HDROP.0 as u32)?;
 
-            // 将文本转换为宽字符
-            let wide_text: Vec<u16> = text.encode_utf16().chain(std::iter::once(0)).collect();
-            let size = wide_text.len() * std::mem::size_of::<u16>();
+            Ok(())
+        }
+    }
 
-            // 分配全局内存
-            let h_global: HGLOBAL = GlobalAlloc(GMEM_MOVEABLE, size)?;
+    /// 写入图片（Windows API 调用）
+    fn try_set_image(&self, image: &ClipboardImage) -> anyhow::Result<()> {
+        unsafe {
+            let dib = GlobalMemory::alloc(&image.dib)?;
+            let png = image.png.as_deref().map(|png| GlobalMemory::alloc(png)).transpose()?;
 
-            // 锁定内存并复制数据
-            let ptr = GlobalLock(h_global) as *mut u16;
-            if ptr.is_null() {
-                return Err(anyhow::anyhow!("无法锁定全局内存"));
+            let _clipboard = OpenedClipboard::open()?;
+            EmptyClipboard()?;
+            dib.set_clipboard_data(CF_DIB.0 as u32)?;
+            if let Some(png) = png {
+                png.set_clipboard_data(RegisterClipboardFormatW(w!("PNG")))?;
             }
 
-            std::ptr::copy_nonoverlapping(wide_text.as_ptr(), ptr, wide_text.len());
+            Ok(())
+        }
+    }
 
-            // 解锁内存
-            GlobalUnlock(h_global)?;
+    /// 读取文件列表（Windows API 调用），剪贴板中没有文件时返回 `None`
+    fn try_get_files(&self) -> Option<Vec<String>> {
+        unsafe {
+            OpenClipboard(HWND(std::ptr::null_mut())).ok()?;
+
+            let files = GetClipboardData(CF_HDROP.0 as u32).ok().map(|handle| {
+                let hdrop = HDROP(handle.0);
+                let count = DragQueryFileW(hdrop, u32::MAX, None);
+                (0..count)
+                    .filter_map(|index| {
+                        // 先查询长度（不含结尾的 \0）再读取
+                        let len = DragQueryFileW(hdrop, index, None) as usize;
+                        let mut buffer = vec![0u16; len + 1];
+                        let copied = DragQueryFileW(hdrop, index, Some(&mut buffer)) as usize;
+                        (copied > 0).then(|| String::from_utf16_lossy(&buffer[..copied]))
+                    })
+                    .collect()
+            });
+
+            let _ = CloseClipboard();
+            files
+        }
+    }
+
+    /// 读取图片（Windows API 调用），剪贴板中没有图片时返回 `None`
+    ///
+    /// 只有位图格式的程序也能读到 CF_DIB（由系统自动转换）
+    fn try_get_image(&self) -> Option<ClipboardImage> {
+        unsafe {
+            OpenClipboard(HWND(std::ptr::null_mut())).ok()?;
+
+            let dib =
+                GetClipboardData(CF_DIB.0 as u32).ok().and_then(|handle| Self::read_global(handle));
+            let png = dib.as_ref().and_then(|_| {
+                let format = RegisterClipboardFormatW(w!("PNG"));
+                GetClipboardData(format).ok().and_then(|handle| Self::read_global(handle))
+            });
+
+            let _ = CloseClipboard();
+            dib.map(|dib| ClipboardImage { dib, png })
+        }
+    }
+
+    /// 复制全局内存块中的数据
+    unsafe fn read_global(handle: HANDLE) -> Option<Vec<u8>> {
+        let h_global = HGLOBAL(handle.0);
+        let size = GlobalSize(h_global);
+        let ptr = GlobalLock(h_global) as *const u8;
+        if ptr.is_null() || size == 0 {
+            return None;
+        }
+
+        let data = std::slice::from_raw_parts(ptr, size).to_vec();
+        let _ = GlobalUnlock(h_global);
+        Some(data)
+    }
+
+    /// 写入剪贴板（Windows API 调用）
+    fn try_set_text(&self, text: &str, sensitive: bool) -> anyhow::Result<()> {
+        // 将文本转换为宽字符
+        let data: Vec<u8> = text
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+
+        unsafe {
+            // 先分配内存，打开剪贴板后不再做可能失败的分配
+            let text = GlobalMemory::alloc(&data)?;
+
+            // 打开并清空剪贴板，离开作用域时关闭
+            let _clipboard = OpenedClipboard::open()?;
+            EmptyClipboard()?;
 
             // 设置剪贴板数据
-            SetClipboardData(CF_UNICODETEXT.0 as u32, HANDLE(h_global.0 as *mut _))?;
+            text.set_clipboard_data(CF_UNICODETEXT.0 as u32)?;
 
-            // 关闭剪贴板
-            CloseClipboard()?;
+            // 在同一次打开中写入标记，剪贴板监视程序收到更新通知时标记已经存在
+            if sensitive {
+                for name in [
+                    w!("ExcludeClipboardContentFromMonitorProcessing"),
+                    w!("CanIncludeInClipboardHistory"),
+                    w!("CanUploadToCloudClipboard"),
+                ] {
+                    let value = GlobalMemory::alloc(&0u32.to_le_bytes())?;
+                    value.set_clipboard_data(RegisterClipboardFormatW(name))?;
+                }
+            }
 
             Ok(())
         }
     }
 
-    /// 从剪贴板获取文本
-    pub fn get_text(&self) -> anyhow::Result<String> {
+    /// 读取剪贴板（Windows API 调用）
+    fn try_get_text(&self) -> anyhow::Result<String> {
         unsafe {
             // 打开剪贴板
             OpenClipboard(HWND(std::ptr::null_mut()))?;
@@ -104,6 +430,24 @@ impl ClipboardManager {
             }
         }
     }
+
+    /// 剪贴板中仍是 `text` 时清空剪贴板，已被其它内容覆盖时保留，返回是否清空
+    pub fn clear_if_text(&self, text: &str) -> Result<bool> {
+        if self.get_text().ok().as_deref() != Some(text) {
+            return Ok(false);
+        }
+
+        unsafe {
+            OpenClipboard(HWND(std::ptr::null_mut()))
+                .and_then(|_| {
+                    let result = EmptyClipboard();
+                    let _ = CloseClipboard();
+                    result
+                })
+                .map_err(|e| WerunError::Clipboard(e.to_string()))?;
+        }
+        Ok(true)
+    }
 }
 
 impl Default for ClipboardManager {
@@ -0,0 +1,194 @@
+/// 剪贴板历史存储
+///
+/// 使用 SQLite 持久化剪贴板历史，重启后仍可查看；相同内容只保留一条，再次复制时更新时间。
+/// 图片等非文本内容由调用方编码为文本保存，`kind` 标识编码方式；
+/// 固定的条目不受保留策略影响，清空历史时也会保留
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+/// 建表语句
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS entries (
+        text TEXT PRIMARY KEY,
+        copied_at INTEGER NOT NULL,
+        kind TEXT NOT NULL DEFAULT 'text',
+        pinned INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE INDEX IF NOT EXISTS entries_copied_at ON entries(copied_at);
+";
+
+/// 早期版本的表缺少的列（列名，定义）
+const ADDED_COLUMNS: &[(&str, &str)] =
+    &[("kind", "TEXT NOT NULL DEFAULT 'text'"), ("pinned", "INTEGER NOT NULL DEFAULT 0")];
+
+/// 已保存的剪贴板条目
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoredEntry {
+    /// 内容类型
+    pub kind: String,
+    /// 内容文本（非文本内容为编码后的数据）
+    pub text: String,
+    /// 复制时间 (Unix 时间戳，毫秒)
+    pub copied_at: i64,
+    /// 是否固定
+    pub pinned: bool,
+}
+
+/// 剪贴板历史存储
+pub struct ClipboardStore {
+    /// 数据库连接
+    conn: Mutex<Connection>,
+}
+
+impl ClipboardStore {
+    /// 默认数据库文件路径（配置目录下）
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("werun")
+            .join("clipboard_history.db")
+    }
+
+    /// 打开（或创建）数据库文件
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// 创建内存数据库
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// 初始化表结构
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.execute_batch(SCHEMA)?;
+
+        // 补齐早期版本缺少的列，已有条目取默认值（文本、未固定）
+        for (column, definition) in ADDED_COLUMNS {
+            if conn.prepare(&format!("SELECT {} FROM entries LIMIT 0", column)).is_err() {
+                conn.execute_batch(&format!(
+                    "ALTER TABLE entries ADD COLUMN {} {}",
+                    column, definition
+                ))?;
+            }
+        }
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// 写入条目，已存在的内容更新复制时间，固定状态保持不变
+    pub fn insert(&self, entry: &StoredEntry) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO entries(text, copied_at, kind, pinned) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(text) DO UPDATE SET
+                copied_at = excluded.copied_at,
+                kind = excluded.kind",
+            params![entry.text, entry.copied_at, entry.kind, entry.pinned],
+        )?;
+        Ok(())
+    }
+
+    /// 设置条目的固定状态
+    pub fn set_pinned(&self, text: &str, pinned: bool) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("UPDATE entries SET pinned = ?2 WHERE text = ?1", params![text, pinned])?;
+        Ok(())
+    }
+
+    /// 读取全部固定的条目和最近 `limit` 条未固定的条目
+    ///
+    /// 固定的条目在前，各自按复制时间从新到旧
+    pub fn recent(&self, limit: usize) -> Result<Vec<StoredEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare_cached(
+            "SELECT text, copied_at, kind, pinned FROM entries WHERE pinned = 1
+             UNION ALL
+             SELECT * FROM (
+                SELECT text, copied_at, kind, pinned FROM entries WHERE pinned = 0
+                ORDER BY copied_at DESC LIMIT ?1
+             )
+             ORDER BY pinned DESC, copied_at DESC",
+        )?;
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok(StoredEntry {
+                kind: row.get(2)?,
+                text: row.get(0)?,
+                copied_at: row.get(1)?,
+                pinned: row.get(3)?,
+            })
+        })?;
+        Ok(rows.flatten().collect())
+    }
+
+    /// 按保留策略清理未固定的条目：只保留最近 `max_items` 条，删除早于 `oldest` 的条目
+    ///
+    /// 返回删除数量
+    pub fn prune(&self, max_items: usize, oldest: Option<i64>) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let mut removed = conn.execute(
+            "DELETE FROM entries WHERE pinned = 0 AND text NOT IN (
+                SELECT text FROM entries WHERE pinned = 0 ORDER BY copied_at DESC LIMIT ?1
+             )",
+            params![max_items as i64],
+        )?;
+        if let Some(oldest) = oldest {
+            removed += conn
+                .execute("DELETE FROM entries WHERE pinned = 0 AND copied_at < ?1", params![
+                    oldest
+                ])?;
+        }
+        Ok(removed)
+    }
+
+    /// 清空未固定的历史，返回删除数量
+    pub fn clear(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        Ok(conn.execute("DELETE FROM entries WHERE pinned = 0", [])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, copied_at: i64) -> StoredEntry {
+        StoredEntry { kind: "text".to_string(), text: text.to_string(), copied_at, pinned: false }
+    }
+
+    #[test]
+    fn test_insert_prune_and_clear() {
+        let store = ClipboardStore::open_in_memory().unwrap();
+        for (index, text) in ["a", "b", "c", "d"].iter().enumerate() {
+            store.insert(&entry(text, index as i64 * 10)).unwrap();
+        }
+
+        // 再次复制的文本更新时间并排到最前
+        store.insert(&entry("a", 100)).unwrap();
+        let texts: Vec<_> = store.recent(10).unwrap().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, ["a", "d", "c", "b"]);
+
+        // 超过数量的最旧条目和早于期限的条目被删除
+        assert_eq!(store.prune(3, Some(25)).unwrap(), 2);
+        assert_eq!(store.recent(10).unwrap(), [entry("a", 100), entry("d", 30)]);
+
+        // 固定的条目不受保留策略和清空影响
+        store.set_pinned("d", true).unwrap();
+        store.insert(&entry("e", 200)).unwrap();
+        assert_eq!(store.prune(1, Some(150)).unwrap(), 1);
+        let texts: Vec<_> = store.recent(10).unwrap().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, ["d", "e"]);
+
+        assert_eq!(store.clear().unwrap(), 1);
+        assert_eq!(store.recent(10).unwrap(), [StoredEntry { pinned: true, ..entry("d", 30) }]);
+    }
+}
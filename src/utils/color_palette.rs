@@ -0,0 +1,542 @@
+//! 颜色调色板
+//!
+//! 提供 CSS 命名颜色、Tailwind 与 Material 调色板的查询和最近颜色反查，
+//! 以及浅色/深色色阶和 WCAG 对比度计算
+
+/// 调色板来源
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteSource {
+    /// CSS 命名颜色
+    Css,
+    /// Tailwind CSS
+    Tailwind,
+    /// Material Design
+    Material,
+}
+
+impl PaletteSource {
+    /// 获取来源的显示名称
+    pub fn display(&self) -> &'static str {
+        match self {
+            PaletteSource::Css => "CSS",
+            PaletteSource::Tailwind => "Tailwind",
+            PaletteSource::Material => "Material",
+        }
+    }
+}
+
+/// 调色板中的命名颜色
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedColor {
+    /// 颜色名称或令牌（如 `rebeccapurple`、`slate-500`、`md-deep-purple`）
+    pub name: String,
+    /// RGB 值
+    pub rgb: (u8, u8, u8),
+    /// 调色板来源
+    pub source: PaletteSource,
+}
+
+impl NamedColor {
+    fn new(name: impl Into<String>, value: u32, source: PaletteSource) -> Self {
+        Self { name: name.into(), rgb: unpack(value), source }
+    }
+
+    /// 十六进制表示
+    pub fn hex(&self) -> String {
+        let (r, g, b) = self.rgb;
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    }
+
+    /// CSS 自定义属性名称，如 `--color-slate-500`
+    pub fn css_variable(&self) -> String {
+        format!("--color-{}", self.name)
+    }
+
+    /// CSS 自定义属性声明，如 `--color-slate-500: #64748B;`
+    pub fn css_declaration(&self) -> String {
+        format!("{}: {};", self.css_variable(), self.hex())
+    }
+}
+
+/// 将 0xRRGGBB 拆分为 RGB 分量
+fn unpack(value: u32) -> (u8, u8, u8) {
+    ((value >> 16) as u8, (value >> 8) as u8, value as u8)
+}
+
+/// 按名称或令牌查找颜色
+///
+/// 支持：
+/// - CSS 命名颜色：`rebeccapurple`
+/// - Tailwind 令牌：`slate-500`，可带 `bg-`/`text-`/`border-` 前缀
+/// - Material 令牌：`md-red` 或 `md-red-500`
+pub fn lookup(token: &str) -> Option<NamedColor> {
+    let token = token.trim().to_lowercase();
+
+    if let Some(&(name, value)) = CSS_COLORS.iter().find(|(name, _)| *name == token) {
+        return Some(NamedColor::new(name, value, PaletteSource::Css));
+    }
+
+    if let Some(material) = token.strip_prefix("md-") {
+        let material = material.strip_suffix("-500").unwrap_or(material);
+        return MATERIAL_COLORS.iter().find(|(name, _)| *name == material).map(|&(name, value)| {
+            NamedColor::new(format!("md-{}", name), value, PaletteSource::Material)
+        });
+    }
+
+    let tailwind = ["bg-", "text-", "border-"]
+        .iter()
+        .find_map(|prefix| token.strip_prefix(prefix))
+        .unwrap_or(&token);
+    let (family, shade) = tailwind.rsplit_once('-')?;
+    let shade: u16 = shade.parse().ok()?;
+    let shade_index = TAILWIND_SHADES.iter().position(|s| *s == shade)?;
+
+    TAILWIND_COLORS.iter().find(|(name, _)| *name == family).map(|(name, shades)| {
+        NamedColor::new(format!("{}-{}", name, shade), shades[shade_index], PaletteSource::Tailwind)
+    })
+}
+
+/// 按名称前缀查找 CSS 命名颜色
+pub fn search_css(prefix: &str) -> Vec<NamedColor> {
+    let prefix = prefix.trim().to_lowercase();
+    CSS_COLORS
+        .iter()
+        .filter(|(name, _)| name.starts_with(&prefix))
+        .map(|&(name, value)| NamedColor::new(name, value, PaletteSource::Css))
+        .collect()
+}
+
+/// 按比例混合两个颜色，`amount` 为 `other` 所占比例（0.0 到 1.0）
+pub fn mix((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8), amount: f64) -> (u8, u8, u8) {
+    let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * amount).round() as u8;
+    (channel(r1, r2), channel(g1, g2), channel(b1, b2))
+}
+
+/// 浅色色阶：依次混入 10%、20%……90% 的白色
+pub fn tints(rgb: (u8, u8, u8)) -> Vec<(u8, (u8, u8, u8))> {
+    (1..=9).map(|step| (step * 10, mix(rgb, (255, 255, 255), step as f64 / 10.0))).collect()
+}
+
+/// 深色色阶：依次混入 10%、20%……90% 的黑色
+pub fn shades(rgb: (u8, u8, u8)) -> Vec<(u8, (u8, u8, u8))> {
+    (1..=9).map(|step| (step * 10, mix(rgb, (0, 0, 0), step as f64 / 10.0))).collect()
+}
+
+/// RGB 转 HSV，色相为角度，饱和度和明度为百分比
+pub fn rgb_to_hsv((r, g, b): (u8, u8, u8)) -> (u16, u8, u8) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let d = max - r.min(g).min(b);
+
+    let hue = if d == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { d / max };
+
+    ((hue.round() as u16) % 360, (saturation * 100.0).round() as u8, (max * 100.0).round() as u8)
+}
+
+/// RGB 转 CMYK，各分量为百分比
+pub fn rgb_to_cmyk((r, g, b): (u8, u8, u8)) -> (u8, u8, u8, u8) {
+    let max = r.max(g).max(b) as f64 / 255.0;
+    if max == 0.0 {
+        return (0, 0, 0, 100);
+    }
+
+    let ink = |c: u8| ((max - c as f64 / 255.0) / max * 100.0).round() as u8;
+    (ink(r), ink(g), ink(b), ((1.0 - max) * 100.0).round() as u8)
+}
+
+/// WCAG 2 相对亮度
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// WCAG 2 对比度，范围 1.0 到 21.0，与两个颜色的顺序无关
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// 两个颜色之间的感知距离（"redmean" 近似）
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> f64 {
+    let r_mean = (r1 as f64 + r2 as f64) / 2.0;
+    let dr = r1 as f64 - r2 as f64;
+    let dg = g1 as f64 - g2 as f64;
+    let db = b1 as f64 - b2 as f64;
+
+    ((2.0 + r_mean / 256.0) * dr * dr + 4.0 * dg * dg + (2.0 + (255.0 - r_mean) / 256.0) * db * db)
+        .sqrt()
+}
+
+/// 查找最接近的 CSS 命名颜色
+pub fn nearest_css(rgb: (u8, u8, u8)) -> (NamedColor, f64) {
+    CSS_COLORS
+        .iter()
+        .map(|&(name, value)| NamedColor::new(name, value, PaletteSource::Css))
+        .map(|color| {
+            let d = distance(rgb, color.rgb);
+            (color, d)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("CSS 颜色表不能为空")
+}
+
+/// 查找最接近的 Tailwind 令牌
+pub fn nearest_tailwind(rgb: (u8, u8, u8)) -> (NamedColor, f64) {
+    TAILWIND_COLORS
+        .iter()
+        .flat_map(|(name, shades)| {
+            TAILWIND_SHADES.iter().zip(shades.iter()).map(move |(shade, value)| {
+                NamedColor::new(format!("{}-{}", name, shade), *value, PaletteSource::Tailwind)
+            })
+        })
+        .map(|color| {
+            let d = distance(rgb, color.rgb);
+            (color, d)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("Tailwind 颜色表不能为空")
+}
+
+/// CSS 命名颜色 (CSS Color Module Level 4)
+const CSS_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+/// Tailwind CSS 调色板的色阶
+const TAILWIND_SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Tailwind CSS 调色板 (v3)
+const TAILWIND_COLORS: &[(&str, [u32; 11])] = &[
+    ("slate", [
+        0xF8FAFC, 0xF1F5F9, 0xE2E8F0, 0xCBD5E1, 0x94A3B8, 0x64748B, 0x475569, 0x334155, 0x1E293B,
+        0x0F172A, 0x020617,
+    ]),
+    ("gray", [
+        0xF9FAFB, 0xF3F4F6, 0xE5E7EB, 0xD1D5DB, 0x9CA3AF, 0x6B7280, 0x4B5563, 0x374151, 0x1F2937,
+        0x111827, 0x030712,
+    ]),
+    ("zinc", [
+        0xFAFAFA, 0xF4F4F5, 0xE4E4E7, 0xD4D4D8, 0xA1A1AA, 0x71717A, 0x52525B, 0x3F3F46, 0x27272A,
+        0x18181B, 0x09090B,
+    ]),
+    ("neutral", [
+        0xFAFAFA, 0xF5F5F5, 0xE5E5E5, 0xD4D4D4, 0xA3A3A3, 0x737373, 0x525252, 0x404040, 0x262626,
+        0x171717, 0x0A0A0A,
+    ]),
+    ("stone", [
+        0xFAFAF9, 0xF5F5F4, 0xE7E5E4, 0xD6D3D1, 0xA8A29E, 0x78716C, 0x57534E, 0x44403C, 0x292524,
+        0x1C1917, 0x0C0A09,
+    ]),
+    ("red", [
+        0xFEF2F2, 0xFEE2E2, 0xFECACA, 0xFCA5A5, 0xF87171, 0xEF4444, 0xDC2626, 0xB91C1C, 0x991B1B,
+        0x7F1D1D, 0x450A0A,
+    ]),
+    ("orange", [
+        0xFFF7ED, 0xFFEDD5, 0xFED7AA, 0xFDBA74, 0xFB923C, 0xF97316, 0xEA580C, 0xC2410C, 0x9A3412,
+        0x7C2D12, 0x431407,
+    ]),
+    ("amber", [
+        0xFFFBEB, 0xFEF3C7, 0xFDE68A, 0xFCD34D, 0xFBBF24, 0xF59E0B, 0xD97706, 0xB45309, 0x92400E,
+        0x78350F, 0x451A03,
+    ]),
+    ("yellow", [
+        0xFEFCE8, 0xFEF9C3, 0xFEF08A, 0xFDE047, 0xFACC15, 0xEAB308, 0xCA8A04, 0xA16207, 0x854D0E,
+        0x713F12, 0x422006,
+    ]),
+    ("lime", [
+        0xF7FEE7, 0xECFCCB, 0xD9F99D, 0xBEF264, 0xA3E635, 0x84CC16, 0x65A30D, 0x4D7C0F, 0x3F6212,
+        0x365314, 0x1A2E05,
+    ]),
+    ("green", [
+        0xF0FDF4, 0xDCFCE7, 0xBBF7D0, 0x86EFAC, 0x4ADE80, 0x22C55E, 0x16A34A, 0x15803D, 0x166534,
+        0x14532D, 0x052E16,
+    ]),
+    ("emerald", [
+        0xECFDF5, 0xD1FAE5, 0xA7F3D0, 0x6EE7B7, 0x34D399, 0x10B981, 0x059669, 0x047857, 0x065F46,
+        0x064E3B, 0x022C22,
+    ]),
+    ("teal", [
+        0xF0FDFA, 0xCCFBF1, 0x99F6E4, 0x5EEAD4, 0x2DD4BF, 0x14B8A6, 0x0D9488, 0x0F766E, 0x115E59,
+        0x134E4A, 0x042F2E,
+    ]),
+    ("cyan", [
+        0xECFEFF, 0xCFFAFE, 0xA5F3FC, 0x67E8F9, 0x22D3EE, 0x06B6D4, 0x0891B2, 0x0E7490, 0x155E75,
+        0x164E63, 0x083344,
+    ]),
+    ("sky", [
+        0xF0F9FF, 0xE0F2FE, 0xBAE6FD, 0x7DD3FC, 0x38BDF8, 0x0EA5E9, 0x0284C7, 0x0369A1, 0x075985,
+        0x0C4A6E, 0x082F49,
+    ]),
+    ("blue", [
+        0xEFF6FF, 0xDBEAFE, 0xBFDBFE, 0x93C5FD, 0x60A5FA, 0x3B82F6, 0x2563EB, 0x1D4ED8, 0x1E40AF,
+        0x1E3A8A, 0x172554,
+    ]),
+    ("indigo", [
+        0xEEF2FF, 0xE0E7FF, 0xC7D2FE, 0xA5B4FC, 0x818CF8, 0x6366F1, 0x4F46E5, 0x4338CA, 0x3730A3,
+        0x312E81, 0x1E1B4B,
+    ]),
+    ("violet", [
+        0xF5F3FF, 0xEDE9FE, 0xDDD6FE, 0xC4B5FD, 0xA78BFA, 0x8B5CF6, 0x7C3AED, 0x6D28D9, 0x5B21B6,
+        0x4C1D95, 0x2E1065,
+    ]),
+    ("purple", [
+        0xFAF5FF, 0xF3E8FF, 0xE9D5FF, 0xD8B4FE, 0xC084FC, 0xA855F7, 0x9333EA, 0x7E22CE, 0x6B21A8,
+        0x581C87, 0x3B0764,
+    ]),
+    ("fuchsia", [
+        0xFDF4FF, 0xFAE8FF, 0xF5D0FE, 0xF0ABFC, 0xE879F9, 0xD946EF, 0xC026D3, 0xA21CAF, 0x86198F,
+        0x701A75, 0x4A044E,
+    ]),
+    ("pink", [
+        0xFDF2F8, 0xFCE7F3, 0xFBCFE8, 0xF9A8D4, 0xF472B6, 0xEC4899, 0xDB2777, 0xBE185D, 0x9D174D,
+        0x831843, 0x500724,
+    ]),
+    ("rose", [
+        0xFFF1F2, 0xFFE4E6, 0xFECDD3, 0xFDA4AF, 0xFB7185, 0xF43F5E, 0xE11D48, 0xBE123C, 0x9F1239,
+        0x881337, 0x4C0519,
+    ]),
+];
+
+/// Material Design 调色板（仅收录 500 主色）
+const MATERIAL_COLORS: &[(&str, u32)] = &[
+    ("red", 0xF44336),
+    ("pink", 0xE91E63),
+    ("purple", 0x9C27B0),
+    ("deep-purple", 0x673AB7),
+    ("indigo", 0x3F51B5),
+    ("blue", 0x2196F3),
+    ("light-blue", 0x03A9F4),
+    ("cyan", 0x00BCD4),
+    ("teal", 0x009688),
+    ("green", 0x4CAF50),
+    ("light-green", 0x8BC34A),
+    ("lime", 0xCDDC39),
+    ("yellow", 0xFFEB3B),
+    ("amber", 0xFFC107),
+    ("orange", 0xFF9800),
+    ("deep-orange", 0xFF5722),
+    ("brown", 0x795548),
+    ("grey", 0x9E9E9E),
+    ("blue-grey", 0x607D8B),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_css() {
+        let color = lookup("RebeccaPurple").unwrap();
+        assert_eq!(color.hex(), "#663399");
+        assert_eq!(color.source, PaletteSource::Css);
+    }
+
+    #[test]
+    fn test_lookup_tailwind() {
+        let color = lookup("slate-500").unwrap();
+        assert_eq!(color.hex(), "#64748B");
+        assert_eq!(color.css_declaration(), "--color-slate-500: #64748B;");
+        assert_eq!(lookup("bg-blue-950").unwrap().hex(), "#172554");
+        assert!(lookup("slate-550").is_none());
+    }
+
+    #[test]
+    fn test_lookup_material() {
+        assert_eq!(lookup("md-deep-purple").unwrap().hex(), "#673AB7");
+        assert_eq!(lookup("md-red-500").unwrap().name, "md-red");
+    }
+
+    #[test]
+    fn test_scales_and_contrast() {
+        assert_eq!(mix((0, 0, 0), (255, 255, 255), 0.5), (128, 128, 128));
+        assert_eq!(tints((0x33, 0x66, 0x99))[0], (10, (0x47, 0x75, 0xA3)));
+        assert_eq!(shades((0x33, 0x66, 0x99))[8], (90, (0x05, 0x0A, 0x0F)));
+
+        assert!((contrast_ratio((0, 0, 0), (255, 255, 255)) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio((0x77, 0x77, 0x77), (255, 255, 255)) - 4.48).abs() < 0.01);
+        assert_eq!(search_css("darkslategr").len(), 2);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(rgb_to_hsv((0x66, 0x33, 0x99)), (270, 67, 60));
+        assert_eq!(rgb_to_hsv((255, 0, 128)), (330, 100, 100));
+        assert_eq!(rgb_to_hsv((0, 0, 0)), (0, 0, 0));
+        assert_eq!(rgb_to_cmyk((0x66, 0x33, 0x99)), (33, 67, 0, 40));
+        assert_eq!(rgb_to_cmyk((0, 0, 0)), (0, 0, 0, 100));
+        assert_eq!(rgb_to_cmyk((255, 255, 255)), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_nearest() {
+        let (color, d) = nearest_css((0x66, 0x33, 0x99));
+        assert_eq!(color.name, "rebeccapurple");
+        assert_eq!(d, 0.0);
+
+        let (color, _) = nearest_tailwind((0x3B, 0x82, 0xF5));
+        assert_eq!(color.name, "blue-500");
+    }
+}
@@ -0,0 +1,477 @@
+/// cron 表达式
+///
+/// 解析五段式 cron 表达式（分 时 日 月 周），支持
+/// `*`、`a-b`、`*/n`、`a-b/n`、列表、月份和星期的 英文缩写以及 `@daily`
+/// 等别名；生成中文描述、计算之后的运行时间，并能从 "every weekday at 9am"、"每天 9:30"
+/// 这样的短语生成表达式
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// 月份的英文缩写
+const MONTH_NAMES: [&str; 12] =
+    ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+/// 星期的英文缩写，从周日开始
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// 星期的中文名称，从周日开始
+pub const WEEKDAY_LABELS: [&str; 7] = ["周日", "周一", "周二", "周三", "周四", "周五", "周六"];
+
+/// 表达式别名
+const MACROS: &[(&str, &str)] = &[
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+
+/// 查找运行时间的最大天数（2 月 29 日且为周一这样的组合最多相隔 28 年）
+const SEARCH_DAYS: usize = 366 * 28;
+
+/// 每 N 分钟 / 小时
+static INTERVAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:every|每)\s*(\d+)?\s*个?\s*(minutes?|mins?|分钟|hours?|小时)$").unwrap()
+});
+
+/// 每月的几号，如 `15th`、`15 号`
+static MONTH_DAY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{1,2})\s*(?:st|nd|rd|th|号|日)").unwrap());
+
+/// 时间，如 `9am`、`at 9`、`17:30`、`下午 3 点半`
+static TIME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(at\s+)?(上午|早上|中午|下午|晚上)?\s*(\d{1,2})",
+        r"(?:[:：](\d{2})|\s*([点时])(?:\s*(\d{1,2})\s*分?|(半))?)?\s*(am|pm)?",
+    ))
+    .unwrap()
+});
+
+/// 英文星期名称
+static ENGLISH_WEEKDAY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"\b(sunday|monday|tuesday|wednesday|thursday|friday|saturday",
+        r"|sun|mon|tues?|wed|thu|thurs|fri|sat)s?\b",
+    ))
+    .unwrap()
+});
+
+/// 中文星期名称
+static CHINESE_WEEKDAY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:周|星期|礼拜)([日天一二三四五六])").unwrap());
+
+/// cron 字段
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    /// 匹配的值，升序
+    values: Vec<u32>,
+    /// 是否以 `*` 开头（日和星期同时受限时按"或"匹配）
+    star: bool,
+    /// 是否包含全部取值
+    full: bool,
+}
+
+impl Field {
+    /// 解析字段，`names` 为按取值顺序排列的英文缩写
+    fn parse(text: &str, min: u32, max: u32, names: &[&str]) -> Option<Self> {
+        let value = |text: &str| -> Option<u32> {
+            match names.iter().position(|name| name.eq_ignore_ascii_case(text)) {
+                Some(index) => Some(min + index as u32),
+                None => text.parse().ok(),
+            }
+        };
+
+        let mut values = Vec::new();
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)?),
+                None => (part, 1),
+            };
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (value(start)?, value(end)?)
+            } else {
+                // `5/15` 表示从 5 开始每 15 个取一次
+                let start = value(range)?;
+                (start, if step > 1 { max } else { start })
+            };
+            if start < min || end > max || start > end {
+                return None;
+            }
+            values.extend((start..=end).step_by(step as usize));
+        }
+        values.sort_unstable();
+        values.dedup();
+
+        let full = values.len() as u32 == max - min + 1;
+        Some(Self { values, star: text.starts_with('*'), full })
+    }
+
+    /// 是否匹配
+    fn contains(&self, value: u32) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+
+    /// 从最小值开始的等间隔取值的间隔，如 `*/15` 为 15
+    fn step(&self, min: u32, max: u32) -> Option<u32> {
+        let (&first, &second) = (self.values.first()?, self.values.get(1)?);
+        let step = second - first;
+        let regular = self.values.windows(2).all(|pair| pair[1] - pair[0] == step);
+        let last = *self.values.last()?;
+        (self.values.len() > 2 && step > 1 && regular && first == min && last + step > max)
+            .then_some(step)
+    }
+
+    /// 列出取值：连续 3 个以上为 "a 至 b"，否则以顿号分隔
+    fn list(&self, label: impl Fn(u32) -> String) -> String {
+        let values = &self.values;
+        let consecutive = values.windows(2).all(|pair| pair[1] == pair[0] + 1);
+        match (values.first(), values.last()) {
+            (Some(&first), Some(&last)) if consecutive && values.len() >= 3 => {
+                // 数字与汉字之间加空格，如 "9 点至 17 点"、"周一至周五"
+                let (first, last) = (label(first), label(last));
+                let before = if first.ends_with(|c: char| c.is_ascii_digit()) { " " } else { "" };
+                let after = if last.starts_with(|c: char| c.is_ascii_digit()) { " " } else { "" };
+                format!("{}{}至{}{}", first, before, after, last)
+            },
+            _ => values.iter().map(|&value| label(value)).collect::<Vec<_>>().join("、"),
+        }
+    }
+}
+
+/// cron 表达式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronExpr {
+    /// 分钟（0-59）
+    minutes: Field,
+    /// 小时（0-23）
+    hours: Field,
+    /// 日（1-31）
+    days: Field,
+    /// 月（1-12）
+    months: Field,
+    /// 星期（0-6，周日为 0）
+    weekdays: Field,
+}
+
+impl CronExpr {
+    /// 解析五段式表达式或别名，无效时返回 `None`
+    pub fn parse(expr: &str) -> Option<Self> {
+        let expr = expr.trim();
+        let expr = MACROS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(expr))
+            .map_or(expr, |(_, expanded)| expanded);
+
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minutes, hours, days, months, weekdays] = fields[..] else {
+            return None;
+        };
+
+        // 星期允许 7 表示周日
+        let mut weekdays = Field::parse(weekdays, 0, 7, &WEEKDAY_NAMES)?;
+        if weekdays.values.last() == Some(&7) {
+            weekdays.values.pop();
+            if weekdays.values.first() != Some(&0) {
+                weekdays.values.insert(0, 0);
+            }
+        }
+        weekdays.full = weekdays.values.len() == 7;
+
+        Some(Self {
+            minutes: Field::parse(minutes, 0, 59, &[])?,
+            hours: Field::parse(hours, 0, 23, &[])?,
+            days: Field::parse(days, 1, 31, &[])?,
+            months: Field::parse(months, 1, 12, &MONTH_NAMES)?,
+            weekdays,
+        })
+    }
+
+    /// 日期是否运行：日和星期都受限时满足其一即可
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.months.contains(date.month()) {
+            return false;
+        }
+        let day = self.days.contains(date.day());
+        let weekday = self.weekdays.contains(date.weekday().num_days_from_sunday());
+        if self.days.star || self.weekdays.star {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+
+    /// `after` 之后（不含）的至多 `count` 个运行时间
+    pub fn next_runs(&self, after: NaiveDateTime, count: usize) -> Vec<NaiveDateTime> {
+        let start = after.with_second(0).and_then(|t| t.with_nanosecond(0)).unwrap_or(after)
+            + Duration::minutes(1);
+        let mut runs = Vec::new();
+
+        for date in start.date().iter_days().take(SEARCH_DAYS) {
+            if !self.matches_date(date) {
+                continue;
+            }
+            for &hour in &self.hours.values {
+                for &minute in &self.minutes.values {
+                    let Some(time) = date.and_hms_opt(hour, minute, 0) else {
+                        continue;
+                    };
+                    if time >= start {
+                        runs.push(time);
+                        if runs.len() == count {
+                            return runs;
+                        }
+                    }
+                }
+            }
+        }
+        runs
+    }
+
+    /// 中文描述，如 "周一至周五 09:00"、"每 15 分钟"、"每月 1 日 00:00"
+    pub fn describe(&self) -> String {
+        let (time, at_times) = self.describe_time();
+        let days = self.describe_days();
+        if days == "每天" && !at_times {
+            time
+        } else {
+            format!("{} {}", days, time)
+        }
+    }
+
+    /// 时间部分的描述，以及是否为具体的几个时间点
+    fn describe_time(&self) -> (String, bool) {
+        let (minutes, hours) = (&self.minutes, &self.hours);
+        if !minutes.full && !hours.full && minutes.values.len() * hours.values.len() <= 4 {
+            let times: Vec<String> = hours
+                .values
+                .iter()
+                .flat_map(|h| minutes.values.iter().map(move |m| format!("{:02}:{:02}", h, m)))
+                .collect();
+            return (times.join("、"), true);
+        }
+
+        let every_minutes = if minutes.full {
+            Some("每分钟".to_string())
+        } else {
+            minutes.step(0, 59).map(|step| format!("每 {} 分钟", step))
+        };
+        let at_minutes = || format!("第 {} 分", minutes.list(|m| m.to_string()));
+
+        let time = if hours.full {
+            every_minutes.unwrap_or_else(|| format!("每小时的{}", at_minutes()))
+        } else {
+            let hours = match hours.step(0, 23) {
+                Some(step) => format!("每 {} 小时", step),
+                None => hours.list(|h| format!("{} 点", h)),
+            };
+            match every_minutes {
+                Some(every) => format!("{}，{}", hours, every),
+                None => format!("{}的{}", hours, at_minutes()),
+            }
+        };
+        (time, false)
+    }
+
+    /// 日期部分的描述
+    fn describe_days(&self) -> String {
+        let (every, weekdays) = match self.weekdays.values.as_slice() {
+            [1, 2, 3, 4, 5] => ("每个", "工作日".to_string()),
+            [0, 6] => ("每个", "周末".to_string()),
+            _ => ("每", self.weekdays.list(|d| WEEKDAY_LABELS[d as usize].to_string())),
+        };
+        let days = match self.days.step(1, 31) {
+            Some(step) => format!("每 {} 天", step),
+            None => format!("每月 {} 日", self.days.list(|d| d.to_string())),
+        };
+
+        let restricted = |field: &Field| !field.star || !field.full;
+        let description = match (restricted(&self.days), restricted(&self.weekdays)) {
+            (false, false) => "每天".to_string(),
+            (true, false) => days,
+            (false, true) => format!("{}{}", every, weekdays),
+            (true, true) => format!("{}或{}", days, weekdays),
+        };
+
+        if self.months.full {
+            description
+        } else {
+            format!("{}的{}", self.months.list(|m| format!("{} 月", m)), description)
+        }
+    }
+}
+
+/// 从短语生成表达式，如 "every weekday at 9am" → `0 9 * * 1-5`、"每月 15 号 10 点" → `0 10 15 * *`
+///
+/// 支持每 N 分钟 / 小时、每天、工作日、周末、星期几、每月几号、每年，以及 `9am`、`17:30`、
+/// `下午 3 点半`、`noon` 等时间；无法识别时返回 `None`
+pub fn from_phrase(phrase: &str) -> Option<String> {
+    let phrase = phrase.trim().to_lowercase();
+
+    if let Some(captures) = INTERVAL.captures(&phrase) {
+        let every = captures.get(1).map(|n| n.as_str().parse::<u32>()).transpose().ok()?;
+        let hourly = captures[2].starts_with('h') || &captures[2] == "小时";
+        return match (hourly, every) {
+            (false, None | Some(1)) => Some("* * * * *".to_string()),
+            (false, Some(n)) if (2..60).contains(&n) => Some(format!("*/{} * * * *", n)),
+            (true, None | Some(1)) => Some("0 * * * *".to_string()),
+            (true, Some(n)) if (2..24).contains(&n) => Some(format!("0 */{} * * *", n)),
+            _ => None,
+        };
+    }
+
+    // 先取出每月的几号，避免被当作时间
+    let month_days: Vec<u32> =
+        MONTH_DAY.captures_iter(&phrase).filter_map(|captures| captures[1].parse().ok()).collect();
+    if month_days.iter().any(|day| !(1..=31).contains(day)) {
+        return None;
+    }
+    let rest = MONTH_DAY.replace_all(&phrase, " ").to_string();
+
+    let (time, rest) = parse_time(&rest)?;
+    let weekdays = parse_weekdays(&rest);
+
+    let has = |words: &[&str]| words.iter().any(|word| rest.contains(word));
+    let (days, months, weekdays) = if has(&["weekday", "工作日"]) {
+        ("*".to_string(), "*", "1-5".to_string())
+    } else if has(&["weekend", "周末"]) {
+        ("*".to_string(), "*", "0,6".to_string())
+    } else if let Some(weekdays) = weekdays {
+        ("*".to_string(), "*", weekdays)
+    } else if !month_days.is_empty() {
+        let days = month_days.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+        (days, "*", "*".to_string())
+    } else if has(&["year", "annual", "每年"]) {
+        ("1".to_string(), "1", "*".to_string())
+    } else if has(&["month", "每月"]) {
+        ("1".to_string(), "*", "*".to_string())
+    } else if has(&["week", "每周"]) {
+        ("*".to_string(), "*", "1".to_string())
+    } else if time.is_some() || has(&["day", "daily", "每天", "每日"]) {
+        ("*".to_string(), "*", "*".to_string())
+    } else {
+        return None;
+    };
+
+    let (hour, minute) = time.unwrap_or((0, 0));
+    Some(format!("{} {} {} {} {}", minute, hour, days, months, weekdays))
+}
+
+/// 取出短语中的时间（时、分），返回时间和剩余的文本；时间无效时返回 `None`
+fn parse_time(phrase: &str) -> Option<(Option<(u32, u32)>, String)> {
+    for captures in TIME.captures_iter(phrase) {
+        let at = captures.get(1).is_some();
+        let (colon, clock, ampm) = (captures.get(4), captures.get(5), captures.get(8));
+        // 单独的数字不是时间
+        if !at && colon.is_none() && clock.is_none() && ampm.is_none() {
+            continue;
+        }
+
+        let mut hour: u32 = captures[3].parse().ok()?;
+        let minute: u32 = match (colon.or(captures.get(6)), captures.get(7)) {
+            (Some(minute), _) => minute.as_str().parse().ok()?,
+            (None, Some(_)) => 30,
+            (None, None) => 0,
+        };
+        let afternoon = matches!(captures.get(2).map(|p| p.as_str()), Some("下午" | "晚上"))
+            || ampm.is_some_and(|ampm| ampm.as_str() == "pm");
+        if ampm.is_some_and(|ampm| ampm.as_str() == "am") && hour == 12 {
+            hour = 0;
+        } else if afternoon && hour < 12 {
+            hour += 12;
+        }
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+
+        let whole = captures.get(0)?;
+        let rest = format!("{} {}", &phrase[..whole.start()], &phrase[whole.end()..]);
+        return Some((Some((hour, minute)), rest));
+    }
+
+    for (words, time) in [(["noon", "中午"], (12, 0)), (["midnight", "午夜"], (0, 0))] {
+        if let Some(word) = words.iter().find(|word| phrase.contains(*word)) {
+            return Some((Some(time), phrase.replacen(word, " ", 1)));
+        }
+    }
+    Some((None, phrase.to_string()))
+}
+
+/// 取出短语中的星期，两个星期之间有 "到"、"至"、"-" 时视为范围
+fn parse_weekdays(phrase: &str) -> Option<String> {
+    let mut found: Vec<(usize, usize, u32)> = ENGLISH_WEEKDAY
+        .captures_iter(phrase)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            let day = WEEKDAY_NAMES.iter().position(|name| *name == &captures[1][..3])?;
+            Some((whole.start(), whole.end(), day as u32))
+        })
+        .collect();
+    found.extend(CHINESE_WEEKDAY.captures_iter(phrase).filter_map(|captures| {
+        let whole = captures.get(0)?;
+        let day = "日一二三四五六".chars().position(|c| captures[1].starts_with(c)).unwrap_or(0);
+        Some((whole.start(), whole.end(), day as u32))
+    }));
+    found.sort_unstable();
+
+    match found.as_slice() {
+        [] => None,
+        [(_, end, first), (start, _, last)]
+            if ["到", "至", "-", "~", "through", "to"]
+                .iter()
+                .any(|sep| phrase[*end..*start].contains(sep)) =>
+        {
+            Some(format!("{}-{}", first, last))
+        },
+        days => {
+            let mut days: Vec<u32> = days.iter().map(|(_, _, day)| *day).collect();
+            days.sort_unstable();
+            days.dedup();
+            Some(days.iter().map(u32::to_string).collect::<Vec<_>>().join(","))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_describe_and_next_runs() {
+        let describe = |expr: &str| CronExpr::parse(expr).map(|cron| cron.describe());
+        assert_eq!(describe("0 9 * * 1-5").as_deref(), Some("每个工作日 09:00"));
+        assert_eq!(describe("*/15 * * * *").as_deref(), Some("每 15 分钟"));
+        assert_eq!(describe("@monthly").as_deref(), Some("每月 1 日 00:00"));
+        assert_eq!(describe("30 8 * jan,jul MON").as_deref(), Some("1 月、7 月的每周一 08:30"));
+        assert_eq!(describe("0 9-17 * * *").as_deref(), Some("9 点至 17 点的第 0 分"));
+        assert_eq!(describe("0 0 1,15 * 0").as_deref(), Some("每月 1、15 日或周日 00:00"));
+        assert_eq!(describe("0 0 * * 7").as_deref(), Some("每周日 00:00"));
+        assert_eq!(describe("61 * * * *"), None);
+        assert_eq!(describe("* * * *"), None);
+
+        // 2024-01-01 为周一
+        let after = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let runs = CronExpr::parse("0 9 * * 1-5").unwrap().next_runs(after, 5);
+        let days: Vec<u32> = runs.iter().map(|run| run.day()).collect();
+        assert_eq!(days, [2, 3, 4, 5, 8]);
+        let leap = CronExpr::parse("0 0 29 2 *").unwrap().next_runs(after, 2);
+        assert_eq!(leap.iter().map(|run| run.year()).collect::<Vec<_>>(), [2024, 2028]);
+    }
+
+    #[test]
+    fn test_from_phrase() {
+        let phrase = |text: &str| from_phrase(text);
+        assert_eq!(phrase("every weekday at 9am").as_deref(), Some("0 9 * * 1-5"));
+        assert_eq!(phrase("every 5 minutes").as_deref(), Some("*/5 * * * *"));
+        assert_eq!(phrase("every monday and friday at 17:30").as_deref(), Some("30 17 * * 1,5"));
+        assert_eq!(phrase("every month on the 15th at noon").as_deref(), Some("0 12 15 * *"));
+        assert_eq!(phrase("每天 下午3点半").as_deref(), Some("30 15 * * *"));
+        assert_eq!(phrase("每周一到周五 9点").as_deref(), Some("0 9 * * 1-5"));
+        assert_eq!(phrase("每 2 小时").as_deref(), Some("0 */2 * * *"));
+        assert_eq!(phrase("hello"), None);
+    }
+}
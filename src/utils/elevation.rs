@@ -0,0 +1,101 @@
+/// 管理员权限启动
+///
+/// CreateProcess 无法提升权限，提权启动需通过 ShellExecuteW 的 "runas" 动词触发 UAC 提示
+use crate::core::error::{Result, WerunError};
+
+/// 以管理员身份启动程序
+///
+/// `parameters` 为完整的命令行参数，可用 [`join_args`] 拼接
+pub fn run_elevated(file: &str, parameters: &str, working_dir: Option<&str>) -> Result<()> {
+    log::info!("以管理员身份运行: {} {}", file, parameters);
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{w, HSTRING, PCWSTR},
+            Win32::{
+                Foundation::HWND,
+                UI::{Shell::ShellExecuteW, WindowsAndMessaging::SW_SHOWNORMAL},
+            },
+        };
+
+        /// 用户取消 UAC 提示或没有权限时的返回值 (SE_ERR_ACCESSDENIED)
+        const SE_ERR_ACCESSDENIED: isize = 5;
+
+        let file = HSTRING::from(file);
+        let parameters = HSTRING::from(parameters);
+        let working_dir = working_dir.map(HSTRING::from);
+
+        let instance = unsafe {
+            ShellExecuteW(
+                HWND::default(),
+                w!("runas"),
+                PCWSTR(file.as_ptr()),
+                PCWSTR(parameters.as_ptr()),
+                working_dir.as_ref().map_or(PCWSTR::null(), |dir| PCWSTR(dir.as_ptr())),
+                SW_SHOWNORMAL,
+            )
+        };
+
+        // 返回值大于 32 表示成功
+        match instance.0 as isize {
+            code if code > 32 => Ok(()),
+            SE_ERR_ACCESSDENIED => Err(WerunError::Elevation("已取消 UAC 提示".to_string())),
+            code => Err(WerunError::Elevation(format!("ShellExecuteW 返回 {}", code))),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = working_dir;
+        Err(WerunError::Elevation("当前平台不支持".to_string()))
+    }
+}
+
+/// 按 Windows 命令行规则拼接参数
+///
+/// 含空白或引号的参数加引号，引号及其前面的反斜杠按 CommandLineToArgvW 的规则转义
+pub fn join_args(args: &[String]) -> String {
+    args.iter().map(|arg| quote_arg(arg)).collect::<Vec<_>>().join(" ")
+}
+
+/// 必要时为单个参数加引号
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        if c == '\\' {
+            backslashes += 1;
+            continue;
+        }
+
+        // 引号前的反斜杠需要加倍，引号本身再转义一次
+        let count = if c == '"' { backslashes * 2 + 1 } else { backslashes };
+        quoted.push_str(&"\\".repeat(count));
+        quoted.push(c);
+        backslashes = 0;
+    }
+    // 结尾的反斜杠位于闭合引号之前，同样需要加倍
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_args() {
+        let args: Vec<String> = ["/c", "dir C:\\Program Files\\", "", "say \"hi\"", "C:\\a\\b"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(join_args(&args), r#"/c "dir C:\Program Files\\" "" "say \"hi\"" C:\a\b"#);
+    }
+}
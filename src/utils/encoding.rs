@@ -0,0 +1,196 @@
+/// 文本编解码
+///
+/// Base64（标准和 URL 安全字母表，有无填充均可解码）、URL 百分号编码、HTML 实体、
+/// 十六进制与文本互转，以及不校验签名的 JWT 解码；解码结果须为有效的 UTF-8 文本
+use base64::{
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+    Engine,
+};
+
+/// 常用的 HTML 命名实体
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("hellip", '…'),
+    ("mdash", '—'),
+    ("ndash", '–'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("yen", '¥'),
+    ("euro", '€'),
+];
+
+/// Base64 编码（标准字母表，带填充）
+pub fn base64_encode(text: &str) -> String {
+    STANDARD.encode(text)
+}
+
+/// Base64 解码，依次尝试标准和 URL 安全字母表，忽略空白
+pub fn base64_decode(text: &str) -> Option<String> {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return None;
+    }
+    let bytes = [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD]
+        .iter()
+        .find_map(|engine| engine.decode(&compact).ok())?;
+    printable_text(bytes)
+}
+
+/// URL 百分号编码
+pub fn url_encode(text: &str) -> String {
+    urlencoding::encode(text).into_owned()
+}
+
+/// URL 百分号解码，`+` 按表单编码视为空格
+pub fn url_decode(text: &str) -> Option<String> {
+    let decoded = urlencoding::decode(&text.replace('+', " ")).ok()?.into_owned();
+    Some(decoded)
+}
+
+/// HTML 转义 `& < > " '`
+pub fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// HTML 反转义：常用命名实体和 `&#NN;`、`&#xHH;` 数字实体，无法识别的实体原样保留
+pub fn html_unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name.strip_prefix('#') {
+                Some(number) => match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => number.parse().ok().and_then(char::from_u32),
+                },
+                None => NAMED_ENTITIES.iter().find(|(n, _)| *n == name).map(|(_, c)| *c),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// 文本的 UTF-8 字节转为小写十六进制
+pub fn hex_encode(text: &str) -> String {
+    text.bytes().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 十六进制转文本，忽略空白、`:` 分隔符和 `0x` 前缀
+pub fn hex_decode(text: &str) -> Option<String> {
+    let text = text.trim();
+    let text = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")).unwrap_or(text);
+    let digits: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace() && *b != b':').collect();
+    if digits.is_empty() || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let bytes = digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    printable_text(bytes)
+}
+
+/// 解码后的 JWT
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jwt {
+    /// 头部
+    pub header: serde_json::Value,
+    /// 载荷
+    pub payload: serde_json::Value,
+}
+
+/// 解码 JWT 的头部和载荷（不校验签名），头部须为含 `alg` 的 JSON 对象
+pub fn jwt_decode(token: &str) -> Option<Jwt> {
+    let mut parts = token.trim().split('.');
+    let (header, payload, _signature) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let decode = |part: &str| -> Option<serde_json::Value> {
+        let bytes = URL_SAFE_NO_PAD.decode(part.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    };
+    let header = decode(header)?;
+    header.get("alg")?;
+    Some(Jwt { header, payload: decode(payload)? })
+}
+
+/// 字节为 UTF-8 文本且不含控制字符（空白除外）时返回文本
+fn printable_text(bytes: Vec<u8>) -> Option<String> {
+    let text = String::from_utf8(bytes).ok()?;
+    text.chars().all(|c| !c.is_control() || c.is_whitespace()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips() {
+        assert_eq!(base64_encode("héllo"), "aMOpbGxv");
+        assert_eq!(base64_decode("aMOpbGxv").as_deref(), Some("héllo"));
+        assert_eq!(base64_decode("aGk").as_deref(), Some("hi"));
+        assert_eq!(base64_decode("AAEC"), None);
+
+        assert_eq!(url_encode("a b&c/中"), "a%20b%26c%2F%E4%B8%AD");
+        assert_eq!(url_decode("a+b%26c%2F%E4%B8%AD").as_deref(), Some("a b&c/中"));
+
+        assert_eq!(html_escape(r#"<a href="x">&'"#), "&lt;a href=&quot;x&quot;&gt;&amp;&#39;");
+        assert_eq!(
+            html_unescape("&lt;p&gt; &#169; &#x4E2D; &copy &unknown; &amp;"),
+            "<p> © 中 &copy &unknown; &"
+        );
+
+        assert_eq!(hex_encode("Hi!"), "486921");
+        assert_eq!(hex_decode("0x48 69:21").as_deref(), Some("Hi!"));
+        assert_eq!(hex_decode("486"), None);
+
+        let token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+                     eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+                     SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+        let jwt = jwt_decode(token).unwrap();
+        assert_eq!(jwt.header["alg"], "HS256");
+        assert_eq!(jwt.payload["name"], "John Doe");
+        assert_eq!(jwt_decode("a.b"), None);
+    }
+}
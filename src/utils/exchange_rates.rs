@@ -0,0 +1,171 @@
+/// 汇率缓存
+///
+/// 从可配置的汇率接口下载汇率并保存到配置目录，离线时继续使用最近一次下载的汇率；
+/// 超过有效期后在后台重新下载
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{error::WerunError, scheduler::global_scheduler},
+    utils::http::download,
+};
+
+/// 汇率
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRates {
+    /// 基准货币（ISO 代码）
+    pub base: String,
+    /// 1 基准货币可兑换的各货币数量
+    pub rates: HashMap<String, f64>,
+    /// 下载时间 (Unix 时间戳，秒)
+    pub fetched_at: i64,
+}
+
+impl ExchangeRates {
+    /// 解析汇率接口的响应
+    ///
+    /// 兼容 `{"base_code": "USD", "rates": {...}}`（open.er-api.com）和
+    /// `{"base": "EUR", "rates": {...}}`（frankfurter.app）两种格式
+    pub fn parse(json: &str, fetched_at: i64) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        let base = value.get("base_code").or_else(|| value.get("base"))?.as_str()?.to_uppercase();
+        let mut rates: HashMap<String, f64> = value
+            .get("rates")?
+            .as_object()?
+            .iter()
+            .filter_map(|(code, rate)| Some((code.to_uppercase(), rate.as_f64()?)))
+            .filter(|(_, rate)| *rate > 0.0)
+            .collect();
+        // 部分接口不包含基准货币自身
+        rates.insert(base.clone(), 1.0);
+
+        Some(Self { base, rates, fetched_at })
+    }
+
+    /// 货币换算，任一货币没有汇率时返回 `None`
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Option<f64> {
+        let from = self.rates.get(&from.to_uppercase())?;
+        let to = self.rates.get(&to.to_uppercase())?;
+        Some(amount / from * to)
+    }
+
+    /// 是否超过有效期
+    pub fn is_stale(&self, now: i64, max_age_secs: i64) -> bool {
+        now - self.fetched_at >= max_age_secs
+    }
+}
+
+/// 汇率缓存
+pub struct RateCache {
+    /// 缓存文件路径
+    path: PathBuf,
+    /// 已加载的汇率
+    rates: RwLock<Option<ExchangeRates>>,
+    /// 是否正在下载
+    fetching: AtomicBool,
+}
+
+impl RateCache {
+    /// 默认缓存文件路径（配置目录下）
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("werun")
+            .join("exchange_rates.json")
+    }
+
+    /// 创建缓存并读取已保存的汇率
+    pub fn open(path: &Path) -> Self {
+        let rates = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+        Self {
+            path: path.to_path_buf(),
+            rates: RwLock::new(rates),
+            fetching: AtomicBool::new(false),
+        }
+    }
+
+    /// 当前汇率（可能已过期）
+    pub fn rates(&self) -> Option<ExchangeRates> {
+        self.rates.read().unwrap().clone()
+    }
+
+    /// 没有汇率或已超过有效期时在后台下载，同一时间只下载一次
+    pub fn refresh_if_stale(&'static self, url: &str, max_age_secs: i64) {
+        let now = chrono::Utc::now().timestamp();
+        let fresh = self.rates().is_some_and(|rates| !rates.is_stale(now, max_age_secs));
+        if fresh || self.fetching.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let url = url.to_string();
+        global_scheduler().run_or_defer("exchange_rates", move || {
+            std::thread::spawn(move || {
+                if let Err(e) = self.refresh(&url) {
+                    WerunError::Network(e).log();
+                }
+                self.fetching.store(false, Ordering::SeqCst);
+            });
+        });
+    }
+
+    /// 下载汇率并保存
+    fn refresh(&self, url: &str) -> Result<()> {
+        let body = download(url)?;
+        let rates = ExchangeRates::parse(&body, chrono::Utc::now().timestamp())
+            .ok_or_else(|| anyhow!("无法解析汇率接口的响应: {}", url))?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string(&rates)?)?;
+
+        log::info!("已更新汇率: 基准 {}，{} 种货币", rates.base, rates.rates.len());
+        *self.rates.write().unwrap() = Some(rates);
+        Ok(())
+    }
+}
+
+/// 全局汇率缓存
+static GLOBAL_RATES: Lazy<RateCache> = Lazy::new(|| RateCache::open(&RateCache::default_path()));
+
+/// 获取全局汇率缓存
+pub fn global_rates() -> &'static RateCache {
+    &GLOBAL_RATES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_convert() {
+        let rates = ExchangeRates::parse(
+            r#"{"base_code": "USD", "rates": {"USD": 1, "EUR": 0.5, "CNY": 7.0}}"#,
+            1_000,
+        )
+        .unwrap();
+        assert_eq!(rates.base, "USD");
+        assert_eq!(rates.convert(100.0, "usd", "EUR"), Some(50.0));
+        assert_eq!(rates.convert(10.0, "EUR", "CNY"), Some(140.0));
+        assert_eq!(rates.convert(1.0, "USD", "XYZ"), None);
+        assert!(!rates.is_stale(1_000 + 3_599, 3_600));
+        assert!(rates.is_stale(1_000 + 3_600, 3_600));
+
+        // 不含基准货币自身的格式
+        let rates = ExchangeRates::parse(r#"{"base": "EUR", "rates": {"USD": 2.0}}"#, 0).unwrap();
+        assert_eq!(rates.convert(3.0, "EUR", "USD"), Some(6.0));
+        assert!(ExchangeRates::parse("not json", 0).is_none());
+    }
+}
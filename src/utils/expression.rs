@@ -0,0 +1,990 @@
+/// 算术表达式解析
+///
+/// 递归下降解析 `+ - * / % ^`、括号、一元负号、函数（`sqrt`、`sin`、`log` 等）、
+/// 常量（`pi`、`e`）、变量、位运算（`& | xor << >>`）和 `0x`、`0b`、`0o` 前缀的整数，
+/// 生成表达式树，求值时可以记录每一步的中间结果，供计算器预览展示
+use std::{collections::BTreeMap, fmt};
+
+/// 二元运算符
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// 加
+    Add,
+    /// 减
+    Sub,
+    /// 乘
+    Mul,
+    /// 除
+    Div,
+    /// 取余
+    Rem,
+    /// 乘方
+    Pow,
+    /// 按位与
+    And,
+    /// 按位或
+    Or,
+    /// 按位异或
+    Xor,
+    /// 左移
+    Shl,
+    /// 右移（算术右移）
+    Shr,
+}
+
+/// 一元负号的优先级：低于乘方（`-2^2` 为 `-(2^2)`），高于乘除
+const NEG_PRECEDENCE: u8 = 7;
+
+/// 数字、常量、百分比和函数调用的优先级
+const ATOM_PRECEDENCE: u8 = 9;
+
+/// 可以精确表示为 `f64` 的最大整数，位运算的操作数不能超过它
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// 转换为整数，不是整数或超出范围时返回 `None`
+pub fn to_integer(value: f64) -> Option<i64> {
+    (value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER).then_some(value as i64)
+}
+
+impl BinaryOp {
+    /// 显示符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "−",
+            BinaryOp::Mul => "×",
+            BinaryOp::Div => "÷",
+            BinaryOp::Rem => "mod",
+            BinaryOp::Pow => "^",
+            BinaryOp::And => "&",
+            BinaryOp::Or => "|",
+            BinaryOp::Xor => "xor",
+            BinaryOp::Shl => "<<",
+            BinaryOp::Shr => ">>",
+        }
+    }
+
+    /// 是否为位运算
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self, BinaryOp::And | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Shl | BinaryOp::Shr)
+    }
+
+    /// 优先级，数值越大越先计算
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 1,
+            BinaryOp::Xor => 2,
+            BinaryOp::And => 3,
+            BinaryOp::Shl | BinaryOp::Shr => 4,
+            BinaryOp::Add | BinaryOp::Sub => 5,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 6,
+            BinaryOp::Pow => 8,
+        }
+    }
+
+    /// 计算，除数为 0、结果不是有限数或位运算的操作数不是整数时返回 `None`
+    fn apply(&self, lhs: f64, rhs: f64) -> Option<f64> {
+        if self.is_bitwise() {
+            return self.apply_bitwise(to_integer(lhs)?, to_integer(rhs)?).map(|v| v as f64);
+        }
+
+        let value = match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div if rhs == 0.0 => return None,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Rem if rhs == 0.0 => return None,
+            BinaryOp::Rem => lhs % rhs,
+            BinaryOp::Pow => lhs.powf(rhs),
+            _ => return None,
+        };
+        value.is_finite().then_some(value)
+    }
+
+    /// 整数位运算，移位位数须在 0..64 之间
+    fn apply_bitwise(&self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            BinaryOp::And => Some(lhs & rhs),
+            BinaryOp::Or => Some(lhs | rhs),
+            BinaryOp::Xor => Some(lhs ^ rhs),
+            BinaryOp::Shl => lhs.checked_shl(u32::try_from(rhs).ok()?),
+            BinaryOp::Shr => lhs.checked_shr(u32::try_from(rhs).ok()?),
+            _ => None,
+        }
+    }
+}
+
+/// 进制
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    /// 十进制
+    Dec,
+    /// 十六进制
+    Hex,
+    /// 二进制
+    Bin,
+    /// 八进制
+    Oct,
+}
+
+impl Radix {
+    /// 所有进制，按显示顺序
+    pub const ALL: &'static [Radix] = &[Radix::Dec, Radix::Hex, Radix::Bin, Radix::Oct];
+
+    /// 显示名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Radix::Dec => "十进制",
+            Radix::Hex => "十六进制",
+            Radix::Bin => "二进制",
+            Radix::Oct => "八进制",
+        }
+    }
+
+    /// 按名称查找，如 `hex`、`binary`、`八进制`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dec" | "decimal" | "十进制" => Some(Radix::Dec),
+            "hex" | "hexadecimal" | "十六进制" => Some(Radix::Hex),
+            "bin" | "binary" | "二进制" => Some(Radix::Bin),
+            "oct" | "octal" | "八进制" => Some(Radix::Oct),
+            _ => None,
+        }
+    }
+
+    /// 按该进制格式化整数（带前缀），负数显示 64 位补码
+    pub fn format(&self, value: i64) -> String {
+        match self {
+            Radix::Dec => value.to_string(),
+            Radix::Hex => format!("0x{:x}", value),
+            Radix::Bin => format!("0b{:b}", value),
+            Radix::Oct => format!("0o{:o}", value),
+        }
+    }
+
+    /// 字面量前缀后的进制字母
+    fn from_prefix(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'x' => Some(Radix::Hex),
+            'b' => Some(Radix::Bin),
+            'o' => Some(Radix::Oct),
+            _ => None,
+        }
+    }
+
+    /// 基数
+    fn base(&self) -> u32 {
+        match self {
+            Radix::Dec => 10,
+            Radix::Hex => 16,
+            Radix::Bin => 2,
+            Radix::Oct => 8,
+        }
+    }
+}
+
+/// 函数，三角函数使用弧度
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    /// 平方根
+    Sqrt,
+    /// 立方根
+    Cbrt,
+    /// 绝对值
+    Abs,
+    /// 正弦
+    Sin,
+    /// 余弦
+    Cos,
+    /// 正切
+    Tan,
+    /// 反正弦
+    Asin,
+    /// 反余弦
+    Acos,
+    /// 反正切
+    Atan,
+    /// 自然对数
+    Ln,
+    /// 常用对数（以 10 为底）
+    Log,
+    /// 以 2 为底的对数
+    Log2,
+    /// e 的乘方
+    Exp,
+    /// 向下取整
+    Floor,
+    /// 向上取整
+    Ceil,
+    /// 四舍五入
+    Round,
+}
+
+impl Function {
+    /// 所有函数
+    const ALL: &'static [Function] = &[
+        Function::Sqrt,
+        Function::Cbrt,
+        Function::Abs,
+        Function::Sin,
+        Function::Cos,
+        Function::Tan,
+        Function::Asin,
+        Function::Acos,
+        Function::Atan,
+        Function::Ln,
+        Function::Log,
+        Function::Log2,
+        Function::Exp,
+        Function::Floor,
+        Function::Ceil,
+        Function::Round,
+    ];
+
+    /// 函数名
+    pub fn name(&self) -> &'static str {
+        match self {
+            Function::Sqrt => "sqrt",
+            Function::Cbrt => "cbrt",
+            Function::Abs => "abs",
+            Function::Sin => "sin",
+            Function::Cos => "cos",
+            Function::Tan => "tan",
+            Function::Asin => "asin",
+            Function::Acos => "acos",
+            Function::Atan => "atan",
+            Function::Ln => "ln",
+            Function::Log => "log",
+            Function::Log2 => "log2",
+            Function::Exp => "exp",
+            Function::Floor => "floor",
+            Function::Ceil => "ceil",
+            Function::Round => "round",
+        }
+    }
+
+    /// 按名称查找（不区分大小写）
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|f| f.name().eq_ignore_ascii_case(name))
+    }
+
+    /// 计算，超出定义域（如负数开平方）时返回 `None`
+    fn apply(&self, x: f64) -> Option<f64> {
+        let value = match self {
+            Function::Sqrt => x.sqrt(),
+            Function::Cbrt => x.cbrt(),
+            Function::Abs => x.abs(),
+            Function::Sin => x.sin(),
+            Function::Cos => x.cos(),
+            Function::Tan => x.tan(),
+            Function::Asin => x.asin(),
+            Function::Acos => x.acos(),
+            Function::Atan => x.atan(),
+            Function::Ln => x.ln(),
+            Function::Log => x.log10(),
+            Function::Log2 => x.log2(),
+            Function::Exp => x.exp(),
+            Function::Floor => x.floor(),
+            Function::Ceil => x.ceil(),
+            Function::Round => x.round(),
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+/// 常量
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Constant {
+    /// 圆周率
+    Pi,
+    /// 自然常数
+    E,
+}
+
+impl Constant {
+    /// 显示符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Constant::Pi => "π",
+            Constant::E => "e",
+        }
+    }
+
+    /// 按名称查找（不区分大小写）
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "pi" | "π" => Some(Constant::Pi),
+            "e" => Some(Constant::E),
+            _ => None,
+        }
+    }
+
+    /// 常量值
+    pub fn value(&self) -> f64 {
+        match self {
+            Constant::Pi => std::f64::consts::PI,
+            Constant::E => std::f64::consts::E,
+        }
+    }
+}
+
+/// 表达式树
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    /// 数字
+    Number(f64),
+    /// 带进制前缀的整数字面量，如 `0xff`
+    Literal { value: i64, radix: Radix },
+    /// 常量
+    Constant(Constant),
+    /// 变量，解析时代入当前的值
+    Variable { name: String, value: f64 },
+    /// 取负
+    Neg(Box<Expr>),
+    /// 百分比（除以 100）
+    Percent(Box<Expr>),
+    /// 函数调用
+    Call { function: Function, arg: Box<Expr> },
+    /// 二元运算
+    Binary { op: BinaryOp, lhs: Box<Expr>, rhs: Box<Expr> },
+}
+
+/// 计算步骤
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    /// 本步的运算，如 `2 × 3`
+    pub operation: String,
+    /// 本步的结果
+    pub value: f64,
+}
+
+impl Expr {
+    /// 求值
+    pub fn eval(&self) -> Option<f64> {
+        self.eval_with_steps(&mut Vec::new())
+    }
+
+    /// 按计算顺序列出每一步运算及其结果
+    pub fn steps(&self) -> Option<Vec<Step>> {
+        let mut steps = Vec::new();
+        self.eval_with_steps(&mut steps)?;
+        Some(steps)
+    }
+
+    /// 求值并记录步骤
+    fn eval_with_steps(&self, steps: &mut Vec<Step>) -> Option<f64> {
+        match self {
+            Expr::Number(value) => Some(*value),
+            Expr::Literal { value, .. } => Some(*value as f64),
+            Expr::Variable { value, .. } => Some(*value),
+            Expr::Constant(constant) => Some(constant.value()),
+            Expr::Percent(inner) => {
+                let percent = inner.eval_with_steps(steps)?;
+                let value = percent / 100.0;
+                steps.push(Step { operation: format!("{}%", format_number(percent)), value });
+                Some(value)
+            },
+            Expr::Call { function, arg } => {
+                let x = arg.eval_with_steps(steps)?;
+                let value = function.apply(x)?;
+                steps.push(Step {
+                    operation: format!("{}({})", function.name(), format_number(x)),
+                    value,
+                });
+                Some(value)
+            },
+            Expr::Neg(inner) => {
+                let value = inner.eval_with_steps(steps)?;
+                // 字面量取负只是负数本身，不算一步
+                if !matches!(**inner, Expr::Number(_) | Expr::Literal { .. }) {
+                    steps.push(Step {
+                        operation: format!("−({})", format_number(value)),
+                        value: -value,
+                    });
+                }
+                Some(-value)
+            },
+            Expr::Binary { op, lhs, rhs } => {
+                let left = lhs.eval_with_steps(steps)?;
+                let right = rhs.eval_with_steps(steps)?;
+                let value = op.apply(left, right)?;
+                steps.push(Step {
+                    operation: format!(
+                        "{} {} {}",
+                        format_number(left),
+                        op.symbol(),
+                        format_number(right)
+                    ),
+                    value,
+                });
+                Some(value)
+            },
+        }
+    }
+
+    /// 是否包含位运算或 `0x` 等进制字面量，计算器据此显示各进制的结果
+    pub fn is_programmer(&self) -> bool {
+        match self {
+            Expr::Number(_) | Expr::Constant(_) | Expr::Variable { .. } => false,
+            Expr::Literal { .. } => true,
+            Expr::Neg(inner) | Expr::Percent(inner) => inner.is_programmer(),
+            Expr::Call { arg, .. } => arg.is_programmer(),
+            Expr::Binary { op, lhs, rhs } => {
+                op.is_bitwise() || lhs.is_programmer() || rhs.is_programmer()
+            },
+        }
+    }
+
+    /// 先序遍历的树节点：(深度, 节点)，节点为运算符或数字
+    pub fn tree(&self) -> Vec<(usize, &Expr)> {
+        let mut nodes = Vec::new();
+        self.collect_nodes(0, &mut nodes);
+        nodes
+    }
+
+    /// 收集树节点
+    fn collect_nodes<'a>(&'a self, depth: usize, nodes: &mut Vec<(usize, &'a Expr)>) {
+        nodes.push((depth, self));
+        match self {
+            Expr::Neg(inner) | Expr::Percent(inner) => inner.collect_nodes(depth + 1, nodes),
+            Expr::Call { arg, .. } => arg.collect_nodes(depth + 1, nodes),
+            Expr::Binary { lhs, rhs, .. } => {
+                lhs.collect_nodes(depth + 1, nodes);
+                rhs.collect_nodes(depth + 1, nodes);
+            },
+            // 数字、常量和变量是叶子节点
+            _ => {},
+        }
+    }
+
+    /// 节点自身的显示名称（运算符或数字）
+    pub fn label(&self) -> String {
+        match self {
+            Expr::Number(value) => format_number(*value),
+            Expr::Literal { value, radix } => radix.format(*value),
+            Expr::Constant(constant) => constant.symbol().to_string(),
+            Expr::Variable { name, .. } => name.clone(),
+            Expr::Neg(_) => "−".to_string(),
+            Expr::Percent(_) => "%".to_string(),
+            Expr::Call { function, .. } => function.name().to_string(),
+            Expr::Binary { op, .. } => op.symbol().to_string(),
+        }
+    }
+
+    /// 作为子表达式显示时的优先级
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Number(_)
+            | Expr::Literal { .. }
+            | Expr::Constant(_)
+            | Expr::Variable { .. }
+            | Expr::Percent(_)
+            | Expr::Call { .. } => ATOM_PRECEDENCE,
+            Expr::Neg(_) => NEG_PRECEDENCE,
+            Expr::Binary { op, .. } => op.precedence(),
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// 规范化显示，只保留必要的括号
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(value) => write!(f, "{}", format_number(*value)),
+            Expr::Literal { value, radix } => write!(f, "{}", radix.format(*value)),
+            Expr::Constant(constant) => write!(f, "{}", constant.symbol()),
+            Expr::Variable { name, .. } => write!(f, "{}", name),
+            Expr::Neg(inner) if inner.precedence() < NEG_PRECEDENCE => write!(f, "−({})", inner),
+            Expr::Neg(inner) => write!(f, "−{}", inner),
+            Expr::Percent(inner) => {
+                write_operand(f, inner, inner.precedence() < ATOM_PRECEDENCE)?;
+                write!(f, "%")
+            },
+            Expr::Call { function, arg } => write!(f, "{}({})", function.name(), arg),
+            Expr::Binary { op: BinaryOp::Pow, lhs, rhs } => {
+                // 乘方右结合：左侧的乘方和负数需要括号，右侧可以是负数
+                write_operand(f, lhs, lhs.precedence() <= BinaryOp::Pow.precedence())?;
+                write!(f, " ^ ")?;
+                write_operand(f, rhs, rhs.precedence() < NEG_PRECEDENCE)
+            },
+            Expr::Binary { op, lhs, rhs } => {
+                // 右侧同级的减法、除法、取余和移位不满足结合律，需要括号
+                let lhs_parens = lhs.precedence() < op.precedence();
+                let rhs_parens = rhs.precedence() < op.precedence()
+                    || (rhs.precedence() == op.precedence()
+                        && matches!(
+                            op,
+                            BinaryOp::Sub
+                                | BinaryOp::Div
+                                | BinaryOp::Rem
+                                | BinaryOp::Shl
+                                | BinaryOp::Shr
+                        ));
+
+                write_operand(f, lhs, lhs_parens)?;
+                write!(f, " {} ", op.symbol())?;
+                write_operand(f, rhs, rhs_parens)
+            },
+        }
+    }
+}
+
+/// 输出操作数，必要时加括号
+fn write_operand(f: &mut fmt::Formatter<'_>, expr: &Expr, parens: bool) -> fmt::Result {
+    if parens {
+        write!(f, "({})", expr)
+    } else {
+        write!(f, "{}", expr)
+    }
+}
+
+/// 格式化数字：整数不带小数点，小数最多保留 6 位
+pub fn format_number(value: f64) -> String {
+    if value == value.trunc() {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.6}", value).trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// 变量名 -> 值，变量名为小写字母
+pub type Variables = BTreeMap<String, f64>;
+
+/// 是否可以用作变量名：只包含字母，且不与函数、常量和运算符重名
+pub fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(char::is_alphabetic)
+        && Function::from_name(name).is_none()
+        && Constant::from_name(name).is_none()
+        && !["mod", "xor"].contains(&name.to_lowercase().as_str())
+}
+
+/// 解析表达式，存在无法识别的字符、未知的函数或括号不匹配时返回 `None`
+///
+/// 除 ASCII 运算符外也接受 `×`、`÷`、`−`、`mod` 和 `xor`。`%` 后面紧跟操作数时为取余
+/// （`7 % 3`），否则为百分比（`200 * 15%`）。位运算的优先级与 C 相同，低于加减
+pub fn parse(input: &str) -> Option<Expr> {
+    parse_with(input, &Variables::new())
+}
+
+/// 解析表达式，未知的名称按 `variables` 中的变量代入
+pub fn parse_with(input: &str, variables: &Variables) -> Option<Expr> {
+    let mut parser =
+        Parser { chars: input.chars().filter(|c| !c.is_whitespace()).collect(), pos: 0, variables };
+    let expr = parser.expression()?;
+    (parser.pos == parser.chars.len()).then_some(expr)
+}
+
+/// 递归下降解析器
+struct Parser<'a> {
+    /// 去除空白后的字符
+    chars: Vec<char>,
+    /// 当前位置
+    pos: usize,
+    /// 已定义的变量
+    variables: &'a Variables,
+}
+
+impl Parser<'_> {
+    /// 按名称查找变量（不区分大小写）
+    fn variable(&self, name: &str) -> Option<f64> {
+        self.variables.get(&name.to_lowercase()).copied()
+    }
+
+    /// 当前字符
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// 左结合的二元运算：operand (operator operand)*，`operator` 识别到运算符时前进
+    fn binary(
+        &mut self,
+        operand: fn(&mut Self) -> Option<Expr>,
+        operator: fn(&mut Self) -> Option<BinaryOp>,
+    ) -> Option<Expr> {
+        let mut expr = operand(self)?;
+        while let Some(op) = operator(self) {
+            let rhs = operand(self)?;
+            expr = Expr::Binary { op, lhs: Box::new(expr), rhs: Box::new(rhs) };
+        }
+        Some(expr)
+    }
+
+    /// 当前位置起为 `token` 时前进并返回 `op`
+    fn eat(&mut self, token: &str, op: BinaryOp) -> Option<BinaryOp> {
+        if !self.at_keyword(token) {
+            return None;
+        }
+        self.pos += token.chars().count();
+        Some(op)
+    }
+
+    /// expression := xor ('|' xor)*
+    fn expression(&mut self) -> Option<Expr> {
+        self.binary(Self::bit_xor, |p| p.eat("|", BinaryOp::Or))
+    }
+
+    /// xor := and ('xor' and)*
+    fn bit_xor(&mut self) -> Option<Expr> {
+        self.binary(Self::bit_and, |p| p.eat("xor", BinaryOp::Xor))
+    }
+
+    /// and := shift ('&' shift)*
+    fn bit_and(&mut self) -> Option<Expr> {
+        self.binary(Self::shift, |p| p.eat("&", BinaryOp::And))
+    }
+
+    /// shift := sum (('<<' | '>>') sum)*
+    fn shift(&mut self) -> Option<Expr> {
+        self.binary(Self::sum, |p| {
+            p.eat("<<", BinaryOp::Shl).or_else(|| p.eat(">>", BinaryOp::Shr))
+        })
+    }
+
+    /// sum := term (('+' | '-') term)*
+    fn sum(&mut self) -> Option<Expr> {
+        self.binary(Self::term, |p| {
+            p.eat("+", BinaryOp::Add)
+                .or_else(|| p.eat("-", BinaryOp::Sub))
+                .or_else(|| p.eat("−", BinaryOp::Sub))
+        })
+    }
+
+    /// 当前位置起是否为关键字（不区分大小写）
+    fn at_keyword(&self, keyword: &str) -> bool {
+        let end = self.pos + keyword.chars().count();
+        end <= self.chars.len()
+            && self.chars[self.pos..end].iter().collect::<String>().eq_ignore_ascii_case(keyword)
+    }
+
+    /// 当前字符能否开始一个操作数
+    fn at_operand(&self) -> bool {
+        self.peek().is_some_and(|c| c.is_alphanumeric() || c == '.' || c == '(')
+    }
+
+    /// term := factor (('*' | '/' | '%' | 'mod') factor)*
+    fn term(&mut self) -> Option<Expr> {
+        self.binary(Self::factor, |p| {
+            let op = match p.peek()? {
+                '*' | '×' => BinaryOp::Mul,
+                '/' | '÷' => BinaryOp::Div,
+                '%' => BinaryOp::Rem,
+                _ => return p.eat("mod", BinaryOp::Rem),
+            };
+            p.pos += 1;
+            Some(op)
+        })
+    }
+
+    /// factor := ('-' | '+') factor | power
+    fn factor(&mut self) -> Option<Expr> {
+        match self.peek()? {
+            '-' | '−' => {
+                self.pos += 1;
+                Some(Expr::Neg(Box::new(self.factor()?)))
+            },
+            '+' => {
+                self.pos += 1;
+                self.factor()
+            },
+            _ => self.power(),
+        }
+    }
+
+    /// power := postfix ('^' factor)?，右结合且指数可以带负号
+    fn power(&mut self) -> Option<Expr> {
+        let base = self.postfix()?;
+        if self.peek() != Some('^') {
+            return Some(base);
+        }
+        self.pos += 1;
+        let exponent = self.factor()?;
+        Some(Expr::Binary { op: BinaryOp::Pow, lhs: Box::new(base), rhs: Box::new(exponent) })
+    }
+
+    /// postfix := primary '%'*，`%` 后紧跟操作数时留给取余
+    fn postfix(&mut self) -> Option<Expr> {
+        let mut expr = self.primary()?;
+        while self.peek() == Some('%') {
+            self.pos += 1;
+            if self.at_operand() {
+                self.pos -= 1;
+                break;
+            }
+            expr = Expr::Percent(Box::new(expr));
+        }
+        Some(expr)
+    }
+
+    /// primary := number | constant | function ('(' expression ')' | factor) | '(' expression ')'
+    fn primary(&mut self) -> Option<Expr> {
+        match self.peek()? {
+            '(' => self.parenthesized(),
+            c if c.is_alphabetic() => self.identifier(),
+            _ => self.number(),
+        }
+    }
+
+    /// '(' expression ')'
+    fn parenthesized(&mut self) -> Option<Expr> {
+        if self.peek()? != '(' {
+            return None;
+        }
+        self.pos += 1;
+        let expr = self.expression()?;
+        if self.peek()? != ')' {
+            return None;
+        }
+        self.pos += 1;
+        Some(expr)
+    }
+
+    /// 常量、变量或函数调用；函数参数不带括号时只取紧跟的一个因子（`sqrt 16`）
+    fn identifier(&mut self) -> Option<Expr> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphabetic()) {
+            self.pos += 1;
+        }
+
+        // 空白已被去除，`pi mod 2` 读到的是 `pimod`，回退到最长的已知名称
+        let mut name: String = self.chars[start..self.pos].iter().collect();
+        while !name.is_empty()
+            && Function::from_name(&name).is_none()
+            && Constant::from_name(&name).is_none()
+            && self.variable(&name).is_none()
+        {
+            name.pop();
+            self.pos -= 1;
+        }
+        // `log2(8)` 为以 2 为底的对数，`log 2` 仍是常用对数
+        if name.eq_ignore_ascii_case("log")
+            && self.chars.get(self.pos..self.pos + 2) == Some(&['2', '('])
+        {
+            self.pos += 1;
+            name.push('2');
+        }
+
+        if let Some(function) = Function::from_name(&name) {
+            let arg = if self.peek() == Some('(') { self.parenthesized()? } else { self.factor()? };
+            return Some(Expr::Call { function, arg: Box::new(arg) });
+        }
+        if let Some(value) = self.variable(&name) {
+            return Some(Expr::Variable { name: name.to_lowercase(), value });
+        }
+        Constant::from_name(&name).map(Expr::Constant)
+    }
+
+    /// 数字（整数或小数），或带 `0x`、`0b`、`0o` 前缀的整数
+    fn number(&mut self) -> Option<Expr> {
+        if self.peek() == Some('0') {
+            if let Some(radix) = self.chars.get(self.pos + 1).and_then(|&c| Radix::from_prefix(c)) {
+                return self.literal(radix);
+            }
+        }
+
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse().ok().map(Expr::Number)
+    }
+
+    /// 进制前缀后的整数，允许用 `_` 分隔数字
+    fn literal(&mut self, radix: Radix) -> Option<Expr> {
+        self.pos += 2;
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_digit(radix.base()) || c == '_') {
+            self.pos += 1;
+        }
+
+        let digits: String = self.chars[start..self.pos].iter().filter(|&&c| c != '_').collect();
+        let value = i64::from_str_radix(&digits, radix.base()).ok()?;
+        to_integer(value as f64)?;
+        Some(Expr::Literal { value, radix })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_steps() {
+        let expr = parse("8 / 2 / 2 + 3 * (1 - 4)").unwrap();
+        assert_eq!(expr.eval(), Some(-7.0));
+        assert_eq!(expr.to_string(), "8 ÷ 2 ÷ 2 + 3 × (1 − 4)");
+
+        let steps: Vec<String> = expr
+            .steps()
+            .unwrap()
+            .iter()
+            .map(|step| format!("{} = {}", step.operation, format_number(step.value)))
+            .collect();
+        assert_eq!(steps, ["8 ÷ 2 = 4", "4 ÷ 2 = 2", "1 − 4 = -3", "3 × -3 = -9", "2 + -9 = -7"]);
+
+        let tree: Vec<(usize, String)> =
+            parse("-(2+3)").unwrap().tree().into_iter().map(|(d, n)| (d, n.label())).collect();
+        assert_eq!(tree, [(0, "−".into()), (1, "+".into()), (2, "2".into()), (2, "3".into())]);
+
+        assert_eq!(parse("1 / 0").unwrap().eval(), None);
+        assert!(parse("(1 + 2").is_none());
+        assert!(parse("2 +").is_none());
+        assert!(parse("abc").is_none());
+    }
+
+    /// 解析并求值
+    fn eval(input: &str) -> Option<f64> {
+        parse(input)?.eval()
+    }
+
+    /// 断言结果近似相等
+    fn assert_close(input: &str, expected: f64) {
+        let value = eval(input).unwrap_or_else(|| panic!("无法计算: {}", input));
+        assert!((value - expected).abs() < 1e-9, "{} = {}，期望 {}", input, value, expected);
+    }
+
+    #[test]
+    fn test_precedence_and_associativity() {
+        assert_close("2 + 3 * 4", 14.0);
+        assert_close("(2 + 3) * 4", 20.0);
+        assert_close("10 - 4 - 3", 3.0);
+        assert_close("100 / 10 / 5", 2.0);
+        assert_close("2 * 3 ^ 2", 18.0);
+        assert_close("2 ^ 3 ^ 2", 512.0);
+        assert_close("(2 ^ 3) ^ 2", 64.0);
+        assert_close("7 % 3 * 2", 2.0);
+        assert_close("1 + 7 mod 4", 4.0);
+        assert_close("2 × 3 ÷ 4 − 1", 0.5);
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        assert_close("-3", -3.0);
+        assert_close("--3", 3.0);
+        assert_close("-3 + 5", 2.0);
+        assert_close("5 - -3", 8.0);
+        assert_close("2 * -3", -6.0);
+        assert_close("-(2 + 3) * 2", -10.0);
+        assert_close("-2 ^ 2", -4.0);
+        assert_close("(-2) ^ 2", 4.0);
+        assert_close("2 ^ -1", 0.5);
+        assert_close("+4 - +1", 3.0);
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_close("50%", 0.5);
+        assert_close("200 * 15%", 30.0);
+        assert_close("(10 + 10)%", 0.2);
+        assert_close("15% + 1", 1.15);
+        assert_close("10 % 4", 2.0);
+        assert_eq!(eval("5 % 0"), None);
+    }
+
+    #[test]
+    fn test_functions_and_constants() {
+        assert_close("sqrt(16)", 4.0);
+        assert_close("sqrt 16 + 1", 5.0);
+        assert_close("SQRT(9) ^ 2", 9.0);
+        assert_close("cbrt(27)", 3.0);
+        assert_close("abs(-2.5)", 2.5);
+        assert_close("sin(pi / 2)", 1.0);
+        assert_close("cos(0) + tan(0)", 1.0);
+        assert_close("asin(1) * 2", std::f64::consts::PI);
+        assert_close("log 1000", 3.0);
+        assert_close("log2(8)", 3.0);
+        assert_close("ln(e ^ 2)", 2.0);
+        assert_close("exp(0)", 1.0);
+        assert_close("floor(2.7) + ceil(2.1) + round(2.5)", 8.0);
+        assert_close("2 * π", 2.0 * std::f64::consts::PI);
+        assert_close("pi mod 3", std::f64::consts::PI - 3.0);
+        assert_close("-sqrt(4)", -2.0);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(eval("sqrt(-1)"), None);
+        assert_eq!(eval("ln(0)"), None);
+        assert_eq!(eval("10 ^ 1000"), None);
+        assert!(parse("foo(2)").is_none());
+        assert!(parse("sinh(1)").is_none());
+        assert!(parse("2 ^").is_none());
+        assert!(parse("2 pi").is_none());
+        assert!(parse("1.2.3").is_none());
+        assert!(parse("()").is_none());
+        assert!(parse("3 + 4)").is_none());
+        assert!(parse("").is_none());
+    }
+
+    #[test]
+    fn test_bitwise_and_literals() {
+        assert_close("0xff", 255.0);
+        assert_close("0b1010 + 0o17", 25.0);
+        assert_close("0XFF_FF", 65535.0);
+        assert_close("12 & 10", 8.0);
+        assert_close("12 | 3", 15.0);
+        assert_close("12 xor 10", 6.0);
+        assert_close("1 << 4 + 1", 32.0);
+        assert_close("0x100 >> 4", 16.0);
+        assert_close("6 & 3 | 8", 10.0);
+        assert_close("-1 & 0xf", 15.0);
+        assert_eq!(eval("1.5 & 1"), None);
+        assert_eq!(eval("1 << 64"), None);
+        assert!(parse("0x").is_none());
+        assert!(parse("0b102").is_none());
+
+        assert!(parse("0x10 + 1").unwrap().is_programmer());
+        assert!(parse("sqrt(16 & 4)").unwrap().is_programmer());
+        assert!(!parse("2 ^ 3").unwrap().is_programmer());
+
+        assert_eq!(Radix::from_name("HEX"), Some(Radix::Hex));
+        let formatted: Vec<String> = Radix::ALL.iter().map(|radix| radix.format(255)).collect();
+        assert_eq!(formatted, ["255", "0xff", "0b11111111", "0o377"]);
+        assert_eq!(Radix::Hex.format(-1), "0xffffffffffffffff");
+    }
+
+    #[test]
+    fn test_variables() {
+        let variables = Variables::from([("ans".to_string(), 12.0), ("rate".to_string(), 0.5)]);
+        let eval_with = |input: &str| parse_with(input, &variables)?.eval();
+
+        assert_eq!(eval_with("ans * 2"), Some(24.0));
+        assert_eq!(eval_with("RATE * ans + 1"), Some(7.0));
+        assert_eq!(eval_with("sqrt(ans + 4)"), Some(4.0));
+        assert_eq!(parse_with("ans * rate", &variables).unwrap().to_string(), "ans × rate");
+        assert!(parse_with("x + 1", &variables).is_none());
+        assert!(parse("ans").is_none());
+
+        assert!(is_variable_name("x"));
+        assert!(is_variable_name("price"));
+        assert!(!is_variable_name("pi"));
+        assert!(!is_variable_name("sqrt"));
+        assert!(!is_variable_name("mod"));
+        assert!(!is_variable_name("x1"));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for (input, display) in [
+            ("-2^2", "−2 ^ 2"),
+            ("(-2)^2", "(−2) ^ 2"),
+            ("(2^3)^2", "(2 ^ 3) ^ 2"),
+            ("2^(1+1)", "2 ^ (1 + 1)"),
+            ("sqrt(16)*2", "sqrt(16) × 2"),
+            ("(1+2)%", "(1 + 2)%"),
+            ("10 % (7 % 4)", "10 mod (7 mod 4)"),
+            ("2*pi", "2 × π"),
+            ("0xff&(1<<4)", "0xff & 1 << 4"),
+            ("1<<(2<<1)", "1 << (2 << 1)"),
+            ("(1|2)&3", "(1 | 2) & 3"),
+        ] {
+            let expr = parse(input).unwrap();
+            assert_eq!(expr.to_string(), display);
+            // 规范化显示重新解析后结果不变
+            assert_eq!(parse(&expr.to_string()).and_then(|e| e.eval()), expr.eval());
+        }
+    }
+}
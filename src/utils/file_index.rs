@@ -0,0 +1,449 @@
+/// 文件索引
+///
+/// 使用 SQLite (FTS5 trigram) 持久化文件列表，支持增量更新和子串/子序列候选查询
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use rusqlite::{ffi, params, params_from_iter, types::Value, Connection, OptionalExtension};
+
+use crate::core::cache::Cache;
+
+/// 文件信息
+#[derive(Clone, Debug)]
+pub struct FileInfo {
+    /// 文件名称
+    pub name: String,
+    /// 完整路径
+    pub path: String,
+    /// 文件大小
+    pub size: u64,
+    /// 是否目录
+    pub is_dir: bool,
+    /// 修改时间
+    pub modified: SystemTime,
+}
+
+impl FileInfo {
+    /// 从文件系统读取文件信息
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let name = path.file_name()?.to_string_lossy().to_string();
+
+        Some(Self {
+            name,
+            path: path.to_string_lossy().to_string(),
+            size: metadata.len(),
+            is_dir: metadata.is_dir(),
+            modified: metadata.modified().unwrap_or(UNIX_EPOCH),
+        })
+    }
+
+    /// 修改时间 (Unix 时间戳，毫秒)
+    pub fn modified_millis(&self) -> i64 {
+        self.modified.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+    }
+}
+
+/// 建表语句
+///
+/// `files_fts` 为外部内容表，通过触发器与 `files` 保持同步
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS files (
+        path TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        is_dir INTEGER NOT NULL,
+        modified INTEGER NOT NULL,
+        scan_id INTEGER NOT NULL
+    );
+    CREATE VIRTUAL TABLE IF NOT EXISTS files_fts USING fts5(
+        name, content='files', tokenize='trigram'
+    );
+    CREATE TRIGGER IF NOT EXISTS files_ai AFTER INSERT ON files BEGIN
+        INSERT INTO files_fts(rowid, name) VALUES (new.rowid, new.name);
+    END;
+    CREATE TRIGGER IF NOT EXISTS files_ad AFTER DELETE ON files BEGIN
+        INSERT INTO files_fts(files_fts, rowid, name) VALUES ('delete', old.rowid, old.name);
+    END;
+    CREATE TRIGGER IF NOT EXISTS files_au AFTER UPDATE OF name ON files BEGIN
+        INSERT INTO files_fts(files_fts, rowid, name) VALUES ('delete', old.rowid, old.name);
+        INSERT INTO files_fts(rowid, name) VALUES (new.rowid, new.name);
+    END;
+    CREATE TABLE IF NOT EXISTS meta (
+        key TEXT PRIMARY KEY,
+        value INTEGER NOT NULL
+    );
+";
+
+/// 查询文件行的列
+const FILE_COLUMNS: &str = "f.path, f.name, f.size, f.is_dir, f.modified";
+
+/// 候选文件的路径过滤条件
+///
+/// 直接作为查询条件，避免候选数量上限截掉满足条件的文件；
+/// LIKE 只对 ASCII 字符不区分大小写，调用方仍需按完整规则再过滤一次
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathFilter {
+    /// 扩展名组（不含点），组内任一扩展名满足即可，组之间为“且”
+    pub extensions: Vec<Vec<String>>,
+    /// 所在目录：单个目录名按某一级目录匹配，含路径分隔符时按目录路径的一部分匹配
+    pub folders: Vec<String>,
+}
+
+impl PathFilter {
+    /// 生成追加到 WHERE 子句的条件和参数，参数从 `?{first}` 开始编号
+    fn to_sql(&self, first: usize) -> (String, Vec<Value>) {
+        let mut sql = String::new();
+        let mut values = Vec::new();
+
+        for group in &self.extensions {
+            let conditions: Vec<String> = group
+                .iter()
+                .map(|ext| {
+                    values.push(Value::Text(format!("%.{}", like_escape(ext))));
+                    format!("f.name LIKE ?{} ESCAPE '\\'", first + values.len() - 1)
+                })
+                .collect();
+            sql.push_str(&format!(" AND ({})", conditions.join(" OR ")));
+        }
+
+        for folder in &self.folders {
+            let folder = folder.replace('/', "\\");
+            let folder = folder.trim_matches('\\');
+            // 匹配部分之后还有路径分隔符，说明位于目录部分而不是文件名中
+            let pattern = if folder.contains('\\') {
+                format!("%{}%\\\\%", like_escape(folder))
+            } else {
+                format!("%\\\\{}\\\\%", like_escape(folder))
+            };
+            values.push(Value::Text(pattern));
+            sql.push_str(&format!(
+                " AND replace(f.path, '/', '\\') LIKE ?{} ESCAPE '\\'",
+                first + values.len() - 1
+            ));
+        }
+
+        (sql, values)
+    }
+}
+
+/// 文件索引
+pub struct FileIndex {
+    /// 数据库连接
+    conn: Mutex<Connection>,
+}
+
+impl FileIndex {
+    /// 默认索引文件路径（配置目录下）
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("werun").join("file_index.db")
+    }
+
+    /// 打开（或创建）索引文件
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// 创建内存索引
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// 初始化表结构
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// 已索引的文件数量
+    pub fn count(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    /// 最近修改的文件名
+    pub fn recent_names(&self, limit: usize) -> Result<Vec<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare_cached("SELECT name FROM files ORDER BY modified DESC LIMIT ?1")?;
+        let names = stmt.query_map(params![limit as i64], |row| row.get(0))?;
+        Ok(names.flatten().collect())
+    }
+
+    /// 开始新一轮全量扫描，返回扫描编号
+    pub fn begin_scan(&self) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        let last: Option<i64> = conn
+            .query_row("SELECT value FROM meta WHERE key = 'scan_id'", [], |row| row.get(0))
+            .optional()?;
+        let scan_id = last.unwrap_or(0) + 1;
+        conn.execute(
+            "INSERT INTO meta(key, value) VALUES ('scan_id', ?1)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![scan_id],
+        )?;
+        Ok(scan_id)
+    }
+
+    /// 结束全量扫描，删除本轮未出现的文件，返回删除数量
+    pub fn finish_scan(&self, scan_id: i64) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        Ok(conn.execute("DELETE FROM files WHERE scan_id != ?1", params![scan_id])?)
+    }
+
+    /// 批量写入或更新文件
+    pub fn upsert(&self, files: &[FileInfo], scan_id: i64) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO files(path, name, size, is_dir, modified, scan_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(path) DO UPDATE SET
+                    name = excluded.name,
+                    size = excluded.size,
+                    is_dir = excluded.is_dir,
+                    modified = excluded.modified,
+                    scan_id = excluded.scan_id",
+            )?;
+
+            for file in files {
+                stmt.execute(params![
+                    file.path,
+                    file.name,
+                    file.size as i64,
+                    file.is_dir,
+                    file.modified_millis(),
+                    scan_id
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 删除路径及其下的所有文件，返回删除数量
+    pub fn remove(&self, path: &str) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let prefix = format!("{}{}", path.trim_end_matches(['/', '\\']), std::path::MAIN_SEPARATOR);
+        Ok(conn.execute(
+            "DELETE FROM files WHERE path = ?1 OR substr(path, 1, length(?2)) = ?2",
+            params![path, prefix],
+        )?)
+    }
+
+    /// 查询候选文件
+    ///
+    /// 三个字符以上先走 FTS5 trigram 子串查询；
+    /// 候选不足时再用 LIKE 做按字符顺序的子序列查询，由调用方做最终评分
+    pub fn candidates(
+        &self,
+        query: &str,
+        filter: &PathFilter,
+        limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        let conn = self.conn.lock().unwrap();
+        let mut files = Vec::new();
+        let (filter_sql, filter_values) = filter.to_sql(3);
+
+        if query.chars().count() >= 3 {
+            let mut stmt = conn.prepare_cached(&format!(
+                "SELECT {} FROM files_fts JOIN files f ON f.rowid = files_fts.rowid
+                 WHERE files_fts MATCH ?1{} LIMIT ?2",
+                FILE_COLUMNS, filter_sql
+            ))?;
+            let phrase = format!("\"{}\"", query.replace('"', "\"\""));
+            let values = [Value::Text(phrase), Value::Integer(limit as i64)];
+            let rows = stmt.query_map(
+                params_from_iter(values.iter().chain(&filter_values)),
+                Self::row_to_file,
+            )?;
+            files.extend(rows.flatten());
+        }
+
+        if files.len() < limit {
+            let mut stmt = conn.prepare_cached(&format!(
+                "SELECT {} FROM files f WHERE f.name LIKE ?1 ESCAPE '\\'{} LIMIT ?2",
+                FILE_COLUMNS, filter_sql
+            ))?;
+            let values =
+                [Value::Text(Self::subsequence_pattern(query)), Value::Integer(limit as i64)];
+            let rows = stmt.query_map(
+                params_from_iter(values.iter().chain(&filter_values)),
+                Self::row_to_file,
+            )?;
+            let mut seen: HashSet<String> = files.iter().map(|f| f.path.clone()).collect();
+            files.extend(rows.flatten().filter(|file| seen.insert(file.path.clone())));
+        }
+
+        Ok(files)
+    }
+
+    /// 构造子序列 LIKE 模式：`gcm` -> `%g%c%m%`
+    fn subsequence_pattern(query: &str) -> String {
+        let mut pattern = String::from("%");
+        for ch in query.chars().filter(|c| !c.is_whitespace()) {
+            pattern.push_str(&like_escape(&ch.to_string()));
+            pattern.push('%');
+        }
+        pattern
+    }
+
+    /// 将查询行转换为文件信息
+    fn row_to_file(row: &rusqlite::Row) -> rusqlite::Result<FileInfo> {
+        let modified: i64 = row.get(4)?;
+        Ok(FileInfo {
+            path: row.get(0)?,
+            name: row.get(1)?,
+            size: row.get::<_, i64>(2)? as u64,
+            is_dir: row.get(3)?,
+            modified: UNIX_EPOCH + Duration::from_millis(modified.max(0) as u64),
+        })
+    }
+}
+
+/// 转义 LIKE 模式中的通配符和转义字符
+fn like_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+impl Cache for FileIndex {
+    fn name(&self) -> &str {
+        "文件索引"
+    }
+
+    /// SQLite 页缓存占用
+    fn memory_usage(&self) -> usize {
+        let conn = self.conn.lock().unwrap();
+        let (mut current, mut highwater) = (0, 0);
+        let status = unsafe {
+            ffi::sqlite3_db_status(
+                conn.handle(),
+                ffi::SQLITE_DBSTATUS_CACHE_USED,
+                &mut current,
+                &mut highwater,
+                0,
+            )
+        };
+        if status == ffi::SQLITE_OK {
+            current.max(0) as usize
+        } else {
+            0
+        }
+    }
+
+    /// 页缓存无法部分释放，超出目标时释放全部未使用的页
+    fn trim(&self, target: usize) -> usize {
+        let before = self.memory_usage();
+        if before <= target {
+            return 0;
+        }
+
+        // 等同于 sqlite3_db_release_memory
+        if let Err(e) = self.conn.lock().unwrap().execute_batch("PRAGMA shrink_memory") {
+            log::warn!("释放文件索引缓存失败: {}", e);
+        }
+        before.saturating_sub(self.memory_usage())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> FileInfo {
+        FileInfo {
+            name: Path::new(path).file_name().unwrap().to_string_lossy().to_string(),
+            path: path.to_string(),
+            size: 1,
+            is_dir: false,
+            modified: UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn test_candidates_substring_and_subsequence() {
+        let index = FileIndex::open_in_memory().unwrap();
+        let scan_id = index.begin_scan().unwrap();
+        index
+            .upsert(&[file("/docs/report.docx"), file("/docs/git-credential-manager.md")], scan_id)
+            .unwrap();
+
+        let hits = index.candidates("port", &PathFilter::default(), 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "report.docx");
+
+        let hits = index.candidates("gcm", &PathFilter::default(), 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "git-credential-manager.md");
+    }
+
+    #[test]
+    fn test_candidates_path_filter() {
+        let index = FileIndex::open_in_memory().unwrap();
+        let scan_id = index.begin_scan().unwrap();
+        index
+            .upsert(
+                &[
+                    file("C:\\Users\\a\\Docs\\report.PDF"),
+                    file("C:\\Users\\a\\Docs\\report.docx"),
+                    file("C:\\Users\\a\\docs2\\report.pdf"),
+                    file("D:/Work/My Docs/report_docs.txt"),
+                ],
+                scan_id,
+            )
+            .unwrap();
+
+        let filter = PathFilter { extensions: vec![vec!["pdf".to_string()]], folders: vec![] };
+        assert_eq!(index.candidates("report", &filter, 10).unwrap().len(), 2);
+
+        let filter = PathFilter { extensions: vec![], folders: vec!["docs".to_string()] };
+        let hits = index.candidates("", &filter, 10).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|hit| hit.path.contains("\\Docs\\")));
+
+        let filter = PathFilter { extensions: vec![], folders: vec!["work/my docs".to_string()] };
+        let hits = index.candidates("rep", &filter, 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "report_docs.txt");
+    }
+
+    #[test]
+    fn test_incremental_scan_and_remove() {
+        let index = FileIndex::open_in_memory().unwrap();
+        let first = index.begin_scan().unwrap();
+        index.upsert(&[file("/a/one.txt"), file("/a/two.txt")], first).unwrap();
+        assert_eq!(index.count().unwrap(), 2);
+
+        // 第二轮扫描只看到 one.txt，two.txt 被清除
+        let second = index.begin_scan().unwrap();
+        index.upsert(&[file("/a/one.txt")], second).unwrap();
+        assert_eq!(index.finish_scan(second).unwrap(), 1);
+        assert!(index.candidates("two", &PathFilter::default(), 10).unwrap().is_empty());
+
+        let sep = std::path::MAIN_SEPARATOR;
+        let nested = format!("{sep}b{sep}inner{sep}three.txt");
+        index.upsert(&[file(&nested)], second).unwrap();
+        assert_eq!(index.remove(&format!("{sep}b")).unwrap(), 1);
+        assert_eq!(index.count().unwrap(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+/// 文件预览
+///
+/// 为预览面板读取文件内容：文本文件的前若干行（按扩展名着色）、图片缩略图、文件夹的条目列表，
+/// 以及大小、修改时间等基本信息；PDF 等文档从文件中解析标题和页数
+use std::{
+    fs::{self, File, Metadata},
+    io::Read,
+    path::Path,
+};
+
+use once_cell::sync::Lazy;
+use regex::bytes::Regex;
+
+use super::syntax::{self, Syntax};
+use crate::core::{
+    cache::format_bytes,
+    preview::{Preview, PreviewBody, PreviewRow},
+};
+
+/// 文本预览的最大行数
+const MAX_LINES: usize = 40;
+
+/// 每行最多显示的字符数
+const MAX_LINE_CHARS: usize = 160;
+
+/// 判断和读取文本时最多读取的字节数
+const MAX_TEXT_BYTES: u64 = 64 * 1024;
+
+/// 解析 PDF 时最多读取的字节数
+const MAX_PDF_BYTES: u64 = 4 * 1024 * 1024;
+
+/// 文件夹预览最多列出的条目数
+const MAX_ENTRIES: usize = 50;
+
+/// 以缩略图预览的图片扩展名
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "webp", "ico"];
+
+/// 不读取文本内容的文档扩展名（压缩或二进制格式，只显示基本信息和文档属性）
+const DOCUMENT_EXTENSIONS: &[&str] =
+    &["pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "odt", "ods", "odp", "rtf"];
+
+/// PDF 页面对象（`/Type /Page`，不含 `/Pages`）
+static PDF_PAGE: Lazy<Regex> = Lazy::new(|| Regex::new(r"/Type\s*/Page[^s]").unwrap());
+
+/// PDF 文档信息中的标题（只处理字面字符串）
+static PDF_TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"/Title\s*\(([^)]*)\)").unwrap());
+
+/// 读取文件或文件夹的预览，`properties` 为额外的属性行（如系统提供的文档属性）
+pub fn load(path: &Path, properties: Vec<(String, String)>) -> Preview {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Preview::new().section("文件信息", vec![PreviewRow::new("状态", "无法读取")]);
+    };
+    if metadata.is_dir() {
+        return folder_preview(path, &metadata);
+    }
+
+    let extension =
+        path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    let mut preview = Preview::new();
+    let mut rows = file_rows(&metadata);
+
+    if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+        preview = preview.body(PreviewBody::Image(path.to_path_buf()));
+    } else if extension == "pdf" {
+        rows.extend(
+            read_head(path, MAX_PDF_BYTES).map(|bytes| pdf_rows(&bytes)).unwrap_or_default(),
+        );
+    } else if !DOCUMENT_EXTENSIONS.contains(&extension.as_str()) {
+        if let Some(text) = read_head(path, MAX_TEXT_BYTES).and_then(|bytes| decode_text(&bytes)) {
+            let syntax = Syntax::for_extension(&extension);
+            preview = preview.body(PreviewBody::Code(syntax::highlight(&text, syntax.as_ref())));
+        }
+    }
+
+    rows.extend(properties.into_iter().map(|(label, value)| PreviewRow::new(label, value)));
+    preview.section("文件信息", rows)
+}
+
+/// 文件夹预览：子文件夹在前，按名称排序
+fn folder_preview(path: &Path, metadata: &Metadata) -> Preview {
+    let mut entries: Vec<(bool, String, u64)> = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let metadata = entry.metadata().ok()?;
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    Some((metadata.is_dir(), name, metadata.len()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by_cached_key(|(is_dir, name, _)| (!is_dir, name.to_lowercase()));
+
+    let total = entries.len();
+    let mut rows: Vec<PreviewRow> = entries
+        .into_iter()
+        .take(MAX_ENTRIES)
+        .map(|(is_dir, name, size)| {
+            let value = if is_dir { "文件夹".to_string() } else { format_bytes(size as usize) };
+            PreviewRow::new(name, value)
+        })
+        .collect();
+    if total > MAX_ENTRIES {
+        rows.push(PreviewRow::new(format!("还有 {} 项", total - MAX_ENTRIES), ""));
+    }
+
+    let mut info = vec![PreviewRow::new("条目", total.to_string())];
+    info.extend(modified_row(metadata));
+    Preview::new().section("文件夹信息", info).section("内容", rows)
+}
+
+/// 文件的基本信息：大小和修改时间
+fn file_rows(metadata: &Metadata) -> Vec<PreviewRow> {
+    let mut rows = vec![PreviewRow::new("大小", format_bytes(metadata.len() as usize))];
+    rows.extend(modified_row(metadata));
+    rows
+}
+
+/// 修改时间
+fn modified_row(metadata: &Metadata) -> Option<PreviewRow> {
+    let modified: chrono::DateTime<chrono::Local> = metadata.modified().ok()?.into();
+    Some(PreviewRow::new("修改时间", modified.format("%Y-%m-%d %H:%M").to_string()))
+}
+
+/// 读取文件开头最多 `limit` 字节
+fn read_head(path: &Path, limit: u64) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    File::open(path).ok()?.take(limit).read_to_end(&mut bytes).ok()?;
+    Some(bytes)
+}
+
+/// 把文件开头解码为预览文本，包含 NUL 字符（二进制文件）时返回 `None`
+///
+/// 只保留前若干行，过长的行截断；截断处的不完整 UTF-8 字符按替换字符显示
+fn decode_text(bytes: &[u8]) -> Option<String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    if bytes.contains(&0) {
+        return None;
+    }
+
+    let text = String::from_utf8_lossy(bytes);
+    let lines: Vec<String> = text
+        .lines()
+        .take(MAX_LINES)
+        .map(|line| {
+            let line = line.replace('\t', "    ");
+            match line.char_indices().nth(MAX_LINE_CHARS) {
+                Some((index, _)) => format!("{}…", &line[..index]),
+                None => line,
+            }
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// PDF 的标题和页数
+fn pdf_rows(bytes: &[u8]) -> Vec<PreviewRow> {
+    let mut rows = Vec::new();
+    if let Some(title) =
+        PDF_TITLE.captures(bytes).map(|c| String::from_utf8_lossy(&c[1]).trim().to_string())
+    {
+        if !title.is_empty() {
+            rows.push(PreviewRow::new("标题", title));
+        }
+    }
+
+    // 页面对象位于压缩的对象流中时数不到，不显示页数
+    let pages = PDF_PAGE.find_iter(bytes).count();
+    if pages > 0 {
+        rows.push(PreviewRow::new("页数", pages.to_string()));
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_text_and_pdf() {
+        assert_eq!(
+            decode_text(b"\xEF\xBB\xBFfn main() {}\r\n\tok").as_deref(),
+            Some("fn main() {}\n    ok")
+        );
+        assert_eq!(decode_text(b"PNG\0\x01"), None);
+
+        let long = "长".repeat(MAX_LINE_CHARS + 5);
+        let decoded = decode_text(long.as_bytes()).unwrap();
+        assert_eq!(decoded.chars().count(), MAX_LINE_CHARS + 1);
+
+        let pdf = b"%PDF-1.4\n1 0 obj << /Type /Pages /Count 2 >>\n2 0 obj << /Type /Page >>\n\
+                    3 0 obj << /Type/Page/Parent 1 0 R >>\n4 0 obj << /Title (Report) >>";
+        let rows = pdf_rows(pdf);
+        assert_eq!(rows, [PreviewRow::new("标题", "Report"), PreviewRow::new("页数", "2")]);
+    }
+}
@@ -0,0 +1,227 @@
+/// 文件夹书签
+///
+/// 保存用户收藏的文件夹，并记录每个文件夹的打开次数和最近打开时间，按"频率 + 新近度"
+/// 排序常用文件夹；路径比较忽略大小写和末尾的分隔符
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// 最多记录的打开过的文件夹数，超出时淘汰得分最低的
+const MAX_VISITS: usize = 200;
+
+/// 打开记录
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FolderVisit {
+    /// 文件夹路径
+    pub path: String,
+    /// 打开次数
+    pub count: u32,
+    /// 最近打开时间 (Unix 时间戳，秒)
+    pub last_visited: i64,
+}
+
+impl FolderVisit {
+    /// 常用程度得分：打开次数按距今时间加权，一小时内 ×4，一天内 ×2，一周内 ×1，更早 ×0.25
+    pub fn frecency(&self, now: i64) -> f64 {
+        let weight = match now - self.last_visited {
+            age if age < 3600 => 4.0,
+            age if age < 86400 => 2.0,
+            age if age < 7 * 86400 => 1.0,
+            _ => 0.25,
+        };
+        self.count as f64 * weight
+    }
+}
+
+/// 保存的数据
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct FolderData {
+    /// 书签（按添加顺序）
+    bookmarks: Vec<String>,
+    /// 打开记录
+    visits: Vec<FolderVisit>,
+}
+
+/// 规范化路径用于比较：去掉末尾的分隔符（保留盘符根目录的 `\`），忽略大小写
+fn normalize(path: &str) -> String {
+    let trimmed = path.trim_end_matches(['\\', '/']);
+    let trimmed = if trimmed.ends_with(':') { path } else { trimmed };
+    trimmed.replace('/', "\\").to_lowercase()
+}
+
+/// 是否为同一个文件夹
+pub fn same_folder(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// 文件夹书签存储
+pub struct FolderStore {
+    /// 书签和打开记录
+    data: Mutex<FolderData>,
+    /// 数据文件路径
+    data_file: PathBuf,
+}
+
+impl FolderStore {
+    /// 创建新的文件夹书签存储并加载数据
+    pub fn new() -> Self {
+        let data_file = dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("werun")
+            .join("folders.json");
+
+        Self::with_path(data_file)
+    }
+
+    /// 使用指定的数据文件创建存储
+    pub fn with_path(data_file: PathBuf) -> Self {
+        let store = Self { data: Mutex::new(FolderData::default()), data_file };
+
+        if let Err(e) = store.load() {
+            log::warn!("加载文件夹书签失败: {:?}", e);
+        }
+
+        store
+    }
+
+    /// 全部书签（按添加顺序）
+    pub fn bookmarks(&self) -> Vec<String> {
+        self.data.lock().unwrap().bookmarks.clone()
+    }
+
+    /// 是否已收藏
+    pub fn is_bookmarked(&self, path: &str) -> bool {
+        self.data.lock().unwrap().bookmarks.iter().any(|b| same_folder(b, path))
+    }
+
+    /// 添加书签，已存在时不重复添加
+    pub fn add_bookmark(&self, path: &str) {
+        {
+            let mut data = self.data.lock().unwrap();
+            if data.bookmarks.iter().any(|b| same_folder(b, path)) {
+                return;
+            }
+            data.bookmarks.push(path.to_string());
+        }
+        self.save_or_log();
+    }
+
+    /// 移除书签
+    pub fn remove_bookmark(&self, path: &str) {
+        self.data.lock().unwrap().bookmarks.retain(|b| !same_folder(b, path));
+        self.save_or_log();
+    }
+
+    /// 记录一次打开
+    pub fn record_visit(&self, path: &str) {
+        let now = chrono::Utc::now().timestamp();
+        {
+            let mut data = self.data.lock().unwrap();
+            match data.visits.iter_mut().find(|v| same_folder(&v.path, path)) {
+                Some(visit) => {
+                    visit.count += 1;
+                    visit.last_visited = now;
+                },
+                None => data.visits.push(FolderVisit {
+                    path: path.to_string(),
+                    count: 1,
+                    last_visited: now,
+                }),
+            }
+
+            if data.visits.len() > MAX_VISITS {
+                data.visits.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+                data.visits.truncate(MAX_VISITS);
+            }
+        }
+        self.save_or_log();
+    }
+
+    /// 打开过的文件夹，按常用程度从高到低排列
+    pub fn frecent(&self) -> Vec<FolderVisit> {
+        let now = chrono::Utc::now().timestamp();
+        let mut visits = self.data.lock().unwrap().visits.clone();
+        visits.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+        visits
+    }
+
+    /// 加载数据
+    fn load(&self) -> Result<()> {
+        if self.data_file.exists() {
+            let content = fs::read_to_string(&self.data_file)?;
+            *self.data.lock().unwrap() = serde_json::from_str(&content)?;
+        }
+        Ok(())
+    }
+
+    /// 保存数据
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.data_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let data = self.data.lock().unwrap();
+        let content = serde_json::to_string_pretty(&*data)?;
+        fs::write(&self.data_file, content)?;
+        Ok(())
+    }
+
+    /// 保存数据，失败时记录日志
+    fn save_or_log(&self) {
+        if let Err(e) = self.save() {
+            log::warn!("保存文件夹书签失败: {:?}", e);
+        }
+    }
+}
+
+impl Default for FolderStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 全局文件夹书签实例
+static GLOBAL_FOLDERS: Lazy<FolderStore> = Lazy::new(FolderStore::new);
+
+/// 获取全局文件夹书签存储
+pub fn global_folders() -> &'static FolderStore {
+    &GLOBAL_FOLDERS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmarks_and_visits() {
+        let dir = std::env::temp_dir().join(format!("werun-folders-{}", std::process::id()));
+        let store = FolderStore::with_path(dir.join("folders.json"));
+
+        store.add_bookmark(r"D:\Code\werun");
+        store.add_bookmark(r"d:/code/werun/");
+        assert_eq!(store.bookmarks(), [r"D:\Code\werun"]);
+        assert!(store.is_bookmarked(r"d:\code\WERUN\"));
+        assert!(same_folder(r"C:\", "c:/"));
+        assert!(!same_folder(r"C:\", "C:"));
+
+        store.record_visit(r"C:\Temp");
+        store.record_visit(r"C:\Users\me\Downloads");
+        store.record_visit(r"c:\temp\");
+        let visits = store.frecent();
+        assert_eq!((visits[0].path.as_str(), visits[0].count), (r"C:\Temp", 2));
+
+        // 重新加载后仍然存在
+        let reloaded = FolderStore::with_path(dir.join("folders.json"));
+        reloaded.remove_bookmark(r"D:\code\werun");
+        assert!(reloaded.bookmarks().is_empty());
+        assert_eq!(reloaded.frecent().len(), 2);
+
+        let old = FolderVisit { path: String::new(), count: 8, last_visited: 0 };
+        assert_eq!(old.frecency(30 * 86400), 2.0);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
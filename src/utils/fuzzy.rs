@@ -1,159 +1,302 @@
 /// 模糊搜索工具
 ///
 /// 提供高性能的模糊匹配功能
+use std::ops::Range;
+
+use crate::core::config::BoundaryBonus;
+
+/// 单词边界类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// 字符串开头
+    Start,
+    /// 分隔符之后 (空格、连字符、下划线等)
+    Separator,
+    /// 大小写切换 (camelCase)
+    CaseChange,
+}
+
+/// 分词器
 ///
-/// 计算模糊匹配分数
+/// 判断字符是否位于单词边界，决定匹配时的边界加分
+pub trait Tokenizer: Send + Sync {
+    /// 根据前一个字符判断当前字符的边界类型
+    fn boundary(&self, prev: Option<char>, current: char) -> Option<Boundary>;
+}
+
+/// 默认分词器
 ///
-/// 返回 (是否匹配，匹配分数)
-/// 分数越高表示匹配度越好
-pub fn fuzzy_match(query: &str, target: &str) -> (bool, u32) {
-    let query = query.to_lowercase();
-    let target = target.to_lowercase();
+/// 识别空格/连字符/下划线/点/路径分隔符以及 camelCase 的大小写切换
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WordTokenizer;
+
+impl WordTokenizer {
+    /// 是否为分隔符
+    fn is_separator(ch: char) -> bool {
+        ch.is_whitespace() || matches!(ch, '-' | '_' | '.' | '/' | '\\')
+    }
+}
 
-    // 空查询匹配所有
-    if query.is_empty() {
-        return (true, 0);
+impl Tokenizer for WordTokenizer {
+    fn boundary(&self, prev: Option<char>, current: char) -> Option<Boundary> {
+        if Self::is_separator(current) {
+            return None;
+        }
+
+        match prev {
+            None => Some(Boundary::Start),
+            Some(p) if Self::is_separator(p) => Some(Boundary::Separator),
+            Some(p) if p.is_lowercase() && current.is_uppercase() => Some(Boundary::CaseChange),
+            _ => None,
+        }
     }
+}
+
+/// 模糊匹配器
+///
+/// 使用可替换的分词器对单词边界上的命中加分，
+/// 使 "nvs" 匹配 "NVIDIA Settings"、"gcm" 匹配 "git-credential-manager"
+pub struct FuzzyMatcher {
+    /// 分词器
+    tokenizer: Box<dyn Tokenizer>,
+    /// 边界加分权重
+    bonus: BoundaryBonus,
+}
 
-    // 精确包含匹配
-    if target.contains(&query) {
-        let score = calculate_contain_score(&query, &target);
-        return (true, score);
+impl FuzzyMatcher {
+    /// 使用默认分词器创建匹配器
+    pub fn new(bonus: BoundaryBonus) -> Self {
+        Self::with_tokenizer(WordTokenizer, bonus)
     }
 
-    // 字符顺序匹配
-    if fuzzy_char_match(&query, &target) {
-        let score = calculate_fuzzy_score(&query, &target);
-        return (true, score);
+    /// 使用自定义分词器创建匹配器
+    pub fn with_tokenizer(tokenizer: impl Tokenizer + 'static, bonus: BoundaryBonus) -> Self {
+        Self { tokenizer: Box::new(tokenizer), bonus }
     }
 
-    (false, 0)
-}
+    /// 计算模糊匹配分数
+    ///
+    /// 返回 (是否匹配，匹配分数)
+    /// 分数越高表示匹配度越好
+    pub fn fuzzy_match(&self, query: &str, target: &str) -> (bool, u32) {
+        let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+
+        // 空查询匹配所有
+        if query.is_empty() {
+            return (true, 0);
+        }
+
+        let target: Vec<char> = target.chars().collect();
+        let lowered: Vec<char> = target.iter().map(|&c| lower_char(c)).collect();
+        let bonuses = self.boundary_bonuses(&target);
+
+        // 精确包含匹配
+        if let Some(bonus) = self.best_contain_bonus(&query, &lowered, &bonuses) {
+            let length_penalty = (target.len() - query.len()) as u32 * 2;
+            return (true, (100 + bonus).saturating_sub(length_penalty));
+        }
 
-/// 计算包含匹配的分数
-fn calculate_contain_score(query: &str, target: &str) -> u32 {
-    let mut score = 100u32;
+        // 字符顺序匹配
+        if let Some((bonus, first_pos)) = self.best_subsequence_bonus(&query, &lowered, &bonuses) {
+            let ratio = query.len() as f32 / target.len() as f32;
+            let score = 50 + bonus + (ratio * 20.0) as u32;
+            return (true, score.saturating_sub(first_pos as u32 * 5));
+        }
 
-    // 开头匹配加分
-    if target.starts_with(query) {
-        score += 50;
+        (false, 0)
     }
 
-    // 单词边界匹配加分
-    if target.contains(&format!(" {}", query)) {
-        score += 30;
+    /// 计算每个字符位置的边界加分
+    fn boundary_bonuses(&self, target: &[char]) -> Vec<u32> {
+        let mut prev = None;
+        target
+            .iter()
+            .map(|&ch| {
+                let bonus = match self.tokenizer.boundary(prev, ch) {
+                    Some(Boundary::Start) => self.bonus.start,
+                    Some(Boundary::Separator) => self.bonus.separator,
+                    Some(Boundary::CaseChange) => self.bonus.case_change,
+                    None => 0,
+                };
+                prev = Some(ch);
+                bonus
+            })
+            .collect()
     }
 
-    // 长度差异惩罚
-    let length_diff = target.len() as i32 - query.len() as i32;
-    score -= (length_diff * 2) as u32;
+    /// 在所有连续出现的位置中取边界加分最高的一处
+    fn best_contain_bonus(&self, query: &[char], target: &[char], bonuses: &[u32]) -> Option<u32> {
+        if query.len() > target.len() {
+            return None;
+        }
 
-    score
-}
+        (0..=target.len() - query.len())
+            .filter(|&start| target[start..start + query.len()] == *query)
+            .map(|start| bonuses[start])
+            .max()
+    }
 
-/// 字符顺序匹配
-///
-/// 检查 query 中的字符是否按顺序出现在 target 中
-fn fuzzy_char_match(query: &str, target: &str) -> bool {
-    let mut query_chars = query.chars();
-    let mut current_char = query_chars.next();
-
-    for target_char in target.chars() {
-        if let Some(qc) = current_char {
-            if target_char == qc {
-                current_char = query_chars.next();
+    /// 计算最优的字符顺序匹配
+    ///
+    /// 动态规划：每个查询字符可以落在任意后续位置，
+    /// 取边界加分与连续加分之和最大的方案，返回 (加分, 首个匹配位置)
+    fn best_subsequence_bonus(
+        &self,
+        query: &[char],
+        target: &[char],
+        bonuses: &[u32],
+    ) -> Option<(u32, usize)> {
+        // best[j] = 当前查询字符落在 j 时的 (最高加分, 首个匹配位置)
+        let mut best: Vec<Option<(u32, usize)>> = target
+            .iter()
+            .enumerate()
+            .map(|(j, &tc)| (tc == query[0]).then(|| (bonuses[j], j)))
+            .collect();
+
+        for &qc in &query[1..] {
+            let mut next = vec![None; target.len()];
+            // 位置 j-1 之前的最优方案 (不含 j-1)
+            let mut prefix_best: Option<(u32, usize)> = None;
+
+            for j in 1..target.len() {
+                if target[j] == qc {
+                    let consecutive =
+                        best[j - 1].map(|(score, first)| (score + self.bonus.consecutive, first));
+                    let candidate = [consecutive, prefix_best]
+                        .into_iter()
+                        .flatten()
+                        .max_by_key(|(score, first)| (*score, std::cmp::Reverse(*first)));
+                    next[j] = candidate.map(|(score, first)| (score + bonuses[j], first));
+                }
+
+                match (best[j - 1], prefix_best) {
+                    (Some(prev), Some(p)) if prev.0 <= p.0 => {},
+                    (Some(prev), _) => prefix_best = Some(prev),
+                    _ => {},
+                }
             }
-        } else {
-            // 所有字符都匹配了
-            return true;
+
+            best = next;
         }
+
+        best.into_iter().flatten().max_by_key(|(score, first)| (*score, std::cmp::Reverse(*first)))
     }
+}
 
-    // 检查是否所有字符都匹配
-    current_char.is_none()
+impl Default for FuzzyMatcher {
+    fn default() -> Self {
+        Self::new(BoundaryBonus::default())
+    }
 }
 
 /// 计算模糊匹配分数
-fn calculate_fuzzy_score(query: &str, target: &str) -> u32 {
-    let mut score = 50u32;
+///
+/// 使用默认分词器和默认边界权重，返回 (是否匹配，匹配分数)
+pub fn fuzzy_match(query: &str, target: &str) -> (bool, u32) {
+    FuzzyMatcher::default().fuzzy_match(query, target)
+}
 
-    // 连续匹配加分
-    let consecutive_bonus = count_consecutive_matches(query, target);
-    score += consecutive_bonus * 10;
+/// 查询是否为目标中某个单词开头的前缀（忽略大小写）
+///
+/// 单词边界按默认分词器判断；中文没有分隔符，每个汉字都可作为开头。
+/// 用于匹配命令的关键词，"bin" 匹配 "recycle bin"，"cle" 不匹配
+pub fn word_prefix_match(query: &str, target: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return false;
+    }
 
-    // 匹配位置靠前加分
-    let first_match_pos = find_first_match_position(query, target);
-    score -= (first_match_pos * 5) as u32;
+    let mut prev = None;
+    target.char_indices().any(|(start, ch)| {
+        let word_start = WordTokenizer.boundary(prev, ch).is_some() || is_cjk(ch);
+        prev = Some(ch);
+        word_start && target[start..].to_lowercase().starts_with(&query)
+    })
+}
 
-    // 长度比例
-    let ratio = query.len() as f32 / target.len() as f32;
-    score += (ratio * 20.0) as u32;
+/// 是否为汉字
+fn is_cjk(ch: char) -> bool {
+    matches!(ch, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}')
+}
 
-    score
+/// 将字符转换为单个小写字符
+///
+/// 小写形式为多个字符时保留原字符，保证与原字符串逐字符对齐
+fn lower_char(ch: char) -> char {
+    let mut lower = ch.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => ch,
+    }
 }
 
-/// 计算连续匹配数量
-fn count_consecutive_matches(query: &str, target: &str) -> u32 {
-    let query_chars: Vec<char> = query.chars().collect();
-    let target_chars: Vec<char> = target.chars().collect();
+/// 计算匹配字符在目标字符串中的字节范围
+///
+/// 按字符逐个比较（忽略大小写），相邻的匹配合并为一个范围。
+/// 返回的范围始终落在字符边界上，可直接用于切片多字节文本（中文、emoji 等）
+pub fn match_ranges(query: &str, target: &str) -> Vec<Range<usize>> {
+    let query_chars: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    if query_chars.is_empty() {
+        return ranges;
+    }
 
-    let mut max_consecutive = 0u32;
-    let mut current_consecutive = 0u32;
-    let mut query_idx = 0usize;
+    let mut query_idx = 0;
+    for (start, tc) in target.char_indices() {
+        if query_idx >= query_chars.len() {
+            break;
+        }
 
-    for target_char in &target_chars {
-        if query_idx < query_chars.len() && *target_char == query_chars[query_idx] {
-            current_consecutive += 1;
+        if chars_match(tc, query_chars[query_idx]) {
+            let end = start + tc.len_utf8();
+            match ranges.last_mut() {
+                // 与上一个匹配相邻则合并
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
             query_idx += 1;
-        } else {
-            max_consecutive = max_consecutive.max(current_consecutive);
-            current_consecutive = 0;
         }
     }
 
-    max_consecutive.max(current_consecutive)
+    ranges
 }
 
-/// 找到第一个匹配字符的位置
-fn find_first_match_position(query: &str, target: &str) -> usize {
-    if let Some(first_char) = query.chars().next() {
-        target.find(first_char).unwrap_or(target.len())
-    } else {
-        0
-    }
+/// 比较目标字符与（已小写的）查询字符
+fn chars_match(target: char, query_lower: char) -> bool {
+    let mut lower = target.to_lowercase();
+    lower.next() == Some(query_lower) && lower.next().is_none()
 }
 
-/// 高亮匹配字符
+/// 按高亮范围切分文本
 ///
-/// 返回带有高亮标记的字符串
-pub fn highlight_matches(query: &str, target: &str) -> String {
-    if query.is_empty() {
-        return target.to_string();
-    }
-
-    let query_lower = query.to_lowercase();
-    let target_lower = target.to_lowercase();
-
-    let mut result = String::new();
-    let mut query_chars = query_lower.chars();
-    let mut current_qc = query_chars.next();
+/// 返回 (片段, 是否高亮) 列表；越界、重叠或不在字符边界上的范围会被忽略
+pub fn split_highlights<'a>(text: &'a str, ranges: &[Range<usize>]) -> Vec<(&'a str, bool)> {
+    let mut fragments = Vec::new();
+    let mut cursor = 0;
+
+    for range in ranges {
+        if range.start < cursor
+            || range.start >= range.end
+            || range.end > text.len()
+            || !text.is_char_boundary(range.start)
+            || !text.is_char_boundary(range.end)
+        {
+            continue;
+        }
 
-    for (tc, original_tc) in target_lower.chars().zip(target.chars()) {
-        if let Some(qc) = current_qc {
-            if tc == qc {
-                result.push('[');
-                result.push(original_tc);
-                result.push(']');
-                current_qc = query_chars.next();
-            } else {
-                result.push(original_tc);
-            }
-        } else {
-            result.push(original_tc);
+        if range.start > cursor {
+            fragments.push((&text[cursor..range.start], false));
         }
+        fragments.push((&text[range.clone()], true));
+        cursor = range.end;
     }
 
-    result
+    if cursor < text.len() {
+        fragments.push((&text[cursor..], false));
+    }
+
+    fragments
 }
 
 #[cfg(test)]
@@ -181,9 +324,97 @@ mod tests {
     }
 
     #[test]
-    fn test_highlight() {
-        let highlighted = highlight_matches("gc", "Google Chrome");
-        assert!(highlighted.contains('['));
-        assert!(highlighted.contains(']'));
+    fn test_boundary_match() {
+        let (matched, nvidia) = fuzzy_match("nvs", "NVIDIA Settings");
+        assert!(matched);
+        let (_, canvas) = fuzzy_match("nvs", "Canvas Editors");
+        assert!(nvidia > canvas);
+
+        let (matched, gcm) = fuzzy_match("gcm", "git-credential-manager");
+        assert!(matched);
+        let (_, magic) = fuzzy_match("gcm", "logic compiler manual");
+        assert!(gcm > magic);
+    }
+
+    #[test]
+    fn test_camel_case_match() {
+        let (matched, camel) = fuzzy_match("vsc", "VisualStudioCode");
+        assert!(matched);
+        let (_, flat) = fuzzy_match("vsc", "Visualstudiocode");
+        assert!(camel > flat);
+    }
+
+    #[test]
+    fn test_boundary_bonus_weights() {
+        let flat = FuzzyMatcher::new(BoundaryBonus {
+            start: 0,
+            separator: 0,
+            case_change: 0,
+            consecutive: 0,
+        });
+        let (_, boundary) = flat.fuzzy_match("gcm", "git-credential-manager");
+        let (_, inner) = flat.fuzzy_match("gcm", "xgxcxm-credential-manager");
+        assert!(boundary >= inner);
+
+        let (_, weighted) = fuzzy_match("gcm", "git-credential-manager");
+        assert!(weighted > boundary);
+    }
+
+    #[test]
+    fn test_word_prefix_match() {
+        assert!(word_prefix_match("bin", "recycle bin"));
+        assert!(word_prefix_match("Recycle B", "recycle bin"));
+        assert!(word_prefix_match("回收站", "清空回收站"));
+        assert!(!word_prefix_match("cle", "recycle bin"));
+        assert!(!word_prefix_match("", "recycle bin"));
+    }
+
+    #[test]
+    fn test_match_ranges() {
+        let ranges = match_ranges("gc", "Google Chrome");
+        assert_eq!(ranges, vec![0..1, 7..8]);
+
+        let ranges = match_ranges("chr", "Google Chrome");
+        assert_eq!(ranges, vec![7..10]);
+    }
+
+    #[test]
+    fn test_match_ranges_cjk() {
+        let target = "打开微信";
+        let ranges = match_ranges("微信", target);
+        assert_eq!(ranges, vec![6..12]);
+        assert_eq!(&target[ranges[0].clone()], "微信");
+    }
+
+    #[test]
+    fn test_match_ranges_emoji() {
+        let target = "🚀 Rocket Launch";
+        let ranges = match_ranges("rl", target);
+        assert_eq!(ranges, vec![5..6, 12..13]);
+        assert_eq!(split_highlights(target, &ranges), vec![
+            ("🚀 ", false),
+            ("R", true),
+            ("ocket ", false),
+            ("L", true),
+            ("aunch", false)
+        ]);
+    }
+
+    #[test]
+    fn test_split_highlights_brackets() {
+        let target = "[v2] App";
+        let ranges = match_ranges("app", target);
+        let fragments = split_highlights(target, &ranges);
+        assert_eq!(fragments, vec![("[v2] ", false), ("App", true)]);
+        assert_eq!(fragments.iter().map(|(text, _)| *text).collect::<String>(), target);
+    }
+
+    #[test]
+    fn test_split_highlights_invalid_ranges() {
+        // 落在多字节字符中间的范围会被忽略
+        let inside_char = 1..2;
+        assert_eq!(split_highlights("微信", &[inside_char]), vec![("微信", false)]);
+        let out_of_bounds = 2..10;
+        assert_eq!(split_highlights("abc", &[out_of_bounds]), vec![("abc", false)]);
     }
 }
@@ -0,0 +1,39 @@
+/// HTTP 下载
+///
+/// 通过 PowerShell 的 `Invoke-WebRequest` 下载文本，避免为几个接口引入 HTTP 客户端
+use anyhow::Result;
+
+use crate::utils::powershell::{self, quote};
+
+/// 传入链接的环境变量
+const URL_VAR: &str = "WERUN_HTTP_URL";
+
+/// 传入请求头的值的环境变量前缀，后接序号
+const HEADER_VAR_PREFIX: &str = "WERUN_HTTP_HEADER_";
+
+/// 下载文本，按 UTF-8 读取响应
+pub fn download(url: &str) -> Result<String> {
+    download_with_headers(url, &[])
+}
+
+/// 带请求头下载文本（如接口的认证信息），按 UTF-8 读取响应
+///
+/// 链接和请求头的值通过环境变量传给 PowerShell，令牌不出现在命令行中
+pub fn download_with_headers(url: &str, headers: &[(&str, &str)]) -> Result<String> {
+    let mut env = vec![(URL_VAR.to_string(), url)];
+    let mut fields = Vec::new();
+    for (index, (name, value)) in headers.iter().enumerate() {
+        let var = format!("{}{}", HEADER_VAR_PREFIX, index);
+        fields.push(format!("{} = $env:{}", quote(name), var));
+        env.push((var, *value));
+    }
+
+    let request = format!(
+        "(Invoke-WebRequest -UseBasicParsing -TimeoutSec 15 -Uri $env:{} -Headers @{{ {} \
+         }}).Content",
+        URL_VAR,
+        fields.join("; ")
+    );
+    let script = ["[Console]::OutputEncoding = [Text.Encoding]::UTF8", &request].join("; ");
+    powershell::run_with_env(&script, &env)
+}
@@ -0,0 +1,139 @@
+/// 链接处理
+///
+/// 去除链接中的跟踪参数、提取网页标题、生成 Markdown 链接，以及跟随重定向展开短链接
+use anyhow::Result;
+
+use crate::utils::{encoding::html_unescape, powershell};
+
+/// 除 `utm_*` 外的常见跟踪参数
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "twclid", "yclid", "igshid",
+    "mc_cid", "mc_eid", "_hsenc", "_hsmi", "mkt_tok", "ref_src", "spm",
+];
+
+/// 网页标题的最大字符数
+const MAX_TITLE_CHARS: usize = 200;
+
+/// 是否为 http(s) 链接
+pub fn is_url(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    let rest = lower.strip_prefix("https://").or_else(|| lower.strip_prefix("http://"));
+    rest.is_some_and(|rest| !rest.is_empty()) && !text.chars().any(char::is_whitespace)
+}
+
+/// 是否为跟踪参数
+fn is_tracking_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.starts_with("utm_") || TRACKING_PARAMS.contains(&name.as_str())
+}
+
+/// 去除跟踪参数，返回清理后的链接和去除的参数个数；其余参数和片段保持原样
+pub fn strip_tracking(url: &str) -> (String, usize) {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((base, query)) = rest.split_once('?') else {
+        return (url.to_string(), 0);
+    };
+
+    let params: Vec<&str> = query.split('&').filter(|param| !param.is_empty()).collect();
+    let kept: Vec<&str> = params
+        .iter()
+        .copied()
+        .filter(|param| !is_tracking_param(param.split('=').next().unwrap_or_default()))
+        .collect();
+
+    let mut cleaned = base.to_string();
+    if !kept.is_empty() {
+        cleaned.push('?');
+        cleaned.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        cleaned.push('#');
+        cleaned.push_str(fragment);
+    }
+    (cleaned, params.len() - kept.len())
+}
+
+/// 提取 HTML 中 `<title>` 的文本，合并空白并反转义实体
+pub fn page_title(html: &str) -> Option<String> {
+    // 只转换 ASCII 大小写，保证下标与原文一致
+    let lower = html.to_ascii_lowercase();
+    let start = lower.find("<title")?;
+    let content_start = start + lower[start..].find('>')? + 1;
+    let content_end = content_start + lower[content_start..].find("</title")?;
+
+    let title = html_unescape(&html[content_start..content_end]);
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.is_empty() {
+        return None;
+    }
+    Some(title.chars().take(MAX_TITLE_CHARS).collect())
+}
+
+/// Markdown 链接：转义标题中的方括号，链接中的空格和括号按百分号编码
+pub fn markdown_link(title: &str, url: &str) -> String {
+    let title = title.replace('[', "\\[").replace(']', "\\]");
+    let url = url.replace(' ', "%20").replace('(', "%28").replace(')', "%29");
+    format!("[{}]({})", title, url)
+}
+
+/// 打开后的网页
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    /// 跟随重定向后的最终地址
+    pub url: String,
+    /// 网页标题
+    pub title: Option<String>,
+}
+
+/// 跟随重定向打开链接，返回最终地址和网页标题
+///
+/// Windows PowerShell 的响应为 `HttpWebResponse`（`ResponseUri`），PowerShell 7 为
+/// `HttpResponseMessage`（`RequestMessage.RequestUri`），两者都兼容
+pub fn fetch_page(url: &str) -> Result<Page> {
+    let request = format!(
+        "$r = Invoke-WebRequest -UseBasicParsing -TimeoutSec 15 -Uri '{}'",
+        url.replace('\'', "''")
+    );
+    let script = [
+        "[Console]::OutputEncoding = [Text.Encoding]::UTF8",
+        &request,
+        "$u = $r.BaseResponse.ResponseUri",
+        "if (-not $u) { $u = $r.BaseResponse.RequestMessage.RequestUri }",
+        "$u.AbsoluteUri",
+        "$r.Content",
+    ]
+    .join("; ");
+    let output = powershell::run(&script)?;
+    let (final_url, content) = output.split_once('\n').unwrap_or((&output, ""));
+    let final_url = final_url.trim();
+
+    Ok(Page {
+        url: if is_url(final_url) { final_url.to_string() } else { url.to_string() },
+        title: page_title(content),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_and_format() {
+        assert!(is_url("https://example.com/a?b=1"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("example.com"));
+
+        let url = "https://example.com/p?id=7&utm_source=x&UTM_Medium=y&fbclid=z#top";
+        assert_eq!(strip_tracking(url), ("https://example.com/p?id=7#top".to_string(), 3));
+        assert_eq!(strip_tracking("https://a.io/?gclid=1").0, "https://a.io/");
+        assert_eq!(strip_tracking("https://a.io/x"), ("https://a.io/x".to_string(), 0));
+
+        let html = "<html><HEAD><Title>\n  Rust &amp; 中文\n</TITLE></head></html>";
+        assert_eq!(page_title(html).as_deref(), Some("Rust & 中文"));
+        assert_eq!(page_title("<title> </title>"), None);
+        assert_eq!(markdown_link("[x] y", "https://a.io/(1)"), r"[\[x\] y](https://a.io/%281%29)");
+    }
+}
@@ -2,4 +2,28 @@
 ///
 /// 提供各种辅助功能
 pub mod clipboard;
+pub mod clipboard_store;
+pub mod color_palette;
+pub mod cron;
+pub mod elevation;
+pub mod encoding;
+pub mod exchange_rates;
+pub mod expression;
+pub mod file_index;
+pub mod file_preview;
+pub mod folder_bookmarks;
 pub mod fuzzy;
+pub mod http;
+pub mod links;
+pub mod night_light;
+pub mod powershell;
+pub mod quotes;
+pub mod random;
+pub mod recent_docs;
+pub mod registry;
+pub mod shell;
+pub mod shell_link;
+pub mod syntax;
+pub mod system_info;
+pub mod timezone;
+pub mod units;
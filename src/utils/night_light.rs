@@ -0,0 +1,108 @@
+/// 夜间模式
+///
+/// 系统没有公开夜间模式的接口，设置页把状态存放在 CloudStore 的二进制值中：
+/// 第 18 字节为 0x15 表示开启、0x13 表示关闭，开启时在第 23 字节处多出 `10 00` 两个字节；
+/// 第 10 到 14 字节为修改时间戳，写入时须递增，系统才会应用新状态
+use super::registry::{binary_value, set_binary_value, RegistryRoot};
+
+/// 夜间模式状态所在的注册表键
+const STATE_KEY: &str = concat!(
+    r"Software\Microsoft\Windows\CurrentVersion\CloudStore\Store\DefaultAccount\Current\",
+    r"default$windows.data.bluelightreduction.bluelightreductionstate\",
+    r"windows.data.bluelightreduction.bluelightreductionstate"
+);
+
+/// 状态值名称
+const STATE_VALUE: &str = "Data";
+
+/// 状态标志所在的位置
+const FLAG_OFFSET: usize = 18;
+
+/// 开启标志
+const FLAG_ON: u8 = 0x15;
+
+/// 关闭标志
+const FLAG_OFF: u8 = 0x13;
+
+/// 开启时多出的字节及其位置
+const ON_EXTRA: [u8; 2] = [0x10, 0x00];
+const ON_EXTRA_OFFSET: usize = 23;
+
+/// 时间戳所在的范围
+const TIMESTAMP: std::ops::Range<usize> = 10..15;
+
+/// 状态数据是否表示已开启，数据格式不符时返回 `None`
+pub fn is_enabled_in(data: &[u8]) -> Option<bool> {
+    match *data.get(FLAG_OFFSET)? {
+        FLAG_ON => Some(true),
+        FLAG_OFF => Some(false),
+        _ => None,
+    }
+}
+
+/// 生成切换后的状态数据，数据格式不符时返回 `None`
+pub fn toggled(data: &[u8]) -> Option<Vec<u8>> {
+    let enabled = is_enabled_in(data)?;
+    if data.len() < ON_EXTRA_OFFSET + ON_EXTRA.len() {
+        return None;
+    }
+
+    let mut data = data.to_vec();
+    if enabled {
+        if data[ON_EXTRA_OFFSET..ON_EXTRA_OFFSET + ON_EXTRA.len()] != ON_EXTRA {
+            return None;
+        }
+        data.drain(ON_EXTRA_OFFSET..ON_EXTRA_OFFSET + ON_EXTRA.len());
+        data[FLAG_OFFSET] = FLAG_OFF;
+    } else {
+        data.splice(ON_EXTRA_OFFSET..ON_EXTRA_OFFSET, ON_EXTRA);
+        data[FLAG_OFFSET] = FLAG_ON;
+    }
+
+    if let Some(byte) = data[TIMESTAMP].iter_mut().find(|byte| **byte != 0xFF) {
+        *byte += 1;
+    }
+    Some(data)
+}
+
+/// 夜间模式是否已开启，无法读取时返回 `None`
+pub fn is_enabled() -> Option<bool> {
+    is_enabled_in(&binary_value(RegistryRoot::CurrentUser, STATE_KEY, STATE_VALUE)?)
+}
+
+/// 切换夜间模式，返回切换后是否开启
+pub fn toggle() -> std::io::Result<bool> {
+    let data = binary_value(RegistryRoot::CurrentUser, STATE_KEY, STATE_VALUE)
+        .ok_or(std::io::ErrorKind::NotFound)?;
+    let updated = toggled(&data).ok_or(std::io::ErrorKind::InvalidData)?;
+    set_binary_value(RegistryRoot::CurrentUser, STATE_KEY, STATE_VALUE, &updated)?;
+    Ok(is_enabled_in(&updated) == Some(true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_round_trip() {
+        let mut off = vec![0u8; 41];
+        off[FLAG_OFFSET] = FLAG_OFF;
+        off[TIMESTAMP.start] = 0xFF;
+        off[40] = 0xAA;
+
+        let on = toggled(&off).unwrap();
+        assert_eq!(is_enabled_in(&on), Some(true));
+        assert_eq!(on.len(), off.len() + 2);
+        assert_eq!(&on[ON_EXTRA_OFFSET..ON_EXTRA_OFFSET + 2], &ON_EXTRA);
+        assert_eq!(on[42], 0xAA);
+        // 时间戳的第一个字节已是 0xFF，递增下一个
+        assert_eq!((on[10], on[11]), (0xFF, 1));
+
+        let back = toggled(&on).unwrap();
+        assert_eq!(is_enabled_in(&back), Some(false));
+        assert_eq!(back.len(), off.len());
+        assert_eq!((back[11], back[40]), (2, 0xAA));
+
+        assert_eq!(toggled(&[0u8; 41]), None);
+    }
+}
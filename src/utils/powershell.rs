@@ -0,0 +1,38 @@
+/// PowerShell 脚本
+///
+/// 网络请求和少量系统查询通过 PowerShell 完成，避免为此引入额外的依赖
+use std::process::Command;
+
+use anyhow::{anyhow, Result};
+
+/// 运行 PowerShell 脚本（不显示窗口），返回标准输出
+pub fn run(script: &str) -> Result<String> {
+    run_with_env(script, &[])
+}
+
+/// 运行 PowerShell 脚本并设置环境变量，脚本通过 `$env:名称` 读取
+///
+/// 令牌等敏感内容通过环境变量传入，不出现在其他进程可见的命令行中
+pub fn run_with_env(script: &str, env: &[(String, &str)]) -> Result<String> {
+    let mut cmd = Command::new("powershell");
+    cmd.args(["-NoProfile", "-NonInteractive", "-Command", script]);
+    cmd.envs(env.iter().map(|(name, value)| (name, value)));
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        return Err(anyhow!("{}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 单引号字符串字面量，内部的单引号加倍
+pub fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
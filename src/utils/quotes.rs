@@ -0,0 +1,301 @@
+/// 行情缓存
+///
+/// 从可配置的行情接口下载股票、外汇和加密货币的价格并保存到配置目录；接口 URL 中的
+/// `{symbol}` 替换为代码，响应按 Yahoo Finance 图表接口或 CoinGecko 简单价格接口的格式解析
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Mutex, RwLock},
+};
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{error::WerunError, progress::global_progress, scheduler::global_scheduler},
+    utils::http::download,
+};
+
+/// 常用加密货币的代码和 CoinGecko ID
+pub const CRYPTO: &[(&str, &str)] = &[
+    ("BTC", "bitcoin"),
+    ("ETH", "ethereum"),
+    ("SOL", "solana"),
+    ("BNB", "binancecoin"),
+    ("XRP", "ripple"),
+    ("DOGE", "dogecoin"),
+    ("ADA", "cardano"),
+    ("LTC", "litecoin"),
+];
+
+/// 行情接口的响应格式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteFormat {
+    /// Yahoo Finance 图表接口（`/v8/finance/chart/{symbol}`），支持股票、外汇和加密货币
+    #[default]
+    Yahoo,
+    /// CoinGecko 简单价格接口（`/simple/price?ids={symbol}`），只支持加密货币
+    CoinGecko,
+}
+
+/// 行情
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Quote {
+    /// 代码，如 `AAPL`、`BTC`、`USD/CNY`
+    pub symbol: String,
+    /// 名称
+    pub name: Option<String>,
+    /// 最新价格
+    pub price: f64,
+    /// 前一交易日收盘价（加密货币为 24 小时前的价格）
+    pub previous_close: f64,
+    /// 计价货币
+    pub currency: String,
+    /// 下载时间 (Unix 时间戳，秒)
+    pub fetched_at: i64,
+}
+
+impl Quote {
+    /// 涨跌额
+    pub fn change(&self) -> f64 {
+        self.price - self.previous_close
+    }
+
+    /// 涨跌幅（百分比）
+    pub fn change_percent(&self) -> f64 {
+        if self.previous_close == 0.0 {
+            0.0
+        } else {
+            self.change() / self.previous_close * 100.0
+        }
+    }
+
+    /// 是否超过有效期
+    pub fn is_stale(&self, now: i64, max_age_secs: i64) -> bool {
+        now - self.fetched_at >= max_age_secs
+    }
+}
+
+/// 规范化输入的代码：大写，外汇以 `/` 分隔，如 `usd/cny` → `USD/CNY`
+pub fn normalize_symbol(symbol: &str) -> String {
+    symbol.trim().to_uppercase()
+}
+
+/// 是否为已知的加密货币代码
+pub fn is_crypto(symbol: &str) -> bool {
+    CRYPTO.iter().any(|(code, _)| code.eq_ignore_ascii_case(symbol.trim()))
+}
+
+/// 接口使用的代码：Yahoo 的加密货币为 `BTC-USD`、外汇为 `USDCNY=X`，CoinGecko 为币种 ID
+pub fn api_symbol(symbol: &str, format: QuoteFormat) -> String {
+    let symbol = normalize_symbol(symbol);
+    let crypto = CRYPTO.iter().find(|(code, _)| *code == symbol);
+    match format {
+        QuoteFormat::Yahoo => {
+            if crypto.is_some() {
+                return format!("{}-USD", symbol);
+            }
+            match symbol.split_once('/') {
+                Some((base, quote)) => format!("{}{}=X", base, quote),
+                None => symbol,
+            }
+        },
+        QuoteFormat::CoinGecko => {
+            crypto.map(|(_, id)| id.to_string()).unwrap_or_else(|| symbol.to_lowercase())
+        },
+    }
+}
+
+/// 解析行情接口的响应
+pub fn parse(json: &str, symbol: &str, format: QuoteFormat, fetched_at: i64) -> Option<Quote> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let symbol = normalize_symbol(symbol);
+
+    match format {
+        QuoteFormat::Yahoo => {
+            let meta = value.get("chart")?.get("result")?.get(0)?.get("meta")?;
+            let price = meta.get("regularMarketPrice")?.as_f64()?;
+            let previous_close = meta
+                .get("previousClose")
+                .or_else(|| meta.get("chartPreviousClose"))
+                .and_then(|close| close.as_f64())
+                .unwrap_or(price);
+            let name = meta
+                .get("shortName")
+                .or_else(|| meta.get("longName"))
+                .and_then(|name| name.as_str())
+                .map(str::to_string);
+            let currency = meta.get("currency").and_then(|c| c.as_str()).unwrap_or_default();
+            Some(Quote {
+                symbol,
+                name,
+                price,
+                previous_close,
+                currency: currency.to_uppercase(),
+                fetched_at,
+            })
+        },
+        // `{"bitcoin": {"usd": 67000.0, "usd_24h_change": 1.5}}`
+        QuoteFormat::CoinGecko => {
+            let (id, prices) = value.as_object()?.iter().next()?;
+            let prices = prices.as_object()?;
+            let (currency, price) = prices
+                .iter()
+                .find(|(key, _)| !key.contains('_'))
+                .and_then(|(key, price)| Some((key, price.as_f64()?)))?;
+            let percent = prices
+                .get(&format!("{}_24h_change", currency))
+                .and_then(|change| change.as_f64())
+                .unwrap_or_default();
+            Some(Quote {
+                symbol,
+                name: Some(id.clone()),
+                price,
+                previous_close: price / (1.0 + percent / 100.0),
+                currency: currency.to_uppercase(),
+                fetched_at,
+            })
+        },
+    }
+}
+
+/// 行情缓存
+pub struct QuoteCache {
+    /// 缓存文件路径
+    path: PathBuf,
+    /// 已加载的行情，键为规范化的代码
+    quotes: RwLock<HashMap<String, Quote>>,
+    /// 最近一次下载失败的时间 (Unix 时间戳，秒) 和原因
+    errors: RwLock<HashMap<String, (i64, String)>>,
+    /// 正在下载的代码
+    fetching: Mutex<HashSet<String>>,
+}
+
+impl QuoteCache {
+    /// 默认缓存文件路径（配置目录下）
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("werun").join("quotes.json")
+    }
+
+    /// 创建缓存并读取已保存的行情
+    pub fn open(path: &Path) -> Self {
+        let quotes = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            path: path.to_path_buf(),
+            quotes: RwLock::new(quotes),
+            errors: RwLock::new(HashMap::new()),
+            fetching: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 代码的当前行情（可能已过期）
+    pub fn get(&self, symbol: &str) -> Option<Quote> {
+        self.quotes.read().unwrap().get(&normalize_symbol(symbol)).cloned()
+    }
+
+    /// 代码最近一次下载失败的原因
+    pub fn error(&self, symbol: &str) -> Option<String> {
+        self.errors.read().unwrap().get(&normalize_symbol(symbol)).map(|(_, error)| error.clone())
+    }
+
+    /// 是否正在下载代码的行情
+    pub fn is_fetching(&self, symbol: &str) -> bool {
+        self.fetching.lock().unwrap().contains(&normalize_symbol(symbol))
+    }
+
+    /// 没有行情或已超过有效期时在后台下载，同一代码同一时间只下载一次，下载失败后在有效期内
+    /// 不再重试；下载完成后通知界面刷新结果
+    pub fn refresh_if_stale(
+        &'static self,
+        url: &str,
+        format: QuoteFormat,
+        symbol: &str,
+        max_age_secs: i64,
+    ) {
+        let symbol = normalize_symbol(symbol);
+        let now = chrono::Utc::now().timestamp();
+        let failed_at = self.errors.read().unwrap().get(&symbol).map(|(failed_at, _)| *failed_at);
+        let fresh = self.get(&symbol).is_some_and(|quote| !quote.is_stale(now, max_age_secs))
+            || failed_at.is_some_and(|failed_at| now - failed_at < max_age_secs);
+        if fresh || !self.fetching.lock().unwrap().insert(symbol.clone()) {
+            return;
+        }
+
+        let url = url.to_string();
+        global_scheduler().run_or_defer(&format!("quote:{}", symbol), move || {
+            std::thread::spawn(move || {
+                match self.refresh(&url, format, &symbol) {
+                    Ok(()) => {
+                        self.errors.write().unwrap().remove(&symbol);
+                    },
+                    Err(e) => {
+                        let error = (chrono::Utc::now().timestamp(), e.to_string());
+                        self.errors.write().unwrap().insert(symbol.clone(), error);
+                        WerunError::Network(e).log();
+                    },
+                }
+                self.fetching.lock().unwrap().remove(&symbol);
+                global_progress().mark_results_changed();
+            });
+        });
+    }
+
+    /// 下载行情并保存
+    fn refresh(&self, url: &str, format: QuoteFormat, symbol: &str) -> Result<()> {
+        let url = url.replace("{symbol}", &urlencoding::encode(&api_symbol(symbol, format)));
+        let body = download(&url)?;
+        let quote = parse(&body, symbol, format, chrono::Utc::now().timestamp())
+            .ok_or_else(|| anyhow!("无法解析行情接口的响应: {}", url))?;
+
+        log::info!("已更新行情: {} {}", quote.symbol, quote.price);
+        let quotes = {
+            let mut quotes = self.quotes.write().unwrap();
+            quotes.insert(quote.symbol.clone(), quote);
+            serde_json::to_string(&*quotes)?
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, quotes)?;
+        Ok(())
+    }
+}
+
+/// 全局行情缓存
+static GLOBAL_QUOTES: Lazy<QuoteCache> =
+    Lazy::new(|| QuoteCache::open(&QuoteCache::default_path()));
+
+/// 获取全局行情缓存
+pub fn global_quotes() -> &'static QuoteCache {
+    &GLOBAL_QUOTES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbols_and_parse() {
+        assert_eq!(api_symbol("btc", QuoteFormat::Yahoo), "BTC-USD");
+        assert_eq!(api_symbol("usd/cny", QuoteFormat::Yahoo), "USDCNY=X");
+        assert_eq!(api_symbol("aapl", QuoteFormat::Yahoo), "AAPL");
+        assert_eq!(api_symbol("Eth", QuoteFormat::CoinGecko), "ethereum");
+
+        let yahoo = r#"{"chart": {"result": [{"meta": {"currency": "USD", "symbol": "AAPL",
+            "regularMarketPrice": 110.0, "chartPreviousClose": 100.0}}]}}"#;
+        let quote = parse(yahoo, "aapl", QuoteFormat::Yahoo, 0).unwrap();
+        assert_eq!((quote.symbol.as_str(), quote.currency.as_str()), ("AAPL", "USD"));
+        assert_eq!(quote.change(), 10.0);
+        assert_eq!(quote.change_percent(), 10.0);
+
+        let gecko = r#"{"bitcoin": {"usd": 120.0, "usd_24h_change": 20.0}}"#;
+        let quote = parse(gecko, "btc", QuoteFormat::CoinGecko, 0).unwrap();
+        assert_eq!(quote.previous_close, 100.0);
+        assert!(parse(r#"{"chart": {"result": []}}"#, "X", QuoteFormat::Yahoo, 0).is_none());
+    }
+}
@@ -0,0 +1,57 @@
+/// 安全随机数
+///
+/// 基于系统的安全随机数生成器（Windows 上为 BCryptGenRandom），
+/// 供密码、UUID 等需要不可预测随机数的功能使用
+use anyhow::{anyhow, Result};
+
+/// 用随机字节填充缓冲区
+pub fn fill(buffer: &mut [u8]) -> Result<()> {
+    getrandom::getrandom(buffer).map_err(|e| anyhow!("获取随机数失败: {}", e))
+}
+
+/// 指定长度的随机字节
+pub fn bytes(len: usize) -> Result<Vec<u8>> {
+    let mut buffer = vec![0u8; len];
+    fill(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// 均匀分布在 `0..bound` 中的随机数，`bound` 须大于 0
+pub fn below(bound: u64) -> Result<u64> {
+    // 拒绝落在最后一段不完整区间内的值，避免取模偏差
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let mut buffer = [0u8; 8];
+        fill(&mut buffer)?;
+        let value = u64::from_le_bytes(buffer);
+        if value < zone {
+            return Ok(value % bound);
+        }
+    }
+}
+
+/// 均匀分布在 `min..=max` 中的随机整数
+pub fn in_range(min: i64, max: i64) -> Result<i64> {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let span = max.abs_diff(min);
+    if span == u64::MAX {
+        let mut buffer = [0u8; 8];
+        fill(&mut buffer)?;
+        return Ok(i64::from_le_bytes(buffer));
+    }
+    Ok(min.wrapping_add(below(span + 1)? as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(bytes(16).unwrap().len(), 16);
+        assert!((0..1000).all(|_| below(7).unwrap() < 7));
+        assert!((0..1000).all(|_| (-3..=3).contains(&in_range(3, -3).unwrap())));
+        assert_eq!(in_range(5, 5).unwrap(), 5);
+        in_range(i64::MIN, i64::MAX).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+/// 最近文档
+///
+/// 读取 Windows 记录的最近使用文件：`%APPDATA%\Microsoft\Windows\Recent` 中的快捷方式，
+/// 以及各应用的跳转列表（AutomaticDestinations 为复合文档，CustomDestinations
+/// 为拼接的快捷方式）
+use std::{
+    collections::HashSet,
+    io::Read,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::shell_link;
+
+/// 最近文档来源
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecentSource {
+    /// 最近使用文件夹
+    RecentFolder,
+    /// 应用跳转列表
+    JumpList,
+}
+
+impl RecentSource {
+    /// 显示名称
+    pub fn display(&self) -> &'static str {
+        match self {
+            RecentSource::RecentFolder => "最近使用",
+            RecentSource::JumpList => "跳转列表",
+        }
+    }
+}
+
+/// 最近文档
+#[derive(Clone, Debug)]
+pub struct RecentDocument {
+    /// 目标路径
+    pub path: String,
+    /// 最近访问时间（快捷方式或跳转列表的修改时间）
+    pub accessed: SystemTime,
+    /// 来源
+    pub source: RecentSource,
+}
+
+impl RecentDocument {
+    /// 访问时间 (Unix 时间戳，毫秒)
+    pub fn accessed_millis(&self) -> i64 {
+        self.accessed.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+    }
+}
+
+/// 最近使用文件夹
+pub fn recent_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join("Microsoft\\Windows\\Recent"))
+}
+
+/// 读取最近文档，按访问时间倒序，同一路径只保留最近一次
+///
+/// 目标已不存在的条目会被跳过
+pub fn read_recent(limit: usize) -> Vec<RecentDocument> {
+    let Some(dir) = recent_dir() else {
+        return Vec::new();
+    };
+
+    let mut documents = read_recent_folder(&dir);
+    documents.extend(read_jump_lists(&dir.join("AutomaticDestinations"), read_automatic));
+    documents.extend(read_jump_lists(&dir.join("CustomDestinations"), read_custom));
+
+    // 稳定排序，同一时间内保留跳转列表中的先后顺序
+    documents.sort_by_key(|d| std::cmp::Reverse(d.accessed));
+
+    let mut seen = HashSet::new();
+    documents
+        .into_iter()
+        .filter(|d| seen.insert(d.path.to_lowercase()))
+        .filter(|d| Path::new(&d.path).exists())
+        .take(limit)
+        .collect()
+}
+
+/// 读取最近使用文件夹中的快捷方式
+fn read_recent_folder(dir: &Path) -> Vec<RecentDocument> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|e| e == "lnk").unwrap_or(false))
+        .filter_map(|path| {
+            Some(RecentDocument {
+                path: shell_link::read_target(&path)?,
+                accessed: modified(&path)?,
+                source: RecentSource::RecentFolder,
+            })
+        })
+        .collect()
+}
+
+/// 读取目录下所有跳转列表文件
+fn read_jump_lists(dir: &Path, read: fn(&Path) -> Vec<String>) -> Vec<RecentDocument> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut documents = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        // 跳转列表只记录整体修改时间，其中的条目按列表顺序排列
+        let Some(accessed) = modified(&path) else {
+            continue;
+        };
+
+        for target in read(&path) {
+            documents.push(RecentDocument {
+                path: target,
+                accessed,
+                source: RecentSource::JumpList,
+            });
+        }
+    }
+    documents
+}
+
+/// 读取 AutomaticDestinations 跳转列表
+///
+/// 复合文档中除 DestList 外的每个流都是一个快捷方式，流名为十六进制序号，序号越大越新
+fn read_automatic(path: &Path) -> Vec<String> {
+    let Ok(mut file) = cfb::open(path) else {
+        return Vec::new();
+    };
+
+    let mut streams: Vec<(u64, PathBuf)> = file
+        .read_root_storage()
+        .filter(|entry| entry.is_stream())
+        .filter_map(|entry| {
+            let number = u64::from_str_radix(entry.name(), 16).ok()?;
+            Some((number, entry.path().to_path_buf()))
+        })
+        .collect();
+    streams.sort_by_key(|(number, _)| std::cmp::Reverse(*number));
+
+    let mut targets = Vec::new();
+    for (_, stream_path) in streams {
+        let mut data = Vec::new();
+        let read = file.open_stream(&stream_path).and_then(|mut s| s.read_to_end(&mut data));
+        if read.is_ok() {
+            targets.extend(shell_link::parse_target(&data));
+        }
+    }
+    targets
+}
+
+/// 读取 CustomDestinations 跳转列表
+///
+/// 文件由分类信息和若干快捷方式拼接而成，按文件头签名定位每个快捷方式
+fn read_custom(path: &Path) -> Vec<String> {
+    let Ok(data) = std::fs::read(path) else {
+        return Vec::new();
+    };
+
+    data.windows(shell_link::SIGNATURE.len())
+        .enumerate()
+        .filter(|(_, window)| *window == shell_link::SIGNATURE)
+        .filter_map(|(offset, _)| shell_link::parse_target(&data[offset..]))
+        .collect()
+}
+
+/// 文件修改时间
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
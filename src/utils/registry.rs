@@ -0,0 +1,342 @@
+/// 注册表根键
+///
+/// 本模块按路径枚举子键、读写字符串值和二进制值、读取整数值；非 Windows 平台没有注册表，
+/// 读取函数返回空结果，写入函数返回 `Unsupported` 错误
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RegistryRoot {
+    /// HKEY_CURRENT_USER
+    CurrentUser,
+    /// HKEY_LOCAL_MACHINE
+    LocalMachine,
+}
+
+impl RegistryRoot {
+    /// 全部根键，按当前用户优先的顺序
+    pub const ALL: [RegistryRoot; 2] = [RegistryRoot::CurrentUser, RegistryRoot::LocalMachine];
+
+    /// 对应的预定义句柄
+    #[cfg(target_os = "windows")]
+    fn hkey(self) -> windows::Win32::System::Registry::HKEY {
+        use windows::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+        match self {
+            RegistryRoot::CurrentUser => HKEY_CURRENT_USER,
+            RegistryRoot::LocalMachine => HKEY_LOCAL_MACHINE,
+        }
+    }
+}
+
+/// 枚举键下的子键名称，键不存在时返回空列表
+pub fn subkeys(root: RegistryRoot, path: &str) -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR, PWSTR},
+            Win32::System::Registry::{RegCloseKey, RegEnumKeyExW, RegOpenKeyExW, HKEY, KEY_READ},
+        };
+
+        let path = HSTRING::from(path);
+        let mut key = HKEY::default();
+        let mut names = Vec::new();
+
+        unsafe {
+            if RegOpenKeyExW(root.hkey(), PCWSTR(path.as_ptr()), 0, KEY_READ, &mut key).is_err() {
+                return names;
+            }
+
+            for index in 0.. {
+                let mut name = [0u16; 256];
+                let mut name_len = name.len() as u32;
+                let status = RegEnumKeyExW(
+                    key,
+                    index,
+                    PWSTR(name.as_mut_ptr()),
+                    &mut name_len,
+                    None,
+                    PWSTR::null(),
+                    None,
+                    None,
+                );
+                if status.is_err() {
+                    break;
+                }
+                names.push(String::from_utf16_lossy(&name[..name_len as usize]));
+            }
+
+            let _ = RegCloseKey(key);
+        }
+
+        names
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path);
+        Vec::new()
+    }
+}
+
+/// 读取字符串值，`name` 为 `None` 时读取默认值
+///
+/// REG_EXPAND_SZ 会自动展开环境变量；值不存在、为空或不是字符串时返回 `None`
+pub fn string_value(root: RegistryRoot, path: &str, name: Option<&str>) -> Option<String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::System::Registry::{RegGetValueW, RRF_RT_REG_SZ},
+        };
+
+        let path = HSTRING::from(path);
+        let name = name.map(HSTRING::from);
+        let name = name.as_ref().map_or(PCWSTR::null(), |name| PCWSTR(name.as_ptr()));
+
+        unsafe {
+            // 先查询所需的缓冲区大小（字节数，包含结尾的 0）
+            let mut size = 0u32;
+            let status = RegGetValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                name,
+                RRF_RT_REG_SZ,
+                None,
+                None,
+                Some(&mut size),
+            );
+            if status.is_err() {
+                return None;
+            }
+
+            let mut data = vec![0u16; (size as usize).div_ceil(2)];
+            let status = RegGetValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                name,
+                RRF_RT_REG_SZ,
+                None,
+                Some(data.as_mut_ptr() as *mut _),
+                Some(&mut size),
+            );
+            if status.is_err() {
+                return None;
+            }
+
+            let len = data.iter().position(|&c| c == 0).unwrap_or(data.len());
+            let value = String::from_utf16_lossy(&data[..len]);
+            (!value.trim().is_empty()).then_some(value)
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name);
+        None
+    }
+}
+
+/// 读取二进制值 (REG_BINARY)，值不存在或类型不符时返回 `None`
+pub fn binary_value(root: RegistryRoot, path: &str, name: &str) -> Option<Vec<u8>> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::System::Registry::{RegGetValueW, RRF_RT_REG_BINARY},
+        };
+
+        let path = HSTRING::from(path);
+        let name = HSTRING::from(name);
+
+        unsafe {
+            let mut size = 0u32;
+            let status = RegGetValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                RRF_RT_REG_BINARY,
+                None,
+                None,
+                Some(&mut size),
+            );
+            if status.is_err() {
+                return None;
+            }
+
+            let mut data = vec![0u8; size as usize];
+            let status = RegGetValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                RRF_RT_REG_BINARY,
+                None,
+                Some(data.as_mut_ptr() as *mut _),
+                Some(&mut size),
+            );
+            if status.is_err() {
+                return None;
+            }
+
+            data.truncate(size as usize);
+            Some(data)
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name);
+        None
+    }
+}
+
+/// 读取 32 位整数值 (REG_DWORD)，值不存在或类型不符时返回 `None`
+pub fn dword_value(root: RegistryRoot, path: &str, name: &str) -> Option<u32> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::System::Registry::{RegGetValueW, RRF_RT_REG_DWORD},
+        };
+
+        let path = HSTRING::from(path);
+        let name = HSTRING::from(name);
+
+        unsafe {
+            let mut value = 0u32;
+            let mut size = std::mem::size_of::<u32>() as u32;
+            let status = RegGetValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                RRF_RT_REG_DWORD,
+                None,
+                Some(&mut value as *mut u32 as *mut _),
+                Some(&mut size),
+            );
+            status.is_ok().then_some(value)
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name);
+        None
+    }
+}
+
+/// 写入二进制值 (REG_BINARY)，键不存在时创建
+pub fn set_binary_value(
+    root: RegistryRoot,
+    path: &str,
+    name: &str,
+    value: &[u8],
+) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::{
+                Foundation::ERROR_SUCCESS,
+                System::Registry::{RegSetKeyValueW, REG_BINARY},
+            },
+        };
+
+        let path = HSTRING::from(path);
+        let name = HSTRING::from(name);
+
+        let status = unsafe {
+            RegSetKeyValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                REG_BINARY.0,
+                Some(value.as_ptr() as *const _),
+                value.len() as u32,
+            )
+        };
+        match status {
+            ERROR_SUCCESS => Ok(()),
+            status => Err(std::io::Error::from_raw_os_error(status.0 as i32)),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name, value);
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// 写入字符串值 (REG_SZ)，键不存在时创建
+pub fn set_string_value(
+    root: RegistryRoot,
+    path: &str,
+    name: &str,
+    value: &str,
+) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::{
+                Foundation::ERROR_SUCCESS,
+                System::Registry::{RegSetKeyValueW, REG_SZ},
+            },
+        };
+
+        let path = HSTRING::from(path);
+        let name = HSTRING::from(name);
+        // 数据包含结尾的 0
+        let data: Vec<u16> = value.encode_utf16().chain(std::iter::once(0)).collect();
+
+        let status = unsafe {
+            RegSetKeyValueW(
+                root.hkey(),
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                REG_SZ.0,
+                Some(data.as_ptr() as *const _),
+                (data.len() * 2) as u32,
+            )
+        };
+        match status {
+            ERROR_SUCCESS => Ok(()),
+            status => Err(std::io::Error::from_raw_os_error(status.0 as i32)),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name, value);
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// 删除值，值不存在时视为成功
+pub fn delete_value(root: RegistryRoot, path: &str, name: &str) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::{
+                Foundation::{ERROR_FILE_NOT_FOUND, ERROR_SUCCESS},
+                System::Registry::RegDeleteKeyValueW,
+            },
+        };
+
+        let path = HSTRING::from(path);
+        let name = HSTRING::from(name);
+
+        let status = unsafe {
+            RegDeleteKeyValueW(root.hkey(), PCWSTR(path.as_ptr()), PCWSTR(name.as_ptr()))
+        };
+        match status {
+            ERROR_SUCCESS | ERROR_FILE_NOT_FOUND => Ok(()),
+            status => Err(std::io::Error::from_raw_os_error(status.0 as i32)),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (root, path, name);
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
@@ -0,0 +1,75 @@
+/// Shell 操作
+///
+/// 通过 ShellExecuteW 打开链接和资源管理器等目标，参数不经过 cmd 解析，
+/// 含空格或 `&`、`|` 等字符的路径不会被截断或当作命令执行
+use crate::core::error::{Result, WerunError};
+
+/// 在资源管理器中显示并选中文件或目录
+pub fn reveal_in_explorer(path: &str) -> Result<()> {
+    log::info!("在资源管理器中显示: {}", path);
+
+    // 路径本身不能包含引号，整体加引号后含空格的路径也能被 explorer 正确解析
+    shell_execute("explorer.exe", &format!("/select,\"{}\"", path))
+}
+
+/// 用默认程序打开本地文件（如配置文件、主题文件）
+pub fn open_path(path: &str) -> Result<()> {
+    log::info!("打开文件: {}", path);
+    shell_execute(path, "")
+}
+
+/// 用默认程序打开链接（网页、`steam://` 等协议）
+///
+/// 只接受带协议名的链接，避免把本地路径当作程序启动
+pub fn open_url(url: &str) -> Result<()> {
+    let has_scheme = url.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.chars().all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.'))
+    });
+    if !has_scheme {
+        return Err(WerunError::ShellOpen(format!("不是有效的链接: {}", url)));
+    }
+
+    log::info!("打开链接: {}", url);
+    shell_execute(url, "")
+}
+
+/// 以 "open" 动词调用 ShellExecuteW
+fn shell_execute(file: &str, parameters: &str) -> Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{w, HSTRING, PCWSTR},
+            Win32::{
+                Foundation::HWND,
+                UI::{Shell::ShellExecuteW, WindowsAndMessaging::SW_SHOWNORMAL},
+            },
+        };
+
+        let file = HSTRING::from(file);
+        let parameters = HSTRING::from(parameters);
+
+        let instance = unsafe {
+            ShellExecuteW(
+                HWND::default(),
+                w!("open"),
+                PCWSTR(file.as_ptr()),
+                PCWSTR(parameters.as_ptr()),
+                PCWSTR::null(),
+                SW_SHOWNORMAL,
+            )
+        };
+
+        // 返回值大于 32 表示成功
+        match instance.0 as isize {
+            code if code > 32 => Ok(()),
+            code => Err(WerunError::ShellOpen(format!("ShellExecuteW 返回 {}", code))),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (file, parameters);
+        Err(WerunError::ShellOpen("当前平台不支持".to_string()))
+    }
+}
@@ -0,0 +1,168 @@
+/// 快捷方式解析
+///
+/// 按 MS-SHLLINK 格式从 .lnk 数据中读取目标路径；
+/// 跳转列表中的条目是内嵌在其他文件里的快捷方式数据，因此直接解析字节而不是按文件打开
+use std::path::Path;
+
+/// 文件头大小
+const HEADER_SIZE: usize = 0x4C;
+
+/// 文件头签名：头大小 + LinkCLSID {00021401-0000-0000-C000-000000000046}
+pub const SIGNATURE: [u8; 20] = [
+    0x4C, 0x00, 0x00, 0x00, 0x01, 0x14, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x46,
+];
+
+/// LinkFlags: 包含 LinkTargetIDList
+const HAS_LINK_TARGET_ID_LIST: u32 = 0x1;
+/// LinkFlags: 包含 LinkInfo
+const HAS_LINK_INFO: u32 = 0x2;
+
+/// LinkInfoFlags: 包含本地路径
+const VOLUME_ID_AND_LOCAL_BASE_PATH: u32 = 0x1;
+/// LinkInfoFlags: 包含网络路径
+const COMMON_NETWORK_RELATIVE_LINK: u32 = 0x2;
+
+/// 读取快捷方式文件的目标路径
+pub fn read_target(path: &Path) -> Option<String> {
+    let data = std::fs::read(path).ok()?;
+    parse_target(&data)
+}
+
+/// 从快捷方式数据中解析目标路径
+///
+/// 只解析 LinkInfo 中的本地或网络路径；仅有 ID 列表的快捷方式（如控制面板项）返回 `None`
+pub fn parse_target(data: &[u8]) -> Option<String> {
+    if !data.starts_with(&SIGNATURE) {
+        return None;
+    }
+
+    let flags = read_u32(data, 0x14)?;
+    if flags & HAS_LINK_INFO == 0 {
+        return None;
+    }
+
+    // 跳过 ID 列表
+    let mut offset = HEADER_SIZE;
+    if flags & HAS_LINK_TARGET_ID_LIST != 0 {
+        offset += 2 + read_u16(data, offset)? as usize;
+    }
+
+    let info = data.get(offset..)?;
+    let info = info.get(..read_u32(info, 0)? as usize)?;
+    let header_size = read_u32(info, 4)?;
+    let info_flags = read_u32(info, 8)?;
+
+    // 头部不小于 0x24 时带有 Unicode 路径偏移
+    let unicode = header_size >= 0x24;
+    let suffix = if unicode {
+        read_utf16(info, read_u32(info, 32)?)
+    } else {
+        read_ansi(info, read_u32(info, 24)?)
+    }
+    .unwrap_or_default();
+
+    let base = if info_flags & VOLUME_ID_AND_LOCAL_BASE_PATH != 0 {
+        if unicode {
+            read_utf16(info, read_u32(info, 28)?)?
+        } else {
+            read_ansi(info, read_u32(info, 16)?)?
+        }
+    } else if info_flags & COMMON_NETWORK_RELATIVE_LINK != 0 {
+        let link = info.get(read_u32(info, 20)? as usize..)?;
+        let net_name_offset = read_u32(link, 8)?;
+        if net_name_offset > 0x14 {
+            read_utf16(link, read_u32(link, 20)?)?
+        } else {
+            read_ansi(link, net_name_offset)?
+        }
+    } else {
+        return None;
+    };
+
+    Some(join_path(base, &suffix))
+}
+
+/// 拼接基础路径和后缀
+fn join_path(mut base: String, suffix: &str) -> String {
+    if !suffix.is_empty() {
+        if !base.ends_with('\\') {
+            base.push('\\');
+        }
+        base.push_str(suffix);
+    }
+    base
+}
+
+/// 读取小端 u16
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+/// 读取小端 u32
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+/// 读取以 0 结尾的 ANSI 字符串
+///
+/// 按 UTF-8 宽松解码，非 ASCII 字符可能无法还原；新版快捷方式通常同时带有 Unicode 路径
+fn read_ansi(data: &[u8], offset: u32) -> Option<String> {
+    let bytes = data.get(offset as usize..)?;
+    let end = bytes.iter().position(|&b| b == 0)?;
+    (end > 0).then(|| String::from_utf8_lossy(&bytes[..end]).into_owned())
+}
+
+/// 读取以 0 结尾的 UTF-16 字符串
+fn read_utf16(data: &[u8], offset: u32) -> Option<String> {
+    let bytes = data.get(offset as usize..)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&u| u != 0)
+        .collect();
+    (!units.is_empty()).then(|| String::from_utf16_lossy(&units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 构造只含 LinkInfo 的快捷方式数据
+    fn shortcut(info_header_size: u32, info_flags: u32, body: &[u8]) -> Vec<u8> {
+        let mut data = SIGNATURE.to_vec();
+        data.resize(HEADER_SIZE, 0);
+        data[0x14..0x18].copy_from_slice(&HAS_LINK_INFO.to_le_bytes());
+
+        let mut info = vec![0u8; info_header_size as usize];
+        info[4..8].copy_from_slice(&info_header_size.to_le_bytes());
+        info[8..12].copy_from_slice(&info_flags.to_le_bytes());
+        info.extend_from_slice(body);
+        let size = info.len() as u32;
+        info[0..4].copy_from_slice(&size.to_le_bytes());
+
+        data.extend_from_slice(&info);
+        data
+    }
+
+    #[test]
+    fn test_parse_target() {
+        // ANSI 本地路径，后缀为空字符串
+        let mut info = shortcut(0x1C, VOLUME_ID_AND_LOCAL_BASE_PATH, b"C:\\docs\\a.txt\0\0");
+        let base = HEADER_SIZE;
+        info[base + 16..base + 20].copy_from_slice(&0x1Cu32.to_le_bytes());
+        info[base + 24..base + 28].copy_from_slice(&(0x1Cu32 + 14).to_le_bytes());
+        assert_eq!(parse_target(&info).as_deref(), Some("C:\\docs\\a.txt"));
+
+        // Unicode 本地路径
+        let wide: Vec<u8> =
+            "D:\\报告.docx\0\0".encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+        let mut info = shortcut(0x24, VOLUME_ID_AND_LOCAL_BASE_PATH, &wide);
+        info[base + 28..base + 32].copy_from_slice(&0x24u32.to_le_bytes());
+        info[base + 32..base + 36].copy_from_slice(&(0x24u32 + 20).to_le_bytes());
+        assert_eq!(parse_target(&info).as_deref(), Some("D:\\报告.docx"));
+
+        // 非快捷方式数据
+        assert_eq!(parse_target(b"not a shortcut"), None);
+    }
+}
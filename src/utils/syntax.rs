@@ -0,0 +1,461 @@
+/// 语法着色
+///
+/// 按扩展名识别常见语言，把文本切分为关键字、字符串、数字和注释，供预览面板着色；
+/// 只做词法层面的近似，不解析语法
+use crate::core::preview::{CodeLine, CodeSpan, TokenKind};
+
+/// 语言的词法规则
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Syntax {
+    /// 关键字
+    keywords: &'static [&'static str],
+    /// 行注释的开头
+    line_comments: &'static [&'static str],
+    /// 块注释的开头和结尾
+    block_comment: Option<(&'static str, &'static str)>,
+    /// 字符串的引号
+    quotes: &'static [char],
+    /// 关键字和注释标记是否不区分大小写（关键字以小写列出）
+    ignore_case: bool,
+}
+
+/// C 系语言的块注释
+const C_BLOCK: Option<(&str, &str)> = Some(("/*", "*/"));
+
+/// Rust
+const RUST: Syntax = Syntax {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while",
+    ],
+    line_comments: &["//"],
+    block_comment: C_BLOCK,
+    quotes: &['"'],
+    ignore_case: false,
+};
+
+/// C、C++、C#、Java 等 C 系语言
+const C_LIKE: Syntax = Syntax {
+    keywords: &[
+        "auto",
+        "bool",
+        "break",
+        "case",
+        "catch",
+        "char",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "extends",
+        "false",
+        "final",
+        "float",
+        "for",
+        "if",
+        "implements",
+        "import",
+        "include",
+        "int",
+        "interface",
+        "long",
+        "namespace",
+        "new",
+        "null",
+        "nullptr",
+        "override",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "short",
+        "static",
+        "struct",
+        "switch",
+        "template",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typedef",
+        "unsigned",
+        "using",
+        "var",
+        "virtual",
+        "void",
+        "while",
+    ],
+    line_comments: &["//"],
+    block_comment: C_BLOCK,
+    quotes: &['"', '\''],
+    ignore_case: false,
+};
+
+/// JavaScript / TypeScript
+const JAVASCRIPT: Syntax = Syntax {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "of",
+        "return",
+        "static",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "type",
+        "typeof",
+        "undefined",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    line_comments: &["//"],
+    block_comment: C_BLOCK,
+    quotes: &['"', '\'', '`'],
+    ignore_case: false,
+};
+
+/// Go
+const GO: Syntax = Syntax {
+    keywords: &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "false",
+        "for",
+        "func",
+        "go",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "nil",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "true",
+        "type",
+        "var",
+    ],
+    line_comments: &["//"],
+    block_comment: C_BLOCK,
+    quotes: &['"', '\'', '`'],
+    ignore_case: false,
+};
+
+/// Python
+const PYTHON: Syntax = Syntax {
+    keywords: &[
+        "and", "as", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+        "except", "False", "finally", "for", "from", "if", "import", "in", "is", "lambda", "None",
+        "not", "or", "pass", "raise", "return", "self", "True", "try", "while", "with", "yield",
+    ],
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    ignore_case: false,
+};
+
+/// Shell 脚本
+const SHELL: Syntax = Syntax {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if",
+        "in", "local", "return", "then", "while",
+    ],
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    ignore_case: false,
+};
+
+/// PowerShell
+const POWERSHELL: Syntax = Syntax {
+    keywords: &[
+        "begin", "break", "catch", "continue", "else", "elseif", "end", "finally", "for",
+        "foreach", "function", "if", "in", "param", "process", "return", "switch", "throw", "try",
+        "while",
+    ],
+    line_comments: &["#"],
+    block_comment: Some(("<#", "#>")),
+    quotes: &['"', '\''],
+    ignore_case: true,
+};
+
+/// 批处理
+const BATCH: Syntax = Syntax {
+    keywords: &[
+        "call", "do", "echo", "else", "exist", "exit", "for", "goto", "if", "in", "not", "off",
+        "set", "setlocal", "endlocal",
+    ],
+    line_comments: &["::", "rem "],
+    block_comment: None,
+    quotes: &['"'],
+    ignore_case: true,
+};
+
+/// SQL
+const SQL: Syntax = Syntax {
+    keywords: &[
+        "and", "as", "by", "create", "delete", "desc", "drop", "from", "group", "insert", "into",
+        "join", "left", "limit", "not", "null", "on", "or", "order", "select", "set", "table",
+        "update", "values", "where",
+    ],
+    line_comments: &["--"],
+    block_comment: C_BLOCK,
+    quotes: &['\''],
+    ignore_case: true,
+};
+
+/// TOML、INI、YAML 等配置文件
+const CONFIG: Syntax = Syntax {
+    keywords: &["true", "false", "null", "yes", "no", "on", "off"],
+    line_comments: &["#", ";"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    ignore_case: false,
+};
+
+/// JSON
+const JSON: Syntax = Syntax {
+    keywords: &["true", "false", "null"],
+    line_comments: &[],
+    block_comment: None,
+    quotes: &['"'],
+    ignore_case: false,
+};
+
+/// HTML、XML 等标记语言
+const MARKUP: Syntax = Syntax {
+    keywords: &[],
+    line_comments: &[],
+    block_comment: Some(("<!--", "-->")),
+    quotes: &['"'],
+    ignore_case: false,
+};
+
+impl Syntax {
+    /// 按扩展名（不区分大小写）识别语言，无法识别时返回 `None`，按纯文本显示
+    pub fn for_extension(extension: &str) -> Option<Syntax> {
+        let syntax = match extension.to_lowercase().as_str() {
+            "rs" => RUST,
+            "c" | "h" | "cc" | "cpp" | "hpp" | "cxx" | "cs" | "java" | "kt" | "swift" => C_LIKE,
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => JAVASCRIPT,
+            "go" => GO,
+            "py" | "pyw" => PYTHON,
+            "sh" | "bash" | "zsh" => SHELL,
+            "ps1" | "psm1" | "psd1" => POWERSHELL,
+            "bat" | "cmd" => BATCH,
+            "sql" => SQL,
+            "toml" | "ini" | "cfg" | "conf" | "yaml" | "yml" | "properties" | "env" => CONFIG,
+            "json" | "jsonc" => JSON,
+            "html" | "htm" | "xml" | "svg" | "xaml" | "csproj" | "vue" => MARKUP,
+            _ => return None,
+        };
+        Some(syntax)
+    }
+
+    /// `text` 是否以 `prefix` 开头
+    fn starts_with(&self, text: &str, prefix: &str) -> bool {
+        if self.ignore_case {
+            text.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        } else {
+            text.starts_with(prefix)
+        }
+    }
+
+    /// 是否为关键字
+    fn is_keyword(&self, word: &str) -> bool {
+        if self.ignore_case {
+            self.keywords.iter().any(|keyword| keyword.eq_ignore_ascii_case(word))
+        } else {
+            self.keywords.contains(&word)
+        }
+    }
+}
+
+/// 着色文本，按行返回；`syntax` 为 `None` 时每行为一段普通文本
+///
+/// 块注释可以跨行，字符串不跨行
+pub fn highlight(text: &str, syntax: Option<&Syntax>) -> Vec<CodeLine> {
+    let mut in_block_comment = false;
+    text.lines()
+        .map(|line| match syntax {
+            Some(syntax) => highlight_line(line, syntax, &mut in_block_comment),
+            None => vec![CodeSpan { text: line.to_string(), kind: TokenKind::Plain }],
+        })
+        .collect()
+}
+
+/// 着色一行，`in_block_comment` 在行间传递块注释状态
+fn highlight_line(line: &str, syntax: &Syntax, in_block_comment: &mut bool) -> CodeLine {
+    let mut spans: CodeLine = Vec::new();
+    let mut push = |text: &str, kind: TokenKind| {
+        if text.is_empty() {
+            return;
+        }
+        match spans.last_mut() {
+            Some(last) if last.kind == kind => last.text.push_str(text),
+            _ => spans.push(CodeSpan { text: text.to_string(), kind }),
+        }
+    };
+
+    let mut rest = line;
+    while !rest.is_empty() {
+        // 块注释内：直到结尾标记或行尾
+        if *in_block_comment {
+            let end = syntax.block_comment.map_or("", |(_, end)| end);
+            match rest.find(end) {
+                Some(index) => {
+                    push(&rest[..index + end.len()], TokenKind::Comment);
+                    rest = &rest[index + end.len()..];
+                    *in_block_comment = false;
+                },
+                None => {
+                    push(rest, TokenKind::Comment);
+                    rest = "";
+                },
+            }
+            continue;
+        }
+
+        if syntax.line_comments.iter().any(|start| syntax.starts_with(rest, start)) {
+            push(rest, TokenKind::Comment);
+            break;
+        }
+        if let Some((start, _)) =
+            syntax.block_comment.filter(|(start, _)| syntax.starts_with(rest, start))
+        {
+            push(start, TokenKind::Comment);
+            rest = &rest[start.len()..];
+            *in_block_comment = true;
+            continue;
+        }
+
+        let first = rest.chars().next().unwrap_or_default();
+        let length = if syntax.quotes.contains(&first) {
+            let length = string_length(rest, first);
+            push(&rest[..length], TokenKind::String);
+            length
+        } else if first.is_alphanumeric() || first == '_' {
+            let length = rest
+                .find(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+                .unwrap_or(rest.len());
+            let word = &rest[..length];
+            if first.is_ascii_digit() {
+                push(word, TokenKind::Number);
+                length
+            } else {
+                // 标识符中的点号是成员访问，只取到点号之前
+                let word = word.split('.').next().unwrap_or(word);
+                let kind =
+                    if syntax.is_keyword(word) { TokenKind::Keyword } else { TokenKind::Plain };
+                push(word, kind);
+                word.len()
+            }
+        } else {
+            push(&rest[..first.len_utf8()], TokenKind::Plain);
+            first.len_utf8()
+        };
+        rest = &rest[length..];
+    }
+
+    spans
+}
+
+/// 从引号开始的字符串长度（字节），包括结尾的引号；没有结尾引号时到行尾
+fn string_length(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (index, c) in text.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            c if c == quote && !escaped => return index + c.len_utf8(),
+            _ => escaped = false,
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 各段的文本和类别
+    fn spans(line: &CodeLine) -> Vec<(&str, TokenKind)> {
+        line.iter().map(|span| (span.text.as_str(), span.kind)).collect()
+    }
+
+    #[test]
+    fn test_highlight() {
+        let rust = Syntax::for_extension("RS").unwrap();
+        let lines = highlight("let s = \"a\\\"b\"; // 注释\n/* 多行\n注释 */ 42", Some(&rust));
+        assert_eq!(spans(&lines[0]), [
+            ("let", TokenKind::Keyword),
+            (" s = ", TokenKind::Plain),
+            ("\"a\\\"b\"", TokenKind::String),
+            ("; ", TokenKind::Plain),
+            ("// 注释", TokenKind::Comment),
+        ]);
+        assert_eq!(spans(&lines[1]), [("/* 多行", TokenKind::Comment)]);
+        assert_eq!(spans(&lines[2]), [
+            ("注释 */", TokenKind::Comment),
+            (" ", TokenKind::Plain),
+            ("42", TokenKind::Number),
+        ]);
+
+        let python = Syntax::for_extension("py").unwrap();
+        let lines = highlight("self.fn_name(3.5)", Some(&python));
+        assert_eq!(spans(&lines[0]), [
+            ("self", TokenKind::Keyword),
+            (".fn_name(", TokenKind::Plain),
+            ("3.5", TokenKind::Number),
+            (")", TokenKind::Plain),
+        ]);
+
+        assert_eq!(Syntax::for_extension("docx"), None);
+        assert_eq!(spans(&highlight("纯文本", None)[0]), [("纯文本", TokenKind::Plain)]);
+    }
+}
@@ -0,0 +1,362 @@
+use std::time::Duration;
+
+/// 系统信息
+///
+/// 读取 CPU、内存、磁盘占用，运行时间、电池、系统版本和显卡名称；
+/// 非 Windows 平台上各项读取函数返回空结果
+use crate::utils::registry::{string_value, RegistryRoot};
+
+/// 系统版本所在的注册表键
+const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+
+/// Windows 11 的起始内部版本号（注册表中的产品名仍为 Windows 10）
+const WINDOWS_11_BUILD: u32 = 22000;
+
+/// CPU 累计时间（100 纳秒为单位）
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuTimes {
+    /// 空闲时间
+    pub idle: u64,
+    /// 总时间（内核时间已包含空闲时间）
+    pub total: u64,
+}
+
+impl CpuTimes {
+    /// 从 `earlier` 到当前采样之间的 CPU 占用百分比
+    pub fn usage_since(&self, earlier: &CpuTimes) -> f32 {
+        let total = self.total.saturating_sub(earlier.total);
+        if total == 0 {
+            return 0.0;
+        }
+        let idle = self.idle.saturating_sub(earlier.idle).min(total);
+        (total - idle) as f32 * 100.0 / total as f32
+    }
+}
+
+/// 内存占用（字节）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// 物理内存总量
+    pub total: u64,
+    /// 可用物理内存
+    pub available: u64,
+    /// 提交限制（物理内存加页面文件）
+    pub commit_total: u64,
+    /// 可用提交量
+    pub commit_available: u64,
+}
+
+impl MemoryUsage {
+    /// 已用物理内存
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+}
+
+/// 磁盘占用（字节）
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskUsage {
+    /// 根目录（如 `C:\`）
+    pub root: String,
+    /// 总容量
+    pub total: u64,
+    /// 剩余空间
+    pub free: u64,
+}
+
+impl DiskUsage {
+    /// 已用空间
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+}
+
+/// 电池状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatteryStatus {
+    /// 剩余电量百分比
+    pub percent: u8,
+    /// 是否接通电源
+    pub plugged_in: bool,
+    /// 剩余使用时间（接通电源或未知时为 `None`）
+    pub remaining: Option<Duration>,
+}
+
+/// 占用百分比
+pub fn percent(used: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        used as f32 * 100.0 / total as f32
+    }
+}
+
+/// 格式化时长，如 "3 天 4 小时 5 分钟"
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{} 分钟", minutes),
+        (0, _) => format!("{} 小时 {} 分钟", hours, minutes),
+        _ => format!("{} 天 {} 小时 {} 分钟", days, hours, minutes),
+    }
+}
+
+/// 系统版本名称，如 "Windows 11 Pro 23H2 (内部版本 22631.3007)"
+///
+/// 内部版本号不低于 22000 时把产品名中的 Windows 10 改为 Windows 11
+pub fn os_name(
+    product: &str,
+    display_version: Option<&str>,
+    build: &str,
+    ubr: Option<u32>,
+) -> String {
+    let is_windows_11 = build.parse::<u32>().is_ok_and(|b| b >= WINDOWS_11_BUILD);
+    let mut name = if is_windows_11 {
+        product.replacen("Windows 10", "Windows 11", 1)
+    } else {
+        product.to_string()
+    };
+    if let Some(version) = display_version {
+        name = format!("{} {}", name, version);
+    }
+    match ubr {
+        Some(ubr) => format!("{} (内部版本 {}.{})", name, build, ubr),
+        None => format!("{} (内部版本 {})", name, build),
+    }
+}
+
+/// 读取 CPU 累计时间
+pub fn cpu_times() -> Option<CpuTimes> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::{Foundation::FILETIME, System::Threading::GetSystemTimes};
+
+        let (mut idle, mut kernel, mut user) =
+            (FILETIME::default(), FILETIME::default(), FILETIME::default());
+        unsafe { GetSystemTimes(Some(&mut idle), Some(&mut kernel), Some(&mut user)).ok()? };
+
+        let ticks =
+            |time: FILETIME| (u64::from(time.dwHighDateTime) << 32) | u64::from(time.dwLowDateTime);
+        Some(CpuTimes { idle: ticks(idle), total: ticks(kernel) + ticks(user) })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+/// 读取内存占用
+pub fn memory_usage() -> Option<MemoryUsage> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+        let mut status = MEMORYSTATUSEX {
+            dwLength: std::mem::size_of::<MEMORYSTATUSEX>() as u32,
+            ..Default::default()
+        };
+        unsafe { GlobalMemoryStatusEx(&mut status).ok()? };
+
+        Some(MemoryUsage {
+            total: status.ullTotalPhys,
+            available: status.ullAvailPhys,
+            commit_total: status.ullTotalPageFile,
+            commit_available: status.ullAvailPageFile,
+        })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+/// 读取本地固定磁盘的占用
+pub fn disk_usage() -> Vec<DiskUsage> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::HSTRING,
+            Win32::Storage::FileSystem::{GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDrives},
+        };
+
+        const DRIVE_FIXED: u32 = 3;
+
+        let drives = unsafe { GetLogicalDrives() };
+        (0..26u8)
+            .filter(|index| drives & (1 << index) != 0)
+            .filter_map(|index| {
+                let root = format!("{}:\\", (b'A' + index) as char);
+                let path = HSTRING::from(&root);
+                if unsafe { GetDriveTypeW(&path) } != DRIVE_FIXED {
+                    return None;
+                }
+
+                let (mut total, mut free) = (0u64, 0u64);
+                unsafe {
+                    GetDiskFreeSpaceExW(&path, None, Some(&mut total), Some(&mut free)).ok()?
+                };
+                Some(DiskUsage { root, total, free })
+            })
+            .collect()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Vec::new()
+    }
+}
+
+/// 系统运行时间
+pub fn uptime() -> Option<Duration> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::SystemInformation::GetTickCount64;
+
+        Some(Duration::from_millis(unsafe { GetTickCount64() }))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+/// 读取电池状态，没有电池时返回 `None`
+pub fn battery_status() -> Option<BatteryStatus> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+        // BatteryFlag：128 表示没有电池，255 表示状态未知
+        const NO_BATTERY: u8 = 128;
+        const UNKNOWN: u8 = 255;
+
+        let mut status = SYSTEM_POWER_STATUS::default();
+        unsafe { GetSystemPowerStatus(&mut status).ok()? };
+        if status.BatteryFlag & NO_BATTERY != 0 || status.BatteryLifePercent == UNKNOWN {
+            return None;
+        }
+
+        let plugged_in = status.ACLineStatus == 1;
+        let remaining = (!plugged_in && status.BatteryLifeTime != u32::MAX)
+            .then(|| Duration::from_secs(u64::from(status.BatteryLifeTime)));
+        Some(BatteryStatus { percent: status.BatteryLifePercent.min(100), plugged_in, remaining })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+/// 系统版本名称
+pub fn os_version() -> Option<String> {
+    let read =
+        |name: &str| string_value(RegistryRoot::LocalMachine, CURRENT_VERSION_KEY, Some(name));
+    let product = read("ProductName")?;
+    let build = read("CurrentBuild")?;
+    let display_version = read("DisplayVersion");
+    Some(os_name(&product, display_version.as_deref(), &build, update_build_revision()))
+}
+
+/// 累积更新的修订号（注册表中的 UBR，为 DWORD 值）
+fn update_build_revision() -> Option<u32> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::{HSTRING, PCWSTR},
+            Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD},
+        };
+
+        let (path, name) = (HSTRING::from(CURRENT_VERSION_KEY), HSTRING::from("UBR"));
+        let (mut value, mut size) = (0u32, std::mem::size_of::<u32>() as u32);
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                PCWSTR(path.as_ptr()),
+                PCWSTR(name.as_ptr()),
+                RRF_RT_REG_DWORD,
+                None,
+                Some(&mut value as *mut u32 as *mut _),
+                Some(&mut size),
+            )
+        };
+        status.is_ok().then_some(value)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
+
+/// 显卡名称（去重，跳过镜像驱动）
+pub fn gpu_names() -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::{
+            core::PCWSTR,
+            Win32::Graphics::Gdi::{
+                EnumDisplayDevicesW, DISPLAY_DEVICEW, DISPLAY_DEVICE_MIRRORING_DRIVER,
+            },
+        };
+
+        let mut names: Vec<String> = Vec::new();
+        for index in 0.. {
+            let mut device = DISPLAY_DEVICEW {
+                cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+                ..Default::default()
+            };
+            if !unsafe { EnumDisplayDevicesW(PCWSTR::null(), index, &mut device, 0) }.as_bool() {
+                break;
+            }
+            if device.StateFlags & DISPLAY_DEVICE_MIRRORING_DRIVER != 0 {
+                continue;
+            }
+
+            let len = device.DeviceString.iter().position(|&c| c == 0).unwrap_or(128);
+            let name = String::from_utf16_lossy(&device.DeviceString[..len]).trim().to_string();
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_and_formatting() {
+        let earlier = CpuTimes { idle: 1_000, total: 2_000 };
+        let later = CpuTimes { idle: 1_750, total: 3_000 };
+        assert_eq!(later.usage_since(&earlier), 25.0);
+        assert_eq!(earlier.usage_since(&earlier), 0.0);
+
+        assert_eq!(percent(1, 4), 25.0);
+        assert_eq!(percent(1, 0), 0.0);
+
+        assert_eq!(format_duration(Duration::from_secs(59)), "0 分钟");
+        assert_eq!(format_duration(Duration::from_secs(3 * 3600 + 120)), "3 小时 2 分钟");
+        assert_eq!(format_duration(Duration::from_secs(90_000)), "1 天 1 小时 0 分钟");
+
+        assert_eq!(
+            os_name("Windows 10 Pro", Some("23H2"), "22631", Some(3007)),
+            "Windows 11 Pro 23H2 (内部版本 22631.3007)"
+        );
+        assert_eq!(
+            os_name("Windows 10 Home", None, "19045", None),
+            "Windows 10 Home (内部版本 19045)"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+/// 时区工具
+///
+/// 内置常用城市的标准时区偏移和夏令时规则，无需额外的时区数据库
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
+
+/// 夏令时规则
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DstRule {
+    /// 无夏令时
+    None,
+    /// 美国/加拿大：三月第二个周日至十一月第一个周日 (当地 02:00)
+    NorthAmerica,
+    /// 欧盟/英国：三月最后一个周日至十月最后一个周日 (UTC 01:00)
+    Europe,
+    /// 澳大利亚东南部：十月第一个周日至四月第一个周日 (当地 02:00)
+    Australia,
+}
+
+/// 城市时区
+#[derive(Clone, Copy, Debug)]
+pub struct City {
+    /// 英文名称（小写，用于匹配）
+    pub name: &'static str,
+    /// 中文显示名称
+    pub display: &'static str,
+    /// 标准时间偏移 (分钟)
+    pub offset_minutes: i32,
+    /// 夏令时规则
+    pub dst: DstRule,
+}
+
+/// 内置城市列表
+pub const CITIES: &[City] = &[
+    City { name: "utc", display: "UTC", offset_minutes: 0, dst: DstRule::None },
+    City { name: "tokyo", display: "东京", offset_minutes: 9 * 60, dst: DstRule::None },
+    City { name: "seoul", display: "首尔", offset_minutes: 9 * 60, dst: DstRule::None },
+    City { name: "beijing", display: "北京", offset_minutes: 8 * 60, dst: DstRule::None },
+    City { name: "shanghai", display: "上海", offset_minutes: 8 * 60, dst: DstRule::None },
+    City { name: "hongkong", display: "香港", offset_minutes: 8 * 60, dst: DstRule::None },
+    City { name: "taipei", display: "台北", offset_minutes: 8 * 60, dst: DstRule::None },
+    City { name: "singapore", display: "新加坡", offset_minutes: 8 * 60, dst: DstRule::None },
+    City { name: "bangkok", display: "曼谷", offset_minutes: 7 * 60, dst: DstRule::None },
+    City { name: "delhi", display: "德里", offset_minutes: 5 * 60 + 30, dst: DstRule::None },
+    City { name: "mumbai", display: "孟买", offset_minutes: 5 * 60 + 30, dst: DstRule::None },
+    City { name: "dubai", display: "迪拜", offset_minutes: 4 * 60, dst: DstRule::None },
+    City { name: "moscow", display: "莫斯科", offset_minutes: 3 * 60, dst: DstRule::None },
+    City { name: "berlin", display: "柏林", offset_minutes: 60, dst: DstRule::Europe },
+    City { name: "paris", display: "巴黎", offset_minutes: 60, dst: DstRule::Europe },
+    City { name: "london", display: "伦敦", offset_minutes: 0, dst: DstRule::Europe },
+    City {
+        name: "newyork", display: "纽约", offset_minutes: -5 * 60, dst: DstRule::NorthAmerica
+    },
+    City {
+        name: "chicago",
+        display: "芝加哥",
+        offset_minutes: -6 * 60,
+        dst: DstRule::NorthAmerica,
+    },
+    City { name: "denver", display: "丹佛", offset_minutes: -7 * 60, dst: DstRule::NorthAmerica },
+    City {
+        name: "losangeles",
+        display: "洛杉矶",
+        offset_minutes: -8 * 60,
+        dst: DstRule::NorthAmerica,
+    },
+    City {
+        name: "sanfrancisco",
+        display: "旧金山",
+        offset_minutes: -8 * 60,
+        dst: DstRule::NorthAmerica,
+    },
+    City {
+        name: "seattle",
+        display: "西雅图",
+        offset_minutes: -8 * 60,
+        dst: DstRule::NorthAmerica,
+    },
+    City { name: "sydney", display: "悉尼", offset_minutes: 10 * 60, dst: DstRule::Australia },
+    City {
+        name: "melbourne", display: "墨尔本", offset_minutes: 10 * 60, dst: DstRule::Australia
+    },
+];
+
+/// 根据名称查找城市（忽略大小写、空格和连字符，如 "New York"、"new-york"）
+pub fn lookup_city(name: &str) -> Option<&'static City> {
+    let key: String = name
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    CITIES.iter().find(|city| city.name == key || city.display == name.trim())
+}
+
+impl City {
+    /// 指定 UTC 时刻的时区偏移（含夏令时）
+    pub fn offset_at(&self, utc: DateTime<Utc>) -> FixedOffset {
+        let dst = self.is_dst(utc);
+        let minutes = self.offset_minutes + if dst { 60 } else { 0 };
+        FixedOffset::east_opt(minutes * 60).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap())
+    }
+
+    /// 指定 UTC 时刻的当地时间
+    pub fn local_time(&self, utc: DateTime<Utc>) -> DateTime<FixedOffset> {
+        utc.with_timezone(&self.offset_at(utc))
+    }
+
+    /// 是否处于夏令时
+    fn is_dst(&self, utc: DateTime<Utc>) -> bool {
+        let year = utc.year();
+        let standard = utc.naive_utc() + chrono::Duration::minutes(self.offset_minutes as i64);
+
+        match self.dst {
+            DstRule::None => false,
+            DstRule::NorthAmerica => {
+                let start = nth_weekday(year, 3, 2).and_hms_opt(2, 0, 0).unwrap();
+                let end = nth_weekday(year, 11, 1).and_hms_opt(1, 0, 0).unwrap();
+                standard >= start && standard < end
+            },
+            DstRule::Europe => {
+                let start = last_sunday(year, 3).and_hms_opt(1, 0, 0).unwrap();
+                let end = last_sunday(year, 10).and_hms_opt(1, 0, 0).unwrap();
+                let naive = utc.naive_utc();
+                naive >= start && naive < end
+            },
+            DstRule::Australia => {
+                // 南半球：夏令时跨年
+                let end = nth_weekday(year, 4, 1).and_hms_opt(2, 0, 0).unwrap();
+                let start = nth_weekday(year, 10, 1).and_hms_opt(2, 0, 0).unwrap();
+                standard < end || standard >= start
+            },
+        }
+    }
+}
+
+/// 某月第 n 个周日
+fn nth_weekday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n)
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, month, 1).unwrap())
+}
+
+/// 某月最后一个周日
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, 4))
+}
+
+/// 将城市当天的当地时间转换为 UTC 时刻
+pub fn city_time_to_utc(city: &City, hour: u32, minute: u32, now: DateTime<Utc>) -> DateTime<Utc> {
+    let offset = city.offset_at(now);
+    let date = now.with_timezone(&offset).date_naive();
+    let naive =
+        date.and_hms_opt(hour, minute, 0).unwrap_or_else(|| date.and_hms_opt(0, 0, 0).unwrap());
+    offset.from_local_datetime(&naive).single().map(|t| t.with_timezone(&Utc)).unwrap_or(now)
+}
@@ -0,0 +1,219 @@
+/// 单位换算
+///
+/// 登记长度、质量、数据大小、体积、面积、速度、时间和温度单位，
+/// 同一量纲的单位之间通过基准单位换算；数据大小按 1024 进位，与资源管理器一致
+use crate::core::search::TemperatureUnit;
+
+/// 量纲
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dimension {
+    /// 长度（基准：米）
+    Length,
+    /// 质量（基准：千克）
+    Mass,
+    /// 数据大小（基准：字节）
+    Data,
+    /// 体积（基准：升）
+    Volume,
+    /// 面积（基准：平方米）
+    Area,
+    /// 速度（基准：米每秒）
+    Speed,
+    /// 时间（基准：秒）
+    Time,
+    /// 温度（基准：摄氏度）
+    Temperature,
+}
+
+impl Dimension {
+    /// 显示名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dimension::Length => "长度",
+            Dimension::Mass => "质量",
+            Dimension::Data => "数据大小",
+            Dimension::Volume => "体积",
+            Dimension::Area => "面积",
+            Dimension::Speed => "速度",
+            Dimension::Time => "时间",
+            Dimension::Temperature => "温度",
+        }
+    }
+}
+
+/// 与基准单位的换算方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Scale {
+    /// 1 单位 = 系数 × 基准单位
+    Factor(f64),
+    /// 温度单位带偏移，按摄氏度换算
+    Temperature(TemperatureUnit),
+}
+
+/// 单位
+#[derive(Debug, PartialEq)]
+pub struct Unit {
+    /// 显示符号
+    pub symbol: &'static str,
+    /// 量纲
+    pub dimension: Dimension,
+    /// 换算方式
+    scale: Scale,
+    /// 识别的别名（小写）
+    aliases: &'static [&'static str],
+}
+
+impl Unit {
+    /// 换算为基准单位
+    fn to_base(&self, value: f64) -> f64 {
+        match self.scale {
+            Scale::Factor(factor) => value * factor,
+            Scale::Temperature(unit) => unit.to_celsius(value),
+        }
+    }
+
+    /// 由基准单位换算
+    fn base_to_unit(&self, value: f64) -> f64 {
+        match self.scale {
+            Scale::Factor(factor) => value / factor,
+            Scale::Temperature(unit) => unit.from_celsius(value),
+        }
+    }
+}
+
+/// 数据大小的进位
+const KIB: f64 = 1024.0;
+
+/// 单位登记表
+const UNITS: &[Unit] = &[
+    // 长度
+    unit("mm", Dimension::Length, 0.001, &["毫米", "millimeter", "millimeters"]),
+    unit("cm", Dimension::Length, 0.01, &["厘米", "centimeter", "centimeters"]),
+    unit("m", Dimension::Length, 1.0, &["米", "meter", "meters", "metre"]),
+    unit("km", Dimension::Length, 1000.0, &["公里", "千米", "kilometer", "kilometers"]),
+    unit("in", Dimension::Length, 0.0254, &["英寸", "inch", "inches", "\""]),
+    unit("ft", Dimension::Length, 0.3048, &["英尺", "foot", "feet", "'"]),
+    unit("yd", Dimension::Length, 0.9144, &["码", "yard", "yards"]),
+    unit("mi", Dimension::Length, 1609.344, &["英里", "mile", "miles"]),
+    unit("nmi", Dimension::Length, 1852.0, &["海里", "nautical mile"]),
+    // 质量
+    unit("mg", Dimension::Mass, 1e-6, &["毫克", "milligram", "milligrams"]),
+    unit("g", Dimension::Mass, 0.001, &["克", "gram", "grams"]),
+    unit("kg", Dimension::Mass, 1.0, &["千克", "公斤", "kilogram", "kilograms"]),
+    unit("t", Dimension::Mass, 1000.0, &["吨", "ton", "tons", "tonne"]),
+    unit("斤", Dimension::Mass, 0.5, &["市斤"]),
+    unit("oz", Dimension::Mass, 0.028_349_523_125, &["盎司", "ounce", "ounces"]),
+    unit("lb", Dimension::Mass, 0.453_592_37, &["磅", "lbs", "pound", "pounds"]),
+    // 数据大小
+    unit("bit", Dimension::Data, 0.125, &["bits", "比特"]),
+    unit("B", Dimension::Data, 1.0, &["byte", "bytes", "字节"]),
+    unit("KB", Dimension::Data, KIB, &["kib"]),
+    unit("MB", Dimension::Data, KIB * KIB, &["mib", "兆"]),
+    unit("GB", Dimension::Data, KIB * KIB * KIB, &["gib"]),
+    unit("TB", Dimension::Data, KIB * KIB * KIB * KIB, &["tib"]),
+    // 体积
+    unit("ml", Dimension::Volume, 0.001, &["毫升", "milliliter", "milliliters"]),
+    unit("l", Dimension::Volume, 1.0, &["升", "liter", "liters", "litre"]),
+    unit("gal", Dimension::Volume, 3.785_411_784, &["加仑", "gallon", "gallons"]),
+    unit("qt", Dimension::Volume, 0.946_352_946, &["quart", "quarts"]),
+    unit("pt", Dimension::Volume, 0.473_176_473, &["pint", "pints"]),
+    unit("cup", Dimension::Volume, 0.236_588_236_5, &["cups", "杯"]),
+    unit("floz", Dimension::Volume, 0.029_573_529_562_5, &["fl oz", "液量盎司"]),
+    // 面积
+    unit("m²", Dimension::Area, 1.0, &["m2", "sqm", "平方米", "㎡"]),
+    unit("km²", Dimension::Area, 1e6, &["km2", "平方公里", "平方千米"]),
+    unit("ft²", Dimension::Area, 0.092_903_04, &["ft2", "sqft", "平方英尺"]),
+    unit("ha", Dimension::Area, 1e4, &["公顷", "hectare", "hectares"]),
+    unit("acre", Dimension::Area, 4_046.856_422_4, &["acres", "英亩"]),
+    unit("亩", Dimension::Area, 10_000.0 / 15.0, &[]),
+    // 速度
+    unit("m/s", Dimension::Speed, 1.0, &["mps", "米每秒"]),
+    unit("km/h", Dimension::Speed, 1.0 / 3.6, &["kmh", "kph", "公里每小时"]),
+    unit("mph", Dimension::Speed, 0.447_04, &["英里每小时"]),
+    unit("kn", Dimension::Speed, 1852.0 / 3600.0, &["knot", "knots", "节"]),
+    // 时间
+    unit("ms", Dimension::Time, 0.001, &["毫秒", "millisecond", "milliseconds"]),
+    unit("s", Dimension::Time, 1.0, &["sec", "秒", "second", "seconds"]),
+    unit("min", Dimension::Time, 60.0, &["分钟", "minute", "minutes"]),
+    unit("h", Dimension::Time, 3600.0, &["hr", "小时", "hour", "hours"]),
+    unit("d", Dimension::Time, 86_400.0, &["天", "day", "days"]),
+    unit("wk", Dimension::Time, 604_800.0, &["周", "week", "weeks"]),
+    // 温度
+    temperature("°C", TemperatureUnit::Celsius, &["c", "°c", "℃", "celsius", "摄氏度"]),
+    temperature("°F", TemperatureUnit::Fahrenheit, &["f", "°f", "℉", "fahrenheit", "华氏度"]),
+    temperature("K", TemperatureUnit::Kelvin, &["k", "kelvin", "开尔文"]),
+];
+
+/// 按系数换算的单位
+const fn unit(
+    symbol: &'static str,
+    dimension: Dimension,
+    factor: f64,
+    aliases: &'static [&'static str],
+) -> Unit {
+    Unit { symbol, dimension, scale: Scale::Factor(factor), aliases }
+}
+
+/// 温度单位
+const fn temperature(
+    symbol: &'static str,
+    unit: TemperatureUnit,
+    aliases: &'static [&'static str],
+) -> Unit {
+    Unit { symbol, dimension: Dimension::Temperature, scale: Scale::Temperature(unit), aliases }
+}
+
+/// 按符号或别名查找单位（不区分大小写），符号优先于别名
+pub fn lookup(name: &str) -> Option<&'static Unit> {
+    let name = name.trim().to_lowercase();
+    UNITS
+        .iter()
+        .find(|unit| unit.symbol.to_lowercase() == name)
+        .or_else(|| UNITS.iter().find(|unit| unit.aliases.contains(&name.as_str())))
+}
+
+/// 在同一量纲的单位之间换算，量纲不同时返回 `None`
+pub fn convert(value: f64, from: &Unit, to: &Unit) -> Option<f64> {
+    (from.dimension == to.dimension).then(|| to.base_to_unit(from.to_base(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert_named(value: f64, from: &str, to: &str) -> Option<f64> {
+        convert(value, lookup(from)?, lookup(to)?)
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("无法换算");
+        assert!((actual - expected).abs() < 1e-6, "{} ≠ {}", actual, expected);
+    }
+
+    #[test]
+    fn test_lookup_and_convert() {
+        assert_eq!(lookup("KM").map(|u| u.symbol), Some("km"));
+        assert_eq!(lookup("公里").map(|u| u.symbol), Some("km"));
+        assert_eq!(lookup("m").map(|u| u.dimension), Some(Dimension::Length));
+        assert_eq!(lookup("gb").map(|u| u.dimension), Some(Dimension::Data));
+        assert_eq!(lookup("B").map(|u| u.symbol), Some("B"));
+        assert!(lookup("parsec").is_none());
+
+        assert_close(convert_named(10.0, "km", "mi"), 6.213_711_922);
+        assert_close(convert_named(1.0, "ft", "in"), 12.0);
+        assert_close(convert_named(5.0, "GB", "MB"), 5120.0);
+        assert_close(convert_named(1.0, "byte", "bit"), 8.0);
+        assert_close(convert_named(1.0, "lb", "kg"), 0.453_592_37);
+        assert_close(convert_named(1.0, "斤", "g"), 500.0);
+        assert_close(convert_named(1.0, "gal", "l"), 3.785_411_784);
+        assert_close(convert_named(100.0, "km/h", "m/s"), 27.777_777_778);
+        assert_close(convert_named(2.0, "h", "min"), 120.0);
+        assert_close(convert_named(1.0, "ha", "亩"), 15.0);
+
+        assert_close(convert_named(72.0, "f", "c"), 22.222_222_222);
+        assert_close(convert_named(0.0, "℃", "k"), 273.15);
+
+        // 不同量纲之间不能换算
+        assert_eq!(convert_named(1.0, "km", "kg"), None);
+    }
+}
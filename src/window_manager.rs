@@ -1,11 +1,23 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
 
 /// 窗口管理器
 ///
 /// 管理启动器窗口的显示、隐藏和状态
 use gpui::*;
 
-use crate::app::LauncherApp;
+use crate::{
+    app::LauncherApp,
+    core::{config_manager::global_config, error::Result, plugin::ForegroundInfo},
+    platform::{
+        monitor,
+        windows::{
+            foreground_info, foreground_window, hide_launcher, load_foreground_details, paste_into,
+        },
+    },
+};
 
 /// 窗口状态
 #[derive(Clone, Debug, PartialEq)]
@@ -26,6 +38,14 @@ pub struct WindowManager {
     visibility: Arc<Mutex<WindowVisibility>>,
     /// 窗口位置
     position: Arc<Mutex<Option<Point<Pixels>>>>,
+    /// 启动器显示前的前台应用，粘贴时将焦点还给它，插件据此提供上下文结果
+    previous_foreground: Arc<Mutex<Option<ForegroundInfo>>>,
+    /// 窗口激活时填入搜索框的查询（如跳转列表的任务）
+    pending_query: Arc<Mutex<Option<String>>>,
+    /// 托盘菜单请求重新扫描索引，由启动器窗口的定时任务处理
+    rescan_requested: AtomicBool,
+    /// 托盘菜单请求退出，由启动器窗口的定时任务处理
+    quit_requested: AtomicBool,
 }
 
 impl WindowManager {
@@ -35,6 +55,10 @@ impl WindowManager {
             window_handle: Arc::new(Mutex::new(None)),
             visibility: Arc::new(Mutex::new(WindowVisibility::Hidden)),
             position: Arc::new(Mutex::new(None)),
+            previous_foreground: Arc::new(Mutex::new(None)),
+            pending_query: Arc::new(Mutex::new(None)),
+            rescan_requested: AtomicBool::new(false),
+            quit_requested: AtomicBool::new(false),
         }
     }
 
@@ -77,6 +101,103 @@ impl WindowManager {
         }
     }
 
+    /// 记录启动器显示前的前台窗口，需在显示窗口之前调用
+    pub fn capture_foreground(&self) {
+        // 前台已是启动器（如重复显示）时保留之前记录的窗口
+        if let Some(hwnd) = foreground_window() {
+            *self.previous_foreground.lock().unwrap() = Some(foreground_info(hwnd));
+        }
+    }
+
+    /// 启动器显示前的前台应用
+    pub fn foreground(&self) -> Option<ForegroundInfo> {
+        self.previous_foreground.lock().unwrap().clone()
+    }
+
+    /// 读取前台应用的文件夹、选中文本等附加信息，返回是否读到了新的信息
+    ///
+    /// 跨进程读取可能较慢，应在后台线程中调用
+    pub fn load_foreground_details(&self) -> bool {
+        let Some(mut info) = self.foreground() else {
+            return false;
+        };
+        let captured = info.clone();
+        load_foreground_details(&mut info);
+        if info == captured {
+            return false;
+        }
+
+        // 读取期间可能已经再次唤出并记录了其他窗口
+        let mut previous = self.previous_foreground.lock().unwrap();
+        if previous.as_ref() != Some(&captured) {
+            return false;
+        }
+        *previous = Some(info);
+        true
+    }
+
+    /// 将窗口移到配置的显示器并居中，需在记录前台窗口之后、显示窗口之前调用
+    pub fn place_window(&self, hwnd: isize) {
+        let window = global_config().get_config().window;
+        let foreground = self.foreground().map(|info| info.hwnd);
+        monitor::center_on_monitor(hwnd, window.monitor, (window.width, window.height), foreground);
+    }
+
+    /// 隐藏启动器窗口
+    ///
+    /// `restore_focus` 为真（取消，如按 Esc 或再次按快捷键）且启动器仍在前台时，
+    /// 将焦点还给启动器显示前的应用；执行结果后隐藏时焦点留给执行打开的窗口
+    pub fn hide_launcher(&self, hwnd: isize, restore_focus: bool) {
+        let previous = restore_focus.then(|| self.foreground().map(|info| info.hwnd)).flatten();
+        hide_launcher(hwnd, previous);
+        *self.visibility.lock().unwrap() = WindowVisibility::Hidden;
+        log::info!("窗口已隐藏");
+    }
+
+    /// 隐藏启动器，回到之前的前台窗口并粘贴剪贴板内容
+    pub fn paste_to_previous(&self) -> Result<()> {
+        let Some(hwnd) = self.previous_foreground.lock().unwrap().as_ref().map(|info| info.hwnd)
+        else {
+            log::warn!("没有记录启动器显示前的窗口，仅复制到剪贴板");
+            return Ok(());
+        };
+
+        paste_into(hwnd)?;
+        *self.visibility.lock().unwrap() = WindowVisibility::Hidden;
+        log::info!("已粘贴到之前的窗口");
+        Ok(())
+    }
+
+    /// 设置窗口下次激活时填入的查询
+    pub fn set_pending_query(&self, query: &str) {
+        *self.pending_query.lock().unwrap() = Some(query.to_string());
+    }
+
+    /// 取出待填入的查询
+    pub fn take_pending_query(&self) -> Option<String> {
+        self.pending_query.lock().unwrap().take()
+    }
+
+    /// 请求重新扫描索引
+    pub fn request_rescan(&self) {
+        self.rescan_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// 取出重新扫描请求
+    pub fn take_rescan_request(&self) -> bool {
+        self.rescan_requested.swap(false, Ordering::SeqCst)
+    }
+
+    /// 请求退出应用
+    pub fn request_quit(&self) {
+        self.quit_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// 取出退出请求
+    pub fn take_quit_request(&self) -> bool {
+        self.quit_requested.swap(false, Ordering::SeqCst)
+    }
+
     /// 最小化窗口
     pub fn minimize_window(&self) {
         if self.window_handle.lock().unwrap().is_some() {